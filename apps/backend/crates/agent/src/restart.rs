@@ -3,8 +3,14 @@
 //! Receives restart commands from the backend (via WebSocket) and
 //! executes `systemctl restart <service_name>`.  Reports success or
 //! failure back to the caller.
+//!
+//! Repeated restarts of the same service back off exponentially, and
+//! are refused outright once a service has been restarted too often
+//! within the backoff window, to avoid a restart storm on a flapping
+//! service.
 
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use tokio::process::Command;
@@ -12,6 +18,18 @@ use tokio::process::Command;
 /// Default timeout for a restart operation.
 const DEFAULT_RESTART_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Default window over which recent restarts are counted.
+const DEFAULT_BACKOFF_WINDOW: Duration = Duration::from_secs(600);
+
+/// Default number of restarts allowed per service within the window.
+const DEFAULT_MAX_RESTARTS_PER_WINDOW: usize = 5;
+
+/// Default delay before the second restart within the window.
+const DEFAULT_BACKOFF_BASE_DELAY: Duration = Duration::from_secs(5);
+
+/// Default upper bound on a single backoff delay.
+const DEFAULT_BACKOFF_MAX_DELAY: Duration = Duration::from_secs(60);
+
 /// Allowed service name avatars: alphanumeric, hyphen, underscore, dot.
 /// Prevents shell injection via the service name field.
 fn is_safe_service_name(name: &str) -> bool {
@@ -39,6 +57,112 @@ pub struct RestartResult {
     pub duration_ms: u64,
 }
 
+/// Backoff policy for repeated restarts of the same service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartBackoffConfig {
+    /// Window over which recent restarts are counted.
+    pub window: Duration,
+    /// Restarts allowed within the window before further ones are refused.
+    pub max_restarts: usize,
+    /// Delay before the second restart; doubles for each one after.
+    pub base_delay: Duration,
+    /// Upper bound on a single delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RestartBackoffConfig {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_BACKOFF_WINDOW,
+            max_restarts: DEFAULT_MAX_RESTARTS_PER_WINDOW,
+            base_delay: DEFAULT_BACKOFF_BASE_DELAY,
+            max_delay: DEFAULT_BACKOFF_MAX_DELAY,
+        }
+    }
+}
+
+/// Delay to wait before the next restart of a service that has already
+/// been restarted `recent_restarts` times within the window.
+///
+/// The first restart runs immediately; each later one waits twice as long
+/// as the previous, capped at `max_delay`.  Returns `None` once the window
+/// limit is reached, meaning the restart should be refused.
+pub fn next_restart_delay(
+    recent_restarts: usize,
+    config: &RestartBackoffConfig,
+) -> Option<Duration> {
+    if recent_restarts >= config.max_restarts {
+        return None;
+    }
+    if recent_restarts == 0 {
+        return Some(Duration::ZERO);
+    }
+    let exponent = u32::try_from(recent_restarts - 1).unwrap_or(u32::MAX);
+    let factor = 2u32.saturating_pow(exponent);
+    Some(
+        config
+            .base_delay
+            .saturating_mul(factor)
+            .min(config.max_delay),
+    )
+}
+
+/// Per-service record of recent restart attempts.
+#[derive(Debug, Default)]
+pub struct RestartTracker {
+    config: RestartBackoffConfig,
+    attempts: HashMap<String, VecDeque<Instant>>,
+}
+
+impl RestartTracker {
+    /// Create a tracker with no recorded restarts.
+    pub fn new(config: RestartBackoffConfig) -> Self {
+        Self {
+            config,
+            attempts: HashMap::new(),
+        }
+    }
+
+    /// The backoff policy this tracker applies.
+    pub fn config(&self) -> &RestartBackoffConfig {
+        &self.config
+    }
+
+    /// Decide whether `service` may be restarted at `now`.
+    ///
+    /// Returns the delay to wait first, recording the attempt at the time it
+    /// will run, or `None` if the service has hit its window limit.
+    pub fn admit(&mut self, service: &str, now: Instant) -> Option<Duration> {
+        let window = self.config.window;
+        let attempts = self.attempts.entry(service.to_string()).or_default();
+        while attempts
+            .front()
+            .is_some_and(|t| now.saturating_duration_since(*t) >= window)
+        {
+            attempts.pop_front();
+        }
+
+        let delay = next_restart_delay(attempts.len(), &self.config)?;
+        attempts.push_back(now + delay);
+        Some(delay)
+    }
+}
+
+/// Result reported when a restart is refused by the backoff policy.
+pub fn refused_restart(cmd: &RestartCommand, config: &RestartBackoffConfig) -> RestartResult {
+    RestartResult {
+        service_name: cmd.service_name.clone(),
+        success: false,
+        message: format!(
+            "Restart of '{}' refused: restarted {} times in the last {}s",
+            cmd.service_name,
+            config.max_restarts,
+            config.window.as_secs(),
+        ),
+        duration_ms: 0,
+    }
+}
+
 /// Execute a service restart and return the result.
 ///
 /// Uses `systemctl restart` by default.  If `force` is set, passes
@@ -155,4 +279,50 @@ mod tests {
         assert!(!is_safe_service_name("foo bar"));
         assert!(!is_safe_service_name(&"a".repeat(200)));
     }
+
+    #[test]
+    fn restart_delays_escalate_up_to_the_cap() {
+        let config = RestartBackoffConfig::default();
+        let delays: Vec<_> = (0..4)
+            .map(|n| next_restart_delay(n, &config).unwrap().as_secs())
+            .collect();
+        assert_eq!(delays, vec![0, 5, 10, 20]);
+
+        let generous = RestartBackoffConfig {
+            max_restarts: 10,
+            ..config
+        };
+        assert_eq!(
+            next_restart_delay(6, &generous),
+            Some(DEFAULT_BACKOFF_MAX_DELAY)
+        );
+    }
+
+    #[test]
+    fn restart_refused_after_window_limit() {
+        let config = RestartBackoffConfig::default();
+        assert!(next_restart_delay(config.max_restarts - 1, &config).is_some());
+        assert_eq!(next_restart_delay(config.max_restarts, &config), None);
+    }
+
+    #[test]
+    fn tracker_records_attempts_per_service_and_forgets_old_ones() {
+        let config = RestartBackoffConfig {
+            max_restarts: 2,
+            ..RestartBackoffConfig::default()
+        };
+        let mut tracker = RestartTracker::new(config);
+        let start = Instant::now();
+
+        assert_eq!(tracker.admit("comfyui", start), Some(Duration::ZERO));
+        assert_eq!(tracker.admit("comfyui", start), Some(config.base_delay));
+        assert_eq!(tracker.admit("comfyui", start), None);
+
+        // Other services are tracked separately.
+        assert_eq!(tracker.admit("worker", start), Some(Duration::ZERO));
+
+        // Once the window has passed, the service may restart again.
+        let later = start + config.window + config.base_delay;
+        assert_eq!(tracker.admit("comfyui", later), Some(Duration::ZERO));
+    }
 }
@@ -5,18 +5,19 @@
 //! and pushes them as JSON.  Also listens for incoming commands
 //! (e.g. service restarts) from the backend.
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message;
 
 use x121_core::metric_names::{MSG_TYPE_GPU_METRICS, MSG_TYPE_RESTART_RESULT};
 
 use crate::collector::{GpuMetrics, MetricsCollector};
-use crate::restart::{self, RestartCommand, RestartResult};
+use crate::restart::{self, RestartCommand, RestartResult, RestartTracker};
 
 /// Reconnection delay after a WebSocket failure.
 const RECONNECT_DELAY: Duration = Duration::from_secs(5);
@@ -50,15 +51,30 @@ enum IncomingMessage {
 /// Run the metrics push loop indefinitely.
 ///
 /// This function never returns under normal operation.  It reconnects
-/// with a fixed delay if the WebSocket connection drops.
+/// with a fixed delay if the WebSocket connection drops.  Restart history
+/// is kept across reconnects so backoff applies to the service, not the
+/// session, and results of restarts that finish while disconnected are
+/// reported on the next session.
 pub async fn run(ws_url: &str, worker_id: i64, interval: Duration, collector: &MetricsCollector) {
+    let mut restarts = RestartTracker::default();
+    let (results_tx, mut results_rx) = mpsc::unbounded_channel();
+
     loop {
         tracing::info!(url = %ws_url, "Connecting to backend WebSocket");
 
         match connect_async(ws_url).await {
             Ok((ws_stream, _response)) => {
                 tracing::info!("WebSocket connected");
-                run_session(ws_stream, worker_id, interval, collector).await;
+                run_session(
+                    ws_stream,
+                    worker_id,
+                    interval,
+                    collector,
+                    &mut restarts,
+                    &results_tx,
+                    &mut results_rx,
+                )
+                .await;
                 tracing::warn!("WebSocket session ended, reconnecting");
             }
             Err(e) => {
@@ -70,8 +86,8 @@ pub async fn run(ws_url: &str, worker_id: i64, interval: Duration, collector: &M
     }
 }
 
-/// Drive a single WebSocket session: push metrics on a timer and
-/// handle incoming commands via `tokio::select!`.
+/// Drive a single WebSocket session: push metrics on a timer, handle
+/// incoming commands, and report finished restarts via `tokio::select!`.
 async fn run_session(
     ws_stream: tokio_tungstenite::WebSocketStream<
         tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
@@ -79,6 +95,9 @@ async fn run_session(
     worker_id: i64,
     interval: Duration,
     collector: &MetricsCollector,
+    restarts: &mut RestartTracker,
+    results_tx: &mpsc::UnboundedSender<RestartResult>,
+    results_rx: &mut mpsc::UnboundedReceiver<RestartResult>,
 ) {
     let (mut sink, mut stream) = ws_stream.split();
     let mut ticker = tokio::time::interval(interval);
@@ -91,10 +110,13 @@ async fn run_session(
                     break;
                 }
             }
+            Some(result) = results_rx.recv() => {
+                send_restart_result(&mut sink, worker_id, result).await;
+            }
             msg = stream.next() => {
                 match msg {
                     Some(Ok(Message::Text(text))) => {
-                        handle_incoming(&mut sink, worker_id, &text).await;
+                        handle_incoming(&mut sink, worker_id, &text, restarts, results_tx).await;
                     }
                     Some(Ok(Message::Ping(_) | Message::Pong(_))) => {
                         // Handled automatically by tungstenite.
@@ -144,15 +166,44 @@ where
 }
 
 /// Parse and dispatch an incoming text message from the backend.
-async fn handle_incoming<S>(sink: &mut S, worker_id: i64, text: &str)
-where
+///
+/// Restart commands go through the backoff policy first: they may be
+/// refused if the service is restarting too often. Admitted restarts run
+/// on their own task, so a backoff delay does not stall metric pushes or
+/// reads; their results arrive on `results_tx`.
+async fn handle_incoming<S>(
+    sink: &mut S,
+    worker_id: i64,
+    text: &str,
+    restarts: &mut RestartTracker,
+    results_tx: &mpsc::UnboundedSender<RestartResult>,
+) where
     S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
 {
     match serde_json::from_str::<IncomingMessage>(text) {
         Ok(IncomingMessage::Restart(cmd)) => {
             tracing::info!(service = %cmd.service_name, force = cmd.force, "Received restart command");
-            let result = restart::execute_restart(&cmd).await;
-            send_restart_result(sink, worker_id, result).await;
+            match restarts.admit(&cmd.service_name, Instant::now()) {
+                Some(delay) => {
+                    if !delay.is_zero() {
+                        tracing::warn!(
+                            service = %cmd.service_name,
+                            delay_secs = delay.as_secs(),
+                            "Backing off before restart",
+                        );
+                    }
+                    let results_tx = results_tx.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(delay).await;
+                        let _ = results_tx.send(restart::execute_restart(&cmd).await);
+                    });
+                }
+                None => {
+                    tracing::warn!(service = %cmd.service_name, "Restart refused by backoff policy");
+                    let result = restart::refused_restart(&cmd, restarts.config());
+                    send_restart_result(sink, worker_id, result).await;
+                }
+            }
         }
         Err(e) => {
             tracing::warn!(error = %e, raw = %text, "Unknown or malformed incoming message");
@@ -3,8 +3,13 @@
 //! Access tokens are HS256-signed JWTs containing a [`Claims`] payload.
 //! Refresh tokens are opaque random strings; only their SHA-256 hash is stored
 //! server-side so a database leak does not compromise active sessions.
+//!
+//! Signing keys can be rotated without logging everyone out: each token
+//! carries the `kid` of the key that signed it, and keys retired by a
+//! rotation keep verifying tokens until their grace window ends.
 
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::errors::{Error as JwtError, ErrorKind};
+use jsonwebtoken::{decode, decode_header, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
@@ -25,11 +30,49 @@ pub struct Claims {
     pub jti: String,
 }
 
+/// An HMAC-SHA256 key with the identifier written to the `kid` header.
+#[derive(Debug, Clone)]
+pub struct JwtKey {
+    /// Key id, derived from the secret so every instance agrees on it.
+    pub kid: String,
+    pub secret: String,
+    /// Unix timestamp after which the key no longer verifies tokens;
+    /// `None` while it is trusted indefinitely.
+    pub expires_at: Option<i64>,
+}
+
+impl JwtKey {
+    /// Build a key trusted indefinitely.
+    pub fn new(secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        Self {
+            kid: key_id(&secret),
+            secret,
+            expires_at: None,
+        }
+    }
+
+    fn is_trusted_at(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+}
+
+/// Key id for `secret`: a truncated SHA-256 digest, so the id is stable
+/// across restarts and instances without revealing the secret.
+fn key_id(secret: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"x121-jwt-kid:");
+    hasher.update(secret.as_bytes());
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
 /// Configuration for JWT token generation and validation.
 #[derive(Debug, Clone)]
 pub struct JwtConfig {
-    /// HMAC-SHA256 secret used to sign and verify tokens.
-    pub secret: String,
+    /// Key that signs new tokens.
+    pub current: JwtKey,
+    /// Earlier keys still accepted for verification.
+    pub previous: Vec<JwtKey>,
     /// Access token lifetime in minutes (default: 15).
     pub access_token_expiry_mins: i64,
     /// Refresh token lifetime in days (default: 7).
@@ -49,6 +92,11 @@ impl JwtConfig {
     /// | `JWT_SECRET`               | **yes**  | --      |
     /// | `JWT_ACCESS_EXPIRY_MINS`   | no       | `15`    |
     /// | `JWT_REFRESH_EXPIRY_DAYS`  | no       | `7`     |
+    /// | `JWT_PREVIOUS_SECRETS`     | no       | --      |
+    ///
+    /// `JWT_PREVIOUS_SECRETS` is a comma-separated list of retired secrets
+    /// whose tokens are still accepted. Drop them once the access token
+    /// lifetime has passed since the switch.
     ///
     /// # Panics
     ///
@@ -68,12 +116,51 @@ impl JwtConfig {
             .parse()
             .expect("JWT_REFRESH_EXPIRY_DAYS must be a valid i64");
 
+        let previous = std::env::var("JWT_PREVIOUS_SECRETS")
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty() && *s != secret)
+                    .map(JwtKey::new)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Self {
-            secret,
+            current: JwtKey::new(secret),
+            previous,
             access_token_expiry_mins,
             refresh_token_expiry_days,
         }
     }
+
+    /// Make `new_secret` the signing key.
+    ///
+    /// The old current key keeps verifying for one access token lifetime,
+    /// long enough for every token it signed to expire on its own. Keys
+    /// whose grace window has already ended are dropped.
+    pub fn rotate(&mut self, new_secret: impl Into<String>) {
+        let now = chrono::Utc::now().timestamp();
+        let new_key = JwtKey::new(new_secret);
+        if new_key.kid == self.current.kid {
+            return;
+        }
+
+        let mut retired = std::mem::replace(&mut self.current, new_key);
+        let grace_end = now + self.access_token_expiry_mins * 60;
+        retired.expires_at = Some(retired.expires_at.map_or(grace_end, |t| t.min(grace_end)));
+        self.previous.insert(0, retired);
+        let current_kid = &self.current.kid;
+        self.previous
+            .retain(|key| key.is_trusted_at(now) && key.kid != *current_kid);
+    }
+
+    /// Find the trusted key with id `kid` as of `now`.
+    fn verifying_key(&self, kid: &str, now: i64) -> Option<&JwtKey> {
+        std::iter::once(&self.current)
+            .chain(&self.previous)
+            .find(|key| key.kid == kid && key.is_trusted_at(now))
+    }
 }
 
 /// Generate an HS256 access token for the given user.
@@ -96,23 +183,33 @@ pub fn generate_access_token(
         jti: Uuid::new_v4().to_string(),
     };
 
+    let header = Header {
+        kid: Some(config.current.kid.clone()),
+        ..Header::default() // HS256
+    };
     encode(
-        &Header::default(), // HS256
+        &header,
         &claims,
-        &EncodingKey::from_secret(config.secret.as_bytes()),
+        &EncodingKey::from_secret(config.current.secret.as_bytes()),
     )
 }
 
 /// Validate and decode an access token, returning the embedded [`Claims`].
 ///
-/// Validates the signature, expiration, and issued-at claims automatically.
-pub fn validate_token(
-    token: &str,
-    config: &JwtConfig,
-) -> Result<Claims, jsonwebtoken::errors::Error> {
+/// The verifying key is picked by the token's `kid`; tokens naming an
+/// unknown or retired key are rejected. Tokens without a `kid` predate key
+/// ids and are checked against the current key. Validates the signature,
+/// expiration, and issued-at claims automatically.
+pub fn validate_token(token: &str, config: &JwtConfig) -> Result<Claims, JwtError> {
+    let key = match decode_header(token)?.kid {
+        Some(kid) => config
+            .verifying_key(&kid, chrono::Utc::now().timestamp())
+            .ok_or_else(|| JwtError::from(ErrorKind::InvalidToken))?,
+        None => &config.current,
+    };
     let token_data = decode::<Claims>(
         token,
-        &DecodingKey::from_secret(config.secret.as_bytes()),
+        &DecodingKey::from_secret(key.secret.as_bytes()),
         &Validation::default(), // HS256, validates exp
     )?;
     Ok(token_data.claims)
@@ -144,7 +241,8 @@ mod tests {
     /// Helper to build a test config with a known secret.
     fn test_config() -> JwtConfig {
         JwtConfig {
-            secret: "test-secret-that-is-long-enough-for-hmac".to_string(),
+            current: JwtKey::new("test-secret-that-is-long-enough-for-hmac"),
+            previous: Vec::new(),
             access_token_expiry_mins: 15,
             refresh_token_expiry_days: 7,
         }
@@ -181,7 +279,7 @@ mod tests {
         let token = encode(
             &Header::default(),
             &claims,
-            &EncodingKey::from_secret(config.secret.as_bytes()),
+            &EncodingKey::from_secret(config.current.secret.as_bytes()),
         )
         .expect("encoding should succeed");
 
@@ -204,14 +302,12 @@ mod tests {
     #[test]
     fn test_different_secrets_fail() {
         let config_a = JwtConfig {
-            secret: "secret-alpha".to_string(),
-            access_token_expiry_mins: 15,
-            refresh_token_expiry_days: 7,
+            current: JwtKey::new("secret-alpha"),
+            ..test_config()
         };
         let config_b = JwtConfig {
-            secret: "secret-bravo".to_string(),
-            access_token_expiry_mins: 15,
-            refresh_token_expiry_days: 7,
+            current: JwtKey::new("secret-bravo"),
+            ..test_config()
         };
 
         let token =
@@ -223,4 +319,87 @@ mod tests {
             "token signed with a different secret must fail"
         );
     }
+
+    #[test]
+    fn test_token_header_carries_current_kid() {
+        let config = test_config();
+        let token = generate_access_token(1, "user", &config).unwrap();
+        let header = decode_header(&token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some(config.current.kid.as_str()));
+        assert_eq!(config.current.kid.len(), 16);
+        assert_eq!(
+            config.current.kid,
+            JwtKey::new(config.current.secret.clone()).kid
+        );
+    }
+
+    #[test]
+    fn test_old_key_token_validates_after_rotation() {
+        let mut config = test_config();
+        let old_token = generate_access_token(7, "admin", &config).unwrap();
+        let old_kid = config.current.kid.clone();
+
+        config.rotate("rotated-secret-that-is-long-enough-for-hmac");
+        assert_ne!(config.current.kid, old_kid);
+        assert_eq!(config.previous.len(), 1);
+        assert!(config.previous[0].expires_at.is_some());
+
+        let claims = validate_token(&old_token, &config).expect("old token stays valid");
+        assert_eq!(claims.sub, 7);
+
+        // New tokens use the new key, which a config without it rejects.
+        let new_token = generate_access_token(8, "user", &config).unwrap();
+        assert_eq!(validate_token(&new_token, &config).unwrap().sub, 8);
+        assert!(validate_token(&new_token, &test_config()).is_err());
+    }
+
+    #[test]
+    fn test_retired_key_rejected_after_grace_window() {
+        let mut config = test_config();
+        let old_token = generate_access_token(7, "admin", &config).unwrap();
+        config.rotate("rotated-secret-that-is-long-enough-for-hmac");
+
+        config.previous[0].expires_at = Some(chrono::Utc::now().timestamp() - 1);
+        assert!(validate_token(&old_token, &config).is_err());
+
+        // The next rotation drops it entirely.
+        config.rotate("another-secret-that-is-long-enough-for-hmac");
+        assert_eq!(config.previous.len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_kid_rejected() {
+        let config = test_config();
+        let now = chrono::Utc::now().timestamp();
+        let claims = Claims {
+            sub: 1,
+            role: "user".to_string(),
+            exp: now + 300,
+            iat: now,
+            jti: Uuid::new_v4().to_string(),
+        };
+        let header = Header {
+            kid: Some("0000000000000000".to_string()),
+            ..Header::default()
+        };
+        // Signed with the right secret, but naming a key we do not hold.
+        let token = encode(
+            &header,
+            &claims,
+            &EncodingKey::from_secret(config.current.secret.as_bytes()),
+        )
+        .unwrap();
+
+        let err = validate_token(&token, &config).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidToken);
+
+        // Tokens from before key ids are checked against the current key.
+        let legacy = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.current.secret.as_bytes()),
+        )
+        .unwrap();
+        assert_eq!(validate_token(&legacy, &config).unwrap().sub, 1);
+    }
 }
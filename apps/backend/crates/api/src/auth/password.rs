@@ -3,23 +3,80 @@
 //! All password hashes use the Argon2id variant with a cryptographically random
 //! salt generated via [`OsRng`]. The PHC string format is used for storage so
 //! that algorithm parameters and salt are embedded in the hash itself.
+//!
+//! The cost parameters for new hashes come from [`PasswordHashConfig`].
+//! Existing hashes keep verifying with the parameters they were created
+//! with; [`needs_rehash`] tells the login flow when to upgrade one.
 
 use argon2::password_hash::rand_core::OsRng;
 use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
-use argon2::Argon2;
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// Argon2id cost parameters for new password hashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordHashConfig {
+    /// Memory cost in KiB.
+    pub memory_kib: u32,
+    /// Number of passes over memory.
+    pub iterations: u32,
+    /// Degree of parallelism (lanes).
+    pub parallelism: u32,
+}
+
+impl PasswordHashConfig {
+    /// Check the parameters are within Argon2's accepted ranges.
+    pub fn validate(&self) -> Result<(), String> {
+        self.params().map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    fn params(&self) -> Result<Params, argon2::Error> {
+        Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+    }
+
+    fn hasher(&self) -> Result<Argon2<'static>, argon2::password_hash::Error> {
+        Ok(Argon2::new(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            self.params()?,
+        ))
+    }
+}
 
-/// Hash a plaintext password using Argon2id with a random salt.
+impl Default for PasswordHashConfig {
+    /// The `argon2` crate's recommended defaults.
+    fn default() -> Self {
+        Self {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// Hash a plaintext password using Argon2id with the default parameters and
+/// a random salt.
 ///
 /// Returns the PHC-formatted hash string (includes algorithm, params, salt, and hash).
 pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    hash_password_with(password, &PasswordHashConfig::default())
+}
+
+/// Hash a plaintext password using Argon2id with the given parameters and a
+/// random salt.
+pub fn hash_password_with(
+    password: &str,
+    config: &PasswordHashConfig,
+) -> Result<String, argon2::password_hash::Error> {
     let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default(); // Argon2id with default params
-    let hash = argon2.hash_password(password.as_bytes(), &salt)?;
+    let hash = config.hasher()?.hash_password(password.as_bytes(), &salt)?;
     Ok(hash.to_string())
 }
 
 /// Verify a plaintext password against a stored PHC-formatted Argon2id hash.
 ///
+/// The parameters embedded in the hash are used, so hashes created with
+/// older settings still verify.
+///
 /// Returns `Ok(true)` if the password matches, `Ok(false)` if it does not.
 pub fn verify_password(password: &str, hash: &str) -> Result<bool, argon2::password_hash::Error> {
     let parsed_hash = PasswordHash::new(hash)?;
@@ -30,6 +87,29 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, argon2::passw
     }
 }
 
+/// Whether a stored hash should be replaced by one made with `config`.
+///
+/// True when the hash is not Argon2id v19 or any of its cost parameters is
+/// below the configured one. Hashes stronger than the configuration are
+/// left alone. Unparseable hashes return `false`: they cannot have been
+/// verified, so there is no password to re-hash.
+pub fn needs_rehash(hash: &str, config: &PasswordHashConfig) -> bool {
+    let Ok(parsed) = PasswordHash::new(hash) else {
+        return false;
+    };
+    if parsed.algorithm != argon2::ARGON2ID_IDENT || parsed.version != Some(Version::V0x13.into()) {
+        return true;
+    }
+    match Params::try_from(&parsed) {
+        Ok(params) => {
+            params.m_cost() < config.memory_kib
+                || params.t_cost() < config.iterations
+                || params.p_cost() < config.parallelism
+        }
+        Err(_) => true,
+    }
+}
+
 /// Validate that a password meets minimum strength requirements.
 ///
 /// Currently enforces a minimum avatar length. Returns `Ok(())` when the
@@ -69,6 +149,58 @@ mod tests {
         assert!(!verified, "wrong password should verify as false");
     }
 
+    /// Cheaper than the defaults, standing in for a previous configuration.
+    const WEAK: PasswordHashConfig = PasswordHashConfig {
+        memory_kib: 8192,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn test_hash_with_old_params_still_verifies() {
+        let hash = hash_password_with("old-password", &WEAK).expect("hashing should succeed");
+        assert!(hash.contains("m=8192,t=1,p=1"));
+        assert!(verify_password("old-password", &hash).expect("verify should succeed"));
+        assert!(!verify_password("other-password", &hash).expect("verify should succeed"));
+    }
+
+    #[test]
+    fn test_needs_rehash_when_stored_params_weaker() {
+        let current = PasswordHashConfig::default();
+        let hash = hash_password_with("pw", &WEAK).unwrap();
+        assert!(needs_rehash(&hash, &current));
+
+        // Any single weaker parameter is enough.
+        let fewer_passes = PasswordHashConfig {
+            iterations: current.iterations - 1,
+            ..current
+        };
+        let hash = hash_password_with("pw", &fewer_passes).unwrap();
+        assert!(needs_rehash(&hash, &current));
+    }
+
+    #[test]
+    fn test_needs_rehash_false_when_params_match() {
+        let current = PasswordHashConfig::default();
+        let hash = hash_password("pw").unwrap();
+        assert!(!needs_rehash(&hash, &current));
+
+        // Stronger than configured is never downgraded.
+        assert!(!needs_rehash(&hash, &WEAK));
+        assert!(!needs_rehash("not-a-phc-hash", &current));
+    }
+
+    #[test]
+    fn test_invalid_params_rejected() {
+        assert!(PasswordHashConfig::default().validate().is_ok());
+        let invalid = PasswordHashConfig {
+            iterations: 0,
+            ..WEAK
+        };
+        assert!(invalid.validate().is_err());
+        assert!(hash_password_with("pw", &invalid).is_err());
+    }
+
     #[test]
     fn test_password_too_short() {
         let result = validate_password_strength("short", 12);
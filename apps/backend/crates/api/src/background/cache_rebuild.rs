@@ -0,0 +1,243 @@
+//! Studio-wide completeness and readiness cache rebuild (PRD-18, PRD-66, PRD-107).
+//!
+//! Walks every live avatar in keyset-paginated chunks, recomputes metadata
+//! completeness with [`calculate_completeness`] and feeds it into
+//! [`evaluate_readiness`], then upserts the result into
+//! `avatar_readiness_cache`. Progress is written to the owning
+//! `bulk_operations` row after each chunk, and the row's status is
+//! re-checked between chunks so an admin can cancel a running rebuild.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use x121_core::maintenance::REBUILD_CACHES_CHUNK_SIZE;
+use x121_core::metadata_editor::{calculate_completeness, MetadataFieldDef};
+use x121_core::readiness::{
+    self, evaluate_readiness, parse_scoped_criteria_json, ReadinessCriteria,
+    ScopedReadinessCriteria,
+};
+use x121_core::types::DbId;
+use x121_db::models::readiness_cache::{AvatarReadinessInputs, UpsertReadinessCache};
+use x121_db::models::status::BulkOperationStatusId;
+use x121_db::repositories::{BulkOperationRepo, ReadinessCacheRepo, ReadinessCriteriaRepo};
+use x121_events::PlatformEvent;
+
+use crate::handlers::avatar_metadata::load_template_fields;
+use crate::state::AppState;
+
+/// Outcome of a cache rebuild run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RebuildCachesReport {
+    /// Avatars whose completeness and readiness were recomputed.
+    pub avatars_processed: u64,
+    /// Avatars whose metadata was fully complete.
+    pub complete_avatars: u64,
+    /// Whether the run stopped early because the operation was cancelled.
+    pub cancelled: bool,
+}
+
+/// Run the rebuild for bulk operation `operation_id`, recording the final
+/// status on the operation row.
+///
+/// Errors are logged and stored as `failed` on the operation.
+pub async fn run_cache_rebuild(state: AppState, operation_id: DbId, executed_by: DbId) {
+    let bus = state.event_bus.clone();
+    let result = rebuild_caches(
+        &state.pool,
+        operation_id,
+        REBUILD_CACHES_CHUNK_SIZE,
+        |processed| {
+            bus.publish(
+                PlatformEvent::new("maintenance.rebuild_caches.progress")
+                    .with_source("bulk_operation", operation_id)
+                    .with_payload(serde_json::json!({ "processed": processed })),
+            );
+        },
+    )
+    .await;
+
+    match result {
+        Ok(report) => {
+            // A cancelled run only records its final count; a completed run
+            // must not overwrite a cancellation that landed after the last chunk.
+            let (from, to) = if report.cancelled {
+                (
+                    BulkOperationStatusId::Cancelled,
+                    BulkOperationStatusId::Cancelled,
+                )
+            } else {
+                (
+                    BulkOperationStatusId::Executing,
+                    BulkOperationStatusId::Completed,
+                )
+            };
+            let _ = BulkOperationRepo::finish_execution(
+                &state.pool,
+                operation_id,
+                from.id(),
+                to.id(),
+                report.avatars_processed as i32,
+                Some(executed_by),
+                Some(chrono::Utc::now()),
+            )
+            .await;
+            tracing::info!(
+                operation_id,
+                avatars_processed = report.avatars_processed,
+                cancelled = report.cancelled,
+                "Cache rebuild finished"
+            );
+        }
+        Err(e) => {
+            tracing::error!(operation_id, error = %e, "Cache rebuild failed");
+            let _ = BulkOperationRepo::update_error(
+                &state.pool,
+                operation_id,
+                BulkOperationStatusId::Failed.id(),
+                &e.to_string(),
+            )
+            .await;
+        }
+    }
+}
+
+/// Recompute completeness and readiness for all live avatars, `chunk_size`
+/// at a time.
+///
+/// After each chunk the processed count is stored on the operation and
+/// passed to `on_progress`. If the operation has been moved to `cancelled`
+/// the loop stops before the next chunk.
+pub async fn rebuild_caches(
+    pool: &sqlx::PgPool,
+    operation_id: DbId,
+    chunk_size: i64,
+    on_progress: impl Fn(u64),
+) -> Result<RebuildCachesReport, sqlx::Error> {
+    let studio_criteria = ReadinessCriteriaRepo::find_studio_default(pool)
+        .await?
+        .and_then(|row| parse_scoped_criteria_json(&row.criteria_json).ok());
+
+    // Criteria and template fields are resolved once per project.
+    let mut project_context: HashMap<DbId, (ReadinessCriteria, Vec<MetadataFieldDef>)> =
+        HashMap::new();
+    let mut report = RebuildCachesReport::default();
+    let mut cursor: DbId = 0;
+
+    loop {
+        if is_cancelled(pool, operation_id).await? {
+            report.cancelled = true;
+            break;
+        }
+
+        let chunk = ReadinessCacheRepo::list_inputs_after(pool, cursor, chunk_size.max(1)).await?;
+        let Some(last) = chunk.last() else {
+            break;
+        };
+        cursor = last.avatar_id;
+
+        for inputs in &chunk {
+            let (criteria, fields) = match project_context.entry(inputs.project_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(
+                    load_project_context(pool, studio_criteria.as_ref(), inputs.project_id).await?,
+                ),
+            };
+
+            let metadata_complete = recompute_avatar(pool, inputs, criteria, fields).await?;
+            if metadata_complete {
+                report.complete_avatars += 1;
+            }
+            report.avatars_processed += 1;
+        }
+
+        BulkOperationRepo::update_affected_count(
+            pool,
+            operation_id,
+            report.avatars_processed as i32,
+        )
+        .await?;
+        on_progress(report.avatars_processed);
+    }
+
+    Ok(report)
+}
+
+/// Resolve the effective readiness criteria and metadata template fields
+/// for a project.
+async fn load_project_context(
+    pool: &sqlx::PgPool,
+    studio_criteria: Option<&ScopedReadinessCriteria>,
+    project_id: DbId,
+) -> Result<(ReadinessCriteria, Vec<MetadataFieldDef>), sqlx::Error> {
+    let project_criteria =
+        ReadinessCriteriaRepo::find_by_scope(pool, readiness::SCOPE_PROJECT, Some(project_id))
+            .await?
+            .and_then(|row| parse_scoped_criteria_json(&row.criteria_json).ok());
+    let criteria =
+        readiness::resolve_effective_criteria(studio_criteria, project_criteria.as_ref());
+    let fields = load_template_fields(pool, Some(project_id)).await?;
+    Ok((criteria, fields))
+}
+
+/// Recompute and cache one avatar's readiness. Returns whether its
+/// metadata was complete.
+async fn recompute_avatar(
+    pool: &sqlx::PgPool,
+    inputs: &AvatarReadinessInputs,
+    criteria: &ReadinessCriteria,
+    fields: &[MetadataFieldDef],
+) -> Result<bool, sqlx::Error> {
+    let metadata = inputs
+        .metadata
+        .as_ref()
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+    let completeness = calculate_completeness(inputs.avatar_id, &metadata, fields);
+    let metadata_complete = completeness.missing_fields.is_empty();
+
+    let present_settings: Vec<String> = inputs
+        .settings
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, _)| k.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let result = evaluate_readiness(
+        inputs.avatar_id,
+        criteria,
+        inputs.has_source_media,
+        inputs.has_approved_variant,
+        metadata_complete,
+        inputs.has_metadata_approved,
+        &present_settings,
+    );
+
+    ReadinessCacheRepo::upsert(
+        pool,
+        &UpsertReadinessCache {
+            avatar_id: inputs.avatar_id,
+            state: result.state.as_str().to_string(),
+            missing_items: serde_json::json!(result.missing_items),
+            readiness_pct: i32::from(result.readiness_pct),
+        },
+    )
+    .await?;
+
+    Ok(metadata_complete)
+}
+
+/// Whether an admin has cancelled the operation since it started.
+pub(crate) async fn is_cancelled(
+    pool: &sqlx::PgPool,
+    operation_id: DbId,
+) -> Result<bool, sqlx::Error> {
+    Ok(BulkOperationRepo::find_by_id(pool, operation_id)
+        .await?
+        .is_some_and(|op| op.status_id == BulkOperationStatusId::Cancelled.id()))
+}
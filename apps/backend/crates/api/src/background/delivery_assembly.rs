@@ -6,6 +6,9 @@
 //! Files inside each per-avatar RAR are named according to the dynamic
 //! naming rules (delivery_video, delivery_image, delivery_metadata, delivery_folder).
 //! Structure is flat unless delivery_folder defines subdirectories.
+//!
+//! Once an export completes, the project's `post_delivery` hooks (PRD-77)
+//! run with the export details as input.
 
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -13,9 +16,10 @@ use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
 use x121_core::activity::{ActivityLogEntry, ActivityLogLevel, ActivityLogSource};
-use x121_core::assembly;
+use x121_core::assembly::{self, WatermarkOverride, WatermarkSource};
 use x121_core::ffmpeg::{self, TranscodeProfileParams};
 use x121_core::naming_engine::{self, NamingContext};
+use x121_core::pipeline_hooks::HookPoint;
 use x121_core::types::DbId;
 use x121_db::models::delivery_export::DeliveryExport;
 use x121_db::models::delivery_log::CreateDeliveryLog;
@@ -23,8 +27,10 @@ use x121_db::repositories::{
     AvatarMetadataVersionRepo, AvatarRepo, DeliveryExportRepo, MediaVariantRepo, NamingRuleRepo,
     OutputFormatProfileRepo, PipelineRepo, ProjectDeliveryLogRepo, ProjectRepo, SceneRepo,
     SceneTypeRepo, SceneTypeTrackConfigRepo, SceneVideoVersionRepo, TrackRepo,
+    WatermarkSettingRepo,
 };
 
+use crate::engine::hook_executor::HookExecutor;
 use crate::state::AppState;
 
 const POLL_INTERVAL: Duration = Duration::from_secs(10);
@@ -182,6 +188,43 @@ async fn run_pipeline(
     )
     .await;
 
+    // Resolve the effective watermark. The studio default is the first
+    // configured watermark setting; output profiles carry no watermark of
+    // their own yet, and the export's `include_watermark` flag acts as the
+    // project-level override (off disables, on inherits).
+    let studio_watermark = WatermarkSettingRepo::list_all(&state.pool)
+        .await?
+        .into_iter()
+        .find(|w| !w.content.trim().is_empty())
+        .map(|w| w.to_spec());
+    let project_watermark = if export.include_watermark {
+        WatermarkOverride::Inherit
+    } else {
+        WatermarkOverride::Disabled
+    };
+    let watermark = assembly::resolve_watermark(
+        studio_watermark.as_ref(),
+        &WatermarkOverride::Inherit,
+        &project_watermark,
+    );
+    let source = match watermark.source {
+        Some(WatermarkSource::Studio) => "studio default",
+        Some(WatermarkSource::Profile) => "output profile",
+        Some(WatermarkSource::Project) => "project",
+        None => "no configuration",
+    };
+    log_step(
+        state,
+        export_id,
+        project_id,
+        "info",
+        &match &watermark.watermark {
+            Some(w) => format!("Watermark: '{}' (from {source})", w.name),
+            None => format!("Watermark: none (from {source})"),
+        },
+    )
+    .await;
+
     // -----------------------------------------------------------------------
     // Phase 1: Assembling — collect all final videos with naming context
     // -----------------------------------------------------------------------
@@ -635,6 +678,14 @@ async fn run_pipeline(
     );
     log_step(state, export_id, project_id, "info", &done_msg).await;
 
+    let hook_input = serde_json::json!({
+        "export_id": export_id,
+        "project_id": project_id,
+        "path": relative_path,
+        "file_size": file_size,
+    });
+    run_post_delivery_hooks(state, export_id, project_id, &hook_input).await;
+
     // Clean up temp transcode directory.
     if temp_dir.exists() {
         let _ = tokio::fs::remove_dir_all(&temp_dir).await;
@@ -688,6 +739,46 @@ fn check_cancelled(cancel: &CancellationToken) -> Result<(), PipelineError> {
     }
 }
 
+/// Run the project's `post_delivery` hooks for a completed export.
+///
+/// The export is already complete, so hook failures are logged against it
+/// but do not fail it; a `block` hook only stops the remaining hooks.
+async fn run_post_delivery_hooks(
+    state: &AppState,
+    export_id: DbId,
+    project_id: DbId,
+    input: &serde_json::Value,
+) {
+    let executor = HookExecutor::new(
+        state.pool.clone(),
+        state.config.hook_execution_concurrency,
+        state.config.hook_scripts_dir.clone(),
+    );
+    let outcome = match executor
+        .run_project_hooks(HookPoint::PostDelivery, project_id, input, None)
+        .await
+    {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            tracing::error!(export_id, error = %e, "Failed to resolve post-delivery hooks");
+            return;
+        }
+    };
+
+    for result in outcome.results.iter().filter(|r| !r.success) {
+        let message = format!(
+            "Post-delivery hook #{} failed: {}",
+            result.hook_id,
+            result.error_message.as_deref().unwrap_or("unknown error"),
+        );
+        log_step(state, export_id, project_id, "warning", &message).await;
+    }
+    if let Some(hook_id) = outcome.halted_by {
+        let message = format!("Post-delivery hooks halted by blocking hook #{hook_id}");
+        log_step(state, export_id, project_id, "warning", &message).await;
+    }
+}
+
 /// Dual-log: write to delivery_logs table + broadcast to activity console.
 async fn log_step(state: &AppState, export_id: DbId, project_id: DbId, level: &str, message: &str) {
     let _ = ProjectDeliveryLogRepo::create(
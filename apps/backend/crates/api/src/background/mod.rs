@@ -7,8 +7,12 @@
 pub mod activity_persistence;
 pub mod activity_retention;
 pub mod activity_tracing;
+pub mod cache_rebuild;
 pub mod delivery_assembly;
 pub mod export_archive;
 pub mod metrics_retention;
+pub mod quota_reset;
 pub mod schedule_executor;
 pub mod video_transcode;
+pub mod webhook_delivery;
+pub mod wiki_search_index;
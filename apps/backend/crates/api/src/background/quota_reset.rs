@@ -0,0 +1,76 @@
+//! Periodic GPU quota resets (PRD-08).
+//!
+//! Advances each enabled quota's daily and weekly reset instants once their
+//! period rolls over in the quota's timezone. Quota checks already resolve
+//! due resets on the fly; this job persists them so the stored windows stay
+//! current. Follows the `metrics_retention.rs` pattern.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use x121_core::quota_reset::quota_windows;
+use x121_db::repositories::GpuQuotaRepo;
+
+/// How often due resets are applied.
+const RESET_INTERVAL: Duration = Duration::from_secs(300); // 5 minutes
+
+/// Run the quota reset loop until `cancel` is triggered.
+pub async fn run(pool: PgPool, cancel: CancellationToken) {
+    tracing::info!(
+        interval_secs = RESET_INTERVAL.as_secs(),
+        "Quota reset job started"
+    );
+
+    let mut interval = tokio::time::interval(RESET_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                tracing::info!("Quota reset job stopping");
+                break;
+            }
+            _ = interval.tick() => {
+                apply_due_resets(&pool).await;
+            }
+        }
+    }
+}
+
+/// Perform one cycle: advance every quota whose period has rolled over.
+async fn apply_due_resets(pool: &PgPool) {
+    let quotas = match GpuQuotaRepo::list_enabled(pool).await {
+        Ok(quotas) => quotas,
+        Err(e) => {
+            tracing::error!(error = %e, "Quota reset: failed to list quotas");
+            return;
+        }
+    };
+
+    let now = Utc::now();
+    let mut reset = 0usize;
+    for quota in quotas {
+        let windows = quota_windows(
+            &quota.timezone,
+            quota.daily_reset_at,
+            quota.weekly_reset_at,
+            now,
+        );
+        match GpuQuotaRepo::apply_resets(pool, quota.id, windows.daily_start, windows.weekly_start)
+            .await
+        {
+            Ok(true) => reset += 1,
+            Ok(false) => {}
+            Err(e) => {
+                tracing::error!(quota_id = quota.id, error = %e, "Quota reset: update failed");
+            }
+        }
+    }
+
+    if reset > 0 {
+        tracing::info!(reset, "Quota reset: advanced quota windows");
+    } else {
+        tracing::debug!("Quota reset: no quotas due");
+    }
+}
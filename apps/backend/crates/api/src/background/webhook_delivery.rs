@@ -0,0 +1,251 @@
+//! Webhook delivery worker with per-webhook circuit breaking (PRD-12).
+//!
+//! Polls `webhook_deliveries` for pending or retrying rows and POSTs each
+//! payload to its webhook, signing the body with the webhook secret when one
+//! is set (see [`sign_request`]). A delivery whose retries are exhausted is
+//! a permanent failure and is moved to the dead-letter queue, from where it
+//! can be replayed; after `webhook_failure_threshold` consecutive permanent
+//! failures the webhook is suspended and its deliveries are held back.
+//! Suspended webhooks receive a probe every `webhook_probe_interval_secs` and
+//! are re-activated by the first successful one.
+
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use tokio_util::sync::CancellationToken;
+use x121_core::api_keys::{
+    webhook_retry_delay_secs, WebhookCircuit, WebhookCircuitState, WebhookOutcome,
+};
+use x121_db::models::api_key::Webhook;
+use x121_db::repositories::WebhookRepo;
+use x121_events::delivery::webhook::{sign_request, WebhookError};
+use x121_events::PlatformEvent;
+
+use crate::state::AppState;
+
+/// How often the worker polls for pending deliveries and due probes.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Maximum deliveries attempted per poll.
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// Maximum suspended webhooks probed per poll.
+const PROBE_BATCH_SIZE: i64 = 20;
+
+/// HTTP request timeout for a single delivery or probe.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run the webhook delivery loop until `cancel` is triggered.
+pub async fn run(state: AppState, cancel: CancellationToken) {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .expect("Failed to build reqwest HTTP client");
+
+    tracing::info!(
+        interval_secs = POLL_INTERVAL.as_secs(),
+        failure_threshold = state.config.webhook_failure_threshold,
+        probe_interval_secs = state.config.webhook_probe_interval_secs,
+        "Webhook delivery worker started"
+    );
+
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                tracing::info!("Webhook delivery worker stopping");
+                break;
+            }
+            _ = interval.tick() => {
+                if let Err(e) = process_pending_deliveries(&state, &client).await {
+                    tracing::error!(error = %e, "Webhook delivery: tick failed");
+                }
+                if let Err(e) = probe_suspended_webhooks(&state, &client).await {
+                    tracing::error!(error = %e, "Webhook delivery: probe tick failed");
+                }
+            }
+        }
+    }
+}
+
+/// Attempt every due delivery for active webhooks.
+async fn process_pending_deliveries(
+    state: &AppState,
+    client: &reqwest::Client,
+) -> Result<(), sqlx::Error> {
+    let deliveries = WebhookRepo::list_pending_deliveries(&state.pool, DELIVERY_BATCH_SIZE).await?;
+
+    for delivery in deliveries {
+        // Re-read the webhook: an earlier failure in this batch may have
+        // suspended it.
+        let Some(webhook) = WebhookRepo::find_by_id(&state.pool, delivery.webhook_id).await? else {
+            continue;
+        };
+        if webhook.circuit_state != WebhookCircuitState::Active.as_str() {
+            continue;
+        }
+
+        let started = Instant::now();
+        let result = send(client, &webhook, &delivery.payload).await;
+        let latency_ms = i32::try_from(started.elapsed().as_millis()).unwrap_or(i32::MAX);
+
+        match result {
+            Ok(status_code) => {
+                WebhookRepo::mark_delivered(&state.pool, delivery.id, status_code, latency_ms)
+                    .await?;
+                WebhookRepo::touch_triggered(&state.pool, webhook.id).await?;
+                record_outcome(state, &webhook, WebhookOutcome::Delivered).await?;
+            }
+            Err(e) => {
+                let attempt = delivery.attempt_count + 1;
+                let status_code = match e {
+                    WebhookError::HttpStatus(code) => i16::try_from(code).ok(),
+                    WebhookError::Request(_) => None,
+                };
+                tracing::warn!(
+                    webhook_id = webhook.id,
+                    delivery_id = delivery.id,
+                    attempt,
+                    error = %e,
+                    "Webhook delivery attempt failed"
+                );
+
+                if attempt >= delivery.max_attempts {
+                    WebhookRepo::dead_letter_delivery(
+                        &state.pool,
+                        delivery.id,
+                        status_code,
+                        attempt,
+                        latency_ms,
+                        &e.to_string(),
+                    )
+                    .await?;
+                    WebhookRepo::increment_failure_count(&state.pool, webhook.id).await?;
+                    record_outcome(state, &webhook, WebhookOutcome::PermanentFailure).await?;
+                } else {
+                    WebhookRepo::schedule_retry(
+                        &state.pool,
+                        delivery.id,
+                        status_code,
+                        attempt,
+                        webhook_retry_delay_secs(attempt),
+                        latency_ms,
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send a probe to each suspended webhook whose probe is due.
+async fn probe_suspended_webhooks(
+    state: &AppState,
+    client: &reqwest::Client,
+) -> Result<(), sqlx::Error> {
+    let due = WebhookRepo::list_due_probes(&state.pool, PROBE_BATCH_SIZE).await?;
+
+    for webhook in due {
+        let payload = serde_json::json!({
+            "event": "webhook.probe",
+            "webhook_id": webhook.id,
+            "timestamp": Utc::now().to_rfc3339(),
+        });
+
+        let outcome = match send(client, &webhook, &payload).await {
+            Ok(_) => WebhookOutcome::Delivered,
+            Err(e) => {
+                tracing::debug!(webhook_id = webhook.id, error = %e, "Webhook probe failed");
+                WebhookOutcome::PermanentFailure
+            }
+        };
+        record_outcome(state, &webhook, outcome).await?;
+    }
+
+    Ok(())
+}
+
+/// Apply `outcome` to the webhook's circuit and persist any change.
+///
+/// While suspended, every recorded outcome reschedules the next probe.
+async fn record_outcome(
+    state: &AppState,
+    webhook: &Webhook,
+    outcome: WebhookOutcome,
+) -> Result<(), sqlx::Error> {
+    let previous = webhook
+        .circuit_state
+        .parse()
+        .unwrap_or(WebhookCircuitState::Active);
+    let current = WebhookCircuit {
+        state: previous,
+        consecutive_failures: webhook.consecutive_failures,
+    };
+    let next = current.record(outcome, state.config.webhook_failure_threshold);
+    if next == current && next.state == WebhookCircuitState::Active {
+        return Ok(());
+    }
+
+    let next_probe_in_secs = (next.state == WebhookCircuitState::Suspended)
+        .then_some(state.config.webhook_probe_interval_secs);
+    WebhookRepo::update_circuit(
+        &state.pool,
+        webhook.id,
+        next.state.as_str(),
+        next.consecutive_failures,
+        next_probe_in_secs,
+    )
+    .await?;
+
+    if next.tripped_from(previous) {
+        tracing::warn!(
+            webhook_id = webhook.id,
+            url = %webhook.url,
+            consecutive_failures = next.consecutive_failures,
+            "Webhook suspended after repeated delivery failures"
+        );
+        state.event_bus.publish(
+            PlatformEvent::new("webhook.suspended")
+                .with_source("webhook", webhook.id)
+                .with_payload(serde_json::json!({
+                    "name": webhook.name,
+                    "consecutive_failures": next.consecutive_failures,
+                })),
+        );
+    } else if previous == WebhookCircuitState::Suspended
+        && next.state == WebhookCircuitState::Active
+    {
+        tracing::info!(
+            webhook_id = webhook.id,
+            "Webhook re-enabled after successful probe"
+        );
+    }
+
+    Ok(())
+}
+
+/// POST a JSON payload to the webhook, returning the response status code
+/// on a 2xx response.
+async fn send(
+    client: &reqwest::Client,
+    webhook: &Webhook,
+    payload: &serde_json::Value,
+) -> Result<i16, WebhookError> {
+    let body = payload.to_string();
+    let mut request = client
+        .post(&webhook.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    if let Some(secret) = webhook.secret.as_deref() {
+        request = sign_request(request, secret, Utc::now().timestamp(), &body);
+    }
+
+    let response = request.body(body).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(WebhookError::HttpStatus(status.as_u16()));
+    }
+    Ok(status.as_u16() as i16)
+}
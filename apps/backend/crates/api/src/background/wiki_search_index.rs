@@ -0,0 +1,243 @@
+//! Wiki article search index maintenance (PRD-56).
+//!
+//! Two entry points keep `wiki_article_search_index` current:
+//!
+//! - [`run_wiki_reindex`] rebuilds the index for every article as a
+//!   `reindex_wiki` bulk operation, in keyset-paginated chunks with a
+//!   configurable number of articles indexed in parallel. Progress is
+//!   written to the operation row after each chunk and cancellation is
+//!   checked between chunks, as in [`cache_rebuild`](super::cache_rebuild).
+//! - [`run`] subscribes to the wiki article events on the [`EventBus`] and
+//!   reindexes changed articles once they have been quiet for the debounce
+//!   window, so a burst of edits costs a single reindex. Deleted articles
+//!   drop out of the index through the foreign-key cascade.
+//!
+//! [`EventBus`]: x121_events::EventBus
+
+use std::time::{Duration, Instant};
+
+use futures::{StreamExt, TryStreamExt};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+use x121_core::types::DbId;
+use x121_core::wiki::{
+    ReindexDebouncer, EVENT_ARTICLE_CREATED, EVENT_ARTICLE_UPDATED, REINDEX_CHUNK_SIZE,
+};
+use x121_db::models::status::BulkOperationStatusId;
+use x121_db::repositories::{BulkOperationRepo, WikiArticleRepo};
+use x121_db::DbPool;
+use x121_events::PlatformEvent;
+
+use crate::background::cache_rebuild::is_cancelled;
+use crate::state::AppState;
+
+// ---------------------------------------------------------------------------
+// Full reindex
+// ---------------------------------------------------------------------------
+
+/// Outcome of a full wiki reindex.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WikiReindexReport {
+    /// Articles whose search index entry was rebuilt.
+    pub articles_indexed: u64,
+    /// Whether the run stopped early because the operation was cancelled.
+    pub cancelled: bool,
+}
+
+/// Run the reindex for bulk operation `operation_id`, recording the final
+/// status on the operation row.
+///
+/// Errors are logged and stored as `failed` on the operation.
+pub async fn run_wiki_reindex(state: AppState, operation_id: DbId, executed_by: DbId) {
+    let bus = state.event_bus.clone();
+    let result = reindex_wiki(
+        &state.pool,
+        operation_id,
+        REINDEX_CHUNK_SIZE,
+        state.config.wiki_reindex_concurrency,
+        |indexed| {
+            bus.publish(
+                PlatformEvent::new("maintenance.reindex_wiki.progress")
+                    .with_source("bulk_operation", operation_id)
+                    .with_payload(serde_json::json!({ "processed": indexed })),
+            );
+        },
+    )
+    .await;
+
+    match result {
+        Ok(report) => {
+            // Same status guard as the cache rebuild: never complete over a
+            // cancellation.
+            let (from, to) = if report.cancelled {
+                (
+                    BulkOperationStatusId::Cancelled,
+                    BulkOperationStatusId::Cancelled,
+                )
+            } else {
+                (
+                    BulkOperationStatusId::Executing,
+                    BulkOperationStatusId::Completed,
+                )
+            };
+            let _ = BulkOperationRepo::finish_execution(
+                &state.pool,
+                operation_id,
+                from.id(),
+                to.id(),
+                report.articles_indexed as i32,
+                Some(executed_by),
+                Some(chrono::Utc::now()),
+            )
+            .await;
+            tracing::info!(
+                operation_id,
+                articles_indexed = report.articles_indexed,
+                cancelled = report.cancelled,
+                "Wiki reindex finished"
+            );
+        }
+        Err(e) => {
+            tracing::error!(operation_id, error = %e, "Wiki reindex failed");
+            let _ = BulkOperationRepo::update_error(
+                &state.pool,
+                operation_id,
+                BulkOperationStatusId::Failed.id(),
+                &e.to_string(),
+            )
+            .await;
+        }
+    }
+}
+
+/// Rebuild the search index entry of every article, `chunk_size` at a time
+/// with up to `concurrency` articles indexed in parallel.
+///
+/// After each chunk the indexed count is stored on the operation and passed
+/// to `on_progress`. If the operation has been moved to `cancelled` the loop
+/// stops before the next chunk.
+pub async fn reindex_wiki(
+    pool: &sqlx::PgPool,
+    operation_id: DbId,
+    chunk_size: i64,
+    concurrency: usize,
+    on_progress: impl Fn(u64),
+) -> Result<WikiReindexReport, sqlx::Error> {
+    let mut report = WikiReindexReport::default();
+    let mut cursor: DbId = 0;
+
+    loop {
+        if is_cancelled(pool, operation_id).await? {
+            report.cancelled = true;
+            break;
+        }
+
+        let ids = WikiArticleRepo::list_ids_after(pool, cursor, chunk_size.max(1)).await?;
+        let Some(&last) = ids.last() else {
+            break;
+        };
+        cursor = last;
+
+        let indexed: Vec<bool> = futures::stream::iter(ids)
+            .map(|id| WikiArticleRepo::index_article(pool, id))
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        // Articles deleted since the chunk was listed are skipped.
+        report.articles_indexed += indexed.into_iter().filter(|&hit| hit).count() as u64;
+
+        BulkOperationRepo::update_affected_count(
+            pool,
+            operation_id,
+            report.articles_indexed as i32,
+        )
+        .await?;
+        on_progress(report.articles_indexed);
+    }
+
+    Ok(report)
+}
+
+// ---------------------------------------------------------------------------
+// Incremental updates
+// ---------------------------------------------------------------------------
+
+/// Run the incremental index loop.
+///
+/// Created and updated articles are queued in a [`ReindexDebouncer`] and
+/// reindexed once no further event for them has arrived within `debounce`.
+/// Pending articles are flushed on shutdown.
+pub async fn run(
+    pool: DbPool,
+    mut receiver: broadcast::Receiver<PlatformEvent>,
+    cancel: CancellationToken,
+    debounce: Duration,
+) {
+    tracing::info!(
+        debounce_ms = debounce.as_millis() as u64,
+        "Wiki search indexer started"
+    );
+
+    let mut debouncer = ReindexDebouncer::new(debounce);
+
+    loop {
+        let next_deadline = debouncer.next_deadline();
+        let wait_for_due = async {
+            match next_deadline {
+                Some(deadline) => {
+                    tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await
+                }
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                tracing::info!("Wiki search indexer stopping, flushing pending articles");
+                index_articles(&pool, debouncer.drain()).await;
+                break;
+            }
+            _ = wait_for_due => {
+                index_articles(&pool, debouncer.take_due(Instant::now())).await;
+            }
+            result = receiver.recv() => {
+                match result {
+                    Ok(event) => {
+                        if let Some(article_id) = changed_article_id(&event) {
+                            debouncer.touch(article_id, Instant::now());
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!(
+                            skipped = n,
+                            "Wiki search indexer lagged, some article changes were missed"
+                        );
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        tracing::info!("Event bus closed, wiki search indexer shutting down");
+                        index_articles(&pool, debouncer.drain()).await;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The article a create or update event refers to, if any.
+fn changed_article_id(event: &PlatformEvent) -> Option<DbId> {
+    match event.event_type.as_str() {
+        EVENT_ARTICLE_CREATED | EVENT_ARTICLE_UPDATED => event.source_entity_id,
+        _ => None,
+    }
+}
+
+/// Reindex the given articles, logging failures without stopping the loop.
+async fn index_articles(pool: &DbPool, article_ids: Vec<DbId>) {
+    for article_id in article_ids {
+        if let Err(e) = WikiArticleRepo::index_article(pool, article_id).await {
+            tracing::error!(article_id, error = %e, "Failed to reindex wiki article");
+        }
+    }
+}
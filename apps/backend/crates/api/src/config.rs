@@ -1,4 +1,11 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
 use crate::auth::jwt::JwtConfig;
+use crate::auth::password::PasswordHashConfig;
+use crate::middleware::rate_limit::RateLimitConfig;
+use crate::middleware::timeout::parse_role_timeouts;
+use crate::ws::{HeartbeatConfig, OnFull, SendQueueConfig};
 
 /// Server configuration loaded from environment variables.
 ///
@@ -14,6 +21,9 @@ pub struct ServerConfig {
     pub cors_origins: Vec<String>,
     /// HTTP request timeout in seconds (default: `30`).
     pub request_timeout_secs: u64,
+    /// Per-role request timeout overrides in seconds, keyed by role name
+    /// (default: `admin=300`).
+    pub role_request_timeouts: HashMap<String, u64>,
     /// Graceful shutdown timeout in seconds (default: `30`).
     /// Used in Phase 7 (graceful shutdown with drain).
     #[allow(dead_code)]
@@ -22,6 +32,65 @@ pub struct ServerConfig {
     pub jwt: JwtConfig,
     /// Root directory for file storage (default: `storage`).
     pub storage_root: String,
+    /// Maximum entity IDs per bulk tag apply/remove request (default: `1000`).
+    pub tag_bulk_max_entities: usize,
+    /// Maximum tags per bulk tag apply/remove request (default: `50`).
+    pub tag_bulk_max_tags: usize,
+    /// Maximum request body size in bytes for bulk tag apply/remove
+    /// (default: `262144`).
+    pub tag_bulk_max_body_bytes: usize,
+    /// Concurrent file metadata reads during importer folder scans (default: `8`).
+    pub import_scan_concurrency: usize,
+    /// Consecutive permanent delivery failures before a webhook is
+    /// suspended (default: `10`).
+    pub webhook_failure_threshold: i32,
+    /// Seconds between recovery probes for a suspended webhook (default: `300`).
+    pub webhook_probe_interval_secs: i64,
+    /// Independent pipeline hooks executed in parallel (default: `4`).
+    pub hook_execution_concurrency: usize,
+    /// Directory shell and Python hook scripts are resolved in; a hook's
+    /// `script_path` may not escape it (default: `scripts/hooks`).
+    pub hook_scripts_dir: std::path::PathBuf,
+    /// Script executions allowed to run at once (default: `4`).
+    pub script_max_concurrent_executions: usize,
+    /// Script executions allowed to wait for a free slot before further
+    /// requests get 429 (default: `16`).
+    pub script_max_queued_executions: usize,
+    /// Maximum generation parameter overrides per scene type (default: `50`).
+    pub max_parameter_overrides: usize,
+    /// Gzip-compress JSON responses when the client accepts it (default: `true`).
+    pub compression_enabled: bool,
+    /// Smallest JSON body, in bytes, worth compressing (default: `1024`).
+    pub compression_min_bytes: u16,
+    /// Read buffer size, in bytes, for video streaming (default: `65536`).
+    pub video_stream_chunk_bytes: usize,
+    /// Wiki articles indexed in parallel during a full reindex (default: `4`).
+    pub wiki_reindex_concurrency: usize,
+    /// Quiet period in milliseconds after a wiki article change before it is
+    /// reindexed (default: `2000`).
+    pub wiki_index_debounce_ms: u64,
+    /// Maximum annotation objects in one frame annotation export
+    /// (default: `500`).
+    pub annotation_export_max: usize,
+    /// Maximum page size when listing a user's undo trees
+    /// (default: `200`).
+    pub undo_tree_list_max: i64,
+    /// WebSocket ping cadence and idle timeout (defaults: `30` and `90`
+    /// seconds).
+    pub ws_heartbeat: HeartbeatConfig,
+    /// Per-connection WebSocket send queue capacity and overflow policy
+    /// (defaults: `256` frames, `drop_oldest`).
+    pub ws_send_queue: SendQueueConfig,
+    /// Per-caller API request rate and burst (defaults: `600` per minute,
+    /// burst of `120`).
+    pub rate_limit: RateLimitConfig,
+    /// Reverse proxies whose `X-Forwarded-For` / `X-Real-IP` headers are
+    /// trusted to name the client, parsed from comma-separated
+    /// `TRUSTED_PROXIES` (default: none).
+    pub trusted_proxies: Vec<IpAddr>,
+    /// Argon2id cost parameters for new password hashes (defaults: `19456`
+    /// KiB, `2` iterations, parallelism `1`).
+    pub password_hash: PasswordHashConfig,
 }
 
 impl ServerConfig {
@@ -33,7 +102,35 @@ impl ServerConfig {
     /// | `PORT`                 | `3000`                     |
     /// | `CORS_ORIGINS`         | `http://localhost:5173`    |
     /// | `REQUEST_TIMEOUT_SECS` | `30`                       |
+    /// | `ROLE_REQUEST_TIMEOUTS`| `admin=300`                |
     /// | `SHUTDOWN_TIMEOUT_SECS`| `30`                       |
+    /// | `TAG_BULK_MAX_ENTITIES`| `1000`                     |
+    /// | `TAG_BULK_MAX_TAGS`    | `50`                       |
+    /// | `TAG_BULK_MAX_BODY_BYTES` | `262144`                |
+    /// | `IMPORT_SCAN_CONCURRENCY` | `8`                     |
+    /// | `WEBHOOK_FAILURE_THRESHOLD` | `10`                  |
+    /// | `WEBHOOK_PROBE_INTERVAL_SECS` | `300`               |
+    /// | `HOOK_EXECUTION_CONCURRENCY` | `4`                  |
+    /// | `SCRIPT_MAX_CONCURRENT_EXECUTIONS` | `4`            |
+    /// | `SCRIPT_MAX_QUEUED_EXECUTIONS` | `16`               |
+    /// | `MAX_PARAMETER_OVERRIDES` | `50`                    |
+    /// | `COMPRESSION_ENABLED`  | `true`                     |
+    /// | `COMPRESSION_MIN_BYTES`| `1024`                     |
+    /// | `VIDEO_STREAM_CHUNK_BYTES` | `65536`                |
+    /// | `WIKI_REINDEX_CONCURRENCY` | `4`                    |
+    /// | `WIKI_INDEX_DEBOUNCE_MS` | `2000`                   |
+    /// | `ANNOTATION_EXPORT_MAX` | `500`                     |
+    /// | `UNDO_TREE_LIST_MAX`   | `200`                      |
+    /// | `WS_PING_INTERVAL_SECS` | `30`                      |
+    /// | `WS_IDLE_TIMEOUT_SECS` | `90`                       |
+    /// | `WS_SEND_QUEUE_CAPACITY` | `256`                    |
+    /// | `WS_SEND_QUEUE_ON_FULL` | `drop_oldest` (or `disconnect`) |
+    /// | `RATE_LIMIT_PER_MINUTE` | `600` (`0` disables)      |
+    /// | `RATE_LIMIT_BURST`     | `120`                      |
+    /// | `TRUSTED_PROXIES`      | (none)                     |
+    /// | `ARGON2_MEMORY_KIB`    | `19456`                    |
+    /// | `ARGON2_ITERATIONS`    | `2`                        |
+    /// | `ARGON2_PARALLELISM`   | `1`                        |
     pub fn from_env() -> Self {
         let host = std::env::var("HOST").unwrap_or_else(|_| "0.0.0.0".into());
 
@@ -54,6 +151,11 @@ impl ServerConfig {
             .parse()
             .expect("REQUEST_TIMEOUT_SECS must be a valid u64");
 
+        let role_request_timeouts = parse_role_timeouts(
+            &std::env::var("ROLE_REQUEST_TIMEOUTS").unwrap_or_else(|_| "admin=300".into()),
+        )
+        .expect("ROLE_REQUEST_TIMEOUTS must be comma-separated role=seconds pairs");
+
         let shutdown_timeout_secs: u64 = std::env::var("SHUTDOWN_TIMEOUT_SECS")
             .unwrap_or_else(|_| "30".into())
             .parse()
@@ -63,14 +165,247 @@ impl ServerConfig {
 
         let storage_root = std::env::var("STORAGE_ROOT").unwrap_or_else(|_| "storage".into());
 
+        let tag_bulk_max_entities: usize = std::env::var("TAG_BULK_MAX_ENTITIES")
+            .map(|v| {
+                v.parse()
+                    .expect("TAG_BULK_MAX_ENTITIES must be a valid usize")
+            })
+            .unwrap_or(x121_core::tags::DEFAULT_BULK_MAX_ENTITIES);
+
+        let tag_bulk_max_tags: usize = std::env::var("TAG_BULK_MAX_TAGS")
+            .map(|v| v.parse().expect("TAG_BULK_MAX_TAGS must be a valid usize"))
+            .unwrap_or(x121_core::tags::DEFAULT_BULK_MAX_TAGS);
+
+        let tag_bulk_max_body_bytes: usize = std::env::var("TAG_BULK_MAX_BODY_BYTES")
+            .map(|v| {
+                v.parse()
+                    .expect("TAG_BULK_MAX_BODY_BYTES must be a valid usize")
+            })
+            .unwrap_or(x121_core::tags::DEFAULT_BULK_MAX_BODY_BYTES);
+
+        let import_scan_concurrency: usize = std::env::var("IMPORT_SCAN_CONCURRENCY")
+            .map(|v| {
+                v.parse()
+                    .expect("IMPORT_SCAN_CONCURRENCY must be a valid usize")
+            })
+            .unwrap_or(x121_core::importer::DEFAULT_SCAN_CONCURRENCY);
+
+        let webhook_failure_threshold: i32 = std::env::var("WEBHOOK_FAILURE_THRESHOLD")
+            .map(|v| {
+                v.parse()
+                    .expect("WEBHOOK_FAILURE_THRESHOLD must be a valid i32")
+            })
+            .unwrap_or(x121_core::api_keys::DEFAULT_WEBHOOK_FAILURE_THRESHOLD);
+
+        let webhook_probe_interval_secs: i64 = std::env::var("WEBHOOK_PROBE_INTERVAL_SECS")
+            .map(|v| {
+                v.parse()
+                    .expect("WEBHOOK_PROBE_INTERVAL_SECS must be a valid i64")
+            })
+            .unwrap_or(x121_core::api_keys::DEFAULT_WEBHOOK_PROBE_INTERVAL_SECS);
+
+        let hook_execution_concurrency: usize = std::env::var("HOOK_EXECUTION_CONCURRENCY")
+            .map(|v| {
+                v.parse()
+                    .expect("HOOK_EXECUTION_CONCURRENCY must be a valid usize")
+            })
+            .unwrap_or(x121_core::pipeline_hooks::DEFAULT_HOOK_CONCURRENCY);
+
+        let hook_scripts_dir = std::env::var("HOOK_SCRIPTS_DIR")
+            .unwrap_or_else(|_| x121_core::pipeline_hooks::DEFAULT_HOOK_SCRIPTS_DIR.to_string())
+            .into();
+
+        let script_max_concurrent_executions: usize =
+            std::env::var("SCRIPT_MAX_CONCURRENT_EXECUTIONS")
+                .map(|v| {
+                    v.parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .expect("SCRIPT_MAX_CONCURRENT_EXECUTIONS must be a positive usize")
+                })
+                .unwrap_or(x121_core::scripting::executor::DEFAULT_MAX_CONCURRENT_EXECUTIONS);
+
+        let script_max_queued_executions: usize = std::env::var("SCRIPT_MAX_QUEUED_EXECUTIONS")
+            .map(|v| {
+                v.parse()
+                    .expect("SCRIPT_MAX_QUEUED_EXECUTIONS must be a valid usize")
+            })
+            .unwrap_or(x121_core::scripting::executor::DEFAULT_MAX_QUEUED_EXECUTIONS);
+
+        let max_parameter_overrides: usize = std::env::var("MAX_PARAMETER_OVERRIDES")
+            .map(|v| {
+                v.parse()
+                    .expect("MAX_PARAMETER_OVERRIDES must be a valid usize")
+            })
+            .unwrap_or(x121_core::workflow_import::DEFAULT_MAX_PARAMETER_OVERRIDES);
+
+        let compression_enabled: bool = std::env::var("COMPRESSION_ENABLED")
+            .unwrap_or_else(|_| "true".into())
+            .parse()
+            .expect("COMPRESSION_ENABLED must be true or false");
+
+        let compression_min_bytes: u16 = std::env::var("COMPRESSION_MIN_BYTES")
+            .unwrap_or_else(|_| "1024".into())
+            .parse()
+            .expect("COMPRESSION_MIN_BYTES must be a valid u16");
+
+        let video_stream_chunk_bytes: usize = std::env::var("VIDEO_STREAM_CHUNK_BYTES")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .expect("VIDEO_STREAM_CHUNK_BYTES must be a positive usize")
+            })
+            .unwrap_or(x121_core::http_range::DEFAULT_STREAM_CHUNK_BYTES);
+
+        let wiki_reindex_concurrency: usize = std::env::var("WIKI_REINDEX_CONCURRENCY")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .expect("WIKI_REINDEX_CONCURRENCY must be a positive usize")
+            })
+            .unwrap_or(x121_core::wiki::DEFAULT_REINDEX_CONCURRENCY);
+
+        let wiki_index_debounce_ms: u64 = std::env::var("WIKI_INDEX_DEBOUNCE_MS")
+            .map(|v| {
+                v.parse()
+                    .expect("WIKI_INDEX_DEBOUNCE_MS must be a valid u64")
+            })
+            .unwrap_or(x121_core::wiki::DEFAULT_INDEX_DEBOUNCE_MS);
+
+        let annotation_export_max: usize = std::env::var("ANNOTATION_EXPORT_MAX")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .expect("ANNOTATION_EXPORT_MAX must be a positive usize")
+            })
+            .unwrap_or(x121_core::annotation::DEFAULT_MAX_ANNOTATIONS_PER_EXPORT);
+
+        let undo_tree_list_max: i64 = std::env::var("UNDO_TREE_LIST_MAX")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .expect("UNDO_TREE_LIST_MAX must be a positive i64")
+            })
+            .unwrap_or(x121_core::undo::DEFAULT_MAX_UNDO_TREE_LIST_LIMIT);
+
+        let ws_defaults = HeartbeatConfig::default();
+        let ws_ping_interval_secs: u64 = std::env::var("WS_PING_INTERVAL_SECS")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .expect("WS_PING_INTERVAL_SECS must be a positive u64")
+            })
+            .unwrap_or(ws_defaults.ping_interval_secs);
+        let ws_idle_timeout_secs: u64 = std::env::var("WS_IDLE_TIMEOUT_SECS")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|n| *n > ws_ping_interval_secs)
+                    .expect("WS_IDLE_TIMEOUT_SECS must be a u64 greater than WS_PING_INTERVAL_SECS")
+            })
+            .unwrap_or_else(|_| ws_defaults.idle_timeout_secs.max(ws_ping_interval_secs * 3));
+        let ws_heartbeat = HeartbeatConfig {
+            ping_interval_secs: ws_ping_interval_secs,
+            idle_timeout_secs: ws_idle_timeout_secs,
+        };
+
+        let ws_queue_defaults = SendQueueConfig::default();
+        let ws_send_queue = SendQueueConfig {
+            capacity: std::env::var("WS_SEND_QUEUE_CAPACITY")
+                .map(|v| {
+                    v.parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .expect("WS_SEND_QUEUE_CAPACITY must be a positive usize")
+                })
+                .unwrap_or(ws_queue_defaults.capacity),
+            on_full: std::env::var("WS_SEND_QUEUE_ON_FULL")
+                .map(|v| {
+                    OnFull::parse(&v)
+                        .expect("WS_SEND_QUEUE_ON_FULL must be `drop_oldest` or `disconnect`")
+                })
+                .unwrap_or(ws_queue_defaults.on_full),
+        };
+
+        let rate_limit_defaults = RateLimitConfig::default();
+        let rate_limit = RateLimitConfig {
+            requests_per_minute: std::env::var("RATE_LIMIT_PER_MINUTE")
+                .map(|v| v.parse().expect("RATE_LIMIT_PER_MINUTE must be a u32"))
+                .unwrap_or(rate_limit_defaults.requests_per_minute),
+            burst: std::env::var("RATE_LIMIT_BURST")
+                .map(|v| {
+                    v.parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .expect("RATE_LIMIT_BURST must be a positive u32")
+                })
+                .unwrap_or(rate_limit_defaults.burst),
+        };
+
+        let trusted_proxies: Vec<IpAddr> = std::env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse()
+                    .unwrap_or_else(|_| panic!("Invalid TRUSTED_PROXIES address '{s}'"))
+            })
+            .collect();
+
+        let password_hash_defaults = PasswordHashConfig::default();
+        let password_hash = PasswordHashConfig {
+            memory_kib: std::env::var("ARGON2_MEMORY_KIB")
+                .map(|v| v.parse().expect("ARGON2_MEMORY_KIB must be a u32"))
+                .unwrap_or(password_hash_defaults.memory_kib),
+            iterations: std::env::var("ARGON2_ITERATIONS")
+                .map(|v| v.parse().expect("ARGON2_ITERATIONS must be a u32"))
+                .unwrap_or(password_hash_defaults.iterations),
+            parallelism: std::env::var("ARGON2_PARALLELISM")
+                .map(|v| v.parse().expect("ARGON2_PARALLELISM must be a u32"))
+                .unwrap_or(password_hash_defaults.parallelism),
+        };
+        if let Err(e) = password_hash.validate() {
+            panic!("Invalid ARGON2_* settings: {e}");
+        }
+
         Self {
             host,
             port,
             cors_origins,
             request_timeout_secs,
+            role_request_timeouts,
             shutdown_timeout_secs,
             jwt,
             storage_root,
+            tag_bulk_max_entities,
+            tag_bulk_max_tags,
+            tag_bulk_max_body_bytes,
+            import_scan_concurrency,
+            webhook_failure_threshold,
+            webhook_probe_interval_secs,
+            hook_execution_concurrency,
+            hook_scripts_dir,
+            script_max_concurrent_executions,
+            script_max_queued_executions,
+            max_parameter_overrides,
+            compression_enabled,
+            compression_min_bytes,
+            video_stream_chunk_bytes,
+            wiki_reindex_concurrency,
+            wiki_index_debounce_ms,
+            annotation_export_max,
+            undo_tree_list_max,
+            ws_heartbeat,
+            ws_send_queue,
+            rate_limit,
+            trusted_proxies,
+            password_hash,
         }
     }
 }
@@ -3,19 +3,27 @@
 //! Polls for pending jobs every `poll_interval` and dispatches them to
 //! available ComfyUI workers.  Uses `SELECT FOR UPDATE SKIP LOCKED` via
 //! [`JobRepo::claim_next`] to prevent double-dispatch.
+//!
+//! Workers whose installed node types are known only receive jobs whose
+//! workflows they can run; see [`x121_comfyui::compat`].
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use sqlx::PgPool;
 use tokio_util::sync::CancellationToken;
+use x121_comfyui::compat::supported_candidates;
 use x121_comfyui::manager::ComfyUIManager;
+use x121_db::models::job::Job;
 use x121_db::models::status::JobStatus;
 use x121_db::repositories::JobRepo;
 
 /// Default polling interval for the dispatcher loop.
 const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
+/// How many queued jobs to scan when looking for one a worker can run.
+const COMPAT_SCAN_LIMIT: i64 = 50;
+
 /// Background job dispatcher.
 ///
 /// A single long-lived Tokio task that matches pending jobs with
@@ -67,7 +75,7 @@ impl JobDispatcher {
         }
 
         for worker_id in available {
-            let claimed = JobRepo::claim_next(&self.pool, worker_id).await?;
+            let claimed = self.claim_for_worker(worker_id).await?;
 
             if let Some(job) = claimed {
                 tracing::info!(
@@ -116,6 +124,32 @@ impl JobDispatcher {
         Ok(())
     }
 
+    /// Claim the highest-priority pending job this worker can run.
+    ///
+    /// Without detected capabilities the worker takes the next job as
+    /// before; otherwise jobs needing nodes it lacks are left for others.
+    async fn claim_for_worker(&self, worker_id: i64) -> Result<Option<Job>, sqlx::Error> {
+        let Some(caps) = self.comfyui_manager.instance_capabilities(worker_id).await else {
+            return JobRepo::claim_next(&self.pool, worker_id).await;
+        };
+
+        let candidates = JobRepo::list_claimable(&self.pool, COMPAT_SCAN_LIMIT).await?;
+        for job in supported_candidates(&caps, &candidates, |job| &job.parameters) {
+            if let Some(claimed) = JobRepo::claim_by_id(&self.pool, job.id, worker_id).await? {
+                return Ok(Some(claimed));
+            }
+        }
+
+        if !candidates.is_empty() {
+            tracing::debug!(
+                worker_id,
+                scanned = candidates.len(),
+                "No pending job compatible with worker's installed nodes",
+            );
+        }
+        Ok(None)
+    }
+
     /// Determine which workers are available for job dispatch.
     ///
     /// A worker is available if:
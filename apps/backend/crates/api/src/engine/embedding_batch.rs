@@ -0,0 +1,232 @@
+//! Cancellable batch face-embedding extraction (PRD-76).
+//!
+//! A batch runs a fixed set of avatars through an extraction function with
+//! bounded concurrency, recording progress as it goes. Cancelling a batch
+//! stops new extractions from starting; extractions already in flight are
+//! allowed to finish so no avatar is left half-processed. Batches are kept
+//! in a [`RunRegistry`].
+
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{FuturesUnordered, StreamExt};
+use serde::Serialize;
+
+use x121_core::types::{DbId, RunId};
+
+use super::run_registry::{RunHandle, RunRegistry, RunReport};
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Result of extracting a single avatar within a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractionOutcome {
+    /// Extraction was started for the avatar.
+    Extracted,
+    /// The avatar already had a current or in-progress extraction.
+    Skipped,
+}
+
+/// Progress report for a batch extraction.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchExtractionReport {
+    pub batch_id: RunId,
+    /// Number of distinct avatars in the batch.
+    pub total: usize,
+    /// Avatars processed so far (extracted + skipped + failed).
+    pub done: usize,
+    pub extracted: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// Whether the batch was cancelled before processing every avatar.
+    pub cancelled: bool,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl BatchExtractionReport {
+    /// Initial report for a batch of `total` avatars.
+    pub fn new(total: usize) -> Self {
+        Self {
+            batch_id: RunId::new(),
+            total,
+            done: 0,
+            extracted: 0,
+            skipped: 0,
+            failed: 0,
+            cancelled: false,
+            started_at: Utc::now(),
+            finished_at: None,
+        }
+    }
+
+    fn record(&mut self, result: &Result<ExtractionOutcome, String>) {
+        self.done += 1;
+        match result {
+            Ok(ExtractionOutcome::Extracted) => self.extracted += 1,
+            Ok(ExtractionOutcome::Skipped) => self.skipped += 1,
+            Err(_) => self.failed += 1,
+        }
+    }
+}
+
+impl RunReport for BatchExtractionReport {
+    fn run_id(&self) -> RunId {
+        self.batch_id
+    }
+
+    fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+}
+
+/// Handle to a running or finished batch.
+pub type BatchHandle = RunHandle<BatchExtractionReport>;
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+/// In-memory registry of batch extractions, keyed by batch ID.
+pub type EmbeddingBatchRegistry = RunRegistry<BatchExtractionReport>;
+
+// ---------------------------------------------------------------------------
+// Runner
+// ---------------------------------------------------------------------------
+
+/// Run `extract` over `avatar_ids`, at most `concurrency` at a time.
+///
+/// Each avatar is passed to `extract` at most once. Progress is written to
+/// the handle's report after every completion, and the final report is
+/// returned once all started extractions have finished.
+pub async fn run_batch<F, Fut>(
+    handle: BatchHandle,
+    avatar_ids: Vec<DbId>,
+    concurrency: usize,
+    extract: F,
+) -> BatchExtractionReport
+where
+    F: Fn(DbId) -> Fut,
+    Fut: Future<Output = Result<ExtractionOutcome, String>>,
+{
+    let mut queue = avatar_ids.into_iter();
+    let mut in_flight = FuturesUnordered::new();
+
+    loop {
+        while in_flight.len() < concurrency.max(1) && !handle.is_cancelled() {
+            let Some(avatar_id) = queue.next() else {
+                break;
+            };
+            let fut = extract(avatar_id);
+            in_flight.push(async move { (avatar_id, fut.await) });
+        }
+
+        let Some((avatar_id, result)) = in_flight.next().await else {
+            break;
+        };
+        if let Err(e) = &result {
+            tracing::warn!(avatar_id, error = %e, "Batch embedding extraction failed");
+        }
+        handle.update(|report| report.record(&result));
+    }
+
+    handle.update(|report| {
+        report.cancelled = handle.is_cancelled() && report.done < report.total;
+        report.finished_at = Some(Utc::now());
+        report.clone()
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration as StdDuration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_every_avatar_once() {
+        let registry = EmbeddingBatchRegistry::new();
+        let handle = registry.register(BatchExtractionReport::new(5));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let report = run_batch(handle, vec![1, 2, 3, 4, 5], 2, |id| {
+            let calls = Arc::clone(&calls);
+            async move {
+                calls.lock().unwrap().push(id);
+                match id {
+                    2 => Ok(ExtractionOutcome::Skipped),
+                    4 => Err("boom".to_string()),
+                    _ => Ok(ExtractionOutcome::Extracted),
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(report.done, 5);
+        assert_eq!(report.extracted, 3);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.failed, 1);
+        assert!(!report.cancelled);
+        assert!(report.finished_at.is_some());
+        assert_eq!(calls.lock().unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn cancel_stops_with_partial_report() {
+        let registry = Arc::new(EmbeddingBatchRegistry::new());
+        let avatar_ids: Vec<DbId> = (1..=20).collect();
+        let handle = registry.register(BatchExtractionReport::new(avatar_ids.len()));
+        let batch_id = handle.report().batch_id;
+        let calls = Arc::new(Mutex::new(HashMap::<DbId, usize>::new()));
+
+        let task = tokio::spawn({
+            let calls = Arc::clone(&calls);
+            async move {
+                run_batch(handle, avatar_ids, 2, |id| {
+                    let calls = Arc::clone(&calls);
+                    async move {
+                        *calls.lock().unwrap().entry(id).or_default() += 1;
+                        tokio::time::sleep(StdDuration::from_millis(20)).await;
+                        Ok(ExtractionOutcome::Extracted)
+                    }
+                })
+                .await
+            }
+        });
+
+        // Wait for some progress, then cancel.
+        while registry.get(batch_id).unwrap().done < 4 {
+            tokio::time::sleep(StdDuration::from_millis(5)).await;
+        }
+        assert!(registry.cancel(batch_id).is_some());
+        let report = task.await.unwrap();
+
+        assert!(report.cancelled);
+        assert!(report.done >= 4 && report.done < report.total);
+        assert_eq!(report.extracted, report.done);
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), report.done, "no extraction left unreported");
+        assert!(calls.values().all(|&n| n == 1), "no duplicate extractions");
+
+        // The registry serves the same final report.
+        let stored = registry.get(batch_id).unwrap();
+        assert_eq!(stored.done, report.done);
+        assert!(stored.cancelled);
+    }
+
+    #[test]
+    fn unknown_batch_is_none() {
+        let registry = EmbeddingBatchRegistry::new();
+        assert!(registry.get(RunId::new()).is_none());
+        assert!(registry.cancel(RunId::new()).is_none());
+    }
+}
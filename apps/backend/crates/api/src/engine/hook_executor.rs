@@ -0,0 +1,691 @@
+//! Pipeline hook executor (PRD-77).
+//!
+//! Runs the effective hooks for a hook point: shell and Python hooks through
+//! the core script executors, webhook hooks as a JSON POST. Every hook runs
+//! under its own timeout with output capture bounded by
+//! [`MAX_OUTPUT_CAPTURE_LENGTH`] (truncation is marked in the text and
+//! flagged on the log row), and every execution is recorded in
+//! `hook_execution_logs`.
+//!
+//! Script hooks run only from the configured hook scripts directory, and
+//! webhook hooks only reach public addresses: the host is resolved up front,
+//! every address must be public, the request is pinned to the checked
+//! address, and redirects are not followed.
+//!
+//! A hook with a `retry` policy is re-run after a growing backoff while its
+//! failures match `retry_on` and attempts remain; only the final attempt is
+//! recorded and judged against the failure mode.
+//!
+//! Hooks sharing a `sort_order` are independent and run in parallel, up to
+//! the configured concurrency. A failed `block` hook halts the chain once
+//! its batch has finished; `warn` and `ignore` failures let it continue.
+//! [`HookExecutor::run_project_hooks`] resolves and runs the hooks a
+//! project inherits at a hook point.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use sqlx::PgPool;
+use x121_core::pipeline_hooks::{
+    self, group_hook_batches, hook_timeout_secs, EffectiveHook, FailureMode, HookInput, HookPoint,
+    HookRunDecision, HookType, ScopeType, MAX_OUTPUT_CAPTURE_LENGTH,
+};
+use x121_core::scripting::capture::{capture_output, capture_prefix, CapturedOutput};
+use x121_core::scripting::executor::{ScriptError, ScriptExecutor, ScriptInput};
+use x121_core::scripting::python::PythonExecutor;
+use x121_core::scripting::shell::ShellExecutor;
+use x121_core::types::DbId;
+use x121_db::models::hook::Hook;
+use x121_db::models::hook_execution_log::{CreateHookExecutionLog, HookExecutionLog};
+use x121_db::repositories::{HookExecutionLogRepo, HookRepo};
+
+// ---------------------------------------------------------------------------
+// Hook rows
+// ---------------------------------------------------------------------------
+
+/// Convert a DB `Hook` row into a `HookInput` for the core resolver.
+pub fn hook_to_input(h: &Hook) -> HookInput {
+    let hook_type = HookType::from_str(&h.hook_type).unwrap_or(HookType::Shell);
+    HookInput {
+        id: h.id,
+        name: h.name.clone(),
+        // Configs are validated on write, so a malformed block only comes
+        // from rows predating retry support and is treated as absent.
+        retry: pipeline_hooks::parse_retry_policy(&hook_type, &h.config_json)
+            .ok()
+            .flatten(),
+        hook_type,
+        hook_point: HookPoint::from_str(&h.hook_point).unwrap_or(HookPoint::PostVariant),
+        scope_type: ScopeType::from_str(&h.scope_type).unwrap_or(ScopeType::Studio),
+        failure_mode: FailureMode::from_str(&h.failure_mode).unwrap_or(FailureMode::Warn),
+        config_json: h.config_json.clone(),
+        sort_order: h.sort_order,
+        enabled: h.enabled,
+    }
+}
+
+/// Convert a DB `Hook` row into an `EffectiveHook` for direct execution.
+pub fn hook_to_effective(h: &Hook) -> EffectiveHook {
+    let input = hook_to_input(h);
+    EffectiveHook {
+        hook_id: input.id,
+        name: input.name,
+        hook_type: input.hook_type,
+        hook_point: input.hook_point,
+        source_level: input.scope_type.as_str().to_string(),
+        scope_type: input.scope_type,
+        failure_mode: input.failure_mode,
+        config_json: input.config_json,
+        sort_order: input.sort_order,
+        retry: input.retry,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Results
+// ---------------------------------------------------------------------------
+
+/// The outcome of a single hook execution.
+#[derive(Debug, Clone)]
+pub struct HookRunResult {
+    pub hook_id: DbId,
+    pub failure_mode: FailureMode,
+    pub success: bool,
+    /// Process exit code; `None` for webhooks and processes that never ran.
+    pub exit_code: Option<i32>,
+    /// Captured stdout (or response body), bounded by the capture limit
+    /// and ending in a truncation marker when cut.
+    pub output: String,
+    /// Whether `output` was truncated.
+    pub output_truncated: bool,
+    /// Length of the full output in bytes.
+    pub output_original_len: i64,
+    pub error_message: Option<String>,
+    /// Total time across all attempts.
+    pub duration_ms: i64,
+    /// Number of attempts made, including the first run.
+    pub attempts: u32,
+}
+
+impl HookRunResult {
+    /// How the pipeline proceeds after this hook.
+    pub fn decision(&self) -> HookRunDecision {
+        self.failure_mode.decision(self.success)
+    }
+}
+
+/// The outcome of running every hook at a hook point.
+#[derive(Debug, Clone, Default)]
+pub struct HookChainOutcome {
+    /// Results of the hooks that ran, in batch order.
+    pub results: Vec<HookRunResult>,
+    /// The first `block` hook that failed, if the chain was halted.
+    pub halted_by: Option<DbId>,
+}
+
+impl HookChainOutcome {
+    /// Whether a blocking failure halted the pipeline.
+    pub fn halted(&self) -> bool {
+        self.halted_by.is_some()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Executor
+// ---------------------------------------------------------------------------
+
+/// Executes pipeline hooks and records each run in the hook execution log.
+pub struct HookExecutor {
+    pool: PgPool,
+    concurrency: usize,
+    scripts_dir: PathBuf,
+}
+
+impl HookExecutor {
+    /// Create an executor running at most `concurrency` independent hooks
+    /// at once (clamped to at least one), with script hooks resolved inside
+    /// `scripts_dir`.
+    pub fn new(pool: PgPool, concurrency: usize, scripts_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            pool,
+            concurrency: concurrency.max(1),
+            scripts_dir: scripts_dir.into(),
+        }
+    }
+
+    /// Resolve the hooks `project_id` inherits at `hook_point` and run them.
+    pub async fn run_project_hooks(
+        &self,
+        hook_point: HookPoint,
+        project_id: DbId,
+        input: &serde_json::Value,
+        job_id: Option<DbId>,
+    ) -> Result<HookChainOutcome, sqlx::Error> {
+        let point = hook_point.as_str();
+        let studio = HookRepo::list_by_scope(&self.pool, "studio", None, point).await?;
+        let project =
+            HookRepo::list_by_scope(&self.pool, "project", Some(project_id), point).await?;
+
+        let studio: Vec<HookInput> = studio.iter().map(hook_to_input).collect();
+        let project: Vec<HookInput> = project.iter().map(hook_to_input).collect();
+        let hooks = pipeline_hooks::resolve_effective_hooks(&studio, &project, &[]);
+
+        Ok(self.run_hooks(hooks, input, job_id).await)
+    }
+
+    /// Run `hooks` in `sort_order` batches, stopping after the first batch
+    /// in which a `block` hook failed.
+    pub async fn run_hooks(
+        &self,
+        hooks: Vec<EffectiveHook>,
+        input: &serde_json::Value,
+        job_id: Option<DbId>,
+    ) -> HookChainOutcome {
+        run_batches(
+            group_hook_batches(hooks),
+            self.concurrency,
+            |hook| async move {
+                let result = self.execute(&hook, input).await;
+                if let Err(e) = self.record(&result, Some(input.clone()), job_id).await {
+                    tracing::error!(
+                        hook_id = hook.hook_id,
+                        error = %e,
+                        "Failed to record hook execution"
+                    );
+                }
+                result
+            },
+        )
+        .await
+    }
+
+    /// Execute a single hook, honouring its retry policy, without
+    /// recording it.
+    pub async fn execute(&self, hook: &EffectiveHook, input: &serde_json::Value) -> HookRunResult {
+        let timeout = Duration::from_secs(hook_timeout_secs(&hook.hook_type, &hook.config_json));
+        let max_attempts = hook.retry.as_ref().map_or(1, |r| r.max_attempts);
+        let start = Instant::now();
+
+        let mut attempts = 1;
+        let attempt = loop {
+            let attempt = match hook.hook_type {
+                HookType::Shell | HookType::Python => self.run_script(hook, input, timeout).await,
+                HookType::Webhook => self.run_webhook(hook, input, timeout).await,
+            };
+            let Some(retry) = hook.retry.as_ref() else {
+                break attempt;
+            };
+            if attempt.success || attempts >= max_attempts || !retry.should_retry(attempt.code) {
+                break attempt;
+            }
+
+            let delay = retry.delay_secs(attempts);
+            tracing::warn!(
+                hook_id = hook.hook_id,
+                hook = %hook.name,
+                attempt = attempts,
+                max_attempts,
+                delay_secs = delay,
+                error = attempt.error_message.as_deref().unwrap_or(""),
+                "Hook attempt failed, retrying"
+            );
+            tokio::time::sleep(Duration::from_secs(delay)).await;
+            attempts += 1;
+        };
+
+        let error_message = attempt.error_message.map(|e| {
+            let e = if attempts > 1 {
+                format!("{e} (after {attempts} attempts)")
+            } else {
+                e
+            };
+            capture_output(e.as_bytes(), MAX_OUTPUT_CAPTURE_LENGTH).text
+        });
+        let captured = attempt.output;
+        let result = HookRunResult {
+            hook_id: hook.hook_id,
+            failure_mode: hook.failure_mode.clone(),
+            success: attempt.success,
+            exit_code: attempt.exit_code,
+            output: captured.text,
+            output_truncated: captured.truncated,
+            output_original_len: captured.original_len as i64,
+            error_message,
+            duration_ms: start.elapsed().as_millis() as i64,
+            attempts,
+        };
+
+        if !result.success {
+            tracing::warn!(
+                hook_id = hook.hook_id,
+                hook = %hook.name,
+                failure_mode = %hook.failure_mode,
+                error = result.error_message.as_deref().unwrap_or(""),
+                "Hook execution failed"
+            );
+        }
+        result
+    }
+
+    /// Record a hook execution in `hook_execution_logs`.
+    pub async fn record(
+        &self,
+        result: &HookRunResult,
+        input_json: Option<serde_json::Value>,
+        job_id: Option<DbId>,
+    ) -> Result<HookExecutionLog, sqlx::Error> {
+        HookExecutionLogRepo::create(
+            &self.pool,
+            &CreateHookExecutionLog {
+                hook_id: result.hook_id,
+                job_id,
+                input_json,
+                output_text: Some(result.output.clone()),
+                output_truncated: result.output_truncated,
+                output_original_len: Some(result.output_original_len),
+                exit_code: result.exit_code,
+                duration_ms: Some(result.duration_ms),
+                success: result.success,
+                error_message: result.error_message.clone(),
+            },
+        )
+        .await
+    }
+
+    /// Run a shell or Python hook with the input JSON piped to stdin.
+    async fn run_script(
+        &self,
+        hook: &EffectiveHook,
+        input: &serde_json::Value,
+        timeout: Duration,
+    ) -> HookAttempt {
+        let Some(script_path) = hook.config_json.get("script_path").and_then(|v| v.as_str()) else {
+            return HookAttempt::failed("Hook config is missing 'script_path'".to_string());
+        };
+        let script_path = match resolve_script_path(&self.scripts_dir, script_path).await {
+            Ok(path) => path.to_string_lossy().into_owned(),
+            Err(e) => return HookAttempt::failed(e),
+        };
+
+        let script_input = ScriptInput {
+            data: input.clone(),
+            env_vars: vec![
+                ("HOOK_ID".to_string(), hook.hook_id.to_string()),
+                (
+                    "HOOK_POINT".to_string(),
+                    hook.hook_point.as_str().to_string(),
+                ),
+            ],
+            working_directory: None,
+            timeout,
+            max_output_bytes: MAX_OUTPUT_CAPTURE_LENGTH,
+        };
+
+        let result = match hook.hook_type {
+            // Hooks carry no requirements file, so no venv is prepared and
+            // the system `python3` is used.
+            HookType::Python => {
+                PythonExecutor::new(String::new())
+                    .execute(&script_path, script_input)
+                    .await
+            }
+            _ => ShellExecutor.execute(&script_path, script_input).await,
+        };
+
+        match result {
+            Ok(out) => HookAttempt {
+                success: out.exit_code == 0,
+                exit_code: Some(out.exit_code),
+                code: Some(out.exit_code),
+                error_message: (out.exit_code != 0).then(|| {
+                    format!(
+                        "Hook exited with code {}: {}",
+                        out.exit_code,
+                        out.stderr.trim()
+                    )
+                }),
+                output: CapturedOutput {
+                    text: out.stdout,
+                    truncated: out.stdout_truncated,
+                    original_len: out.stdout_original_len,
+                },
+            },
+            Err(ScriptError::Timeout { .. }) => {
+                HookAttempt::failed(format!("Hook timed out after {}s", timeout.as_secs()))
+            }
+            Err(e) => HookAttempt::failed(e.to_string()),
+        }
+    }
+
+    /// POST the input JSON to a webhook hook's `url`.
+    async fn run_webhook(
+        &self,
+        hook: &EffectiveHook,
+        input: &serde_json::Value,
+        timeout: Duration,
+    ) -> HookAttempt {
+        let Some(url) = hook.config_json.get("url").and_then(|v| v.as_str()) else {
+            return HookAttempt::failed("Hook config is missing 'url'".to_string());
+        };
+        let (host, addr) = match resolve_public_host(url).await {
+            Ok(target) => target,
+            Err(e) => return HookAttempt::failed(e),
+        };
+        let client = match reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .resolve(&host, addr)
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => return HookAttempt::failed(e.to_string()),
+        };
+
+        let response = match client.post(url).json(input).timeout(timeout).send().await {
+            Ok(response) => response,
+            Err(e) if e.is_timeout() => {
+                return HookAttempt::failed(format!("Hook timed out after {}s", timeout.as_secs()));
+            }
+            Err(e) => return HookAttempt::failed(e.to_string()),
+        };
+
+        let status = response.status();
+        let output = read_body_capped(response, MAX_OUTPUT_CAPTURE_LENGTH).await;
+        HookAttempt {
+            success: status.is_success(),
+            exit_code: None,
+            code: Some(i32::from(status.as_u16())),
+            output,
+            error_message: (!status.is_success())
+                .then(|| format!("Webhook returned HTTP {}", status.as_u16())),
+        }
+    }
+}
+
+/// Resolve a hook's `script_path` inside `scripts_dir`.
+///
+/// The path must pass [`pipeline_hooks::validate_script_path`] and, once
+/// symlinks are resolved, still lie inside the scripts directory.
+async fn resolve_script_path(scripts_dir: &Path, script_path: &str) -> Result<PathBuf, String> {
+    pipeline_hooks::validate_script_path(script_path).map_err(|e| e.to_string())?;
+
+    let root = tokio::fs::canonicalize(scripts_dir).await.map_err(|e| {
+        format!(
+            "Hook scripts directory {} is unavailable: {e}",
+            scripts_dir.display()
+        )
+    })?;
+    let path = tokio::fs::canonicalize(root.join(script_path))
+        .await
+        .map_err(|e| format!("Hook script {script_path} not found: {e}"))?;
+    if !path.starts_with(&root) {
+        return Err(format!(
+            "Hook script {script_path} resolves outside the hook scripts directory"
+        ));
+    }
+    Ok(path)
+}
+
+/// Resolve a webhook hook's host, returning it with the address to pin the
+/// request to.
+///
+/// The URL must pass [`pipeline_hooks::validate_webhook_url`] and every
+/// address the host resolves to must be public, so a hostname cannot be
+/// pointed at an internal service.
+async fn resolve_public_host(url: &str) -> Result<(String, SocketAddr), String> {
+    pipeline_hooks::validate_webhook_url(url).map_err(|e| e.to_string())?;
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid webhook url: {e}"))?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| "Webhook url has no host".to_string())?
+        .to_string();
+    let port = parsed
+        .port_or_known_default()
+        .ok_or_else(|| "Webhook url has no port".to_string())?;
+
+    let lookup_host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((lookup_host, port))
+        .await
+        .map_err(|e| format!("Could not resolve webhook host {host}: {e}"))?
+        .collect();
+    match addrs.first() {
+        None => Err(format!("Webhook host {host} has no addresses")),
+        Some(_) if addrs.iter().any(|a| !pipeline_hooks::is_public_ip(a.ip())) => Err(format!(
+            "Webhook host {host} resolves to a non-public address"
+        )),
+        Some(&addr) => Ok((host, addr)),
+    }
+}
+
+/// Read a response body chunk by chunk, stopping once `max` bytes are held.
+///
+/// The rest of the body is never read, so a misbehaving endpoint cannot
+/// make the executor buffer an unbounded response. The original length is
+/// taken from `Content-Length` when present, otherwise it is the number of
+/// bytes received before the cut.
+async fn read_body_capped(mut response: reqwest::Response, max: usize) -> CapturedOutput {
+    // Keep a few bytes past `max` so the cut can find a character boundary.
+    let limit = max + 3;
+    let content_length = response.content_length();
+    let mut kept = Vec::new();
+    let mut received = 0usize;
+
+    while let Ok(Some(chunk)) = response.chunk().await {
+        received += chunk.len();
+        let room = limit - kept.len();
+        kept.extend_from_slice(&chunk[..chunk.len().min(room)]);
+        if kept.len() >= limit {
+            break;
+        }
+    }
+
+    let original_len = content_length
+        .and_then(|len| usize::try_from(len).ok())
+        .unwrap_or(received);
+    capture_prefix(&kept, original_len, max)
+}
+
+/// The raw outcome of one attempt at running a hook.
+struct HookAttempt {
+    success: bool,
+    /// Process exit code; `None` for webhooks and processes that never ran.
+    exit_code: Option<i32>,
+    /// Exit code or HTTP status matched against the retry policy.
+    code: Option<i32>,
+    /// Stdout or response body, captured once at the hook capture limit.
+    output: CapturedOutput,
+    error_message: Option<String>,
+}
+
+impl HookAttempt {
+    /// An attempt that failed before producing an exit code or status.
+    fn failed(error_message: String) -> Self {
+        Self {
+            success: false,
+            exit_code: None,
+            code: None,
+            output: CapturedOutput::default(),
+            error_message: Some(error_message),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Chain orchestration
+// ---------------------------------------------------------------------------
+
+/// Run hook batches in order, executing up to `concurrency` hooks of a
+/// batch at once via `run`.
+///
+/// Hooks already running in a batch are allowed to finish when one of them
+/// fails in `block` mode; no later batch is started.
+async fn run_batches<F, Fut>(
+    batches: Vec<Vec<EffectiveHook>>,
+    concurrency: usize,
+    run: F,
+) -> HookChainOutcome
+where
+    F: Fn(EffectiveHook) -> Fut,
+    Fut: Future<Output = HookRunResult>,
+{
+    let mut outcome = HookChainOutcome::default();
+
+    for batch in batches {
+        let results: Vec<HookRunResult> = futures::stream::iter(batch)
+            .map(&run)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        outcome.halted_by = results
+            .iter()
+            .find(|r| r.decision() == HookRunDecision::Halt)
+            .map(|r| r.hook_id);
+        outcome.results.extend(results);
+
+        if outcome.halted() {
+            break;
+        }
+    }
+
+    outcome
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use x121_core::pipeline_hooks::{HookPoint, ScopeType};
+
+    use super::*;
+
+    fn make_hook(id: DbId, sort_order: i32, failure_mode: FailureMode) -> EffectiveHook {
+        EffectiveHook {
+            hook_id: id,
+            name: format!("hook-{id}"),
+            hook_type: HookType::Shell,
+            hook_point: HookPoint::PostVariant,
+            scope_type: ScopeType::Studio,
+            failure_mode,
+            config_json: serde_json::json!({"script_path": "/tmp/hook.sh"}),
+            sort_order,
+            source_level: "studio".to_string(),
+            retry: None,
+        }
+    }
+
+    /// Stub runner: hooks with an odd id fail, even ids succeed.
+    async fn stub_run(hook: EffectiveHook) -> HookRunResult {
+        HookRunResult {
+            hook_id: hook.hook_id,
+            failure_mode: hook.failure_mode,
+            success: hook.hook_id % 2 == 0,
+            exit_code: Some(0),
+            output: String::new(),
+            output_truncated: false,
+            output_original_len: 0,
+            error_message: None,
+            duration_ms: 0,
+            attempts: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn block_failure_halts_chain() {
+        let hooks = vec![
+            make_hook(2, 0, FailureMode::Warn),
+            make_hook(1, 1, FailureMode::Block),
+            make_hook(4, 2, FailureMode::Warn),
+        ];
+        let outcome = run_batches(group_hook_batches(hooks), 4, stub_run).await;
+
+        assert!(outcome.halted());
+        assert_eq!(outcome.halted_by, Some(1));
+        let ran: Vec<DbId> = outcome.results.iter().map(|r| r.hook_id).collect();
+        assert_eq!(ran, vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn warn_failure_continues_chain() {
+        let hooks = vec![
+            make_hook(1, 0, FailureMode::Warn),
+            make_hook(3, 1, FailureMode::Ignore),
+            make_hook(2, 2, FailureMode::Block),
+        ];
+        let outcome = run_batches(group_hook_batches(hooks), 4, stub_run).await;
+
+        assert!(!outcome.halted());
+        assert_eq!(outcome.results.len(), 3);
+        assert!(!outcome.results[0].success);
+    }
+
+    #[tokio::test]
+    async fn script_path_must_stay_inside_scripts_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let scripts = root.path().join("hooks");
+        std::fs::create_dir(&scripts).unwrap();
+        std::fs::write(scripts.join("ok.sh"), "exit 0").unwrap();
+        std::fs::write(root.path().join("outside.sh"), "exit 0").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(root.path().join("outside.sh"), scripts.join("link.sh"))
+            .unwrap();
+
+        let resolved = resolve_script_path(&scripts, "ok.sh").await.unwrap();
+        assert!(resolved.ends_with("hooks/ok.sh"));
+        assert!(resolve_script_path(&scripts, "../outside.sh")
+            .await
+            .is_err());
+        assert!(resolve_script_path(&scripts, "/bin/sh").await.is_err());
+        assert!(resolve_script_path(&scripts, "missing.sh").await.is_err());
+        #[cfg(unix)]
+        assert!(resolve_script_path(&scripts, "link.sh").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn webhook_host_must_be_public() {
+        for url in [
+            "http://127.0.0.1:8080/hook",
+            "http://localhost/hook",
+            "http://[::1]/hook",
+            "http://10.1.2.3/hook",
+        ] {
+            assert!(resolve_public_host(url).await.is_err(), "{url}");
+        }
+        let (host, addr) = resolve_public_host("https://93.184.216.34/hook")
+            .await
+            .unwrap();
+        assert_eq!(host, "93.184.216.34");
+        assert_eq!(addr.port(), 443);
+    }
+
+    #[tokio::test]
+    async fn batch_respects_concurrency_limit() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let hooks = (0..6)
+            .map(|i| make_hook(i * 2, 0, FailureMode::Warn))
+            .collect();
+
+        let outcome = run_batches(group_hook_batches(hooks), 2, |hook| {
+            let in_flight = &in_flight;
+            let peak = &peak;
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                stub_run(hook).await
+            }
+        })
+        .await;
+
+        assert_eq!(outcome.results.len(), 6);
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+}
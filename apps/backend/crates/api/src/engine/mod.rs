@@ -3,8 +3,15 @@
 //! Contains the background dispatcher that polls for pending jobs and
 //! assigns them to available ComfyUI workers, plus the progress handler
 //! that translates ComfyUI events into job record updates and WebSocket
-//! notifications.
+//! notifications, the executor that runs pipeline stage hooks, and the
+//! runners for cancellable batch embedding extraction, scene re-stitching,
+//! and full worker repair along with the registry that tracks their runs.
 
 pub mod dispatcher;
+pub mod embedding_batch;
 pub mod health_aggregator;
+pub mod hook_executor;
 pub mod progress;
+pub mod run_registry;
+pub mod scene_restitch;
+pub mod worker_repair;
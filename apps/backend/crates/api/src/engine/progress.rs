@@ -3,15 +3,11 @@
 //! Translates [`ComfyUIEvent`] variants into job database updates and
 //! WebSocket notifications.  No automatic retry is performed on failure.
 
-use axum::extract::ws::Message;
 use sqlx::PgPool;
 use x121_comfyui::events::ComfyUIEvent;
-use x121_core::job_events::{
-    MSG_TYPE_JOB_CANCELLED, MSG_TYPE_JOB_COMPLETED, MSG_TYPE_JOB_FAILED, MSG_TYPE_JOB_PROGRESS,
-};
 use x121_db::repositories::JobRepo;
 
-use crate::ws::WsManager;
+use crate::ws::{OutboundMessage, WsManager, WsTarget};
 
 /// Handle a ComfyUI event by updating the job record and notifying
 /// connected WebSocket clients.
@@ -34,14 +30,9 @@ pub async fn handle_comfyui_event(pool: &PgPool, ws_manager: &WsManager, event:
                 );
             }
 
-            broadcast_json(
+            broadcast(
                 ws_manager,
-                serde_json::json!({
-                    "type": MSG_TYPE_JOB_PROGRESS,
-                    "job_id": platform_job_id,
-                    "percent": percent,
-                    "current_node": current_node,
-                }),
+                OutboundMessage::job_progress(*platform_job_id, *percent, current_node.clone()),
             )
             .await;
         }
@@ -59,14 +50,7 @@ pub async fn handle_comfyui_event(pool: &PgPool, ws_manager: &WsManager, event:
                 );
             }
 
-            broadcast_json(
-                ws_manager,
-                serde_json::json!({
-                    "type": MSG_TYPE_JOB_COMPLETED,
-                    "job_id": platform_job_id,
-                }),
-            )
-            .await;
+            broadcast(ws_manager, OutboundMessage::job_completed(*platform_job_id)).await;
         }
 
         ComfyUIEvent::GenerationError {
@@ -83,13 +67,9 @@ pub async fn handle_comfyui_event(pool: &PgPool, ws_manager: &WsManager, event:
                 );
             }
 
-            broadcast_json(
+            broadcast(
                 ws_manager,
-                serde_json::json!({
-                    "type": MSG_TYPE_JOB_FAILED,
-                    "job_id": platform_job_id,
-                    "error": error,
-                }),
+                OutboundMessage::job_failed(*platform_job_id, error.clone()),
             )
             .await;
         }
@@ -107,14 +87,7 @@ pub async fn handle_comfyui_event(pool: &PgPool, ws_manager: &WsManager, event:
                 );
             }
 
-            broadcast_json(
-                ws_manager,
-                serde_json::json!({
-                    "type": MSG_TYPE_JOB_CANCELLED,
-                    "job_id": platform_job_id,
-                }),
-            )
-            .await;
+            broadcast(ws_manager, OutboundMessage::job_cancelled(*platform_job_id)).await;
         }
 
         // Instance connect/disconnect events are not job-specific.
@@ -122,9 +95,7 @@ pub async fn handle_comfyui_event(pool: &PgPool, ws_manager: &WsManager, event:
     }
 }
 
-/// Serialize a JSON value and broadcast it to all connected WebSocket clients.
-async fn broadcast_json(ws_manager: &WsManager, payload: serde_json::Value) {
-    ws_manager
-        .broadcast(Message::Text(payload.to_string().into()))
-        .await;
+/// Broadcast a typed frame to all connected WebSocket clients.
+async fn broadcast(ws_manager: &WsManager, message: OutboundMessage) {
+    ws_manager.send_typed(WsTarget::All, &message).await;
 }
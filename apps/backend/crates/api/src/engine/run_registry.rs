@@ -0,0 +1,218 @@
+//! In-memory registry of cancellable background runs.
+//!
+//! Shared by batch embedding extraction, scene re-stitching and full worker
+//! repair. Each run owns a progress report behind a lock plus a
+//! cancellation token; the runner updates the report as it goes and checks
+//! the token between units of work. Runs live in memory only and are
+//! dropped a while after they finish.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+use tokio_util::sync::CancellationToken;
+use x121_core::types::RunId;
+
+/// How long a finished run's report stays queryable.
+const FINISHED_RETENTION_MINUTES: i64 = 60;
+
+/// Progress report of a run kept in a [`RunRegistry`].
+pub trait RunReport: Clone {
+    /// The run's ID, fixed when the report is created.
+    fn run_id(&self) -> RunId;
+
+    /// When the run stopped, or `None` while it is still going.
+    fn finished_at(&self) -> Option<DateTime<Utc>>;
+}
+
+/// Handle to a running or finished run.
+pub struct RunHandle<R> {
+    report: Arc<Mutex<R>>,
+    cancel: CancellationToken,
+}
+
+// Derived `Clone` would require `R: Clone` on the handle itself.
+impl<R> Clone for RunHandle<R> {
+    fn clone(&self) -> Self {
+        Self {
+            report: Arc::clone(&self.report),
+            cancel: self.cancel.clone(),
+        }
+    }
+}
+
+impl<R: RunReport> RunHandle<R> {
+    fn new(report: R) -> Self {
+        Self {
+            report: Arc::new(Mutex::new(report)),
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Snapshot of the run's current progress.
+    pub fn report(&self) -> R {
+        self.update(|report| report.clone())
+    }
+
+    /// Apply `apply` to the report under its lock.
+    pub fn update<T>(&self, apply: impl FnOnce(&mut R) -> T) -> T {
+        let mut report = self.report.lock().expect("run report lock poisoned");
+        apply(&mut report)
+    }
+
+    /// Whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+}
+
+/// In-memory registry of runs, keyed by run ID.
+pub struct RunRegistry<R> {
+    runs: Mutex<HashMap<RunId, RunHandle<R>>>,
+}
+
+impl<R> Default for RunRegistry<R> {
+    fn default() -> Self {
+        Self {
+            runs: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<R: RunReport> RunRegistry<R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new run starting from `report` and return its handle.
+    ///
+    /// Finished runs older than the retention window are pruned.
+    pub fn register(&self, report: R) -> RunHandle<R> {
+        self.register_exclusive(report, |_| false)
+            .expect("a run that conflicts with nothing is always registered")
+    }
+
+    /// Register a new run unless an unfinished run matches `conflicts`.
+    ///
+    /// The check and the insert happen under one lock, so two concurrent
+    /// registrations cannot both pass. Returns `None` on a conflict.
+    pub fn register_exclusive(
+        &self,
+        report: R,
+        conflicts: impl Fn(&R) -> bool,
+    ) -> Option<RunHandle<R>> {
+        let handle = RunHandle::new(report);
+        let run_id = handle.report().run_id();
+
+        let cutoff = Utc::now() - Duration::minutes(FINISHED_RETENTION_MINUTES);
+        let mut runs = self.runs.lock().expect("run registry lock poisoned");
+        runs.retain(|_, h| h.report().finished_at().is_none_or(|at| at > cutoff));
+        if runs.values().any(|h| {
+            let existing = h.report();
+            existing.finished_at().is_none() && conflicts(&existing)
+        }) {
+            return None;
+        }
+        runs.insert(run_id, handle.clone());
+        Some(handle)
+    }
+
+    /// Look up a run's current report.
+    pub fn get(&self, run_id: RunId) -> Option<R> {
+        let runs = self.runs.lock().expect("run registry lock poisoned");
+        runs.get(&run_id).map(RunHandle::report)
+    }
+
+    /// Request cancellation of a run, returning its report.
+    ///
+    /// Returns `None` if the run is unknown.
+    pub fn cancel(&self, run_id: RunId) -> Option<R> {
+        let runs = self.runs.lock().expect("run registry lock poisoned");
+        let handle = runs.get(&run_id)?;
+        handle.cancel.cancel();
+        Some(handle.report())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestReport {
+        run_id: RunId,
+        key: u32,
+        finished_at: Option<DateTime<Utc>>,
+    }
+
+    impl TestReport {
+        fn new(key: u32) -> Self {
+            Self {
+                run_id: RunId::new(),
+                key,
+                finished_at: None,
+            }
+        }
+    }
+
+    impl RunReport for TestReport {
+        fn run_id(&self) -> RunId {
+            self.run_id
+        }
+
+        fn finished_at(&self) -> Option<DateTime<Utc>> {
+            self.finished_at
+        }
+    }
+
+    #[test]
+    fn cancel_marks_handle_and_returns_report() {
+        let registry = RunRegistry::new();
+        let handle = registry.register(TestReport::new(1));
+        let run_id = handle.report().run_id;
+
+        assert!(!handle.is_cancelled());
+        assert_eq!(registry.cancel(run_id).map(|r| r.key), Some(1));
+        assert!(handle.is_cancelled());
+        assert!(registry.cancel(RunId::new()).is_none());
+        assert!(registry.get(RunId::new()).is_none());
+    }
+
+    #[test]
+    fn exclusive_registration_ignores_finished_runs() {
+        let registry = RunRegistry::new();
+        let same_key = |key| move |r: &TestReport| r.key == key;
+        let first = registry
+            .register_exclusive(TestReport::new(1), same_key(1))
+            .unwrap();
+
+        assert!(registry
+            .register_exclusive(TestReport::new(1), same_key(1))
+            .is_none());
+        assert!(registry
+            .register_exclusive(TestReport::new(2), same_key(2))
+            .is_some());
+
+        first.update(|r| r.finished_at = Some(Utc::now()));
+        assert!(registry
+            .register_exclusive(TestReport::new(1), same_key(1))
+            .is_some());
+    }
+
+    #[test]
+    fn stale_finished_runs_are_pruned() {
+        let registry = RunRegistry::new();
+        let old = registry.register(TestReport::new(1));
+        let old_id = old.report().run_id;
+        old.update(|r| {
+            r.finished_at = Some(Utc::now() - Duration::minutes(FINISHED_RETENTION_MINUTES + 1))
+        });
+
+        registry.register(TestReport::new(2));
+        assert!(registry.get(old_id).is_none());
+    }
+}
@@ -0,0 +1,332 @@
+//! Cancellable scene-level re-stitching (PRD-25).
+//!
+//! A re-stitch run walks a scene's segments in sequence order, following a
+//! plan from [`x121_core::restitching::plan_scene_restitch`]: segments whose
+//! incoming boundary fails the SSIM threshold are regenerated, the rest are
+//! kept. Cancelling a run stops it before the next segment; the segment in
+//! progress is allowed to finish. The report's `checkpoint` is the sequence
+//! index to pass as `resume_from` to continue an interrupted run. Runs are
+//! kept in a [`RunRegistry`].
+
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use x121_core::restitching::{RestitchAction, SegmentRestitchPlan};
+use x121_core::types::{DbId, RunId};
+
+use super::run_registry::{RunHandle, RunRegistry, RunReport};
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Where a segment stands within a re-stitch run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentRestitchStatus {
+    /// Not reached yet (or never reached, if the run was cancelled).
+    Pending,
+    /// Boundaries passed; the segment was left as-is.
+    Kept,
+    /// The segment was replaced by a newly created one.
+    Regenerated,
+    /// Regeneration failed; see `error`.
+    Failed,
+}
+
+/// Planned action and outcome for one segment of a re-stitch run.
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentRestitchEntry {
+    pub segment_id: DbId,
+    pub sequence_index: i32,
+    pub action: RestitchAction,
+    pub failing_ssim: Option<f64>,
+    pub status: SegmentRestitchStatus,
+    /// The replacement segment, once regenerated.
+    pub new_segment_id: Option<DbId>,
+    pub error: Option<String>,
+}
+
+/// Progress report for a scene re-stitch run.
+#[derive(Debug, Clone, Serialize)]
+pub struct SceneRestitchReport {
+    pub run_id: RunId,
+    pub scene_id: DbId,
+    pub ssim_threshold: f64,
+    pub segments: Vec<SegmentRestitchEntry>,
+    /// Sequence index of the first segment not yet processed, or `None` once
+    /// every planned segment has been handled.
+    pub checkpoint: Option<i32>,
+    /// Whether the run was cancelled before processing every segment.
+    pub cancelled: bool,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl SceneRestitchReport {
+    /// Initial report for a run over `plan`, with every segment pending.
+    pub fn new(scene_id: DbId, ssim_threshold: f64, plan: &[SegmentRestitchPlan]) -> Self {
+        Self {
+            run_id: RunId::new(),
+            scene_id,
+            ssim_threshold,
+            segments: plan
+                .iter()
+                .map(|p| SegmentRestitchEntry {
+                    segment_id: p.segment_id,
+                    sequence_index: p.sequence_index,
+                    action: p.action,
+                    failing_ssim: p.failing_ssim,
+                    status: SegmentRestitchStatus::Pending,
+                    new_segment_id: None,
+                    error: None,
+                })
+                .collect(),
+            checkpoint: plan.first().map(|p| p.sequence_index),
+            cancelled: false,
+            started_at: Utc::now(),
+            finished_at: None,
+        }
+    }
+
+    /// Record the outcome of the segment at `position` and advance the
+    /// checkpoint past it.
+    fn record(&mut self, position: usize, status: SegmentRestitchStatus, result: RegenerateResult) {
+        let entry = &mut self.segments[position];
+        entry.status = status;
+        match result {
+            Ok(new_segment_id) => entry.new_segment_id = new_segment_id,
+            Err(e) => entry.error = Some(e),
+        }
+        self.checkpoint = self.segments.get(position + 1).map(|e| e.sequence_index);
+    }
+}
+
+/// Outcome of regenerating one segment: the replacement segment's ID.
+type RegenerateResult = Result<Option<DbId>, String>;
+
+impl RunReport for SceneRestitchReport {
+    fn run_id(&self) -> RunId {
+        self.run_id
+    }
+
+    fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+}
+
+/// Handle to a running or finished re-stitch run.
+pub type RestitchHandle = RunHandle<SceneRestitchReport>;
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+/// In-memory registry of scene re-stitch runs, keyed by run ID.
+pub type SceneRestitchRegistry = RunRegistry<SceneRestitchReport>;
+
+/// Register a new run over `plan` and return its handle.
+///
+/// Returns `None` if the scene already has an unfinished run, since two
+/// runs regenerating the same segments would race.
+pub fn register_restitch(
+    registry: &SceneRestitchRegistry,
+    scene_id: DbId,
+    ssim_threshold: f64,
+    plan: &[SegmentRestitchPlan],
+) -> Option<RestitchHandle> {
+    registry.register_exclusive(
+        SceneRestitchReport::new(scene_id, ssim_threshold, plan),
+        |run| run.scene_id == scene_id,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Runner
+// ---------------------------------------------------------------------------
+
+/// Walk the run's planned segments in order, calling `regenerate` with the
+/// ID of each segment planned for regeneration.
+///
+/// Segments are processed one at a time because regenerating a segment
+/// changes what the segments after it continue from. Cancellation is checked
+/// before each segment. The final report is returned once the run stops.
+pub async fn run_restitch<F, Fut>(handle: RestitchHandle, regenerate: F) -> SceneRestitchReport
+where
+    F: Fn(DbId) -> Fut,
+    Fut: Future<Output = Result<DbId, String>>,
+{
+    let planned: Vec<(DbId, RestitchAction)> = handle
+        .report()
+        .segments
+        .iter()
+        .map(|e| (e.segment_id, e.action))
+        .collect();
+
+    for (position, (segment_id, action)) in planned.into_iter().enumerate() {
+        if handle.is_cancelled() {
+            break;
+        }
+        let (status, result) = match action {
+            RestitchAction::Keep => (SegmentRestitchStatus::Kept, Ok(None)),
+            RestitchAction::Regenerate => match regenerate(segment_id).await {
+                Ok(new_id) => (SegmentRestitchStatus::Regenerated, Ok(Some(new_id))),
+                Err(e) => {
+                    tracing::warn!(segment_id, error = %e, "Scene re-stitch regeneration failed");
+                    (SegmentRestitchStatus::Failed, Err(e))
+                }
+            },
+        };
+        handle.update(|report| report.record(position, status, result));
+    }
+
+    handle.update(|report| {
+        report.cancelled = handle.is_cancelled() && report.checkpoint.is_some();
+        report.finished_at = Some(Utc::now());
+        report.clone()
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration as StdDuration;
+
+    use x121_core::restitching::{plan_scene_restitch, SegmentBoundaries, DEFAULT_SSIM_THRESHOLD};
+
+    use super::*;
+
+    fn plan_with_failure_at(count: i32, failing_index: i32) -> Vec<SegmentRestitchPlan> {
+        let segments: Vec<SegmentBoundaries> = (0..count)
+            .map(|i| SegmentBoundaries {
+                segment_id: DbId::from(i) + 1,
+                sequence_index: i,
+                ssim_before: Some(if i == failing_index { 0.40 } else { 0.97 }),
+                ssim_after: Some(0.97),
+            })
+            .collect();
+        plan_scene_restitch(&segments, DEFAULT_SSIM_THRESHOLD, None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn regenerates_only_the_failing_segment() {
+        let registry = SceneRestitchRegistry::new();
+        let plan = plan_with_failure_at(4, 2);
+        let handle = register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).unwrap();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let report = run_restitch(handle, |segment_id| {
+            let calls = Arc::clone(&calls);
+            async move {
+                calls.lock().unwrap().push(segment_id);
+                Ok(segment_id + 100)
+            }
+        })
+        .await;
+
+        assert_eq!(*calls.lock().unwrap(), vec![3]);
+        let statuses: Vec<_> = report.segments.iter().map(|e| e.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                SegmentRestitchStatus::Kept,
+                SegmentRestitchStatus::Kept,
+                SegmentRestitchStatus::Regenerated,
+                SegmentRestitchStatus::Kept,
+            ]
+        );
+        assert_eq!(report.segments[2].new_segment_id, Some(103));
+        assert_eq!(report.checkpoint, None);
+        assert!(!report.cancelled);
+        assert!(report.finished_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn failed_regeneration_is_reported_and_run_continues() {
+        let registry = SceneRestitchRegistry::new();
+        let plan = plan_with_failure_at(3, 1);
+        let handle = register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).unwrap();
+
+        let report = run_restitch(handle, |_| async { Err("boom".to_string()) }).await;
+
+        assert_eq!(report.segments[1].status, SegmentRestitchStatus::Failed);
+        assert_eq!(report.segments[1].error.as_deref(), Some("boom"));
+        assert_eq!(report.segments[2].status, SegmentRestitchStatus::Kept);
+        assert_eq!(report.checkpoint, None);
+    }
+
+    #[tokio::test]
+    async fn cancel_stops_cleanly_with_resumable_checkpoint() {
+        let registry = Arc::new(SceneRestitchRegistry::new());
+        let plan: Vec<SegmentRestitchPlan> = (0..10)
+            .map(|i| SegmentRestitchPlan {
+                segment_id: DbId::from(i) + 1,
+                sequence_index: i,
+                action: RestitchAction::Regenerate,
+                failing_ssim: Some(0.1),
+            })
+            .collect();
+        let handle = register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).unwrap();
+        let run_id = handle.report().run_id;
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let task = tokio::spawn({
+            let calls = Arc::clone(&calls);
+            async move {
+                run_restitch(handle, |segment_id| {
+                    let calls = Arc::clone(&calls);
+                    async move {
+                        calls.lock().unwrap().push(segment_id);
+                        tokio::time::sleep(StdDuration::from_millis(20)).await;
+                        Ok(segment_id + 100)
+                    }
+                })
+                .await
+            }
+        });
+
+        // Wait for some progress, then cancel.
+        while registry.get(run_id).unwrap().checkpoint.unwrap_or(i32::MAX) < 2 {
+            tokio::time::sleep(StdDuration::from_millis(5)).await;
+        }
+        assert!(registry.cancel(run_id).is_some());
+
+        let report = task.await.unwrap();
+        assert!(report.cancelled);
+        let checkpoint = report.checkpoint.expect("run stopped early");
+        assert!(checkpoint < 10);
+
+        // Everything before the checkpoint finished; nothing after it started.
+        for entry in &report.segments {
+            if entry.sequence_index < checkpoint {
+                assert_eq!(entry.status, SegmentRestitchStatus::Regenerated);
+            } else {
+                assert_eq!(entry.status, SegmentRestitchStatus::Pending);
+                assert!(entry.new_segment_id.is_none());
+            }
+        }
+        assert_eq!(calls.lock().unwrap().len(), checkpoint as usize);
+        assert!(registry.get(run_id).unwrap().finished_at.is_some());
+
+        assert!(registry.cancel(RunId::new()).is_none());
+    }
+
+    #[tokio::test]
+    async fn second_run_for_an_active_scene_is_refused() {
+        let registry = SceneRestitchRegistry::new();
+        let plan = plan_with_failure_at(3, 1);
+        let handle = register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).unwrap();
+
+        assert!(register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).is_none());
+        assert!(register_restitch(&registry, 8, DEFAULT_SSIM_THRESHOLD, &plan).is_some());
+
+        run_restitch(handle, |segment_id| async move { Ok(segment_id + 100) }).await;
+        assert!(register_restitch(&registry, 7, DEFAULT_SSIM_THRESHOLD, &plan).is_some());
+    }
+}
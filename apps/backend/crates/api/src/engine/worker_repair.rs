@@ -0,0 +1,351 @@
+//! Cancellable full worker repair with per-step reporting (PRD-43).
+//!
+//! A full repair runs the steps in [`x121_core::integrity::FULL_REPAIR_STEPS`]
+//! in order. Every step's outcome is recorded in the run report as it
+//! happens; a failing step is reported and the run moves on to the next one.
+//! Cancelling a run stops it before the next step; the step in progress is
+//! allowed to finish. A run can resume an earlier one, in which case the
+//! steps that already succeeded are skipped. Runs are kept in a
+//! [`RunRegistry`].
+
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use x121_core::types::{DbId, RunId};
+
+use super::run_registry::{RunHandle, RunRegistry, RunReport};
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Where a step stands within a repair run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepairStepStatus {
+    /// Not reached yet (or never reached, if the run was cancelled).
+    Pending,
+    /// Currently executing.
+    Running,
+    Succeeded,
+    /// The step failed; see `error`.
+    Failed,
+    /// Already succeeded in the run this one resumes.
+    Skipped,
+}
+
+/// Outcome of one step of a repair run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RepairStepEntry {
+    /// Repair action name, e.g. `sync_models`.
+    pub step: String,
+    pub status: RepairStepStatus,
+    /// The integrity scan created by the step, once it succeeded.
+    pub scan_id: Option<DbId>,
+    pub error: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Progress report for a full worker repair run.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkerRepairReport {
+    pub run_id: RunId,
+    pub worker_id: DbId,
+    /// The run whose succeeded steps were skipped, if this is a resume.
+    pub resumed_from: Option<RunId>,
+    pub steps: Vec<RepairStepEntry>,
+    /// Whether the run was cancelled before reaching every step.
+    pub cancelled: bool,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl WorkerRepairReport {
+    /// Initial report for a run of `steps` on a worker.
+    ///
+    /// When `resumed_from` is given, steps that succeeded in that run are
+    /// marked skipped.
+    pub fn new(worker_id: DbId, steps: &[&str], resumed_from: Option<&WorkerRepairReport>) -> Self {
+        Self {
+            run_id: RunId::new(),
+            worker_id,
+            resumed_from: resumed_from.map(|r| r.run_id),
+            steps: steps
+                .iter()
+                .map(|step| {
+                    let done = resumed_from.is_some_and(|r| r.step_done(step));
+                    RepairStepEntry {
+                        step: step.to_string(),
+                        status: if done {
+                            RepairStepStatus::Skipped
+                        } else {
+                            RepairStepStatus::Pending
+                        },
+                        scan_id: None,
+                        error: None,
+                        started_at: None,
+                        finished_at: None,
+                    }
+                })
+                .collect(),
+            cancelled: false,
+            started_at: Utc::now(),
+            finished_at: None,
+        }
+    }
+
+    /// Whether `step` succeeded in this run or an earlier one it resumed.
+    pub fn step_done(&self, step: &str) -> bool {
+        self.steps.iter().any(|e| {
+            e.step == step
+                && matches!(
+                    e.status,
+                    RepairStepStatus::Succeeded | RepairStepStatus::Skipped
+                )
+        })
+    }
+
+    /// Whether every step succeeded (in this run or the one it resumed).
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(|e| self.step_done(&e.step))
+    }
+}
+
+impl RunReport for WorkerRepairReport {
+    fn run_id(&self) -> RunId {
+        self.run_id
+    }
+
+    fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+}
+
+/// Handle to a running or finished repair run.
+pub type RepairHandle = RunHandle<WorkerRepairReport>;
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+/// In-memory registry of worker repair runs, keyed by run ID.
+pub type WorkerRepairRegistry = RunRegistry<WorkerRepairReport>;
+
+// ---------------------------------------------------------------------------
+// Runner
+// ---------------------------------------------------------------------------
+
+/// Run the pending steps of a repair in order, calling `run_step` with each
+/// step's name.
+///
+/// `run_step` returns the ID of the integrity scan the step created. A
+/// failing step is recorded and the run continues with the next step.
+/// Cancellation is checked before each step. The final report is returned
+/// once the run stops.
+pub async fn run_repair<F, Fut>(handle: RepairHandle, run_step: F) -> WorkerRepairReport
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<DbId, String>>,
+{
+    let pending: Vec<(usize, String)> = handle
+        .report()
+        .steps
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.status == RepairStepStatus::Pending)
+        .map(|(position, e)| (position, e.step.clone()))
+        .collect();
+
+    for (position, step) in pending {
+        if handle.is_cancelled() {
+            break;
+        }
+        update_step(&handle, position, |e| {
+            e.status = RepairStepStatus::Running;
+            e.started_at = Some(Utc::now());
+        });
+
+        let result = run_step(step.clone()).await;
+        if let Err(e) = &result {
+            tracing::warn!(step = %step, error = %e, "Worker repair step failed");
+        }
+        update_step(&handle, position, |e| {
+            match result {
+                Ok(scan_id) => {
+                    e.status = RepairStepStatus::Succeeded;
+                    e.scan_id = Some(scan_id);
+                }
+                Err(err) => {
+                    e.status = RepairStepStatus::Failed;
+                    e.error = Some(err);
+                }
+            }
+            e.finished_at = Some(Utc::now());
+        });
+    }
+
+    handle.update(|report| {
+        report.cancelled = handle.is_cancelled()
+            && report
+                .steps
+                .iter()
+                .any(|e| e.status == RepairStepStatus::Pending);
+        report.finished_at = Some(Utc::now());
+        report.clone()
+    })
+}
+
+/// Apply `apply` to the step at `position` of the run's report.
+fn update_step(handle: &RepairHandle, position: usize, apply: impl FnOnce(&mut RepairStepEntry)) {
+    handle.update(|report| apply(&mut report.steps[position]));
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::sync::Notify;
+    use x121_core::integrity::{FULL_REPAIR_STEPS, REPAIR_INSTALL_NODES, REPAIR_SYNC_MODELS};
+
+    use super::*;
+
+    const STEPS: &[&str] = &["verify", REPAIR_SYNC_MODELS, REPAIR_INSTALL_NODES];
+
+    fn statuses(report: &WorkerRepairReport) -> Vec<RepairStepStatus> {
+        report.steps.iter().map(|e| e.status).collect()
+    }
+
+    #[tokio::test]
+    async fn failing_step_is_reported_and_later_steps_still_run() {
+        let registry = WorkerRepairRegistry::new();
+        let handle = registry.register(WorkerRepairReport::new(3, STEPS, None));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let report = run_repair(handle, |step| {
+            let calls = Arc::clone(&calls);
+            async move {
+                calls.lock().unwrap().push(step.clone());
+                if step == REPAIR_SYNC_MODELS {
+                    Err("model source unreachable".to_string())
+                } else {
+                    Ok(calls.lock().unwrap().len() as DbId)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(*calls.lock().unwrap(), STEPS);
+        assert_eq!(
+            statuses(&report),
+            vec![
+                RepairStepStatus::Succeeded,
+                RepairStepStatus::Failed,
+                RepairStepStatus::Succeeded,
+            ]
+        );
+        assert_eq!(report.steps[0].scan_id, Some(1));
+        assert_eq!(
+            report.steps[1].error.as_deref(),
+            Some("model source unreachable")
+        );
+        assert_eq!(report.steps[1].scan_id, None);
+        assert_eq!(report.steps[2].scan_id, Some(3));
+        assert!(report
+            .steps
+            .iter()
+            .all(|e| e.started_at.is_some() && e.finished_at.is_some()));
+        assert!(!report.cancelled);
+        assert!(!report.is_complete());
+        assert!(report.finished_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn resume_skips_steps_that_already_succeeded() {
+        let registry = WorkerRepairRegistry::new();
+        let first = run_repair(
+            registry.register(WorkerRepairReport::new(3, FULL_REPAIR_STEPS, None)),
+            |step| async move {
+                if step == REPAIR_INSTALL_NODES {
+                    Err("pip failed".to_string())
+                } else {
+                    Ok(10)
+                }
+            },
+        )
+        .await;
+        assert!(!first.is_complete());
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let handle = registry.register(WorkerRepairReport::new(3, FULL_REPAIR_STEPS, Some(&first)));
+        let report = run_repair(handle, |step| {
+            let calls = Arc::clone(&calls);
+            async move {
+                calls.lock().unwrap().push(step);
+                Ok(11)
+            }
+        })
+        .await;
+
+        assert_eq!(*calls.lock().unwrap(), vec![REPAIR_INSTALL_NODES]);
+        assert_eq!(report.resumed_from, Some(first.run_id));
+        assert_eq!(
+            statuses(&report),
+            vec![RepairStepStatus::Skipped, RepairStepStatus::Succeeded]
+        );
+        assert!(report.is_complete());
+    }
+
+    #[tokio::test]
+    async fn cancel_halts_before_the_next_step() {
+        let registry = Arc::new(WorkerRepairRegistry::new());
+        let handle = registry.register(WorkerRepairReport::new(3, STEPS, None));
+        let run_id = handle.report().run_id;
+        let started = Arc::new(Notify::new());
+        let release = Arc::new(Notify::new());
+
+        let task = tokio::spawn({
+            let started = Arc::clone(&started);
+            let release = Arc::clone(&release);
+            async move {
+                run_repair(handle, |_| {
+                    let started = Arc::clone(&started);
+                    let release = Arc::clone(&release);
+                    async move {
+                        started.notify_one();
+                        release.notified().await;
+                        Ok(1)
+                    }
+                })
+                .await
+            }
+        });
+
+        // Cancel while the first step is in progress.
+        started.notified().await;
+        let snapshot = registry.cancel(run_id).unwrap();
+        assert_eq!(snapshot.steps[0].status, RepairStepStatus::Running);
+        release.notify_one();
+
+        let report = task.await.unwrap();
+        assert!(report.cancelled);
+        assert_eq!(
+            statuses(&report),
+            vec![
+                RepairStepStatus::Succeeded,
+                RepairStepStatus::Pending,
+                RepairStepStatus::Pending,
+            ]
+        );
+        assert!(report.steps[1].started_at.is_none());
+        assert!(registry.get(run_id).unwrap().finished_at.is_some());
+
+        assert!(registry.cancel(RunId::new()).is_none());
+    }
+}
@@ -32,6 +32,10 @@ pub enum AppError {
     /// The resource existed but has been permanently removed (HTTP 410).
     #[error("Gone: {0}")]
     Gone(String),
+
+    /// The server is at capacity for this kind of request (HTTP 429).
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
 }
 
 /// Cloud provider error from `x121_core::cloud`.
@@ -80,6 +84,11 @@ impl IntoResponse for AppError {
                     "NOT_FOUND",
                     format!("{entity} with id {id} not found"),
                 ),
+                CoreError::RunNotFound { entity, run_id } => (
+                    StatusCode::NOT_FOUND,
+                    "NOT_FOUND",
+                    format!("{entity} with id {run_id} not found"),
+                ),
                 CoreError::Validation(msg) => {
                     (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", msg.clone())
                 }
@@ -145,6 +154,11 @@ impl IntoResponse for AppError {
                 msg.clone(),
             ),
             AppError::Gone(msg) => (StatusCode::GONE, "GONE", msg.clone()),
+            AppError::TooManyRequests(msg) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "TOO_MANY_REQUESTS",
+                msg.clone(),
+            ),
         };
 
         let body = json!({
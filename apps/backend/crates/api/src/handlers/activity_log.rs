@@ -23,6 +23,7 @@ use crate::middleware::rbac::RequireAdmin;
 use crate::query::parse_timestamp;
 use crate::response::DataResponse;
 use crate::state::AppState;
+use crate::ws::{OutboundMessage, WsTarget};
 
 // ---------------------------------------------------------------------------
 // REST query parameter types
@@ -402,6 +403,11 @@ async fn handle_activity_ws(socket: WebSocket, state: AppState, auth: AuthUser)
     let mut rx = state.activity_broadcaster.subscribe();
     let (mut sink, mut stream) = socket.split();
 
+    // Frames reach the socket through the connection manager so they share
+    // the versioned wire format and send-queue limits of the main endpoint.
+    let mut out = state.ws_manager.add(conn_id.clone(), None).await;
+    let target = WsTarget::Connection(conn_id.clone());
+
     // Default filter: all levels, all sources, no mode restriction (show everything).
     let mut filter = WsFilter {
         levels: vec![
@@ -431,6 +437,7 @@ async fn handle_activity_ws(socket: WebSocket, state: AppState, auth: AuthUser)
                             }
                         }
                     }
+                    Some(Ok(Message::Pong(_))) => state.ws_manager.record_pong(&conn_id).await,
                     Some(Ok(Message::Close(_))) | None => break,
                     Some(Err(e)) => {
                         tracing::debug!(conn_id = %conn_id, error = %e, "Activity WS receive error");
@@ -440,7 +447,19 @@ async fn handle_activity_ws(socket: WebSocket, state: AppState, auth: AuthUser)
                 }
             }
 
-            // Outbound: broadcast entries.
+            // Outbound: frames queued for this connection.
+            frame = out.recv() => {
+                match frame {
+                    Some(frame) => {
+                        if sink.send(frame).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            }
+
+            // Broadcast entries, filtered and queued for this connection.
             result = rx.recv() => {
                 match result {
                     Ok(entry) => {
@@ -457,33 +476,16 @@ async fn handle_activity_ws(socket: WebSocket, state: AppState, auth: AuthUser)
                             continue;
                         }
 
-                        let payload = serde_json::json!({
-                            "type": "entry",
-                            "timestamp": entry.timestamp.to_rfc3339(),
-                            "level": entry.level,
-                            "source": entry.source,
-                            "message": entry.message,
-                            "fields": entry.fields,
-                            "category": entry.category,
-                            "entity_type": entry.entity_type,
-                            "entity_id": entry.entity_id,
-                            "user_id": entry.user_id,
-                            "job_id": entry.job_id,
-                            "project_id": entry.project_id,
-                            "trace_id": entry.trace_id,
-                        });
-
-                        let msg = Message::Text(payload.to_string().into());
-                        if sink.send(msg).await.is_err() {
-                            break;
-                        }
+                        state
+                            .ws_manager
+                            .send_typed(target.clone(), &OutboundMessage::ActivityEntry { entry })
+                            .await;
                     }
-                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
-                        let msg = serde_json::json!({
-                            "type": "lagged",
-                            "skipped": n,
-                        });
-                        let _ = sink.send(Message::Text(msg.to_string().into())).await;
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        state
+                            .ws_manager
+                            .send_typed(target.clone(), &OutboundMessage::ActivityLagged { skipped })
+                            .await;
                     }
                     Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
                 }
@@ -491,6 +493,8 @@ async fn handle_activity_ws(socket: WebSocket, state: AppState, auth: AuthUser)
         }
     }
 
+    state.ws_manager.remove(&conn_id).await;
+
     // Publish a curated entry about disconnection.
     state.activity_broadcaster.publish(
         ActivityLogEntry::curated(
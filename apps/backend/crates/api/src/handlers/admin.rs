@@ -11,7 +11,7 @@ use x121_core::types::DbId;
 use x121_db::models::user::{CreateUser, UpdateUser, User, UserResponse};
 use x121_db::repositories::{RoleRepo, UserRepo};
 
-use crate::auth::password::{hash_password, validate_password_strength};
+use crate::auth::password::{hash_password_with, validate_password_strength};
 use crate::error::{AppError, AppResult};
 use crate::middleware::rbac::RequireAdmin;
 use crate::state::AppState;
@@ -65,7 +65,7 @@ pub async fn create_user(
         .map_err(|msg| AppError::Core(CoreError::Validation(msg)))?;
 
     // Hash the password.
-    let hashed = hash_password(&input.password)
+    let hashed = hash_password_with(&input.password, &state.config.password_hash)
         .map_err(|e| AppError::InternalError(format!("Password hashing error: {e}")))?;
 
     let create_dto = CreateUser {
@@ -178,7 +178,7 @@ pub async fn reset_password(
         .map_err(|msg| AppError::Core(CoreError::Validation(msg)))?;
 
     // Hash the new password.
-    let hashed = hash_password(&input.new_password)
+    let hashed = hash_password_with(&input.new_password, &state.config.password_hash)
         .map_err(|e| AppError::InternalError(format!("Password hashing error: {e}")))?;
 
     let updated = UserRepo::update_password(&state.pool, id, &hashed).await?;
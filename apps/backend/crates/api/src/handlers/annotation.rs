@@ -9,15 +9,21 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
 
-use x121_core::annotation::{validate_annotations_json, validate_frame_number};
+use x121_core::annotation::{
+    build_coco_export, collect_export_objects, segment_frame_count, validate_annotations_json,
+    validate_export_frame, validate_frame_number, AnnotationExportFormat,
+};
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset};
 use x121_core::types::DbId;
+use x121_core::video_settings::DEFAULT_FPS;
 use x121_db::models::frame_annotation::{
     CreateFrameAnnotation, CreateMediaVariantAnnotation, CreateVersionAnnotation,
     UpdateFrameAnnotation,
 };
-use x121_db::repositories::{FrameAnnotationRepo, MediaVariantRepo, SceneVideoVersionRepo};
+use x121_db::repositories::{
+    FrameAnnotationRepo, MediaVariantRepo, SceneVideoVersionRepo, SegmentRepo,
+};
 
 use crate::error::{AppError, AppResult};
 use crate::handlers::segment::ensure_segment_exists;
@@ -37,6 +43,13 @@ pub struct AnnotationListFilters {
     pub frame_number: Option<i32>,
 }
 
+/// Query parameters for exporting a frame's annotations.
+#[derive(Debug, Deserialize)]
+pub struct AnnotationExportParams {
+    /// `json` (default) or `coco`.
+    pub format: Option<String>,
+}
+
 /// Query parameters for browsing all annotated items.
 #[derive(Debug, Deserialize)]
 pub struct AnnotationBrowseParams {
@@ -194,27 +207,64 @@ pub async fn annotation_summary(
     Ok(Json(DataResponse { data: summary }))
 }
 
-/// GET /segments/{id}/annotations/export/{frame}
+/// GET /segments/{id}/annotations/export/{frame}?format=json|coco
 ///
-/// Returns JSON with annotation data for a specific frame,
-/// suitable for client-side PNG compositing.
+/// Exports the annotation objects on a frame, capped at the configured
+/// maximum. `json` (the default) returns a flat array of drawing objects,
+/// suitable for client-side PNG compositing; `coco` returns a COCO-style
+/// document. Returns 404 when the segment is missing or the frame lies past
+/// its end.
 pub async fn export_frame(
     _auth: AuthUser,
     State(state): State<AppState>,
     Path((segment_id, frame)): Path<(DbId, i32)>,
+    Query(params): Query<AnnotationExportParams>,
 ) -> AppResult<impl IntoResponse> {
-    validate_frame_number(frame).map_err(AppError::Core)?;
+    let format = params
+        .format
+        .as_deref()
+        .map(str::parse::<AnnotationExportFormat>)
+        .transpose()
+        .map_err(AppError::Core)?
+        .unwrap_or_default();
+
+    let segment = SegmentRepo::find_by_id(&state.pool, segment_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::Core(CoreError::NotFound {
+                entity: "Segment",
+                id: segment_id,
+            })
+        })?;
+    let frame_count = segment
+        .duration_secs
+        .map(|secs| segment_frame_count(secs, DEFAULT_FPS));
+    validate_export_frame(frame, frame_count).map_err(AppError::Core)?;
 
     let annotations =
         FrameAnnotationRepo::list_by_segment_and_frame(&state.pool, segment_id, frame).await?;
+    let (objects, truncated) = collect_export_objects(
+        annotations.iter().map(|a| &a.annotations_json),
+        state.config.annotation_export_max,
+    );
+    if truncated {
+        tracing::warn!(
+            segment_id,
+            frame_number = frame,
+            max = state.config.annotation_export_max,
+            "Frame annotation export truncated"
+        );
+    }
 
-    // Collect all annotation JSON arrays into a flat list for easy compositing.
-    let all_objects: Vec<serde_json::Value> = annotations
-        .iter()
-        .flat_map(|a| a.annotations_json.as_array().cloned().unwrap_or_default())
-        .collect();
+    let data = match format {
+        AnnotationExportFormat::Json => serde_json::Value::Array(objects),
+        AnnotationExportFormat::Coco => {
+            serde_json::to_value(build_coco_export(segment_id, frame, objects, truncated))
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+        }
+    };
 
-    Ok(Json(DataResponse { data: all_objects }))
+    Ok(Json(DataResponse { data }))
 }
 
 /* --------------------------------------------------------------------------
@@ -291,7 +341,8 @@ pub async fn list_version_annotators(
     State(state): State<AppState>,
     Path((_scene_id, version_id)): Path<(DbId, DbId)>,
 ) -> AppResult<impl IntoResponse> {
-    let user_ids = FrameAnnotationRepo::list_annotators_for_version(&state.pool, version_id).await?;
+    let user_ids =
+        FrameAnnotationRepo::list_annotators_for_version(&state.pool, version_id).await?;
 
     // Resolve user names
     #[derive(serde::Serialize, sqlx::FromRow)]
@@ -7,7 +7,11 @@ use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use x121_core::assets::dependencies::check_deletion_safe;
+use serde::Deserialize;
+use x121_core::assets::dependencies::{
+    check_deletion_safe, traverse_asset_dependents, DEFAULT_MAX_DEPENDENCY_DEPTH,
+    MAX_DEPENDENCY_DEPTH,
+};
 use x121_core::assets::impact::{AffectedGroup, UpdateImpact};
 use x121_core::assets::registry::validate_rating;
 use x121_core::error::CoreError;
@@ -220,27 +224,55 @@ pub async fn add_dependency(
 // Impact analysis
 // ---------------------------------------------------------------------------
 
+/// Query parameters for impact analysis.
+#[derive(Debug, Deserialize)]
+pub struct ImpactParams {
+    /// Longest asset-to-asset chain to follow (default
+    /// [`DEFAULT_MAX_DEPENDENCY_DEPTH`], at most [`MAX_DEPENDENCY_DEPTH`]).
+    pub max_depth: Option<usize>,
+}
+
 /// GET /api/v1/assets/{id}/impact
 ///
-/// Analyze the update impact of changing/removing an asset.
+/// Analyze the update impact of changing/removing an asset, including
+/// everything that depends on it through other assets. A dependency cycle
+/// or a chain longer than `max_depth` is rejected with 422.
 pub async fn get_impact(
     RequireAuth(_auth): RequireAuth,
     State(state): State<AppState>,
     Path(id): Path<DbId>,
+    Query(params): Query<ImpactParams>,
 ) -> AppResult<impl IntoResponse> {
+    let max_depth = params.max_depth.unwrap_or(DEFAULT_MAX_DEPENDENCY_DEPTH);
+    if !(1..=MAX_DEPENDENCY_DEPTH).contains(&max_depth) {
+        return Err(AppError::BadRequest(format!(
+            "max_depth must be between 1 and {MAX_DEPENDENCY_DEPTH}"
+        )));
+    }
+
     ensure_asset_exists(&state.pool, id).await?;
 
-    let deps = AssetRepo::get_dependents(&state.pool, id).await?;
+    let mut graph: std::collections::HashMap<DbId, Vec<DbId>> = std::collections::HashMap::new();
+    for (asset_id, dependent_id) in AssetRepo::list_asset_edges(&state.pool).await? {
+        graph.entry(asset_id).or_default().push(dependent_id);
+    }
+    let traversal = traverse_asset_dependents(id, &graph, max_depth)
+        .map_err(|e| AppError::Unprocessable(e.to_string()))?;
+
+    let mut reached = vec![id];
+    reached.extend(&traversal.asset_ids);
+    let deps = AssetRepo::get_dependents_for_assets(&state.pool, &reached).await?;
 
-    // Group dependencies by entity type.
+    // Group dependencies by entity type, counting each entity once.
     let mut groups: std::collections::HashMap<String, Vec<DbId>> = std::collections::HashMap::new();
     for dep in &deps {
-        groups
-            .entry(dep.dependent_entity_type.clone())
-            .or_default()
-            .push(dep.dependent_entity_id);
+        let ids = groups.entry(dep.dependent_entity_type.clone()).or_default();
+        if !ids.contains(&dep.dependent_entity_id) {
+            ids.push(dep.dependent_entity_id);
+        }
     }
 
+    let total_dependents = groups.values().map(|ids| ids.len() as i64).sum();
     let affected_entities: Vec<AffectedGroup> = groups
         .into_iter()
         .map(|(entity_type, ids)| AffectedGroup {
@@ -250,12 +282,12 @@ pub async fn get_impact(
         })
         .collect();
 
-    let total_dependents = deps.len() as i64;
-
     let impact = UpdateImpact {
         asset_id: id,
         total_dependents,
         affected_entities,
+        transitive_asset_ids: traversal.asset_ids,
+        dependency_depth: traversal.depth,
     };
 
     Ok(Json(DataResponse { data: impact }))
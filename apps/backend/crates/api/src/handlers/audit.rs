@@ -2,13 +2,18 @@
 //!
 //! All endpoints require admin role.
 
+use std::convert::Infallible;
+
 use axum::extract::{Path, Query, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Deserialize;
-use x121_core::audit::compute_integrity_hash;
+use futures::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio_stream::wrappers::ReceiverStream;
+use x121_core::audit::ChainVerifier;
 use x121_db::models::audit::{
-    AuditLogPage, AuditQuery, IntegrityCheckResult, UpdateRetentionPolicy,
+    AuditLog, AuditLogPage, AuditQuery, IntegrityCheckResult, UpdateRetentionPolicy,
 };
 use x121_db::repositories::{AuditLogRepo, AuditRetentionPolicyRepo};
 
@@ -153,52 +158,110 @@ pub async fn export_audit_logs(
 // Integrity check
 // ---------------------------------------------------------------------------
 
+/// Number of audit log rows fetched per integrity-check page.
+const INTEGRITY_BATCH_SIZE: i64 = 1000;
+
+/// Buffer size for integrity-check progress events.
+const INTEGRITY_CHANNEL_CAPACITY: usize = 16;
+
+/// Progress payload emitted after each verified page.
+#[derive(Debug, Serialize)]
+struct IntegrityProgressEvent {
+    verified_entries: usize,
+}
+
+/// Walk the whole audit log page by page, feeding each page to `verifier`.
+///
+/// `on_page` is invoked after every page so callers can report progress.
+/// Stops early at the first chain break.
+async fn verify_audit_chain<F, Fut>(
+    pool: &sqlx::PgPool,
+    verifier: &mut ChainVerifier,
+    mut on_page: F,
+) -> Result<(), sqlx::Error>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let mut after_id = None;
+    loop {
+        let entries =
+            AuditLogRepo::fetch_integrity_batch(pool, after_id, INTEGRITY_BATCH_SIZE).await?;
+        let Some(last) = entries.last() else {
+            return Ok(());
+        };
+        after_id = Some(last.id);
+
+        let rows: Vec<_> = entries.iter().map(AuditLog::to_chain_row).collect();
+        let intact = verifier.feed(&rows);
+        on_page(verifier.verified_entries()).await;
+
+        if !intact || (entries.len() as i64) < INTEGRITY_BATCH_SIZE {
+            return Ok(());
+        }
+    }
+}
+
 /// GET /admin/audit-logs/integrity-check
 ///
 /// Run integrity verification on the audit log hash chain. Admin only.
+///
+/// On failure the result pinpoints the first break (position, entry ID, and
+/// expected vs. stored hash) so tampering can be localized.
 pub async fn check_integrity(
     State(state): State<AppState>,
     RequireAdmin(_admin): RequireAdmin,
 ) -> AppResult<impl IntoResponse> {
-    let entries = AuditLogRepo::fetch_for_integrity_check(&state.pool, None, None).await?;
-
-    let mut verified: i64 = 0;
-    let mut prev_hash: Option<String> = None;
-    let mut first_break: Option<i64> = None;
-
-    for entry in &entries {
-        // Build canonical entry data for hash computation.
-        let entry_data = format!(
-            "{}|{}|{}|{}|{}",
-            entry.timestamp.to_rfc3339(),
-            entry.user_id.map_or(String::new(), |id| id.to_string()),
-            entry.action_type,
-            entry.entity_type.as_deref().unwrap_or(""),
-            entry.entity_id.map_or(String::new(), |id| id.to_string()),
-        );
-
-        let expected_hash = compute_integrity_hash(prev_hash.as_deref(), &entry_data);
-
-        if let Some(ref stored_hash) = entry.integrity_hash {
-            if *stored_hash != expected_hash {
-                first_break = Some(entry.id);
-                break;
-            }
-        }
-        // Entries without a hash (e.g., legacy entries) are skipped in chain
-        // validation but still counted.
+    let mut verifier = ChainVerifier::new();
+    verify_audit_chain(&state.pool, &mut verifier, |_| async {}).await?;
 
-        verified += 1;
-        prev_hash = entry.integrity_hash.clone();
-    }
+    let result = IntegrityCheckResult::from(verifier.finish());
+    Ok(Json(DataResponse { data: result }))
+}
 
-    let result = IntegrityCheckResult {
-        verified_entries: verified,
-        chain_valid: first_break.is_none(),
-        first_break,
-    };
+/// GET /admin/audit-logs/integrity-check/stream
+///
+/// Same verification as [`check_integrity`], streamed over Server-Sent
+/// Events: a `progress` event after each page, then a single `done` event
+/// carrying the [`IntegrityCheckResult`] (or an `error` event). Admin only.
+pub async fn stream_integrity_check(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let (tx, rx) = tokio::sync::mpsc::channel::<Event>(INTEGRITY_CHANNEL_CAPACITY);
+
+    tokio::spawn(async move {
+        let mut verifier = ChainVerifier::new();
+        let progress_tx = tx.clone();
+        let outcome = verify_audit_chain(&state.pool, &mut verifier, |verified_entries| {
+            let progress_tx = progress_tx.clone();
+            async move {
+                let payload = IntegrityProgressEvent { verified_entries };
+                let data = serde_json::to_string(&payload).unwrap_or_else(|_| "{}".into());
+                let _ = progress_tx
+                    .send(Event::default().event("progress").data(data))
+                    .await;
+            }
+        })
+        .await;
+
+        let event = match outcome {
+            Ok(()) => {
+                let result = IntegrityCheckResult::from(verifier.finish());
+                let data = serde_json::to_string(&result).unwrap_or_else(|_| "{}".into());
+                Event::default().event("done").data(data)
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Audit log integrity check failed");
+                let data = serde_json::json!({ "message": "Integrity check failed" });
+                Event::default().event("error").data(data.to_string())
+            }
+        };
+        let _ = tx.send(event).await;
+    });
 
-    Ok(Json(DataResponse { data: result }))
+    let stream = ReceiverStream::new(rx).map(Ok::<Event, Infallible>);
+    Sse::new(stream).keep_alive(KeepAlive::default())
 }
 
 // ---------------------------------------------------------------------------
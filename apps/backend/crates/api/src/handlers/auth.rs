@@ -10,7 +10,7 @@ use x121_core::types::DbId;
 use x121_db::repositories::{RoleRepo, SessionRepo, UserRepo};
 
 use crate::auth::jwt::{generate_access_token, generate_refresh_token, hash_refresh_token};
-use crate::auth::password::verify_password;
+use crate::auth::password::{hash_password_with, needs_rehash, verify_password};
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
@@ -116,16 +116,42 @@ pub async fn login(
     // 6. On success: reset failed count, set last_login_at.
     UserRepo::record_successful_login(&state.pool, user.id).await?;
 
-    // 7. Resolve role name for JWT claims.
+    // 7. Upgrade the stored hash if it predates the current Argon2 params.
+    if needs_rehash(&user.password_hash, &state.config.password_hash) {
+        rehash_password(&state, user.id, &user.password_hash, &input.password).await;
+    }
+
+    // 8. Resolve role name for JWT claims.
     let role_name = RoleRepo::resolve_name(&state.pool, user.role_id).await?;
 
-    // 8. Generate tokens and create session.
+    // 9. Generate tokens and create session.
     let response =
         create_auth_response(&state, user.id, &user.username, &user.email, &role_name).await?;
 
     Ok(Json(DataResponse { data: response }))
 }
 
+/// Re-hash a just-verified password with the configured parameters.
+///
+/// The new hash replaces `verified_hash` only if it is still stored, so a
+/// password reset that lands between verification and re-hash is not
+/// overwritten. Failures are logged rather than returned: the old hash
+/// still verifies, so the upgrade is simply retried on the next login.
+async fn rehash_password(state: &AppState, user_id: DbId, verified_hash: &str, password: &str) {
+    let hashed = match hash_password_with(password, &state.config.password_hash) {
+        Ok(hashed) => hashed,
+        Err(e) => {
+            tracing::warn!(user_id, error = %e, "Failed to re-hash password");
+            return;
+        }
+    };
+    match UserRepo::replace_password_hash(&state.pool, user_id, verified_hash, &hashed).await {
+        Ok(true) => {}
+        Ok(false) => tracing::debug!(user_id, "Password changed before re-hash, skipping"),
+        Err(e) => tracing::warn!(user_id, error = %e, "Failed to store re-hashed password"),
+    }
+}
+
 /// POST /api/v1/auth/refresh
 ///
 /// Exchange a valid refresh token for new access + refresh tokens.
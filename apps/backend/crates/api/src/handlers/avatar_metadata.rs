@@ -6,23 +6,25 @@
 //! Metadata is stored in the `avatars.metadata` JSONB column.
 //! Field definitions come from the metadata template system (PRD-113).
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use x121_core::completeness_trend::{completeness_trend, CompletenessSnapshot, TrendGranularity};
 use x121_core::error::CoreError;
 use x121_core::metadata_editor::{
-    build_csv, calculate_completeness, calculate_project_completeness, parse_csv,
-    standard_field_defs, unflatten_metadata, validate_metadata_fields, CompletenessResult,
-    CsvDiffEntry, FieldCategory, FieldType, MetadataFieldDef, MetadataFieldError,
+    build_csv, calculate_completeness, calculate_project_completeness, completeness_delta,
+    parse_csv, standard_field_defs, unflatten_metadata, validate_metadata_fields,
+    CompletenessDelta, CompletenessResult, CsvDiffEntry, FieldCategory, FieldType,
+    MetadataFieldDef, MetadataFieldError,
 };
-use x121_core::types::DbId;
+use x121_core::types::{DbId, Timestamp};
 use x121_db::models::avatar::Avatar;
 use x121_db::models::metadata_template::MetadataTemplateField;
 use x121_db::repositories::{
-    AvatarMetadataVersionRepo, AvatarRepo, MetadataTemplateFieldRepo, MetadataTemplateRepo,
-    ProjectRepo,
+    AvatarMetadataVersionRepo, AvatarRepo, CompletenessSnapshotRepo, MetadataTemplateFieldRepo,
+    MetadataTemplateRepo, ProjectRepo,
 };
 
 use crate::error::{AppError, AppResult};
@@ -57,6 +59,17 @@ pub struct MetadataUpdateResult {
     pub status: String,
     pub avatar_id: DbId,
     pub metadata: serde_json::Value,
+    /// Present when the update cleared previously filled required fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completeness_warning: Option<CompletenessWarning>,
+}
+
+/// Warning attached to an update that reduced required-field completeness.
+#[derive(Debug, Serialize)]
+pub struct CompletenessWarning {
+    pub message: String,
+    #[serde(flatten)]
+    pub delta: CompletenessDelta,
 }
 
 /// Result of a validation failure.
@@ -84,6 +97,13 @@ pub struct CsvRecordError {
     pub errors: Vec<MetadataFieldError>,
 }
 
+/// Query parameters for the completeness trend endpoint.
+#[derive(Debug, Deserialize)]
+pub struct CompletenessTrendQuery {
+    pub granularity: Option<String>,
+    pub since: Option<Timestamp>,
+}
+
 /// Response for the active template endpoint.
 #[derive(Debug, Serialize)]
 pub struct ActiveTemplateResponse {
@@ -136,7 +156,7 @@ fn category_from_sort_order(sort_order: i32) -> FieldCategory {
 /// Tries to find the default template for the given project, falling back
 /// to the global default, and finally to `standard_field_defs()` if no
 /// template exists in the database.
-async fn load_template_fields(
+pub(crate) async fn load_template_fields(
     pool: &sqlx::PgPool,
     project_id: Option<DbId>,
 ) -> Result<Vec<MetadataFieldDef>, sqlx::Error> {
@@ -169,6 +189,8 @@ async fn load_template_fields(
             category: category_from_sort_order(f.sort_order),
             is_required: f.is_required,
             options: vec![],
+            min: f.constraints.get("min").and_then(|v| v.as_f64()),
+            max: f.constraints.get("max").and_then(|v| v.as_f64()),
         })
         .collect();
 
@@ -215,6 +237,8 @@ fn build_metadata_response(avatar: &Avatar, fields: &[MetadataFieldDef]) -> Avat
                 category: FieldCategory::Preferences,
                 is_required: false,
                 options: vec![],
+                min: None,
+                max: None,
             },
             value: value.clone(),
         });
@@ -325,6 +349,7 @@ pub async fn update_avatar_metadata(
     // Unflatten dot-notation keys to nested JSON, then merge into existing.
     let unflattened = unflatten_metadata(&updates);
     let mut existing = avatar_metadata_map(&avatar);
+    let previous = existing.clone();
     for (key, value) in &unflattened {
         if let (Some(existing_obj), serde_json::Value::Object(new_obj)) =
             (existing.get(key).and_then(|v| v.as_object()), &value)
@@ -374,6 +399,24 @@ pub async fn update_avatar_metadata(
         id: avatar_id,
     }))?;
 
+    // Record a completeness snapshot for the project trend. Best-effort: a
+    // failure here must not fail the metadata write that already succeeded.
+    if let Some(map) = new_metadata.as_object() {
+        let completeness = calculate_completeness(avatar_id, map, &fields);
+        if let Err(e) = CompletenessSnapshotRepo::record_if_changed(
+            &state.pool,
+            avatar_id,
+            updated.project_id,
+            completeness.filled as i32,
+            completeness.total_required as i32,
+            completeness.percentage,
+        )
+        .await
+        {
+            tracing::warn!(avatar_id, error = %e, "Failed to record completeness snapshot");
+        }
+    }
+
     // Create a metadata version only if real metadata fields changed (dedup).
     // Source file uploads (_source_bio, _source_tov) are stored on the avatar
     // but do NOT create metadata versions — they are source data, not metadata.
@@ -430,10 +473,23 @@ pub async fn update_avatar_metadata(
         let _ = AvatarMetadataVersionRepo::create_as_active(&state.pool, &version_input).await;
     }
 
+    let completeness_warning = new_metadata
+        .as_object()
+        .map(|map| completeness_delta(&previous, map, &fields))
+        .filter(CompletenessDelta::regressed)
+        .map(|delta| CompletenessWarning {
+            message: format!(
+                "Update cleared required field(s): {}",
+                delta.newly_missing.join(", ")
+            ),
+            delta,
+        });
+
     let result = MetadataUpdateResult {
         status: "updated".to_string(),
         avatar_id: updated.id,
         metadata: new_metadata,
+        completeness_warning,
     };
 
     Ok(Json(DataResponse { data: result }).into_response())
@@ -498,6 +554,41 @@ pub async fn get_project_completeness(
     Ok(Json(DataResponse { data: result }))
 }
 
+/// GET /api/v1/projects/{id}/completeness-trend
+///
+/// Return the project's metadata completeness over time, downsampled to one
+/// point per `granularity` bucket (`hour`, `day`, or `week`; default `day`).
+/// Points before `since` are omitted.
+pub async fn get_completeness_trend(
+    State(state): State<AppState>,
+    Path(project_id): Path<DbId>,
+    Query(params): Query<CompletenessTrendQuery>,
+) -> AppResult<impl IntoResponse> {
+    let granularity = TrendGranularity::parse(params.granularity.as_deref().unwrap_or("day"))?;
+
+    ProjectRepo::find_by_id(&state.pool, project_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Project",
+            id: project_id,
+        }))?;
+
+    let snapshots: Vec<CompletenessSnapshot> =
+        CompletenessSnapshotRepo::list_by_project(&state.pool, project_id)
+            .await?
+            .iter()
+            .map(|s| s.to_snapshot())
+            .collect();
+
+    let mut points = completeness_trend(&snapshots, granularity);
+    if let Some(since) = params.since {
+        let since_bucket = granularity.bucket_start(since);
+        points.retain(|p| p.bucket_start >= since_bucket);
+    }
+
+    Ok(Json(DataResponse { data: points }))
+}
+
 /// GET /api/v1/projects/{project_id}/avatars/metadata/csv
 ///
 /// Export all avatar metadata as CSV.
@@ -2,18 +2,20 @@
 //!
 //! Provides endpoints for creating, listing, comparing, promoting, and
 //! deleting branches used for concurrent creative exploration of scenes.
+//! Promotion is preceded by a divergence check against the current default.
+//! Stale branches are reported with the reasons they were judged stale.
 
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use x121_core::branching;
 use x121_core::error::CoreError;
-use x121_core::types::DbId;
-use x121_db::models::branch::{BranchWithStats, CreateBranch, UpdateBranch};
+use x121_core::types::{DbId, Timestamp};
+use x121_db::models::branch::{Branch, BranchWithStats, CreateBranch, UpdateBranch};
 use x121_db::repositories::BranchRepo;
 
 use crate::error::{AppError, AppResult};
@@ -26,9 +28,44 @@ use crate::state::AppState;
 // ---------------------------------------------------------------------------
 
 /// Query parameters for the stale-branches endpoint.
+///
+/// Each threshold falls back to the corresponding `branching::DEFAULT_STALE_*`
+/// constant. `abandoned_after_days=0` disables the abandonment rule.
 #[derive(Debug, Deserialize)]
 pub struct StaleParams {
     pub older_than_days: Option<i32>,
+    pub min_parent_changes: Option<usize>,
+    pub abandoned_after_days: Option<i64>,
+}
+
+impl StaleParams {
+    fn to_config(&self) -> AppResult<branching::StaleBranchConfig> {
+        let inactive_days = self
+            .older_than_days
+            .map_or(branching::DEFAULT_STALE_INACTIVE_DAYS, i64::from);
+        if inactive_days < 0 {
+            return Err(AppError::BadRequest(
+                "older_than_days must not be negative".to_string(),
+            ));
+        }
+        let abandoned_days = match self.abandoned_after_days {
+            None => Some(branching::DEFAULT_STALE_ABANDONED_DAYS),
+            Some(0) => None,
+            Some(days) if days < 0 => {
+                return Err(AppError::BadRequest(
+                    "abandoned_after_days must not be negative".to_string(),
+                ))
+            }
+            Some(days) => Some(days),
+        };
+        Ok(branching::StaleBranchConfig {
+            inactive_days,
+            min_parent_changes: self
+                .min_parent_changes
+                .unwrap_or(branching::DEFAULT_STALE_MIN_PARENT_CHANGES),
+            abandoned_days,
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -48,6 +85,42 @@ async fn ensure_branch_exists(
     })
 }
 
+/// Load the segment states of a branch for divergence checks.
+async fn load_segment_states(
+    pool: &sqlx::PgPool,
+    branch_id: DbId,
+) -> AppResult<Vec<branching::SegmentState>> {
+    let rows = BranchRepo::list_segment_states(pool, branch_id).await?;
+    Ok(rows
+        .into_iter()
+        .map(|(sequence_index, updated_at)| branching::SegmentState {
+            sequence_index,
+            updated_at,
+        })
+        .collect())
+}
+
+/// Compare a branch against its scene's current default.
+///
+/// Returns a fast-forward result with no conflicts when the scene has no
+/// default branch.
+async fn check_promotion(
+    pool: &sqlx::PgPool,
+    branch: &x121_db::models::branch::Branch,
+) -> AppResult<branching::PromotionCheck> {
+    let default_segments = match BranchRepo::get_default(pool, branch.scene_id).await? {
+        Some(default) => load_segment_states(pool, default.id).await?,
+        None => Vec::new(),
+    };
+    let branch_segments = load_segment_states(pool, branch.id).await?;
+
+    Ok(branching::check_promotion_conflicts(
+        branch.created_at,
+        &default_segments,
+        &branch_segments,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // GET /scenes/:scene_id/branches
 // ---------------------------------------------------------------------------
@@ -199,7 +272,9 @@ pub async fn delete_branch(
 
 /// Promote a branch to the scene's default.
 ///
-/// Uses a transactional promote in the repository.
+/// Rejects the promotion with 409 when the current default has diverged
+/// from the branch since it was forked. Uses a transactional promote in the
+/// repository.
 pub async fn promote_branch(
     State(state): State<AppState>,
     auth: AuthUser,
@@ -213,6 +288,20 @@ pub async fn promote_branch(
         )));
     }
 
+    let check = check_promotion(&state.pool, &branch).await?;
+    if !check.can_promote() {
+        let indices: Vec<String> = check
+            .conflicts
+            .iter()
+            .map(|c| c.sequence_index.to_string())
+            .collect();
+        return Err(AppError::Core(CoreError::Conflict(format!(
+            "Default branch has diverged since this branch was forked; \
+             resolve conflicting segments before promoting: {}",
+            indices.join(", ")
+        ))));
+    }
+
     BranchRepo::promote(&state.pool, id, branch.scene_id).await?;
 
     tracing::info!(
@@ -227,6 +316,30 @@ pub async fn promote_branch(
     Ok(Json(DataResponse { data: updated }))
 }
 
+// ---------------------------------------------------------------------------
+// GET /branches/:id/promote-check
+// ---------------------------------------------------------------------------
+
+/// Check whether a branch can be promoted without conflicts.
+///
+/// Reports the segment slots changed on each side since the fork and any
+/// slots changed on both.
+pub async fn promote_check(
+    State(state): State<AppState>,
+    Path(id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    let branch = ensure_branch_exists(&state.pool, id).await?;
+    let check = check_promotion(&state.pool, &branch).await?;
+
+    tracing::debug!(
+        branch_id = id,
+        conflicts = check.conflicts.len(),
+        "Checked branch promotion"
+    );
+
+    Ok(Json(DataResponse { data: check }))
+}
+
 // ---------------------------------------------------------------------------
 // GET /branches/:id/compare/:other_id
 // ---------------------------------------------------------------------------
@@ -279,20 +392,83 @@ pub async fn compare_branches(
 // GET /branches/stale
 // ---------------------------------------------------------------------------
 
-/// List stale branches (not updated in N days, not default).
+/// A stale branch together with why it was judged stale.
+#[derive(Debug, Serialize)]
+pub struct StaleBranch {
+    #[serde(flatten)]
+    pub branch: Branch,
+    pub last_activity_at: Timestamp,
+    pub reasons: Vec<branching::StaleReason>,
+}
+
+/// Gather the activity facts staleness is judged on for one branch.
+///
+/// Activity is the later of the branch row and its segments. The parent is
+/// the branch it was forked from, or the scene's default branch for
+/// top-level branches.
+async fn load_branch_activity(
+    pool: &sqlx::PgPool,
+    branch: &Branch,
+) -> AppResult<branching::BranchActivity> {
+    let last_activity_at = load_segment_states(pool, branch.id)
+        .await?
+        .into_iter()
+        .map(|s| s.updated_at)
+        .fold(branch.updated_at, Timestamp::max);
+
+    let parent_id = match branch.parent_branch_id {
+        Some(id) => Some(id),
+        None => BranchRepo::get_default(pool, branch.scene_id)
+            .await?
+            .map(|d| d.id),
+    };
+    let parent_changed_segments = match parent_id {
+        Some(id) => {
+            let parent_segments = load_segment_states(pool, id).await?;
+            branching::count_changed_since(branch.created_at, &parent_segments)
+        }
+        None => 0,
+    };
+
+    Ok(branching::BranchActivity {
+        last_activity_at,
+        parent_changed_segments,
+    })
+}
+
+/// List stale non-default branches with the reasons each is stale.
+///
+/// Branches idle for `older_than_days` are candidates; each is then judged
+/// by [`branching::stale_reasons`] against its parent's progress.
 pub async fn list_stale(
     State(state): State<AppState>,
     Query(params): Query<StaleParams>,
 ) -> AppResult<impl IntoResponse> {
-    let older_than_days = params.older_than_days.unwrap_or(30);
-
-    let branches = BranchRepo::list_stale_branches(&state.pool, older_than_days).await?;
+    let config = params.to_config()?;
+    let now = chrono::Utc::now();
+
+    let candidates =
+        BranchRepo::list_stale_branches(&state.pool, config.inactive_days as i32).await?;
+
+    let mut stale = Vec::new();
+    for branch in candidates {
+        let activity = load_branch_activity(&state.pool, &branch).await?;
+        let reasons = branching::stale_reasons(&activity, now, &config);
+        if !reasons.is_empty() {
+            stale.push(StaleBranch {
+                branch,
+                last_activity_at: activity.last_activity_at,
+                reasons,
+            });
+        }
+    }
 
     tracing::debug!(
-        count = branches.len(),
-        older_than_days,
+        count = stale.len(),
+        inactive_days = config.inactive_days,
+        min_parent_changes = config.min_parent_changes,
         "Listed stale branches"
     );
 
-    Ok(Json(DataResponse { data: branches }))
+    Ok(Json(DataResponse { data: stale }))
 }
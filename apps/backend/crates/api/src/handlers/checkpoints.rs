@@ -8,9 +8,14 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
 use x121_core::error::CoreError;
+use x121_core::failure_diagnosis::{self, Diagnosis, KnownFix};
+use x121_core::failure_tracking::FailureSignature;
 use x121_core::types::DbId;
+use x121_db::models::job::Job;
 use x121_db::models::status::JobStatus;
-use x121_db::repositories::{CheckpointRepo, JobRepo};
+use x121_db::repositories::{
+    CheckpointRepo, FailurePatternRepo, FailureSignatureRepo, JobRepo, PatternFixRepo,
+};
 
 use crate::error::{AppError, AppResult};
 use crate::handlers::jobs::find_and_authorize;
@@ -155,11 +160,15 @@ struct JobDiagnosticsResponse {
     failure_diagnostics: Option<serde_json::Value>,
     last_checkpoint_id: Option<DbId>,
     original_job_id: Option<DbId>,
+    /// Classified cause and suggested fix; `None` if the job has no error.
+    diagnosis: Option<Diagnosis>,
 }
 
 /// GET /api/v1/jobs/{id}/diagnostics
 ///
-/// Get structured failure diagnostics for a job.
+/// Get structured failure diagnostics for a job, classified with
+/// [`failure_diagnosis::classify_failure`] and linked to any fixes recorded
+/// for the matched failure pattern (PRD-64).
 pub async fn get_failure_diagnostics(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -167,6 +176,7 @@ pub async fn get_failure_diagnostics(
 ) -> AppResult<impl IntoResponse> {
     let job = find_and_authorize(&state.pool, job_id, &auth, "view diagnostics for").await?;
 
+    let diagnosis = diagnose_job(&state, &job).await?;
     let diagnostics = JobDiagnosticsResponse {
         job_id: job.id,
         failure_stage_index: job.failure_stage_index,
@@ -174,7 +184,61 @@ pub async fn get_failure_diagnostics(
         failure_diagnostics: job.failure_diagnostics.clone(),
         last_checkpoint_id: job.last_checkpoint_id,
         original_job_id: job.original_job_id,
+        diagnosis,
     };
 
     Ok(Json(DataResponse { data: diagnostics }))
 }
+
+/// Classify a job's failure from its error message and logged details.
+///
+/// The ComfyUI error captured in `failure_diagnostics` and the raw
+/// `error_details` serve as the logs.
+async fn diagnose_job(state: &AppState, job: &Job) -> AppResult<Option<Diagnosis>> {
+    let stage_error = job
+        .failure_diagnostics
+        .as_ref()
+        .and_then(|d| d.get("error_message"))
+        .and_then(|v| v.as_str());
+    let Some(error) = job.error_message.as_deref().or(stage_error) else {
+        return Ok(None);
+    };
+
+    let mut logs = Vec::new();
+    if let Some(comfyui_error) = job
+        .failure_diagnostics
+        .as_ref()
+        .and_then(|d| d.get("comfyui_error"))
+        .and_then(|v| v.as_str())
+    {
+        logs.push(comfyui_error.to_string());
+    }
+    if let Some(details) = &job.error_details {
+        logs.push(details.to_string());
+    }
+
+    let signatures: Vec<FailureSignature> = FailureSignatureRepo::list_enabled(&state.pool)
+        .await?
+        .iter()
+        .filter_map(|row| row.to_signature().ok())
+        .collect();
+    let mut diagnosis = failure_diagnosis::classify_failure(&logs.join("\n"), error, &signatures);
+
+    if let Some(key) = &diagnosis.pattern_key {
+        if let Some(pattern) = FailurePatternRepo::find_by_key(&state.pool, key).await? {
+            let fixes = PatternFixRepo::list_by_pattern(&state.pool, pattern.id)
+                .await?
+                .into_iter()
+                .map(|fix| KnownFix {
+                    fix_id: fix.id,
+                    description: fix.fix_description,
+                    parameters: fix.fix_parameters,
+                    effectiveness: fix.effectiveness,
+                })
+                .collect();
+            diagnosis.attach_known_fixes(fixes);
+        }
+    }
+
+    Ok(Some(diagnosis))
+}
@@ -10,12 +10,14 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
 
+use x121_core::audit::action_types;
 use x121_core::config_export::{
     self, ConfigExport, ConfigValidationResult, ALL_SECTIONS, REDACTED_VALUE, SECTION_ROLES,
     SECTION_SCENE_TYPES, SECTION_SCHEDULING_POLICIES, SECTION_THEMES, SECTION_VALIDATION_RULES,
 };
+use x121_db::models::audit::CreateAuditLog;
 use x121_db::repositories::{
-    RoleRepo, SceneTypeRepo, SchedulingPolicyRepo, ThemeRepo, ValidationRuleRepo,
+    AuditLogRepo, RoleRepo, SceneTypeRepo, SchedulingPolicyRepo, ThemeRepo, ValidationRuleRepo,
 };
 
 use crate::error::{AppError, AppResult};
@@ -37,53 +39,66 @@ pub struct ImportConfigRequest {
 }
 
 // ---------------------------------------------------------------------------
-// POST /admin/config/export
+// Helpers
 // ---------------------------------------------------------------------------
 
-/// Export the current platform configuration as a JSON snapshot.
-///
-/// Sensitive values (passwords, API keys) are redacted in the export.
-pub async fn export_config(
-    RequireAdmin(admin): RequireAdmin,
-    State(state): State<AppState>,
-) -> AppResult<impl IntoResponse> {
+/// Load the live configuration sections, unredacted.
+async fn collect_current_sections(
+    pool: &sqlx::PgPool,
+) -> AppResult<HashMap<String, serde_json::Value>> {
     let mut sections: HashMap<String, serde_json::Value> = HashMap::new();
 
     // Themes (custom themes)
-    let themes = ThemeRepo::list_custom_themes(&state.pool).await?;
+    let themes = ThemeRepo::list_custom_themes(pool).await?;
     sections.insert(
         SECTION_THEMES.to_string(),
         serde_json::to_value(&themes).unwrap_or_default(),
     );
 
     // Roles
-    let roles = RoleRepo::list(&state.pool).await?;
+    let roles = RoleRepo::list(pool).await?;
     sections.insert(
         SECTION_ROLES.to_string(),
         serde_json::to_value(&roles).unwrap_or_default(),
     );
 
     // Scene types (studio-level, not project-scoped)
-    let scene_types = SceneTypeRepo::list_studio_level(&state.pool).await?;
+    let scene_types = SceneTypeRepo::list_studio_level(pool).await?;
     sections.insert(
         SECTION_SCENE_TYPES.to_string(),
         serde_json::to_value(&scene_types).unwrap_or_default(),
     );
 
     // Scheduling policies
-    let policies = SchedulingPolicyRepo::list(&state.pool).await?;
+    let policies = SchedulingPolicyRepo::list(pool).await?;
     sections.insert(
         SECTION_SCHEDULING_POLICIES.to_string(),
         serde_json::to_value(&policies).unwrap_or_default(),
     );
 
     // Validation rule types (global listing)
-    let rule_types = ValidationRuleRepo::list_rule_types(&state.pool).await?;
+    let rule_types = ValidationRuleRepo::list_rule_types(pool).await?;
     sections.insert(
         SECTION_VALIDATION_RULES.to_string(),
         serde_json::to_value(&rule_types).unwrap_or_default(),
     );
 
+    Ok(sections)
+}
+
+// ---------------------------------------------------------------------------
+// POST /admin/config/export
+// ---------------------------------------------------------------------------
+
+/// Export the current platform configuration as a JSON snapshot.
+///
+/// Sensitive values (passwords, API keys) are redacted in the export.
+pub async fn export_config(
+    RequireAdmin(admin): RequireAdmin,
+    State(state): State<AppState>,
+) -> AppResult<impl IntoResponse> {
+    let mut sections = collect_current_sections(&state.pool).await?;
+
     // Redact sensitive data in all sections.
     for val in sections.values_mut() {
         config_export::redact_sensitive(val);
@@ -170,9 +185,12 @@ pub async fn validate_config(
 /// This is a simplified MVP implementation that validates the snapshot
 /// structure and reports what would be imported. Full section-by-section
 /// application will be expanded post-MVP.
+///
+/// Every import is recorded in the audit log together with the settings it
+/// changes, as before/after pairs per entry.
 pub async fn import_config(
     RequireAdmin(admin): RequireAdmin,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Json(input): Json<ImportConfigRequest>,
 ) -> AppResult<impl IntoResponse> {
     // Determine which sections to import.
@@ -209,10 +227,39 @@ pub async fn import_config(
         }
     }
 
+    let current = collect_current_sections(&state.pool).await?;
+    let changes = config_export::compute_import_changes(
+        &current,
+        &input.config.sections,
+        &requested_sections,
+    );
+
+    AuditLogRepo::batch_insert(
+        &state.pool,
+        &[CreateAuditLog {
+            user_id: Some(admin.user_id),
+            session_id: None,
+            action_type: action_types::CONFIG_CHANGE.to_string(),
+            entity_type: Some("platform_config".to_string()),
+            entity_id: None,
+            details_json: Some(serde_json::json!({
+                "operation": "import",
+                "source_version": input.config.version,
+                "sections": requested_sections,
+                "changes": changes,
+            })),
+            ip_address: None,
+            user_agent: None,
+            integrity_hash: None,
+        }],
+    )
+    .await?;
+
     tracing::info!(
         user_id = admin.user_id,
         sections = ?requested_sections,
         version = %input.config.version,
+        changes = changes.len(),
         "Configuration import accepted (MVP: validation-only)",
     );
 
@@ -220,6 +267,7 @@ pub async fn import_config(
     let summary = serde_json::json!({
         "imported_sections": requested_sections,
         "source_version": input.config.version,
+        "changes": changes,
         "message": "Configuration validated successfully. Section-by-section application available in future release."
     });
 
@@ -12,7 +12,7 @@ use x121_db::models::dashboard::SaveDashboardConfig;
 use x121_db::models::status::{JobStatus, ProjectStatus, SceneStatus};
 use x121_db::repositories::DashboardRepo;
 
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
 use crate::state::AppState;
@@ -126,6 +126,33 @@ pub struct ActivityFeedQuery {
     pub project_id: Option<DbId>,
 }
 
+// ---------------------------------------------------------------------------
+// Helpers: widget result coalescing
+// ---------------------------------------------------------------------------
+
+/// Serve a widget through the shared [`WidgetCache`]: concurrent requests
+/// with the same `key` run `load` once and briefly reuse its result.
+///
+/// [`WidgetCache`]: crate::widget_cache::WidgetCache
+async fn cached_widget<T, F, Fut>(
+    state: &AppState,
+    key: String,
+    load: F,
+) -> AppResult<serde_json::Value>
+where
+    T: Serialize,
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = AppResult<T>>,
+{
+    state
+        .widget_cache
+        .get_or_compute(key, || async move {
+            let items = load().await?;
+            serde_json::to_value(items).map_err(|e| AppError::InternalError(e.to_string()))
+        })
+        .await
+}
+
 // ---------------------------------------------------------------------------
 // Helpers: Active-task row (raw from DB)
 // ---------------------------------------------------------------------------
@@ -169,15 +196,30 @@ fn job_status_label(status_id: i16) -> &'static str {
 ///
 /// Returns running, pending/queued, and recently completed jobs.
 /// Optionally filtered by pipeline_id (PRD-139).
+///
+/// Concurrent identical requests share one computation via the widget cache.
 pub async fn active_tasks(
     _auth: AuthUser,
     State(state): State<AppState>,
     Query(params): Query<ActiveTasksQuery>,
 ) -> AppResult<impl IntoResponse> {
     let recent_limit = params.recent_completed.unwrap_or(10).min(50);
+    let key = format!("active-tasks:{recent_limit}:{:?}", params.pipeline_id);
+    let data = cached_widget(&state, key, || {
+        load_active_tasks(&state.pool, recent_limit, params.pipeline_id)
+    })
+    .await?;
+    Ok(Json(DataResponse { data }))
+}
 
+/// Run the Active Tasks aggregation.
+async fn load_active_tasks(
+    pool: &sqlx::PgPool,
+    recent_limit: i64,
+    pipeline_id: Option<DbId>,
+) -> AppResult<Vec<ActiveTaskItem>> {
     // Build optional pipeline filter clause.
-    let pipeline_filter = if params.pipeline_id.is_some() {
+    let pipeline_filter = if pipeline_id.is_some() {
         "AND p.pipeline_id = $5"
     } else {
         ""
@@ -229,11 +271,11 @@ pub async fn active_tasks(
         .bind(JobStatus::Completed.id())
         .bind(recent_limit);
 
-    if let Some(pid) = params.pipeline_id {
+    if let Some(pid) = pipeline_id {
         q = q.bind(pid);
     }
 
-    let rows = q.fetch_all(&state.pool).await?;
+    let rows = q.fetch_all(pool).await?;
 
     let items: Vec<ActiveTaskItem> = rows
         .into_iter()
@@ -255,7 +297,7 @@ pub async fn active_tasks(
         })
         .collect();
 
-    Ok(Json(DataResponse { data: items }))
+    Ok(items)
 }
 
 // ---------------------------------------------------------------------------
@@ -276,13 +318,28 @@ struct ProjectProgressRow {
 ///
 /// Returns per-project scene completion tracking.
 /// Optionally filtered by pipeline_id (PRD-139).
+///
+/// Concurrent identical requests share one computation via the widget cache.
 pub async fn project_progress(
     _auth: AuthUser,
     State(state): State<AppState>,
     Query(params): Query<ProjectProgressQuery>,
 ) -> AppResult<impl IntoResponse> {
+    let key = format!("project-progress:{:?}", params.pipeline_id);
+    let data = cached_widget(&state, key, || {
+        load_project_progress(&state.pool, params.pipeline_id)
+    })
+    .await?;
+    Ok(Json(DataResponse { data }))
+}
+
+/// Run the Project Progress aggregation.
+async fn load_project_progress(
+    pool: &sqlx::PgPool,
+    pipeline_id: Option<DbId>,
+) -> AppResult<Vec<ProjectProgressItem>> {
     // Build optional pipeline filter.
-    let pipeline_filter = if params.pipeline_id.is_some() {
+    let pipeline_filter = if pipeline_id.is_some() {
         "AND p.pipeline_id = $4"
     } else {
         ""
@@ -314,11 +371,11 @@ pub async fn project_progress(
         .bind(ProjectStatus::Archived.id())
         .bind(ProjectStatus::Completed.id());
 
-    if let Some(pid) = params.pipeline_id {
+    if let Some(pid) = pipeline_id {
         q = q.bind(pid);
     }
 
-    let rows = q.fetch_all(&state.pool).await?;
+    let rows = q.fetch_all(pool).await?;
 
     // Avatar counts per project (non-archived, non-deleted).
     let char_counts: Vec<(DbId, i64)> = sqlx::query_as(
@@ -326,7 +383,7 @@ pub async fn project_progress(
          WHERE deleted_at IS NULL AND status_id != 3 \
          GROUP BY project_id",
     )
-    .fetch_all(&state.pool)
+    .fetch_all(pool)
     .await?;
     let count_map: std::collections::HashMap<DbId, i64> = char_counts.into_iter().collect();
 
@@ -337,7 +394,7 @@ pub async fn project_progress(
          WHERE crc.state = 'ready' AND c.deleted_at IS NULL AND c.status_id != 3 \
          GROUP BY c.project_id",
     )
-    .fetch_all(&state.pool)
+    .fetch_all(pool)
     .await?;
     let ready_map: std::collections::HashMap<DbId, i64> = ready_counts.into_iter().collect();
 
@@ -375,7 +432,7 @@ pub async fn project_progress(
         })
         .collect();
 
-    Ok(Json(DataResponse { data: items }))
+    Ok(items)
 }
 
 // ---------------------------------------------------------------------------
@@ -466,6 +523,8 @@ const FEED_DEFAULT_LIMIT: i64 = 50;
 /// GET /api/v1/dashboard/widgets/activity-feed
 ///
 /// Returns chronological event stream with optional filtering.
+///
+/// Concurrent identical requests share one computation via the widget cache.
 pub async fn activity_feed(
     _auth: AuthUser,
     State(state): State<AppState>,
@@ -476,7 +535,24 @@ pub async fn activity_feed(
         .unwrap_or(FEED_DEFAULT_LIMIT)
         .min(FEED_MAX_LIMIT);
     let offset = params.offset.unwrap_or(0);
+    let key = format!(
+        "activity-feed:{limit}:{offset}:{:?}:{:?}",
+        params.category, params.project_id
+    );
+    let data = cached_widget(&state, key, || {
+        load_activity_feed(&state.pool, &params, limit, offset)
+    })
+    .await?;
+    Ok(Json(DataResponse { data }))
+}
 
+/// Run the Activity Feed query.
+async fn load_activity_feed(
+    pool: &sqlx::PgPool,
+    params: &ActivityFeedQuery,
+    limit: i64,
+    offset: i64,
+) -> AppResult<Vec<ActivityFeedItem>> {
     // Build dynamic query with optional filters.
     let mut conditions: Vec<String> = Vec::new();
     let mut bind_idx: u32 = 1;
@@ -530,9 +606,7 @@ pub async fn activity_feed(
 
     q = q.bind(limit).bind(offset);
 
-    let items = q.fetch_all(&state.pool).await?;
-
-    Ok(Json(DataResponse { data: items }))
+    Ok(q.fetch_all(pool).await?)
 }
 
 // ---------------------------------------------------------------------------
@@ -74,48 +74,68 @@ fn parse_and_validate_layout(
     Ok(items)
 }
 
+/// Parse a stored layout for resolution.
+///
+/// A malformed layout is logged and treated as absent so the dashboard
+/// falls through to the next priority level instead of failing.
+fn parse_stored_layout(
+    layout_json: &serde_json::Value,
+    level: &'static str,
+) -> Option<Vec<dashboard_customization::LayoutItem>> {
+    serde_json::from_value(layout_json.clone())
+        .map_err(|e| tracing::warn!(level, error = %e, "Ignoring malformed stored layout"))
+        .ok()
+}
+
 // ===========================================================================
 // User: Effective Dashboard
 // ===========================================================================
 
 /// `GET /user/dashboard/effective` -- resolve the effective layout for the user.
 ///
-/// Priority: active preset > saved dashboard config > role default > platform default.
+/// Priority: active preset > role default > platform default. Widgets the
+/// user's role cannot access are dropped from the resolved layout.
 pub async fn get_dashboard(
     auth: AuthUser,
     State(state): State<AppState>,
 ) -> AppResult<impl IntoResponse> {
     // 1. Check for active preset.
     let active_preset = DashboardPresetRepo::get_active(&state.pool, auth.user_id).await?;
-    let preset_layout = active_preset.as_ref().map(|p| &p.layout_json);
+    let preset_layout = active_preset
+        .as_ref()
+        .and_then(|p| parse_stored_layout(&p.layout_json, "preset"));
 
     // 2. Check for role default.
     let role_default = DashboardRoleDefaultRepo::find_by_role(&state.pool, &auth.role).await?;
-    let role_layout = role_default.as_ref().map(|r| &r.layout_json);
+    let role_layout = role_default
+        .as_ref()
+        .and_then(|r| parse_stored_layout(&r.layout_json, "role_default"));
 
-    // 3. Resolve with priority chain (user_config is None here -- separate from presets).
-    let effective = dashboard_customization::resolve_layout_priority(
-        preset_layout,
-        None, // user_config covered by the existing PRD-42 save_dashboard_config
-        role_layout,
+    // 3. Resolve with priority chain, limited to the widgets the role can see.
+    let available = dashboard_customization::available_widgets_for_role(
+        dashboard_customization::get_native_widget_catalogue(),
+        &auth.role,
+    );
+    let layout = dashboard_customization::resolve_dashboard(
+        preset_layout.as_deref(),
+        role_layout.as_deref(),
+        &dashboard_customization::platform_default_layout_items(),
+        &available,
     );
 
-    let widget_settings = active_preset
-        .as_ref()
-        .map(|p| p.widget_settings_json.clone())
-        .unwrap_or_else(|| serde_json::json!({}));
-
-    let source = if active_preset.is_some() {
-        "preset"
-    } else if role_default.is_some() {
-        "role_default"
-    } else {
-        "platform_default"
+    let (source, widget_settings) = match (&active_preset, &role_default) {
+        (Some(preset), _) if preset_layout.is_some() => {
+            ("preset", preset.widget_settings_json.clone())
+        }
+        (_, Some(role)) if role_layout.is_some() => {
+            ("role_default", role.widget_settings_json.clone())
+        }
+        _ => ("platform_default", serde_json::json!({})),
     };
 
     Ok(Json(DataResponse {
         data: EffectiveDashboardResponse {
-            layout: effective,
+            layout,
             widget_settings,
             source,
         },
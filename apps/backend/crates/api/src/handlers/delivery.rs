@@ -3,6 +3,8 @@
 //! Provides endpoints for managing output format profiles, watermark settings,
 //! starting delivery exports, listing exports, and running pre-export validation.
 
+use std::collections::HashMap;
+
 use axum::body::Body;
 use axum::extract::{Path, Query, State};
 use axum::http::{header, StatusCode};
@@ -14,6 +16,7 @@ use x121_core::assembly;
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset};
 use x121_core::types::DbId;
+use x121_db::models::avatar::Avatar;
 use x121_db::models::delivery_export::{
     AssemblyStartedResponse, CreateDeliveryExport, DeliveryExport, DeliveryValidationResponse,
     StartAssemblyRequest, ValidationIssueDto,
@@ -21,12 +24,14 @@ use x121_db::models::delivery_export::{
 use x121_db::models::output_format_profile::{
     CreateOutputFormatProfile, OutputFormatProfile, UpdateOutputFormatProfile,
 };
+use x121_db::models::project::Project;
+use x121_db::models::scene_video_version::SceneVideoVersion;
 use x121_db::models::watermark_setting::{
     CreateWatermarkSetting, UpdateWatermarkSetting, WatermarkSetting,
 };
 use x121_db::repositories::{
     AvatarMetadataVersionRepo, AvatarRepo, DeliveryExportRepo, OutputFormatProfileRepo,
-    PlatformSettingRepo, ProjectDeliveryLogRepo, ProjectRepo, SceneVideoVersionRepo,
+    PlatformSettingRepo, ProjectDeliveryLogRepo, ProjectRepo, SceneRepo, SceneVideoVersionRepo,
     WatermarkSettingRepo,
 };
 
@@ -85,6 +90,96 @@ async fn ensure_export_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Delive
         })
 }
 
+/// Resolve the deliverable sections that block delivery for a project:
+/// project override → studio setting → hardcoded default.
+async fn resolve_blocking_sections(
+    pool: &sqlx::PgPool,
+    project: &Project,
+) -> AppResult<Vec<String>> {
+    if let Some(ref bd) = project.blocking_deliverables {
+        return Ok(bd.clone());
+    }
+    let default_sections = || {
+        vec![
+            "metadata".to_string(),
+            "images".to_string(),
+            "scenes".to_string(),
+        ]
+    };
+    let studio_bd = PlatformSettingRepo::find_by_key(pool, "blocking_deliverables").await?;
+    Ok(match studio_bd {
+        Some(setting) => serde_json::from_str::<Vec<String>>(&setting.value)
+            .unwrap_or_else(|_| default_sections()),
+        None => default_sections(),
+    })
+}
+
+/// Gather the inputs to [`assembly::validate_delivery`] for `avatars`.
+///
+/// Scenes are only loaded when `include_scenes` is set (i.e. "scenes" is a
+/// blocking deliverable).
+async fn load_delivery_state(
+    pool: &sqlx::PgPool,
+    project_id: DbId,
+    avatars: &[Avatar],
+    include_scenes: bool,
+    watermark_requested: bool,
+) -> AppResult<assembly::DeliveryProjectState> {
+    let mut scenes = Vec::new();
+    if include_scenes {
+        let model_names: HashMap<DbId, &str> =
+            avatars.iter().map(|c| (c.id, c.name.as_str())).collect();
+        let all_scenes = SceneRepo::list_by_project(pool, project_id).await?;
+        let mut finals: HashMap<DbId, SceneVideoVersion> =
+            SceneVideoVersionRepo::list_finals_for_project(pool, project_id)
+                .await?
+                .into_iter()
+                .map(|v| (v.scene_id, v))
+                .collect();
+
+        for scene in all_scenes {
+            let Some(model_name) = model_names.get(&scene.avatar_id) else {
+                continue;
+            };
+            scenes.push(assembly::DeliveryScene {
+                scene_id: scene.id,
+                model_name: (*model_name).to_string(),
+                final_version: finals
+                    .remove(&scene.id)
+                    .map(|v| assembly::DeliveryFinalVersion {
+                        version_id: v.id,
+                        version_number: v.version_number,
+                        qa_status: v.qa_status,
+                        video_codec: v.video_codec,
+                    }),
+            });
+        }
+    }
+
+    let watermark_configured = WatermarkSettingRepo::list_all(pool)
+        .await?
+        .iter()
+        .any(|w| !w.content.trim().is_empty());
+
+    Ok(assembly::DeliveryProjectState {
+        scenes,
+        watermark_requested,
+        watermark_configured,
+    })
+}
+
+/// Convert an output format profile row into the validator's input.
+fn delivery_profile(profile: &OutputFormatProfile) -> assembly::DeliveryProfile {
+    assembly::DeliveryProfile {
+        name: profile.name.clone(),
+        resolution: profile.resolution.clone(),
+        codec: profile.codec.clone(),
+        container: profile.container.clone(),
+        pixel_format: profile.pixel_format.clone(),
+        is_passthrough: profile.is_passthrough,
+    }
+}
+
 // ===========================================================================
 // OUTPUT FORMAT PROFILE HANDLERS
 // ===========================================================================
@@ -226,26 +321,60 @@ pub async fn start_assembly(
 ) -> AppResult<impl IntoResponse> {
     // Verify the format profile exists.
     let profile = ensure_profile_exists(&state.pool, body.format_profile_id).await?;
+    let project = ProjectRepo::find_by_id(&state.pool, project_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Project",
+            id: project_id,
+        }))?;
 
-    // Resolve avatar names for the activity log message.
     let all_avatars = AvatarRepo::list_by_project(&state.pool, project_id).await?;
-    let (model_names, model_count) = match &body.avatar_ids {
-        Some(ids) => {
-            let names: Vec<&str> = all_avatars
-                .iter()
-                .filter(|c| ids.contains(&c.id))
-                .map(|c| c.name.as_str())
-                .collect();
-            let count = names.len();
-            (names.join(", "), count)
-        }
-        None => {
-            let names: Vec<&str> = all_avatars.iter().map(|c| c.name.as_str()).collect();
-            let count = names.len();
-            (names.join(", "), count)
-        }
+    let avatars: Vec<Avatar> = match &body.avatar_ids {
+        Some(ids) => all_avatars
+            .into_iter()
+            .filter(|c| ids.contains(&c.id))
+            .collect(),
+        None => all_avatars,
     };
 
+    // Refuse to start while any blocking readiness issue exists.
+    let blocking_sections = resolve_blocking_sections(&state.pool, &project).await?;
+    let delivery_state = load_delivery_state(
+        &state.pool,
+        project_id,
+        &avatars,
+        blocking_sections.iter().any(|s| s == "scenes"),
+        body.include_watermark,
+    )
+    .await?;
+    let blocking: Vec<String> =
+        assembly::validate_delivery(&delivery_state, &delivery_profile(&profile))
+            .into_iter()
+            .filter(assembly::DeliveryIssue::is_blocking)
+            .map(|issue| issue.message)
+            .collect();
+    if !blocking.is_empty() {
+        const SHOWN: usize = 5;
+        let mut summary = blocking
+            .iter()
+            .take(SHOWN)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("; ");
+        if blocking.len() > SHOWN {
+            summary.push_str(&format!("; and {} more", blocking.len() - SHOWN));
+        }
+        return Err(AppError::Core(CoreError::Validation(format!(
+            "Delivery is not ready ({} blocking issue{}): {summary}",
+            blocking.len(),
+            if blocking.len() != 1 { "s" } else { "" },
+        ))));
+    }
+
+    // Resolve avatar names for the activity log message.
+    let names: Vec<&str> = avatars.iter().map(|c| c.name.as_str()).collect();
+    let (model_names, model_count) = (names.join(", "), names.len());
+
     let avatars_json = body
         .avatar_ids
         .as_ref()
@@ -546,13 +675,20 @@ pub async fn download_model_archive(
 pub struct ValidationQueryParams {
     /// Comma-separated avatar IDs to validate. When absent, validates all.
     pub avatar_ids: Option<String>,
+    /// Output profile to check compatibility against. Defaults to the
+    /// system default profile, if any.
+    pub format_profile_id: Option<DbId>,
+    /// Whether the export will include a watermark.
+    pub include_watermark: Option<bool>,
 }
 
 /// Run pre-export validation checks for a project.
 ///
-/// Validates that all scenes in the project have finalized video versions
-/// and that each avatar has at least one scene.
-/// Accepts optional `?avatar_ids=1,2,3` to scope validation to selected models.
+/// Runs [`assembly::validate_delivery`] (final versions, QA gates, watermark
+/// settings, output profile compatibility) plus codec, per-avatar scene, and
+/// metadata checks. Accepts optional `?avatar_ids=1,2,3` to scope validation
+/// to selected models, and `format_profile_id` / `include_watermark` to
+/// mirror the intended export.
 pub async fn validate_delivery(
     State(state): State<AppState>,
     Path(project_id): Path<DbId>,
@@ -575,28 +711,7 @@ pub async fn validate_delivery(
             entity: "Project",
             id: project_id,
         }))?;
-    let blocking_sections: Vec<String> = if let Some(ref bd) = project.blocking_deliverables {
-        bd.clone()
-    } else {
-        // Fall back to studio-level setting, then hardcoded default.
-        let studio_bd =
-            PlatformSettingRepo::find_by_key(&state.pool, "blocking_deliverables").await?;
-        if let Some(setting) = studio_bd {
-            serde_json::from_str::<Vec<String>>(&setting.value).unwrap_or_else(|_| {
-                vec![
-                    "metadata".to_string(),
-                    "images".to_string(),
-                    "scenes".to_string(),
-                ]
-            })
-        } else {
-            vec![
-                "metadata".to_string(),
-                "images".to_string(),
-                "scenes".to_string(),
-            ]
-        }
-    };
+    let blocking_sections = resolve_blocking_sections(&state.pool, &project).await?;
 
     let check_scenes = blocking_sections.iter().any(|s| s == "scenes");
     let check_metadata = blocking_sections.iter().any(|s| s == "metadata");
@@ -647,48 +762,42 @@ pub async fn validate_delivery(
         });
     }
 
+    // Assembly readiness: final versions, QA, watermark, and (when a
+    // profile is known) output profile compatibility.
+    let delivery_state = load_delivery_state(
+        &state.pool,
+        project_id,
+        &avatars,
+        check_scenes,
+        params.include_watermark.unwrap_or(false),
+    )
+    .await?;
+    let profile = match params.format_profile_id {
+        Some(id) => Some(ensure_profile_exists(&state.pool, id).await?),
+        None => OutputFormatProfileRepo::find_default(&state.pool).await?,
+    };
+    let delivery_issues = match &profile {
+        Some(profile) => assembly::validate_delivery(&delivery_state, &delivery_profile(profile)),
+        None => assembly::validate_delivery_content(&delivery_state),
+    };
+    issues.extend(
+        delivery_issues
+            .into_iter()
+            .map(assembly::ValidationIssue::from),
+    );
+
     // Build avatar name map (needed for scene-level checks).
     let char_name_map: std::collections::HashMap<DbId, &str> =
         avatars.iter().map(|c| (c.id, c.name.as_str())).collect();
 
     // --- Scene-related checks (only when "scenes" is a blocking deliverable) ---
     if check_scenes {
-        let all_scenes: Vec<(DbId, DbId)> = sqlx::query_as(
-            "SELECT s.id, s.avatar_id FROM scenes s \
-             JOIN avatars c ON s.avatar_id = c.id \
-             WHERE c.project_id = $1 AND s.deleted_at IS NULL AND c.deleted_at IS NULL",
-        )
-        .bind(project_id)
-        .fetch_all(&state.pool)
-        .await?;
+        let all_scenes = SceneRepo::list_by_project(&state.pool, project_id).await?;
         let scene_to_char: std::collections::HashMap<DbId, &str> = all_scenes
             .iter()
-            .filter_map(|(sid, cid)| char_name_map.get(cid).map(|name| (*sid, *name)))
+            .filter_map(|s| char_name_map.get(&s.avatar_id).map(|name| (s.id, *name)))
             .collect();
 
-        // Missing finalized video versions.
-        let missing_final =
-            SceneVideoVersionRepo::find_scenes_missing_final(&state.pool, project_id)
-                .await
-                .map_err(|e| {
-                    tracing::error!(%e, "validate_delivery: find_scenes_missing_final failed");
-                    e
-                })?;
-        for scene_id in &missing_final {
-            let Some(model_name) = scene_to_char.get(scene_id) else {
-                continue;
-            };
-            issues.push(assembly::ValidationIssue {
-                severity: assembly::IssueSeverity::Error,
-                category: "missing_final_video".to_string(),
-                message: format!(
-                    "Model '{}' — scene {scene_id} has no finalized video version",
-                    model_name
-                ),
-                entity_id: Some(*scene_id),
-            });
-        }
-
         // Non-H.264 codec warnings.
         let all_versions = SceneVideoVersionRepo::list_non_h264_finals(&state.pool, project_id)
             .await
@@ -715,7 +824,7 @@ pub async fn validate_delivery(
         // Avatars with no scenes.
         for avatar in &avatars {
             let scenes =
-                x121_db::repositories::SceneRepo::list_by_avatar(&state.pool, avatar.id).await
+                SceneRepo::list_by_avatar(&state.pool, avatar.id).await
                 .map_err(|e| { tracing::error!(%e, avatar_id = avatar.id, "validate_delivery: list_by_avatar failed"); e })?;
             if scenes.is_empty() {
                 issues.push(assembly::ValidationIssue {
@@ -9,7 +9,8 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
 
-use x121_core::download_manager;
+use x121_core::crypto;
+use x121_core::download_manager::{self, PlacementRuleSpec};
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
@@ -43,6 +44,17 @@ async fn ensure_download_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Mode
         })
 }
 
+/// Load the keyring used to encrypt user API tokens at rest.
+///
+/// `USER_TOKEN_ENCRYPTION_KEYS` holds `id:hex` entries separated by commas;
+/// the first is used for new tokens and the rest stay available to decrypt
+/// tokens stored before a key rotation.
+fn token_keyring() -> AppResult<crypto::TokenKeyring> {
+    let spec = std::env::var("USER_TOKEN_ENCRYPTION_KEYS")
+        .map_err(|_| AppError::InternalError("USER_TOKEN_ENCRYPTION_KEYS not set".into()))?;
+    Ok(crypto::TokenKeyring::parse(&spec)?)
+}
+
 /// Verify that a placement rule exists, returning the full row.
 async fn ensure_rule_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<PlacementRule> {
     PlacementRuleRepo::find_by_id(pool, id)
@@ -107,7 +119,15 @@ pub async fn create_download(
     download_manager::validate_model_type(&model_type)?;
 
     // Resolve target path using placement rules.
-    let target_path = PlacementRuleRepo::resolve_path(&state.pool, &model_type, None).await?;
+    let placement = PlacementRuleRepo::resolve_placement(
+        &state.pool,
+        &download_manager::DownloadFileMeta {
+            model_type: &model_type,
+            base_model: None,
+            file_name: &file_name,
+        },
+    )
+    .await?;
 
     let create_input = CreateModelDownload {
         source_type: source_type.to_string(),
@@ -119,7 +139,7 @@ pub async fn create_download(
         base_model: None,
         file_name,
         file_size_bytes: None,
-        target_path: Some(target_path),
+        target_path: Some(placement.path),
         expected_hash: None,
         source_metadata: None,
         initiated_by: Some(user.user_id),
@@ -298,7 +318,12 @@ pub async fn create_placement_rule(
     State(state): State<AppState>,
     Json(input): Json<CreatePlacementRule>,
 ) -> AppResult<impl IntoResponse> {
-    download_manager::validate_model_type(&input.model_type)?;
+    download_manager::validate_placement_rule(&PlacementRuleSpec {
+        model_type: input.model_type.clone(),
+        base_model: input.base_model.clone(),
+        target_directory: input.target_directory.clone(),
+        priority: input.priority.unwrap_or_default(),
+    })?;
 
     let rule = PlacementRuleRepo::create(&state.pool, &input).await?;
 
@@ -323,11 +348,20 @@ pub async fn update_placement_rule(
     Path(id): Path<DbId>,
     Json(input): Json<UpdatePlacementRule>,
 ) -> AppResult<impl IntoResponse> {
-    ensure_rule_exists(&state.pool, id).await?;
+    let existing = ensure_rule_exists(&state.pool, id).await?;
 
+    // Validate the rule as it will be after the update.
+    let mut updated = existing.to_spec();
     if let Some(ref mt) = input.model_type {
-        download_manager::validate_model_type(mt)?;
+        updated.model_type = mt.clone();
+    }
+    if input.base_model.is_some() {
+        updated.base_model = input.base_model.clone();
+    }
+    if let Some(ref dir) = input.target_directory {
+        updated.target_directory = dir.clone();
     }
+    download_manager::validate_placement_rule(&updated)?;
 
     let rule = PlacementRuleRepo::update(&state.pool, id, &input)
         .await?
@@ -404,9 +438,8 @@ pub async fn store_token(
 
     let hint = download_manager::generate_token_hint(&input.token);
 
-    // In production, this would use proper encryption.
-    // For now, store the token bytes directly (placeholder for encryption layer).
-    let encrypted = input.token.as_bytes().to_vec();
+    let keyring = token_keyring()?;
+    let encrypted = crypto::encrypt_token(&input.token, &keyring)?;
 
     let token = UserApiTokenRepo::upsert(
         &state.pool,
@@ -420,6 +453,7 @@ pub async fn store_token(
     tracing::info!(
         user_id = user.user_id,
         service = %input.service_name,
+        key_id = %encrypted.key_id,
         "API token stored",
     );
 
@@ -3,18 +3,21 @@
 //! Provides single and batch duplicate checking, resolution workflows,
 //! and per-project/studio-level threshold settings management.
 
+use std::collections::HashMap;
+
 use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
 use x121_core::duplicate_detection;
+use x121_core::duplicate_detection::BatchSkipReason;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
 use x121_db::models::duplicate_check::{
-    BatchCheckRequest, CheckDuplicateRequest, CreateDuplicateCheck, DuplicateCheck,
-    ResolveCheckRequest,
+    BatchCheckItem, BatchCheckOutcome, BatchCheckRequest, CheckDuplicateRequest,
+    CreateDuplicateCheck, DuplicateCheck, ResolveCheckRequest,
 };
 use x121_db::models::duplicate_setting::UpdateDuplicateSetting;
-use x121_db::repositories::{DuplicateCheckRepo, DuplicateSettingRepo};
+use x121_db::repositories::{DuplicateCheckRepo, DuplicateSettingRepo, EmbeddingRepo};
 
 use crate::error::AppResult;
 use crate::middleware::auth::AuthUser;
@@ -69,34 +72,99 @@ pub async fn check_duplicate(
 /// POST /api/v1/avatars/duplicates/batch
 ///
 /// Batch-check multiple avatars for cross-duplicates.
-/// Creates a check record for each pair that exceeds the threshold.
+///
+/// Input IDs are deduplicated and capped at `MAX_BATCH_CHECK_SIZE`.
+/// Avatars with a face embedding are compared against each other: every
+/// pair above the similarity threshold gets a `match_found` check record,
+/// and avatars without a match get a `no_match` record. Avatars that are
+/// missing or lack an embedding are reported as skipped with a reason
+/// instead of failing the batch. Returns one result per distinct input ID,
+/// in input order.
 pub async fn batch_check(
     State(state): State<AppState>,
     _auth: AuthUser,
     Json(body): Json<BatchCheckRequest>,
 ) -> AppResult<impl IntoResponse> {
-    duplicate_detection::validate_check_type(duplicate_detection::CHECK_TYPE_BATCH)?;
+    let avatar_ids = duplicate_detection::prepare_batch_ids(&body.avatar_ids)?;
 
     let settings = DuplicateSettingRepo::get_for_project(&state.pool, body.project_id).await?;
 
-    // In a production system we'd load embeddings from the DB and run
-    // `find_cross_matches`. For the API layer, create a no_match record per avatar.
-    let mut checks: Vec<DuplicateCheck> = Vec::new();
+    let stored: HashMap<DbId, Option<String>> =
+        EmbeddingRepo::list_avatar_embeddings(&state.pool, &avatar_ids)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut embeddings: Vec<(DbId, Vec<f32>)> = Vec::new();
+    let mut skipped: HashMap<DbId, BatchSkipReason> = HashMap::new();
+    for &avatar_id in &avatar_ids {
+        match stored.get(&avatar_id) {
+            None => {
+                skipped.insert(avatar_id, BatchSkipReason::NotFound);
+            }
+            Some(text) => match text
+                .as_deref()
+                .and_then(duplicate_detection::parse_vector_literal)
+            {
+                Some(embedding) => embeddings.push((avatar_id, embedding)),
+                None => {
+                    skipped.insert(avatar_id, BatchSkipReason::NoEmbedding);
+                }
+            },
+        }
+    }
 
-    for &char_id in &body.avatar_ids {
+    let threshold = settings.similarity_threshold;
+    let matches = duplicate_detection::find_cross_matches(&embeddings, threshold);
+
+    let mut checks: HashMap<DbId, Vec<DuplicateCheck>> = HashMap::new();
+    for m in &matches {
         let create = CreateDuplicateCheck {
-            source_avatar_id: char_id,
+            source_avatar_id: m.avatar_a_id,
+            matched_avatar_id: Some(m.avatar_b_id),
+            similarity_score: Some(m.similarity_score),
+            threshold_used: threshold,
+            check_type: duplicate_detection::CHECK_TYPE_BATCH.to_string(),
+            status_id: Some(duplicate_detection::STATUS_MATCH_FOUND_ID),
+        };
+        let check = DuplicateCheckRepo::create(&state.pool, &create).await?;
+        checks.entry(m.avatar_b_id).or_default().push(check.clone());
+        checks.entry(m.avatar_a_id).or_default().push(check);
+    }
+
+    for (avatar_id, _) in &embeddings {
+        if checks.contains_key(avatar_id) {
+            continue;
+        }
+        let create = CreateDuplicateCheck {
+            source_avatar_id: *avatar_id,
             matched_avatar_id: None,
             similarity_score: None,
-            threshold_used: settings.similarity_threshold,
+            threshold_used: threshold,
             check_type: duplicate_detection::CHECK_TYPE_BATCH.to_string(),
             status_id: Some(duplicate_detection::STATUS_NO_MATCH_ID),
         };
         let check = DuplicateCheckRepo::create(&state.pool, &create).await?;
-        checks.push(check);
+        checks.insert(*avatar_id, vec![check]);
     }
 
-    Ok(Json(DataResponse { data: checks }))
+    let items: Vec<BatchCheckItem> = avatar_ids
+        .into_iter()
+        .map(|avatar_id| {
+            let outcome = match skipped.get(&avatar_id) {
+                Some(&reason) => BatchCheckOutcome::Skipped {
+                    reason,
+                    message: reason.message(),
+                },
+                None => BatchCheckOutcome::Checked {
+                    checks: checks.remove(&avatar_id).unwrap_or_default(),
+                },
+            };
+            BatchCheckItem { avatar_id, outcome }
+        })
+        .collect();
+
+    Ok(Json(DataResponse { data: items }))
 }
 
 /// GET /api/v1/avatars/duplicates/history
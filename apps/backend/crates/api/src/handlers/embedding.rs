@@ -6,19 +6,27 @@
 //! - GET    /avatars/{avatar_id}/detected-faces
 //! - POST   /avatars/{avatar_id}/select-face
 //! - GET    /avatars/{avatar_id}/embedding-history
+//! - POST   /avatars/embedding/batch-extract
+//! - GET    /avatars/embedding/batch-extract/{batch_id}
+//! - POST   /avatars/embedding/batch-extract/{batch_id}/cancel
 
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 
-use x121_core::embedding::EmbeddingStatus;
-use x121_core::types::DbId;
+use serde::Deserialize;
+use x121_core::embedding::{EmbeddingStatus, DEFAULT_BATCH_EXTRACTION_CONCURRENCY};
+use x121_core::error::CoreError;
+use x121_core::types::{DbId, RunId};
 use x121_db::models::embedding::{ExtractEmbeddingRequest, SelectFaceRequest};
 use x121_db::repositories::EmbeddingRepo;
+use x121_db::DbPool;
 
-use crate::error::AppResult;
+use crate::engine::embedding_batch::{self, BatchExtractionReport, ExtractionOutcome};
+use crate::error::{AppError, AppResult};
 use crate::handlers::consistency_report::ensure_avatar_exists;
+use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
 use crate::state::AppState;
 
@@ -127,3 +135,136 @@ pub async fn get_embedding_history(
 
     Ok(Json(DataResponse { data: history }))
 }
+
+/// Body for the batch-extract endpoint.
+#[derive(Debug, Deserialize)]
+pub struct BatchExtractRequest {
+    pub avatar_ids: Vec<DbId>,
+    /// Extractions run at once (default: 4, max: 16).
+    pub concurrency: Option<usize>,
+}
+
+/// POST /api/v1/avatars/embedding/batch-extract
+///
+/// Start face embedding extraction for a set of avatars in the background.
+/// Avatars whose extraction is already in progress or completed are
+/// skipped. Returns the batch report immediately; poll it via
+/// `GET /avatars/embedding/batch-extract/{batch_id}`.
+pub async fn batch_extract(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(body): Json<BatchExtractRequest>,
+) -> AppResult<impl IntoResponse> {
+    let concurrency = body
+        .concurrency
+        .unwrap_or(DEFAULT_BATCH_EXTRACTION_CONCURRENCY);
+    let avatar_ids = x121_core::embedding::prepare_batch_extraction(&body.avatar_ids, concurrency)?;
+
+    let handle = state
+        .embedding_batches
+        .register(BatchExtractionReport::new(avatar_ids.len()));
+    let report = handle.report();
+
+    tracing::info!(
+        user_id = auth.user_id,
+        batch_id = %report.batch_id,
+        total = report.total,
+        concurrency,
+        "Batch embedding extraction started"
+    );
+
+    let pool = state.pool.clone();
+    tokio::spawn(async move {
+        let report = embedding_batch::run_batch(handle, avatar_ids, concurrency, |avatar_id| {
+            start_extraction(pool.clone(), avatar_id)
+        })
+        .await;
+        tracing::info!(
+            batch_id = %report.batch_id,
+            done = report.done,
+            total = report.total,
+            cancelled = report.cancelled,
+            "Batch embedding extraction finished"
+        );
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(DataResponse { data: report })))
+}
+
+/// GET /api/v1/avatars/embedding/batch-extract/{batch_id}
+///
+/// Return the progress report of a batch extraction.
+pub async fn get_batch_extraction(
+    _auth: AuthUser,
+    State(state): State<AppState>,
+    Path(batch_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .embedding_batches
+        .get(batch_id)
+        .ok_or_else(|| batch_not_found(batch_id))?;
+
+    Ok(Json(DataResponse { data: report }))
+}
+
+/// POST /api/v1/avatars/embedding/batch-extract/{batch_id}/cancel
+///
+/// Stop a batch extraction from starting further avatars. Extractions
+/// already in flight finish normally.
+pub async fn cancel_batch_extraction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(batch_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .embedding_batches
+        .cancel(batch_id)
+        .ok_or_else(|| batch_not_found(batch_id))?;
+
+    tracing::info!(
+        user_id = auth.user_id,
+        batch_id = %batch_id,
+        done = report.done,
+        total = report.total,
+        "Batch embedding extraction cancelled"
+    );
+
+    Ok(Json(DataResponse { data: report }))
+}
+
+/// Claim an avatar for extraction and reset its previous results.
+///
+/// Avatars that are already extracting or have a current embedding are
+/// skipped, which also keeps overlapping batches from extracting twice.
+async fn start_extraction(pool: DbPool, avatar_id: DbId) -> Result<ExtractionOutcome, String> {
+    let claimed = EmbeddingRepo::claim_for_extraction(
+        &pool,
+        avatar_id,
+        EmbeddingStatus::Extracting.id(),
+        &[
+            EmbeddingStatus::Extracting.id(),
+            EmbeddingStatus::Completed.id(),
+        ],
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    if !claimed {
+        return Ok(ExtractionOutcome::Skipped);
+    }
+
+    EmbeddingRepo::archive_embedding(&pool, avatar_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    EmbeddingRepo::clear_detected_faces(&pool, avatar_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ExtractionOutcome::Extracted)
+}
+
+fn batch_not_found(run_id: RunId) -> AppError {
+    AppError::Core(CoreError::RunNotFound {
+        entity: "EmbeddingBatch",
+        run_id,
+    })
+}
@@ -5,17 +5,22 @@
 //! generation parameters.
 
 use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Deserialize;
-use x121_core::failure_tracking::{self, PatternInput};
+use serde::{Deserialize, Serialize};
+use x121_core::error::CoreError;
+use x121_core::failure_tracking::{self, FailureSignature, PatternInput, SpikeConfig, TrendPoint};
 use x121_core::types::DbId;
 use x121_db::models::failure_pattern::{HeatmapCellResponse, HeatmapData, TrendPointResponse};
+use x121_db::models::failure_signature::CreateFailureSignature;
 use x121_db::models::pattern_fix::CreatePatternFix;
-use x121_db::repositories::{FailurePatternRepo, PatternFixRepo};
+use x121_db::repositories::{FailurePatternRepo, FailureSignatureRepo, PatternFixRepo};
+use x121_events::PlatformEvent;
 
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
+use crate::middleware::rbac::RequireAdmin;
 use crate::response::DataResponse;
 use crate::state::AppState;
 
@@ -48,11 +53,29 @@ pub struct AlertParams {
     pub avatar_id: Option<DbId>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SpikeParams {
+    /// Restrict to failures classified with this signature key.
+    pub pattern_key: Option<String>,
+    /// Days of history to analyse (default: 14).
+    pub period_days: Option<i32>,
+    /// Trailing days compared against the rest (default: 1).
+    pub window_days: Option<usize>,
+    pub factor: Option<f64>,
+    pub min_samples: Option<i32>,
+    pub min_rate: Option<f64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateEffectiveness {
     pub effectiveness: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateSignatureEnabled {
+    pub is_enabled: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Pattern listing
 // ---------------------------------------------------------------------------
@@ -206,6 +229,88 @@ pub async fn check_alerts(
     Ok(Json(DataResponse { data: all_patterns }))
 }
 
+/// Default history analysed by the spike check, in days.
+const DEFAULT_SPIKE_PERIOD_DAYS: i32 = 14;
+
+/// Response for the failure spike check.
+#[derive(Debug, Serialize)]
+pub struct SpikeCheckResponse {
+    pub trend: Vec<TrendPointResponse>,
+    pub alert: Option<failure_tracking::FailureSpikeAlert>,
+}
+
+/// GET /api/v1/analytics/failure-spikes
+///
+/// Computes the daily job failure trend and flags a spike when the trailing
+/// window's failure rate exceeds the baseline by the configured factor.
+/// Publishes a `failure.spike_detected` event when a spike is found.
+pub async fn check_spike(
+    State(state): State<AppState>,
+    Query(params): Query<SpikeParams>,
+) -> AppResult<impl IntoResponse> {
+    let defaults = SpikeConfig::default();
+    let config = SpikeConfig {
+        window_points: params.window_days.unwrap_or(defaults.window_points),
+        factor: params.factor.unwrap_or(defaults.factor),
+        min_samples: params.min_samples.unwrap_or(defaults.min_samples),
+        min_rate: params.min_rate.unwrap_or(defaults.min_rate),
+    };
+    failure_tracking::validate_spike_config(&config)?;
+    let period_days = params
+        .period_days
+        .unwrap_or(DEFAULT_SPIKE_PERIOD_DAYS)
+        .max(1);
+
+    let points: Vec<TrendPoint> = FailurePatternRepo::get_job_failure_trend(
+        &state.pool,
+        params.pattern_key.as_deref(),
+        period_days,
+    )
+    .await?
+    .into_iter()
+    .map(|(period, failure_rate, sample_count)| TrendPoint {
+        period,
+        failure_rate,
+        sample_count,
+    })
+    .collect();
+
+    let alert = failure_tracking::detect_failure_spike(&points, &config);
+    if let Some(alert) = &alert {
+        tracing::warn!(
+            pattern_key = ?params.pattern_key,
+            window_rate = alert.window_rate,
+            baseline_rate = alert.baseline_rate,
+            "Failure rate spike detected"
+        );
+        state
+            .event_bus
+            .publish(PlatformEvent::new("failure.spike_detected").with_payload(
+                serde_json::json!({
+                    "pattern_key": params.pattern_key,
+                    "window_start": alert.window_start,
+                    "window_rate": alert.window_rate,
+                    "window_samples": alert.window_samples,
+                    "baseline_rate": alert.baseline_rate,
+                    "baseline_samples": alert.baseline_samples,
+                }),
+            ));
+    }
+
+    let trend = points
+        .into_iter()
+        .map(|p| TrendPointResponse {
+            period: p.period,
+            failure_rate: p.failure_rate,
+            sample_count: p.sample_count,
+        })
+        .collect();
+
+    Ok(Json(DataResponse {
+        data: SpikeCheckResponse { trend, alert },
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // Pattern fixes
 // ---------------------------------------------------------------------------
@@ -247,6 +352,73 @@ pub async fn update_fix_effectiveness(
     Ok(Json(DataResponse { data: fix }))
 }
 
+// ---------------------------------------------------------------------------
+// Failure signatures
+// ---------------------------------------------------------------------------
+
+/// GET /api/v1/analytics/failure-signatures
+///
+/// Lists all failure signatures, highest priority first.
+pub async fn list_signatures(State(state): State<AppState>) -> AppResult<impl IntoResponse> {
+    let signatures = FailureSignatureRepo::list(&state.pool).await?;
+    Ok(Json(DataResponse { data: signatures }))
+}
+
+/// POST /api/v1/analytics/failure-signatures
+///
+/// Registers a new failure signature. Regex patterns are compiled before
+/// storing so a bad rule cannot reach the job failure path.
+pub async fn create_signature(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+    Json(body): Json<CreateFailureSignature>,
+) -> AppResult<impl IntoResponse> {
+    failure_tracking::validate_signature(&FailureSignature {
+        pattern_key: body.pattern_key.clone(),
+        kind: body.kind.parse()?,
+        pattern: body.pattern.clone(),
+        priority: body.priority,
+    })?;
+
+    let signature = FailureSignatureRepo::create(&state.pool, &body).await?;
+    Ok((StatusCode::CREATED, Json(DataResponse { data: signature })))
+}
+
+/// PATCH /api/v1/analytics/failure-signatures/{id}
+///
+/// Enables or disables a failure signature.
+pub async fn update_signature(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+    Path(id): Path<DbId>,
+    Json(body): Json<UpdateSignatureEnabled>,
+) -> AppResult<impl IntoResponse> {
+    let signature = FailureSignatureRepo::set_enabled(&state.pool, id, body.is_enabled)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "FailureSignature",
+            id,
+        }))?;
+    Ok(Json(DataResponse { data: signature }))
+}
+
+/// DELETE /api/v1/analytics/failure-signatures/{id}
+///
+/// Removes a failure signature.
+pub async fn delete_signature(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+    Path(id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    if !FailureSignatureRepo::delete(&state.pool, id).await? {
+        return Err(AppError::Core(CoreError::NotFound {
+            entity: "FailureSignature",
+            id,
+        }));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
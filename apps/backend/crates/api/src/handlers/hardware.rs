@@ -13,7 +13,7 @@ use axum::response::IntoResponse;
 use axum::Json;
 use chrono::{Duration, Utc};
 use futures::StreamExt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use x121_core::alert::MetricAlert;
 use x121_core::error::CoreError;
@@ -21,7 +21,9 @@ use x121_core::hardware::thresholds::{evaluate, AlertCooldownTracker, GpuSnapsho
 use x121_core::metric_names::MSG_TYPE_GPU_METRICS;
 use x121_core::types::DbId;
 use x121_db::models::hardware::{CreateGpuMetric, CreateRestartLog, UpsertThreshold};
-use x121_db::repositories::{GpuMetricRepo, MetricThresholdRepo, RestartLogRepo};
+use x121_db::repositories::{
+    ComfyUIInstanceRepo, GpuMetricRepo, MetricThresholdRepo, RestartLogRepo,
+};
 use x121_events::PlatformEvent;
 
 use crate::error::{AppError, AppResult};
@@ -150,6 +152,49 @@ pub async fn list_restart_logs(
     Ok(Json(DataResponse { data: logs }))
 }
 
+/// ComfyUI version report for one registered instance.
+#[derive(Debug, Serialize)]
+pub struct ComfyUIVersionInfo {
+    pub instance_id: DbId,
+    pub name: String,
+    pub is_enabled: bool,
+    pub connected: bool,
+    /// Version from the live connection, or the last one recorded if offline.
+    pub version: Option<String>,
+    /// Installed node types detected on the live connection.
+    pub node_type_count: Option<usize>,
+}
+
+/// GET /admin/hardware/comfyui/versions
+///
+/// List the ComfyUI version detected on each registered instance.
+pub async fn list_comfyui_versions(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+) -> AppResult<Json<DataResponse<Vec<ComfyUIVersionInfo>>>> {
+    let instances = ComfyUIInstanceRepo::list(&state.pool).await?;
+    let live = state.comfyui_manager.instance_versions().await;
+
+    let data = instances
+        .into_iter()
+        .map(|instance| {
+            let live = live.iter().find(|v| v.instance_id == instance.id);
+            ComfyUIVersionInfo {
+                instance_id: instance.id,
+                name: instance.name,
+                is_enabled: instance.is_enabled,
+                connected: live.is_some_and(|v| v.connected),
+                version: live
+                    .and_then(|v| v.version.clone())
+                    .or(instance.comfyui_version),
+                node_type_count: live.and_then(|v| v.node_type_count),
+            }
+        })
+        .collect();
+
+    Ok(Json(DataResponse { data }))
+}
+
 /// GET /admin/hardware/thresholds
 ///
 /// List all metric thresholds (global and worker-specific).
@@ -6,11 +6,13 @@
 use axum::extract::{Multipart, Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
+use futures::{StreamExt, TryStreamExt};
 use serde::Deserialize;
 use x121_core::error::CoreError;
 use x121_core::importer::{
-    default_mapping_rules, detect_uniqueness_conflicts, is_hidden_or_system, map_files_to_entities,
-    ParsedFile, MAX_FOLDER_DEPTH, SESSION_STATUS_CANCELLED, SESSION_STATUS_COMMITTED,
+    default_mapping_rules, detect_uniqueness_conflicts, is_after_scan_cursor, is_hidden_or_system,
+    map_files_to_entities, parsed_file_from_relative, scan_dir_may_follow_cursor, ParsedFile,
+    MAX_FOLDER_DEPTH, SCAN_CHECKPOINT_INTERVAL, SESSION_STATUS_CANCELLED, SESSION_STATUS_COMMITTED,
     SESSION_STATUS_PARSING, SESSION_STATUS_PREVIEW, STAGING_DIR_PREFIX,
 };
 use x121_core::types::DbId;
@@ -18,6 +20,7 @@ use x121_db::models::importer::{
     CreateImportMappingEntry, CreateImportSession, FolderImportPreview, ImportCommitResult,
 };
 use x121_db::repositories::{ImportMappingEntryRepo, ImportSessionRepo};
+use x121_events::PlatformEvent;
 
 use serde::Serialize;
 
@@ -145,9 +148,9 @@ pub async fn get_preview(
     // Transition to parsing.
     ImportSessionRepo::update_status(&state.pool, session_id, SESSION_STATUS_PARSING).await?;
 
-    // Parse folder tree.
+    // Parse folder tree, continuing from any interrupted scan.
     let staging_path = std::path::Path::new(&session.staging_path);
-    let files = parse_folder_tree(staging_path, MAX_FOLDER_DEPTH).await?;
+    let files = scan_folder_resumable(&state, session_id, staging_path).await?;
 
     // Map to entities.
     let rules = default_mapping_rules();
@@ -189,6 +192,9 @@ pub async fn get_preview(
 
     let entries = ImportMappingEntryRepo::batch_insert(&state.pool, &create_entries).await?;
 
+    // The scan's results are now stored as mapping entries.
+    ImportSessionRepo::clear_scan(&state.pool, session_id).await?;
+
     // Update session counts.
     let total_size: u64 = files.iter().map(|f| f.file_size_bytes).sum();
     ImportSessionRepo::update_counts(
@@ -332,22 +338,70 @@ pub async fn get_import_session(
 
 // ── Private helpers ──────────────────────────────────────────────────
 
-/// Recursively parse a folder tree into a flat list of [`ParsedFile`] entries.
+/// Scan the staged folder tree into a flat list of [`ParsedFile`] entries,
+/// resuming after the session's scan cursor if a previous scan was
+/// interrupted.
+///
+/// The tree is walked in sorted order (see [`compare_scan_paths`]), skipping
+/// everything at or before the cursor, and file sizes are read with bounded
+/// concurrency. Every [`SCAN_CHECKPOINT_INTERVAL`] files the batch is
+/// appended to the session's scanned files, the cursor advances, and an
+/// `import.scan_progress` event is published.
 ///
-/// Skips hidden files, system files, and files beyond `max_depth`.
-async fn parse_folder_tree(
+/// [`compare_scan_paths`]: x121_core::importer::compare_scan_paths
+async fn scan_folder_resumable(
+    state: &AppState,
+    session_id: DbId,
     root: &std::path::Path,
-    max_depth: usize,
 ) -> Result<Vec<ParsedFile>, AppError> {
-    let mut files = Vec::new();
-    parse_recursive(root, root, &mut files, 0, max_depth).await?;
+    let cursor = ImportSessionRepo::get_scan_cursor(&state.pool, session_id).await?;
+    let mut paths = Vec::new();
+    collect_file_paths(root, "", cursor.as_deref(), &mut paths, 0, MAX_FOLDER_DEPTH).await?;
+
+    let mut processed = ImportSessionRepo::list_scan_files(&state.pool, session_id)
+        .await?
+        .len();
+    let total = processed + paths.len();
+    let concurrency = state.config.import_scan_concurrency.max(1);
+
+    for batch in paths.chunks(SCAN_CHECKPOINT_INTERVAL) {
+        let sizes: Vec<i64> = futures::stream::iter(batch.iter().cloned())
+            .map(|relative| read_file_size(root.join(relative)))
+            .buffered(concurrency)
+            .try_collect()
+            .await?;
+        ImportSessionRepo::append_scan_files(&state.pool, session_id, batch, &sizes).await?;
+        processed += batch.len();
+
+        state.event_bus.publish(
+            PlatformEvent::new("import.scan_progress")
+                .with_source("import_session", session_id)
+                .with_payload(serde_json::json!({
+                    "processed": processed,
+                    "total": total,
+                })),
+        );
+    }
+
+    let files = ImportSessionRepo::list_scan_files(&state.pool, session_id)
+        .await?
+        .into_iter()
+        .map(|(relative, size)| parsed_file_from_relative(&relative, size.max(0) as u64))
+        .collect();
     Ok(files)
 }
 
-async fn parse_recursive(
+/// Recursively collect `/`-separated file paths relative to `root` that
+/// sort after `cursor`, in walk order.
+///
+/// Each directory's entries are visited sorted by name, and directories
+/// that cannot contain anything after the cursor are not listed. Skips
+/// hidden files, system files, and anything beyond `max_depth`.
+async fn collect_file_paths(
     root: &std::path::Path,
-    current: &std::path::Path,
-    files: &mut Vec<ParsedFile>,
+    prefix: &str,
+    cursor: Option<&str>,
+    paths: &mut Vec<String>,
     depth: usize,
     max_depth: usize,
 ) -> Result<(), AppError> {
@@ -355,57 +409,57 @@ async fn parse_recursive(
         return Ok(());
     }
 
-    let mut entries = tokio::fs::read_dir(current)
+    let mut entries = tokio::fs::read_dir(root.join(prefix))
         .await
         .map_err(|e| AppError::InternalError(format!("Failed to read directory: {e}")))?;
 
+    let mut children = Vec::new();
     while let Some(entry) = entries
         .next_entry()
         .await
         .map_err(|e| AppError::InternalError(format!("Failed to read entry: {e}")))?
     {
-        let path = entry.path();
         let name = entry.file_name().to_string_lossy().to_string();
 
         // Skip hidden and system files.
         if is_hidden_or_system(&name) {
             continue;
         }
+        children.push((name, entry.path().is_dir()));
+    }
+    children.sort();
 
-        if path.is_dir() {
-            Box::pin(parse_recursive(root, &path, files, depth + 1, max_depth)).await?;
+    for (name, is_dir) in children {
+        let relative = if prefix.is_empty() {
+            name
         } else {
-            let relative = path.strip_prefix(root).unwrap_or(&path);
-            let parent_folders: Vec<String> = relative
-                .parent()
-                .map(|p| {
-                    p.components()
-                        .map(|c| c.as_os_str().to_string_lossy().to_string())
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            let ext = path
-                .extension()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_lowercase();
-
-            let metadata = entry
-                .metadata()
-                .await
-                .map_err(|e| AppError::InternalError(format!("Failed to read metadata: {e}")))?;
-
-            files.push(ParsedFile {
-                relative_path: relative.to_string_lossy().to_string(),
-                file_name: name,
-                file_extension: ext,
-                file_size_bytes: metadata.len(),
-                depth,
-                parent_folders,
-            });
+            format!("{prefix}/{name}")
+        };
+
+        if is_dir {
+            if scan_dir_may_follow_cursor(&relative, cursor) {
+                Box::pin(collect_file_paths(
+                    root,
+                    &relative,
+                    cursor,
+                    paths,
+                    depth + 1,
+                    max_depth,
+                ))
+                .await?;
+            }
+        } else if is_after_scan_cursor(&relative, cursor) {
+            paths.push(relative);
         }
     }
 
     Ok(())
 }
+
+/// Read a single file's size in bytes.
+async fn read_file_size(path: std::path::PathBuf) -> Result<i64, AppError> {
+    let metadata = tokio::fs::metadata(&path)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Failed to read metadata: {e}")))?;
+    Ok(metadata.len() as i64)
+}
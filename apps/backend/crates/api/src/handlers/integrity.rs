@@ -1,22 +1,26 @@
 //! Handlers for System Integrity & Repair Tools endpoints (PRD-43).
 //!
-//! Provides integrity scan management, repair actions, and model
-//! checksum CRUD for the admin dashboard.
+//! Provides integrity scan management (including NDJSON-streamed per-file
+//! reports), repair actions (including cancellable full repairs with
+//! per-step reports), and model checksum CRUD for the admin dashboard.
 
+use axum::body::{Body, Bytes};
 use axum::extract::{Path, Query, State};
-use axum::response::IntoResponse;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde::Deserialize;
 use serde::Serialize;
 use x121_core::error::CoreError;
 use x121_core::integrity;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
-use x121_core::types::DbId;
-use x121_db::models::integrity_scan::CreateIntegrityScan;
+use x121_core::types::{DbId, RunId};
+use x121_db::models::integrity_scan::{CreateIntegrityScan, IntegrityScan, IntegrityScanFile};
 use x121_db::models::model_checksum::{CreateModelChecksum, UpdateModelChecksum};
-use x121_db::repositories::{IntegrityScanRepo, ModelChecksumRepo};
+use x121_db::repositories::{IntegrityScanRepo, ModelChecksumRepo, WorkerRepo};
 
-use crate::error::AppResult;
+use crate::engine::worker_repair::{self, WorkerRepairReport};
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
 use crate::query::PaginationParams;
 use crate::response::DataResponse;
@@ -51,15 +55,55 @@ pub struct WorkerScanRequest {
     pub scan_type: String,
 }
 
+/// Request body for a full worker repair.
+#[derive(Debug, Default, Deserialize)]
+pub struct RepairWorkerRequest {
+    /// An earlier run for the same worker to resume: steps that succeeded
+    /// there are skipped.
+    pub resume_run_id: Option<RunId>,
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Number of per-file records fetched per query while streaming a report.
+const REPORT_STREAM_CHUNK: i64 = 500;
+
 /// Typed response for worker integrity report (DRY-258).
 #[derive(Serialize)]
 struct WorkerReportResponse {
-    scan: x121_db::models::integrity_scan::IntegrityScan,
+    scan: IntegrityScan,
     health_status: String,
+    /// One page of per-file results; see `files_total` for the full count.
+    files: Vec<IntegrityScanFile>,
+    files_total: i64,
+}
+
+/// One line of a streamed worker report.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ReportLine<'a> {
+    File(&'a IntegrityScanFile),
+    Summary {
+        scan_id: DbId,
+        #[serde(flatten)]
+        summary: integrity::ScanReportSummary,
+    },
+}
+
+impl ReportLine<'_> {
+    /// Serialize as one newline-terminated NDJSON line.
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        serde_json::to_writer(&mut *buf, self).expect("report line serialization cannot fail");
+        buf.push(b'\n');
+    }
+}
+
+/// Find the latest integrity scan for a worker.
+async fn latest_scan(pool: &sqlx::PgPool, worker_id: DbId) -> AppResult<Option<IntegrityScan>> {
+    let scans = IntegrityScanRepo::list_by_worker(pool, worker_id, 1, 0).await?;
+    Ok(scans.into_iter().next())
 }
 
 /// Look up a checksum by ID or return a 404.
@@ -125,27 +169,100 @@ pub async fn start_worker_scan(
 
 /// GET /api/v1/admin/integrity-scans/{worker_id}
 ///
-/// Get the latest integrity scan report for a worker.
+/// Get the latest integrity scan report for a worker with one page of its
+/// per-file results (at most [`integrity::MAX_REPORT_FILES_LIMIT`]). Use
+/// the `/stream` variant for the complete file list.
 pub async fn get_worker_report(
     State(state): State<AppState>,
     Path(worker_id): Path<DbId>,
+    Query(params): Query<PaginationParams>,
 ) -> AppResult<impl IntoResponse> {
-    let scans = IntegrityScanRepo::list_by_worker(&state.pool, worker_id, 1, 0).await?;
-    let latest = scans.into_iter().next();
-
-    let report = latest.map(|scan| {
-        let health = integrity::assess_health(
-            scan.models_missing,
-            scan.models_corrupted,
-            scan.nodes_missing,
-        );
-        WorkerReportResponse {
+    let Some(scan) = latest_scan(&state.pool, worker_id).await? else {
+        return Ok(Json(DataResponse {
+            data: None::<WorkerReportResponse>,
+        }));
+    };
+
+    let limit = clamp_limit(
+        params.limit,
+        integrity::DEFAULT_REPORT_FILES_LIMIT,
+        integrity::MAX_REPORT_FILES_LIMIT,
+    );
+    let offset = clamp_offset(params.offset);
+    let files = IntegrityScanRepo::list_files(&state.pool, scan.id, limit, offset).await?;
+    let files_total = IntegrityScanRepo::count_files(&state.pool, scan.id).await?;
+
+    let health_status = integrity::assess_health(
+        scan.models_missing,
+        scan.models_corrupted,
+        scan.nodes_missing,
+    );
+    Ok(Json(DataResponse {
+        data: Some(WorkerReportResponse {
             scan,
-            health_status: health,
+            health_status,
+            files,
+            files_total,
+        }),
+    }))
+}
+
+/// GET /api/v1/admin/integrity-scans/{worker_id}/stream
+///
+/// Stream the latest scan's per-file results for a worker as NDJSON: one
+/// `{"type":"file",...}` line per file, then a `{"type":"summary",...}`
+/// line with per-status counts. Files are read in chunks, so the report is
+/// never held in memory. A response that ends without a summary line was
+/// cut short by an error.
+pub async fn stream_worker_report(
+    State(state): State<AppState>,
+    Path(worker_id): Path<DbId>,
+) -> AppResult<Response> {
+    let scan = latest_scan(&state.pool, worker_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "IntegrityScan",
+            id: worker_id,
+        }))?;
+
+    let pool = state.pool.clone();
+    let scan_id = scan.id;
+    let cursor = Some((0, integrity::ScanReportTally::default()));
+    let lines = futures::stream::unfold(cursor, move |cursor| {
+        let pool = pool.clone();
+        async move {
+            let (after_id, mut tally) = cursor?;
+            let chunk = match IntegrityScanRepo::list_files_after(
+                &pool,
+                scan_id,
+                after_id,
+                REPORT_STREAM_CHUNK,
+            )
+            .await
+            {
+                Ok(chunk) => chunk,
+                Err(e) => return Some((Err(e), None)),
+            };
+
+            let mut buf = Vec::new();
+            let Some(last) = chunk.last() else {
+                let summary = tally.summary();
+                ReportLine::Summary { scan_id, summary }.write_to(&mut buf);
+                return Some((Ok(Bytes::from(buf)), None));
+            };
+            for file in &chunk {
+                tally.record(&file.status);
+                ReportLine::File(file).write_to(&mut buf);
+            }
+            Some((Ok(Bytes::from(buf)), Some((last.id, tally))))
         }
     });
 
-    Ok(Json(DataResponse { data: report }))
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/x-ndjson")
+        .body(Body::from_stream(lines))
+        .expect("valid response"))
 }
 
 /// GET /api/v1/admin/integrity-scans
@@ -168,13 +285,136 @@ pub async fn list_scans(
 
 /// POST /api/v1/admin/repair/{worker_id}
 ///
-/// Trigger a full verify-and-repair pass for a worker.
+/// Start a full repair of a worker: each step of
+/// [`integrity::FULL_REPAIR_STEPS`] in turn, continuing past failed steps.
+///
+/// Runs in the background and returns the run report immediately; poll it
+/// via `GET /admin/repair/runs/{run_id}`.
 pub async fn repair_worker(
     State(state): State<AppState>,
     auth: AuthUser,
     Path(worker_id): Path<DbId>,
+    body: Option<Json<RepairWorkerRequest>>,
 ) -> AppResult<impl IntoResponse> {
-    trigger_scan(&state, &auth, worker_id, integrity::SCAN_TYPE_FULL).await
+    let body = body.map(|Json(b)| b).unwrap_or_default();
+    WorkerRepo::find_by_id(&state.pool, worker_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Worker",
+            id: worker_id,
+        }))?;
+
+    let resume = match body.resume_run_id {
+        Some(run_id) => {
+            let previous = state
+                .worker_repairs
+                .get(run_id)
+                .ok_or_else(|| repair_run_not_found(run_id))?;
+            if previous.worker_id != worker_id {
+                return Err(AppError::BadRequest(format!(
+                    "Repair run {run_id} belongs to worker {}",
+                    previous.worker_id
+                )));
+            }
+            if previous.finished_at.is_none() {
+                return Err(AppError::Core(CoreError::Conflict(format!(
+                    "Repair run {run_id} is still in progress"
+                ))));
+            }
+            Some(previous)
+        }
+        None => None,
+    };
+
+    let handle = state.worker_repairs.register(WorkerRepairReport::new(
+        worker_id,
+        integrity::FULL_REPAIR_STEPS,
+        resume.as_ref(),
+    ));
+    let report = handle.report();
+
+    tracing::info!(
+        user_id = auth.user_id,
+        worker_id,
+        run_id = %report.run_id,
+        resumed_from = ?report.resumed_from,
+        "Worker repair started"
+    );
+
+    let pool = state.pool.clone();
+    let triggered_by = auth.user_id;
+    tokio::spawn(async move {
+        let report = worker_repair::run_repair(handle, |step| {
+            let pool = pool.clone();
+            async move {
+                let scan_type = integrity::repair_step_scan_type(&step)
+                    .ok_or_else(|| format!("Unknown repair step '{step}'"))?;
+                let create = CreateIntegrityScan {
+                    worker_id,
+                    scan_type: scan_type.to_string(),
+                    triggered_by: Some(triggered_by),
+                };
+                IntegrityScanRepo::create(&pool, &create)
+                    .await
+                    .map(|scan| scan.id)
+                    .map_err(|e| e.to_string())
+            }
+        })
+        .await;
+        tracing::info!(
+            worker_id,
+            run_id = %report.run_id,
+            complete = report.is_complete(),
+            cancelled = report.cancelled,
+            "Worker repair finished"
+        );
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(DataResponse { data: report })))
+}
+
+/// GET /api/v1/admin/repair/runs/{run_id}
+///
+/// Return the per-step report of a full repair run.
+pub async fn get_repair_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .worker_repairs
+        .get(run_id)
+        .ok_or_else(|| repair_run_not_found(run_id))?;
+    Ok(Json(DataResponse { data: report }))
+}
+
+/// POST /api/v1/admin/repair/runs/{run_id}/cancel
+///
+/// Stop a full repair run before its next step. The step in progress, if
+/// any, finishes normally; resume later with `resume_run_id`.
+pub async fn cancel_repair_run(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(run_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .worker_repairs
+        .cancel(run_id)
+        .ok_or_else(|| repair_run_not_found(run_id))?;
+
+    tracing::info!(
+        user_id = auth.user_id,
+        run_id = %run_id,
+        "Worker repair cancelled"
+    );
+
+    Ok(Json(DataResponse { data: report }))
+}
+
+fn repair_run_not_found(run_id: RunId) -> AppError {
+    AppError::Core(CoreError::RunNotFound {
+        entity: "WorkerRepairRun",
+        run_id,
+    })
 }
 
 /// POST /api/v1/admin/repair/{worker_id}/sync-models
@@ -231,6 +471,7 @@ pub async fn create_checksum(
     _auth: AuthUser,
     Json(body): Json<CreateModelChecksum>,
 ) -> AppResult<impl IntoResponse> {
+    integrity::validate_checksum_format(&body.expected_hash).map_err(AppError::BadRequest)?;
     if let Some(ref mt) = body.model_type {
         integrity::validate_model_type(mt)?;
     }
@@ -250,6 +491,9 @@ pub async fn update_checksum(
     // Verify existence first.
     let _existing = ensure_checksum_exists(&state.pool, id).await?;
 
+    if let Some(ref hash) = body.expected_hash {
+        integrity::validate_checksum_format(hash).map_err(AppError::BadRequest)?;
+    }
     if let Some(ref mt) = body.model_type {
         integrity::validate_model_type(mt)?;
     }
@@ -7,10 +7,12 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
+use serde::Serialize;
 use x121_core::error::CoreError;
+use x121_core::layout::{resolve_effective_layout, EffectiveLayout};
 use x121_core::types::DbId;
 use x121_db::models::layout::{
-    CreateAdminPreset, CreateUserLayout, UpdateAdminPreset, UpdateUserLayout,
+    CreateAdminPreset, CreateUserLayout, UpdateAdminPreset, UpdateUserLayout, UserLayout,
 };
 use x121_db::repositories::LayoutRepo;
 
@@ -19,6 +21,18 @@ use crate::middleware::rbac::{RequireAdmin, RequireAuth};
 use crate::response::DataResponse;
 use crate::state::AppState;
 
+// ---------------------------------------------------------------------------
+// Response DTOs
+// ---------------------------------------------------------------------------
+
+/// A user layout with the result of resolving it against the role preset.
+#[derive(Debug, Serialize)]
+pub struct UserLayoutResponse {
+    #[serde(flatten)]
+    pub layout: UserLayout,
+    pub effective: EffectiveLayout,
+}
+
 // ---------------------------------------------------------------------------
 // User layout endpoints
 // ---------------------------------------------------------------------------
@@ -57,9 +71,10 @@ pub async fn create_user_layout(
 
 /// GET /api/v1/user/layouts/:id
 ///
-/// Retrieve a single user layout by ID.
+/// Retrieve a single user layout by ID, together with its effective layout:
+/// the user's panels overlaid on the default preset for their role.
 pub async fn get_user_layout(
-    RequireAuth(_user): RequireAuth,
+    RequireAuth(user): RequireAuth,
     State(state): State<AppState>,
     Path(layout_id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
@@ -70,7 +85,15 @@ pub async fn get_user_layout(
             id: layout_id,
         }))?;
 
-    Ok(Json(DataResponse { data: layout }))
+    let preset = LayoutRepo::get_default_for_role(&state.pool, &user.role).await?;
+    let effective = resolve_effective_layout(
+        preset.as_ref().map(|p| &p.layout_json),
+        Some(&layout.layout_json),
+    );
+
+    Ok(Json(DataResponse {
+        data: UserLayoutResponse { layout, effective },
+    }))
 }
 
 /// PUT /api/v1/user/layouts/:id
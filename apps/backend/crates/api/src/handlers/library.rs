@@ -1,23 +1,27 @@
 //! Handlers for the avatar library (PRD-60).
 //!
 //! Provides endpoints for managing library avatars, importing them into
-//! projects, viewing cross-project usage, and managing field links.
+//! projects, recommending avatars to import, viewing cross-project usage,
+//! and managing field links.
+
+use std::collections::{HashMap, HashSet};
 
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use x121_core::avatar_library;
 use x121_core::error::CoreError;
 use x121_core::types::DbId;
-use x121_db::models::avatar::CreateAvatar;
 use x121_db::models::library_avatar::{
-    CreateLibraryAvatar, CreateProjectAvatarLink, ImportAvatarRequest, LibraryAvatar,
+    CreateLibraryAvatar, ImportAvatarRequest, LibraryAvatar, LibraryAvatarDeletion,
     UpdateLibraryAvatar,
 };
-use x121_db::repositories::{AvatarRepo, LibraryAvatarRepo, ProjectAvatarLinkRepo};
+use x121_db::repositories::{
+    AvatarRepo, LibraryAvatarRepo, ProjectAvatarLinkRepo, ProjectRepo, ReadinessCacheRepo,
+};
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
@@ -132,20 +136,45 @@ pub async fn update_library_avatar(
 // DELETE /library/avatars/{id}
 // ---------------------------------------------------------------------------
 
+/// Query params for deleting a library avatar.
+#[derive(Debug, Deserialize)]
+pub struct DeleteLibraryAvatarParams {
+    /// Confirm removal of the avatar's project links along with it.
+    pub cascade: Option<bool>,
+}
+
 /// Delete a library avatar by ID.
+///
+/// Refused with 409 while projects still link to the avatar, naming them in
+/// the error, unless `cascade=true` confirms the links should go too.
+/// Imported project avatars are kept either way.
 pub async fn delete_library_avatar(
     State(state): State<AppState>,
     Path(id): Path<DbId>,
+    Query(params): Query<DeleteLibraryAvatarParams>,
 ) -> AppResult<StatusCode> {
-    let deleted = LibraryAvatarRepo::delete(&state.pool, id).await?;
-    if deleted {
-        tracing::info!(id, "Library avatar deleted");
-        Ok(StatusCode::NO_CONTENT)
-    } else {
-        Err(AppError::Core(CoreError::NotFound {
+    let cascade = params.cascade.unwrap_or(false);
+    if !cascade {
+        let usage = ProjectAvatarLinkRepo::get_usage(&state.pool, id).await?;
+        let projects: Vec<String> = usage.into_iter().map(|u| u.project_name).collect();
+        avatar_library::check_library_deletion(projects.len() as i64, &projects)?;
+    }
+
+    match LibraryAvatarRepo::delete(&state.pool, id, cascade).await? {
+        LibraryAvatarDeletion::Deleted { unlinked } => {
+            tracing::info!(id, unlinked, "Library avatar deleted");
+            Ok(StatusCode::NO_CONTENT)
+        }
+        LibraryAvatarDeletion::NotFound => Err(AppError::Core(CoreError::NotFound {
             entity: "LibraryAvatar",
             id,
-        }))
+        })),
+        // A project imported the avatar after the check above.
+        LibraryAvatarDeletion::HasLinks { active_links } => {
+            Err(AppError::Core(CoreError::Conflict(format!(
+                "Cannot delete: library avatar has {active_links} active project link(s)"
+            ))))
+        }
     }
 }
 
@@ -170,7 +199,7 @@ pub async fn get_library_usage(
 /// Import a library avatar into a project.
 ///
 /// Creates a new avatar in the target project with the library avatar's
-/// master_metadata, then creates a project-avatar link.
+/// master_metadata and a project-avatar link, in a single transaction.
 pub async fn import_to_project(
     State(state): State<AppState>,
     Path(library_id): Path<DbId>,
@@ -181,8 +210,7 @@ pub async fn import_to_project(
         avatar_library::validate_linked_fields(fields)?;
     }
 
-    // Fetch the library avatar.
-    let lc = ensure_library_avatar_exists(&state.pool, library_id).await?;
+    ensure_library_avatar_exists(&state.pool, library_id).await?;
 
     // Check if already linked to this project.
     let existing = ProjectAvatarLinkRepo::find_by_project_and_library(
@@ -197,42 +225,114 @@ pub async fn import_to_project(
         )));
     }
 
-    // Create a project avatar from the library avatar's data.
-    let create_char = CreateAvatar {
-        project_id: input.project_id,
-        name: lc.name.clone(),
-        status_id: Some(1), // Draft
-        metadata: Some(lc.master_metadata.clone()),
-        settings: None,
-        group_id: None,
-    };
-    let project_char = AvatarRepo::create(&state.pool, &create_char).await?;
-
-    // Build linked_fields JSON.
+    // Create the project avatar and its link together so a failed link
+    // never leaves an orphaned avatar behind.
     let linked_fields_json = input
         .linked_fields
         .as_ref()
         .and_then(|f| serde_json::to_value(f).ok());
-
-    // Create the link.
-    let link_input = CreateProjectAvatarLink {
-        project_id: input.project_id,
-        library_avatar_id: library_id,
-        project_avatar_id: project_char.id,
-        linked_fields: linked_fields_json,
-    };
-    let link = ProjectAvatarLinkRepo::create_link(&state.pool, &link_input).await?;
+    let link = ProjectAvatarLinkRepo::import_avatar(
+        &state.pool,
+        library_id,
+        input.project_id,
+        linked_fields_json.as_ref(),
+    )
+    .await?
+    .ok_or(AppError::Core(CoreError::NotFound {
+        entity: "LibraryAvatar",
+        id: library_id,
+    }))?;
 
     tracing::info!(
         library_id,
         project_id = input.project_id,
-        project_avatar_id = project_char.id,
+        project_avatar_id = link.project_avatar_id,
         "Library avatar imported into project"
     );
 
     Ok((StatusCode::CREATED, Json(DataResponse { data: link })))
 }
 
+// ---------------------------------------------------------------------------
+// GET /library/avatars/projects/{project_id}/recommendations
+// ---------------------------------------------------------------------------
+
+/// A library avatar suggested for import, with its project-fit score.
+#[derive(Debug, Serialize)]
+pub struct LibraryRecommendation {
+    #[serde(flatten)]
+    pub library_avatar: LibraryAvatar,
+    /// Fit score in `0.0..=1.0` from [`avatar_library::score_library_fit`].
+    pub fit_score: f64,
+}
+
+/// Recommend library avatars to import into a project, best fit first.
+///
+/// Candidates are the library avatars visible to the caller that are not
+/// yet imported into the project. Each is scored against the metadata of
+/// the project's existing avatars and the readiness of its source avatar.
+pub async fn list_project_recommendations(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(project_id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    ProjectRepo::find_by_id(&state.pool, project_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Project",
+            id: project_id,
+        }))?;
+
+    let project_avatars = AvatarRepo::list_by_project(&state.pool, project_id).await?;
+    let profile = avatar_library::ProjectFitProfile::from_metadata(
+        project_avatars.iter().filter_map(|a| a.metadata.as_ref()),
+    );
+
+    let imported: HashSet<DbId> = ProjectAvatarLinkRepo::list_by_project(&state.pool, project_id)
+        .await?
+        .into_iter()
+        .map(|link| link.library_avatar_id)
+        .collect();
+    let candidates: Vec<LibraryAvatar> = LibraryAvatarRepo::list(&state.pool, auth.user_id)
+        .await?
+        .into_iter()
+        .filter(|lc| !imported.contains(&lc.id))
+        .collect();
+
+    let source_ids: Vec<DbId> = candidates
+        .iter()
+        .filter_map(|lc| lc.source_avatar_id)
+        .collect();
+    let readiness: HashMap<DbId, i32> =
+        ReadinessCacheRepo::find_by_avatar_ids(&state.pool, &source_ids)
+            .await?
+            .into_iter()
+            .map(|entry| (entry.avatar_id, entry.readiness_pct))
+            .collect();
+
+    let mut recommendations: Vec<LibraryRecommendation> = candidates
+        .into_iter()
+        .map(|lc| {
+            let candidate = avatar_library::LibraryFitCandidate {
+                master_metadata: &lc.master_metadata,
+                readiness_pct: lc
+                    .source_avatar_id
+                    .and_then(|id| readiness.get(&id).copied()),
+            };
+            let fit_score = avatar_library::score_library_fit(&candidate, &profile);
+            LibraryRecommendation {
+                library_avatar: lc,
+                fit_score,
+            }
+        })
+        .collect();
+    recommendations.sort_by(|a, b| b.fit_score.total_cmp(&a.fit_score));
+
+    Ok(Json(DataResponse {
+        data: recommendations,
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // GET /library/avatars/projects/{project_id}/links
 // ---------------------------------------------------------------------------
@@ -1,9 +1,11 @@
 //! Handlers for Bulk Data Maintenance endpoints (PRD-18).
 //!
 //! Provides find/replace preview and execution, re-path preview and
-//! execution, undo, operation history, and single operation detail.
+//! execution, undo, studio-wide cache rebuild and wiki search reindex with
+//! cancellation, operation history, and single operation detail.
 
 use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
@@ -11,12 +13,15 @@ use x121_core::error::CoreError;
 use x121_core::maintenance;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
+use x121_core::wiki;
 use x121_db::models::bulk_operation::CreateBulkOperation;
 use x121_db::models::status::{BulkOperationStatusId, BulkOperationTypeId};
 use x121_db::repositories::BulkOperationRepo;
 
-use crate::error::AppResult;
+use crate::background::{cache_rebuild, wiki_search_index};
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
+use crate::middleware::rbac::RequireAdmin;
 use crate::response::DataResponse;
 use crate::state::AppState;
 
@@ -76,6 +81,8 @@ pub struct PreviewResponse {
     pub operation_id: DbId,
     pub total_matches: i32,
     pub searchable_fields: Vec<FieldInfo>,
+    /// Exact before/after values for the first matches (empty for re-path).
+    pub matches: Vec<maintenance::ReplacementPreview>,
 }
 
 /// Info about a searchable field included in a preview.
@@ -107,14 +114,14 @@ pub async fn preview_find_replace(
     Json(body): Json<FindReplaceRequest>,
 ) -> AppResult<impl IntoResponse> {
     // Validate inputs.
-    if body.use_regex {
-        maintenance::validate_regex_pattern(&body.search_term)?;
-    } else {
-        maintenance::validate_search_term(&body.search_term)?;
-    }
+    let re =
+        maintenance::compile_find_pattern(&body.search_term, body.use_regex, body.case_sensitive)?;
     maintenance::validate_replacement(&body.replace_with)?;
 
-    let fields = maintenance::get_searchable_fields(body.entity_type.as_deref());
+    let fields = maintenance::resolve_find_replace_fields(
+        body.entity_type.as_deref(),
+        body.field_name.as_deref(),
+    )?;
     let field_infos: Vec<FieldInfo> = fields
         .iter()
         .map(|f| FieldInfo {
@@ -134,6 +141,23 @@ pub async fn preview_find_replace(
         "case_sensitive": body.case_sensitive,
     });
 
+    let mut matches = Vec::new();
+    for field in &fields {
+        let rows = BulkOperationRepo::scan_text_field(
+            &state.pool,
+            field,
+            maintenance::MAX_FIND_REPLACE_SCAN_ROWS,
+        )
+        .await?;
+        matches.extend(maintenance::preview_replacements(
+            &re,
+            &body.replace_with,
+            body.use_regex,
+            field,
+            &rows,
+        ));
+    }
+
     let create = CreateBulkOperation {
         operation_type_id: BulkOperationTypeId::FindReplace.id(),
         status_id: BulkOperationStatusId::Preview.id(),
@@ -141,16 +165,18 @@ pub async fn preview_find_replace(
         scope_project_id: body.project_id,
         affected_entity_type: body.entity_type.clone(),
         affected_field: body.field_name.clone(),
-        preview_count: fields.len() as i32,
+        preview_count: matches.len() as i32,
     };
 
     let op = BulkOperationRepo::create(&state.pool, &create).await?;
+    matches.truncate(maintenance::MAX_PREVIEW_SAMPLES);
 
     Ok(Json(DataResponse {
         data: PreviewResponse {
             operation_id: op.id,
             total_matches: op.preview_count,
             searchable_fields: field_infos,
+            matches,
         },
     }))
 }
@@ -264,6 +290,7 @@ pub async fn preview_repath(
             operation_id: op.id,
             total_matches: op.preview_count,
             searchable_fields: field_infos,
+            matches: Vec::new(),
         },
     }))
 }
@@ -355,6 +382,189 @@ pub async fn undo_operation(
     }))
 }
 
+// ---------------------------------------------------------------------------
+// Cache rebuild, wiki reindex & cancellation handlers
+// ---------------------------------------------------------------------------
+
+/// Partial unique index allowing one executing cache rebuild at a time.
+const EXECUTING_REBUILD_UNIQUE_INDEX: &str = "uq_bulk_operations_executing_rebuild";
+
+/// Partial unique index allowing one executing wiki reindex at a time.
+const EXECUTING_REINDEX_UNIQUE_INDEX: &str = "uq_bulk_operations_executing_reindex_wiki";
+
+/// Operation types whose background work checks for cancellation between
+/// chunks. Other types run to completion in the request that started them.
+const CANCELLABLE_OP_TYPES: [BulkOperationTypeId; 2] = [
+    BulkOperationTypeId::RebuildCaches,
+    BulkOperationTypeId::ReindexWiki,
+];
+
+/// Map a violation of `unique_index` to a 409 with `message`; any other
+/// error passes through unchanged.
+fn already_running(err: sqlx::Error, unique_index: &str, message: &str) -> AppError {
+    match &err {
+        sqlx::Error::Database(db_err)
+            if db_err.code().as_deref() == Some("23505")
+                && db_err.constraint() == Some(unique_index) =>
+        {
+            AppError::Core(CoreError::Conflict(message.to_string()))
+        }
+        _ => AppError::Database(err),
+    }
+}
+
+/// POST /rebuild-caches
+///
+/// Start a studio-wide recomputation of completeness and readiness caches
+/// in the background. Returns the tracking operation; poll `GET /{id}` for
+/// progress (`affected_count`) and `POST /{id}/cancel` to stop it.
+pub async fn rebuild_caches(
+    State(state): State<AppState>,
+    RequireAdmin(admin): RequireAdmin,
+) -> AppResult<impl IntoResponse> {
+    let op = BulkOperationRepo::create(
+        &state.pool,
+        &CreateBulkOperation {
+            operation_type_id: BulkOperationTypeId::RebuildCaches.id(),
+            status_id: BulkOperationStatusId::Executing.id(),
+            parameters: serde_json::json!({ "chunk_size": maintenance::REBUILD_CACHES_CHUNK_SIZE }),
+            scope_project_id: None,
+            affected_entity_type: Some("avatar".to_string()),
+            affected_field: None,
+            preview_count: 0,
+        },
+    )
+    .await
+    .map_err(|e| {
+        already_running(
+            e,
+            EXECUTING_REBUILD_UNIQUE_INDEX,
+            "A cache rebuild is already running",
+        )
+    })?;
+
+    tracing::info!(
+        operation_id = op.id,
+        user_id = admin.user_id,
+        "Cache rebuild started"
+    );
+
+    let bg_state = state.clone();
+    let operation_id = op.id;
+    tokio::spawn(async move {
+        cache_rebuild::run_cache_rebuild(bg_state, operation_id, admin.user_id).await;
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(DataResponse { data: op })))
+}
+
+/// POST /reindex-wiki
+///
+/// Start a rebuild of the wiki article search index in the background.
+/// Returns the tracking operation; poll `GET /{id}` for progress
+/// (`affected_count`) and `POST /{id}/cancel` to stop it.
+pub async fn reindex_wiki(
+    State(state): State<AppState>,
+    RequireAdmin(admin): RequireAdmin,
+) -> AppResult<impl IntoResponse> {
+    let op = BulkOperationRepo::create(
+        &state.pool,
+        &CreateBulkOperation {
+            operation_type_id: BulkOperationTypeId::ReindexWiki.id(),
+            status_id: BulkOperationStatusId::Executing.id(),
+            parameters: serde_json::json!({
+                "chunk_size": wiki::REINDEX_CHUNK_SIZE,
+                "concurrency": state.config.wiki_reindex_concurrency,
+            }),
+            scope_project_id: None,
+            affected_entity_type: Some("wiki_article".to_string()),
+            affected_field: None,
+            preview_count: 0,
+        },
+    )
+    .await
+    .map_err(|e| {
+        already_running(
+            e,
+            EXECUTING_REINDEX_UNIQUE_INDEX,
+            "A wiki reindex is already running",
+        )
+    })?;
+
+    tracing::info!(
+        operation_id = op.id,
+        user_id = admin.user_id,
+        "Wiki reindex started"
+    );
+
+    let bg_state = state.clone();
+    let operation_id = op.id;
+    tokio::spawn(async move {
+        wiki_search_index::run_wiki_reindex(bg_state, operation_id, admin.user_id).await;
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(DataResponse { data: op })))
+}
+
+/// POST /{id}/cancel
+///
+/// Cancel a running operation. Background work stops before its next chunk.
+pub async fn cancel_operation(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+    Path(id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    let op = BulkOperationRepo::find_by_id(&state.pool, id)
+        .await?
+        .ok_or(CoreError::NotFound {
+            entity: "BulkOperation",
+            id,
+        })?;
+
+    if !CANCELLABLE_OP_TYPES
+        .iter()
+        .any(|t| t.id() == op.operation_type_id)
+    {
+        return Err(CoreError::Validation(
+            "Only cache rebuild and wiki reindex operations can be cancelled".to_string(),
+        )
+        .into());
+    }
+
+    let status = if op.status_id == BulkOperationStatusId::Executing.id() {
+        Some(maintenance::BulkOperationStatus::Executing)
+    } else {
+        None
+    };
+    if !status
+        .as_ref()
+        .map(maintenance::can_cancel_operation)
+        .unwrap_or(false)
+    {
+        return Err(CoreError::Validation(
+            "Only operations in 'executing' status can be cancelled".to_string(),
+        )
+        .into());
+    }
+
+    let cancelled = BulkOperationRepo::transition_status(
+        &state.pool,
+        id,
+        BulkOperationStatusId::Executing.id(),
+        BulkOperationStatusId::Cancelled.id(),
+    )
+    .await?
+    .ok_or_else(|| CoreError::Conflict("Operation finished before it could be cancelled".into()))?;
+
+    Ok(Json(DataResponse {
+        data: ExecutionResponse {
+            operation_id: cancelled.id,
+            affected_count: cancelled.affected_count,
+            status: maintenance::STATUS_CANCELLED.to_string(),
+        },
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // History & detail handlers
 // ---------------------------------------------------------------------------
@@ -375,6 +585,8 @@ pub async fn list_operations(
             "find_replace" => BulkOperationTypeId::FindReplace.id(),
             "repath" => BulkOperationTypeId::Repath.id(),
             "batch_update" => BulkOperationTypeId::BatchUpdate.id(),
+            "rebuild_caches" => BulkOperationTypeId::RebuildCaches.id(),
+            "reindex_wiki" => BulkOperationTypeId::ReindexWiki.id(),
             _ => {
                 return Err(
                     CoreError::Validation(format!("Unknown operation type: '{op_type}'")).into(),
@@ -390,6 +602,7 @@ pub async fn list_operations(
             maintenance::BulkOperationStatus::Completed => BulkOperationStatusId::Completed.id(),
             maintenance::BulkOperationStatus::Failed => BulkOperationStatusId::Failed.id(),
             maintenance::BulkOperationStatus::Undone => BulkOperationStatusId::Undone.id(),
+            maintenance::BulkOperationStatus::Cancelled => BulkOperationStatusId::Cancelled.id(),
         };
         BulkOperationRepo::list_by_status(&state.pool, status_id, limit, offset).await?
     } else {
@@ -7,10 +7,10 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
-use x121_core::channels::CHANNEL_IN_APP;
+use x121_core::digest_schedule::{parse_timezone, validate_digest_hour, DigestCadence};
 use x121_core::error::CoreError;
 use x121_core::types::DbId;
-use x121_db::models::notification::{UpdateNotificationSettings, UpdatePreference};
+use x121_db::models::notification::{MarkReadFilter, UpdateNotificationSettings, UpdatePreference};
 use x121_db::repositories::{EventRepo, NotificationPreferenceRepo, NotificationRepo};
 
 use crate::error::{AppError, AppResult};
@@ -82,6 +82,22 @@ pub async fn mark_read(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// POST /api/v1/notifications/read
+///
+/// Mark the authenticated user's notifications matching a filter (project,
+/// event type, created-before) as read. Returns the number marked.
+pub async fn mark_read_matching(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(filter): Json<MarkReadFilter>,
+) -> AppResult<Json<serde_json::Value>> {
+    let count = NotificationRepo::mark_read_matching(&state.pool, auth.user_id, &filter).await?;
+
+    Ok(Json(serde_json::json!({
+        "data": { "marked_read": count }
+    })))
+}
+
 /// POST /api/v1/notifications/read-all
 ///
 /// Mark all of the authenticated user's notifications as read.
@@ -138,27 +154,15 @@ pub async fn get_preferences(
 /// PUT /api/v1/notifications/preferences/{event_type_id}
 ///
 /// Create or update a notification preference for a specific event type.
+/// Omitted fields keep their current value (or the default on creation).
 pub async fn update_preference(
     auth: AuthUser,
     State(state): State<AppState>,
     Path(event_type_id): Path<DbId>,
     Json(input): Json<UpdatePreference>,
 ) -> AppResult<Json<serde_json::Value>> {
-    let is_enabled = input.is_enabled.unwrap_or(true);
-    let channels = input
-        .channels
-        .unwrap_or_else(|| serde_json::json!([CHANNEL_IN_APP]));
-    let scope = input.scope.unwrap_or_else(|| "all".to_string());
-
-    let pref = NotificationPreferenceRepo::upsert(
-        &state.pool,
-        auth.user_id,
-        event_type_id,
-        is_enabled,
-        &channels,
-        &scope,
-    )
-    .await?;
+    let pref = NotificationPreferenceRepo::upsert(&state.pool, auth.user_id, event_type_id, &input)
+        .await?;
 
     Ok(Json(serde_json::json!({ "data": pref })))
 }
@@ -188,6 +192,16 @@ pub async fn update_settings(
     State(state): State<AppState>,
     Json(input): Json<UpdateNotificationSettings>,
 ) -> AppResult<Json<serde_json::Value>> {
+    if let Some(ref interval) = input.digest_interval {
+        DigestCadence::parse(interval)?;
+    }
+    if let Some(ref tz) = input.timezone {
+        parse_timezone(tz)?;
+    }
+    if let Some(hour) = input.digest_hour {
+        validate_digest_hour(i32::from(hour))?;
+    }
+
     let settings =
         NotificationPreferenceRepo::upsert_settings(&state.pool, auth.user_id, &input).await?;
 
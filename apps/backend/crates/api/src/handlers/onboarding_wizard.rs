@@ -9,14 +9,14 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use x121_core::error::CoreError;
 use x121_core::onboarding_wizard;
 use x121_core::search::{clamp_limit, clamp_offset};
 use x121_core::types::DbId;
 use x121_db::models::onboarding_session::{
-    CreateOnboardingSession, OnboardingSession, UpdateOnboardingStepData,
+    CreateOnboardingSession, OnboardingSession, OnboardingSessionEvent, UpdateOnboardingStepData,
 };
 use x121_db::repositories::OnboardingSessionRepo;
 
@@ -37,6 +37,20 @@ pub struct ListSessionsParams {
     pub offset: Option<i64>,
 }
 
+/// Pagination parameters for a session's event history.
+#[derive(Debug, Deserialize)]
+pub struct HistoryParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// One page of a session's event history, oldest first.
+#[derive(Debug, Serialize)]
+pub struct SessionHistoryPage {
+    pub items: Vec<OnboardingSessionEvent>,
+    pub total: i64,
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -97,7 +111,7 @@ pub async fn get_session(
 /// Validates the current step's data before allowing advancement.
 pub async fn advance_step(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Path(id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
     let session = ensure_session_exists(&state.pool, id).await?;
@@ -117,14 +131,20 @@ pub async fn advance_step(
     // Validate that current step data is sufficient to advance.
     onboarding_wizard::validate_step_data(current, &session.step_data)?;
 
-    let updated = OnboardingSessionRepo::update_step(&state.pool, id, next as i32)
-        .await?
-        .ok_or_else(|| {
-            AppError::Core(CoreError::NotFound {
-                entity: "OnboardingSession",
-                id,
-            })
-        })?;
+    let updated = OnboardingSessionRepo::update_step(
+        &state.pool,
+        id,
+        next as i32,
+        onboarding_wizard::OnboardingEventType::Advance,
+        auth.user_id,
+    )
+    .await?
+    .ok_or_else(|| {
+        AppError::Core(CoreError::NotFound {
+            entity: "OnboardingSession",
+            id,
+        })
+    })?;
 
     tracing::info!(
         session_id = id,
@@ -143,7 +163,7 @@ pub async fn advance_step(
 /// Go back one step in the wizard.
 pub async fn go_back(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Path(id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
     let session = ensure_session_exists(&state.pool, id).await?;
@@ -164,14 +184,20 @@ pub async fn go_back(
     let prev = current - 1;
     onboarding_wizard::validate_step_transition(current, prev)?;
 
-    let updated = OnboardingSessionRepo::update_step(&state.pool, id, prev as i32)
-        .await?
-        .ok_or_else(|| {
-            AppError::Core(CoreError::NotFound {
-                entity: "OnboardingSession",
-                id,
-            })
-        })?;
+    let updated = OnboardingSessionRepo::update_step(
+        &state.pool,
+        id,
+        prev as i32,
+        onboarding_wizard::OnboardingEventType::GoBack,
+        auth.user_id,
+    )
+    .await?
+    .ok_or_else(|| {
+        AppError::Core(CoreError::NotFound {
+            entity: "OnboardingSession",
+            id,
+        })
+    })?;
 
     tracing::info!(
         session_id = id,
@@ -190,7 +216,7 @@ pub async fn go_back(
 /// Update the step data for the current step.
 pub async fn update_step_data(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Path(id): Path<DbId>,
     Json(body): Json<UpdateOnboardingStepData>,
 ) -> AppResult<impl IntoResponse> {
@@ -202,14 +228,15 @@ pub async fn update_step_data(
         )));
     }
 
-    let updated = OnboardingSessionRepo::update_step_data(&state.pool, id, &body.step_data)
-        .await?
-        .ok_or_else(|| {
-            AppError::Core(CoreError::NotFound {
-                entity: "OnboardingSession",
-                id,
-            })
-        })?;
+    let updated =
+        OnboardingSessionRepo::update_step_data(&state.pool, id, &body.step_data, auth.user_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Core(CoreError::NotFound {
+                    entity: "OnboardingSession",
+                    id,
+                })
+            })?;
 
     tracing::info!(
         session_id = id,
@@ -220,40 +247,110 @@ pub async fn update_step_data(
     Ok(Json(DataResponse { data: updated }))
 }
 
+// ---------------------------------------------------------------------------
+// GET /onboarding-sessions/{id}/history
+// ---------------------------------------------------------------------------
+
+/// List the session's advance / go-back / step-data events in the order
+/// they happened, so support can see where a user got stuck.
+pub async fn get_history(
+    State(state): State<AppState>,
+    _auth: AuthUser,
+    Path(id): Path<DbId>,
+    Query(params): Query<HistoryParams>,
+) -> AppResult<impl IntoResponse> {
+    ensure_session_exists(&state.pool, id).await?;
+
+    let limit = clamp_limit(params.limit, 50, 200);
+    let offset = clamp_offset(params.offset);
+
+    let items = OnboardingSessionRepo::list_events(&state.pool, id, limit, offset).await?;
+    let total = OnboardingSessionRepo::count_events(&state.pool, id).await?;
+
+    Ok(Json(DataResponse {
+        data: SessionHistoryPage { items, total },
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Status transitions
+// ---------------------------------------------------------------------------
+
+/// Move a session to the terminal status `target`.
+///
+/// Repeating a transition the session already made returns it unchanged;
+/// any other move out of a terminal status is a conflict. The update is
+/// conditional on the status read here, so if another request changes the
+/// status first the transition is re-checked against the new one.
+async fn transition_session(
+    pool: &sqlx::PgPool,
+    id: DbId,
+    target: onboarding_wizard::OnboardingStatus,
+) -> AppResult<(OnboardingSession, onboarding_wizard::StatusTransition)> {
+    let session = ensure_session_exists(pool, id).await?;
+    let current = onboarding_wizard::OnboardingStatus::from_str_db(&session.status)?;
+
+    let transition = onboarding_wizard::check_status_transition(current, target)?;
+    if transition == onboarding_wizard::StatusTransition::AlreadyInState {
+        return Ok((session, transition));
+    }
+
+    if target == onboarding_wizard::OnboardingStatus::Completed {
+        onboarding_wizard::can_complete_session(session.current_step as u8)?;
+    }
+
+    let updated =
+        OnboardingSessionRepo::transition_status(pool, id, current.as_str(), target.as_str())
+            .await?;
+    match updated {
+        Some(updated) => Ok((updated, transition)),
+        None => {
+            // Lost a race with another transition: judge against its result.
+            let session = ensure_session_exists(pool, id).await?;
+            let current = onboarding_wizard::OnboardingStatus::from_str_db(&session.status)?;
+            match onboarding_wizard::check_status_transition(current, target)? {
+                onboarding_wizard::StatusTransition::AlreadyInState => {
+                    Ok((session, onboarding_wizard::StatusTransition::AlreadyInState))
+                }
+                onboarding_wizard::StatusTransition::Apply => {
+                    Err(AppError::Core(CoreError::Conflict(
+                        "Onboarding session status changed concurrently; retry".to_string(),
+                    )))
+                }
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // POST /onboarding-sessions/{id}/abandon
 // ---------------------------------------------------------------------------
 
 /// Mark an onboarding session as abandoned.
+///
+/// Idempotent: abandoning an abandoned session returns it unchanged.
+/// Abandoning a completed session is rejected with 409.
 pub async fn abandon_session(
     State(state): State<AppState>,
     auth: AuthUser,
     Path(id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
-    let session = ensure_session_exists(&state.pool, id).await?;
-
-    onboarding_wizard::can_abandon_session(&session.status)?;
-
-    let updated = OnboardingSessionRepo::update_status(
+    let (session, transition) = transition_session(
         &state.pool,
         id,
-        onboarding_wizard::OnboardingStatus::Abandoned.as_str(),
+        onboarding_wizard::OnboardingStatus::Abandoned,
     )
-    .await?
-    .ok_or_else(|| {
-        AppError::Core(CoreError::NotFound {
-            entity: "OnboardingSession",
-            id,
-        })
-    })?;
-
-    tracing::info!(
-        session_id = id,
-        user_id = auth.user_id,
-        "Onboarding session abandoned"
-    );
+    .await?;
+
+    if transition == onboarding_wizard::StatusTransition::Apply {
+        tracing::info!(
+            session_id = id,
+            user_id = auth.user_id,
+            "Onboarding session abandoned"
+        );
+    }
 
-    Ok(Json(DataResponse { data: updated }))
+    Ok(Json(DataResponse { data: session }))
 }
 
 // ---------------------------------------------------------------------------
@@ -262,43 +359,31 @@ pub async fn abandon_session(
 
 /// Mark an onboarding session as completed.
 ///
-/// Only allowed when the session is on step 6 (Summary).
+/// Only allowed when the session is on step 6 (Summary). Idempotent:
+/// completing a completed session returns it unchanged. Completing an
+/// abandoned session is rejected with 409.
 pub async fn complete_session(
     State(state): State<AppState>,
     auth: AuthUser,
     Path(id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
-    let session = ensure_session_exists(&state.pool, id).await?;
-
-    if session.status != onboarding_wizard::OnboardingStatus::InProgress.as_str() {
-        return Err(AppError::Core(CoreError::Validation(
-            "Cannot complete a session that is not in progress".to_string(),
-        )));
-    }
-
-    onboarding_wizard::can_complete_session(session.current_step as u8)?;
-
-    let updated = OnboardingSessionRepo::update_status(
+    let (session, transition) = transition_session(
         &state.pool,
         id,
-        onboarding_wizard::OnboardingStatus::Completed.as_str(),
+        onboarding_wizard::OnboardingStatus::Completed,
     )
-    .await?
-    .ok_or_else(|| {
-        AppError::Core(CoreError::NotFound {
-            entity: "OnboardingSession",
-            id,
-        })
-    })?;
-
-    tracing::info!(
-        session_id = id,
-        user_id = auth.user_id,
-        avatar_count = session.avatar_ids.len(),
-        "Onboarding session completed"
-    );
+    .await?;
+
+    if transition == onboarding_wizard::StatusTransition::Apply {
+        tracing::info!(
+            session_id = id,
+            user_id = auth.user_id,
+            avatar_count = session.avatar_ids.len(),
+            "Onboarding session completed"
+        );
+    }
 
-    Ok(Json(DataResponse { data: updated }))
+    Ok(Json(DataResponse { data: session }))
 }
 
 // ---------------------------------------------------------------------------
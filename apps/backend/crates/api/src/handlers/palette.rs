@@ -3,13 +3,15 @@
 //! Provides endpoints for palette search, recording entity access, listing
 //! recent items, and clearing recent items. All endpoints require authentication.
 
+use std::collections::HashMap;
+
 use axum::extract::{Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
 
-use x121_core::command_palette;
-use x121_db::models::recent_item::{PaletteSearchParams, RecordAccessRequest};
-use x121_db::models::search::TypeaheadResult;
+use x121_core::command_palette::{self, PaletteCandidate, PaletteItemKind};
+use x121_core::types::DbId;
+use x121_db::models::recent_item::{PaletteSearchParams, PaletteSearchResult, RecordAccessRequest};
 use x121_db::repositories::{RecentItemRepo, SearchRepo};
 
 use crate::error::AppResult;
@@ -21,12 +23,14 @@ use crate::state::AppState;
 // GET /search/palette?q=...
 // ---------------------------------------------------------------------------
 
-/// Search the command palette for matching entities and commands.
+/// Search the command palette for matching entities.
 ///
-/// Uses the full-text typeahead search from `SearchRepo` to find matching
-/// avatars, projects, and scene types by name prefix.
+/// Candidates come from the full-text typeahead search in `SearchRepo`
+/// (avatars, projects, and scene types by name prefix) and are ranked by
+/// [`command_palette::rank_palette_results`], boosted by the user's recent
+/// usage. Each result carries the matched spans of its name.
 pub async fn palette_search(
-    _auth: AuthUser,
+    auth: AuthUser,
     State(state): State<AppState>,
     Query(params): Query<PaletteSearchParams>,
 ) -> AppResult<impl IntoResponse> {
@@ -34,12 +38,53 @@ pub async fn palette_search(
 
     if query.trim().is_empty() {
         return Ok(Json(DataResponse {
-            data: Vec::<TypeaheadResult>::new(),
+            data: Vec::<PaletteSearchResult>::new(),
         }));
     }
 
     let limit = params.limit.map(|l| l as i64);
-    let results = SearchRepo::typeahead(&state.pool, &query, limit).await?;
+    let matches = SearchRepo::typeahead(&state.pool, &query, limit).await?;
+
+    let now = chrono::Utc::now();
+    let recent: HashMap<(String, DbId), f64> =
+        RecentItemRepo::get_recent(&state.pool, auth.user_id, command_palette::MAX_RECENT_ITEMS)
+            .await?
+            .into_iter()
+            .map(|item| {
+                let score = command_palette::decayed_frecency_score(
+                    item.access_count,
+                    item.last_accessed_at,
+                    now,
+                );
+                ((item.entity_type, item.entity_id), score)
+            })
+            .collect();
+
+    let candidates = matches
+        .into_iter()
+        .map(|result| PaletteCandidate {
+            kind: PaletteItemKind::Entity,
+            label: result.name.clone(),
+            recent_score: recent
+                .get(&(result.entity_type.clone(), result.entity_id))
+                .copied()
+                .unwrap_or(0.0),
+            item: result,
+        })
+        .collect();
+
+    let results: Vec<PaletteSearchResult> =
+        command_palette::rank_palette_results(&query, candidates)
+            .into_iter()
+            .map(|ranked| PaletteSearchResult {
+                kind: ranked.kind,
+                entity_type: ranked.item.entity_type,
+                entity_id: ranked.item.entity_id,
+                name: ranked.item.name,
+                score: ranked.score,
+                match_spans: ranked.match_spans,
+            })
+            .collect();
 
     tracing::debug!(query = %query, count = results.len(), "Palette search executed");
 
@@ -50,7 +95,10 @@ pub async fn palette_search(
 // GET /user/recent-items?limit=N
 // ---------------------------------------------------------------------------
 
-/// List recent items for the authenticated user, ordered by last access time.
+/// List recent items for the authenticated user, ranked by frecency.
+///
+/// The user's `MAX_RECENT_ITEMS` most recently accessed items are ranked by
+/// [`command_palette::rank_recent_items`] and the top `limit` are returned.
 pub async fn get_recent_items(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -62,7 +110,14 @@ pub async fn get_recent_items(
             .unwrap_or(command_palette::DEFAULT_RECENT_LIMIT),
     );
 
-    let items = RecentItemRepo::get_recent(&state.pool, auth.user_id, limit).await?;
+    let candidates =
+        RecentItemRepo::get_recent(&state.pool, auth.user_id, command_palette::MAX_RECENT_ITEMS)
+            .await?;
+    let items: Vec<_> = command_palette::rank_recent_items(candidates, chrono::Utc::now())
+        .into_iter()
+        .take(limit as usize)
+        .map(|scored| scored.item)
+        .collect();
 
     tracing::debug!(
         user_id = auth.user_id,
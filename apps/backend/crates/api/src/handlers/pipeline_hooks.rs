@@ -15,12 +15,13 @@ use x121_core::pipeline_hooks::{self, EffectiveHook, HookInput, HookPoint, HookT
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
 use x121_db::models::hook::{CreateHook, Hook, HookFilter, UpdateHook};
-use x121_db::models::hook_execution_log::CreateHookExecutionLog;
 use x121_db::repositories::HookExecutionLogRepo;
 use x121_db::repositories::HookRepo;
 
+use crate::engine::hook_executor::{hook_to_effective, hook_to_input, HookExecutor};
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
+use crate::middleware::rbac::RequireAdmin;
 use crate::query::PaginationParams;
 use crate::response::DataResponse;
 use crate::state::AppState;
@@ -59,30 +60,14 @@ async fn ensure_hook_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Hook> {
         .ok_or_else(|| AppError::Core(CoreError::NotFound { entity: "Hook", id }))
 }
 
-/// Convert a DB `Hook` row into a `HookInput` for the core resolver.
-fn hook_to_input(h: &Hook) -> HookInput {
-    HookInput {
-        id: h.id,
-        name: h.name.clone(),
-        hook_type: HookType::from_str(&h.hook_type).unwrap_or(HookType::Shell),
-        hook_point: HookPoint::from_str(&h.hook_point).unwrap_or(HookPoint::PostVariant),
-        scope_type: ScopeType::from_str(&h.scope_type).unwrap_or(ScopeType::Studio),
-        failure_mode: pipeline_hooks::FailureMode::from_str(&h.failure_mode)
-            .unwrap_or(pipeline_hooks::FailureMode::Warn),
-        config_json: h.config_json.clone(),
-        sort_order: h.sort_order,
-        enabled: h.enabled,
-    }
-}
-
 // ---------------------------------------------------------------------------
 // POST /hooks
 // ---------------------------------------------------------------------------
 
-/// Create a new hook.
+/// Create a new hook. Admin only: hooks run scripts and call URLs.
 pub async fn create_hook(
     State(state): State<AppState>,
-    auth: AuthUser,
+    RequireAdmin(auth): RequireAdmin,
     Json(body): Json<CreateHook>,
 ) -> AppResult<impl IntoResponse> {
     // Validate name
@@ -152,14 +137,14 @@ pub async fn get_hook(
 // PUT /hooks/{id}
 // ---------------------------------------------------------------------------
 
-/// Update an existing hook.
+/// Update an existing hook. Admin only, like [`create_hook`].
 pub async fn update_hook(
     State(state): State<AppState>,
-    auth: AuthUser,
+    RequireAdmin(auth): RequireAdmin,
     Path(id): Path<DbId>,
     Json(body): Json<UpdateHook>,
 ) -> AppResult<impl IntoResponse> {
-    ensure_hook_exists(&state.pool, id).await?;
+    let existing = ensure_hook_exists(&state.pool, id).await?;
 
     // Validate fields if provided
     if let Some(ref name) = body.name {
@@ -175,9 +160,10 @@ pub async fn update_hook(
         pipeline_hooks::validate_sort_order(order)?;
     }
 
-    // If both hook_type and config_json are provided, cross-validate
-    if let (Some(ref ht), Some(ref cfg)) = (&body.hook_type, &body.config_json) {
-        let hook_type = HookType::from_str(ht)?;
+    // Cross-validate the config against the new or existing hook type
+    if let Some(ref cfg) = body.config_json {
+        let hook_type =
+            HookType::from_str(body.hook_type.as_deref().unwrap_or(&existing.hook_type))?;
         pipeline_hooks::validate_hook_config(&hook_type, cfg)?;
     }
 
@@ -245,39 +231,39 @@ pub async fn toggle_hook(
 
 /// Test a hook with sample input data.
 ///
-/// Executes a dry-run simulation and logs the result. In a production
-/// implementation this would invoke the shell/Python/webhook; here we
-/// record a simulated execution.
+/// Executes the hook for real (subject to its timeout and the script
+/// directory and public-address restrictions) and returns the recorded
+/// execution log entry. Admin only.
 pub async fn test_hook(
     State(state): State<AppState>,
-    auth: AuthUser,
+    RequireAdmin(auth): RequireAdmin,
     Path(id): Path<DbId>,
     Json(body): Json<TestHookRequest>,
 ) -> AppResult<impl IntoResponse> {
     let hook = ensure_hook_exists(&state.pool, id).await?;
-
-    // Simulate execution (placeholder for actual hook runner integration)
-    let start = std::time::Instant::now();
-    let (success, exit_code, output, error_msg) = simulate_hook_execution(&hook);
-    let duration_ms = start.elapsed().as_millis() as i64;
-
-    let log_input = CreateHookExecutionLog {
-        hook_id: id,
-        job_id: body.job_id,
-        input_json: body.input_json,
-        output_text: Some(output),
-        exit_code: Some(exit_code),
-        duration_ms: Some(duration_ms),
-        success,
-        error_message: error_msg,
-    };
-
-    let log = HookExecutionLogRepo::create(&state.pool, &log_input).await?;
+    HookType::from_str(&hook.hook_type)?;
+
+    let effective = hook_to_effective(&hook);
+    let input = body
+        .input_json
+        .clone()
+        .unwrap_or_else(|| serde_json::json!({}));
+
+    let executor = HookExecutor::new(
+        state.pool.clone(),
+        state.config.hook_execution_concurrency,
+        state.config.hook_scripts_dir.clone(),
+    );
+    let result = executor.execute(&effective, &input).await;
+    let log = executor
+        .record(&result, body.input_json, body.job_id)
+        .await?;
 
     tracing::info!(
         hook_id = id,
-        success,
-        duration_ms,
+        success = result.success,
+        attempts = result.attempts,
+        duration_ms = result.duration_ms,
         user_id = auth.user_id,
         "Hook tested"
     );
@@ -285,44 +271,6 @@ pub async fn test_hook(
     Ok((StatusCode::CREATED, Json(DataResponse { data: log })))
 }
 
-/// Simulate a hook execution, returning (success, exit_code, output, error_message).
-fn simulate_hook_execution(hook: &Hook) -> (bool, i32, String, Option<String>) {
-    match hook.hook_type.as_str() {
-        "shell" | "python" => {
-            let script_path = hook
-                .config_json
-                .get("script_path")
-                .and_then(|v| v.as_str())
-                .unwrap_or("<unknown>");
-            (
-                true,
-                0,
-                format!("[test] Would execute {}: {}", hook.hook_type, script_path),
-                None,
-            )
-        }
-        "webhook" => {
-            let url = hook
-                .config_json
-                .get("url")
-                .and_then(|v| v.as_str())
-                .unwrap_or("<unknown>");
-            (
-                true,
-                0,
-                format!("[test] Would POST to webhook: {url}"),
-                None,
-            )
-        }
-        _ => (
-            false,
-            1,
-            String::new(),
-            Some(format!("Unknown hook type: {}", hook.hook_type)),
-        ),
-    }
-}
-
 // ---------------------------------------------------------------------------
 // GET /hooks/effective/{scope_type}/{scope_id}
 // ---------------------------------------------------------------------------
@@ -8,16 +8,19 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use x121_core::error::CoreError;
 use x121_core::preset;
 use x121_core::types::DbId;
 use x121_db::models::preset::{CreatePreset, CreatePresetRating, Preset, UpdatePreset};
+use x121_db::models::scene_type::UpdateSceneType;
 use x121_db::models::template::{CreateTemplate, Template, UpdateTemplate};
-use x121_db::repositories::{PresetRepo, TemplateRepo};
+use x121_db::repositories::{PresetRepo, SceneTypeRepo, TemplateRepo};
 
 use crate::error::{AppError, AppResult};
+use crate::handlers::scene_type::validate_scene_type_update;
+use crate::handlers::scene_type_inheritance::ensure_scene_type_exists;
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
 use crate::state::AppState;
@@ -319,42 +322,94 @@ pub async fn rate_preset(
 // GET /presets/{id}/diff/{scene_type_id}
 // ---------------------------------------------------------------------------
 
-/// Preview what fields a preset would override on a scene type's current parameters.
+/// Preview the field changes applying a preset would make to a scene type's
+/// `generation_params`.
 ///
-/// NOTE: This is a simplified implementation that compares the preset parameters
-/// against an empty set. A full implementation would fetch the scene type's
-/// current parameters from the database.
+/// Uses the same [`preset::preset_diff`] as [`apply_preset`], so the preview
+/// lists exactly the changes apply will write.
 pub async fn preview_apply(
     State(state): State<AppState>,
-    Path((id, _scene_type_id)): Path<(DbId, DbId)>,
+    Path((id, scene_type_id)): Path<(DbId, DbId)>,
 ) -> AppResult<impl IntoResponse> {
     let p = ensure_preset_exists(&state.pool, id).await?;
+    let current_params = current_generation_params(&state, scene_type_id).await?;
 
-    // Compare preset params against an empty baseline (placeholder for
-    // real scene-type parameter lookup in a future iteration).
-    let current_params = serde_json::Value::Object(serde_json::Map::new());
-    let diffs = preset::compute_override_diff(&current_params, &p.parameters);
+    let changes = preset::preset_diff(&p.parameters, &current_params);
 
-    Ok(Json(DataResponse { data: diffs }))
+    Ok(Json(DataResponse { data: changes }))
 }
 
 // ---------------------------------------------------------------------------
 // POST /presets/{id}/apply/{scene_type_id}
 // ---------------------------------------------------------------------------
 
-/// Apply a preset to a scene type, incrementing the preset's usage counter.
+/// Result of applying a preset: the changes written and the scene type's
+/// resulting `generation_params`.
+#[derive(Debug, Serialize)]
+pub struct PresetApplyResult {
+    pub applied: Vec<preset::FieldChange>,
+    pub generation_params: serde_json::Value,
+}
+
+/// Apply a preset to a scene type's `generation_params`, incrementing the
+/// preset's usage counter.
 ///
-/// NOTE: This is a simplified implementation that increments usage and returns
-/// the preset parameters. A full implementation would merge the preset
-/// parameters into the scene type configuration.
+/// Writes exactly the changes [`preview_apply`] reports. The resulting config
+/// is checked with the same validator as a scene type update before anything
+/// is written, and the params and usage counter are updated in one
+/// transaction, so a rejected preset leaves the scene type untouched.
 pub async fn apply_preset(
     State(state): State<AppState>,
-    Path((id, _scene_type_id)): Path<(DbId, DbId)>,
+    Path((id, scene_type_id)): Path<(DbId, DbId)>,
 ) -> AppResult<impl IntoResponse> {
     let p = ensure_preset_exists(&state.pool, id).await?;
+    let existing = ensure_scene_type_exists(&state.pool, scene_type_id).await?;
+
+    let mut tx = state.pool.begin().await?;
+    let current_params = SceneTypeRepo::lock_generation_params(&mut tx, scene_type_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "SceneType",
+            id: scene_type_id,
+        }))?
+        .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+
+    let changes = preset::preset_diff(&p.parameters, &current_params);
+    let new_params = preset::apply_field_changes(&current_params, &changes);
+
+    if !changes.is_empty() {
+        let update = UpdateSceneType {
+            generation_params: Some(new_params.clone()),
+            ..Default::default()
+        };
+        validate_scene_type_update(&state, &existing, &update).await?;
+        SceneTypeRepo::set_generation_params(&mut tx, scene_type_id, &new_params).await?;
+    }
 
-    PresetRepo::increment_usage(&state.pool, id).await?;
-    tracing::info!(preset_id = id, "Preset applied");
+    PresetRepo::increment_usage_in_tx(&mut tx, id).await?;
+    tx.commit().await?;
+    tracing::info!(
+        preset_id = id,
+        scene_type_id,
+        changed_fields = changes.len(),
+        "Preset applied"
+    );
+
+    Ok(Json(DataResponse {
+        data: PresetApplyResult {
+            applied: changes,
+            generation_params: new_params,
+        },
+    }))
+}
 
-    Ok(Json(DataResponse { data: p.parameters }))
+/// Load a scene type's `generation_params`, treating unset as an empty object.
+async fn current_generation_params(
+    state: &AppState,
+    scene_type_id: DbId,
+) -> AppResult<serde_json::Value> {
+    let scene_type = ensure_scene_type_exists(&state.pool, scene_type_id).await?;
+    Ok(scene_type
+        .generation_params
+        .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new())))
 }
@@ -10,7 +10,7 @@ use axum::Json;
 
 use x121_core::error::CoreError;
 use x121_core::production_notes::{
-    validate_entity_type, validate_note_content, validate_visibility,
+    build_thread, validate_entity_type, validate_note_content, validate_visibility,
 };
 use x121_core::types::DbId;
 use x121_db::models::note_category::{CreateNoteCategory, UpdateNoteCategory};
@@ -257,28 +257,29 @@ pub async fn unresolve_note(
     Ok(Json(DataResponse { data: note }))
 }
 
-/// GET /notes/search?q=&entity_type=
+/// GET /notes/search?q=&entity_type=&entity_id=&category_id=&user_id=&resolved=&limit=&offset=
 ///
-/// Search notes by content.
+/// Search notes by content and/or filters. With `q`, results are ranked by
+/// relevance and carry a match snippet; otherwise they are newest first.
 pub async fn search_notes(
     _auth: AuthUser,
     State(state): State<AppState>,
-    Query(params): Query<NoteSearchParams>,
+    Query(mut params): Query<NoteSearchParams>,
 ) -> AppResult<impl IntoResponse> {
-    if params.q.is_empty() {
+    params.q = params
+        .q
+        .map(|q| q.trim().to_string())
+        .filter(|q| !q.is_empty());
+
+    if let Some(ref entity_type) = params.entity_type {
+        validate_entity_type(entity_type).map_err(AppError::BadRequest)?;
+    } else if params.entity_id.is_some() {
         return Err(AppError::BadRequest(
-            "Search query 'q' is required".to_string(),
+            "entity_id requires entity_type".to_string(),
         ));
     }
 
-    let notes = ProductionNoteRepo::search(
-        &state.pool,
-        &params.q,
-        params.entity_type.as_deref(),
-        None,
-        None,
-    )
-    .await?;
+    let notes = ProductionNoteRepo::search(&state.pool, &params).await?;
 
     Ok(Json(DataResponse { data: notes }))
 }
@@ -301,14 +302,24 @@ pub async fn list_pinned(
 
 /// GET /notes/{id}/thread
 ///
-/// List replies to a note.
+/// Return the note and its replies at every depth as a tree, siblings
+/// oldest first. Replies whose parent has been deleted are grouped under a
+/// tombstone node.
 pub async fn list_thread(
     _auth: AuthUser,
     State(state): State<AppState>,
     Path(id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
-    let replies = ProductionNoteRepo::list_thread(&state.pool, id).await?;
-    Ok(Json(DataResponse { data: replies }))
+    let notes = ProductionNoteRepo::list_thread(&state.pool, id).await?;
+    if notes.is_empty() {
+        return Err(AppError::Core(CoreError::NotFound {
+            entity: "ProductionNote",
+            id,
+        }));
+    }
+    Ok(Json(DataResponse {
+        data: build_thread(id, notes),
+    }))
 }
 
 // ---------------------------------------------------------------------------
@@ -4,6 +4,8 @@
 //! the matrix state, submitting cells, resubmitting failures, triggering
 //! delivery, and tracking aggregate progress.
 
+use std::collections::HashSet;
+
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
@@ -12,8 +14,8 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use x121_core::batch_production::{
-    self, MatrixConfig, RUN_STATUS_ID_COMPLETED, RUN_STATUS_ID_DRAFT, RUN_STATUS_ID_FAILED,
-    RUN_STATUS_ID_SUBMITTING,
+    self, MatrixCellKey, MatrixConfig, MatrixFilters, RUN_STATUS_ID_COMPLETED, RUN_STATUS_ID_DRAFT,
+    RUN_STATUS_ID_FAILED, RUN_STATUS_ID_SUBMITTING,
 };
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset};
@@ -65,7 +67,9 @@ async fn ensure_run_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Productio
 
 /// Create a new production run with the specified matrix configuration.
 ///
-/// Generates all cells (avatar x scene_type combinations) and inserts them.
+/// Expands the avatar x scene_type x track matrix with
+/// [`batch_production::expand_matrix`], keeping each avatar's enabled scene
+/// settings minus any `excluded_cells`, and inserts the resulting cells.
 pub async fn create_run(
     State(state): State<AppState>,
     auth: AuthUser,
@@ -75,16 +79,14 @@ pub async fn create_run(
     let config = MatrixConfig {
         avatar_ids: body.avatar_ids.clone(),
         scene_type_ids: body.scene_type_ids.clone(),
+        excluded_cells: body.excluded_cells.clone(),
     };
     batch_production::validate_matrix_config(&config)?;
 
-    // Build the set of requested scene_type_ids for filtering.
-    let requested_scene_types: std::collections::HashSet<DbId> =
-        body.scene_type_ids.iter().copied().collect();
-
     // For each avatar, resolve the enabled (scene_type, track) pairs via the
-    // four-level inheritance chain, then filter to requested scene_type_ids.
-    let mut cells: Vec<CreateProductionRunCell> = Vec::new();
+    // four-level inheritance chain; only those may become cells.
+    let mut allowed = HashSet::new();
+    let mut track_ids = Vec::new();
     for &cid in &body.avatar_ids {
         let settings = AvatarSceneOverrideRepo::list_effective(
             &state.pool,
@@ -94,33 +96,35 @@ pub async fn create_run(
         )
         .await?;
 
-        for s in settings {
-            if !s.is_enabled {
-                continue;
-            }
-            if !requested_scene_types.contains(&s.scene_type_id) {
-                continue;
-            }
-            cells.push(CreateProductionRunCell {
-                run_id: 0, // placeholder — set after run creation
+        for s in settings.into_iter().filter(|s| s.is_enabled) {
+            track_ids.push(s.track_id);
+            allowed.insert(MatrixCellKey {
                 avatar_id: cid,
                 scene_type_id: s.scene_type_id,
                 track_id: s.track_id,
-                variant_label: "default".to_string(),
             });
         }
     }
 
-    // Deduplicate: one cell per (avatar_id, scene_type_id, track_id).
-    cells.sort_by(|a, b| {
-        a.avatar_id
-            .cmp(&b.avatar_id)
-            .then(a.scene_type_id.cmp(&b.scene_type_id))
-            .then(a.track_id.cmp(&b.track_id))
-    });
-    cells.dedup_by(|a, b| {
-        a.avatar_id == b.avatar_id && a.scene_type_id == b.scene_type_id && a.track_id == b.track_id
-    });
+    let filters = MatrixFilters {
+        allowed: Some(allowed),
+        excluded: config.excluded_cells.iter().copied().collect(),
+    };
+    let mut cells: Vec<CreateProductionRunCell> = batch_production::expand_matrix(
+        &config.avatar_ids,
+        &config.scene_type_ids,
+        &track_ids,
+        &filters,
+    )
+    .into_iter()
+    .map(|cell| CreateProductionRunCell {
+        run_id: 0, // placeholder — set after run creation
+        avatar_id: cell.avatar_id,
+        scene_type_id: cell.scene_type_id,
+        track_id: cell.track_id,
+        variant_label: "default".to_string(),
+    })
+    .collect();
 
     let total_cells = cells.len() as i32;
 
@@ -7,6 +7,7 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
+use x121_core::proficiency::{self, FocusMode, ProficiencyLevel};
 use x121_db::models::proficiency::{SetFocusMode, SetProficiency};
 use x121_db::repositories::ProficiencyRepo;
 
@@ -128,3 +129,35 @@ pub async fn set_focus_mode(
 
     Ok(Json(DataResponse { data: pref }))
 }
+
+// ---------------------------------------------------------------------------
+// Feature visibility
+// ---------------------------------------------------------------------------
+
+/// GET /api/v1/user/proficiency/visibility
+///
+/// Resolve which UI features the authenticated user should see, combining
+/// their per-area proficiency with their current focus mode. Unrecognised
+/// stored levels count as beginner and an unrecognised focus mode as none.
+pub async fn get_feature_visibility(
+    RequireAuth(user): RequireAuth,
+    State(state): State<AppState>,
+) -> AppResult<impl IntoResponse> {
+    let levels = ProficiencyRepo::get_all_proficiency(&state.pool, user.user_id)
+        .await?
+        .into_iter()
+        .filter_map(|p| {
+            let level = ProficiencyLevel::parse(&p.proficiency_level).ok()?;
+            Some((p.feature_area, level))
+        })
+        .collect();
+    let focus_mode = ProficiencyRepo::get_focus_preference(&state.pool, user.user_id)
+        .await?
+        .and_then(|p| p.focus_mode)
+        .and_then(|m| FocusMode::parse(&m).ok());
+
+    let visibility =
+        proficiency::resolve_feature_visibility(&levels, focus_mode, proficiency::FEATURE_DEFS);
+
+    Ok(Json(DataResponse { data: visibility }))
+}
@@ -3,6 +3,8 @@
 //! Provides endpoints for saving/listing/diffing/restoring prompt versions,
 //! and for browsing/creating/updating/deleting/rating prompt library entries.
 
+use std::collections::HashMap;
+
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
@@ -11,11 +13,11 @@ use axum::Json;
 use serde::Deserialize;
 
 use x121_core::error::CoreError;
-use x121_core::prompt_editor::{self, compute_diff};
+use x121_core::prompt_editor::{self, compute_diff, LibrarySort, RankableEntry};
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
 use x121_db::models::prompt_library_entry::{
-    CreateLibraryEntry, RateLibraryEntryRequest, UpdateLibraryEntry,
+    CreateLibraryEntry, PromptLibraryEntry, RateLibraryEntryRequest, UpdateLibraryEntry,
 };
 use x121_db::models::prompt_version::{CreatePromptVersion, PromptVersion};
 use x121_db::repositories::PromptLibraryRepo;
@@ -41,6 +43,9 @@ pub struct VersionListParams {
 #[derive(Debug, Deserialize)]
 pub struct LibraryListParams {
     pub search: Option<String>,
+    /// Ranked ordering: `top`, `recent`, or `trending`. Without it entries
+    /// are ordered by usage count.
+    pub sort: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
@@ -236,14 +241,50 @@ pub async fn list_library(
     let limit = clamp_limit(params.limit, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT);
     let offset = clamp_offset(params.offset);
 
-    let entries =
-        PromptLibraryRepo::list(&state.pool, params.search.as_deref(), limit, offset).await?;
+    let entries = match params.sort.as_deref() {
+        Some(sort) => {
+            let sort: LibrarySort = sort.parse()?;
+            let all = PromptLibraryRepo::list_all(&state.pool, params.search.as_deref()).await?;
+            rank_library_page(all, sort, limit, offset)
+        }
+        None => {
+            PromptLibraryRepo::list(&state.pool, params.search.as_deref(), limit, offset).await?
+        }
+    };
 
     tracing::debug!(count = entries.len(), "Listed prompt library entries");
 
     Ok(Json(DataResponse { data: entries }))
 }
 
+/// Order entries with the core ranking and return one page.
+fn rank_library_page(
+    entries: Vec<PromptLibraryEntry>,
+    sort: LibrarySort,
+    limit: i64,
+    offset: i64,
+) -> Vec<PromptLibraryEntry> {
+    let rankable: Vec<RankableEntry> = entries
+        .iter()
+        .map(|e| RankableEntry {
+            id: e.id,
+            avg_rating: e.avg_rating,
+            rating_count: e.rating_count,
+            created_at: e.created_at,
+        })
+        .collect();
+    let ranked = prompt_editor::rank_prompt_entries(&rankable, sort, chrono::Utc::now());
+
+    let mut by_id: HashMap<DbId, PromptLibraryEntry> =
+        entries.into_iter().map(|e| (e.id, e)).collect();
+    ranked
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .filter_map(|scored| by_id.remove(&scored.id))
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // POST /prompt-library
 // ---------------------------------------------------------------------------
@@ -372,9 +413,7 @@ pub async fn rate_library_entry(
     // Ensure the entry exists.
     ensure_library_entry_exists(&state.pool, id).await?;
 
-    // For simplicity, set the rating directly (a production system would
-    // maintain a separate ratings table and compute the average).
-    PromptLibraryRepo::update_rating(&state.pool, id, body.rating).await?;
+    PromptLibraryRepo::record_rating(&state.pool, id, body.rating).await?;
     PromptLibraryRepo::increment_usage(&state.pool, id).await?;
 
     let updated = ensure_library_entry_exists(&state.pool, id).await?;
@@ -6,12 +6,15 @@
 use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use axum::Json;
-use x121_core::quality_gate;
+use serde::Serialize;
+use x121_core::error::CoreError;
+use x121_core::quality_gate::{self, GateDecision, GatePolicy};
 use x121_core::types::DbId;
 use x121_db::models::qa_threshold::CreateQaThreshold;
-use x121_db::repositories::{QaThresholdRepo, QualityScoreRepo};
+use x121_db::models::quality_score::SceneQaSummary;
+use x121_db::repositories::{AvatarRepo, QaThresholdRepo, QualityScoreRepo, SceneRepo};
 
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
 use crate::state::AppState;
@@ -35,15 +38,60 @@ pub async fn get_segment_scores(
 // Scene QA summary
 // ---------------------------------------------------------------------------
 
+/// Scene QA summary together with the gate decision for its scores.
+#[derive(Debug, Serialize)]
+pub struct SceneQaSummaryResponse {
+    #[serde(flatten)]
+    pub summary: SceneQaSummary,
+    pub gate: GateDecision,
+}
+
 /// GET /api/v1/scenes/{scene_id}/qa-summary
 ///
-/// Returns an aggregated QA summary across all segments in the scene.
+/// Returns an aggregated QA summary across all segments in the scene, plus
+/// whether the scene's scores clear the project's effective thresholds.
 pub async fn get_scene_qa_summary(
     State(state): State<AppState>,
     Path(scene_id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
     let summary = QualityScoreRepo::summary_by_scene(&state.pool, scene_id).await?;
-    Ok(Json(DataResponse { data: summary }))
+    let gate = evaluate_scene_gate(&state, scene_id, &GatePolicy::AllMustPass).await?;
+    Ok(Json(DataResponse {
+        data: SceneQaSummaryResponse { summary, gate },
+    }))
+}
+
+/// Evaluate a scene's QA scores against its project's effective thresholds.
+pub async fn evaluate_scene_gate(
+    state: &AppState,
+    scene_id: DbId,
+    policy: &GatePolicy,
+) -> AppResult<GateDecision> {
+    let scene = SceneRepo::find_by_id(&state.pool, scene_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Scene",
+            id: scene_id,
+        }))?;
+    let avatar = AvatarRepo::find_by_id(&state.pool, scene.avatar_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Avatar",
+            id: scene.avatar_id,
+        }))?;
+
+    let scores: Vec<_> = QualityScoreRepo::find_by_scene(&state.pool, scene_id)
+        .await?
+        .iter()
+        .map(|s| s.to_spec())
+        .collect();
+    let thresholds: Vec<_> = QaThresholdRepo::list_for_project(&state.pool, avatar.project_id)
+        .await?
+        .iter()
+        .map(|t| t.to_spec())
+        .collect();
+
+    Ok(quality_gate::evaluate_gate(&scores, &thresholds, policy))
 }
 
 // ---------------------------------------------------------------------------
@@ -6,6 +6,10 @@ use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use axum::Json;
 use serde::{Deserialize, Serialize};
+use x121_core::digest_schedule::parse_timezone;
+use x121_core::scheduling::{
+    place_in_queue, validate_queue_snapshot, QueuePlacement, MIN_QUEUE_RANK_GAP,
+};
 use x121_core::types::DbId;
 use x121_db::models::job::QueuedJobView;
 use x121_db::models::scheduling::{SetGpuQuota, UpsertSchedulingPolicy};
@@ -32,10 +36,17 @@ pub struct QueueStatusResponse {
 }
 
 /// Request body for PUT /admin/queue/reorder.
+///
+/// Give exactly one of `position` (move the job to that zero-based index in
+/// the queue) or `new_priority` (set its priority directly).
 #[derive(Debug, Deserialize)]
 pub struct ReorderRequest {
     pub job_id: DbId,
-    pub new_priority: i32,
+    pub position: Option<usize>,
+    pub new_priority: Option<i32>,
+    /// Queued job ids in the order the caller last saw them. Required with
+    /// `position`; the reorder is rejected if the queue has changed since.
+    pub expected_order: Option<Vec<DbId>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -80,24 +91,73 @@ pub async fn get_queue_status(
 
 /// PUT /api/v1/admin/queue/reorder
 ///
-/// Change a job's priority (admin only). Takes effect on next scheduler tick.
+/// Move a queued job to a new position, or change its priority (admin only).
+/// The reorder runs in one transaction against a locked queue snapshot and
+/// rewrites only the moved job (plus, rarely, its priority band when ranks
+/// need re-spacing). Returns the new queue order; takes effect on the next
+/// scheduler tick.
 pub async fn reorder_job(
     RequireAdmin(admin): RequireAdmin,
     State(state): State<AppState>,
     Json(input): Json<ReorderRequest>,
 ) -> AppResult<impl IntoResponse> {
-    let job = JobRepo::update_priority(&state.pool, input.job_id, input.new_priority)
-        .await
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let job_id = input.job_id;
+    let mut tx = state.pool.begin().await?;
+
+    let mut queue = JobRepo::lock_queue(&mut tx).await?;
+    if let Some(expected) = &input.expected_order {
+        validate_queue_snapshot(&queue, expected)?;
+    }
+
+    let (priority, rank) = match (input.position, input.new_priority) {
+        (Some(position), None) => {
+            if input.expected_order.is_none() {
+                return Err(AppError::BadRequest(
+                    "expected_order is required with position".into(),
+                ));
+            }
+            let mut placement = place_in_queue(&queue, job_id, position)?;
+            if let QueuePlacement::RenumberBand(band) = placement {
+                JobRepo::renumber_queue_band(&mut tx, band, MIN_QUEUE_RANK_GAP).await?;
+                queue = JobRepo::lock_queue(&mut tx).await?;
+                placement = place_in_queue(&queue, job_id, position)?;
+            }
+            match placement {
+                QueuePlacement::At { priority, rank } => (priority, rank),
+                QueuePlacement::RenumberBand(band) => {
+                    return Err(AppError::InternalError(format!(
+                        "No queue rank available in priority band {band} after renumbering"
+                    )));
+                }
+            }
+        }
+        (None, Some(new_priority)) => {
+            let slot = queue
+                .iter()
+                .find(|s| s.job_id == job_id)
+                .ok_or_else(|| AppError::BadRequest(format!("Job {job_id} is not queued")))?;
+            (new_priority, slot.rank)
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "Provide exactly one of position or new_priority".into(),
+            ));
+        }
+    };
+
+    JobRepo::set_queue_placement(&mut tx, job_id, priority, rank).await?;
+    let jobs = JobRepo::list_queue(&mut *tx).await?;
+    tx.commit().await?;
 
     tracing::info!(
-        job_id = input.job_id,
-        new_priority = input.new_priority,
+        job_id,
+        position = ?input.position,
+        priority,
         admin_id = admin.user_id,
-        "Job priority updated by admin",
+        "Job reordered by admin",
     );
 
-    Ok(Json(DataResponse { data: job }))
+    Ok(Json(DataResponse { data: jobs }))
 }
 
 // ---------------------------------------------------------------------------
@@ -106,13 +166,18 @@ pub async fn reorder_job(
 
 /// PUT /api/v1/admin/users/{id}/quota
 ///
-/// Set or update a user's GPU time quota. Admin only.
+/// Set or update a user's GPU time quota. Admin only. Daily and weekly
+/// usage resets at local midnight in the quota's `timezone`.
 pub async fn set_user_quota(
     RequireAdmin(admin): RequireAdmin,
     State(state): State<AppState>,
     Path(user_id): Path<DbId>,
     Json(input): Json<SetGpuQuota>,
 ) -> AppResult<impl IntoResponse> {
+    if let Some(tz) = &input.timezone {
+        parse_timezone(tz)?;
+    }
+
     let quota = GpuQuotaRepo::set_user_quota(&state.pool, user_id, &input).await?;
 
     tracing::info!(
@@ -120,6 +185,7 @@ pub async fn set_user_quota(
         admin_id = admin.user_id,
         daily_limit = ?input.daily_limit_secs,
         weekly_limit = ?input.weekly_limit_secs,
+        timezone = ?input.timezone,
         "User GPU quota updated by admin",
     );
 
@@ -132,7 +198,8 @@ pub async fn set_user_quota(
 
 /// GET /api/v1/quota/status
 ///
-/// Get the current user's GPU quota usage.
+/// Get the current user's GPU quota usage, including when the daily and
+/// weekly windows next reset.
 pub async fn get_quota_status(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -1,7 +1,8 @@
 //! Handlers for the avatar readiness system (PRD-107).
 //!
 //! Provides endpoints for computing readiness, managing readiness criteria,
-//! and querying the readiness cache.
+//! previewing the effective criteria for an avatar, and querying the
+//! readiness cache.
 
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
@@ -12,7 +13,7 @@ use x121_core::error::CoreError;
 use x121_core::readiness::{self, validate_criteria_json, validate_scope_type};
 use x121_core::types::DbId;
 use x121_db::models::readiness_criteria::{CreateReadinessCriteria, UpdateReadinessCriteria};
-use x121_db::repositories::{ReadinessCacheRepo, ReadinessCriteriaRepo};
+use x121_db::repositories::{AvatarRepo, ReadinessCacheRepo, ReadinessCriteriaRepo};
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
@@ -123,6 +124,43 @@ pub async fn batch_evaluate(
     Ok(Json(DataResponse { data: existing }))
 }
 
+/// GET /avatars/{avatar_id}/readiness-criteria
+///
+/// Resolve the readiness criteria that apply to an avatar after
+/// studio -> project inheritance, with the source scope of each field.
+pub async fn get_effective_criteria(
+    _auth: AuthUser,
+    State(state): State<AppState>,
+    Path(avatar_id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    let avatar = AvatarRepo::find_by_id(&state.pool, avatar_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Avatar",
+            id: avatar_id,
+        }))?;
+
+    let studio = ReadinessCriteriaRepo::find_studio_default(&state.pool)
+        .await?
+        .map(|row| readiness::parse_scoped_criteria_json(&row.criteria_json))
+        .transpose()
+        .map_err(AppError::InternalError)?;
+    let project = ReadinessCriteriaRepo::find_by_scope(
+        &state.pool,
+        readiness::SCOPE_PROJECT,
+        Some(avatar.project_id),
+    )
+    .await?
+    .map(|row| readiness::parse_scoped_criteria_json(&row.criteria_json))
+    .transpose()
+    .map_err(AppError::InternalError)?;
+
+    let effective =
+        readiness::resolve_effective_criteria_with_sources(studio.as_ref(), project.as_ref());
+
+    Ok(Json(DataResponse { data: effective }))
+}
+
 // ---------------------------------------------------------------------------
 // Library Readiness Summary
 // ---------------------------------------------------------------------------
@@ -10,6 +10,7 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
 use x121_core::error::CoreError;
+use x121_core::reclamation::protection::ProtectionRule;
 use x121_core::reclamation::types::{CleanupReport, ProjectReclamationSummary, ReclamationPreview};
 use x121_core::types::DbId;
 use x121_db::models::reclamation::{
@@ -403,6 +404,13 @@ pub async fn create_protection_rule(
     RequireAdmin(_admin): RequireAdmin,
     Json(input): Json<CreateProtectionRule>,
 ) -> AppResult<impl IntoResponse> {
+    validate_protection_rule(
+        &input.name,
+        &input.entity_type,
+        &input.condition_field,
+        &input.condition_operator,
+        &input.condition_value,
+    )?;
     let rule = ReclamationRepo::create_protection_rule(&state.pool, &input).await?;
     Ok((StatusCode::CREATED, Json(DataResponse { data: rule })))
 }
@@ -416,15 +424,62 @@ pub async fn update_protection_rule(
     Path(id): Path<DbId>,
     Json(input): Json<UpdateProtectionRule>,
 ) -> AppResult<impl IntoResponse> {
-    let rule = ReclamationRepo::update_protection_rule(&state.pool, id, &input)
-        .await?
-        .ok_or(AppError::Core(CoreError::NotFound {
+    let not_found = || {
+        AppError::Core(CoreError::NotFound {
             entity: "AssetProtectionRule",
             id,
-        }))?;
+        })
+    };
+    let existing = ReclamationRepo::find_protection_rule(&state.pool, id)
+        .await?
+        .ok_or_else(not_found)?;
+
+    // Validate the rule as it will look after the partial update.
+    validate_protection_rule(
+        input.name.as_deref().unwrap_or(&existing.name),
+        input
+            .entity_type
+            .as_deref()
+            .unwrap_or(&existing.entity_type),
+        input
+            .condition_field
+            .as_deref()
+            .unwrap_or(&existing.condition_field),
+        input
+            .condition_operator
+            .as_deref()
+            .unwrap_or(&existing.condition_operator),
+        input
+            .condition_value
+            .as_deref()
+            .unwrap_or(&existing.condition_value),
+    )?;
+
+    let rule = ReclamationRepo::update_protection_rule(&state.pool, id, &input)
+        .await?
+        .ok_or_else(not_found)?;
     Ok(Json(DataResponse { data: rule }))
 }
 
+/// Reject malformed protection rules with a 400 listing every problem.
+fn validate_protection_rule(
+    name: &str,
+    entity_type: &str,
+    condition_field: &str,
+    condition_operator: &str,
+    condition_value: &str,
+) -> AppResult<()> {
+    ProtectionRule::new(
+        name,
+        entity_type,
+        condition_field,
+        condition_operator,
+        condition_value,
+    )
+    .map(|_| ())
+    .map_err(|e| AppError::BadRequest(e.to_string()))
+}
+
 /// DELETE /api/v1/admin/reclamation/protection-rules/{id}
 ///
 /// Delete a protection rule. Returns 204 on success, 404 if not found.
@@ -9,10 +9,12 @@ use axum::Json;
 
 use x121_core::error::CoreError;
 use x121_core::resolution::{
-    can_upscale, validate_dimensions, validate_speed_factor, validate_tier_name,
+    plan_batch_upscale_item, plan_upscale, prepare_batch_upscale, validate_dimensions,
+    validate_speed_factor, validate_tier_name, TierSpec,
 };
 use x121_core::types::DbId;
 use x121_db::models::resolution_tier::{
+    BatchUpscaleItem, BatchUpscaleOutcome, BatchUpscaleRequest, BatchUpscaleResponse,
     CreateResolutionTier, ResolutionTier, UpscaleRequest, UpscaleResponse,
 };
 use x121_db::models::scene::Scene;
@@ -49,6 +51,29 @@ async fn ensure_tier_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Resoluti
         })
 }
 
+/// Convert tier rows into the specs used by the upscale planner.
+fn tier_specs(tiers: &[ResolutionTier]) -> Vec<TierSpec> {
+    tiers
+        .iter()
+        .map(|t| TierSpec {
+            id: t.id,
+            name: t.name.clone(),
+            width: t.width,
+            height: t.height,
+            sort_order: t.sort_order,
+        })
+        .collect()
+}
+
+/// Move a scene to its target tier and record the upscale provenance.
+async fn apply_upscale(pool: &sqlx::PgPool, scene_id: DbId, target_tier_id: DbId) -> AppResult<()> {
+    ResolutionTierRepo::update_scene_tier(pool, scene_id, target_tier_id).await?;
+
+    // Set provenance link (the scene was upscaled from itself).
+    ResolutionTierRepo::set_upscaled_from(pool, scene_id, scene_id).await?;
+    Ok(())
+}
+
 /* --------------------------------------------------------------------------
 Resolution tier CRUD
 -------------------------------------------------------------------------- */
@@ -110,7 +135,8 @@ Scene resolution endpoints
 
 /// POST /scenes/{id}/upscale
 ///
-/// Upscale a scene to a higher resolution tier. Validates tier ordering.
+/// Upscale a scene to a higher resolution tier, stepping through every tier
+/// in between. Requesting the scene's current tier is a no-op.
 pub async fn upscale_scene(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -121,34 +147,116 @@ pub async fn upscale_scene(
 
     let current_tier_id = scene.resolution_tier_id.unwrap_or(1);
 
-    // CoreError auto-converts to AppError via #[from] (DRY-275).
-    can_upscale(current_tier_id, input.target_tier_id)?;
-
-    let target_tier = ensure_tier_exists(&state.pool, input.target_tier_id).await?;
+    let tiers = ResolutionTierRepo::list_all(&state.pool).await?;
 
-    // Update the scene's resolution tier.
-    ResolutionTierRepo::update_scene_tier(&state.pool, scene_id, input.target_tier_id).await?;
+    // CoreError auto-converts to AppError via #[from] (DRY-275).
+    let plan = plan_upscale(current_tier_id, input.target_tier_id, &tier_specs(&tiers))?;
+    let target_tier = tiers
+        .iter()
+        .find(|t| t.id == input.target_tier_id)
+        .map(|t| t.name.clone())
+        .unwrap_or_default();
 
-    // Set provenance link (the scene was upscaled from itself).
-    ResolutionTierRepo::set_upscaled_from(&state.pool, scene_id, scene_id).await?;
+    if !plan.is_noop() {
+        apply_upscale(&state.pool, scene_id, input.target_tier_id).await?;
 
-    tracing::info!(
-        user_id = auth.user_id,
-        scene_id = scene_id,
-        from_tier = current_tier_id,
-        to_tier = input.target_tier_id,
-        "Scene upscaled"
-    );
+        tracing::info!(
+            user_id = auth.user_id,
+            scene_id = scene_id,
+            from_tier = current_tier_id,
+            to_tier = input.target_tier_id,
+            steps = plan.steps.len(),
+            "Scene upscaled"
+        );
+    }
 
     let response = UpscaleResponse {
         original_scene_id: scene_id,
         new_scene_id: scene_id,
-        target_tier: target_tier.name,
+        target_tier,
+        plan,
     };
 
     Ok(Json(DataResponse { data: response }))
 }
 
+/// POST /scenes/batch-upscale
+///
+/// Upscale several scenes to the same tier. Each scene is planned on its
+/// own; scenes that cannot be upscaled (missing, already at the target, or
+/// above it) are rejected with a reason while the rest are applied.
+pub async fn batch_upscale(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(input): Json<BatchUpscaleRequest>,
+) -> AppResult<impl IntoResponse> {
+    let scene_ids = prepare_batch_upscale(&input.scene_ids)?;
+    let target_tier = ensure_tier_exists(&state.pool, input.target_tier_id).await?;
+    let specs = tier_specs(&ResolutionTierRepo::list_all(&state.pool).await?);
+
+    let mut results = Vec::with_capacity(scene_ids.len());
+    for scene_id in scene_ids {
+        let outcome = match SceneRepo::find_by_id(&state.pool, scene_id).await? {
+            None => Err("Scene not found".to_string()),
+            Some(scene) => plan_batch_upscale_item(
+                scene.resolution_tier_id.unwrap_or(1),
+                input.target_tier_id,
+                &specs,
+            ),
+        };
+
+        let outcome = match outcome {
+            Ok(plan) => match apply_upscale(&state.pool, scene_id, input.target_tier_id).await {
+                Ok(()) => Ok(plan),
+                Err(e) => {
+                    tracing::warn!(scene_id, error = %e, "Batch upscale failed to apply");
+                    Err("Failed to apply upscale".to_string())
+                }
+            },
+            Err(reason) => Err(reason),
+        };
+
+        results.push(match outcome {
+            Ok(plan) => BatchUpscaleItem {
+                scene_id,
+                outcome: BatchUpscaleOutcome::Accepted,
+                reason: None,
+                plan: Some(plan),
+            },
+            Err(reason) => BatchUpscaleItem {
+                scene_id,
+                outcome: BatchUpscaleOutcome::Rejected,
+                reason: Some(reason),
+                plan: None,
+            },
+        });
+    }
+
+    let accepted = results
+        .iter()
+        .filter(|r| r.outcome == BatchUpscaleOutcome::Accepted)
+        .count();
+    let rejected = results.len() - accepted;
+
+    tracing::info!(
+        user_id = auth.user_id,
+        target_tier_id = input.target_tier_id,
+        accepted,
+        rejected,
+        "Batch scene upscale completed"
+    );
+
+    Ok(Json(DataResponse {
+        data: BatchUpscaleResponse {
+            target_tier_id: target_tier.id,
+            target_tier: target_tier.name,
+            accepted,
+            rejected,
+            results,
+        },
+    }))
+}
+
 /// GET /scenes/{id}/tier
 ///
 /// Return the current resolution tier for a scene.
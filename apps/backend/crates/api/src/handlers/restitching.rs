@@ -2,20 +2,25 @@
 //!
 //! Provides endpoints for regenerating individual segments, checking boundary
 //! consistency (SSIM), applying boundary smoothing, listing segment versions,
-//! and clearing stale flags.
+//! clearing stale flags, and cancellable scene-wide re-stitching.
 
 use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use x121_core::error::CoreError;
 use x121_core::restitching;
-use x121_core::types::DbId;
+use x121_core::types::{DbId, RunId};
+use x121_db::models::segment::{CreateSegment, Segment};
 use x121_db::models::segment_version::{
     BoundaryCheckResult, RegenerateRequest, SmoothBoundaryRequest,
 };
-use x121_db::repositories::{SegmentRepo, SegmentVersionRepo};
+use x121_db::repositories::{SceneRepo, SegmentRepo, SegmentVersionRepo};
 
-use crate::error::AppResult;
+use crate::engine::scene_restitch;
+use crate::error::{AppError, AppResult};
 use crate::handlers::segment::ensure_segment_exists;
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
@@ -70,17 +75,30 @@ pub async fn regenerate(
         .await?
         .expect("ensured above");
 
+    let data = regenerate_segment(&state.pool, &segment, body.modified_params).await?;
+
+    Ok(Json(DataResponse { data }))
+}
+
+/// Replace `segment` with a new pending segment at the same position.
+///
+/// Shared by the single-segment and scene-level re-stitch endpoints.
+async fn regenerate_segment(
+    pool: &PgPool,
+    segment: &Segment,
+    modified_params: Option<serde_json::Value>,
+) -> Result<RegenerateResponse, sqlx::Error> {
     // Create a new segment at the same position with the same seed frame.
     let new_seg = SegmentRepo::create(
-        &state.pool,
-        &x121_db::models::segment::CreateSegment {
+        pool,
+        &CreateSegment {
             scene_id: segment.scene_id,
             sequence_index: segment.sequence_index,
             status_id: Some(1), // Pending
             seed_frame_path: segment.seed_frame_path.clone(),
             output_video_path: None,
             last_frame_path: None,
-            quality_scores: body.modified_params,
+            quality_scores: modified_params,
             duration_secs: None,
             cumulative_duration_secs: None,
             boundary_frame_index: None,
@@ -96,7 +114,7 @@ pub async fn regenerate(
 
     // Archive: link the new segment to the old one.
     SegmentVersionRepo::archive_segment(
-        &state.pool,
+        pool,
         new_seg.id,
         segment.id,
         segment.regeneration_count + 1,
@@ -105,22 +123,17 @@ pub async fn regenerate(
 
     // Soft-delete the old segment so it doesn't appear in the active list
     // but is still accessible via version history.
-    SegmentRepo::soft_delete(&state.pool, segment.id).await?;
+    SegmentRepo::soft_delete(pool, segment.id).await?;
 
     // Flag downstream segments as stale.
-    let stale_count = SegmentVersionRepo::flag_downstream_stale(
-        &state.pool,
-        segment.scene_id,
-        segment.sequence_index,
-    )
-    .await?;
+    let stale_count =
+        SegmentVersionRepo::flag_downstream_stale(pool, segment.scene_id, segment.sequence_index)
+            .await?;
 
-    Ok(Json(DataResponse {
-        data: RegenerateResponse {
-            new_segment_id: new_seg.id,
-            stale_count,
-        },
-    }))
+    Ok(RegenerateResponse {
+        new_segment_id: new_seg.id,
+        stale_count,
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -171,11 +184,9 @@ pub async fn smooth_boundary(
         "before" => restitching::BoundaryPosition::Before,
         "after" => restitching::BoundaryPosition::After,
         other => {
-            return Err(crate::error::AppError::Core(
-                x121_core::error::CoreError::Validation(format!(
-                    "Invalid boundary position: '{other}'. Must be 'before' or 'after'."
-                )),
-            ));
+            return Err(AppError::Core(CoreError::Validation(format!(
+                "Invalid boundary position: '{other}'. Must be 'before' or 'after'."
+            ))));
         }
     };
 
@@ -239,3 +250,151 @@ pub async fn clear_stale(
         data: ClearStaleResponse { cleared },
     }))
 }
+
+// ---------------------------------------------------------------------------
+// POST /api/v1/scenes/{id}/restitch
+// ---------------------------------------------------------------------------
+
+/// Request body for the scene-level re-stitch endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct SceneRestitchRequest {
+    /// SSIM below which a boundary fails (default: [`restitching::DEFAULT_SSIM_THRESHOLD`]).
+    pub ssim_threshold: Option<f64>,
+    /// Skip segments before this sequence index, typically the `checkpoint`
+    /// of an earlier cancelled run.
+    pub resume_from: Option<i32>,
+}
+
+/// Re-check every boundary of a scene and regenerate only the segments
+/// whose incoming boundary fails the SSIM threshold.
+///
+/// Runs in the background and returns the run report immediately, listing
+/// the planned action for each segment; poll it via
+/// `GET /scenes/restitch/{run_id}`. Returns 409 while the scene already has
+/// a run in progress.
+pub async fn restitch_scene(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(scene_id): Path<DbId>,
+    body: Option<Json<SceneRestitchRequest>>,
+) -> AppResult<impl IntoResponse> {
+    let body = body.map(|Json(b)| b).unwrap_or_default();
+    SceneRepo::find_by_id(&state.pool, scene_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Scene",
+            id: scene_id,
+        }))?;
+
+    let threshold = body
+        .ssim_threshold
+        .unwrap_or(restitching::DEFAULT_SSIM_THRESHOLD);
+    let segments: Vec<restitching::SegmentBoundaries> =
+        SegmentRepo::list_by_scene(&state.pool, scene_id)
+            .await?
+            .iter()
+            .map(|s| restitching::SegmentBoundaries {
+                segment_id: s.id,
+                sequence_index: s.sequence_index,
+                ssim_before: s.boundary_ssim_before,
+                ssim_after: s.boundary_ssim_after,
+            })
+            .collect();
+    let plan = restitching::plan_scene_restitch(&segments, threshold, body.resume_from)?;
+
+    let handle =
+        scene_restitch::register_restitch(&state.scene_restitches, scene_id, threshold, &plan)
+            .ok_or_else(|| {
+                AppError::Core(CoreError::Conflict(format!(
+                    "Scene {scene_id} already has a re-stitch in progress"
+                )))
+            })?;
+    let report = handle.report();
+
+    tracing::info!(
+        user_id = auth.user_id,
+        scene_id,
+        run_id = %report.run_id,
+        segments = report.segments.len(),
+        resume_from = body.resume_from,
+        "Scene re-stitch started"
+    );
+
+    let pool = state.pool.clone();
+    tokio::spawn(async move {
+        let report = scene_restitch::run_restitch(handle, |segment_id| {
+            let pool = pool.clone();
+            async move {
+                let segment = SegmentRepo::find_by_id(&pool, segment_id)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| format!("Segment {segment_id} no longer exists"))?;
+                regenerate_segment(&pool, &segment, None)
+                    .await
+                    .map(|r| r.new_segment_id)
+                    .map_err(|e| e.to_string())
+            }
+        })
+        .await;
+        tracing::info!(
+            scene_id,
+            run_id = %report.run_id,
+            checkpoint = report.checkpoint,
+            cancelled = report.cancelled,
+            "Scene re-stitch finished"
+        );
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(DataResponse { data: report })))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/v1/scenes/restitch/{run_id}
+// ---------------------------------------------------------------------------
+
+/// Return the progress report of a scene re-stitch run.
+pub async fn get_scene_restitch(
+    State(state): State<AppState>,
+    _auth: AuthUser,
+    Path(run_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .scene_restitches
+        .get(run_id)
+        .ok_or_else(|| restitch_run_not_found(run_id))?;
+    Ok(Json(DataResponse { data: report }))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/v1/scenes/restitch/{run_id}/cancel
+// ---------------------------------------------------------------------------
+
+/// Stop a scene re-stitch run before its next segment. The segment being
+/// regenerated, if any, finishes normally; resume later from the report's
+/// `checkpoint`.
+pub async fn cancel_scene_restitch(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(run_id): Path<RunId>,
+) -> AppResult<impl IntoResponse> {
+    let report = state
+        .scene_restitches
+        .cancel(run_id)
+        .ok_or_else(|| restitch_run_not_found(run_id))?;
+
+    tracing::info!(
+        user_id = auth.user_id,
+        run_id = %run_id,
+        checkpoint = report.checkpoint,
+        "Scene re-stitch cancelled"
+    );
+
+    Ok(Json(DataResponse { data: report }))
+}
+
+fn restitch_run_not_found(run_id: RunId) -> AppError {
+    AppError::Core(CoreError::RunNotFound {
+        entity: "SceneRestitchRun",
+        run_id,
+    })
+}
@@ -13,12 +13,20 @@ use axum::response::IntoResponse;
 use axum::Json;
 use serde::Deserialize;
 use x121_core::error::CoreError;
+use x121_core::scene_type_config::{
+    check_slug_available, resolve_effective_scene_type,
+    validate_scene_type_config as validate_config, ConfigIssue, EffectiveSceneType,
+    ProjectSceneTypeOverride, ScenePrompts, SceneTypeConfig,
+};
 use x121_core::types::DbId;
+use x121_core::workflow_import;
 use x121_db::models::scene_type::{
     CreateSceneType, MatrixCellDto, MatrixRequest, PromptPreviewQuery, PromptPreviewResponse,
     SceneType, UpdateSceneType, ValidationResult,
 };
-use x121_db::repositories::{AvatarRepo, SceneTypeRepo};
+use x121_db::repositories::{
+    AvatarRepo, ProjectPromptOverrideRepo, SceneTypeRepo, WorkflowPromptSlotRepo, WorkflowRepo,
+};
 
 use crate::error::{AppError, AppResult};
 use crate::handlers::scene_type_inheritance::ensure_scene_type_exists;
@@ -44,7 +52,20 @@ pub async fn create(
     Json(mut input): Json<CreateSceneType>,
 ) -> AppResult<(StatusCode, Json<DataResponse<SceneType>>)> {
     input.project_id = Some(project_id);
-    let scene_type = SceneTypeRepo::create(&state.pool, &input).await?;
+    ensure_config_valid(&state, &input).await?;
+    if let Some(params) = &input.generation_params {
+        validate_generation_params(
+            &state,
+            params,
+            input.workflow_id,
+            input.workflow_json.as_ref(),
+        )
+        .await?;
+    }
+    let scene_type = match SceneTypeRepo::create(&state.pool, &input).await {
+        Ok(scene_type) => scene_type,
+        Err(err) => return Err(slug_conflict(&state, &input.slug, None, err).await),
+    };
     Ok((StatusCode::CREATED, Json(DataResponse { data: scene_type })))
 }
 
@@ -95,7 +116,11 @@ pub async fn create_studio(
 ) -> AppResult<(StatusCode, Json<DataResponse<SceneType>>)> {
     input.project_id = None;
     input.is_studio_level = Some(true);
-    let scene_type = SceneTypeRepo::create(&state.pool, &input).await?;
+    ensure_config_valid(&state, &input).await?;
+    let scene_type = match SceneTypeRepo::create(&state.pool, &input).await {
+        Ok(scene_type) => scene_type,
+        Err(err) => return Err(slug_conflict(&state, &input.slug, None, err).await),
+    };
     Ok((StatusCode::CREATED, Json(DataResponse { data: scene_type })))
 }
 
@@ -152,12 +177,20 @@ async fn update_inner(
 ) -> AppResult<Json<DataResponse<SceneType>>> {
     let track_ids = input.track_ids.clone();
 
-    let scene_type = SceneTypeRepo::update(&state.pool, id, &input)
-        .await?
-        .ok_or(AppError::Core(CoreError::NotFound {
-            entity: "SceneType",
-            id,
-        }))?;
+    let existing = ensure_scene_type_exists(&state.pool, id).await?;
+    validate_scene_type_update(state, &existing, &input).await?;
+
+    let updated = match SceneTypeRepo::update(&state.pool, id, &input).await {
+        Ok(updated) => updated,
+        Err(err) => {
+            let slug = input.slug.as_deref().unwrap_or(&existing.slug);
+            return Err(slug_conflict(state, slug, Some(id), err).await);
+        }
+    };
+    let scene_type = updated.ok_or(AppError::Core(CoreError::NotFound {
+        entity: "SceneType",
+        id,
+    }))?;
 
     // Sync track associations if provided
     if let Some(ids) = track_ids {
@@ -167,6 +200,247 @@ async fn update_inner(
     Ok(Json(DataResponse { data: scene_type }))
 }
 
+/// Validate the config a scene type would have after applying `input`,
+/// rejecting any error as a 400 before anything is written.
+///
+/// Shared by scene type updates and preset application so both enforce the
+/// same rules.
+pub(crate) async fn validate_scene_type_update(
+    state: &AppState,
+    existing: &SceneType,
+    input: &UpdateSceneType,
+) -> AppResult<()> {
+    let workflow_id = input.workflow_id.or(existing.workflow_id);
+    let config = SceneTypeConfig {
+        name: input.name.as_deref().unwrap_or(&existing.name),
+        prompt_templates: prompt_templates([
+            (
+                "prompt_template",
+                input
+                    .prompt_template
+                    .as_deref()
+                    .or(existing.prompt_template.as_deref()),
+            ),
+            (
+                "negative_prompt_template",
+                input
+                    .negative_prompt_template
+                    .as_deref()
+                    .or(existing.negative_prompt_template.as_deref()),
+            ),
+            (
+                "prompt_start_clip",
+                input
+                    .prompt_start_clip
+                    .as_deref()
+                    .or(existing.prompt_start_clip.as_deref()),
+            ),
+            (
+                "negative_prompt_start_clip",
+                input
+                    .negative_prompt_start_clip
+                    .as_deref()
+                    .or(existing.negative_prompt_start_clip.as_deref()),
+            ),
+            (
+                "prompt_continuation_clip",
+                input
+                    .prompt_continuation_clip
+                    .as_deref()
+                    .or(existing.prompt_continuation_clip.as_deref()),
+            ),
+            (
+                "negative_prompt_continuation_clip",
+                input
+                    .negative_prompt_continuation_clip
+                    .as_deref()
+                    .or(existing.negative_prompt_continuation_clip.as_deref()),
+            ),
+        ]),
+        workflow_id,
+        workflow_exists: workflow_exists(state, workflow_id).await?,
+        target_duration_secs: input.target_duration_secs.or(existing.target_duration_secs),
+        segment_duration_secs: input
+            .segment_duration_secs
+            .or(existing.segment_duration_secs),
+        duration_tolerance_secs: input
+            .duration_tolerance_secs
+            .or(Some(existing.duration_tolerance_secs)),
+        transition_segment_index: input
+            .transition_segment_index
+            .or(existing.transition_segment_index),
+        expected_chunks: input.expected_chunks.or(existing.expected_chunks),
+        auto_retry_max_attempts: input
+            .auto_retry_max_attempts
+            .or(Some(existing.auto_retry_max_attempts)),
+        auto_retry_cfg_jitter: input
+            .auto_retry_cfg_jitter
+            .or(existing.auto_retry_cfg_jitter),
+        target_fps: input.target_fps.or(existing.target_fps),
+    };
+    reject_config_errors(&validate_config(&config))?;
+
+    if let Some(params) = &input.generation_params {
+        validate_generation_params(
+            state,
+            params,
+            input.workflow_id.or(existing.workflow_id),
+            input
+                .workflow_json
+                .as_ref()
+                .or(existing.workflow_json.as_ref()),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Partial unique index keeping live scene type slugs unique.
+const SLUG_UNIQUE_INDEX: &str = "uq_scene_types_slug";
+
+/// Map a failed scene type write to the error returned to the client.
+///
+/// Slug uniqueness is enforced only by [`SLUG_UNIQUE_INDEX`], so concurrent
+/// writes cannot both claim a slug. A violation of that index becomes a 409
+/// naming a free alternative; any other error passes through unchanged.
+async fn slug_conflict(
+    state: &AppState,
+    slug: &str,
+    exclude_id: Option<DbId>,
+    err: sqlx::Error,
+) -> AppError {
+    let violates_slug_index = matches!(
+        &err,
+        sqlx::Error::Database(db_err)
+            if db_err.code().as_deref() == Some("23505")
+                && db_err.constraint() == Some(SLUG_UNIQUE_INDEX)
+    );
+    if !violates_slug_index {
+        return AppError::Database(err);
+    }
+    match SceneTypeRepo::list_slugs_with_base(&state.pool, slug, exclude_id).await {
+        Ok(existing) => match check_slug_available(slug, &existing) {
+            Err(conflict) => AppError::Core(conflict.into()),
+            Ok(()) => AppError::Database(err),
+        },
+        Err(lookup_err) => AppError::Database(lookup_err),
+    }
+}
+
+/// Whether `workflow_id` references an existing workflow. `None` counts as
+/// existing since there is nothing to resolve.
+async fn workflow_exists(state: &AppState, workflow_id: Option<DbId>) -> AppResult<bool> {
+    match workflow_id {
+        Some(id) => Ok(WorkflowRepo::find_by_id(&state.pool, id).await?.is_some()),
+        None => Ok(true),
+    }
+}
+
+/// Keep only the prompt template fields that are set.
+fn prompt_templates<'a, const N: usize>(
+    fields: [(&'static str, Option<&'a str>); N],
+) -> Vec<(&'static str, &'a str)> {
+    fields
+        .into_iter()
+        .filter_map(|(field, template)| template.map(|t| (field, t)))
+        .collect()
+}
+
+/// Build the validator input for a scene type about to be created.
+async fn create_config<'a>(
+    state: &AppState,
+    input: &'a CreateSceneType,
+) -> AppResult<SceneTypeConfig<'a>> {
+    Ok(SceneTypeConfig {
+        name: &input.name,
+        prompt_templates: prompt_templates([
+            ("prompt_template", input.prompt_template.as_deref()),
+            (
+                "negative_prompt_template",
+                input.negative_prompt_template.as_deref(),
+            ),
+            ("prompt_start_clip", input.prompt_start_clip.as_deref()),
+            (
+                "negative_prompt_start_clip",
+                input.negative_prompt_start_clip.as_deref(),
+            ),
+            (
+                "prompt_continuation_clip",
+                input.prompt_continuation_clip.as_deref(),
+            ),
+            (
+                "negative_prompt_continuation_clip",
+                input.negative_prompt_continuation_clip.as_deref(),
+            ),
+        ]),
+        workflow_id: input.workflow_id,
+        workflow_exists: workflow_exists(state, input.workflow_id).await?,
+        target_duration_secs: input.target_duration_secs,
+        segment_duration_secs: input.segment_duration_secs,
+        duration_tolerance_secs: input.duration_tolerance_secs,
+        transition_segment_index: input.transition_segment_index,
+        expected_chunks: input.expected_chunks,
+        auto_retry_max_attempts: input.auto_retry_max_attempts,
+        auto_retry_cfg_jitter: input.auto_retry_cfg_jitter,
+        target_fps: input.target_fps,
+    })
+}
+
+/// Reject a config with any error-severity issue as a 400 listing them all.
+fn reject_config_errors(issues: &[ConfigIssue]) -> AppResult<()> {
+    let errors: Vec<String> = issues
+        .iter()
+        .filter(|i| i.is_error())
+        .map(ToString::to_string)
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Invalid scene type config: {}",
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Validate a new scene type's config up front, before it is persisted.
+async fn ensure_config_valid(state: &AppState, input: &CreateSceneType) -> AppResult<()> {
+    let config = create_config(state, input).await?;
+    reject_config_errors(&validate_config(&config))
+}
+
+/// Validate `generation_params` overrides against the parameters discovered
+/// in the scene type's workflow, resolved the way the pipeline does: the
+/// linked workflow first, then the inline JSON.
+///
+/// Without a parseable workflow only the override count is checked.
+async fn validate_generation_params(
+    state: &AppState,
+    params: &serde_json::Value,
+    workflow_id: Option<DbId>,
+    workflow_json: Option<&serde_json::Value>,
+) -> AppResult<()> {
+    let template = match workflow_id {
+        Some(wf_id) => WorkflowRepo::find_by_id(&state.pool, wf_id)
+            .await?
+            .map(|wf| wf.json_content),
+        None => None,
+    }
+    .or_else(|| workflow_json.cloned());
+
+    let discovered = template
+        .and_then(|json| workflow_import::parse_workflow(&json).ok())
+        .map(|parsed| workflow_import::discover_parameters(&parsed));
+
+    workflow_import::validate_parameter_overrides(
+        params,
+        discovered.as_deref(),
+        state.config.max_parameter_overrides,
+    )?;
+    Ok(())
+}
+
 async fn delete_inner(state: &AppState, id: DbId) -> AppResult<StatusCode> {
     let deleted = SceneTypeRepo::soft_delete(&state.pool, id).await?;
     if deleted {
@@ -179,6 +453,69 @@ async fn delete_inner(state: &AppState, id: DbId) -> AppResult<StatusCode> {
     }
 }
 
+/// The prompt templates stored on a scene type row.
+fn scene_prompts(scene_type: &SceneType) -> ScenePrompts {
+    ScenePrompts {
+        prompt_template: scene_type.prompt_template.clone(),
+        negative_prompt_template: scene_type.negative_prompt_template.clone(),
+        prompt_start_clip: scene_type.prompt_start_clip.clone(),
+        negative_prompt_start_clip: scene_type.negative_prompt_start_clip.clone(),
+        prompt_continuation_clip: scene_type.prompt_continuation_clip.clone(),
+        negative_prompt_continuation_clip: scene_type.negative_prompt_continuation_clip.clone(),
+    }
+}
+
+/// Resolve a scene type's prompts for a project.
+///
+/// A studio scene type is shadowed by the project's own scene type with the
+/// same slug, if any; a project-level scene type stands on its own. Project
+/// prompt overrides with `override_text` then replace the positive or
+/// negative prompt according to their workflow slot's type.
+async fn effective_scene_type(
+    pool: &sqlx::PgPool,
+    scene_type: &SceneType,
+    project_id: DbId,
+) -> AppResult<EffectiveSceneType> {
+    let (studio, project_scene_type) = if scene_type.project_id.is_some() {
+        (ScenePrompts::default(), Some(scene_prompts(scene_type)))
+    } else {
+        let shadow = SceneTypeRepo::list_by_project(pool, project_id)
+            .await?
+            .into_iter()
+            .find(|st| st.slug == scene_type.slug)
+            .map(|st| scene_prompts(&st));
+        (scene_prompts(scene_type), shadow)
+    };
+
+    let mut project_override = ProjectSceneTypeOverride {
+        scene_type: project_scene_type,
+        ..Default::default()
+    };
+    let overrides =
+        ProjectPromptOverrideRepo::list_by_project_and_scene_type(pool, project_id, scene_type.id)
+            .await?;
+    if let Some(workflow_id) = scene_type.workflow_id {
+        if overrides.iter().any(|o| o.override_text.is_some()) {
+            let slots = WorkflowPromptSlotRepo::list_by_workflow(pool, workflow_id).await?;
+            for o in &overrides {
+                let Some(slot) = slots.iter().find(|s| s.id == o.prompt_slot_id) else {
+                    continue;
+                };
+                let target = if slot.slot_type == "negative" {
+                    &mut project_override.negative_override
+                } else {
+                    &mut project_override.positive_override
+                };
+                if target.is_none() {
+                    target.clone_from(&o.override_text);
+                }
+            }
+        }
+    }
+
+    Ok(resolve_effective_scene_type(&studio, &project_override))
+}
+
 // ---------------------------------------------------------------------------
 // PRD-23 endpoints
 // ---------------------------------------------------------------------------
@@ -208,19 +545,10 @@ pub async fn preview_prompt(
         None => ClipPosition::FullClip,
     };
 
-    // 4. Select prompt for position
-    let positive_template = scene_type_config::select_prompt_for_position(
-        scene_type.prompt_template.as_deref(),
-        scene_type.prompt_start_clip.as_deref(),
-        scene_type.prompt_continuation_clip.as_deref(),
-        position,
-    );
-    let negative_template = scene_type_config::select_prompt_for_position(
-        scene_type.negative_prompt_template.as_deref(),
-        scene_type.negative_prompt_start_clip.as_deref(),
-        scene_type.negative_prompt_continuation_clip.as_deref(),
-        position,
-    );
+    // 4. Resolve the scene type as the avatar's project sees it, then pick
+    //    the templates for the requested position.
+    let effective = effective_scene_type(&state.pool, &scene_type, avatar.project_id).await?;
+    let (positive_template, negative_template) = effective.prompts_for_position(position);
 
     // 5. Build metadata map from avatar
     let mut metadata = std::collections::HashMap::new();
@@ -242,14 +570,14 @@ pub async fn preview_prompt(
 
     // 6. Resolve templates
     let positive_resolved = match positive_template {
-        Some(t) => scene_type_config::resolve_prompt_template(t, &metadata),
+        Some(t) => scene_type_config::resolve_prompt_template(&t.text, &metadata),
         None => ResolvedPrompt {
             text: String::new(),
             unresolved_placeholders: vec![],
         },
     };
     let negative_resolved = match negative_template {
-        Some(t) => scene_type_config::resolve_prompt_template(t, &metadata),
+        Some(t) => scene_type_config::resolve_prompt_template(&t.text, &metadata),
         None => ResolvedPrompt {
             text: String::new(),
             unresolved_placeholders: vec![],
@@ -260,14 +588,14 @@ pub async fn preview_prompt(
     let source = match position {
         ClipPosition::FullClip => "full_clip".to_string(),
         ClipPosition::StartClip => {
-            if scene_type.prompt_start_clip.is_some() {
+            if effective.prompt_start_clip.is_some() {
                 "start_clip".to_string()
             } else {
                 "full_clip (fallback)".to_string()
             }
         }
         ClipPosition::ContinuationClip => {
-            if scene_type.prompt_continuation_clip.is_some() {
+            if effective.prompt_continuation_clip.is_some() {
                 "continuation_clip".to_string()
             } else {
                 "full_clip (fallback)".to_string()
@@ -289,6 +617,8 @@ pub async fn preview_prompt(
             negative_prompt: negative_resolved.text,
             unresolved_placeholders: unresolved,
             source,
+            positive_source: positive_template.map(|t| t.source),
+            negative_source: negative_template.map(|t| t.source),
         },
     }))
 }
@@ -366,43 +696,23 @@ fn scene_status_label(status_id: i16) -> &'static str {
 }
 
 /// POST /api/v1/scene-types/validate
+///
+/// Run the same checks create/update enforce and report every issue,
+/// split into blocking errors and advisory warnings.
 pub async fn validate_scene_type_config(
+    State(state): State<AppState>,
     Json(input): Json<CreateSceneType>,
 ) -> AppResult<impl IntoResponse> {
-    use x121_core::scene_type_config;
-
-    let mut errors = Vec::new();
-    let mut warnings = Vec::new();
-
-    // Validate name
-    if input.name.trim().is_empty() {
-        errors.push("name is required".to_string());
-    }
-
-    // Validate duration
-    if let Err(e) = scene_type_config::validate_duration_config(
-        input.target_duration_secs,
-        input.segment_duration_secs,
-        input.duration_tolerance_secs,
-    ) {
-        errors.push(e);
-    }
-
-    // Validate prompt placeholders (warnings only)
-    if let Some(ref template) = input.prompt_template {
-        let unknown = scene_type_config::validate_placeholders(template);
-        for p in unknown {
-            warnings.push(format!(
-                "Unknown placeholder '{{{p}}}' in prompt_template — may not resolve"
-            ));
-        }
-    }
+    let config = create_config(&state, &input).await?;
+    let (errors, warnings): (Vec<_>, Vec<_>) = validate_config(&config)
+        .into_iter()
+        .partition(ConfigIssue::is_error);
 
     Ok(Json(DataResponse {
         data: ValidationResult {
             valid: errors.is_empty(),
-            errors,
-            warnings,
+            errors: errors.iter().map(ToString::to_string).collect(),
+            warnings: warnings.iter().map(ToString::to_string).collect(),
         },
     }))
 }
@@ -23,6 +23,7 @@ use x121_db::repositories::{
     SceneRepo, SceneTypeRepo, SceneVideoVersionArtifactRepo, SceneVideoVersionRepo, SegmentRepo,
     TagRepo,
 };
+use x121_events::PlatformEvent;
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
@@ -351,7 +352,12 @@ pub async fn delete(
         )));
     }
 
-    SceneVideoVersionRepo::soft_delete(&state.pool, id).await?;
+    // Re-checked atomically in case a concurrent set-final promoted it.
+    if !SceneVideoVersionRepo::soft_delete_unless_final(&state.pool, id).await? {
+        return Err(AppError::Core(CoreError::Conflict(
+            "Cannot delete the final version. Select a different final version first.".into(),
+        )));
+    }
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -359,7 +365,9 @@ pub async fn delete(
 ///
 /// Marks this version as the final version for its scene, un-marking any
 /// previously final version in the same transaction.
-/// Rejects the request if the clip has a `qa_status` of `"rejected"`.
+/// Rejects the request if the clip has a `qa_status` of `"rejected"`, and
+/// returns 404 if the version does not belong to the scene or was deleted.
+/// Publishes a `version.finalized` event on success.
 pub async fn set_final(
     State(state): State<AppState>,
     Path((scene_id, id)): Path<(DbId, DbId)>,
@@ -381,6 +389,16 @@ pub async fn set_final(
                 id,
             })
         })?;
+
+    state.event_bus.publish(
+        PlatformEvent::new("version.finalized")
+            .with_source("scene_video_version", version.id)
+            .with_payload(serde_json::json!({
+                "scene_id": scene_id,
+                "version_number": version.version_number,
+            })),
+    );
+
     Ok(Json(DataResponse { data: version }))
 }
 
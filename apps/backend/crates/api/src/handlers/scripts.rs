@@ -7,15 +7,20 @@ use axum::http::StatusCode;
 use axum::Json;
 use serde::Deserialize;
 use x121_core::scripting::executor::ScriptOutput;
+use x121_core::scripting::status::ExecutionOutcome;
 use x121_core::types::DbId;
-use x121_db::models::script::{CreateScript, Script, ScriptExecution, UpdateScript};
+use x121_db::models::script::{
+    CreateScript, Script, ScriptExecution, ScriptExecutionFilter, ScriptExecutionPage, UpdateScript,
+};
 use x121_db::repositories::{ScriptExecutionRepo, ScriptRepo};
 
 use x121_core::error::CoreError;
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::rbac::RequireAdmin;
+use crate::query::parse_timestamp;
 use crate::response::DataResponse;
+use crate::scripting::limiter::ExecutionCapacity;
 use crate::state::AppState;
 
 // ---------------------------------------------------------------------------
@@ -36,6 +41,12 @@ pub struct ExecutionListQuery {
     pub limit: Option<i64>,
     /// Offset for pagination (default: 0).
     pub offset: Option<i64>,
+    /// Outcome filter: `success`, `failure`, `timeout`, or `cancelled`.
+    pub status: Option<String>,
+    /// Only executions created at or after this RFC 3339 timestamp.
+    pub from: Option<String>,
+    /// Only executions created at or before this RFC 3339 timestamp.
+    pub to: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -197,22 +208,61 @@ pub async fn test_script(
     Ok(Json(DataResponse { data: output }))
 }
 
+/// GET /admin/scripts/capacity
+///
+/// Report how many script executions are running and queued against the
+/// configured limits.
+pub async fn get_execution_capacity(
+    State(state): State<AppState>,
+    RequireAdmin(_admin): RequireAdmin,
+) -> AppResult<Json<DataResponse<ExecutionCapacity>>> {
+    let orchestrator = state.script_orchestrator.as_ref().ok_or_else(|| {
+        AppError::InternalError("Script orchestrator not initialized".to_string())
+    })?;
+
+    Ok(Json(DataResponse {
+        data: orchestrator.capacity(),
+    }))
+}
+
 /// GET /admin/scripts/{id}/executions
 ///
-/// List execution history for a script (paginated).
+/// List execution history for a script, most recent first. Supports
+/// pagination and filtering by outcome (`status`) and creation time range
+/// (`from`/`to`); the response carries the total matching count.
 pub async fn list_executions(
     State(state): State<AppState>,
     RequireAdmin(_admin): RequireAdmin,
     Path(script_id): Path<DbId>,
     Query(params): Query<ExecutionListQuery>,
-) -> AppResult<Json<DataResponse<Vec<ScriptExecution>>>> {
-    let limit = params.limit.unwrap_or(25).min(100);
-    let offset = params.offset.unwrap_or(0);
-
-    let executions =
-        ScriptExecutionRepo::list_by_script(&state.pool, script_id, limit, offset).await?;
-
-    Ok(Json(DataResponse { data: executions }))
+) -> AppResult<Json<DataResponse<ScriptExecutionPage>>> {
+    let limit = params.limit.unwrap_or(25).clamp(1, 100);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let outcome = params
+        .status
+        .as_deref()
+        .map(str::parse::<ExecutionOutcome>)
+        .transpose()?;
+    let from = if params.from.is_some() {
+        Some(parse_timestamp(&params.from, chrono::Utc::now())?)
+    } else {
+        None
+    };
+    let to = if params.to.is_some() {
+        Some(parse_timestamp(&params.to, chrono::Utc::now())?)
+    } else {
+        None
+    };
+    let filter = ScriptExecutionFilter { outcome, from, to };
+
+    let items =
+        ScriptExecutionRepo::list_history(&state.pool, script_id, &filter, limit, offset).await?;
+    let total = ScriptExecutionRepo::count_history(&state.pool, script_id, &filter).await?;
+
+    Ok(Json(DataResponse {
+        data: ScriptExecutionPage { items, total },
+    }))
 }
 
 /// GET /admin/scripts/executions/{id}
@@ -9,10 +9,14 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use x121_core::error::CoreError;
 use x121_core::storage;
+use x121_core::storage::policy::{
+    simulate_move, summarize_moves, AssetAttributes, BackendMoveSummary, ResolvedPolicy,
+    StoragePolicy,
+};
 use x121_core::storage::StorageProvider as _;
 use x121_core::types::DbId;
 use x121_db::models::status::StorageBackendStatus;
@@ -30,6 +34,10 @@ use crate::middleware::rbac::RequireAdmin;
 use crate::response::DataResponse;
 use crate::state::AppState;
 
+/// Maximum relocated assets listed individually in a policy simulation.
+/// The totals always cover every relocated asset.
+const MAX_SIMULATED_ASSETS: usize = 500;
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -241,32 +249,125 @@ pub async fn create_policy(
 // POST /admin/storage/policies/simulate
 // ---------------------------------------------------------------------------
 
-/// Simulate a tiering policy: find assets that would be moved.
+/// A proposed tiering policy to simulate.
 #[derive(Debug, Deserialize)]
 pub struct SimulatePolicyInput {
     pub entity_type: String,
     pub source_tier: String,
+    pub target_tier: String,
+    pub target_backend_id: DbId,
     pub age_threshold_days: Option<i32>,
     pub access_threshold_days: Option<i32>,
 }
 
+/// An asset the simulated policy would relocate.
+#[derive(Debug, Serialize)]
+pub struct SimulatedMove {
+    pub entity_type: String,
+    pub entity_id: DbId,
+    pub file_field: String,
+    pub file_size_bytes: i64,
+    pub from_backend_id: DbId,
+    pub to_backend_id: DbId,
+    pub to_tier: String,
+}
+
+/// What applying a policy would relocate.
+#[derive(Debug, Serialize)]
+pub struct PolicySimulation {
+    /// Relocated assets, largest first, capped at `MAX_SIMULATED_ASSETS`.
+    pub assets: Vec<SimulatedMove>,
+    /// Relocations aggregated per source/target backend pair.
+    pub by_backend: Vec<BackendMoveSummary>,
+    pub total_assets: usize,
+    /// Total bytes that would be migrated.
+    pub total_bytes: i64,
+}
+
+/// Simulate a tiering policy: report which assets it would move, and where,
+/// using the same resolver that places assets.
 pub async fn simulate_policy(
     RequireAdmin(_admin): RequireAdmin,
     State(state): State<AppState>,
     Json(input): Json<SimulatePolicyInput>,
 ) -> AppResult<impl IntoResponse> {
     storage::validate_tier(&input.source_tier)?;
+    storage::validate_tier(&input.target_tier)?;
+    if input.source_tier == input.target_tier {
+        return Err(AppError::Core(CoreError::Validation(
+            "Source and target tiers must be different".into(),
+        )));
+    }
+    ensure_backend_exists(&state.pool, input.target_backend_id).await?;
+
+    let proposed = StoragePolicy {
+        id: 0, // Not persisted; never reported.
+        entity_type: input.entity_type.clone(),
+        source_tier: input.source_tier.clone(),
+        target_tier: input.target_tier.clone(),
+        target_backend_id: input.target_backend_id,
+        condition_field: None,
+        condition_operator: None,
+        condition_value: None,
+        age_threshold_days: input.age_threshold_days,
+        access_threshold_days: input.access_threshold_days,
+        project_id: None,
+        is_active: true,
+    };
 
-    let candidates = AssetLocationRepo::find_tiering_candidates(
+    let placements = AssetLocationRepo::list_placements(
         &state.pool,
         &input.entity_type,
-        &input.source_tier,
-        input.age_threshold_days,
-        input.access_threshold_days,
+        &[input.source_tier, input.target_tier],
     )
     .await?;
 
-    Ok(Json(DataResponse { data: candidates }))
+    let mut moves: Vec<SimulatedMove> = placements
+        .into_iter()
+        .filter_map(|placement| {
+            let asset = AssetAttributes {
+                entity_type: placement.entity_type.clone(),
+                project_id: None,
+                tier: placement.tier.clone(),
+                age_days: placement.age_days,
+                idle_days: placement.idle_days,
+                fields: Default::default(),
+            };
+            let current = ResolvedPolicy {
+                backend_id: placement.backend_id,
+                tier: placement.tier,
+                policy_id: None,
+            };
+            let target = simulate_move(&asset, &current, &proposed)?;
+            Some(SimulatedMove {
+                entity_type: placement.entity_type,
+                entity_id: placement.entity_id,
+                file_field: placement.file_field,
+                file_size_bytes: placement.file_size_bytes,
+                from_backend_id: placement.backend_id,
+                to_backend_id: target.backend_id,
+                to_tier: target.tier,
+            })
+        })
+        .collect();
+
+    let by_backend = summarize_moves(
+        moves
+            .iter()
+            .map(|m| (m.from_backend_id, m.to_backend_id, m.file_size_bytes)),
+    );
+    let total_assets = moves.len();
+    let total_bytes = moves.iter().map(|m| m.file_size_bytes).sum();
+    moves.truncate(MAX_SIMULATED_ASSETS);
+
+    Ok(Json(DataResponse {
+        data: PolicySimulation {
+            assets: moves,
+            by_backend,
+            total_assets,
+            total_bytes,
+        },
+    }))
 }
 
 // ---------------------------------------------------------------------------
@@ -16,7 +16,7 @@ use x121_core::types::DbId;
 use x121_db::models::keyframe::CreateKeyframe;
 use x121_db::repositories::KeyframeRepo;
 
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
 use crate::response::DataResponse;
 use crate::state::AppState;
@@ -42,8 +42,13 @@ pub struct CreateKeyframeRequest {
     pub segment_id: DbId,
     pub frame_number: i32,
     pub timestamp_secs: f64,
-    pub thumbnail_path: String,
+    /// Explicit thumbnail location. When omitted, the content-addressed
+    /// cache key derived from the source hash is used.
+    pub thumbnail_path: Option<String>,
     pub full_res_path: Option<String>,
+    /// SHA-256 of the source frame. Computed from `full_res_path` when
+    /// omitted.
+    pub source_hash: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -99,7 +104,8 @@ pub async fn list_segment_keyframes(
 
 /// Create a new keyframe record.
 ///
-/// Validates the frame number before persisting.
+/// Validates the frame number and records the source frame's content hash,
+/// which keys the thumbnail cache so changed content gets a new thumbnail.
 pub async fn create_keyframe(
     State(state): State<AppState>,
     _auth: AuthUser,
@@ -107,12 +113,34 @@ pub async fn create_keyframe(
 ) -> AppResult<impl IntoResponse> {
     storyboard::validate_frame_number(body.frame_number)?;
 
+    let source_hash = match (body.source_hash, body.full_res_path.as_deref()) {
+        (Some(hash), _) => {
+            storyboard::validate_source_hash(&hash)?;
+            Some(hash.to_ascii_lowercase())
+        }
+        (None, Some(path)) => hash_source_frame(&state, path).await,
+        (None, None) => None,
+    };
+
+    let thumbnail_path = match (body.thumbnail_path, source_hash.as_deref()) {
+        (Some(path), _) => path,
+        (None, Some(hash)) => {
+            storyboard::thumbnail_cache_key(hash, storyboard::DEFAULT_THUMBNAIL_HEIGHT)
+        }
+        (None, None) => {
+            return Err(AppError::BadRequest(
+                "thumbnail_path is required when the source frame cannot be hashed".into(),
+            ))
+        }
+    };
+
     let input = CreateKeyframe {
         segment_id: body.segment_id,
         frame_number: body.frame_number,
         timestamp_secs: body.timestamp_secs,
-        thumbnail_path: body.thumbnail_path,
+        thumbnail_path,
         full_res_path: body.full_res_path,
+        source_hash,
     };
 
     let keyframe = KeyframeRepo::create(&state.pool, &input).await?;
@@ -127,6 +155,18 @@ pub async fn create_keyframe(
     Ok((StatusCode::CREATED, Json(DataResponse { data: keyframe })))
 }
 
+/// Hash the source frame at `path`, or `None` if it cannot be read.
+async fn hash_source_frame(state: &AppState, path: &str) -> Option<String> {
+    let resolved = state.resolve_to_path(path).await.ok()?;
+    match tokio::fs::read(&resolved).await {
+        Ok(bytes) => Some(storyboard::keyframe_source_hash(&bytes)),
+        Err(e) => {
+            tracing::warn!(path, error = %e, "Could not read keyframe source frame for hashing");
+            None
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // DELETE /keyframes/segment/{segment_id}
 // ---------------------------------------------------------------------------
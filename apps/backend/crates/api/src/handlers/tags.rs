@@ -9,6 +9,7 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use x121_core::error::CoreError;
+use x121_core::tags::{dedupe_preserving_order, validate_bulk_count};
 use x121_core::types::DbId;
 use x121_db::models::tag::{
     ApplyTagsRequest, BulkApplyRequest, BulkRemoveRequest, TagListParams, TagSuggestParams,
@@ -214,6 +215,8 @@ pub async fn remove_entity_tag(
 /// POST /api/v1/tags/bulk-apply
 ///
 /// Apply tags to multiple entities at once. Tags are created on first use.
+/// Lists exceeding the configured maximums are rejected with 422 before any
+/// database work; duplicate IDs and names are then dropped.
 pub async fn bulk_apply(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -228,11 +231,20 @@ pub async fn bulk_apply(
         return Err(AppError::BadRequest("tag_names must not be empty".into()));
     }
 
+    validate_bulk_sizes(
+        &state,
+        input.entity_ids.len(),
+        "tag_names",
+        input.tag_names.len(),
+    )?;
+    let entity_ids = dedupe_preserving_order(&input.entity_ids);
+    let tag_names = dedupe_preserving_order(&input.tag_names);
+
     let result = TagRepo::bulk_apply(
         &state.pool,
         &input.entity_type,
-        &input.entity_ids,
-        &input.tag_names,
+        &entity_ids,
+        &tag_names,
         Some(auth.user_id),
         input.pipeline_id,
     )
@@ -240,8 +252,8 @@ pub async fn bulk_apply(
 
     tracing::info!(
         entity_type = %input.entity_type,
-        entities = input.entity_ids.len(),
-        tags = input.tag_names.len(),
+        entities = entity_ids.len(),
+        tags = tag_names.len(),
         applied = result.applied,
         user_id = auth.user_id,
         "Bulk tags applied",
@@ -252,7 +264,8 @@ pub async fn bulk_apply(
 
 /// POST /api/v1/tags/bulk-remove
 ///
-/// Remove tags from multiple entities at once.
+/// Remove tags from multiple entities at once. Inputs are bounded and
+/// deduped the same way as [`bulk_apply`].
 pub async fn bulk_remove(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -267,18 +280,22 @@ pub async fn bulk_remove(
         return Err(AppError::BadRequest("tag_ids must not be empty".into()));
     }
 
-    let result = TagRepo::bulk_remove(
-        &state.pool,
-        &input.entity_type,
-        &input.entity_ids,
-        &input.tag_ids,
-    )
-    .await?;
+    validate_bulk_sizes(
+        &state,
+        input.entity_ids.len(),
+        "tag_ids",
+        input.tag_ids.len(),
+    )?;
+    let entity_ids = dedupe_preserving_order(&input.entity_ids);
+    let tag_ids = dedupe_preserving_order(&input.tag_ids);
+
+    let result =
+        TagRepo::bulk_remove(&state.pool, &input.entity_type, &entity_ids, &tag_ids).await?;
 
     tracing::info!(
         entity_type = %input.entity_type,
-        entities = input.entity_ids.len(),
-        tags = input.tag_ids.len(),
+        entities = entity_ids.len(),
+        tags = tag_ids.len(),
         removed = result.removed,
         user_id = auth.user_id,
         "Bulk tags removed",
@@ -291,6 +308,24 @@ pub async fn bulk_remove(
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Check raw (pre-dedupe) bulk input sizes against the configured limits.
+fn validate_bulk_sizes(
+    state: &AppState,
+    entity_count: usize,
+    tag_field: &str,
+    tag_count: usize,
+) -> AppResult<()> {
+    validate_bulk_count(
+        "entity_ids",
+        entity_count,
+        state.config.tag_bulk_max_entities,
+    )
+    .map_err(AppError::Unprocessable)?;
+    validate_bulk_count(tag_field, tag_count, state.config.tag_bulk_max_tags)
+        .map_err(AppError::Unprocessable)?;
+    Ok(())
+}
+
 /// Allowed entity types for tagging.
 const VALID_ENTITY_TYPES: &[&str] = &[
     "project",
@@ -7,9 +7,13 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
+use serde::Serialize;
 use x121_core::error::CoreError;
+use x121_core::theme::{resolve_effective_theme, EffectiveTheme};
 use x121_core::types::DbId;
-use x121_db::models::theme::{CreateCustomTheme, UpdateCustomTheme, UpsertThemePreference};
+use x121_db::models::theme::{
+    CreateCustomTheme, UpdateCustomTheme, UpsertThemePreference, UserThemePreference,
+};
 use x121_db::repositories::ThemeRepo;
 
 use crate::error::{AppError, AppResult};
@@ -17,35 +21,105 @@ use crate::middleware::rbac::{RequireAdmin, RequireAuth};
 use crate::response::DataResponse;
 use crate::state::AppState;
 
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// A user theme preference with the tokens it resolves to.
+#[derive(Debug, Serialize)]
+pub struct UserThemeResponse {
+    #[serde(flatten)]
+    pub preference: UserThemePreference,
+    pub effective: EffectiveTheme,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Resolve a preference against the studio theme it selects.
+///
+/// The studio theme is the admin-managed custom theme referenced by
+/// `custom_theme_id`; without one, the user's overrides stand alone.
+async fn resolve_preference(
+    pool: &sqlx::PgPool,
+    pref: &UserThemePreference,
+) -> AppResult<EffectiveTheme> {
+    let studio = match pref.custom_theme_id {
+        Some(id) => ThemeRepo::export_custom_theme(pool, id).await?,
+        None => None,
+    };
+    Ok(resolve_effective_theme(
+        studio.as_ref(),
+        Some(&pref.token_overrides),
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // User theme preference endpoints
 // ---------------------------------------------------------------------------
 
 /// GET /api/v1/user/theme
 ///
-/// Retrieve the authenticated user's theme preference.
+/// Retrieve the authenticated user's theme preference together with the
+/// effective tokens (studio theme overlaid with the user's overrides).
 /// Returns 204 if no preference has been saved yet.
 pub async fn get_user_theme(
     RequireAuth(user): RequireAuth,
     State(state): State<AppState>,
 ) -> AppResult<impl IntoResponse> {
-    let pref = ThemeRepo::get_user_preference(&state.pool, user.user_id).await?;
+    let Some(preference) = ThemeRepo::get_user_preference(&state.pool, user.user_id).await? else {
+        return Ok(StatusCode::NO_CONTENT.into_response());
+    };
+    let effective = resolve_preference(&state.pool, &preference).await?;
+
+    Ok(Json(DataResponse {
+        data: UserThemeResponse {
+            preference,
+            effective,
+        },
+    })
+    .into_response())
+}
 
-    match pref {
-        Some(p) => Ok(Json(DataResponse { data: p }).into_response()),
-        None => Ok(StatusCode::NO_CONTENT.into_response()),
-    }
+/// GET /api/v1/user/theme/export
+///
+/// Export the authenticated user's effective token set as raw JSON, in the
+/// same shape as an admin theme export. Users without a saved preference
+/// get an empty token set.
+pub async fn export_user_theme(
+    RequireAuth(user): RequireAuth,
+    State(state): State<AppState>,
+) -> AppResult<impl IntoResponse> {
+    let tokens = match ThemeRepo::get_user_preference(&state.pool, user.user_id).await? {
+        Some(pref) => resolve_preference(&state.pool, &pref).await?.tokens,
+        None => serde_json::json!({}),
+    };
+
+    Ok(Json(DataResponse { data: tokens }))
 }
 
 /// PUT /api/v1/user/theme
 ///
-/// Create or update the authenticated user's theme preference.
+/// Create or update the authenticated user's theme preference, returning it
+/// with its effective tokens.
 pub async fn update_user_theme(
     RequireAuth(user): RequireAuth,
     State(state): State<AppState>,
     Json(input): Json<UpsertThemePreference>,
 ) -> AppResult<impl IntoResponse> {
-    let pref = ThemeRepo::upsert_user_preference(&state.pool, user.user_id, &input).await?;
+    if input
+        .token_overrides
+        .as_ref()
+        .is_some_and(|t| !t.is_object())
+    {
+        return Err(AppError::BadRequest(
+            "token_overrides must be a JSON object".into(),
+        ));
+    }
+
+    let preference = ThemeRepo::upsert_user_preference(&state.pool, user.user_id, &input).await?;
+    let effective = resolve_preference(&state.pool, &preference).await?;
 
     tracing::info!(
         user_id = user.user_id,
@@ -54,7 +128,12 @@ pub async fn update_user_theme(
         "User theme preference updated",
     );
 
-    Ok(Json(DataResponse { data: pref }))
+    Ok(Json(DataResponse {
+        data: UserThemeResponse {
+            preference,
+            effective,
+        },
+    }))
 }
 
 // ---------------------------------------------------------------------------
@@ -3,18 +3,22 @@
 //! Provides endpoints for creating, reverting, batch-applying, and querying
 //! non-destructive segment trims with frame-accurate in/out points.
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 
+use futures::stream::{self, StreamExt};
 use x121_core::error::CoreError;
-use x121_core::trimming::{self, seed_frame_after_trim, TrimPreset};
+use x121_core::trimming::{
+    self, BatchTrimStatus, BatchTrimSummary, SeedImpactReport, SegmentFrames, TrimPreset, TrimRange,
+};
 use x121_core::types::DbId;
 use x121_db::models::segment_trim::{
-    ApplyPresetRequest, BatchTrimRequest, BatchTrimResponse, CreateSegmentTrim, SeedFrameUpdate,
+    ApplyPresetRequest, BatchTrimRequest, BatchTrimResponse, BatchTrimResult, CreateSegmentTrim,
+    SeedFrameUpdate,
 };
-use x121_db::repositories::SegmentTrimRepo;
+use x121_db::repositories::{SegmentRepo, SegmentTrimRepo};
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
@@ -134,34 +138,108 @@ pub async fn revert_trim(
 // GET /segments/{id}/trim/seed-impact
 // ---------------------------------------------------------------------------
 
-/// Check the downstream seed frame impact of the active trim on a segment.
+/// Query parameters for previewing the seed impact of a proposed trim.
+///
+/// When all three are given the proposed trim is evaluated; otherwise the
+/// segment's active trim is.
+#[derive(Debug, serde::Deserialize)]
+pub struct SeedImpactParams {
+    pub in_frame: Option<i32>,
+    pub out_frame: Option<i32>,
+    pub total_frames: Option<i32>,
+}
+
+/// Check the downstream seed frame impact of a trim on a segment.
+///
+/// Loads the segment's seed anchor and whether the next segment has been
+/// generated, then delegates to [`trimming::compute_seed_impact`] so the UI
+/// can warn before a trim that would force regeneration is applied.
 pub async fn get_seed_frame_impact(
     State(state): State<AppState>,
     Path(segment_id): Path<DbId>,
+    Query(params): Query<SeedImpactParams>,
 ) -> AppResult<impl IntoResponse> {
-    let active = SegmentTrimRepo::get_active_trim(&state.pool, segment_id).await?;
+    let trim = match (params.in_frame, params.out_frame, params.total_frames) {
+        (Some(in_frame), Some(out_frame), Some(total_frames)) => {
+            trimming::validate_trim_points(in_frame, out_frame, total_frames)?;
+            Some((
+                TrimRange {
+                    in_frame,
+                    out_frame,
+                },
+                total_frames,
+            ))
+        }
+        _ => SegmentTrimRepo::get_active_trim(&state.pool, segment_id)
+            .await?
+            .map(|active| {
+                (
+                    TrimRange {
+                        in_frame: active.in_frame,
+                        out_frame: active.out_frame,
+                    },
+                    active.total_original_frames,
+                )
+            }),
+    };
 
-    let impact = match active {
-        Some(trim) => {
-            let new_seed = seed_frame_after_trim(trim.out_frame);
-            SeedFrameUpdate {
+    let Some((trim, total_frames)) = trim else {
+        return Ok(Json(DataResponse {
+            data: SeedFrameUpdate {
                 segment_id,
-                new_seed_frame: new_seed,
-                // In a full implementation, this would look up the next
-                // segment in the scene and check if it has been generated.
+                new_seed_frame: 0,
                 downstream_segment_id: None,
                 downstream_invalidated: false,
-            }
-        }
-        None => SeedFrameUpdate {
+                impact: None,
+            },
+        }));
+    };
+
+    let (impact, downstream_segment_id) =
+        seed_impact(&state.pool, segment_id, trim, total_frames).await?;
+
+    Ok(Json(DataResponse {
+        data: SeedFrameUpdate {
             segment_id,
-            new_seed_frame: 0,
-            downstream_segment_id: None,
-            downstream_invalidated: false,
+            new_seed_frame: impact.new_seed_frame,
+            downstream_segment_id,
+            downstream_invalidated: impact.regeneration_required,
+            impact: Some(impact),
         },
-    };
+    }))
+}
 
-    Ok(Json(DataResponse { data: impact }))
+/// Compute the seed impact of trimming a segment to `trim`.
+///
+/// Loads the segment's seed anchor and whether the next segment has been
+/// generated. Returns the report and the next segment's ID, if any.
+async fn seed_impact(
+    pool: &sqlx::PgPool,
+    segment_id: DbId,
+    trim: TrimRange,
+    total_frames: i32,
+) -> AppResult<(SeedImpactReport, Option<DbId>)> {
+    let segment = SegmentRepo::find_by_id(pool, segment_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Segment",
+            id: segment_id,
+        }))?;
+    let downstream =
+        SegmentRepo::find_by_scene_and_index(pool, segment.scene_id, segment.sequence_index + 1)
+            .await?;
+
+    let impact = trimming::compute_seed_impact(
+        SegmentFrames {
+            total_frames,
+            seed_anchor_frame: segment.boundary_frame_index,
+            downstream_generated: downstream
+                .as_ref()
+                .is_some_and(|next| next.output_video_path.is_some()),
+        },
+        trim,
+    );
+    Ok((impact, downstream.map(|next| next.id)))
 }
 
 // ---------------------------------------------------------------------------
@@ -169,49 +247,115 @@ pub async fn get_seed_frame_impact(
 // ---------------------------------------------------------------------------
 
 /// Apply the same trim to multiple segments at once.
+///
+/// Segments are trimmed with bounded concurrency. Each segment's seed impact
+/// is checked first: a trim that would invalidate an already generated next
+/// segment is skipped and reported rather than applied. A failure on one
+/// segment is reported in its result and does not abort the batch.
 pub async fn batch_trim(
     State(state): State<AppState>,
     auth: AuthUser,
     Json(body): Json<BatchTrimRequest>,
 ) -> AppResult<impl IntoResponse> {
     trimming::validate_batch_trim_size(body.segment_ids.len())?;
+    let concurrency = body
+        .concurrency
+        .unwrap_or(trimming::DEFAULT_BATCH_TRIM_CONCURRENCY);
+    trimming::validate_batch_trim_concurrency(concurrency)?;
+
+    // Without an explicit total, out_frame is used as the total so the
+    // range only has to be positive.
+    let total_frames = body.total_frames.unwrap_or(body.out_frame);
+    trimming::validate_trim_points(body.in_frame, body.out_frame, total_frames)?;
+    let trim = TrimRange {
+        in_frame: body.in_frame,
+        out_frame: body.out_frame,
+    };
 
-    let mut ids = Vec::with_capacity(body.segment_ids.len());
-
-    for &sid in &body.segment_ids {
-        // For batch trim we use in_frame/out_frame as provided.
-        // The caller is responsible for ensuring total_original_frames is
-        // consistent. We validate the trim points against out_frame as the
-        // total to ensure out > in and the range is positive.
-        trimming::validate_trim_points(body.in_frame, body.out_frame, body.out_frame)?;
-
-        let input = CreateSegmentTrim {
-            segment_id: sid,
-            original_path: String::new(), // batch trim does not set path
-            in_frame: body.in_frame,
-            out_frame: body.out_frame,
-            total_original_frames: body.out_frame,
-            created_by: auth.user_id,
-        };
-        let trim = SegmentTrimRepo::create(&state.pool, &input).await?;
-        ids.push(trim.id);
-    }
+    let results: Vec<BatchTrimResult> = stream::iter(body.segment_ids.iter().copied())
+        .map(|segment_id| {
+            trim_batch_segment(&state.pool, segment_id, trim, total_frames, auth.user_id)
+        })
+        .buffered(concurrency)
+        .collect()
+        .await;
 
-    let count = ids.len();
+    let trim_ids: Vec<DbId> = results.iter().filter_map(|r| r.trim_id).collect();
+    let summary = BatchTrimSummary::from_statuses(results.iter().map(|r| r.status));
 
-    tracing::info!(count, user_id = auth.user_id, "Batch segment trims created");
+    tracing::info!(
+        trimmed = summary.trimmed,
+        skipped = summary.skipped,
+        failed = summary.failed,
+        concurrency,
+        user_id = auth.user_id,
+        "Batch segment trims applied"
+    );
 
     Ok((
         StatusCode::CREATED,
         Json(DataResponse {
             data: BatchTrimResponse {
-                trim_ids: ids,
-                count,
+                count: trim_ids.len(),
+                trim_ids,
+                results,
+                summary,
             },
         }),
     ))
 }
 
+/// Check one segment's seed impact and, if safe, create its trim.
+async fn trim_batch_segment(
+    pool: &sqlx::PgPool,
+    segment_id: DbId,
+    trim: TrimRange,
+    total_frames: i32,
+    user_id: DbId,
+) -> BatchTrimResult {
+    let result = |status, trim_id, reason, impact| BatchTrimResult {
+        segment_id,
+        status,
+        trim_id,
+        reason,
+        impact,
+    };
+
+    let impact = match seed_impact(pool, segment_id, trim, total_frames).await {
+        Ok((impact, _)) => impact,
+        Err(e) => return result(BatchTrimStatus::Failed, None, Some(e.to_string()), None),
+    };
+    if let Some(reason) = trimming::batch_trim_skip_reason(&impact) {
+        return result(BatchTrimStatus::Skipped, None, Some(reason), Some(impact));
+    }
+
+    let input = CreateSegmentTrim {
+        segment_id,
+        original_path: String::new(), // batch trim does not set path
+        in_frame: trim.in_frame,
+        out_frame: trim.out_frame,
+        total_original_frames: total_frames,
+        created_by: user_id,
+    };
+    match SegmentTrimRepo::create(pool, &input).await {
+        Ok(created) => result(
+            BatchTrimStatus::Trimmed,
+            Some(created.id),
+            None,
+            Some(impact),
+        ),
+        Err(e) => {
+            tracing::warn!(segment_id, error = %e, "Batch segment trim failed");
+            result(
+                BatchTrimStatus::Failed,
+                None,
+                Some(AppError::from(e).to_string()),
+                Some(impact),
+            )
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // POST /trims/preset
 // ---------------------------------------------------------------------------
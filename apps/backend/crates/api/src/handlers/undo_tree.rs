@@ -3,12 +3,13 @@
 //! Provides GET/PUT/DELETE endpoints for per-user per-entity undo trees.
 //! All endpoints require authentication.
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
 
-use x121_core::undo::{validate_entity_type, validate_tree_json};
-use x121_db::models::undo_tree::SaveUndoTree;
+use x121_core::search::{clamp_limit, clamp_offset};
+use x121_core::undo::{validate_entity_type, validate_tree_json, DEFAULT_UNDO_TREE_LIST_LIMIT};
+use x121_db::models::undo_tree::{SaveUndoTree, UndoTreeListParams};
 use x121_db::repositories::UndoTreeRepo;
 
 use crate::error::AppResult;
@@ -86,13 +87,27 @@ pub async fn delete_tree(
 // User-level undo tree listing
 // ---------------------------------------------------------------------------
 
-/// GET /api/v1/user/undo-trees
+/// GET /api/v1/user/undo-trees?entity_type=&limit=&offset=
 ///
-/// Lists all undo trees for the authenticated user.
+/// Lists summaries of the authenticated user's undo trees, most recently
+/// updated first. The page size is capped by `UNDO_TREE_LIST_MAX`.
 pub async fn list_trees(
     auth: AuthUser,
     State(state): State<AppState>,
+    Query(params): Query<UndoTreeListParams>,
 ) -> AppResult<impl IntoResponse> {
-    let trees = UndoTreeRepo::list_trees_for_user(&state.pool, auth.user_id).await?;
+    if let Some(ref entity_type) = params.entity_type {
+        validate_entity_type(entity_type)?;
+    }
+
+    let max = state.config.undo_tree_list_max;
+    let trees = UndoTreeRepo::list_summaries_for_user(
+        &state.pool,
+        auth.user_id,
+        params.entity_type.as_deref(),
+        clamp_limit(params.limit, DEFAULT_UNDO_TREE_LIST_LIMIT.min(max), max),
+        clamp_offset(params.offset),
+    )
+    .await?;
     Ok(Json(DataResponse { data: trees }))
 }
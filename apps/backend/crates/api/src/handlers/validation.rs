@@ -8,12 +8,11 @@ use axum::http::StatusCode;
 use axum::Json;
 use serde::Deserialize;
 use x121_core::error::CoreError;
-use x121_core::import_status::{
-    IMPORT_STATUS_COMMITTED, IMPORT_STATUS_PREVIEW, IMPORT_STATUS_PREVIEW_ID,
-};
+use x121_core::import_status::{IMPORT_STATUS_COMMITTED, IMPORT_STATUS_PREVIEW};
 use x121_core::types::DbId;
 use x121_core::validation::conflict::ConflictResolutionChoice;
 use x121_core::validation::evaluator::evaluate_rules;
+use x121_core::validation::import_commit::resolve_commit_batch_size;
 use x121_core::validation::import_preview::{ImportAction, ImportPreview, ImportPreviewEntry};
 use x121_core::validation::rules::{ValidationRule, ValidationSeverity};
 use x121_db::models::validation::{
@@ -183,30 +182,67 @@ pub async fn validate(
 pub struct CommitImportRequest {
     #[serde(default)]
     pub conflict_resolutions: Vec<ConflictResolutionChoice>,
+    /// Entries applied per transaction. Defaults to
+    /// `DEFAULT_COMMIT_BATCH_SIZE`.
+    #[serde(default)]
+    pub batch_size: Option<i64>,
 }
 
 /// POST /api/v1/imports/{id}/commit
 ///
-/// Commit a previously-created import preview. Only reports in `preview`
-/// status (status_id = 1) can be committed. Returns 409 if the report
-/// has already been committed or is in another state.
+/// Commit a previously-created import preview in chunks of `batch_size`
+/// entries, one transaction per chunk. Progress (`committed_records`) and
+/// each entry's outcome are visible via `/imports/{id}/report` as chunks
+/// land. If a chunk fails the report stays in `committing` with the error
+/// recorded; committing it again resumes from the first pending entry.
+///
+/// Returns 409 if the report is neither in `preview` nor `committing`.
 pub async fn commit_import(
     State(state): State<AppState>,
     Path(report_id): Path<DbId>,
-    Json(_body): Json<CommitImportRequest>,
+    Json(body): Json<CommitImportRequest>,
 ) -> AppResult<Json<serde_json::Value>> {
-    let report = ImportReportRepo::find_by_id(&state.pool, report_id)
+    let batch_size = resolve_commit_batch_size(body.batch_size)?;
+
+    if ImportReportRepo::begin_commit(&state.pool, report_id)
         .await?
-        .ok_or(AppError::Core(CoreError::NotFound {
-            entity: "ImportReport",
-            id: report_id,
-        }))?;
+        .is_none()
+    {
+        return Err(
+            match ImportReportRepo::find_by_id(&state.pool, report_id).await? {
+                Some(_) => AppError::Core(CoreError::Conflict(
+                    "Only reports in 'preview' or 'committing' status can be committed".to_string(),
+                )),
+                None => AppError::Core(CoreError::NotFound {
+                    entity: "ImportReport",
+                    id: report_id,
+                }),
+            },
+        );
+    }
 
-    // Only "preview" reports can be committed.
-    if report.status_id != IMPORT_STATUS_PREVIEW_ID {
-        return Err(AppError::Core(CoreError::Conflict(
-            "Only reports in 'preview' status can be committed".to_string(),
-        )));
+    let mut chunks = 0u32;
+    loop {
+        match ImportReportRepo::commit_next_chunk(&state.pool, report_id, batch_size).await {
+            Ok(0) => break,
+            Ok(applied) => {
+                chunks += 1;
+                tracing::debug!(report_id, chunk = chunks, applied, "Import chunk committed");
+                // Let other tasks use the pool between chunks.
+                tokio::task::yield_now().await;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    report_id,
+                    chunks_committed = chunks,
+                    error = %e,
+                    "Import commit interrupted",
+                );
+                ImportReportRepo::record_commit_error(&state.pool, report_id, &e.to_string())
+                    .await?;
+                return Err(e.into());
+            }
+        }
     }
 
     let updated = ImportReportRepo::update_status(&state.pool, report_id, IMPORT_STATUS_COMMITTED)
@@ -11,6 +11,7 @@ use axum::http::header::{self, HeaderMap, HeaderValue};
 use axum::http::StatusCode;
 use axum::response::Response;
 use axum::Json;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::response::DataResponse;
@@ -18,6 +19,7 @@ use tokio::io::{AsyncReadExt, AsyncSeekExt};
 use tokio_util::io::ReaderStream;
 use x121_core::error::CoreError;
 use x121_core::ffmpeg;
+use x121_core::http_range::{self, RangeRequest};
 use x121_core::types::DbId;
 use x121_core::video_sources;
 use x121_db::models::video::{CreateVideoThumbnail, VideoMetadata};
@@ -36,9 +38,6 @@ const THUMB_HEIGHT: i32 = 180;
 /// Default thumbnail extraction interval in seconds.
 const DEFAULT_INTERVAL_SECS: f32 = 1.0;
 
-/// Maximum read chunk size for streaming (1 MiB).
-const MAX_CHUNK_SIZE: u64 = 1024 * 1024;
-
 // ---------------------------------------------------------------------------
 // Query / path types
 // ---------------------------------------------------------------------------
@@ -132,23 +131,6 @@ async fn resolve_thumbnail_dir(
     state.resolve_to_path(&key).await
 }
 
-/// Parse a `Range: bytes=START-END` header value.
-/// Returns `(start, optional_end)`.
-fn parse_range_header(range: &str) -> Option<(u64, Option<u64>)> {
-    let range = range.strip_prefix("bytes=")?;
-    let parts: Vec<&str> = range.splitn(2, '-').collect();
-    if parts.len() != 2 {
-        return None;
-    }
-    let start = parts[0].parse::<u64>().ok()?;
-    let end = if parts[1].is_empty() {
-        None
-    } else {
-        Some(parts[1].parse::<u64>().ok()?)
-    };
-    Some((start, end))
-}
-
 // ---------------------------------------------------------------------------
 // Handlers
 // ---------------------------------------------------------------------------
@@ -156,6 +138,9 @@ fn parse_range_header(range: &str) -> Option<(u64, Option<u64>)> {
 /// GET /api/v1/videos/{source_type}/{source_id}/stream
 ///
 /// Streams a video file with HTTP range request support.
+/// Single, open-ended, and suffix ranges are served with 206; multi-range
+/// and out-of-bounds requests get 416. `If-Range` is honoured against the
+/// file's `ETag` and `Last-Modified`.
 /// Supports `?quality=proxy|full`:
 /// - `proxy`: serves the low-res preview (640x360 H.264 baseline)
 /// - `full`: serves the full-res browser-compatible transcode (H.264 main)
@@ -206,62 +191,73 @@ pub async fn stream_video(
     let file_size = metadata.len();
     let content_type = content_type_for_extension(&file_path);
 
-    // Check for Range header.
-    if let Some(range_value) = headers.get(header::RANGE) {
-        let range_str = range_value
-            .to_str()
-            .map_err(|_| AppError::BadRequest("Invalid Range header".into()))?;
-
-        if let Some((start, end)) = parse_range_header(range_str) {
-            let end = end
-                .map(|e| e.min(file_size - 1))
-                .unwrap_or_else(|| (start + MAX_CHUNK_SIZE - 1).min(file_size - 1));
-
-            if start >= file_size || start > end {
-                return Ok(Response::builder()
-                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
-                    .header(header::CONTENT_RANGE, format!("bytes */{file_size}"))
-                    .body(Body::empty())
-                    .unwrap());
+    let modified: DateTime<Utc> = metadata
+        .modified()
+        .map(DateTime::from)
+        .unwrap_or(DateTime::UNIX_EPOCH);
+    let etag = http_range::file_etag(file_size, modified);
+    let last_modified = http_range::http_date(modified);
+
+    // A Range header is honoured only if If-Range (when present) still
+    // matches; otherwise the client's cached prefix is stale and it gets
+    // the whole file.
+    let range = match headers.get(header::RANGE) {
+        Some(range_value) => {
+            let range_str = range_value
+                .to_str()
+                .map_err(|_| AppError::BadRequest("Invalid Range header".into()))?;
+            let if_range = headers.get(header::IF_RANGE).and_then(|v| v.to_str().ok());
+            if http_range::if_range_matches(if_range, &etag, &last_modified) {
+                http_range::resolve_range(range_str, file_size)
+            } else {
+                RangeRequest::Full
             }
+        }
+        None => RangeRequest::Full,
+    };
 
-            let length = end - start + 1;
-
-            let mut file = tokio::fs::File::open(&path)
-                .await
-                .map_err(|e| AppError::InternalError(e.to_string()))?;
-            file.seek(std::io::SeekFrom::Start(start))
-                .await
-                .map_err(|e| AppError::InternalError(e.to_string()))?;
-
-            let limited = file.take(length);
-            let stream = ReaderStream::new(limited);
+    let builder = Response::builder()
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::ETAG, &etag)
+        .header(header::LAST_MODIFIED, &last_modified);
 
-            return Ok(Response::builder()
-                .status(StatusCode::PARTIAL_CONTENT)
-                .header(header::CONTENT_TYPE, content_type)
-                .header(header::CONTENT_LENGTH, length.to_string())
+    let (builder, start, length) = match range {
+        RangeRequest::Unsatisfiable => {
+            return Ok(builder
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
                 .header(
                     header::CONTENT_RANGE,
-                    format!("bytes {start}-{end}/{file_size}"),
+                    http_range::unsatisfiable_content_range(file_size),
                 )
-                .header(header::ACCEPT_RANGES, "bytes")
-                .body(Body::from_stream(stream))
+                .body(Body::empty())
                 .unwrap());
         }
-    }
+        RangeRequest::Partial(r) => (
+            builder
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header(header::CONTENT_RANGE, r.content_range(file_size)),
+            r.start,
+            r.byte_count(),
+        ),
+        RangeRequest::Full => (builder.status(StatusCode::OK), 0, file_size),
+    };
 
-    // No Range header — serve the full file.
-    let file = tokio::fs::File::open(&path)
+    // Stream the selected bytes in bounded chunks; only one read buffer is
+    // held in memory regardless of the range size.
+    let mut file = tokio::fs::File::open(&path)
         .await
         .map_err(|e| AppError::InternalError(e.to_string()))?;
-    let stream = ReaderStream::new(file);
+    if start > 0 {
+        file.seek(std::io::SeekFrom::Start(start))
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+    }
+    let stream =
+        ReaderStream::with_capacity(file.take(length), state.config.video_stream_chunk_bytes);
 
-    Ok(Response::builder()
-        .status(StatusCode::OK)
+    Ok(builder
         .header(header::CONTENT_TYPE, content_type)
-        .header(header::CONTENT_LENGTH, file_size.to_string())
-        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::CONTENT_LENGTH, length.to_string())
         .body(Body::from_stream(stream))
         .unwrap())
 }
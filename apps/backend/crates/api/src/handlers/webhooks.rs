@@ -1,21 +1,25 @@
 //! Admin handlers for webhook management (PRD-12).
 //!
 //! All endpoints require the admin role via [`RequireAdmin`].
-//! Provides CRUD for webhooks, delivery history, test delivery, and replay.
+//! Provides CRUD for webhooks, delivery history, test delivery, replay, and
+//! manual re-enable of webhooks suspended by the circuit breaker.
 
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
+use serde::{Deserialize, Serialize};
+use x121_core::api_keys::{DeliveryOutcome, WebhookCircuitState};
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset};
-use x121_core::types::DbId;
-use x121_db::models::api_key::{CreateWebhook, UpdateWebhook};
+use x121_core::types::{DbId, Timestamp};
+use x121_db::models::api_key::{
+    CreateWebhook, UpdateWebhook, WebhookDelivery, WebhookDeliveryFilter,
+};
 use x121_db::repositories::WebhookRepo;
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::rbac::RequireAdmin;
-use crate::query::PaginationParams;
 use crate::response::DataResponse;
 use crate::state::AppState;
 
@@ -131,18 +135,68 @@ pub async fn delete_webhook(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// POST /api/v1/admin/webhooks/{id}/reenable
+///
+/// Re-activate a webhook suspended after repeated delivery failures.
+/// Held-back deliveries resume on the next worker poll.
+pub async fn reenable_webhook(
+    RequireAdmin(admin): RequireAdmin,
+    State(state): State<AppState>,
+    Path(webhook_id): Path<DbId>,
+) -> AppResult<impl IntoResponse> {
+    let webhook = WebhookRepo::update_circuit(
+        &state.pool,
+        webhook_id,
+        WebhookCircuitState::Active.as_str(),
+        0,
+        None,
+    )
+    .await?
+    .ok_or(AppError::Core(CoreError::NotFound {
+        entity: "Webhook",
+        id: webhook_id,
+    }))?;
+
+    tracing::info!(webhook_id, user_id = admin.user_id, "Webhook re-enabled",);
+
+    Ok(Json(DataResponse { data: webhook }))
+}
+
 // ---------------------------------------------------------------------------
 // Delivery management
 // ---------------------------------------------------------------------------
 
+/// Query parameters for a webhook's delivery history.
+#[derive(Debug, Deserialize)]
+pub struct DeliveryHistoryParams {
+    /// `success`, `failed`, `retrying`, or `dead_lettered`.
+    pub outcome: Option<String>,
+    /// Only deliveries created at or after this instant.
+    pub since: Option<Timestamp>,
+    /// Only deliveries created before this instant.
+    pub until: Option<Timestamp>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// One page of a webhook's delivery history, newest first.
+#[derive(Debug, Serialize)]
+pub struct DeliveryHistoryPage {
+    pub items: Vec<WebhookDelivery>,
+    /// Deliveries matching the filter across all pages.
+    pub total: i64,
+}
+
 /// GET /api/v1/admin/webhooks/{id}/deliveries
 ///
-/// List delivery history for a specific webhook.
+/// List delivery history for a specific webhook, optionally filtered by
+/// outcome and creation time range. Each delivery carries its attempt count
+/// and the latency of its most recent attempt.
 pub async fn list_deliveries(
     _admin: RequireAdmin,
     State(state): State<AppState>,
     Path(webhook_id): Path<DbId>,
-    Query(params): Query<PaginationParams>,
+    Query(params): Query<DeliveryHistoryParams>,
 ) -> AppResult<impl IntoResponse> {
     // Verify webhook exists
     WebhookRepo::find_by_id(&state.pool, webhook_id)
@@ -152,13 +206,36 @@ pub async fn list_deliveries(
             id: webhook_id,
         }))?;
 
+    let status = params
+        .outcome
+        .as_deref()
+        .map(DeliveryOutcome::parse)
+        .transpose()?
+        .map(DeliveryOutcome::delivery_status);
+    if let (Some(since), Some(until)) = (params.since, params.until) {
+        if since >= until {
+            return Err(AppError::BadRequest(
+                "since must be earlier than until".into(),
+            ));
+        }
+    }
+    let filter = WebhookDeliveryFilter {
+        status,
+        since: params.since,
+        until: params.until,
+    };
+
     let limit = clamp_limit(params.limit, 50, 200);
     let offset = clamp_offset(params.offset);
 
-    let deliveries =
-        WebhookRepo::list_deliveries_for_webhook(&state.pool, webhook_id, limit, offset).await?;
+    let items =
+        WebhookRepo::list_deliveries_for_webhook(&state.pool, webhook_id, &filter, limit, offset)
+            .await?;
+    let total = WebhookRepo::count_deliveries_for_webhook(&state.pool, webhook_id, &filter).await?;
 
-    Ok(Json(DataResponse { data: deliveries }))
+    Ok(Json(DataResponse {
+        data: DeliveryHistoryPage { items, total },
+    }))
 }
 
 /// POST /api/v1/admin/webhooks/{id}/test
@@ -204,7 +281,8 @@ pub async fn test_webhook(
 
 /// POST /api/v1/admin/webhooks/deliveries/{id}/replay
 ///
-/// Replay a failed or delivered webhook delivery by resetting its status.
+/// Replay a failed, dead-lettered, or delivered webhook delivery by
+/// resetting its status; the delivery worker re-attempts it on its next poll.
 pub async fn replay_delivery(
     RequireAdmin(admin): RequireAdmin,
     State(state): State<AppState>,
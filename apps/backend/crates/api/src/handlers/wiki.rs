@@ -10,15 +10,19 @@ use axum::Json;
 
 use x121_core::error::CoreError;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
+use x121_core::types::DbId;
 use x121_core::wiki::{
-    compute_line_diff, generate_slug, validate_category, validate_content, validate_pin_location,
-    validate_slug, validate_tags, validate_title, DiffLineType,
+    compute_line_diff, compute_version_diff, generate_slug, validate_category, validate_content,
+    validate_pin_location, validate_slug, validate_tags, validate_title, DiffLineType,
+    VersionSnapshot, EVENT_ARTICLE_CREATED, EVENT_ARTICLE_DELETED, EVENT_ARTICLE_UPDATED,
 };
 use x121_db::models::wiki_article::{
-    ContextualHelpResponse, CreateWikiArticle, DiffLineDto, DiffRequest, DiffResponse,
-    UpdateWikiArticle, WikiArticle,
+    ContextualHelpResponse, CreateWikiArticle, DiffLineDto, DiffRequest, DiffResponse, DiffSpanDto,
+    TitleChangeDto, UpdateWikiArticle, WikiArticle, WordDiffResponse,
 };
+use x121_db::models::wiki_version::WikiVersion;
 use x121_db::repositories::{WikiArticleRepo, WikiVersionRepo};
+use x121_events::PlatformEvent;
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::auth::AuthUser;
@@ -59,6 +63,16 @@ async fn ensure_article_by_slug(pool: &sqlx::PgPool, slug: &str) -> AppResult<Wi
         })
 }
 
+/// Publish a wiki article lifecycle event. The search indexer subscribes to
+/// these to keep the article's index entry current.
+fn publish_article_event(state: &AppState, event_type: &str, article_id: DbId, user_id: DbId) {
+    state.event_bus.publish(
+        PlatformEvent::new(event_type)
+            .with_source("wiki_article", article_id)
+            .with_actor(user_id),
+    );
+}
+
 /* --------------------------------------------------------------------------
 Article CRUD
 -------------------------------------------------------------------------- */
@@ -129,6 +143,7 @@ pub async fn create_article(
         slug = %article.slug,
         "Wiki article created"
     );
+    publish_article_event(&state, EVENT_ARTICLE_CREATED, article.id, auth.user_id);
 
     Ok((StatusCode::CREATED, Json(DataResponse { data: article })))
 }
@@ -147,7 +162,8 @@ pub async fn get_article_by_slug(
 
 /// PUT /wiki/articles/{slug}
 ///
-/// Update a wiki article. Creates a new version if content changes.
+/// Update a wiki article. Creates a new version if the title or content
+/// changes.
 pub async fn update_article(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -180,6 +196,7 @@ pub async fn update_article(
         slug = %slug,
         "Wiki article updated"
     );
+    publish_article_event(&state, EVENT_ARTICLE_UPDATED, article.id, auth.user_id);
 
     Ok(Json(DataResponse { data: article }))
 }
@@ -208,6 +225,7 @@ pub async fn delete_article(
         slug = %slug,
         "Wiki article deleted"
     );
+    publish_article_event(&state, EVENT_ARTICLE_DELETED, article.id, auth.user_id);
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -251,15 +269,18 @@ pub async fn get_version(
 
 /// POST /wiki/articles/{slug}/revert/{version}
 ///
-/// Revert an article to a previous version, creating a new version.
+/// Revert an article to a previous version. History is preserved: a new
+/// version is appended with the reverted-to content, recording the editor
+/// and the versions reverted from and to.
 pub async fn revert_to_version(
     auth: AuthUser,
     State(state): State<AppState>,
     Path((slug, version)): Path<(String, i32)>,
 ) -> AppResult<impl IntoResponse> {
     let article = ensure_article_by_slug(&state.pool, &slug).await?;
-    let old_version =
-        WikiVersionRepo::find_by_article_and_version(&state.pool, article.id, version)
+
+    let result =
+        WikiArticleRepo::revert_to_version(&state.pool, article.id, version, Some(auth.user_id))
             .await?
             .ok_or_else(|| {
                 AppError::Core(CoreError::Validation(format!(
@@ -268,22 +289,17 @@ pub async fn revert_to_version(
                 )))
             })?;
 
-    let updated = WikiArticleRepo::revert_to_version(
-        &state.pool,
-        article.id,
-        &old_version,
-        Some(auth.user_id),
-    )
-    .await?;
-
     tracing::info!(
         user_id = auth.user_id,
         article_id = article.id,
+        reverted_from = result.version.reverted_from_version,
         reverted_to = version,
+        new_version = result.version.version,
         "Wiki article reverted"
     );
+    publish_article_event(&state, EVENT_ARTICLE_UPDATED, article.id, auth.user_id);
 
-    Ok(Json(DataResponse { data: updated }))
+    Ok(Json(DataResponse { data: result }))
 }
 
 /* --------------------------------------------------------------------------
@@ -300,33 +316,14 @@ pub async fn diff_versions(
     Query(params): Query<DiffRequest>,
 ) -> AppResult<impl IntoResponse> {
     let article = ensure_article_by_slug(&state.pool, &slug).await?;
-
-    let v1 = WikiVersionRepo::find_by_article_and_version(&state.pool, article.id, params.v1)
-        .await?
-        .ok_or_else(|| {
-            AppError::Core(CoreError::Validation(format!(
-                "Version {} not found",
-                params.v1
-            )))
-        })?;
-    let v2 = WikiVersionRepo::find_by_article_and_version(&state.pool, article.id, params.v2)
-        .await?
-        .ok_or_else(|| {
-            AppError::Core(CoreError::Validation(format!(
-                "Version {} not found",
-                params.v2
-            )))
-        })?;
+    let v1 = ensure_version(&state.pool, article.id, params.v1).await?;
+    let v2 = ensure_version(&state.pool, article.id, params.v2).await?;
 
     let diff = compute_line_diff(&v1.content_md, &v2.content_md);
     let lines: Vec<DiffLineDto> = diff
         .into_iter()
         .map(|d| DiffLineDto {
-            line_type: match d.line_type {
-                DiffLineType::Added => "added".to_string(),
-                DiffLineType::Removed => "removed".to_string(),
-                DiffLineType::Unchanged => "unchanged".to_string(),
-            },
+            line_type: diff_type_label(&d.line_type),
             content: d.content,
         })
         .collect();
@@ -342,6 +339,84 @@ pub async fn diff_versions(
     Ok(Json(DataResponse { data: response }))
 }
 
+/// GET /wiki/articles/{slug}/word-diff?v1=X&v2=Y
+///
+/// Compute a word-level diff from version `v1` to version `v2`, with the
+/// title change and editor of `v2`.
+pub async fn word_diff_versions(
+    _auth: AuthUser,
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    Query(params): Query<DiffRequest>,
+) -> AppResult<impl IntoResponse> {
+    let article = ensure_article_by_slug(&state.pool, &slug).await?;
+    let v1 = ensure_version(&state.pool, article.id, params.v1).await?;
+    let v2 = ensure_version(&state.pool, article.id, params.v2).await?;
+
+    let diff = compute_version_diff(&version_snapshot(&v1), &version_snapshot(&v2));
+
+    let response = WordDiffResponse {
+        article_id: article.id,
+        slug: article.slug,
+        v1: params.v1,
+        v2: params.v2,
+        title_change: diff.title_change.map(|t| TitleChangeDto {
+            from: t.from,
+            to: t.to,
+        }),
+        author: diff.author,
+        edit_summary: diff.edit_summary,
+        words_added: diff.words_added,
+        words_removed: diff.words_removed,
+        spans: diff
+            .spans
+            .into_iter()
+            .map(|s| DiffSpanDto {
+                span_type: diff_type_label(&s.span_type),
+                text: s.text,
+            })
+            .collect(),
+    };
+
+    Ok(Json(DataResponse { data: response }))
+}
+
+/// Fetch a specific version of an article or return a validation error.
+async fn ensure_version(
+    pool: &sqlx::PgPool,
+    article_id: DbId,
+    version: i32,
+) -> AppResult<WikiVersion> {
+    WikiVersionRepo::find_by_article_and_version(pool, article_id, version)
+        .await?
+        .ok_or_else(|| {
+            AppError::Core(CoreError::Validation(format!(
+                "Version {} not found",
+                version
+            )))
+        })
+}
+
+/// Borrow a version row as the fields compared by a word diff.
+fn version_snapshot(version: &WikiVersion) -> VersionSnapshot<'_> {
+    VersionSnapshot {
+        version: version.version,
+        title: version.title.as_deref(),
+        content_md: &version.content_md,
+        edited_by: version.edited_by,
+        edit_summary: version.edit_summary.as_deref(),
+    }
+}
+
+/// Serialized name of a diff line or span type.
+fn diff_type_label(diff_type: &DiffLineType) -> String {
+    match diff_type {
+        DiffLineType::Added => "added".to_string(),
+        DiffLineType::Removed => "removed".to_string(),
+        DiffLineType::Unchanged => "unchanged".to_string(),
+    }
+}
+
 /* --------------------------------------------------------------------------
 Search
 -------------------------------------------------------------------------- */
@@ -3,7 +3,7 @@
 //! Provides endpoints for canvas layout persistence, per-node timing
 //! telemetry, and ComfyUI workflow JSON import.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -12,8 +12,9 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 use x121_core::error::CoreError;
 use x121_core::types::DbId;
+use x121_core::workflow_import;
 use x121_db::models::workflow_layout::CreateWorkflowLayout;
-use x121_db::repositories::WorkflowLayoutRepo;
+use x121_db::repositories::{WorkflowLayoutRepo, WorkflowRepo};
 
 use crate::error::{AppError, AppResult};
 use crate::middleware::rbac::RequireAuth;
@@ -83,11 +84,20 @@ pub struct NodeTiming {
 /// Populated once the ComfyUI WebSocket bridge (PRD-05) streams execution
 /// events into the telemetry store. Until then, `nodes` will be empty and
 /// `total_ms` will be `None`.
+///
+/// The structural counts are derived from the stored workflow JSON and are
+/// always present.
 #[derive(Debug, Clone, Serialize)]
 pub struct WorkflowTelemetry {
     pub workflow_id: DbId,
     pub nodes: HashMap<String, NodeTiming>,
     pub total_ms: Option<u64>,
+    /// Number of nodes in the workflow.
+    pub total_nodes: usize,
+    /// Number of connections between nodes.
+    pub total_connections: usize,
+    /// Node count per class type, sorted by class type.
+    pub class_type_counts: BTreeMap<String, usize>,
 }
 
 // ---------------------------------------------------------------------------
@@ -96,18 +106,31 @@ pub struct WorkflowTelemetry {
 
 /// GET /api/v1/workflows/:id/telemetry
 ///
-/// Return per-node timing telemetry for recent runs of a workflow.
+/// Return per-node timing telemetry for recent runs of a workflow, along
+/// with its node and per-class-type counts.
 ///
-/// Returns a typed but empty telemetry response until the ComfyUI WebSocket
-/// bridge (PRD-05) streams execution events into the telemetry store.
+/// Timing fields stay empty until the ComfyUI WebSocket bridge (PRD-05)
+/// streams execution events into the telemetry store.
 pub async fn get_telemetry(
     RequireAuth(_user): RequireAuth,
+    State(state): State<AppState>,
     Path(workflow_id): Path<DbId>,
 ) -> AppResult<impl IntoResponse> {
+    let workflow = WorkflowRepo::find_by_id(&state.pool, workflow_id)
+        .await?
+        .ok_or(AppError::Core(CoreError::NotFound {
+            entity: "Workflow",
+            id: workflow_id,
+        }))?;
+    let parsed = workflow_import::parse_workflow(&workflow.json_content)?;
+
     let telemetry = WorkflowTelemetry {
         workflow_id,
         nodes: HashMap::new(),
         total_ms: None,
+        total_nodes: parsed.total_nodes(),
+        total_connections: parsed.total_connections(),
+        class_type_counts: parsed.class_type_counts(),
     };
 
     Ok(Json(DataResponse { data: telemetry }))
@@ -139,6 +162,13 @@ pub async fn import_comfyui(
         )));
     }
 
+    // Reject cyclic graphs, which ComfyUI can never execute. Nodes the
+    // strict parser rejects (e.g. without `class_type`) are still imported
+    // as "unknown" below, so only a parsed graph is checked.
+    if let Ok(parsed) = workflow_import::parse_workflow(&input.workflow_json) {
+        workflow_import::detect_cycles(&parsed)?;
+    }
+
     let workflow_obj = input.workflow_json.as_object().unwrap();
 
     // Parse ComfyUI nodes from the workflow JSON.
@@ -75,8 +75,9 @@ async fn ensure_workflow_exists(pool: &sqlx::PgPool, id: DbId) -> AppResult<Work
 
 /// Import a new ComfyUI workflow.
 ///
-/// Parses the JSON, validates the name and size, discovers parameters,
-/// creates the workflow record and version 1, and returns the workflow.
+/// Parses the JSON, rejects cyclic node graphs, validates the name and size,
+/// discovers parameters, creates the workflow record and version 1, and
+/// returns the workflow.
 pub async fn import_workflow(
     State(state): State<AppState>,
     auth: AuthUser,
@@ -90,6 +91,7 @@ pub async fn import_workflow(
 
     // Parse the workflow to validate structure.
     let parsed = workflow_import::parse_workflow(&body.json_content)?;
+    workflow_import::detect_cycles(&parsed)?;
 
     // Discover configurable parameters.
     let discovered = workflow_import::discover_parameters(&parsed);
@@ -170,8 +172,9 @@ pub async fn import_workflow(
         workflow_id = workflow.id,
         name = %body.name,
         user_id = auth.user_id,
+        format = ?parsed.workflow_format,
         nodes = parsed.nodes.len(),
-        models = parsed.referenced_models.len(),
+        models = parsed.model_references().count(),
         "Workflow imported"
     );
 
@@ -239,6 +242,7 @@ pub async fn update_workflow(
     if let Some(ref json_content) = body.json_content {
         workflow_import::validate_workflow_json_size(json_content)?;
         let parsed = workflow_import::parse_workflow(json_content)?;
+        workflow_import::detect_cycles(&parsed)?;
         let discovered = workflow_import::discover_parameters(&parsed);
         let discovered_json = serde_json::to_value(&discovered).ok();
 
@@ -398,9 +402,7 @@ pub async fn validate_workflow(
     };
 
     let model_results: Vec<workflow_import::ModelValidationResult> = parsed
-        .referenced_models
-        .iter()
-        .chain(parsed.referenced_loras.iter())
+        .model_references()
         .map(|name| workflow_import::ModelValidationResult {
             model_name: name.clone(),
             found_in_registry: false, // Requires worker filesystem check.
@@ -19,4 +19,5 @@ pub mod router;
 pub mod routes;
 pub mod scripting;
 pub mod state;
+pub mod widget_cache;
 pub mod ws;
@@ -71,10 +71,12 @@ async fn main() {
     }
 
     // --- WebSocket manager ---
-    let ws_manager = Arc::new(ws::WsManager::new());
+    let ws_manager = Arc::new(
+        ws::WsManager::with_heartbeat(config.ws_heartbeat).with_send_queue(config.ws_send_queue),
+    );
 
     // --- Heartbeat ---
-    let heartbeat_handle = ws::start_heartbeat(Arc::clone(&ws_manager));
+    let heartbeat_handle = ws::start_heartbeat(Arc::clone(&ws_manager), config.ws_heartbeat);
 
     // --- ComfyUI manager ---
     let comfyui_manager = x121_comfyui::manager::ComfyUIManager::start_with_activity(
@@ -95,9 +97,15 @@ async fn main() {
     ));
 
     // Spawn notification router (routes events to users via WebSocket).
+    // Events missed while the server was down are replayed first; the
+    // receiver is subscribed up front so nothing published meanwhile is lost.
     let notification_router =
         x121_api::notifications::NotificationRouter::new(pool.clone(), Arc::clone(&ws_manager));
-    let router_handle = tokio::spawn(notification_router.run(event_bus.subscribe()));
+    let router_receiver = event_bus.subscribe();
+    let router_handle = tokio::spawn(async move {
+        notification_router.replay_recent().await;
+        notification_router.run(router_receiver).await;
+    });
 
     // Spawn digest scheduler (checks hourly for digest deliveries).
     let digest_cancel = tokio_util::sync::CancellationToken::new();
@@ -115,6 +123,14 @@ async fn main() {
         retention_cancel_clone,
     ));
 
+    // Spawn GPU quota reset job (advances daily/weekly windows, PRD-08).
+    let quota_reset_cancel = tokio_util::sync::CancellationToken::new();
+    let quota_reset_cancel_clone = quota_reset_cancel.clone();
+    let quota_reset_handle = tokio::spawn(x121_api::background::quota_reset::run(
+        pool.clone(),
+        quota_reset_cancel_clone,
+    ));
+
     // Spawn activity log persistence (batch writes to activity_logs table, PRD-118).
     let activity_persist_cancel = tokio_util::sync::CancellationToken::new();
     let activity_persist_cancel_clone = activity_persist_cancel.clone();
@@ -134,19 +150,36 @@ async fn main() {
         activity_retention_cancel_clone,
     ));
 
+    // Spawn wiki search indexer (debounced incremental reindex on article events, PRD-56).
+    let wiki_index_cancel = tokio_util::sync::CancellationToken::new();
+    let wiki_index_cancel_clone = wiki_index_cancel.clone();
+    let wiki_index_handle = tokio::spawn(x121_api::background::wiki_search_index::run(
+        pool.clone(),
+        event_bus.subscribe(),
+        wiki_index_cancel_clone,
+        Duration::from_millis(config.wiki_index_debounce_ms),
+    ));
+
     // Spawn schedule executor (checks for due schedules every 30s, PRD-134).
     let schedule_executor_cancel = tokio_util::sync::CancellationToken::new();
     let schedule_executor_cancel_clone = schedule_executor_cancel.clone();
 
-    tracing::info!("Event services started (persistence, notification router, digest scheduler, metrics retention, activity log persistence, activity log retention)");
+    tracing::info!("Event services started (persistence, notification router, digest scheduler, metrics retention, activity log persistence, activity log retention, wiki search indexer)");
 
     // --- Script orchestrator (PRD-09) ---
     let venv_base_dir = std::env::var("VENV_BASE_DIR").unwrap_or_else(|_| "./venvs".to_string());
-    let script_orchestrator = Arc::new(x121_api::scripting::orchestrator::ScriptOrchestrator::new(
-        pool.clone(),
-        venv_base_dir,
-    ));
-    tracing::info!("Script orchestrator initialized");
+    let script_orchestrator = Arc::new(
+        x121_api::scripting::orchestrator::ScriptOrchestrator::new(pool.clone(), venv_base_dir)
+            .with_execution_limits(
+                config.script_max_concurrent_executions,
+                config.script_max_queued_executions,
+            ),
+    );
+    tracing::info!(
+        max_concurrent = config.script_max_concurrent_executions,
+        max_queued = config.script_max_queued_executions,
+        "Script orchestrator initialized"
+    );
 
     // --- Health aggregator (PRD-117) ---
     // Created here but polling is started later, after storage provider is initialized.
@@ -318,6 +351,12 @@ async fn main() {
         storage,
         lifecycle_bridge,
         scaling_nudge,
+        embedding_batches: Arc::new(
+            x121_api::engine::embedding_batch::EmbeddingBatchRegistry::new(),
+        ),
+        scene_restitches: Arc::new(x121_api::engine::scene_restitch::SceneRestitchRegistry::new()),
+        worker_repairs: Arc::new(x121_api::engine::worker_repair::WorkerRepairRegistry::new()),
+        widget_cache: Arc::new(x121_api::widget_cache::WidgetCache::default()),
     };
 
     // Spawn schedule executor (needs AppState, so must be after state construction).
@@ -342,6 +381,14 @@ async fn main() {
         video_transcode_cancel_clone,
     ));
 
+    // Spawn webhook delivery worker with circuit breaking (PRD-12).
+    let webhook_delivery_cancel = tokio_util::sync::CancellationToken::new();
+    let webhook_delivery_cancel_clone = webhook_delivery_cancel.clone();
+    let webhook_delivery_handle = tokio::spawn(x121_api::background::webhook_delivery::run(
+        state.clone(),
+        webhook_delivery_cancel_clone,
+    ));
+
     // --- Router ---
     let app = build_app_router(state, &config);
 
@@ -356,10 +403,14 @@ async fn main() {
         .await
         .expect("Failed to bind to address");
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
-        .expect("Server error");
+    // Connect info gives the rate limiter each caller's socket address.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await
+    .expect("Server error");
 
     // --- Post-shutdown cleanup ---
     tracing::info!("Server stopped accepting connections, cleaning up");
@@ -383,6 +434,11 @@ async fn main() {
     let _ = tokio::time::timeout(Duration::from_secs(5), retention_handle).await;
     tracing::info!("Metrics retention job stopped");
 
+    // Stop GPU quota reset job (PRD-08).
+    quota_reset_cancel.cancel();
+    let _ = tokio::time::timeout(Duration::from_secs(5), quota_reset_handle).await;
+    tracing::info!("Quota reset job stopped");
+
     // Stop activity log services (PRD-118).
     activity_persist_cancel.cancel();
     let _ = tokio::time::timeout(Duration::from_secs(5), activity_persist_handle).await;
@@ -390,6 +446,11 @@ async fn main() {
     let _ = tokio::time::timeout(Duration::from_secs(5), activity_retention_handle).await;
     tracing::info!("Activity log services stopped");
 
+    // Stop wiki search indexer (PRD-56), flushing pending reindexes.
+    wiki_index_cancel.cancel();
+    let _ = tokio::time::timeout(Duration::from_secs(5), wiki_index_handle).await;
+    tracing::info!("Wiki search indexer stopped");
+
     // Stop schedule executor (PRD-134).
     schedule_executor_cancel.cancel();
     let _ = tokio::time::timeout(Duration::from_secs(5), schedule_executor_handle).await;
@@ -406,6 +467,11 @@ async fn main() {
     let _ = tokio::time::timeout(Duration::from_secs(30), video_transcode_handle).await;
     tracing::info!("Video transcode worker stopped");
 
+    // Stop webhook delivery worker (PRD-12).
+    webhook_delivery_cancel.cancel();
+    let _ = tokio::time::timeout(Duration::from_secs(15), webhook_delivery_handle).await;
+    tracing::info!("Webhook delivery worker stopped");
+
     // Drop the event bus sender to close the broadcast channel.
     // This signals persistence and notification router to shut down.
     drop(event_bus);
@@ -1,14 +1,47 @@
 //! JWT-based authentication extractor for Axum handlers.
+//!
+//! [`decode_bearer_claims`] validates the bearer token once per request and
+//! stores its [`Claims`] in the request extensions, where the timeout and
+//! rate-limit middleware and the [`AuthUser`] extractor pick them up.
 
-use axum::extract::FromRequestParts;
+use std::sync::Arc;
+
+use axum::extract::{FromRequestParts, Request, State};
 use axum::http::request::Parts;
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
 use x121_core::error::CoreError;
 use x121_core::types::DbId;
 
-use crate::auth::jwt::validate_token;
+use crate::auth::jwt::{validate_token, Claims, JwtConfig};
 use crate::error::AppError;
 use crate::state::AppState;
 
+/// The token from an `Authorization: Bearer <token>` header, if present.
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+}
+
+/// Store the [`Claims`] of a valid bearer token in the request extensions.
+///
+/// Requests without a valid bearer token pass through unchanged; handlers
+/// that require authentication reject them through [`AuthUser`].
+pub async fn decode_bearer_claims(
+    State(jwt): State<Arc<JwtConfig>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let claims = bearer_token(request.headers()).and_then(|token| validate_token(token, &jwt).ok());
+    if let Some(claims) = claims {
+        request.extensions_mut().insert(claims);
+    }
+    next.run(request).await
+}
+
 /// Authenticated user extracted from a JWT Bearer token in the `Authorization` header.
 ///
 /// Use this as an extractor parameter in any handler that requires authentication:
@@ -34,6 +67,14 @@ impl FromRequestParts<AppState> for AuthUser {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
+        // Reuse the claims decoded by `decode_bearer_claims`, if any.
+        if let Some(claims) = parts.extensions.get::<Claims>() {
+            return Ok(AuthUser {
+                user_id: claims.sub,
+                role: claims.role.clone(),
+            });
+        }
+
         // Try Authorization header first, then fall back to ?token= query param
         // (needed for WebSocket connections which can't set custom headers).
         let token = if let Some(auth_header) = parts
@@ -1,9 +1,14 @@
-//! Authentication and authorization middleware extractors.
+//! Authentication and authorization middleware extractors, plus the
+//! role-aware request timeout and per-caller rate limiting layers.
 //!
 //! - [`auth::AuthUser`] -- Extracts the authenticated user from a JWT Bearer token.
 //! - [`rbac::RequireAdmin`] -- Requires the `admin` role.
 //! - [`rbac::RequireCreator`] -- Requires `creator` or `admin` role.
 //! - [`rbac::RequireAuth`] -- Requires any authenticated user.
+//! - [`timeout::role_timeout`] -- Applies the caller's role timeout budget.
+//! - [`rate_limit::rate_limit`] -- Rejects callers that exceed their request rate.
 
 pub mod auth;
+pub mod rate_limit;
 pub mod rbac;
+pub mod timeout;
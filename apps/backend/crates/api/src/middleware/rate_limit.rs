@@ -0,0 +1,411 @@
+//! Per-caller request rate limiting.
+//!
+//! Each caller gets a token bucket holding up to `burst` requests that
+//! refills at `requests_per_minute`. Callers with a valid bearer token are
+//! keyed by user id, so one user's tabs and extensions share a budget;
+//! everyone else is keyed by client IP. The client IP is the socket peer;
+//! `X-Forwarded-For` / `X-Real-IP` are honored only when the peer is one of
+//! the configured trusted proxies, since any client can set them. A request
+//! arriving at an empty bucket gets `429 Too Many Requests` with a
+//! `Retry-After` header.
+//!
+//! Buckets live in memory and are pruned once they have been idle long
+//! enough to refill completely, since such a bucket is indistinguishable
+//! from a fresh one.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, HeaderValue};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use x121_core::types::DbId;
+
+use crate::auth::jwt::Claims;
+use crate::error::AppError;
+use crate::request::extract_ip;
+
+/// Default sustained request rate per caller.
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 600;
+
+/// Default number of requests a caller may make in a burst.
+pub const DEFAULT_BURST: u32 = 120;
+
+/// How often idle buckets are pruned.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Sustained rate and burst size for the [`rate_limit`] middleware.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Requests per minute each caller's bucket refills at. `0` disables
+    /// rate limiting.
+    pub requests_per_minute: u32,
+    /// Bucket capacity: requests allowed back to back after an idle period.
+    pub burst: u32,
+}
+
+impl RateLimitConfig {
+    /// Whether requests should be limited at all.
+    pub fn is_enabled(&self) -> bool {
+        self.requests_per_minute > 0 && self.burst > 0
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: DEFAULT_REQUESTS_PER_MINUTE,
+            burst: DEFAULT_BURST,
+        }
+    }
+}
+
+/// Who a request is counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// An authenticated user.
+    User(DbId),
+    /// An unauthenticated client, by IP address.
+    Ip(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+struct Buckets {
+    by_key: HashMap<RateLimitKey, Bucket>,
+    last_pruned: Instant,
+}
+
+/// In-memory token buckets shared by the [`rate_limit`] middleware.
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    /// Peers whose forwarding headers name the real client.
+    trusted_proxies: Vec<IpAddr>,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    /// Create a limiter with no tracked callers.
+    pub fn new(config: RateLimitConfig, trusted_proxies: Vec<IpAddr>) -> Self {
+        Self {
+            config,
+            trusted_proxies,
+            buckets: Mutex::new(Buckets {
+                by_key: HashMap::new(),
+                last_pruned: Instant::now(),
+            }),
+        }
+    }
+
+    /// Tokens added to a bucket per second.
+    fn refill_per_sec(&self) -> f64 {
+        f64::from(self.config.requests_per_minute) / 60.0
+    }
+
+    /// Idle time after which a bucket is full again.
+    fn full_refill(&self) -> Duration {
+        Duration::from_secs_f64(f64::from(self.config.burst) / self.refill_per_sec())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buckets> {
+        self.buckets.lock().expect("rate limiter lock poisoned")
+    }
+
+    /// Take one token from `key`'s bucket as of `now`.
+    ///
+    /// Returns how long until a token is available when the bucket is empty.
+    /// Idle buckets are pruned at most once per minute along the way.
+    pub fn check(&self, key: RateLimitKey, now: Instant) -> Result<(), Duration> {
+        let burst = f64::from(self.config.burst);
+        let refill_per_sec = self.refill_per_sec();
+
+        let mut buckets = self.lock();
+        if now.saturating_duration_since(buckets.last_pruned) >= PRUNE_INTERVAL {
+            Self::prune_locked(&mut buckets, now, self.full_refill());
+        }
+
+        let bucket = buckets.by_key.entry(key).or_insert(Bucket {
+            tokens: burst,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_sec).min(burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / refill_per_sec,
+            ))
+        }
+    }
+
+    /// Drop buckets that have refilled completely as of `now`, returning how
+    /// many were removed.
+    pub fn prune(&self, now: Instant) -> usize {
+        let full_refill = self.full_refill();
+        Self::prune_locked(&mut self.lock(), now, full_refill)
+    }
+
+    fn prune_locked(buckets: &mut Buckets, now: Instant, full_refill: Duration) -> usize {
+        let before = buckets.by_key.len();
+        buckets
+            .by_key
+            .retain(|_, bucket| now.saturating_duration_since(bucket.updated) < full_refill);
+        buckets.last_pruned = now;
+        before - buckets.by_key.len()
+    }
+
+    /// Number of callers currently tracked.
+    pub fn tracked(&self) -> usize {
+        self.lock().by_key.len()
+    }
+
+    /// Pick the key a request is counted against: the user id from the
+    /// decoded bearer token, else the client IP.
+    ///
+    /// The client IP is read from proxy headers only when the socket peer is
+    /// a trusted proxy; otherwise it is the peer itself.
+    pub fn key_for(&self, request: &Request) -> RateLimitKey {
+        if let Some(claims) = request.extensions().get::<Claims>() {
+            return RateLimitKey::User(claims.sub);
+        }
+
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let ip = match peer {
+            Some(peer) if self.trusted_proxies.contains(&peer) => {
+                extract_ip(request.headers()).unwrap_or_else(|| peer.to_string())
+            }
+            Some(peer) => peer.to_string(),
+            None => "unknown".to_string(),
+        };
+        RateLimitKey::Ip(ip)
+    }
+}
+
+/// Reject the request with `429 Too Many Requests` once the caller's bucket
+/// is empty.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = limiter.key_for(&request);
+    match limiter.check(key.clone(), Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            tracing::debug!(?key, ?retry_after, "Rate limit exceeded");
+            let secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+            let mut response =
+                AppError::TooManyRequests("Rate limit exceeded".to_string()).into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+            response
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::auth::jwt::{generate_access_token, JwtConfig, JwtKey};
+    use crate::middleware::auth::decode_bearer_claims;
+
+    fn jwt_config() -> JwtConfig {
+        JwtConfig {
+            current: JwtKey::new("test-secret"),
+            previous: Vec::new(),
+            access_token_expiry_mins: 15,
+            refresh_token_expiry_days: 7,
+        }
+    }
+
+    /// The proxy trusted by [`limiter`].
+    const PROXY: &str = "10.0.0.100";
+
+    /// 60 requests per minute (one per second) with a burst of 3.
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(
+            RateLimitConfig {
+                requests_per_minute: 60,
+                burst: 3,
+            },
+            vec![PROXY.parse().unwrap()],
+        )
+    }
+
+    fn user(id: DbId) -> RateLimitKey {
+        RateLimitKey::User(id)
+    }
+
+    #[test]
+    fn request_past_burst_is_rejected() {
+        let limiter = limiter();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.check(user(1), now).is_ok());
+        }
+        let retry_after = limiter.check(user(1), now).unwrap_err();
+        assert_eq!(retry_after, Duration::from_secs(1));
+
+        // Other callers have their own bucket.
+        assert!(limiter.check(user(2), now).is_ok());
+        assert!(limiter
+            .check(RateLimitKey::Ip("10.0.0.1".into()), now)
+            .is_ok());
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let limiter = limiter();
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.check(user(1), start).unwrap();
+        }
+        assert!(limiter.check(user(1), start).is_err());
+
+        // Half a token is not enough; a full one is.
+        let half = start + Duration::from_millis(500);
+        assert_eq!(
+            limiter.check(user(1), half).unwrap_err(),
+            Duration::from_millis(500)
+        );
+        assert!(limiter
+            .check(user(1), start + Duration::from_secs(1))
+            .is_ok());
+
+        // A long idle period refills only up to the burst.
+        let later = start + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert!(limiter.check(user(1), later).is_ok());
+        }
+        assert!(limiter.check(user(1), later).is_err());
+    }
+
+    #[test]
+    fn prune_drops_only_refilled_buckets() {
+        let limiter = limiter();
+        let start = Instant::now();
+        limiter.check(user(1), start).unwrap();
+        limiter
+            .check(user(2), start + Duration::from_secs(2))
+            .unwrap();
+        assert_eq!(limiter.tracked(), 2);
+
+        assert_eq!(limiter.prune(start + Duration::from_secs(4)), 1);
+        assert_eq!(limiter.tracked(), 1);
+        assert_eq!(limiter.prune(start + Duration::from_secs(5)), 1);
+        assert_eq!(limiter.tracked(), 0);
+    }
+
+    fn router() -> Router {
+        Router::new()
+            .route("/search/typeahead", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(limiter()),
+                rate_limit,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(jwt_config()),
+                decode_bearer_claims,
+            ))
+    }
+
+    /// A request from socket peer `peer`, optionally forwarded for `client`.
+    fn request_via(auth: Option<&str>, peer: &str, client: Option<&str>) -> Request {
+        let mut builder = Request::get("/search/typeahead");
+        if let Some(client) = client {
+            builder = builder.header("x-forwarded-for", client);
+        }
+        if let Some(token) = auth {
+            builder = builder.header("authorization", format!("Bearer {token}"));
+        }
+        let mut request = builder.body(Body::empty()).unwrap();
+        let addr = SocketAddr::new(peer.parse().unwrap(), 40000);
+        request.extensions_mut().insert(ConnectInfo(addr));
+        request
+    }
+
+    fn request(auth: Option<&str>, ip: &str) -> Request {
+        request_via(auth, ip, None)
+    }
+
+    #[tokio::test]
+    async fn nth_request_gets_429_with_retry_after() {
+        let app = router();
+        let token = generate_access_token(7, "creator", &jwt_config()).unwrap();
+
+        for _ in 0..3 {
+            let response = app
+                .clone()
+                .oneshot(request(Some(&token), "10.0.0.1"))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        // Same user from another address shares the bucket.
+        let response = app
+            .clone()
+            .oneshot(request(Some(&token), "10.0.0.2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+
+        // Unauthenticated callers are limited by IP instead.
+        let response = app.oneshot(request(None, "10.0.0.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn forwarded_headers_are_honored_only_from_trusted_proxies() {
+        let limiter = limiter();
+        let key = |peer, client| limiter.key_for(&request_via(None, peer, client));
+
+        assert_eq!(
+            key(PROXY, Some("203.0.113.7")),
+            RateLimitKey::Ip("203.0.113.7".into())
+        );
+        assert_eq!(key(PROXY, None), RateLimitKey::Ip(PROXY.into()));
+
+        // A spoofed header from an untrusted peer is ignored.
+        assert_eq!(
+            key("198.51.100.9", Some("203.0.113.7")),
+            RateLimitKey::Ip("198.51.100.9".into())
+        );
+    }
+
+    #[test]
+    fn zero_rate_disables_limiting() {
+        assert!(RateLimitConfig::default().is_enabled());
+        let disabled = RateLimitConfig {
+            requests_per_minute: 0,
+            burst: 10,
+        };
+        assert!(!disabled.is_enabled());
+    }
+}
@@ -0,0 +1,187 @@
+//! Request timeout middleware with per-role overrides.
+//!
+//! Every request gets the default `REQUEST_TIMEOUT_SECS` budget unless the
+//! caller's JWT carries a role listed in `ROLE_REQUEST_TIMEOUTS`, in which
+//! case that role's budget applies instead. This lets admin maintenance
+//! operations run longer without loosening the timeout for everyone.
+//!
+//! The role is read from the claims stored by
+//! [`decode_bearer_claims`](crate::middleware::auth::decode_bearer_claims)
+//! only to pick a budget; requests with a missing or invalid token get the
+//! default and are rejected (or not) by the handler's own auth extractor as
+//! usual.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::{Request, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+use crate::auth::jwt::Claims;
+use crate::config::ServerConfig;
+
+/// Resolved timeout budgets, shared by the [`role_timeout`] middleware.
+#[derive(Debug, Clone)]
+pub struct RoleTimeouts {
+    /// Budget for requests without a role override.
+    pub default: Duration,
+    /// Budgets keyed by role name.
+    pub overrides: HashMap<String, Duration>,
+}
+
+impl RoleTimeouts {
+    /// Build the budgets from server configuration.
+    pub fn from_config(config: &ServerConfig) -> Self {
+        Self {
+            default: Duration::from_secs(config.request_timeout_secs),
+            overrides: config
+                .role_request_timeouts
+                .iter()
+                .map(|(role, secs)| (role.clone(), Duration::from_secs(*secs)))
+                .collect(),
+        }
+    }
+
+    /// Pick the budget for a request from its decoded token claims.
+    pub fn resolve(&self, claims: Option<&Claims>) -> Duration {
+        claims
+            .and_then(|claims| self.overrides.get(&claims.role).copied())
+            .unwrap_or(self.default)
+    }
+}
+
+/// Abort the request with `408 Request Timeout` once its budget is spent.
+pub async fn role_timeout(
+    State(timeouts): State<Arc<RoleTimeouts>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let budget = timeouts.resolve(request.extensions().get::<Claims>());
+    match tokio::time::timeout(budget, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => StatusCode::REQUEST_TIMEOUT.into_response(),
+    }
+}
+
+/// Parse `role=seconds` pairs separated by commas, e.g. `admin=300,creator=60`.
+pub fn parse_role_timeouts(raw: &str) -> Result<HashMap<String, u64>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (role, secs) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected role=seconds, got '{pair}'"))?;
+            let secs = secs
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid seconds for role '{}'", role.trim()))?;
+            Ok((role.trim().to_string(), secs))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::auth::jwt::{generate_access_token, JwtConfig, JwtKey};
+    use crate::middleware::auth::decode_bearer_claims;
+
+    fn jwt_config() -> JwtConfig {
+        JwtConfig {
+            current: JwtKey::new("test-secret"),
+            previous: Vec::new(),
+            access_token_expiry_mins: 15,
+            refresh_token_expiry_days: 7,
+        }
+    }
+
+    /// A route that takes 200ms, behind a 50ms default and a 2s admin budget.
+    fn slow_router() -> Router {
+        let timeouts = RoleTimeouts {
+            default: Duration::from_millis(50),
+            overrides: HashMap::from([("admin".to_string(), Duration::from_secs(2))]),
+        };
+        Router::new()
+            .route(
+                "/admin/maintenance/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    "done"
+                }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(timeouts),
+                role_timeout,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(jwt_config()),
+                decode_bearer_claims,
+            ))
+    }
+
+    async fn call_as(role: &str) -> StatusCode {
+        let token = generate_access_token(1, role, &jwt_config()).unwrap();
+        slow_router()
+            .oneshot(
+                Request::get("/admin/maintenance/slow")
+                    .header("authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn admin_survives_past_default_timeout() {
+        assert_eq!(call_as("admin").await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn other_roles_are_cut_at_default_timeout() {
+        assert_eq!(call_as("creator").await, StatusCode::REQUEST_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn invalid_token_gets_default_timeout() {
+        let status = slow_router()
+            .oneshot(
+                Request::get("/admin/maintenance/slow")
+                    .header("authorization", "Bearer not-a-jwt")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status();
+        assert_eq!(status, StatusCode::REQUEST_TIMEOUT);
+    }
+
+    #[test]
+    fn parses_role_pairs() {
+        let parsed = parse_role_timeouts(" admin=300, creator = 60 ,").unwrap();
+        assert_eq!(parsed.get("admin"), Some(&300));
+        assert_eq!(parsed.get("creator"), Some(&60));
+        assert!(parse_role_timeouts("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_pairs() {
+        assert!(parse_role_timeouts("admin").is_err());
+        assert!(parse_role_timeouts("admin=soon").is_err());
+    }
+}
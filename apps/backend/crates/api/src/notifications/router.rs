@@ -6,15 +6,19 @@
 
 use std::sync::Arc;
 
-use axum::extract::ws::Message;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
 use tokio::sync::broadcast;
 use x121_core::channels::{CHANNEL_DIGEST, CHANNEL_IN_APP};
 use x121_core::types::DbId;
 use x121_db::repositories::{EventRepo, NotificationPreferenceRepo, NotificationRepo};
 use x121_db::DbPool;
-use x121_events::PlatformEvent;
+use x121_events::{EventPersistence, PlatformEvent};
 
-use crate::ws::WsManager;
+use crate::ws::{user_topic, OutboundMessage, WsManager, WsTarget};
+
+/// How far back [`NotificationRouter::replay_recent`] looks on startup.
+const STARTUP_REPLAY_HOURS: i64 = 24;
 
 /// Routes platform events to user notifications.
 ///
@@ -41,7 +45,7 @@ impl NotificationRouter {
         loop {
             match receiver.recv().await {
                 Ok(event) => {
-                    if let Err(e) = self.route_event(&event).await {
+                    if let Err(e) = self.route_event(&event, None).await {
                         tracing::error!(
                             error = %e,
                             event_type = %event.event_type,
@@ -60,15 +64,50 @@ impl NotificationRouter {
         }
     }
 
+    /// Re-route stored events created in `[start, end)`, oldest first.
+    ///
+    /// Run on startup, before [`run`](Self::run), to deliver events
+    /// published while the router was down. Users who were already notified
+    /// of an event are skipped, so overlapping replays are harmless.
+    /// Routing failures are logged per event. Returns the number of events
+    /// replayed.
+    pub async fn replay(&self, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> usize {
+        let mut events = std::pin::pin!(EventPersistence::replay_range(&self.pool, start, end));
+        let mut replayed = 0;
+        while let Some(stored) = events.next().await {
+            let event = stored.event;
+            if let Err(e) = self.route_event(&event, Some(stored.id)).await {
+                tracing::error!(
+                    error = %e,
+                    event_type = %event.event_type,
+                    "Failed to route replayed event"
+                );
+            }
+            replayed += 1;
+        }
+        tracing::info!(replayed, %start, "Notification router replay finished");
+        replayed
+    }
+
+    /// Replay events stored in the last [`STARTUP_REPLAY_HOURS`] hours.
+    pub async fn replay_recent(&self) -> usize {
+        let start = Utc::now() - chrono::Duration::hours(STARTUP_REPLAY_HOURS);
+        self.replay(start, None).await
+    }
+
     /// Route a single event to all affected users.
+    ///
+    /// `event_id` is the stored row of a replayed event; live events are
+    /// matched to their row by [`find_latest_event_id`](Self::find_latest_event_id).
     async fn route_event(
         &self,
         event: &PlatformEvent,
+        event_id: Option<DbId>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let target_users = self.determine_targets(event).await?;
 
         for user_id in target_users {
-            self.route_to_user(user_id, event).await?;
+            self.route_to_user(user_id, event, event_id).await?;
         }
 
         Ok(())
@@ -79,6 +118,7 @@ impl NotificationRouter {
         &self,
         user_id: DbId,
         event: &PlatformEvent,
+        event_id: Option<DbId>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         // Resolve event type metadata.
         let event_type =
@@ -123,7 +163,7 @@ impl NotificationRouter {
         let is_digest = settings.as_ref().is_some_and(|s| s.digest_enabled);
         if is_digest && !event_type.is_critical {
             // Create notification but do not deliver now; the digest job will pick it up.
-            if let Some(event_id) = self.find_latest_event_id(&event.event_type).await {
+            if let Some(event_id) = self.resolve_event_id(event, event_id).await {
                 NotificationRepo::create(&self.pool, event_id, user_id, CHANNEL_DIGEST)
                     .await
                     .ok();
@@ -139,7 +179,7 @@ impl NotificationRouter {
 
         for channel in &channels {
             match channel.as_str() {
-                CHANNEL_IN_APP => self.deliver_in_app(user_id, event).await,
+                CHANNEL_IN_APP => self.deliver_in_app(user_id, event, event_id).await,
                 // Webhook and email delivery will be implemented in Phase 5.
                 other => {
                     tracing::debug!(channel = other, "Channel delivery not yet implemented");
@@ -206,23 +246,41 @@ impl NotificationRouter {
         .flatten()
     }
 
+    /// The stored row for `event`: `event_id` when known, otherwise the
+    /// latest row of the event's type.
+    async fn resolve_event_id(
+        &self,
+        event: &PlatformEvent,
+        event_id: Option<DbId>,
+    ) -> Option<DbId> {
+        match event_id {
+            Some(id) => Some(id),
+            None => self.find_latest_event_id(&event.event_type).await,
+        }
+    }
+
     /// Create a notification record in the database and push a WebSocket message.
-    async fn deliver_in_app(&self, user_id: DbId, event: &PlatformEvent) {
+    ///
+    /// Nothing is pushed if the user was already notified of the event.
+    async fn deliver_in_app(&self, user_id: DbId, event: &PlatformEvent, event_id: Option<DbId>) {
         // Look up the persisted event row for the notification FK.
-        if let Some(event_id) = self.find_latest_event_id(&event.event_type).await {
-            NotificationRepo::create(&self.pool, event_id, user_id, CHANNEL_IN_APP)
+        if let Some(event_id) = self.resolve_event_id(event, event_id).await {
+            let created = NotificationRepo::create(&self.pool, event_id, user_id, CHANNEL_IN_APP)
                 .await
                 .ok();
+            if created == Some(None) {
+                return;
+            }
         }
 
-        // Push the notification over WebSocket.
-        let msg = serde_json::json!({
-            "type": "notification",
-            "event_type": event.event_type,
-            "payload": event.payload,
-            "timestamp": event.timestamp,
-        });
-        let ws_msg = Message::Text(msg.to_string().into());
-        self.ws_manager.send_to_user(user_id, ws_msg).await;
+        // Push the notification to the user's topic over WebSocket.
+        let msg = OutboundMessage::Notification {
+            event_type: event.event_type.clone(),
+            payload: event.payload.clone(),
+            timestamp: event.timestamp,
+        };
+        self.ws_manager
+            .send_typed(WsTarget::Topic(user_topic(user_id)), &msg)
+            .await;
     }
 }
@@ -4,20 +4,25 @@
 //! and integration tests (`tests/common/mod.rs`) use the exact same middleware
 //! stack. Extracted per DRY-017.
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
-use axum::http::{HeaderName, Method, StatusCode};
+use axum::http::{Extensions, HeaderMap, HeaderName, Method, StatusCode, Version};
 use axum::Router;
 use tower_http::catch_panic::CatchPanicLayer;
+use tower_http::compression::predicate::{Predicate, SizeAbove};
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 use tower_http::services::ServeDir;
-use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
 use tracing::Level;
 
 use crate::config::ServerConfig;
+use crate::middleware::auth::decode_bearer_claims;
+use crate::middleware::rate_limit::{rate_limit, RateLimiter};
+use crate::middleware::timeout::{role_timeout, RoleTimeouts};
 use crate::routes;
 use crate::state::AppState;
 
@@ -29,26 +34,52 @@ use crate::state::AppState;
 /// 2. Set request ID on incoming requests
 /// 3. Structured request/response tracing
 /// 4. Propagate request ID to response
-/// 5. Request timeout
-/// 6. Panic recovery (catch panics, return 500)
+/// 5. Bearer token decoding (see [`decode_bearer_claims`])
+/// 6. Request timeout (per-role budgets, see [`role_timeout`])
+/// 7. Panic recovery (catch panics, return 500)
+/// 8. Gzip compression of JSON responses (when enabled)
+///
+/// Routes under `/api/v1` are additionally rate limited per caller (see
+/// [`rate_limit`]) unless disabled in configuration. Health checks and
+/// static storage files are not limited.
 pub fn build_app_router(state: AppState, config: &ServerConfig) -> Router {
     let cors = build_cors_layer(config);
     let request_id_header = HeaderName::from_static("x-request-id");
 
-    Router::new()
+    let mut api_routes = routes::api_routes(config);
+    if config.rate_limit.is_enabled() {
+        let limiter = RateLimiter::new(config.rate_limit, config.trusted_proxies.clone());
+        api_routes = api_routes.layer(axum::middleware::from_fn_with_state(
+            Arc::new(limiter),
+            rate_limit,
+        ));
+    }
+
+    let mut router = Router::new()
         // Health check at root level (not under /api/v1).
         .merge(routes::health::router())
         // API v1 routes.
-        .nest("/api/v1", routes::api_routes())
+        .nest("/api/v1", api_routes)
         // Serve uploaded files (images, etc.) from the configured storage root.
-        .nest_service("/storage", ServeDir::new(&config.storage_root))
+        .nest_service("/storage", ServeDir::new(&config.storage_root));
+
+    if config.compression_enabled {
+        router = router.layer(build_compression_layer(config.compression_min_bytes));
+    }
+
+    router
         // -- Middleware stack (applied bottom-up) --
         // Panic recovery: catch panics and return 500 JSON.
         .layer(CatchPanicLayer::new())
-        // Request timeout.
-        .layer(TimeoutLayer::with_status_code(
-            StatusCode::REQUEST_TIMEOUT,
-            Duration::from_secs(config.request_timeout_secs),
+        // Request timeout, with longer budgets for configured roles.
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(RoleTimeouts::from_config(config)),
+            role_timeout,
+        ))
+        // Decode the bearer token once for the layers and handlers below.
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(config.jwt.clone()),
+            decode_bearer_claims,
         ))
         // Propagate request ID to response.
         .layer(PropagateRequestIdLayer::new(request_id_header.clone()))
@@ -96,3 +127,123 @@ pub fn build_cors_layer(config: &ServerConfig) -> CorsLayer {
         .allow_credentials(true)
         .max_age(Duration::from_secs(3600))
 }
+
+/// Build the response compression layer.
+///
+/// Only JSON bodies of at least `min_bytes` are compressed, and
+/// only when the request's `Accept-Encoding` allows gzip. Video streams,
+/// file downloads, and SSE carry other content types and pass through
+/// untouched.
+pub fn build_compression_layer(min_bytes: u16) -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new().compress_when(SizeAbove::new(min_bytes).and(is_json_response))
+}
+
+/// Whether the response declares a JSON content type.
+fn is_json_response(
+    _status: StatusCode,
+    _version: Version,
+    headers: &HeaderMap,
+    _extensions: &Extensions,
+) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::io::Read;
+
+    use axum::body::Body;
+    use axum::http::header::{ACCEPT_ENCODING, CONTENT_ENCODING};
+    use axum::http::Request;
+    use axum::response::sse::{Event, Sse};
+    use axum::routing::get;
+    use axum::Json;
+    use flate2::read::GzDecoder;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn large_payload() -> serde_json::Value {
+        let items: Vec<_> = (0..500)
+            .map(|i| serde_json::json!({ "id": i, "name": format!("item-{i}") }))
+            .collect();
+        serde_json::json!({ "data": items })
+    }
+
+    fn test_router() -> Router {
+        Router::new()
+            .route("/large", get(|| async { Json(large_payload()) }))
+            .route(
+                "/events",
+                get(|| async {
+                    let events =
+                        futures::stream::iter((0..200).map(|i| {
+                            Ok::<_, Infallible>(Event::default().data(format!("{i:0>32}")))
+                        }));
+                    Sse::new(events)
+                }),
+            )
+            .route(
+                "/video",
+                get(|| async { ([(CONTENT_TYPE, "video/mp4")], vec![0u8; 8192]) }),
+            )
+            .layer(build_compression_layer(1024))
+    }
+
+    async fn get_gzip(uri: &str) -> axum::response::Response {
+        test_router()
+            .oneshot(
+                Request::get(uri)
+                    .header(ACCEPT_ENCODING, "gzip")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn large_json_is_gzipped_and_decompresses() {
+        let response = get_gzip("/large").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+
+        let compressed = response.into_body().collect().await.unwrap().to_bytes();
+        let mut decoded = String::new();
+        GzDecoder::new(compressed.as_ref())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&decoded).unwrap();
+        assert_eq!(body, large_payload());
+    }
+
+    #[tokio::test]
+    async fn json_is_not_compressed_without_accept_encoding() {
+        let response = test_router()
+            .oneshot(Request::get("/large").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+    }
+
+    #[tokio::test]
+    async fn streaming_routes_are_not_compressed() {
+        for uri in ["/events", "/video"] {
+            let response = get_gzip(uri).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert!(
+                response.headers().get(CONTENT_ENCODING).is_none(),
+                "{uri} should not be compressed"
+            );
+        }
+    }
+}
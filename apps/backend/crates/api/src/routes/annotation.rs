@@ -29,7 +29,7 @@ pub fn annotation_browse_router() -> Router<AppState> {
 /// GET    /{id}/annotations                     list_annotations (?user_id, ?frame_number)
 /// POST   /{id}/annotations                     create_annotation
 /// GET    /{id}/annotations/summary             annotation_summary
-/// GET    /{id}/annotations/export/{frame}      export_frame
+/// GET    /{id}/annotations/export/{frame}      export_frame (?format=json|coco)
 /// GET    /{id}/annotations/{ann_id}            get_annotation
 /// PUT    /{id}/annotations/{ann_id}            update_annotation
 /// DELETE /{id}/annotations/{ann_id}            delete_annotation
@@ -14,6 +14,7 @@ use crate::state::AppState;
 /// GET  /                       -> query_audit_logs
 /// GET  /export                 -> export_audit_logs
 /// GET  /integrity-check        -> check_integrity
+/// GET  /integrity-check/stream -> stream_integrity_check (SSE)
 /// GET  /retention              -> list_retention_policies
 /// PUT  /retention/{category}   -> update_retention_policy
 /// ```
@@ -22,6 +23,10 @@ pub fn router() -> Router<AppState> {
         .route("/", get(audit::query_audit_logs))
         .route("/export", get(audit::export_audit_logs))
         .route("/integrity-check", get(audit::check_integrity))
+        .route(
+            "/integrity-check/stream",
+            get(audit::stream_integrity_check),
+        )
         .route("/retention", get(audit::list_retention_policies))
         .route("/retention/{category}", put(audit::update_retention_policy))
 }
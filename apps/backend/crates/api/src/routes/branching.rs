@@ -6,11 +6,12 @@
 //! POST /{scene_id}/branch                create_branch
 //!
 //! BRANCH-LEVEL (mounted at /branches):
-//! GET    /stale                           list_stale (?older_than_days)
+//! GET    /stale                           list_stale (?older_than_days, min_parent_changes, abandoned_after_days)
 //! GET    /{id}                            get_branch
 //! PUT    /{id}                            update_branch
 //! DELETE /{id}                            delete_branch
 //! POST   /{id}/promote                    promote_branch
+//! GET    /{id}/promote-check              promote_check
 //! GET    /{id}/compare/{other_id}         compare_branches
 //! ```
 
@@ -39,6 +40,7 @@ pub fn branch_router() -> Router<AppState> {
                 .delete(branching::delete_branch),
         )
         .route("/{id}/promote", post(branching::promote_branch))
+        .route("/{id}/promote-check", get(branching::promote_check))
         .route(
             "/{id}/compare/{other_id}",
             get(branching::compare_branches),
@@ -9,6 +9,9 @@
 //! GET    /{avatar_id}/detected-faces       -> get_detected_faces
 //! POST   /{avatar_id}/select-face          -> select_face
 //! GET    /{avatar_id}/embedding-history    -> get_embedding_history
+//! POST   /embedding/batch-extract          -> batch_extract
+//! GET    /embedding/batch-extract/{batch_id}        -> get_batch_extraction
+//! POST   /embedding/batch-extract/{batch_id}/cancel -> cancel_batch_extraction
 //! ```
 
 use axum::routing::{get, post};
@@ -37,4 +40,13 @@ pub fn embedding_router() -> Router<AppState> {
             "/{avatar_id}/embedding-history",
             get(embedding::get_embedding_history),
         )
+        .route("/embedding/batch-extract", post(embedding::batch_extract))
+        .route(
+            "/embedding/batch-extract/{batch_id}",
+            get(embedding::get_batch_extraction),
+        )
+        .route(
+            "/embedding/batch-extract/{batch_id}/cancel",
+            post(embedding::cancel_batch_extraction),
+        )
 }
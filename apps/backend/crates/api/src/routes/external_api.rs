@@ -39,7 +39,8 @@ pub fn api_keys_router() -> Router<AppState> {
 /// POST   /                          -> create_webhook
 /// PUT    /{id}                      -> update_webhook
 /// DELETE /{id}                      -> delete_webhook
-/// GET    /{id}/deliveries           -> list_deliveries
+/// POST   /{id}/reenable             -> reenable_webhook
+/// GET    /{id}/deliveries           -> list_deliveries (?outcome, since, until)
 /// POST   /{id}/test                 -> test_webhook
 /// POST   /deliveries/{id}/replay    -> replay_delivery
 /// ```
@@ -53,6 +54,7 @@ pub fn webhooks_router() -> Router<AppState> {
             "/{id}",
             put(webhooks::update_webhook).delete(webhooks::delete_webhook),
         )
+        .route("/{id}/reenable", post(webhooks::reenable_webhook))
         .route("/{id}/deliveries", get(webhooks::list_deliveries))
         .route("/{id}/test", post(webhooks::test_webhook))
         .route("/deliveries/{id}/replay", post(webhooks::replay_delivery))
@@ -8,6 +8,9 @@
 //! /analytics/failure-heatmap               heatmap data (GET)
 //! /analytics/failure-trends                trend data (GET)
 //! /analytics/failure-alerts                alert check (GET)
+//! /analytics/failure-spikes                failure rate spike check (GET)
+//! /analytics/failure-signatures            list (GET), create (POST, admin)
+//! /analytics/failure-signatures/{id}       toggle (PATCH, admin), delete (DELETE, admin)
 //!
 //! /failure-patterns/{id}/fixes             create fix (POST), list fixes (GET)
 //! /failure-patterns/fixes/{id}/effectiveness  update effectiveness (PATCH)
@@ -30,6 +33,15 @@ pub fn analytics_router() -> Router<AppState> {
         .route("/failure-heatmap", get(failure_analytics::get_heatmap))
         .route("/failure-trends", get(failure_analytics::get_trends))
         .route("/failure-alerts", get(failure_analytics::check_alerts))
+        .route("/failure-spikes", get(failure_analytics::check_spike))
+        .route(
+            "/failure-signatures",
+            get(failure_analytics::list_signatures).post(failure_analytics::create_signature),
+        )
+        .route(
+            "/failure-signatures/{id}",
+            patch(failure_analytics::update_signature).delete(failure_analytics::delete_signature),
+        )
 }
 
 /// Pattern fix routes nested at `/failure-patterns`.
@@ -15,6 +15,7 @@ use crate::state::AppState;
 /// GET  /workers/{id}/metrics          -> get_worker_metrics
 /// POST /workers/{id}/restart          -> restart_service
 /// GET  /workers/{id}/restarts         -> list_restart_logs
+/// GET  /comfyui/versions              -> list_comfyui_versions
 /// GET  /thresholds                    -> list_thresholds
 /// PUT  /workers/{id}/thresholds       -> update_worker_thresholds
 /// PUT  /thresholds/global             -> update_global_thresholds
@@ -31,6 +32,7 @@ pub fn router() -> Router<AppState> {
             axum::routing::post(hardware::restart_service),
         )
         .route("/workers/{id}/restarts", get(hardware::list_restart_logs))
+        .route("/comfyui/versions", get(hardware::list_comfyui_versions))
         .route("/thresholds", get(hardware::list_thresholds))
         .route(
             "/workers/{id}/thresholds",
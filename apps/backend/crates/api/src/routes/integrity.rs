@@ -3,8 +3,11 @@
 //! ```text
 //! /admin/integrity-scans                  list scans (GET), start scan (POST)
 //! /admin/integrity-scans/{worker_id}      worker report (GET), start worker scan (POST)
+//! /admin/integrity-scans/{worker_id}/stream  worker report as NDJSON (GET)
 //!
 //! /admin/repair/{worker_id}               full repair (POST)
+//! /admin/repair/runs/{run_id}             full repair report (GET)
+//! /admin/repair/runs/{run_id}/cancel      cancel full repair (POST)
 //! /admin/repair/{worker_id}/sync-models   sync models (POST)
 //! /admin/repair/{worker_id}/install-nodes install nodes (POST)
 //!
@@ -26,12 +29,15 @@ pub fn scan_router() -> Router<AppState> {
             "/{worker_id}",
             post(integrity::start_worker_scan).get(integrity::get_worker_report),
         )
+        .route("/{worker_id}/stream", get(integrity::stream_worker_report))
 }
 
 /// Routes for repair actions on a specific worker.
 pub fn repair_router() -> Router<AppState> {
     Router::new()
         .route("/{worker_id}", post(integrity::repair_worker))
+        .route("/runs/{run_id}", get(integrity::get_repair_run))
+        .route("/runs/{run_id}/cancel", post(integrity::cancel_repair_run))
         .route("/{worker_id}/sync-models", post(integrity::sync_models))
         .route("/{worker_id}/install-nodes", post(integrity::install_nodes))
 }
@@ -11,6 +11,8 @@
 //! GET    /{id}/usage                    get_library_usage
 //! POST   /{id}/import                   import_to_project
 //! GET    /projects/{project_id}/links   list_project_links
+//! GET    /projects/{project_id}/recommendations
+//!                                        list_project_recommendations
 //! PUT    /links/{link_id}               update_link_fields
 //! DELETE /links/{link_id}               delete_link
 //! ```
@@ -39,6 +41,10 @@ pub fn router() -> Router<AppState> {
             "/projects/{project_id}/links",
             get(library::list_project_links),
         )
+        .route(
+            "/projects/{project_id}/recommendations",
+            get(library::list_project_recommendations),
+        )
         .route(
             "/links/{link_id}",
             put(library::update_link_fields).delete(library::delete_link),
@@ -15,7 +15,10 @@ use crate::state::AppState;
 /// POST   /find-replace/{id}/execute -> execute_find_replace
 /// POST   /repath/preview            -> preview_repath
 /// POST   /repath/{id}/execute       -> execute_repath
+/// POST   /rebuild-caches            -> rebuild_caches (admin)
+/// POST   /reindex-wiki              -> reindex_wiki (admin)
 /// POST   /{id}/undo                 -> undo_operation
+/// POST   /{id}/cancel               -> cancel_operation (admin)
 /// GET    /history                   -> list_operations (?limit, offset, operation_type, status)
 /// GET    /{id}                      -> get_operation
 /// ```
@@ -31,7 +34,10 @@ pub fn maintenance_router() -> Router<AppState> {
         )
         .route("/repath/preview", post(maintenance::preview_repath))
         .route("/repath/{id}/execute", post(maintenance::execute_repath))
+        .route("/rebuild-caches", post(maintenance::rebuild_caches))
+        .route("/reindex-wiki", post(maintenance::reindex_wiki))
         .route("/{id}/undo", post(maintenance::undo_operation))
+        .route("/{id}/cancel", post(maintenance::cancel_operation))
         .route("/history", get(maintenance::list_operations))
         .route("/{id}", get(maintenance::get_operation))
 }
@@ -148,6 +148,7 @@ pub mod workspace;
 use axum::routing::get;
 use axum::Router;
 
+use crate::config::ServerConfig;
 use crate::handlers;
 use crate::state::AppState;
 use crate::ws;
@@ -179,11 +180,13 @@ use crate::ws;
 /// /admin/hardware/workers/{id}/metrics              worker metric history
 /// /admin/hardware/workers/{id}/restart              restart service (POST)
 /// /admin/hardware/workers/{id}/restarts             restart history
+/// /admin/hardware/comfyui/versions                  ComfyUI version per instance
 /// /admin/hardware/thresholds                        list all thresholds
 /// /admin/hardware/workers/{id}/thresholds           update worker thresholds (PUT)
 /// /admin/hardware/thresholds/global                 update global thresholds (PUT)
 ///
 /// /admin/scripts                                    list, register (admin only)
+/// /admin/scripts/capacity                           running/queued executions (GET)
 /// /admin/scripts/{id}                               get, update, deactivate
 /// /admin/scripts/{id}/test                          test execution (POST)
 /// /admin/scripts/{id}/executions                    execution history (GET)
@@ -210,6 +213,7 @@ use crate::ws;
 /// /admin/reclamation/policies/{id}                  update, delete (PUT, DELETE)
 ///
 /// /user/theme                                       get, update (auth required)
+/// /user/theme/export                                export effective tokens (GET)
 ///
 /// /user/keymap                                      get, update (auth required)
 ///
@@ -220,6 +224,7 @@ use crate::ws;
 /// /user/proficiency                                 list, set (auth required)
 /// /user/proficiency/record-usage                    record usage (POST)
 /// /user/proficiency/focus-mode                      get, set focus mode
+/// /user/proficiency/visibility                      resolved feature visibility (GET)
 ///
 /// /user/layouts                                     list, create (auth required)
 /// /user/layouts/{id}                                get, update, delete
@@ -251,6 +256,7 @@ use crate::ws;
 /// /projects                                        list, create
 /// /projects/{id}                                   get, update, delete
 /// /projects/{id}/stats                             project stats (GET, PRD-112)
+/// /projects/{id}/completeness-trend                completeness over time (GET, PRD-66)
 /// /projects/{project_id}/avatars                list, create
 /// /projects/{project_id}/avatars/{id}           get, update, delete
 /// /projects/{project_id}/avatars/{id}/settings  get, put, patch
@@ -311,6 +317,9 @@ use crate::ws;
 /// /avatars/{avatar_id}/detected-faces        list faces (GET, PRD-76)
 /// /avatars/{avatar_id}/select-face           select face (POST, PRD-76)
 /// /avatars/{avatar_id}/embedding-history     history (GET, PRD-76)
+/// /avatars/embedding/batch-extract           start batch extraction (POST, PRD-76)
+/// /avatars/embedding/batch-extract/{batch_id}         batch progress (GET, PRD-76)
+/// /avatars/embedding/batch-extract/{batch_id}/cancel  cancel batch (POST, PRD-76)
 ///
 /// /scenes/{scene_id}/segments                      list, create
 /// /scenes/{scene_id}/segments/{id}                 get, update, delete
@@ -320,6 +329,9 @@ use crate::ws;
 /// /scenes/batch-generate                           batch generate (POST, PRD-24)
 /// /scenes/{scene_id}/qa-summary                    scene QA summary (GET, PRD-49)
 /// /scenes/{id}/poster-frame                         get, set poster frame (GET, POST, PRD-96)
+/// /scenes/{id}/restitch                             re-stitch whole scene (POST, PRD-25)
+/// /scenes/restitch/{run_id}                         re-stitch run report (GET, PRD-25)
+/// /scenes/restitch/{run_id}/cancel                  cancel re-stitch run (POST, PRD-25)
 ///
 /// /segments/{segment_id}/approve                   approve segment (POST, PRD-35)
 /// /segments/{segment_id}/reject                    reject segment (POST, PRD-35)
@@ -384,6 +396,7 @@ use crate::ws;
 /// /trash/{entity_type}/{id}/purge                  purge one (DELETE)
 ///
 /// /notifications                                   list (?unread_only, limit, offset)
+/// /notifications/read                              mark read by filter (POST)
 /// /notifications/read-all                          mark all read (POST)
 /// /notifications/unread-count                      unread count (GET)
 /// /notifications/{id}/read                         mark read (POST)
@@ -484,6 +497,7 @@ use crate::ws;
 /// /admin/audit-logs                                      query logs (GET, PRD-45)
 /// /admin/audit-logs/export                               export logs (GET, PRD-45)
 /// /admin/audit-logs/integrity-check                      integrity check (GET, PRD-45)
+/// /admin/audit-logs/integrity-check/stream               integrity check progress (SSE, PRD-45)
 /// /admin/audit-logs/retention                            list policies (GET, PRD-45)
 /// /admin/audit-logs/retention/{category}                 update policy (PUT, PRD-45)
 ///
@@ -495,7 +509,7 @@ use crate::ws;
 /// /workspace/undo/{entity_type}/{entity_id}              get, save snapshot (GET, PUT, PRD-04)
 ///
 /// /user/undo-tree/{entity_type}/{entity_id}              get, save, delete (GET, PUT, DELETE, PRD-51)
-/// /user/undo-trees                                       list all trees (GET, PRD-51)
+/// /user/undo-trees                                       list tree summaries (GET, PRD-51)
 ///
 /// /collaboration/locks/acquire                            acquire lock (POST, PRD-11)
 /// /collaboration/locks/release                            release lock (POST, PRD-11)
@@ -511,7 +525,8 @@ use crate::ws;
 ///
 /// /admin/webhooks                                         list, create (GET, POST, PRD-12)
 /// /admin/webhooks/{id}                                    update, delete (PUT, DELETE, PRD-12)
-/// /admin/webhooks/{id}/deliveries                         delivery history (GET, PRD-12)
+/// /admin/webhooks/{id}/reenable                           re-enable suspended webhook (POST, PRD-12)
+/// /admin/webhooks/{id}/deliveries                         delivery history (GET, ?outcome, since, until, PRD-12)
 /// /admin/webhooks/{id}/test                               test webhook (POST, PRD-12)
 /// /admin/webhooks/deliveries/{id}/replay                  replay delivery (POST, PRD-12)
 ///
@@ -542,6 +557,7 @@ use crate::ws;
 /// /library/avatars/{id}/usage                               cross-project usage (GET, PRD-60)
 /// /library/avatars/{id}/import                              import to project (POST, PRD-60)
 /// /library/avatars/projects/{project_id}/links              list links (GET, PRD-60)
+/// /library/avatars/projects/{project_id}/recommendations    import recommendations (GET, PRD-60)
 /// /library/avatars/links/{link_id}                          update, delete link (PUT, DELETE, PRD-60)
 /// /library/avatars/readiness-summary                        readiness summary (GET, PRD-107)
 ///
@@ -577,7 +593,10 @@ use crate::ws;
 ///
 /// /admin/integrity-scans                                        list, start scan (GET, POST, PRD-43)
 /// /admin/integrity-scans/{worker_id}                            worker report, start worker scan (GET, POST, PRD-43)
+/// /admin/integrity-scans/{worker_id}/stream                     worker report as NDJSON (GET, PRD-43)
 /// /admin/repair/{worker_id}                                     full repair (POST, PRD-43)
+/// /admin/repair/runs/{run_id}                                   full repair report (GET, PRD-43)
+/// /admin/repair/runs/{run_id}/cancel                            cancel full repair (POST, PRD-43)
 /// /admin/repair/{worker_id}/sync-models                         sync models (POST, PRD-43)
 /// /admin/repair/{worker_id}/install-nodes                       install nodes (POST, PRD-43)
 /// /admin/model-checksums                                        list, create (GET, POST, PRD-43)
@@ -592,6 +611,7 @@ use crate::ws;
 /// /wiki/articles/{slug}/versions/{version}                       get version (GET, PRD-56)
 /// /wiki/articles/{slug}/revert/{version}                         revert (POST, PRD-56)
 /// /wiki/articles/{slug}/diff                                     diff versions (GET, PRD-56)
+/// /wiki/articles/{slug}/word-diff                                word-level diff versions (GET, PRD-56)
 ///
 /// /avatars/duplicates/check                                   check single (POST, PRD-79)
 /// /avatars/duplicates/batch                                   batch check (POST, PRD-79)
@@ -630,6 +650,7 @@ use crate::ws;
 /// /resolution-tiers/{id}                                       get tier (GET, PRD-59)
 /// /scenes/{id}/upscale                                         upscale scene (POST, PRD-59)
 /// /scenes/{id}/tier                                            get scene tier (GET, PRD-59)
+/// /scenes/batch-upscale                                        batch upscale scenes (POST, PRD-59)
 ///
 /// /estimates                                                   compute batch estimate (POST, PRD-61)
 /// /estimates/history                                           calibration data (GET, PRD-61)
@@ -665,10 +686,10 @@ use crate::ws;
 /// /prompt-library/{id}                                       get, update, delete (GET, PUT, DELETE, PRD-63)
 /// /prompt-library/{id}/rate                                  rate entry (POST, PRD-63)
 ///
-/// /hooks                                                     list, create (GET, POST, PRD-77)
-/// /hooks/{id}                                                get, update, delete (GET, PUT, DELETE, PRD-77)
+/// /hooks                                                     list, create (GET, POST, create admin only, PRD-77)
+/// /hooks/{id}                                                get, update, delete (GET, PUT, DELETE, update admin only, PRD-77)
 /// /hooks/{id}/toggle                                         toggle enabled (PATCH, PRD-77)
-/// /hooks/{id}/test                                           test hook (POST, PRD-77)
+/// /hooks/{id}/test                                           test hook (POST, admin only, PRD-77)
 /// /hooks/{id}/logs                                           execution logs (GET, PRD-77)
 /// /hooks/effective/{scope_type}/{scope_id}                    effective hooks (GET, PRD-77)
 /// /jobs/{id}/hook-logs                                        job hook logs (GET, PRD-77)
@@ -685,6 +706,9 @@ use crate::ws;
 /// /analytics/failure-heatmap                                   heatmap data (GET, PRD-64)
 /// /analytics/failure-trends                                    trend data (GET, PRD-64)
 /// /analytics/failure-alerts                                    alert check (GET, PRD-64)
+/// /analytics/failure-spikes                                    failure rate spike check (GET, PRD-64)
+/// /analytics/failure-signatures                                list, create (GET, POST, PRD-64)
+/// /analytics/failure-signatures/{id}                           toggle, delete (PATCH, DELETE, PRD-64)
 /// /failure-patterns/{id}/fixes                                 create, list fixes (POST, GET, PRD-64)
 /// /failure-patterns/fixes/{id}/effectiveness                   update effectiveness (PATCH, PRD-64)
 ///
@@ -712,6 +736,7 @@ use crate::ws;
 /// /onboarding-sessions/{id}/advance                             advance step (POST, PRD-67)
 /// /onboarding-sessions/{id}/go-back                             go back (POST, PRD-67)
 /// /onboarding-sessions/{id}/step-data                           update step data (PUT, PRD-67)
+/// /onboarding-sessions/{id}/history                             transition history (GET, PRD-67)
 /// /onboarding-sessions/{id}/abandon                             abandon session (POST, PRD-67)
 /// /onboarding-sessions/{id}/complete                            complete session (POST, PRD-67)
 ///
@@ -737,7 +762,10 @@ use crate::ws;
 /// /admin/maintenance/find-replace/{id}/execute                  execute find/replace (POST, PRD-18)
 /// /admin/maintenance/repath/preview                             preview re-path (POST, PRD-18)
 /// /admin/maintenance/repath/{id}/execute                        execute re-path (POST, PRD-18)
+/// /admin/maintenance/rebuild-caches                             rebuild completeness/readiness caches (POST, admin)
+/// /admin/maintenance/reindex-wiki                               rebuild wiki search index (POST, admin)
 /// /admin/maintenance/{id}/undo                                  undo operation (POST, PRD-18)
+/// /admin/maintenance/{id}/cancel                                cancel running operation (POST, admin)
 /// /admin/maintenance/history                                    list operations (GET, PRD-18)
 /// /admin/maintenance/{id}                                       get operation (GET, PRD-18)
 ///
@@ -778,7 +806,7 @@ use crate::ws;
 /// /scenes/{scene_id}/compliance-checks                             list checks (GET, PRD-102)
 /// /scenes/{scene_id}/compliance-summary                            check summary (GET, PRD-102)
 /// ```
-pub fn api_routes() -> Router<AppState> {
+pub fn api_routes(config: &ServerConfig) -> Router<AppState> {
     Router::new()
         // WebSocket endpoints.
         .route("/ws", get(ws::ws_handler))
@@ -886,7 +914,7 @@ pub fn api_routes() -> Router<AppState> {
             .nest("/{avatar_id}/deliverable-ignores", avatar_deliverable_ignore::router())
             .nest("/{avatar_id}/speeches", avatar_speech::router())
             .route("/{avatar_id}/derived-clips", axum::routing::get(crate::handlers::scene_video_version::list_derived_clips)))
-        // Scene-scoped sub-resources (segments, review queue, generation PRD-24, QA PRD-49, resolution PRD-59, storyboard PRD-62, branching PRD-50).
+        // Scene-scoped sub-resources (segments, review queue, generation PRD-24, QA PRD-49, resolution PRD-59, storyboard PRD-62, branching PRD-50, re-stitching PRD-25).
         .nest("/scenes", scene::router()
             .merge(metadata::scene_metadata_router())
             .merge(approval::scene_review_router())
@@ -897,6 +925,7 @@ pub fn api_routes() -> Router<AppState> {
             .merge(storyboard::scene_storyboard_router())
             .merge(branching::scene_branch_router())
             .merge(poster_frame::scene_poster_router())
+            .merge(restitching::scene_restitching_router())
             .merge(compliance::compliance_check_router()))
         // Segment-scoped approval actions (approve, reject, flag) (PRD-35).
         // Segment-scoped review notes and tags (PRD-38).
@@ -1005,7 +1034,7 @@ pub fn api_routes() -> Router<AppState> {
         // Command palette search (PRD-31).
         .nest("/search/palette", palette::search_router())
         // Tag system: tag CRUD, suggestions, bulk ops (PRD-47).
-        .nest("/tags", tags::router())
+        .nest("/tags", tags::router(config.tag_bulk_max_body_bytes))
         // Entity-scoped tag associations (PRD-47).
         .nest("/entities", tags::entity_tags_router())
         // Asset registry: CRUD, dependencies, notes, ratings (PRD-17).
@@ -12,6 +12,7 @@ use crate::state::AppState;
 ///
 /// ```text
 /// GET    /                          -> list_notifications
+/// POST   /read                      -> mark_read_matching
 /// POST   /read-all                  -> mark_all_read
 /// GET    /unread-count              -> unread_count
 /// POST   /{id}/read                 -> mark_read
@@ -26,6 +27,7 @@ pub fn router() -> Router<AppState> {
     Router::new()
         // Core notification endpoints
         .route("/", get(notification::list_notifications))
+        .route("/read", post(notification::mark_read_matching))
         .route("/read-all", post(notification::mark_all_read))
         .route("/unread-count", get(notification::unread_count))
         .route("/{id}/read", post(notification::mark_read))
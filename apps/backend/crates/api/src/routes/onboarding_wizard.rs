@@ -9,6 +9,7 @@
 //! POST   /{id}/advance                  advance_step
 //! POST   /{id}/go-back                  go_back
 //! PUT    /{id}/step-data                update_step_data
+//! GET    /{id}/history                  get_history (?limit, offset)
 //! POST   /{id}/abandon                  abandon_session
 //! POST   /{id}/complete                 complete_session
 //! ```
@@ -30,6 +31,7 @@ pub fn router() -> Router<AppState> {
         .route("/{id}/advance", post(onboarding_wizard::advance_step))
         .route("/{id}/go-back", post(onboarding_wizard::go_back))
         .route("/{id}/step-data", put(onboarding_wizard::update_step_data))
+        .route("/{id}/history", get(onboarding_wizard::get_history))
         .route("/{id}/abandon", post(onboarding_wizard::abandon_session))
         .route("/{id}/complete", post(onboarding_wizard::complete_session))
 }
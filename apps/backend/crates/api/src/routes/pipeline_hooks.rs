@@ -2,13 +2,13 @@
 //!
 //! ```text
 //! HOOKS:
-//! POST   /                                  create_hook
+//! POST   /                                  create_hook (admin)
 //! GET    /                                  list_hooks (?scope_type, scope_id, hook_point, enabled)
 //! GET    /{id}                              get_hook
-//! PUT    /{id}                              update_hook
+//! PUT    /{id}                              update_hook (admin)
 //! DELETE /{id}                              delete_hook
 //! PATCH  /{id}/toggle                       toggle_hook
-//! POST   /{id}/test                         test_hook
+//! POST   /{id}/test                         test_hook (admin)
 //! GET    /{id}/logs                         list_hook_logs (?limit, offset)
 //! GET    /effective/{scope_type}/{scope_id}  get_effective_hooks (?hook_point)
 //!
@@ -13,7 +13,8 @@ use crate::state::AppState;
 /// ```text
 /// GET    /                   -> list_notes (?entity_type, entity_id, limit, offset)
 /// POST   /                   -> create_note
-/// GET    /search             -> search_notes (?q, entity_type)
+/// GET    /search             -> search_notes (?q, entity_type, entity_id, category_id,
+///                               user_id, resolved, limit, offset)
 /// GET    /pinned             -> list_pinned (?entity_type, entity_id)
 /// GET    /{id}               -> get_note
 /// PUT    /{id}               -> update_note
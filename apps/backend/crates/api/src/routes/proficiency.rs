@@ -16,6 +16,7 @@ use crate::state::AppState;
 /// POST   /record-usage    -> record_usage
 /// GET    /focus-mode      -> get_focus_mode
 /// PUT    /focus-mode      -> set_focus_mode
+/// GET    /visibility      -> get_feature_visibility
 /// ```
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -28,4 +29,5 @@ pub fn router() -> Router<AppState> {
             "/focus-mode",
             get(proficiency::get_focus_mode).put(proficiency::set_focus_mode),
         )
+        .route("/visibility", get(proficiency::get_feature_visibility))
 }
@@ -6,7 +6,7 @@
 use axum::routing::get;
 use axum::Router;
 
-use crate::handlers::{avatar, avatar_group, project, scene_type};
+use crate::handlers::{avatar, avatar_group, avatar_metadata, project, scene_type};
 use crate::state::AppState;
 
 /// Routes mounted at `/projects`.
@@ -18,6 +18,7 @@ use crate::state::AppState;
 /// PUT    /{id}                              -> update
 /// DELETE /{id}                              -> delete
 /// GET    /{id}/stats                        -> get_stats (PRD-112)
+/// GET    /{id}/completeness-trend           -> get_completeness_trend (PRD-66)
 /// GET    /{id}/avatar-deliverables       -> get_avatar_deliverables
 ///
 /// GET    /{project_id}/avatars           -> list_by_project
@@ -114,6 +115,10 @@ pub fn router() -> Router<AppState> {
                 .delete(project::delete),
         )
         .route("/{id}/stats", get(project::get_stats))
+        .route(
+            "/{id}/completeness-trend",
+            get(avatar_metadata::get_completeness_trend),
+        )
         .route(
             "/{id}/avatar-deliverables",
             get(project::get_avatar_deliverables),
@@ -10,7 +10,7 @@
 //! POST   /{id}/restore                       restore_version
 //!
 //! PROMPT LIBRARY (mounted at /prompt-library):
-//! GET    /                                   list_library
+//! GET    /                                   list_library (?search, ?sort=top|recent|trending)
 //! POST   /                                   create_library_entry
 //! GET    /{id}                               get_library_entry
 //! PUT    /{id}                               update_library_entry
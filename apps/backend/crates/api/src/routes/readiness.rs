@@ -16,6 +16,7 @@ use crate::state::AppState;
 /// ```text
 /// GET    /{avatar_id}/readiness               -> get_avatar_readiness
 /// POST   /{avatar_id}/readiness/invalidate     -> invalidate_cache
+/// GET    /{avatar_id}/readiness-criteria       -> get_effective_criteria
 /// ```
 pub fn readiness_router() -> Router<AppState> {
     Router::new()
@@ -27,6 +28,10 @@ pub fn readiness_router() -> Router<AppState> {
             "/{avatar_id}/readiness/invalidate",
             post(readiness::invalidate_cache),
         )
+        .route(
+            "/{avatar_id}/readiness-criteria",
+            get(readiness::get_effective_criteria),
+        )
         .route("/readiness/batch-evaluate", post(readiness::batch_evaluate))
 }
 
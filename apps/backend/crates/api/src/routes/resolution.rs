@@ -26,14 +26,16 @@ pub fn resolution_router() -> Router<AppState> {
         .route("/{id}", get(resolution::get_tier))
 }
 
-/// Scene-scoped resolution routes, merged under `/scenes/{id}`.
+/// Scene-scoped resolution routes, merged into the `/scenes` nest.
 ///
 /// ```text
-/// POST   /upscale     upscale_scene
-/// GET    /tier        get_scene_tier
+/// POST   /{id}/upscale     upscale_scene
+/// GET    /{id}/tier        get_scene_tier
+/// POST   /batch-upscale    batch_upscale
 /// ```
 pub fn scene_resolution_router() -> Router<AppState> {
     Router::new()
-        .route("/upscale", post(resolution::upscale_scene))
-        .route("/tier", get(resolution::get_scene_tier))
+        .route("/{id}/upscale", post(resolution::upscale_scene))
+        .route("/{id}/tier", get(resolution::get_scene_tier))
+        .route("/batch-upscale", post(resolution::batch_upscale))
 }
@@ -9,6 +9,14 @@
 //! /segments/{id}/versions           — version history (GET)
 //! /segments/{id}/clear-stale        — clear stale flag (PATCH)
 //! ```
+//!
+//! Scene-level re-stitching is merged into the `/scenes` nest:
+//!
+//! ```text
+//! /scenes/{id}/restitch                 — re-stitch a whole scene (POST)
+//! /scenes/restitch/{run_id}             — re-stitch run report (GET)
+//! /scenes/restitch/{run_id}/cancel      — cancel a re-stitch run (POST)
+//! ```
 
 use axum::routing::{get, patch, post};
 use axum::Router;
@@ -25,3 +33,14 @@ pub fn segment_restitching_router() -> Router<AppState> {
         .route("/{id}/versions", get(restitching::list_versions))
         .route("/{id}/clear-stale", patch(restitching::clear_stale))
 }
+
+/// Scene-scoped re-stitching routes, merged into the `/scenes` nest.
+pub fn scene_restitching_router() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/restitch", post(restitching::restitch_scene))
+        .route("/restitch/{run_id}", get(restitching::get_scene_restitch))
+        .route(
+            "/restitch/{run_id}/cancel",
+            post(restitching::cancel_scene_restitch),
+        )
+}
@@ -13,6 +13,7 @@ use crate::state::AppState;
 /// ```text
 /// POST   /                          -> register_script
 /// GET    /                          -> list_scripts
+/// GET    /capacity                  -> get_execution_capacity
 /// GET    /{id}                      -> get_script
 /// PUT    /{id}                      -> update_script
 /// DELETE /{id}                      -> deactivate_script
@@ -26,6 +27,7 @@ pub fn router() -> Router<AppState> {
             "/",
             get(scripts::list_scripts).post(scripts::register_script),
         )
+        .route("/capacity", get(scripts::get_execution_capacity))
         .route(
             "/{id}",
             get(scripts::get_script)
@@ -4,6 +4,7 @@
 //! - `router()` for tag-specific routes mounted at `/tags`
 //! - `entity_tags_router()` for entity-scoped tag routes mounted at `/entities`
 
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{delete, get, post, put};
 use axum::Router;
 
@@ -12,6 +13,9 @@ use crate::state::AppState;
 
 /// Tag-specific routes mounted at `/tags`.
 ///
+/// The bulk endpoints reject request bodies larger than `bulk_body_limit`
+/// bytes.
+///
 /// ```text
 /// GET    /                  -> list_tags
 /// GET    /suggest           -> suggest_tags
@@ -20,13 +24,17 @@ use crate::state::AppState;
 /// POST   /bulk-apply        -> bulk_apply
 /// POST   /bulk-remove       -> bulk_remove
 /// ```
-pub fn router() -> Router<AppState> {
+pub fn router(bulk_body_limit: usize) -> Router<AppState> {
+    let bulk_routes = Router::new()
+        .route("/bulk-apply", post(tags::bulk_apply))
+        .route("/bulk-remove", post(tags::bulk_remove))
+        .layer(DefaultBodyLimit::max(bulk_body_limit));
+
     Router::new()
         .route("/", get(tags::list_tags))
         .route("/suggest", get(tags::suggest_tags))
         .route("/{id}", put(tags::update_tag).delete(tags::delete_tag))
-        .route("/bulk-apply", post(tags::bulk_apply))
-        .route("/bulk-remove", post(tags::bulk_remove))
+        .merge(bulk_routes)
 }
 
 /// Entity-scoped tag routes mounted at `/entities`.
@@ -13,14 +13,17 @@ use crate::state::AppState;
 /// User theme preference routes mounted at `/user/theme`.
 ///
 /// ```text
-/// GET /  -> get_user_theme
-/// PUT /  -> update_user_theme
+/// GET /        -> get_user_theme
+/// PUT /        -> update_user_theme
+/// GET /export  -> export_user_theme
 /// ```
 pub fn user_router() -> Router<AppState> {
-    Router::new().route(
-        "/",
-        get(themes::get_user_theme).put(themes::update_user_theme),
-    )
+    Router::new()
+        .route(
+            "/",
+            get(themes::get_user_theme).put(themes::update_user_theme),
+        )
+        .route("/export", get(themes::export_user_theme))
 }
 
 /// Admin custom theme routes mounted at `/admin/themes`.
@@ -27,7 +27,7 @@ pub fn router() -> Router<AppState> {
 /// User-level undo tree listing routes mounted at `/user/undo-trees`.
 ///
 /// ```text
-/// GET /  -> list_trees
+/// GET /  -> list_trees (?entity_type, limit, offset)
 /// ```
 pub fn user_router() -> Router<AppState> {
     Router::new().route("/", get(undo_tree::list_trees))
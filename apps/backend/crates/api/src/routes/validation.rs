@@ -34,7 +34,7 @@ pub fn validation_router() -> Router<AppState> {
 ///
 /// ```text
 /// GET    /                  -> list_imports     (?entity_type, ?project_id)
-/// POST   /{id}/commit       -> commit_import
+/// POST   /{id}/commit       -> commit_import    (chunked, resumable)
 /// GET    /{id}/report        -> get_report       (JSON)
 /// GET    /{id}/report/csv    -> get_report_csv   (CSV)
 /// ```
@@ -23,6 +23,7 @@ use crate::state::AppState;
 /// GET    /{slug}/versions/{version} get_version
 /// POST   /{slug}/revert/{version}   revert_to_version
 /// GET    /{slug}/diff               diff_versions
+/// GET    /{slug}/word-diff          word_diff_versions
 /// ```
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -40,4 +41,5 @@ pub fn router() -> Router<AppState> {
         .route("/{slug}/versions/{version}", get(wiki::get_version))
         .route("/{slug}/revert/{version}", post(wiki::revert_to_version))
         .route("/{slug}/diff", get(wiki::diff_versions))
+        .route("/{slug}/word-diff", get(wiki::word_diff_versions))
 }
@@ -0,0 +1,197 @@
+//! Concurrency limiter for script executions.
+//!
+//! Caps how many script subprocesses run at once. Executions beyond the cap
+//! wait in a bounded queue; once the queue is full further requests are
+//! rejected so the caller can answer with `429 Too Many Requests`.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Point-in-time view of the limiter, reported to admins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ExecutionCapacity {
+    pub max_concurrent: usize,
+    pub max_queued: usize,
+    pub running: usize,
+    pub queued: usize,
+}
+
+/// Returned when an execution can neither run nor wait for a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionQueueFull {
+    pub max_concurrent: usize,
+    pub max_queued: usize,
+}
+
+impl fmt::Display for ExecutionQueueFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Script execution limit reached: {} running and {} queued",
+            self.max_concurrent, self.max_queued
+        )
+    }
+}
+
+/// A running execution's slot. Dropping it frees the slot for the next
+/// queued execution, whether the script completed, timed out, or the
+/// caller was cancelled.
+#[derive(Debug)]
+pub struct ExecutionSlot {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Bounds concurrent script executions with a fixed-size waiting queue.
+#[derive(Debug)]
+pub struct ExecutionLimiter {
+    slots: Arc<Semaphore>,
+    max_concurrent: usize,
+    max_queued: usize,
+    queued: AtomicUsize,
+}
+
+impl ExecutionLimiter {
+    /// Create a limiter running at most `max_concurrent` executions (at
+    /// least one) with up to `max_queued` more waiting.
+    pub fn new(max_concurrent: usize, max_queued: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            slots: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            max_queued,
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Take an execution slot, waiting in the queue if all slots are busy.
+    ///
+    /// Fails immediately when the queue is already full.
+    pub async fn acquire(&self) -> Result<ExecutionSlot, ExecutionQueueFull> {
+        if let Ok(permit) = self.slots.clone().try_acquire_owned() {
+            return Ok(ExecutionSlot { _permit: permit });
+        }
+
+        let reserved = self
+            .queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (n < self.max_queued).then_some(n + 1)
+            });
+        if reserved.is_err() {
+            return Err(ExecutionQueueFull {
+                max_concurrent: self.max_concurrent,
+                max_queued: self.max_queued,
+            });
+        }
+
+        // Released on drop so a caller cancelled while waiting gives up its
+        // queue position too.
+        let _position = QueuePosition(&self.queued);
+        let permit = self
+            .slots
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("execution semaphore is never closed");
+        Ok(ExecutionSlot { _permit: permit })
+    }
+
+    /// Current running and queued counts alongside the configured limits.
+    pub fn capacity(&self) -> ExecutionCapacity {
+        ExecutionCapacity {
+            max_concurrent: self.max_concurrent,
+            max_queued: self.max_queued,
+            running: self.max_concurrent - self.slots.available_permits(),
+            queued: self.queued.load(Ordering::SeqCst),
+        }
+    }
+}
+
+/// Occupies one queue position until dropped.
+struct QueuePosition<'a>(&'a AtomicUsize);
+
+impl Drop for QueuePosition<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Give spawned tasks a chance to reach their await point.
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    #[tokio::test]
+    async fn excess_executions_queue_then_reject() {
+        let limiter = Arc::new(ExecutionLimiter::new(2, 1));
+        let first = limiter.acquire().await.unwrap();
+        let _second = limiter.acquire().await.unwrap();
+
+        let waiter = {
+            let limiter = Arc::clone(&limiter);
+            tokio::spawn(async move { limiter.acquire().await.map(|_slot| ()) })
+        };
+        settle().await;
+        assert!(!waiter.is_finished());
+
+        let rejected = limiter.acquire().await.unwrap_err();
+        assert_eq!(rejected.max_concurrent, 2);
+        assert_eq!(rejected.max_queued, 1);
+
+        let capacity = limiter.capacity();
+        assert_eq!(capacity.running, 2);
+        assert_eq!(capacity.queued, 1);
+
+        // Finishing one execution lets the queued one run.
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("queued execution should start once a slot frees")
+            .unwrap()
+            .unwrap();
+
+        let capacity = limiter.capacity();
+        assert_eq!(capacity.running, 1);
+        assert_eq!(capacity.queued, 0);
+    }
+
+    #[tokio::test]
+    async fn cancelled_waiter_frees_its_queue_position() {
+        let limiter = ExecutionLimiter::new(1, 1);
+        let _running = limiter.acquire().await.unwrap();
+
+        let timed_out = tokio::time::timeout(Duration::from_millis(20), limiter.acquire()).await;
+        assert!(timed_out.is_err());
+        assert_eq!(limiter.capacity().queued, 0);
+
+        // The queue position is available again.
+        let retry = tokio::time::timeout(Duration::from_millis(20), limiter.acquire()).await;
+        assert!(retry.is_err(), "should wait, not be rejected");
+    }
+
+    #[tokio::test]
+    async fn zero_queue_rejects_as_soon_as_slots_are_busy() {
+        let limiter = ExecutionLimiter::new(1, 0);
+        let running = limiter.acquire().await.unwrap();
+        assert!(limiter.acquire().await.is_err());
+
+        drop(running);
+        assert!(limiter.acquire().await.is_ok());
+        assert_eq!(limiter.capacity().running, 0);
+    }
+
+    #[test]
+    fn zero_concurrency_is_raised_to_one() {
+        let limiter = ExecutionLimiter::new(0, 3);
+        assert_eq!(limiter.capacity().max_concurrent, 1);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! The [`ScriptOrchestrator`] ties together the core executors with the
 //! database repositories, providing a single entry point for running
-//! registered scripts.
+//! registered scripts. The [`limiter`] caps how many of them run at once.
 
+pub mod limiter;
 pub mod orchestrator;
@@ -9,7 +9,10 @@ use std::time::Duration;
 use sqlx::PgPool;
 use x121_core::script_types::{SCRIPT_TYPE_BINARY, SCRIPT_TYPE_PYTHON, SCRIPT_TYPE_SHELL};
 use x121_core::scripting::binary::BinaryExecutor;
-use x121_core::scripting::executor::{ScriptError, ScriptExecutor, ScriptInput, ScriptOutput};
+use x121_core::scripting::executor::{
+    ScriptError, ScriptExecutor, ScriptInput, ScriptOutput, DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+    DEFAULT_MAX_OUTPUT_BYTES, DEFAULT_MAX_QUEUED_EXECUTIONS,
+};
 use x121_core::scripting::python::PythonExecutor;
 use x121_core::scripting::shell::ShellExecutor;
 use x121_core::types::DbId;
@@ -18,21 +21,24 @@ use x121_db::models::script::CreateScriptExecution;
 use x121_db::repositories::{ScriptExecutionRepo, ScriptRepo};
 
 use crate::error::{AppError, AppResult};
+use crate::scripting::limiter::{ExecutionCapacity, ExecutionLimiter};
 
 /// Orchestrates script execution across shell, Python, and binary runtimes.
 ///
 /// Manages the full lifecycle:
 /// 1. Load script configuration from the registry.
 /// 2. Validate the script is enabled.
-/// 3. Create an execution record (pending).
-/// 4. Mark execution as running.
-/// 5. Dispatch to the appropriate executor.
-/// 6. Record the result (completed / failed / timeout).
+/// 3. Wait for a free execution slot (rejected when the queue is full).
+/// 4. Create an execution record (pending).
+/// 5. Mark execution as running.
+/// 6. Dispatch to the appropriate executor.
+/// 7. Record the result (completed / failed / timeout).
 pub struct ScriptOrchestrator {
     pool: PgPool,
     shell_executor: ShellExecutor,
     python_executor: PythonExecutor,
     binary_executor: BinaryExecutor,
+    limiter: ExecutionLimiter,
 }
 
 impl ScriptOrchestrator {
@@ -43,9 +49,27 @@ impl ScriptOrchestrator {
             shell_executor: ShellExecutor,
             python_executor: PythonExecutor::new(venv_base_dir),
             binary_executor: BinaryExecutor,
+            limiter: ExecutionLimiter::new(
+                DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+                DEFAULT_MAX_QUEUED_EXECUTIONS,
+            ),
         }
     }
 
+    /// Replace the default execution limits.
+    ///
+    /// At most `max_concurrent` scripts run at once; up to `max_queued` more
+    /// wait for a slot and anything beyond that is rejected.
+    pub fn with_execution_limits(mut self, max_concurrent: usize, max_queued: usize) -> Self {
+        self.limiter = ExecutionLimiter::new(max_concurrent, max_queued);
+        self
+    }
+
+    /// Current running and queued execution counts.
+    pub fn capacity(&self) -> ExecutionCapacity {
+        self.limiter.capacity()
+    }
+
     /// Run a registered script by ID.
     ///
     /// `job_id` should be `Some` when triggered by the pipeline engine,
@@ -70,7 +94,15 @@ impl ScriptOrchestrator {
             )));
         }
 
-        // 3. Create execution record (status: pending).
+        // 3. Wait for an execution slot. It is held until this call returns,
+        //    so completion, timeout, and caller cancellation all release it.
+        let _slot = self
+            .limiter
+            .acquire()
+            .await
+            .map_err(|e| AppError::TooManyRequests(e.to_string()))?;
+
+        // 4. Create execution record (status: pending).
         let execution = ScriptExecutionRepo::create(
             &self.pool,
             &CreateScriptExecution {
@@ -82,10 +114,10 @@ impl ScriptOrchestrator {
         )
         .await?;
 
-        // 4. Mark as running.
+        // 5. Mark as running.
         ScriptExecutionRepo::mark_running(&self.pool, execution.id).await?;
 
-        // 5. Build script input.
+        // 6. Build script input.
         let mut env_vars = vec![
             ("SCRIPT_ID".to_string(), script_id.to_string()),
             ("EXECUTION_ID".to_string(), execution.id.to_string()),
@@ -114,9 +146,10 @@ impl ScriptOrchestrator {
             env_vars,
             working_directory: script.working_directory.clone(),
             timeout: Duration::from_secs(script.timeout_secs as u64),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
         };
 
-        // 6. Dispatch to the correct executor.
+        // 7. Dispatch to the correct executor.
         let result = match script.script_type_name.as_str() {
             SCRIPT_TYPE_SHELL => {
                 self.shell_executor
@@ -140,7 +173,7 @@ impl ScriptOrchestrator {
             }
         };
 
-        // 7. Record result.
+        // 8. Record result.
         match &result {
             Ok(output) => {
                 ScriptExecutionRepo::complete(
@@ -3,8 +3,12 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::config::ServerConfig;
+use crate::engine::embedding_batch::EmbeddingBatchRegistry;
 use crate::engine::health_aggregator::HealthAggregator;
+use crate::engine::scene_restitch::SceneRestitchRegistry;
+use crate::engine::worker_repair::WorkerRepairRegistry;
 use crate::scripting::orchestrator::ScriptOrchestrator;
+use crate::widget_cache::WidgetCache;
 use crate::ws::WsManager;
 use x121_core::storage::StorageProvider;
 
@@ -42,6 +46,14 @@ pub struct AppState {
     pub lifecycle_bridge: Arc<x121_cloud::lifecycle::LifecycleBridge>,
     /// Nudge handle to trigger immediate scaling evaluation.
     pub scaling_nudge: x121_cloud::services::ServiceNudge,
+    /// Running and recently finished batch embedding extractions (PRD-76).
+    pub embedding_batches: Arc<EmbeddingBatchRegistry>,
+    /// Running and recently finished scene re-stitch runs (PRD-25).
+    pub scene_restitches: Arc<SceneRestitchRegistry>,
+    /// Running and recently finished full worker repairs (PRD-43).
+    pub worker_repairs: Arc<WorkerRepairRegistry>,
+    /// Coalesced, briefly cached dashboard widget results (PRD-42).
+    pub widget_cache: Arc<WidgetCache<serde_json::Value>>,
 }
 
 impl AppState {
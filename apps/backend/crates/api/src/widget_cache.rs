@@ -0,0 +1,146 @@
+//! Single-flight result cache for dashboard widgets (PRD-42).
+//!
+//! Many users load the Studio Pulse dashboard at once, and each widget is an
+//! aggregation query. Requests are keyed by widget name plus parameters:
+//! concurrent requests for the same key share one computation, and the
+//! result is reused for a short TTL. Different keys compute independently.
+//! Failed computations are not cached.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a computed widget result is served before recomputing.
+pub const DEFAULT_WIDGET_TTL: Duration = Duration::from_secs(5);
+
+/// Per-key slot. Holding its async lock means "computing this key"; callers
+/// that arrive meanwhile queue on the lock and then find the fresh value.
+type Slot<V> = Arc<tokio::sync::Mutex<Option<(Instant, V)>>>;
+
+/// Coalescing TTL cache keyed by request identity.
+pub struct WidgetCache<V> {
+    ttl: Duration,
+    slots: Mutex<HashMap<String, Slot<V>>>,
+}
+
+impl<V: Clone> WidgetCache<V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the cached value for `key` if still fresh, otherwise run
+    /// `compute` once on behalf of every concurrent caller for that key.
+    pub async fn get_or_compute<E, F, Fut>(&self, key: String, compute: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        let slot = self.slot(key);
+        let mut guard = slot.lock().await;
+
+        if let Some((computed_at, value)) = guard.as_ref() {
+            if computed_at.elapsed() < self.ttl {
+                return Ok(value.clone());
+            }
+        }
+
+        let value = compute().await?;
+        *guard = Some((Instant::now(), value.clone()));
+        Ok(value)
+    }
+
+    /// Fetch or create the slot for `key`, dropping expired slots nobody is
+    /// using so the map stays bounded by the set of live parameter combos.
+    fn slot(&self, key: String) -> Slot<V> {
+        let mut slots = self.slots.lock().expect("widget cache lock poisoned");
+        let ttl = self.ttl;
+        slots.retain(|k, slot| {
+            *k == key
+                || Arc::strong_count(slot) > 1
+                || slot
+                    .try_lock()
+                    .map(|g| matches!(&*g, Some((at, _)) if at.elapsed() < ttl))
+                    .unwrap_or(true)
+        });
+        Arc::clone(slots.entry(key).or_default())
+    }
+}
+
+impl<V: Clone> Default for WidgetCache<V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_WIDGET_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    async fn slow_count(calls: &AtomicUsize, value: u32) -> Result<u32, String> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok(value)
+    }
+
+    #[tokio::test]
+    async fn concurrent_identical_requests_compute_once() {
+        let cache = Arc::new(WidgetCache::<u32>::new(Duration::from_secs(60)));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                let calls = Arc::clone(&calls);
+                tokio::spawn(async move {
+                    cache
+                        .get_or_compute("active-tasks".to_string(), || slow_count(&calls, 42))
+                        .await
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), Ok(42));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn different_keys_compute_separately() {
+        let cache = WidgetCache::<u32>::new(Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+
+        let (a, b) = tokio::join!(
+            cache.get_or_compute("feed:1".to_string(), || slow_count(&calls, 1)),
+            cache.get_or_compute("feed:2".to_string(), || slow_count(&calls, 2)),
+        );
+
+        assert_eq!((a, b), (Ok(1), Ok(2)));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn expired_and_failed_results_are_recomputed() {
+        let cache = WidgetCache::<u32>::new(Duration::ZERO);
+        let calls = AtomicUsize::new(0);
+
+        let failed: Result<u32, String> = cache
+            .get_or_compute("disk".to_string(), || async { Err("db down".to_string()) })
+            .await;
+        assert!(failed.is_err());
+
+        for _ in 0..2 {
+            let value = cache
+                .get_or_compute("disk".to_string(), || slow_count(&calls, 7))
+                .await;
+            assert_eq!(value, Ok(7));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -4,16 +4,24 @@ use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::State;
 use axum::response::IntoResponse;
 use futures::{SinkExt, StreamExt};
+use x121_core::types::DbId;
 
+use crate::middleware::auth::AuthUser;
 use crate::state::AppState;
-use crate::ws::manager::WsManager;
+use crate::ws::manager::{user_topic, WsManager};
+use crate::ws::messages::InboundMessage;
 
 /// HTTP handler that upgrades the connection to WebSocket.
 ///
-/// After the upgrade the connection is registered with `WsManager` and
+/// After the upgrade the connection is registered with `WsManager` under
+/// the authenticated user, subscribed to the user's notification topic, and
 /// managed by two spawned tasks (sender + receiver).
-pub async fn ws_handler(ws: WebSocketUpgrade, State(state): State<AppState>) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_socket(socket, state.ws_manager))
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| handle_socket(socket, state.ws_manager, auth.user_id))
 }
 
 /// Manage a single WebSocket connection after upgrade.
@@ -23,18 +31,20 @@ pub async fn ws_handler(ws: WebSocketUpgrade, State(state): State<AppState>) ->
 ///   2. Spawns a sender task that forwards messages from the manager channel.
 ///   3. Processes inbound messages on the current task.
 ///   4. Cleans up on disconnect.
-async fn handle_socket(socket: WebSocket, ws_manager: Arc<WsManager>) {
+async fn handle_socket(socket: WebSocket, ws_manager: Arc<WsManager>, user_id: DbId) {
     let conn_id = uuid::Uuid::new_v4().to_string();
-    tracing::info!(conn_id = %conn_id, "WebSocket connected");
+    tracing::info!(conn_id = %conn_id, user_id, "WebSocket connected");
 
     // Register and get the receiver for outbound messages.
-    let mut rx = ws_manager.add(conn_id.clone(), None).await;
+    let mut rx = ws_manager.add(conn_id.clone(), Some(user_id)).await;
+    ws_manager.subscribe(&conn_id, &user_topic(user_id)).await;
 
     let (mut sink, mut stream) = socket.split();
 
-    // Sender task: forward channel messages to the WebSocket sink.
+    // Sender task: forward channel messages to the WebSocket sink. It ends
+    // when the manager drops the connection (idle timeout or shutdown).
     let sender_conn_id = conn_id.clone();
-    let send_task = tokio::spawn(async move {
+    let mut send_task = tokio::spawn(async move {
         while let Some(msg) = rx.recv().await {
             if sink.send(msg).await.is_err() {
                 tracing::debug!(conn_id = %sender_conn_id, "WebSocket sink closed");
@@ -43,16 +53,26 @@ async fn handle_socket(socket: WebSocket, ws_manager: Arc<WsManager>) {
         }
     });
 
-    // Receiver loop: process inbound messages.
-    while let Some(result) = stream.next().await {
+    // Receiver loop: process inbound messages until the client goes away
+    // or the sender task ends.
+    loop {
+        let result = tokio::select! {
+            _ = &mut send_task => break,
+            next = stream.next() => match next {
+                Some(result) => result,
+                None => break,
+            },
+        };
         match result {
             Ok(Message::Close(_)) => break,
             Ok(Message::Pong(_)) => {
                 tracing::trace!(conn_id = %conn_id, "Pong received");
+                ws_manager.record_pong(&conn_id).await;
             }
-            Ok(_msg) => {
-                // Future PRDs will add message dispatching here.
+            Ok(Message::Text(text)) => {
+                handle_control(&ws_manager, &conn_id, &text).await;
             }
+            Ok(_msg) => {}
             Err(e) => {
                 tracing::debug!(conn_id = %conn_id, error = %e, "WebSocket receive error");
                 break;
@@ -65,3 +85,20 @@ async fn handle_socket(socket: WebSocket, ws_manager: Arc<WsManager>) {
     send_task.abort();
     tracing::info!(conn_id = %conn_id, "WebSocket disconnected");
 }
+
+/// Apply a client control frame to the connection's subscriptions.
+async fn handle_control(ws_manager: &WsManager, conn_id: &str, text: &str) {
+    match InboundMessage::parse(text) {
+        Some(InboundMessage::Subscribe { topic }) => {
+            if !ws_manager.subscribe(conn_id, &topic).await {
+                tracing::debug!(conn_id = %conn_id, topic = %topic, "Subscription rejected");
+            }
+        }
+        Some(InboundMessage::Unsubscribe { topic }) => {
+            ws_manager.unsubscribe(conn_id, &topic).await;
+        }
+        None => {
+            tracing::debug!(conn_id = %conn_id, "Ignoring unrecognised WebSocket message");
+        }
+    }
+}
@@ -1,23 +1,73 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::ws::manager::WsManager;
 
-/// Interval between heartbeat pings (in seconds).
-const HEARTBEAT_INTERVAL_SECS: u64 = 30;
+/// Default interval between heartbeat pings (in seconds).
+pub const DEFAULT_PING_INTERVAL_SECS: u64 = 30;
+
+/// Default time (in seconds) a connection may go without answering a ping
+/// before it is closed: three missed pings at the default interval.
+pub const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 90;
+
+/// Ping cadence and liveness timeout for WebSocket connections.
+///
+/// Deployments behind a proxy that drops idle connections should set
+/// `ping_interval_secs` below the proxy's idle timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatConfig {
+    /// Seconds between Ping frames sent to every connection.
+    pub ping_interval_secs: u64,
+    /// Seconds without a Pong after which a connection is closed.
+    pub idle_timeout_secs: u64,
+}
+
+impl HeartbeatConfig {
+    /// Interval between heartbeat pings.
+    pub fn ping_interval(&self) -> Duration {
+        Duration::from_secs(self.ping_interval_secs)
+    }
+
+    /// Maximum time a connection may go without a Pong.
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout_secs)
+    }
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval_secs: DEFAULT_PING_INTERVAL_SECS,
+            idle_timeout_secs: DEFAULT_IDLE_TIMEOUT_SECS,
+        }
+    }
+}
 
 /// Spawn a background task that sends periodic Ping frames to all connected
 /// WebSocket clients.
 ///
+/// Before each round of pings, connections that have not answered a ping
+/// within the manager's idle timeout are closed and removed.
+///
 /// The task runs until the provided `WsManager` is dropped (which happens
 /// during shutdown). The returned `JoinHandle` can be used to abort the task
 /// explicitly if needed.
-pub fn start_heartbeat(ws_manager: Arc<WsManager>) -> tokio::task::JoinHandle<()> {
+pub fn start_heartbeat(
+    ws_manager: Arc<WsManager>,
+    config: HeartbeatConfig,
+) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(HEARTBEAT_INTERVAL_SECS));
+        let mut interval = tokio::time::interval(config.ping_interval());
 
         loop {
             interval.tick().await;
+            let closed = ws_manager.close_idle(Instant::now()).await;
+            if !closed.is_empty() {
+                tracing::info!(
+                    count = closed.len(),
+                    "Closed WebSocket connections that stopped answering pings"
+                );
+            }
             let count = ws_manager.connection_count().await;
             tracing::debug!(count, "WebSocket heartbeat ping");
             ws_manager.ping_all().await;
@@ -1,23 +1,78 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
 
 use axum::body::Bytes;
 use axum::extract::ws::Message;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::RwLock;
 use x121_core::types::{DbId, Timestamp};
 
-/// Channel sender half for pushing messages to a WebSocket connection.
-pub type WsSender = mpsc::UnboundedSender<Message>;
+use super::heartbeat::HeartbeatConfig;
+use super::messages::OutboundMessage;
+use super::queue::{self, OnFull, PushOutcome, SendQueueConfig, WsReceiver, WsSender};
+
+/// Maximum length of a topic name such as `project:42`.
+pub const MAX_TOPIC_LEN: usize = 128;
+
+/// Maximum number of topics a single connection may subscribe to.
+pub const MAX_TOPICS_PER_CONNECTION: usize = 256;
+
+/// Topic carrying one user's in-app notifications.
+pub fn user_topic(user_id: DbId) -> String {
+    format!("user:{user_id}")
+}
+
+/// Whether `user_id` may subscribe to `topic`.
+///
+/// Topics are `<kind>:<id>`. A user topic is private to its user; project
+/// and scene topics are open to any authenticated user. Other kinds are
+/// rejected.
+fn may_subscribe(user_id: DbId, topic: &str) -> bool {
+    let Some((kind, id)) = topic.split_once(':') else {
+        return false;
+    };
+    let Ok(id) = id.parse::<DbId>() else {
+        return false;
+    };
+    match kind {
+        "user" => id == user_id,
+        "project" | "scene" => true,
+        _ => false,
+    }
+}
+
+/// Recipients of a typed outbound frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsTarget {
+    /// Every connected client.
+    All,
+    /// All connections belonging to one user.
+    User(DbId),
+    /// Every connection subscribed to a topic such as `project:42`.
+    Topic(String),
+    /// A single connection, by its ID.
+    Connection(String),
+}
 
 /// Metadata for a single WebSocket connection.
 pub struct WsConnection {
     /// Authenticated user ID, if the connection has been authenticated.
     /// Set after authentication (PRD-03).
     pub user_id: Option<DbId>,
-    /// Channel sender for outbound messages to this connection.
+    /// Bounded queue of outbound messages to this connection.
     pub sender: WsSender,
     /// When this connection was established.
     /// Used by connection management and monitoring (PRD-09).
     pub connected_at: Timestamp,
+    /// When this connection last answered a ping (initially, when it
+    /// connected). Used to close connections that stop responding.
+    pub last_pong: Instant,
+    /// Frames shed from this connection's full queue.
+    pub dropped_frames: AtomicU64,
+    /// Topics (e.g. `project:42`, `scene:1001`) this connection receives
+    /// [`WsTarget::Topic`] frames for. Dropped with the
+    /// connection, so every removal path also clears its subscriptions.
+    pub topics: HashSet<String>,
 }
 
 /// Manages all active WebSocket connections.
@@ -26,30 +81,47 @@ pub struct WsConnection {
 /// shared across the application.
 pub struct WsManager {
     connections: RwLock<HashMap<String, WsConnection>>,
+    heartbeat: HeartbeatConfig,
+    send_queue: SendQueueConfig,
 }
 
 impl WsManager {
-    /// Create a new, empty connection manager.
+    /// Create a new, empty connection manager with the default heartbeat
+    /// settings.
     pub fn new() -> Self {
+        Self::with_heartbeat(HeartbeatConfig::default())
+    }
+
+    /// Create a new, empty connection manager that closes connections
+    /// which go `heartbeat.idle_timeout_secs` without answering a ping.
+    pub fn with_heartbeat(heartbeat: HeartbeatConfig) -> Self {
         Self {
             connections: RwLock::new(HashMap::new()),
+            heartbeat,
+            send_queue: SendQueueConfig::default(),
         }
     }
 
+    /// Bound each connection's outbound queue to `send_queue.capacity`
+    /// frames, applying `send_queue.on_full` when a slow client fills it.
+    pub fn with_send_queue(mut self, send_queue: SendQueueConfig) -> Self {
+        self.send_queue = send_queue;
+        self
+    }
+
     /// Register a new connection.
     ///
-    /// Returns the receiver half of the message channel so the caller can
-    /// forward messages to the WebSocket sink.
-    pub async fn add(
-        &self,
-        conn_id: String,
-        user_id: Option<DbId>,
-    ) -> mpsc::UnboundedReceiver<Message> {
-        let (tx, rx) = mpsc::unbounded_channel();
+    /// Returns the receiver half of the connection's bounded queue so the
+    /// caller can forward messages to the WebSocket sink.
+    pub async fn add(&self, conn_id: String, user_id: Option<DbId>) -> WsReceiver {
+        let (tx, rx) = queue::channel(self.send_queue.capacity);
         let conn = WsConnection {
             user_id,
             sender: tx,
             connected_at: chrono::Utc::now(),
+            last_pong: Instant::now(),
+            dropped_frames: AtomicU64::new(0),
+            topics: HashSet::new(),
         };
         self.connections.write().await.insert(conn_id, conn);
         rx
@@ -60,6 +132,83 @@ impl WsManager {
         self.connections.write().await.remove(conn_id);
     }
 
+    /// Record that a connection answered a ping.
+    pub async fn record_pong(&self, conn_id: &str) {
+        if let Some(conn) = self.connections.write().await.get_mut(conn_id) {
+            conn.last_pong = Instant::now();
+        }
+    }
+
+    /// When a connection last answered a ping, or `None` if it is not
+    /// registered. Intended for debugging stale connections.
+    pub async fn last_pong(&self, conn_id: &str) -> Option<Instant> {
+        self.connections
+            .read()
+            .await
+            .get(conn_id)
+            .map(|conn| conn.last_pong)
+    }
+
+    /// Subscribe a connection to a topic.
+    ///
+    /// Returns `false` if the connection is not registered or not
+    /// authenticated, the topic name is empty or longer than
+    /// [`MAX_TOPIC_LEN`], the connection's user may not read the topic, or
+    /// the connection already holds [`MAX_TOPICS_PER_CONNECTION`]
+    /// subscriptions. Subscribing twice to the same topic is a no-op that
+    /// returns `true`.
+    pub async fn subscribe(&self, conn_id: &str, topic: &str) -> bool {
+        if topic.is_empty() || topic.len() > MAX_TOPIC_LEN {
+            return false;
+        }
+        let mut conns = self.connections.write().await;
+        let Some(conn) = conns.get_mut(conn_id) else {
+            return false;
+        };
+        if !conn
+            .user_id
+            .is_some_and(|user_id| may_subscribe(user_id, topic))
+        {
+            return false;
+        }
+        if !conn.topics.contains(topic) && conn.topics.len() >= MAX_TOPICS_PER_CONNECTION {
+            return false;
+        }
+        conn.topics.insert(topic.to_string());
+        true
+    }
+
+    /// Unsubscribe a connection from a topic.
+    ///
+    /// Returns `true` if the connection was subscribed to it.
+    pub async fn unsubscribe(&self, conn_id: &str, topic: &str) -> bool {
+        self.connections
+            .write()
+            .await
+            .get_mut(conn_id)
+            .is_some_and(|conn| conn.topics.remove(topic))
+    }
+
+    /// Number of connections subscribed to a topic.
+    pub async fn subscriber_count(&self, topic: &str) -> usize {
+        self.connections
+            .read()
+            .await
+            .values()
+            .filter(|conn| conn.topics.contains(topic))
+            .count()
+    }
+
+    /// Number of frames queued for a connection and not yet written to its
+    /// socket, or `None` if it is not registered.
+    pub async fn queue_depth(&self, conn_id: &str) -> Option<usize> {
+        self.connections
+            .read()
+            .await
+            .get(conn_id)
+            .map(|conn| conn.sender.len())
+    }
+
     /// Find all connection IDs associated with a given user.
     /// Used by authenticated messaging handlers (PRD-03+).
     pub async fn get_by_user(&self, user_id: DbId) -> Vec<String> {
@@ -77,33 +226,95 @@ impl WsManager {
             .collect()
     }
 
-    /// Broadcast a message to all connected clients.
+    /// Send a typed frame to the given target.
     ///
-    /// Connections whose send channels are closed are silently skipped
-    /// (they will be cleaned up on their next receive loop iteration).
-    /// Used by real-time event broadcasting (PRD-07+).
-    pub async fn broadcast(&self, message: Message) {
-        let conns = self.connections.read().await;
-        for conn in conns.values() {
-            let _ = conn.sender.send(message.clone());
-        }
+    /// This is the entry point all application producers should use so that
+    /// every frame carries the shared, versioned schema.
+    ///
+    /// Returns the number of connections the frame was sent to.
+    pub async fn send_typed(&self, target: WsTarget, message: &OutboundMessage) -> usize {
+        let frame = message.to_frame();
+        self.send_where(
+            |conn_id, conn| match &target {
+                WsTarget::All => true,
+                WsTarget::User(user_id) => conn.user_id == Some(*user_id),
+                WsTarget::Topic(topic) => conn.topics.contains(topic),
+                WsTarget::Connection(id) => conn_id == id,
+            },
+            &frame,
+        )
+        .await
     }
 
-    /// Send a message to all connections belonging to a specific user.
+    /// Queue `frame` on every connection matching `filter`, then disconnect
+    /// any connection whose full queue could not take it.
     ///
-    /// Returns the number of connections the message was sent to.
-    pub async fn send_to_user(&self, user_id: DbId, message: Message) -> usize {
-        let conns = self.connections.read().await;
+    /// Returns the number of matching connections.
+    async fn send_where(
+        &self,
+        filter: impl Fn(&str, &WsConnection) -> bool,
+        frame: &Message,
+    ) -> usize {
         let mut count = 0;
-        for conn in conns.values() {
-            if conn.user_id == Some(user_id) {
-                let _ = conn.sender.send(message.clone());
+        let mut overflowed = Vec::new();
+        {
+            let conns = self.connections.read().await;
+            for (conn_id, conn) in conns.iter().filter(|(conn_id, conn)| filter(conn_id, conn)) {
+                if !self.enqueue(conn_id, conn, frame.clone()) {
+                    overflowed.push(conn_id.clone());
+                }
                 count += 1;
             }
         }
+        self.disconnect_overflowed(&overflowed).await;
         count
     }
 
+    /// Queue a frame for one connection, applying the overflow policy.
+    ///
+    /// Returns `false` if the queue is full and the connection must be
+    /// disconnected.
+    fn enqueue(&self, conn_id: &str, conn: &WsConnection, frame: Message) -> bool {
+        match conn.sender.push(frame, self.send_queue.on_full) {
+            PushOutcome::Queued | PushOutcome::Closed => true,
+            PushOutcome::DroppedOldest => {
+                let dropped = conn.dropped_frames.fetch_add(1, Ordering::Relaxed) + 1;
+                if dropped == 1 {
+                    tracing::warn!(
+                        conn_id = %conn_id,
+                        capacity = self.send_queue.capacity,
+                        "WebSocket send queue full; shedding oldest frames"
+                    );
+                } else {
+                    tracing::debug!(conn_id = %conn_id, dropped, "WebSocket frame shed");
+                }
+                true
+            }
+            PushOutcome::Full => false,
+        }
+    }
+
+    /// Close and remove connections whose send queue overflowed under the
+    /// [`OnFull::Disconnect`] policy. Their backlog is discarded so the
+    /// Close frame is the next thing written.
+    async fn disconnect_overflowed(&self, conn_ids: &[String]) {
+        if conn_ids.is_empty() {
+            return;
+        }
+        let mut conns = self.connections.write().await;
+        for conn_id in conn_ids {
+            if let Some(conn) = conns.remove(conn_id) {
+                conn.sender.clear();
+                conn.sender.push(Message::Close(None), OnFull::Disconnect);
+                tracing::warn!(
+                    conn_id = %conn_id,
+                    capacity = self.send_queue.capacity,
+                    "WebSocket send queue full; disconnecting slow client"
+                );
+            }
+        }
+    }
+
     /// Return the current number of active connections.
     pub async fn connection_count(&self) -> usize {
         self.connections.read().await.len()
@@ -117,21 +328,43 @@ impl WsManager {
         let mut conns = self.connections.write().await;
         let count = conns.len();
         for conn in conns.values() {
-            let _ = conn.sender.send(Message::Close(None));
+            conn.sender
+                .push(Message::Close(None), self.send_queue.on_full);
         }
         conns.clear();
         tracing::info!(count, "Closed all WebSocket connections");
     }
 
+    /// Close and remove every connection that has not answered a ping
+    /// within the idle timeout as of `now`.
+    ///
+    /// Each removed connection is sent a Close frame; dropping its sender
+    /// then ends the connection's tasks. Returns the removed connection IDs.
+    pub async fn close_idle(&self, now: Instant) -> Vec<String> {
+        let idle_timeout = self.heartbeat.idle_timeout();
+        let mut conns = self.connections.write().await;
+        let idle: Vec<String> = conns
+            .iter()
+            .filter(|(_, conn)| now.saturating_duration_since(conn.last_pong) > idle_timeout)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for conn_id in &idle {
+            if let Some(conn) = conns.remove(conn_id) {
+                conn.sender
+                    .push(Message::Close(None), self.send_queue.on_full);
+                tracing::debug!(conn_id = %conn_id, "WebSocket connection timed out");
+            }
+        }
+        idle
+    }
+
     /// Send a Ping frame to every connected client.
     ///
     /// Used by the heartbeat task to keep connections alive and detect
     /// stale ones.
     pub async fn ping_all(&self) {
-        let conns = self.connections.read().await;
-        for conn in conns.values() {
-            let _ = conn.sender.send(Message::Ping(Bytes::new()));
-        }
+        self.send_where(|_, _| true, &Message::Ping(Bytes::new()))
+            .await;
     }
 }
 
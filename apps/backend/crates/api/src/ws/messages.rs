@@ -0,0 +1,148 @@
+//! Typed WebSocket frames.
+//!
+//! Every frame pushed to clients is an [`OutboundMessage`] serialized with a
+//! `type` discriminator and the schema version `v`, so all producers share
+//! one wire format. Send frames via [`WsManager::send_typed`].
+//!
+//! Clients send [`InboundMessage`] control frames, using the same `type`
+//! discriminator, to manage their topic subscriptions.
+//!
+//! [`WsManager::send_typed`]: super::WsManager::send_typed
+
+use axum::extract::ws::Message;
+use serde::{Deserialize, Serialize};
+use x121_core::activity::ActivityLogEntry;
+use x121_core::job_events::{
+    MSG_TYPE_JOB_CANCELLED, MSG_TYPE_JOB_COMPLETED, MSG_TYPE_JOB_FAILED, MSG_TYPE_JOB_PROGRESS,
+};
+use x121_core::types::{DbId, Timestamp};
+
+/// Current outbound frame schema version, sent as `v` on every frame.
+///
+/// Bump when an existing variant changes shape incompatibly. Adding a new
+/// variant or a new optional field does not require a bump.
+pub const WS_SCHEMA_VERSION: u32 = 1;
+
+/// A single outbound WebSocket frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutboundMessage {
+    /// In-app notification for a platform event.
+    Notification {
+        event_type: String,
+        payload: serde_json::Value,
+        timestamp: Timestamp,
+    },
+    /// Job lifecycle update.
+    Progress {
+        /// One of the `MSG_TYPE_JOB_*` constants in `x121_core::job_events`.
+        event: String,
+        job_id: DbId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        percent: Option<i16>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        current_node: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A user came online or went offline.
+    Presence { user_id: DbId, online: bool },
+    /// Connection-level instruction from the server (e.g. shutdown).
+    Control {
+        action: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+    /// Activity console log entry (PRD-118).
+    #[serde(rename = "entry")]
+    ActivityEntry {
+        #[serde(flatten)]
+        entry: ActivityLogEntry,
+    },
+    /// The activity console stream fell behind and skipped entries.
+    #[serde(rename = "lagged")]
+    ActivityLagged { skipped: u64 },
+    /// Any `type` this build does not know about.
+    ///
+    /// Only produced when parsing frames from a newer server; never sent.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Wire envelope adding the schema version alongside the tagged message.
+#[derive(Serialize)]
+struct Envelope<'a> {
+    v: u32,
+    #[serde(flatten)]
+    message: &'a OutboundMessage,
+}
+
+impl OutboundMessage {
+    /// Job progress percentage and current node.
+    pub fn job_progress(job_id: DbId, percent: i16, current_node: Option<String>) -> Self {
+        Self::Progress {
+            event: MSG_TYPE_JOB_PROGRESS.to_string(),
+            job_id,
+            percent: Some(percent),
+            current_node,
+            error: None,
+        }
+    }
+
+    /// Job completed successfully.
+    pub fn job_completed(job_id: DbId) -> Self {
+        Self::job_event(MSG_TYPE_JOB_COMPLETED, job_id, None)
+    }
+
+    /// Job failed with an error.
+    pub fn job_failed(job_id: DbId, error: impl Into<String>) -> Self {
+        Self::job_event(MSG_TYPE_JOB_FAILED, job_id, Some(error.into()))
+    }
+
+    /// Job was cancelled.
+    pub fn job_cancelled(job_id: DbId) -> Self {
+        Self::job_event(MSG_TYPE_JOB_CANCELLED, job_id, None)
+    }
+
+    fn job_event(event: &str, job_id: DbId, error: Option<String>) -> Self {
+        Self::Progress {
+            event: event.to_string(),
+            job_id,
+            percent: None,
+            current_node: None,
+            error,
+        }
+    }
+
+    /// Serialize to the versioned JSON wire format.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(Envelope {
+            v: WS_SCHEMA_VERSION,
+            message: self,
+        })
+        .expect("outbound message serialization cannot fail")
+    }
+
+    /// Build the text frame sent to clients.
+    pub fn to_frame(&self) -> Message {
+        Message::Text(self.to_json().to_string().into())
+    }
+}
+
+/// A control frame sent by a client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InboundMessage {
+    /// Start receiving frames broadcast to `topic` (e.g. `project:42`).
+    Subscribe { topic: String },
+    /// Stop receiving frames broadcast to `topic`.
+    Unsubscribe { topic: String },
+}
+
+impl InboundMessage {
+    /// Parse a client text frame, returning `None` if it is not a known
+    /// control message.
+    pub fn parse(text: &str) -> Option<Self> {
+        serde_json::from_str(text).ok()
+    }
+}
@@ -1,12 +1,17 @@
 //! WebSocket infrastructure for real-time communication.
 //!
-//! Provides connection management, heartbeat monitoring, and the HTTP
-//! upgrade handler used by Axum routes.
+//! Provides connection management, topic subscriptions, heartbeat
+//! monitoring, bounded per-connection send queues, typed frames, and the
+//! HTTP upgrade handler used by Axum routes.
 
 mod handler;
 mod heartbeat;
 pub mod manager;
+pub mod messages;
+pub mod queue;
 
 pub use handler::ws_handler;
-pub use heartbeat::start_heartbeat;
-pub use manager::WsManager;
+pub use heartbeat::{start_heartbeat, HeartbeatConfig};
+pub use manager::{user_topic, WsManager, WsTarget};
+pub use messages::{InboundMessage, OutboundMessage, WS_SCHEMA_VERSION};
+pub use queue::{OnFull, SendQueueConfig};
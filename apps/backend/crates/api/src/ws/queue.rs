@@ -0,0 +1,209 @@
+//! Bounded per-connection outbound queue.
+//!
+//! Each WebSocket connection gets a queue of at most `capacity` frames
+//! between the producers (via [`WsManager`](super::WsManager)) and the task
+//! writing to the socket. When a slow client lets the queue fill up, the
+//! configured [`OnFull`] policy decides whether the oldest frame is shed or
+//! the connection is dropped, so memory stays bounded either way.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::ws::Message;
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::Notify;
+
+/// Default number of frames buffered per connection.
+pub const DEFAULT_SEND_QUEUE_CAPACITY: usize = 256;
+
+/// What to do when a connection's outbound queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnFull {
+    /// Discard the oldest non-critical frame to make room.
+    DropOldest,
+    /// Close the connection.
+    Disconnect,
+}
+
+impl OnFull {
+    /// Parse a policy name as used in configuration (`drop_oldest` or
+    /// `disconnect`).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "drop_oldest" => Some(Self::DropOldest),
+            "disconnect" => Some(Self::Disconnect),
+            _ => None,
+        }
+    }
+}
+
+/// Capacity and overflow policy for per-connection outbound queues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendQueueConfig {
+    /// Maximum frames buffered for one connection.
+    pub capacity: usize,
+    pub on_full: OnFull,
+}
+
+impl Default for SendQueueConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_SEND_QUEUE_CAPACITY,
+            on_full: OnFull::DropOldest,
+        }
+    }
+}
+
+/// Result of pushing a frame onto a connection's queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    /// The frame was queued without shedding anything.
+    Queued,
+    /// The frame was queued after discarding the oldest non-critical frame.
+    DroppedOldest,
+    /// The frame was not queued: the queue is full (or holds only critical
+    /// frames) and the policy does not allow shedding.
+    Full,
+    /// The receiving side is gone; the frame was discarded.
+    Closed,
+}
+
+struct State {
+    frames: VecDeque<Message>,
+    sender_dropped: bool,
+    receiver_dropped: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    notify: Notify,
+    capacity: usize,
+}
+
+impl Shared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().expect("ws send queue lock poisoned")
+    }
+}
+
+/// Close frames must reach the client even when the queue is full.
+fn is_critical(frame: &Message) -> bool {
+    matches!(frame, Message::Close(_))
+}
+
+/// Create a bounded queue holding at most `capacity` frames.
+pub fn channel(capacity: usize) -> (WsSender, WsReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            frames: VecDeque::with_capacity(capacity.min(DEFAULT_SEND_QUEUE_CAPACITY)),
+            sender_dropped: false,
+            receiver_dropped: false,
+        }),
+        notify: Notify::new(),
+        capacity: capacity.max(1),
+    });
+    (
+        WsSender {
+            shared: Arc::clone(&shared),
+        },
+        WsReceiver { shared },
+    )
+}
+
+/// Producer half of a connection's outbound queue.
+///
+/// Dropping it ends the stream: the receiver yields the frames still queued
+/// and then `None`.
+pub struct WsSender {
+    shared: Arc<Shared>,
+}
+
+impl WsSender {
+    /// Queue a frame, shedding the oldest non-critical frame when full and
+    /// `on_full` is [`OnFull::DropOldest`].
+    ///
+    /// Critical frames (Close) are always queued, even past capacity.
+    pub fn push(&self, frame: Message, on_full: OnFull) -> PushOutcome {
+        let mut state = self.shared.lock();
+        if state.receiver_dropped {
+            return PushOutcome::Closed;
+        }
+
+        let mut outcome = PushOutcome::Queued;
+        if state.frames.len() >= self.shared.capacity {
+            let oldest = state.frames.iter().position(|f| !is_critical(f));
+            match (on_full, oldest) {
+                (OnFull::DropOldest, Some(position)) => {
+                    state.frames.remove(position);
+                    outcome = PushOutcome::DroppedOldest;
+                }
+                _ if is_critical(&frame) => {}
+                _ => return PushOutcome::Full,
+            }
+        }
+
+        state.frames.push_back(frame);
+        drop(state);
+        self.shared.notify.notify_one();
+        outcome
+    }
+
+    /// Discard every queued frame.
+    pub fn clear(&self) {
+        self.shared.lock().frames.clear();
+    }
+
+    /// Number of frames waiting to be written.
+    pub fn len(&self) -> usize {
+        self.shared.lock().frames.len()
+    }
+
+    /// Whether no frames are waiting to be written.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Drop for WsSender {
+    fn drop(&mut self) {
+        self.shared.lock().sender_dropped = true;
+        self.shared.notify.notify_one();
+    }
+}
+
+/// Consumer half of a connection's outbound queue.
+pub struct WsReceiver {
+    shared: Arc<Shared>,
+}
+
+impl WsReceiver {
+    /// Wait for the next frame. Returns `None` once the sender is dropped
+    /// and every queued frame has been received.
+    pub async fn recv(&mut self) -> Option<Message> {
+        loop {
+            match self.try_recv() {
+                Ok(frame) => return Some(frame),
+                Err(TryRecvError::Disconnected) => return None,
+                Err(TryRecvError::Empty) => self.shared.notify.notified().await,
+            }
+        }
+    }
+
+    /// Take the next frame without waiting.
+    pub fn try_recv(&mut self) -> Result<Message, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.frames.pop_front() {
+            Some(frame) => Ok(frame),
+            None if state.sender_dropped => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+}
+
+impl Drop for WsReceiver {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_dropped = true;
+        state.frames.clear();
+    }
+}
@@ -0,0 +1,123 @@
+//! Integration tests for frame annotation export (PRD-70).
+//!
+//! Verifies `GET /segments/{id}/annotations/export/{frame}` returns the
+//! frame's drawing objects as JSON or COCO, and 404s for a frame past the
+//! end of the segment instead of returning an empty export.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, post_json_auth,
+    seed_scenes,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::repositories::SegmentRepo;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Seed a scene with one 2-second segment (60 frames at the default fps)
+/// and return the segment id.
+async fn setup_segment(pool: &PgPool) -> i64 {
+    let scene_id = seed_scenes(pool, "export", 1, json!({})).await.scene_ids[0];
+    let segment = SegmentRepo::create(
+        pool,
+        &serde_json::from_value(json!({
+            "scene_id": scene_id,
+            "sequence_index": 0,
+            "duration_secs": 2.0,
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    segment.id
+}
+
+// ---------------------------------------------------------------------------
+// Test: an annotated frame exports as JSON and COCO
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_export_frame_formats(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "annotator", 2).await;
+    let segment_id = setup_segment(&pool).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "annotator", &password).await;
+
+    let response = post_json_auth(
+        app.clone(),
+        &format!("/api/v1/segments/{segment_id}/annotations"),
+        json!({
+            "frame_number": 12,
+            "annotations_json": [
+                {
+                    "tool": "rectangle",
+                    "data": {"startX": 10, "startY": 20, "endX": 40, "endY": 60},
+                    "color": "#FF0000",
+                    "strokeWidth": 2
+                }
+            ],
+        }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let uri = format!("/api/v1/segments/{segment_id}/annotations/export/12");
+    let response = get_auth(app.clone(), &uri, &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let data = body_json(response).await["data"].clone();
+    assert_eq!(data.as_array().unwrap().len(), 1);
+    assert_eq!(data[0]["tool"], "rectangle");
+
+    let response = get_auth(app, &format!("{uri}?format=coco"), &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let data = body_json(response).await["data"].clone();
+    assert_eq!(data["images"][0]["frame_number"], 12);
+    assert_eq!(
+        data["annotations"][0]["bbox"],
+        json!([10.0, 20.0, 30.0, 40.0])
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Test: a frame past the end of the segment is 404
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_export_frame_out_of_range_returns_404(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "annotator", 2).await;
+    let segment_id = setup_segment(&pool).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "annotator", &password).await;
+
+    let response = get_auth(
+        app.clone(),
+        &format!("/api/v1/segments/{segment_id}/annotations/export/60"),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The last frame is still exportable, even when it has no annotations.
+    let response = get_auth(
+        app.clone(),
+        &format!("/api/v1/segments/{segment_id}/annotations/export/59"),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await["data"], json!([]));
+
+    let response = get_auth(
+        app,
+        &format!("/api/v1/segments/{segment_id}/annotations/export/0?format=xml"),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
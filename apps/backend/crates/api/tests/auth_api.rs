@@ -281,3 +281,32 @@ async fn test_account_lockout(pool: PgPool) {
         "error message should mention the account is locked, got: {error_msg}"
     );
 }
+
+/// A re-hash computed from a stale hash does not overwrite a newer password.
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_replace_password_hash_skips_changed_hash(pool: PgPool) {
+    let (user, _password) = create_test_user(&pool, "rehashed", 1).await;
+    let verified_hash = user.password_hash.clone();
+
+    // An admin reset lands between login verification and the re-hash.
+    let reset_hash = hash_password("reset_password_456!").unwrap();
+    UserRepo::update_password(&pool, user.id, &reset_hash)
+        .await
+        .unwrap();
+
+    let rehashed = hash_password("test_password_123!").unwrap();
+    let replaced = UserRepo::replace_password_hash(&pool, user.id, &verified_hash, &rehashed)
+        .await
+        .unwrap();
+    assert!(!replaced);
+
+    let stored = UserRepo::find_by_id(&pool, user.id).await.unwrap().unwrap();
+    assert_eq!(stored.password_hash, reset_hash);
+
+    // With the current hash the replacement goes through.
+    assert!(
+        UserRepo::replace_password_hash(&pool, user.id, &reset_hash, &rehashed)
+            .await
+            .unwrap()
+    );
+}
@@ -2,6 +2,7 @@
 // uses every helper, so we suppress dead_code warnings at the item level.
 #![allow(dead_code)]
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::body::Body;
@@ -9,18 +10,28 @@ use axum::http::{Method, Request, StatusCode};
 use axum::Router;
 use http_body_util::BodyExt;
 use sqlx::PgPool;
+use tokio::sync::RwLock;
 use tower::ServiceExt;
 
-use x121_api::auth::jwt::JwtConfig;
-use x121_api::auth::password::hash_password;
+use x121_api::auth::jwt::{JwtConfig, JwtKey};
+use x121_api::auth::password::{hash_password, PasswordHashConfig};
 use x121_api::config::ServerConfig;
+use x121_api::engine::embedding_batch::EmbeddingBatchRegistry;
 use x121_api::engine::health_aggregator::HealthAggregator;
+use x121_api::engine::scene_restitch::SceneRestitchRegistry;
+use x121_api::engine::worker_repair::WorkerRepairRegistry;
+use x121_api::middleware::rate_limit::RateLimitConfig;
 use x121_api::router::build_app_router;
 use x121_api::scripting::orchestrator::ScriptOrchestrator;
 use x121_api::state::AppState;
-use x121_api::ws::WsManager;
+use x121_api::widget_cache::WidgetCache;
+use x121_api::ws::{HeartbeatConfig, SendQueueConfig, WsManager};
+use x121_db::models::avatar::CreateAvatar;
+use x121_db::models::project::CreateProject;
 use x121_db::models::user::{CreateUser, User};
-use x121_db::repositories::UserRepo;
+use x121_db::repositories::{
+    AvatarRepo, MediaVariantRepo, ProjectRepo, SceneRepo, SceneTypeRepo, UserRepo,
+};
 
 /// Build a test `ServerConfig` with safe defaults.
 ///
@@ -32,12 +43,39 @@ pub fn test_config() -> ServerConfig {
         port: 0,
         cors_origins: vec!["http://localhost:5173".to_string()],
         request_timeout_secs: 30,
+        role_request_timeouts: HashMap::from([("admin".to_string(), 300)]),
         shutdown_timeout_secs: 30,
         jwt: JwtConfig {
-            secret: "test-secret-for-integration-tests-minimum-length".to_string(),
+            current: JwtKey::new("test-secret-for-integration-tests-minimum-length"),
+            previous: Vec::new(),
             access_token_expiry_mins: 15,
             refresh_token_expiry_days: 7,
         },
+        storage_root: "/tmp/x121_test_storage".to_string(),
+        tag_bulk_max_entities: x121_core::tags::DEFAULT_BULK_MAX_ENTITIES,
+        tag_bulk_max_tags: x121_core::tags::DEFAULT_BULK_MAX_TAGS,
+        tag_bulk_max_body_bytes: x121_core::tags::DEFAULT_BULK_MAX_BODY_BYTES,
+        import_scan_concurrency: x121_core::importer::DEFAULT_SCAN_CONCURRENCY,
+        webhook_failure_threshold: x121_core::api_keys::DEFAULT_WEBHOOK_FAILURE_THRESHOLD,
+        webhook_probe_interval_secs: x121_core::api_keys::DEFAULT_WEBHOOK_PROBE_INTERVAL_SECS,
+        hook_execution_concurrency: x121_core::pipeline_hooks::DEFAULT_HOOK_CONCURRENCY,
+        hook_scripts_dir: x121_core::pipeline_hooks::DEFAULT_HOOK_SCRIPTS_DIR.into(),
+        max_parameter_overrides: x121_core::workflow_import::DEFAULT_MAX_PARAMETER_OVERRIDES,
+        compression_enabled: true,
+        compression_min_bytes: 1024,
+        video_stream_chunk_bytes: x121_core::http_range::DEFAULT_STREAM_CHUNK_BYTES,
+        script_max_concurrent_executions:
+            x121_core::scripting::executor::DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+        script_max_queued_executions: x121_core::scripting::executor::DEFAULT_MAX_QUEUED_EXECUTIONS,
+        wiki_reindex_concurrency: x121_core::wiki::DEFAULT_REINDEX_CONCURRENCY,
+        wiki_index_debounce_ms: x121_core::wiki::DEFAULT_INDEX_DEBOUNCE_MS,
+        annotation_export_max: x121_core::annotation::DEFAULT_MAX_ANNOTATIONS_PER_EXPORT,
+        undo_tree_list_max: x121_core::undo::DEFAULT_MAX_UNDO_TREE_LIST_LIMIT,
+        ws_heartbeat: HeartbeatConfig::default(),
+        ws_send_queue: SendQueueConfig::default(),
+        rate_limit: RateLimitConfig::default(),
+        trusted_proxies: Vec::new(),
+        password_hash: PasswordHashConfig::default(),
     }
 }
 
@@ -62,6 +100,21 @@ async fn build_test_app_with(
     pool: PgPool,
     script_orchestrator: Option<Arc<ScriptOrchestrator>>,
 ) -> Router {
+    let state = build_test_state_with(pool, script_orchestrator).await;
+    let config = state.config.as_ref().clone();
+    build_app_router(state, &config)
+}
+
+/// Build the application state used by [`build_test_app`], for driving
+/// background workers directly.
+pub async fn build_test_state(pool: PgPool) -> AppState {
+    build_test_state_with(pool, None).await
+}
+
+async fn build_test_state_with(
+    pool: PgPool,
+    script_orchestrator: Option<Arc<ScriptOrchestrator>>,
+) -> AppState {
     let config = test_config();
     let ws_manager = Arc::new(WsManager::new());
     let comfyui_manager = x121_comfyui::manager::ComfyUIManager::start(pool.clone()).await;
@@ -72,10 +125,16 @@ async fn build_test_app_with(
         std::time::Duration::from_secs(60),
     ));
     let activity_broadcaster = Arc::new(x121_events::ActivityLogBroadcaster::default());
+    let lifecycle_bridge = Arc::new(x121_cloud::lifecycle::LifecycleBridge::new(
+        pool.clone(),
+        Arc::clone(&comfyui_manager),
+    ));
+    let storage_provider = x121_core::storage::factory::build_provider(None, &settings_service)
+        .expect("local storage provider should build");
 
-    let state = AppState {
+    AppState {
         pool,
-        config: Arc::new(config.clone()),
+        config: Arc::new(config),
         ws_manager,
         comfyui_manager,
         event_bus,
@@ -83,9 +142,15 @@ async fn build_test_app_with(
         health_aggregator,
         settings_service,
         activity_broadcaster,
-    };
-
-    build_app_router(state, &config)
+        cloud_registry: Arc::new(x121_cloud::registry::ProviderRegistry::new()),
+        storage: Arc::new(RwLock::new(storage_provider)),
+        lifecycle_bridge,
+        scaling_nudge: x121_cloud::services::ServiceNudge::detached(),
+        embedding_batches: Arc::new(EmbeddingBatchRegistry::new()),
+        widget_cache: Arc::new(WidgetCache::default()),
+        scene_restitches: Arc::new(SceneRestitchRegistry::new()),
+        worker_repairs: Arc::new(WorkerRepairRegistry::new()),
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -228,13 +293,13 @@ pub async fn create_test_user(pool: &PgPool, username: &str, role_id: i64) -> (U
     (user, password.to_string())
 }
 
-/// Log in a user via the API and return the JSON response containing
-/// `access_token`, `refresh_token`, and `user` info.
+/// Log in a user via the API and return the response's `data` object
+/// containing `access_token`, `refresh_token`, and `user` info.
 pub async fn login_user(app: Router, username: &str, password: &str) -> serde_json::Value {
     let body = serde_json::json!({ "username": username, "password": password });
     let response = post_json(app, "/api/v1/auth/login", body).await;
     assert_eq!(response.status(), StatusCode::OK);
-    body_json(response).await
+    body_json(response).await["data"].clone()
 }
 
 /// Convenience: log in and return just the access token string.
@@ -245,3 +310,106 @@ pub async fn login_for_token(app: Router, username: &str, password: &str) -> Str
         .expect("access_token should be a string")
         .to_string()
 }
+
+// ---------------------------------------------------------------------------
+// Shared scene fixture helpers
+// ---------------------------------------------------------------------------
+
+/// IDs of the rows created by [`seed_scenes`].
+pub struct SeededScenes {
+    pub project_id: i64,
+    pub avatar_id: i64,
+    pub media_variant_id: i64,
+    /// One scene type per scene, in creation order.
+    pub scene_type_ids: Vec<i64>,
+    pub scene_ids: Vec<i64>,
+}
+
+/// Build project -> avatar -> variant and `count` scene types with one
+/// scene each.
+///
+/// Names, slugs and paths are derived from `label`, which must be a valid
+/// slug fragment (e.g. `"trim"`). `scene_type_fields` is merged into every
+/// scene type's create payload; pass `json!({})` for the defaults.
+pub async fn seed_scenes(
+    pool: &PgPool,
+    label: &str,
+    count: usize,
+    scene_type_fields: serde_json::Value,
+) -> SeededScenes {
+    let project = ProjectRepo::create(
+        pool,
+        &CreateProject {
+            name: format!("{label} project"),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+    let avatar = AvatarRepo::create(
+        pool,
+        &CreateAvatar {
+            project_id: project.id,
+            name: format!("{label} avatar"),
+            status_id: None,
+            metadata: None,
+            settings: None,
+            group_id: None,
+        },
+    )
+    .await
+    .unwrap();
+    let variant = MediaVariantRepo::create(
+        pool,
+        &serde_json::from_value(serde_json::json!({
+            "avatar_id": avatar.id,
+            "variant_label": "clothed",
+            "file_path": format!("/img/{label}.png"),
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let mut seeded = SeededScenes {
+        project_id: project.id,
+        avatar_id: avatar.id,
+        media_variant_id: variant.id,
+        scene_type_ids: Vec::with_capacity(count),
+        scene_ids: Vec::with_capacity(count),
+    };
+    for i in 0..count {
+        let mut scene_type_input = serde_json::json!({
+            "project_id": project.id,
+            "name": format!("{label} scene type {i}"),
+            "slug": format!("{label}-scene-type-{i}"),
+        });
+        if let (Some(input), Some(fields)) = (
+            scene_type_input.as_object_mut(),
+            scene_type_fields.as_object(),
+        ) {
+            input.extend(fields.clone());
+        }
+        let scene_type =
+            SceneTypeRepo::create(pool, &serde_json::from_value(scene_type_input).unwrap())
+                .await
+                .unwrap();
+        let scene = SceneRepo::create(
+            pool,
+            &serde_json::from_value(serde_json::json!({
+                "avatar_id": avatar.id,
+                "scene_type_id": scene_type.id,
+                "media_variant_id": variant.id,
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+        seeded.scene_type_ids.push(scene_type.id);
+        seeded.scene_ids.push(scene.id);
+    }
+    seeded
+}
@@ -0,0 +1,152 @@
+//! Integration tests for batch duplicate checks (PRD-79).
+//!
+//! Seeds avatars with and without face embeddings and verifies that
+//! `POST /avatars/duplicates/batch` checks the former against each other
+//! while reporting the latter as skipped, without failing the batch.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{body_json, build_test_app, create_test_user, login_for_token, post_json_auth};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::models::avatar::CreateAvatar;
+use x121_db::models::project::CreateProject;
+use x121_db::repositories::{AvatarRepo, ProjectRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create an avatar in `project_id`, optionally storing a face embedding
+/// whose first component is `lead` (the remaining 511 are constant).
+async fn seed_avatar(pool: &PgPool, project_id: i64, name: &str, lead: Option<f32>) -> i64 {
+    let avatar = AvatarRepo::create(
+        pool,
+        &CreateAvatar {
+            project_id,
+            name: name.to_string(),
+            status_id: None,
+            metadata: None,
+            settings: None,
+            group_id: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    if let Some(lead) = lead {
+        let values: Vec<String> = std::iter::once(lead.to_string())
+            .chain(std::iter::repeat_n("0.01".to_string(), 511))
+            .collect();
+        sqlx::query("UPDATE avatars SET face_embedding = $2::vector WHERE id = $1")
+            .bind(avatar.id)
+            .bind(format!("[{}]", values.join(",")))
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    avatar.id
+}
+
+// ---------------------------------------------------------------------------
+// Test: batch check skips avatars without embeddings
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_batch_check_reports_missing_embeddings_per_item(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "dup_checker", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "dup_checker", &password).await;
+
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Duplicate Batch".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    // Two near-identical faces, one distinct face, and one without a face.
+    let twin_a = seed_avatar(&pool, project.id, "Twin A", Some(1.0)).await;
+    let twin_b = seed_avatar(&pool, project.id, "Twin B", Some(1.0)).await;
+    let distinct = seed_avatar(&pool, project.id, "Distinct", Some(-1.0)).await;
+    let faceless = seed_avatar(&pool, project.id, "Faceless", None).await;
+    let missing = 999_999;
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/avatars/duplicates/batch",
+        json!({ "avatar_ids": [twin_a, faceless, twin_b, distinct, twin_a, missing] }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let items = body_json(response).await["data"]
+        .as_array()
+        .unwrap()
+        .clone();
+
+    // One result per distinct ID, in input order.
+    let ids: Vec<i64> = items
+        .iter()
+        .map(|i| i["avatar_id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(ids, vec![twin_a, faceless, twin_b, distinct, missing]);
+
+    // The twins were checked and matched each other.
+    for item in [&items[0], &items[2]] {
+        assert_eq!(item["outcome"], "checked");
+        let checks = item["checks"].as_array().unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0]["source_avatar_id"], twin_a);
+        assert_eq!(checks[0]["matched_avatar_id"], twin_b);
+    }
+
+    // The distinct face was checked and matched nothing.
+    assert_eq!(items[3]["outcome"], "checked");
+    let checks = items[3]["checks"].as_array().unwrap();
+    assert_eq!(checks.len(), 1);
+    assert!(checks[0]["matched_avatar_id"].is_null());
+
+    // Avatars without an embedding, or that do not exist, were skipped.
+    assert_eq!(items[1]["outcome"], "skipped");
+    assert_eq!(items[1]["reason"], "no_embedding");
+    assert!(items[1]["message"].is_string());
+    assert_eq!(items[4]["outcome"], "skipped");
+    assert_eq!(items[4]["reason"], "not_found");
+
+    let recorded: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM duplicate_checks WHERE source_avatar_id = $1")
+            .bind(faceless)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(recorded, 0, "skipped avatars get no check record");
+}
+
+// ---------------------------------------------------------------------------
+// Test: oversized batches are rejected
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_batch_check_rejects_oversized_batch(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "dup_bulk", 1).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "dup_bulk", &password).await;
+
+    let ids: Vec<i64> = (1..=500).collect();
+    let response = post_json_auth(
+        app,
+        "/api/v1/avatars/duplicates/batch",
+        json!({ "avatar_ids": ids }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -200,7 +200,7 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
     // Set up: project -> avatar, scene_type, image_variant -> scene.
     // We use the repository layer directly to avoid many HTTP calls for setup.
     use x121_db::models::avatar::CreateAvatar;
-    use x121_db::models::image::CreateMediaVariant;
+    use x121_db::models::media::CreateMediaVariant;
     use x121_db::models::project::CreateProject;
     use x121_db::models::scene::CreateScene;
     use x121_db::models::scene_type::CreateSceneType;
@@ -213,6 +213,7 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
             description: None,
             status_id: None,
             retention_days: None,
+            pipeline_id: 1,
         },
     )
     .await
@@ -225,6 +226,7 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
             status_id: None,
             metadata: None,
             settings: None,
+            group_id: None,
         },
     )
     .await
@@ -234,6 +236,7 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
         &CreateSceneType {
             project_id: Some(project.id),
             name: "Run".to_string(),
+            slug: "run".to_string(),
             status_id: None,
             workflow_json: None,
             lora_config: None,
@@ -253,6 +256,19 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
             sort_order: None,
             is_active: None,
             is_studio_level: None,
+            workflow_id: None,
+            has_clothes_off_transition: None,
+            parent_scene_type_id: None,
+            generation_strategy: None,
+            expected_chunks: None,
+            chunk_output_pattern: None,
+            auto_retry_enabled: None,
+            auto_retry_max_attempts: None,
+            auto_retry_trigger_checks: None,
+            auto_retry_seed_variation: None,
+            auto_retry_cfg_jitter: None,
+            target_fps: None,
+            target_resolution: None,
         },
     )
     .await
@@ -276,6 +292,7 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
             version: None,
             parent_variant_id: None,
             generation_params: None,
+            content_hash: None,
         },
     )
     .await
@@ -285,9 +302,16 @@ async fn test_segment_crud_under_scene(pool: PgPool) {
         &CreateScene {
             avatar_id: avatar.id,
             scene_type_id: scene_type.id,
-            media_variant_id: variant.id,
+            media_variant_id: Some(variant.id),
             status_id: None,
             transition_mode: None,
+            track_id: None,
+            total_segments_estimated: None,
+            total_segments_completed: None,
+            actual_duration_secs: None,
+            transition_segment_index: None,
+            generation_started_at: None,
+            generation_completed_at: None,
         },
     )
     .await
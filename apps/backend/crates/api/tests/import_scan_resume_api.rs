@@ -0,0 +1,116 @@
+//! HTTP-level integration tests for resumable folder scans behind
+//! `GET /api/v1/import/{id}/preview`.
+//!
+//! A staged folder is laid out in a temp dir with a file that cannot be
+//! read sorting after the first checkpoint batch, so the first preview
+//! fails partway through the walk. The second preview must continue from
+//! the saved cursor instead of rescanning the tree.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{body_json, build_test_app, get};
+use sqlx::PgPool;
+use x121_core::importer::SCAN_CHECKPOINT_INTERVAL;
+use x121_db::models::importer::CreateImportSession;
+use x121_db::models::project::CreateProject;
+use x121_db::repositories::{ImportSessionRepo, ProjectRepo};
+
+/// Files past the first checkpoint batch, scanned only after the resume.
+const TRAILING_FILES: usize = 50;
+
+fn file_name(index: usize) -> String {
+    format!("a{index:04}.png")
+}
+
+// ---------------------------------------------------------------------------
+// Test: an interrupted preview resumes from the saved scan cursor
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_interrupted_preview_resumes_from_scan_cursor(pool: PgPool) {
+    let staging = tempfile::tempdir().unwrap();
+    let total = SCAN_CHECKPOINT_INTERVAL + TRAILING_FILES;
+    for index in 0..total {
+        std::fs::write(staging.path().join(file_name(index)), b"x").unwrap();
+    }
+    // A dangling symlink walks as a file but fails the size read, which
+    // interrupts the scan in the second batch.
+    let broken = staging.path().join("z_broken.png");
+    std::os::unix::fs::symlink(staging.path().join("missing.png"), &broken).unwrap();
+
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Import Resume".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+    let session = ImportSessionRepo::create(
+        &pool,
+        &CreateImportSession {
+            project_id: project.id,
+            staging_path: staging.path().to_string_lossy().to_string(),
+            source_name: "resume".to_string(),
+            created_by: None,
+        },
+    )
+    .await
+    .unwrap();
+    let uri = format!("/api/v1/import/{}/preview", session.id);
+
+    // First preview: the first batch is checkpointed, then the scan fails.
+    let app = build_test_app(pool.clone()).await;
+    let response = get(app, &uri).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let cursor = ImportSessionRepo::get_scan_cursor(&pool, session.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        cursor.as_deref(),
+        Some(file_name(SCAN_CHECKPOINT_INTERVAL - 1).as_str())
+    );
+    let scanned = ImportSessionRepo::list_scan_files(&pool, session.id)
+        .await
+        .unwrap();
+    assert_eq!(scanned.len(), SCAN_CHECKPOINT_INTERVAL);
+
+    // Remove the unreadable file and every checkpointed file: a resumed
+    // scan must not need them, while a full rescan would lose them.
+    std::fs::remove_file(&broken).unwrap();
+    for index in 0..SCAN_CHECKPOINT_INTERVAL {
+        std::fs::remove_file(staging.path().join(file_name(index))).unwrap();
+    }
+
+    // Second preview: only the trailing files are walked.
+    let app = build_test_app(pool.clone()).await;
+    let response = get(app, &uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = body_json(response).await;
+    assert_eq!(json["data"]["total_files"], total);
+    assert_eq!(json["data"]["total_size_bytes"], total);
+
+    let mut sources: Vec<String> = json["data"]["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["source_path"].as_str().unwrap().to_string())
+        .collect();
+    sources.sort();
+    sources.dedup();
+    assert_eq!(sources.len(), total);
+    assert_eq!(sources.first().map(String::as_str), Some("a0000.png"));
+
+    // A completed scan clears its checkpoint.
+    let cursor = ImportSessionRepo::get_scan_cursor(&pool, session.id)
+        .await
+        .unwrap();
+    assert_eq!(cursor, None);
+}
@@ -0,0 +1,173 @@
+//! Integration tests for worker integrity reports (PRD-43).
+//!
+//! Records per-file results for a scan large enough to span several stream
+//! chunks, then verifies the NDJSON stream yields one valid file record per
+//! line followed by a summary, and that the JSON report pages its files.
+
+mod common;
+
+use axum::http::{header, StatusCode};
+use common::{body_json, build_test_app, create_test_user, get_auth, login_for_token};
+use http_body_util::BodyExt;
+use sqlx::PgPool;
+use x121_core::integrity::{
+    FILE_STATUS_CORRUPTED, FILE_STATUS_MISSING, FILE_STATUS_OK, MAX_REPORT_FILES_LIMIT,
+};
+use x121_core::types::DbId;
+use x121_db::models::integrity_scan::{CreateIntegrityScan, CreateIntegrityScanFile};
+use x121_db::models::worker::CreateWorker;
+use x121_db::repositories::{IntegrityScanRepo, WorkerRepo};
+
+const FILE_COUNT: usize = 1203;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Status for the `i`-th seeded file: every 100th is corrupted, every 10th
+/// (otherwise) missing, the rest ok.
+fn status_for(i: usize) -> &'static str {
+    if i.is_multiple_of(100) {
+        FILE_STATUS_CORRUPTED
+    } else if i.is_multiple_of(10) {
+        FILE_STATUS_MISSING
+    } else {
+        FILE_STATUS_OK
+    }
+}
+
+/// Register a worker with a completed scan holding `FILE_COUNT` file
+/// results. Returns `(worker_id, scan_id)`.
+async fn seed_scan(pool: &PgPool) -> (DbId, DbId) {
+    let worker = WorkerRepo::register(
+        pool,
+        &CreateWorker {
+            name: "scan-worker".to_string(),
+            hostname: "scan-worker.local".to_string(),
+            ip_address: None,
+            gpu_model: None,
+            gpu_count: None,
+            vram_total_mb: None,
+            tags: None,
+            comfyui_instance_id: None,
+            metadata: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let scan = IntegrityScanRepo::create(
+        pool,
+        &CreateIntegrityScan {
+            worker_id: worker.id,
+            scan_type: "models".to_string(),
+            triggered_by: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let files: Vec<CreateIntegrityScanFile> = (0..FILE_COUNT)
+        .map(|i| CreateIntegrityScanFile {
+            file_path: format!("/models/checkpoints/model_{i:04}.safetensors"),
+            model_name: Some(format!("model_{i:04}")),
+            status: status_for(i).to_string(),
+            expected_hash: Some(format!("{i:064x}")),
+            actual_hash: (status_for(i) != FILE_STATUS_MISSING).then(|| format!("{i:064x}")),
+        })
+        .collect();
+    let inserted = IntegrityScanRepo::insert_files(pool, scan.id, &files)
+        .await
+        .unwrap();
+    assert_eq!(inserted, FILE_COUNT as u64);
+
+    (worker.id, scan.id)
+}
+
+// ---------------------------------------------------------------------------
+// Test: streamed report is per-file records followed by a summary
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_streamed_report_lines_then_summary(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "scan_admin", 1).await;
+    let (worker_id, scan_id) = seed_scan(&pool).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "scan_admin", &password).await;
+
+    let response = get_auth(
+        app,
+        &format!("/api/v1/admin/integrity-scans/{worker_id}/stream"),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-ndjson"
+    );
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let text = String::from_utf8(body.to_vec()).unwrap();
+    assert!(text.ends_with('\n'));
+    let lines: Vec<serde_json::Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is valid JSON"))
+        .collect();
+    assert_eq!(lines.len(), FILE_COUNT + 1);
+
+    let (summary, files) = lines.split_last().unwrap();
+    let mut last_id = 0;
+    for (i, file) in files.iter().enumerate() {
+        assert_eq!(file["type"], "file");
+        assert_eq!(file["scan_id"], scan_id);
+        assert_eq!(file["status"], status_for(i));
+        assert_eq!(
+            file["file_path"],
+            format!("/models/checkpoints/model_{i:04}.safetensors")
+        );
+        let id = file["id"].as_i64().unwrap();
+        assert!(id > last_id, "files are streamed in order");
+        last_id = id;
+    }
+
+    assert_eq!(summary["type"], "summary");
+    assert_eq!(summary["scan_id"], scan_id);
+    assert_eq!(summary["total_files"], FILE_COUNT);
+    assert_eq!(summary["corrupted"], 13);
+    assert_eq!(summary["missing"], 108);
+    assert_eq!(summary["ok"], FILE_COUNT - 13 - 108);
+}
+
+// ---------------------------------------------------------------------------
+// Test: JSON report pages and caps its files
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_report_files_are_paged_and_capped(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "scan_admin", 1).await;
+    let (worker_id, _) = seed_scan(&pool).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "scan_admin", &password).await;
+
+    let uri = format!("/api/v1/admin/integrity-scans/{worker_id}?limit=10&offset=20");
+    let response = get_auth(app.clone(), &uri, &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let report = body_json(response).await["data"].clone();
+    assert_eq!(report["files_total"], FILE_COUNT);
+    let files = report["files"].as_array().unwrap();
+    assert_eq!(files.len(), 10);
+    assert_eq!(files[0]["model_name"], "model_0020");
+
+    let uri = format!("/api/v1/admin/integrity-scans/{worker_id}?limit=100000");
+    let response = get_auth(app.clone(), &uri, &token).await;
+    let report = body_json(response).await["data"].clone();
+    assert_eq!(
+        report["files"].as_array().unwrap().len(),
+        MAX_REPORT_FILES_LIMIT as usize
+    );
+
+    // No scan for this worker: nothing to stream.
+    let response = get_auth(app, "/api/v1/admin/integrity-scans/999999/stream", &token).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
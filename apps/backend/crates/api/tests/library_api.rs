@@ -0,0 +1,105 @@
+//! Integration tests for avatar library link integrity (PRD-60).
+//!
+//! Imports a library avatar into a project, then verifies that deleting it
+//! is refused while the project link exists, reporting the link, and that a
+//! confirmed cascading delete removes the link but keeps the project avatar.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, delete_auth, get_auth, login_for_token,
+    post_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::models::project::CreateProject;
+use x121_db::repositories::{AvatarRepo, ProjectRepo};
+
+// ---------------------------------------------------------------------------
+// Test: deleting a linked library avatar is blocked until confirmed
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_delete_linked_library_avatar_is_blocked(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "librarian", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "librarian", &password).await;
+
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Library Consumer".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/library/avatars",
+        json!({ "name": "Shared Hero", "master_metadata": { "hair": "red" } }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let library_id = body_json(response).await["data"]["id"].as_i64().unwrap();
+
+    let response = post_json_auth(
+        app.clone(),
+        &format!("/api/v1/library/avatars/{library_id}/import"),
+        json!({ "project_id": project.id }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let project_avatar_id = body_json(response).await["data"]["project_avatar_id"]
+        .as_i64()
+        .unwrap();
+
+    // A second import is rejected without creating another avatar.
+    let response = post_json_auth(
+        app.clone(),
+        &format!("/api/v1/library/avatars/{library_id}/import"),
+        json!({ "project_id": project.id }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let avatars = AvatarRepo::list_by_project(&pool, project.id)
+        .await
+        .unwrap();
+    assert_eq!(avatars.len(), 1);
+
+    // Plain delete is blocked and reports the active link.
+    let uri = format!("/api/v1/library/avatars/{library_id}");
+    let response = delete_auth(app.clone(), &uri, &token).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let message = body_json(response).await["error"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(message.contains("1 active project link(s)"), "{message}");
+    assert!(message.contains("Library Consumer"), "{message}");
+
+    let response = get_auth(app.clone(), &format!("{uri}/usage"), &token).await;
+    assert_eq!(
+        body_json(response).await["data"].as_array().unwrap().len(),
+        1
+    );
+
+    // Confirmed cascade removes the link and the library avatar only.
+    let response = delete_auth(app.clone(), &format!("{uri}?cascade=true"), &token).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = get_auth(app, &uri, &token).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(AvatarRepo::find_by_id(&pool, project_avatar_id)
+        .await
+        .unwrap()
+        .is_some());
+}
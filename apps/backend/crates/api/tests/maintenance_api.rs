@@ -0,0 +1,202 @@
+//! Integration tests for the studio-wide cache rebuild (PRD-18).
+//!
+//! Exercises the chunked rebuild directly against the repository layer and
+//! verifies the admin guard on `POST /admin/maintenance/rebuild-caches`.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{body_json, build_test_app, create_test_user, login_for_token, post_json_auth};
+use sqlx::PgPool;
+use x121_api::background::cache_rebuild::rebuild_caches;
+use x121_db::models::avatar::CreateAvatar;
+use x121_db::models::bulk_operation::CreateBulkOperation;
+use x121_db::models::project::CreateProject;
+use x121_db::models::status::{BulkOperationStatusId, BulkOperationTypeId};
+use x121_db::repositories::{AvatarRepo, BulkOperationRepo, ProjectRepo, ReadinessCacheRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+async fn create_rebuild_operation(pool: &PgPool) -> i64 {
+    BulkOperationRepo::create(
+        pool,
+        &CreateBulkOperation {
+            operation_type_id: BulkOperationTypeId::RebuildCaches.id(),
+            status_id: BulkOperationStatusId::Executing.id(),
+            parameters: serde_json::json!({}),
+            scope_project_id: None,
+            affected_entity_type: Some("avatar".to_string()),
+            affected_field: None,
+            preview_count: 0,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+// ---------------------------------------------------------------------------
+// Test: rebuild recomputes every avatar across chunks and reports the count
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_rebuild_caches_recomputes_all_avatars(pool: PgPool) {
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Rebuild Caches".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut avatar_ids = Vec::new();
+    for name in ["Alpha", "Bravo", "Charlie"] {
+        let avatar = AvatarRepo::create(
+            &pool,
+            &CreateAvatar {
+                project_id: project.id,
+                name: name.to_string(),
+                status_id: None,
+                metadata: None,
+                settings: None,
+                group_id: None,
+            },
+        )
+        .await
+        .unwrap();
+        avatar_ids.push(avatar.id);
+    }
+
+    let operation_id = create_rebuild_operation(&pool).await;
+
+    // Chunk size 2 forces the rebuild to span multiple chunks.
+    let report = rebuild_caches(&pool, operation_id, 2, |_| {})
+        .await
+        .unwrap();
+
+    assert_eq!(report.avatars_processed, 3);
+    assert!(!report.cancelled);
+
+    for avatar_id in avatar_ids {
+        let cached = ReadinessCacheRepo::find_by_avatar_id(&pool, avatar_id)
+            .await
+            .unwrap();
+        assert!(
+            cached.is_some(),
+            "avatar {avatar_id} should have a cache entry"
+        );
+    }
+
+    let op = BulkOperationRepo::find_by_id(&pool, operation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(op.affected_count, 3);
+}
+
+// ---------------------------------------------------------------------------
+// Test: a cancelled operation processes nothing
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_rebuild_caches_stops_when_cancelled(pool: PgPool) {
+    let operation_id = create_rebuild_operation(&pool).await;
+    BulkOperationRepo::update_status(&pool, operation_id, BulkOperationStatusId::Cancelled.id())
+        .await
+        .unwrap();
+
+    let report = rebuild_caches(&pool, operation_id, 2, |_| {})
+        .await
+        .unwrap();
+
+    assert!(report.cancelled);
+    assert_eq!(report.avatars_processed, 0);
+}
+
+// ---------------------------------------------------------------------------
+// Test: POST /admin/maintenance/rebuild-caches requires admin
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_rebuild_caches_requires_admin(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "rebuildcreator", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "rebuildcreator", &password).await;
+
+    let response = post_json_auth(
+        app,
+        "/api/v1/admin/maintenance/rebuild-caches",
+        serde_json::json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let json = body_json(response).await;
+    assert!(json["error"].is_string());
+}
+
+// ---------------------------------------------------------------------------
+// Test: only one cache rebuild can be executing at a time
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_second_executing_rebuild_is_rejected(pool: PgPool) {
+    create_rebuild_operation(&pool).await;
+
+    let second = BulkOperationRepo::create(
+        &pool,
+        &CreateBulkOperation {
+            operation_type_id: BulkOperationTypeId::RebuildCaches.id(),
+            status_id: BulkOperationStatusId::Executing.id(),
+            parameters: serde_json::json!({}),
+            scope_project_id: None,
+            affected_entity_type: Some("avatar".to_string()),
+            affected_field: None,
+            preview_count: 0,
+        },
+    )
+    .await;
+
+    let err = second.unwrap_err();
+    let db_err = err.as_database_error().unwrap();
+    assert_eq!(db_err.code().as_deref(), Some("23505"));
+}
+
+// ---------------------------------------------------------------------------
+// Test: completion does not overwrite a cancellation
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_finish_execution_keeps_cancelled_status(pool: PgPool) {
+    let operation_id = create_rebuild_operation(&pool).await;
+    BulkOperationRepo::update_status(&pool, operation_id, BulkOperationStatusId::Cancelled.id())
+        .await
+        .unwrap();
+
+    let finished = BulkOperationRepo::finish_execution(
+        &pool,
+        operation_id,
+        BulkOperationStatusId::Executing.id(),
+        BulkOperationStatusId::Completed.id(),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert!(finished.is_none());
+
+    let op = BulkOperationRepo::find_by_id(&pool, operation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(op.status_id, BulkOperationStatusId::Cancelled.id());
+}
@@ -0,0 +1,250 @@
+//! Integration tests for production note search (PRD-95).
+//!
+//! Verifies `GET /notes/search` combines category, resolution, author, and
+//! entity filters with relevance-ranked full-text search, returns match
+//! snippets, and paginates.
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, post_json_auth,
+    send_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+async fn category_id(pool: &PgPool, name: &str) -> i64 {
+    sqlx::query_scalar("SELECT id FROM note_categories WHERE name = $1")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+/// Create a note on `scene` `entity_id` and optionally resolve it.
+async fn create_note(
+    app: axum::Router,
+    token: &str,
+    entity_id: i64,
+    category_id: i64,
+    content: &str,
+    resolved: bool,
+) -> i64 {
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/notes",
+        json!({
+            "entity_type": "scene",
+            "entity_id": entity_id,
+            "content_md": content,
+            "category_id": category_id,
+        }),
+        token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let id = body_json(response).await["data"]["id"].as_i64().unwrap();
+
+    if resolved {
+        let response = send_json_auth(
+            app,
+            Method::PATCH,
+            &format!("/api/v1/notes/{id}/resolve"),
+            json!({}),
+            token,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    id
+}
+
+async fn search_ids(app: axum::Router, token: &str, query: &str) -> Vec<i64> {
+    let response = get_auth(app, &format!("/api/v1/notes/search?{query}"), token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    body_json(response).await["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["id"].as_i64().unwrap())
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Test: filters and full-text search combine
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_search_combines_filters_and_text(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "noteauthor", 2).await;
+    let (other, other_password) = create_test_user(&pool, "otherauthor", 2).await;
+    let blocker = category_id(&pool, "blocker").await;
+    let fyi = category_id(&pool, "fyi").await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "noteauthor", &password).await;
+    let other_token = login_for_token(app.clone(), "otherauthor", &other_password).await;
+
+    let open_blocker = create_note(
+        app.clone(),
+        &token,
+        1,
+        blocker,
+        "Lighting flickers in the final shot",
+        false,
+    )
+    .await;
+    let _resolved_blocker = create_note(
+        app.clone(),
+        &token,
+        1,
+        blocker,
+        "Lighting was too dark, fixed",
+        true,
+    )
+    .await;
+    let _fyi_note = create_note(
+        app.clone(),
+        &token,
+        1,
+        fyi,
+        "Lighting reference attached",
+        false,
+    )
+    .await;
+    let _other_entity = create_note(
+        app.clone(),
+        &token,
+        2,
+        blocker,
+        "Lighting flickers here too",
+        false,
+    )
+    .await;
+    let other_author = create_note(
+        app.clone(),
+        &other_token,
+        1,
+        blocker,
+        "Lighting flickers again",
+        false,
+    )
+    .await;
+    let _unrelated = create_note(
+        app.clone(),
+        &token,
+        1,
+        blocker,
+        "Audio drifts out of sync",
+        false,
+    )
+    .await;
+
+    let ids = search_ids(
+        app.clone(),
+        &token,
+        &format!("q=lighting&category_id={blocker}&resolved=false&entity_type=scene&entity_id=1"),
+    )
+    .await;
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![open_blocker, other_author]);
+
+    let ids = search_ids(
+        app.clone(),
+        &token,
+        &format!(
+            "q=lighting&category_id={blocker}&resolved=false&entity_type=scene&entity_id=1&user_id={}",
+            other.id
+        ),
+    )
+    .await;
+    assert_eq!(ids, vec![other_author]);
+
+    // Filters alone, without a text query, still apply.
+    let ids = search_ids(app, &token, "resolved=true").await;
+    assert_eq!(ids.len(), 1);
+}
+
+// ---------------------------------------------------------------------------
+// Test: results are ranked and carry snippets
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_search_ranks_by_relevance_with_snippets(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "noteauthor", 2).await;
+    let fyi = category_id(&pool, "fyi").await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "noteauthor", &password).await;
+
+    let weak = create_note(
+        app.clone(),
+        &token,
+        1,
+        fyi,
+        "The render queue is long today",
+        false,
+    )
+    .await;
+    let strong = create_note(
+        app.clone(),
+        &token,
+        1,
+        fyi,
+        "Render farm down: every render failed, rerun the render",
+        false,
+    )
+    .await;
+
+    let response = get_auth(app, "/api/v1/notes/search?q=render", &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let data = body_json(response).await["data"]
+        .as_array()
+        .unwrap()
+        .clone();
+
+    let ids: Vec<i64> = data.iter().map(|n| n["id"].as_i64().unwrap()).collect();
+    assert_eq!(ids, vec![strong, weak]);
+    assert!(data[0]["rank"].as_f64().unwrap() > data[1]["rank"].as_f64().unwrap());
+    assert!(data[0]["snippet"].as_str().unwrap().contains("<b>"));
+}
+
+// ---------------------------------------------------------------------------
+// Test: pagination and validation
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_search_paginates_and_validates(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "noteauthor", 2).await;
+    let fyi = category_id(&pool, "fyi").await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "noteauthor", &password).await;
+
+    for i in 0..3 {
+        create_note(
+            app.clone(),
+            &token,
+            1,
+            fyi,
+            &format!("Checklist item {i}"),
+            false,
+        )
+        .await;
+    }
+
+    let first = search_ids(app.clone(), &token, "q=checklist&limit=2").await;
+    let second = search_ids(app.clone(), &token, "q=checklist&limit=2&offset=2").await;
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 1);
+    assert!(!first.contains(&second[0]));
+
+    let response = get_auth(app.clone(), "/api/v1/notes/search?entity_id=1", &token).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = get_auth(app, "/api/v1/notes/search?entity_type=bogus", &token).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,159 @@
+//! Integration tests for notification preferences and bulk reads (PRD-10).
+//!
+//! Verifies that concurrent `PUT /notifications/preferences/{event_type_id}`
+//! requests for the same preference all succeed and converge on a single,
+//! consistent row, and that `POST /notifications/read` only marks the
+//! notifications matching its filter.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, login_for_token, post_json_auth, put_json_auth,
+};
+use sqlx::PgPool;
+use tokio::task::JoinSet;
+use x121_db::models::notification::UpdatePreference;
+use x121_db::repositories::{EventRepo, NotificationPreferenceRepo, NotificationRepo};
+
+/// `job.completed` is seeded by the events migration.
+const EVENT_TYPE_ID: i64 = 4;
+
+// ---------------------------------------------------------------------------
+// Test: simultaneous PUTs for the same preference never error
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_concurrent_preference_updates_converge(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "pref_racer", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "pref_racer", &password).await;
+
+    let uri = format!("/api/v1/notifications/preferences/{EVENT_TYPE_ID}");
+    let mut tasks = JoinSet::new();
+    for i in 0..16 {
+        let app = app.clone();
+        let uri = uri.clone();
+        let token = token.clone();
+        tasks.spawn(async move {
+            let body = serde_json::json!({
+                "is_enabled": i % 2 == 0,
+                "channels": ["in_app", "email"],
+            });
+            let response = put_json_auth(app, &uri, body, &token).await;
+            (response.status(), body_json(response).await)
+        });
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        let (status, json) = result.unwrap();
+        assert_eq!(status, StatusCode::OK, "unexpected response: {json}");
+        assert_eq!(json["data"]["event_type_id"], EVENT_TYPE_ID);
+    }
+
+    let prefs = NotificationPreferenceRepo::list_for_user(&pool, user.id)
+        .await
+        .unwrap();
+    assert_eq!(prefs.len(), 1, "concurrent upserts must not duplicate rows");
+    assert_eq!(prefs[0].channels, serde_json::json!(["in_app", "email"]));
+    assert_eq!(prefs[0].scope, "all");
+}
+
+// ---------------------------------------------------------------------------
+// Test: concurrent partial updates each keep their own field
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_concurrent_partial_updates_do_not_clobber(pool: PgPool) {
+    let (user, _password) = create_test_user(&pool, "pref_partial", 1).await;
+
+    let disable = UpdatePreference {
+        is_enabled: Some(false),
+        channels: None,
+        scope: None,
+    };
+    let rescope = UpdatePreference {
+        is_enabled: None,
+        channels: None,
+        scope: Some("own".to_string()),
+    };
+
+    let (a, b) = tokio::join!(
+        NotificationPreferenceRepo::upsert(&pool, user.id, EVENT_TYPE_ID, &disable),
+        NotificationPreferenceRepo::upsert(&pool, user.id, EVENT_TYPE_ID, &rescope),
+    );
+    a.unwrap();
+    b.unwrap();
+
+    let pref = NotificationPreferenceRepo::get_for_event_type(&pool, user.id, EVENT_TYPE_ID)
+        .await
+        .unwrap()
+        .expect("preference should exist");
+    assert!(!pref.is_enabled);
+    assert_eq!(pref.scope, "own");
+    assert_eq!(pref.channels, serde_json::json!(["in_app"]));
+}
+
+// ---------------------------------------------------------------------------
+// Test: bulk mark-read by project leaves other notifications unread
+// ---------------------------------------------------------------------------
+
+/// Record a `job.completed` event for `project_id` and notify `user_id`.
+async fn notify(pool: &PgPool, user_id: i64, project_id: i64) -> i64 {
+    let payload = serde_json::json!({ "project_id": project_id });
+    let event_id = EventRepo::insert(pool, EVENT_TYPE_ID, Some("job"), None, None, &payload)
+        .await
+        .unwrap();
+    NotificationRepo::create(pool, event_id, user_id, "in_app")
+        .await
+        .unwrap()
+        .expect("first notification for the event")
+}
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_mark_read_by_project_leaves_others_unread(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "bulk_reader", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "bulk_reader", &password).await;
+
+    let project_a = [
+        notify(&pool, user.id, 10).await,
+        notify(&pool, user.id, 10).await,
+    ];
+    let project_b = notify(&pool, user.id, 20).await;
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/notifications/read",
+        serde_json::json!({ "project_id": 10 }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await["data"]["marked_read"], 2);
+
+    let unread: Vec<i64> = NotificationRepo::list_for_user(&pool, user.id, true, 50, 0)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|n| n.id)
+        .collect();
+    assert_eq!(unread, vec![project_b]);
+    assert!(project_a.iter().all(|id| !unread.contains(id)));
+
+    // A filter matching nothing marks nothing.
+    let response = post_json_auth(
+        app,
+        "/api/v1/notifications/read",
+        serde_json::json!({ "project_id": 20, "event_type": "job.failed" }),
+        &token,
+    )
+    .await;
+    assert_eq!(body_json(response).await["data"]["marked_read"], 0);
+    assert_eq!(
+        NotificationRepo::unread_count(&pool, user.id)
+            .await
+            .unwrap(),
+        1
+    );
+}
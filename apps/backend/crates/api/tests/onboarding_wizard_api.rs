@@ -0,0 +1,247 @@
+//! Integration tests for onboarding session history and status (PRD-67).
+//!
+//! Drives a wizard session through step-data updates, advances, and a
+//! go-back, then verifies `GET /onboarding-sessions/{id}/history` returns
+//! exactly that sequence in order, with pagination. Also checks that the
+//! `complete` and `abandon` transitions are idempotent and reject moving
+//! between terminal statuses.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, post_json_auth,
+    put_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_core::onboarding_wizard::OnboardingEventType;
+use x121_db::models::project::CreateProject;
+use x121_db::repositories::{OnboardingSessionRepo, ProjectRepo};
+
+// ---------------------------------------------------------------------------
+// Test: history reflects the exact sequence of transitions
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_history_records_transitions_in_order(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "wizard_user", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "wizard_user", &password).await;
+
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Onboarding History".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/onboarding-sessions",
+        json!({ "project_id": project.id }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let session_id = body_json(response).await["data"]["id"].as_i64().unwrap();
+    let base = format!("/api/v1/onboarding-sessions/{session_id}");
+
+    let step1 = json!({ "files": ["alice.png"] });
+    let step2 = json!({ "files": ["alice.png"], "variant_jobs": [] });
+
+    let steps = [
+        ("step-data", Some(step1.clone())),
+        ("advance", None),
+        ("step-data", Some(step2.clone())),
+        ("advance", None),
+        ("go-back", None),
+    ];
+    for (action, body) in steps {
+        let uri = format!("{base}/{action}");
+        let response = match body {
+            Some(body) => put_json_auth(app.clone(), &uri, body, &token).await,
+            None => post_json_auth(app.clone(), &uri, json!({}), &token).await,
+        };
+        assert_eq!(response.status(), StatusCode::OK, "{action} failed");
+    }
+
+    let response = get_auth(app.clone(), &format!("{base}/history"), &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let page = body_json(response).await["data"].clone();
+    assert_eq!(page["total"], 5);
+
+    let sequence: Vec<(String, i64, i64)> = page["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["event_type"].as_str().unwrap().to_string(),
+                e["from_step"].as_i64().unwrap(),
+                e["to_step"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sequence,
+        vec![
+            ("step_data".to_string(), 1, 1),
+            ("advance".to_string(), 1, 2),
+            ("step_data".to_string(), 2, 2),
+            ("advance".to_string(), 2, 3),
+            ("go_back".to_string(), 3, 2),
+        ]
+    );
+
+    let items = page["items"].as_array().unwrap();
+    assert_eq!(items[0]["step_data"], step1);
+    assert_eq!(items[2]["step_data"], step2);
+    assert!(items[1]["step_data"].is_null());
+    assert!(items.iter().all(|e| e["actor_id"] == user.id));
+    assert!(items.iter().all(|e| e["created_at"].is_string()));
+
+    // Pagination keeps the same ordering.
+    let response = get_auth(app, &format!("{base}/history?limit=2&offset=3"), &token).await;
+    let page = body_json(response).await["data"].clone();
+    assert_eq!(page["total"], 5);
+    let types: Vec<&str> = page["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect();
+    assert_eq!(types, vec!["advance", "go_back"]);
+}
+
+// ---------------------------------------------------------------------------
+// Test: rejected transitions are not recorded
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_rejected_transition_leaves_history_empty(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "wizard_blocked", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "wizard_blocked", &password).await;
+
+    let project = ProjectRepo::create(
+        &pool,
+        &CreateProject {
+            name: "Onboarding Rejected".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/onboarding-sessions",
+        json!({ "project_id": project.id }),
+        &token,
+    )
+    .await;
+    let session_id = body_json(response).await["data"]["id"].as_i64().unwrap();
+    let base = format!("/api/v1/onboarding-sessions/{session_id}");
+
+    // Step 1 has no uploads yet, and there is no step before it.
+    for action in ["advance", "go-back"] {
+        let response =
+            post_json_auth(app.clone(), &format!("{base}/{action}"), json!({}), &token).await;
+        assert_ne!(response.status(), StatusCode::OK, "{action} should fail");
+    }
+
+    let response = get_auth(app, &format!("{base}/history"), &token).await;
+    let page = body_json(response).await["data"].clone();
+    assert_eq!(page["total"], 0);
+    assert_eq!(page["items"], json!([]));
+}
+
+// ---------------------------------------------------------------------------
+// Status transitions
+// ---------------------------------------------------------------------------
+
+/// Create a project and start an onboarding session in it, returning the
+/// session's base URI and id.
+async fn start_session(app: axum::Router, pool: &PgPool, token: &str) -> (String, i64) {
+    let project = ProjectRepo::create(
+        pool,
+        &CreateProject {
+            name: "Onboarding Status".to_string(),
+            description: None,
+            status_id: None,
+            retention_days: None,
+            pipeline_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let response = post_json_auth(
+        app,
+        "/api/v1/onboarding-sessions",
+        json!({ "project_id": project.id }),
+        token,
+    )
+    .await;
+    let session_id = body_json(response).await["data"]["id"].as_i64().unwrap();
+    (
+        format!("/api/v1/onboarding-sessions/{session_id}"),
+        session_id,
+    )
+}
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_complete_twice_is_idempotent(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "wizard_finisher", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "wizard_finisher", &password).await;
+    let (base, session_id) = start_session(app.clone(), &pool, &token).await;
+
+    // Jump straight to the summary step; completion is only allowed there.
+    OnboardingSessionRepo::update_step(&pool, session_id, 6, OnboardingEventType::Advance, user.id)
+        .await
+        .unwrap();
+
+    let mut completed = Vec::new();
+    for _ in 0..2 {
+        let response =
+            post_json_auth(app.clone(), &format!("{base}/complete"), json!({}), &token).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        completed.push(body_json(response).await["data"].clone());
+    }
+    assert_eq!(completed[0]["status"], "completed");
+    assert_eq!(completed[0], completed[1]);
+}
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_complete_abandoned_session_is_rejected(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "wizard_quitter", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "wizard_quitter", &password).await;
+    let (base, _) = start_session(app.clone(), &pool, &token).await;
+
+    for _ in 0..2 {
+        let response =
+            post_json_auth(app.clone(), &format!("{base}/abandon"), json!({}), &token).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["data"]["status"], "abandoned");
+    }
+
+    let response =
+        post_json_auth(app.clone(), &format!("{base}/complete"), json!({}), &token).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(body_json(response).await["code"], "CONFLICT");
+
+    let response = get_auth(app, &base, &token).await;
+    assert_eq!(body_json(response).await["data"]["status"], "abandoned");
+}
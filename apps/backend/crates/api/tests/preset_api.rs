@@ -0,0 +1,148 @@
+//! Integration tests for applying presets to scene types (PRD-27).
+//!
+//! Verifies `POST /presets/{id}/apply/{scene_type_id}` validates the
+//! resulting config before writing: a preset that would produce invalid
+//! `generation_params` is rejected and leaves both the scene type and the
+//! preset's usage counter untouched, while a valid preset returns the diff it
+//! applied.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, login_for_token, post_json_auth, seed_scenes,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::models::preset::CreatePreset;
+use x121_db::repositories::{PresetRepo, SceneTypeRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create a scene type whose inline workflow exposes `3.seed` and `3.cfg`,
+/// with `3.cfg` already overridden.
+async fn setup_scene_type(pool: &PgPool) -> i64 {
+    seed_scenes(
+        pool,
+        "preset",
+        1,
+        json!({
+            "workflow_json": {
+                "3": {
+                    "class_type": "KSampler",
+                    "inputs": { "seed": 42, "cfg": 7.0 }
+                }
+            },
+            "generation_params": { "3.cfg": 6.5 },
+        }),
+    )
+    .await
+    .scene_type_ids[0]
+}
+
+/// Create a preset owned by `owner_id` with the given parameters.
+async fn create_preset(pool: &PgPool, owner_id: i64, parameters: serde_json::Value) -> i64 {
+    PresetRepo::create(
+        pool,
+        owner_id,
+        &CreatePreset {
+            name: "Test Preset".to_string(),
+            description: None,
+            scope: None,
+            project_id: None,
+            parameters,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+// ---------------------------------------------------------------------------
+// Test: a preset producing an invalid config is rejected without changes
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_apply_invalid_preset_is_rejected_atomically(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "presetter", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "presetter", &password).await;
+
+    let scene_type_id = setup_scene_type(&pool).await;
+    // `3.seed` is valid, but `99.steps` targets a node the workflow lacks.
+    let preset_id = create_preset(&pool, user.id, json!({ "3.seed": 7, "99.steps": 30 })).await;
+
+    let response = post_json_auth(
+        app,
+        &format!("/api/v1/presets/{preset_id}/apply/{scene_type_id}"),
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Neither the valid nor the invalid field was written.
+    let scene_type = SceneTypeRepo::find_by_id(&pool, scene_type_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(scene_type.generation_params, Some(json!({ "3.cfg": 6.5 })));
+
+    let preset = PresetRepo::find_by_id(&pool, preset_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(preset.usage_count, 0);
+}
+
+// ---------------------------------------------------------------------------
+// Test: a valid preset is applied and the diff is returned
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_apply_valid_preset_returns_applied_diff(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "presetter", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "presetter", &password).await;
+
+    let scene_type_id = setup_scene_type(&pool).await;
+    let preset_id = create_preset(&pool, user.id, json!({ "3.seed": 7, "3.cfg": 6.5 })).await;
+
+    let response = post_json_auth(
+        app,
+        &format!("/api/v1/presets/{preset_id}/apply/{scene_type_id}"),
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = body_json(response).await;
+    let data = &body["data"];
+
+    // Only the field that actually differed is reported.
+    assert_eq!(
+        data["applied"],
+        json!([{ "field": "3.seed", "current_value": null, "preset_value": 7 }])
+    );
+    assert_eq!(
+        data["generation_params"],
+        json!({ "3.cfg": 6.5, "3.seed": 7 })
+    );
+
+    let scene_type = SceneTypeRepo::find_by_id(&pool, scene_type_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        scene_type.generation_params,
+        Some(json!({ "3.cfg": 6.5, "3.seed": 7 }))
+    );
+
+    let preset = PresetRepo::find_by_id(&pool, preset_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(preset.usage_count, 1);
+}
@@ -0,0 +1,149 @@
+//! Integration tests for admin queue reordering (PRD-08).
+//!
+//! Verifies `PUT /admin/queue/reorder` moves a job to a position validated
+//! against the caller's queue snapshot, returns the new order, and that two
+//! concurrent reorders from the same snapshot cannot both apply.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, put_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::models::job::SubmitJob;
+use x121_db::repositories::JobRepo;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+async fn submit(pool: &PgPool, user_id: i64, priority: i32) -> i64 {
+    let input = SubmitJob {
+        job_type: "render".to_string(),
+        parameters: json!({}),
+        priority: Some(priority),
+        estimated_duration_secs: None,
+        scheduled_start_at: None,
+        is_off_peak_only: false,
+    };
+    JobRepo::submit(pool, user_id, &input).await.unwrap().id
+}
+
+fn job_ids(jobs: &serde_json::Value) -> Vec<i64> {
+    jobs.as_array()
+        .unwrap()
+        .iter()
+        .map(|j| j["id"].as_i64().unwrap())
+        .collect()
+}
+
+async fn queue_order(app: axum::Router, token: &str) -> Vec<i64> {
+    let response = get_auth(app, "/api/v1/queue", token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    job_ids(&body_json(response).await["data"]["jobs"])
+}
+
+async fn reorder(
+    app: axum::Router,
+    token: &str,
+    job_id: i64,
+    position: usize,
+    expected_order: &[i64],
+) -> axum::response::Response {
+    put_json_auth(
+        app,
+        "/api/v1/admin/queue/reorder",
+        json!({
+            "job_id": job_id,
+            "position": position,
+            "expected_order": expected_order,
+        }),
+        token,
+    )
+    .await
+}
+
+// ---------------------------------------------------------------------------
+// Test: reorder moves a job and returns the new order
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_reorder_moves_job_and_returns_order(pool: PgPool) {
+    let (admin, password) = create_test_user(&pool, "queue_admin", 1).await;
+    let urgent = submit(&pool, admin.id, 10).await;
+    let a = submit(&pool, admin.id, 0).await;
+    let b = submit(&pool, admin.id, 0).await;
+    let c = submit(&pool, admin.id, 0).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "queue_admin", &password).await;
+
+    let order = queue_order(app.clone(), &token).await;
+    assert_eq!(order, vec![urgent, a, b, c]);
+
+    // Within a priority band.
+    let response = reorder(app.clone(), &token, c, 1, &order).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let order = job_ids(&body_json(response).await["data"]);
+    assert_eq!(order, vec![urgent, c, a, b]);
+    assert_eq!(queue_order(app.clone(), &token).await, order);
+
+    // Ahead of a higher-priority job, and to the end of the queue.
+    let response = reorder(app.clone(), &token, b, 0, &order).await;
+    let order = job_ids(&body_json(response).await["data"]);
+    assert_eq!(order, vec![b, urgent, c, a]);
+    let response = reorder(app.clone(), &token, urgent, 3, &order).await;
+    let order = job_ids(&body_json(response).await["data"]);
+    assert_eq!(order, vec![b, c, a, urgent]);
+
+    // A stale snapshot is rejected and changes nothing.
+    let response = reorder(app.clone(), &token, a, 0, &[urgent, a, b, c]).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(queue_order(app.clone(), &token).await, order);
+
+    // Position past the end of the queue.
+    let response = reorder(app, &token, a, 4, &order).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// ---------------------------------------------------------------------------
+// Test: concurrent reorders do not lose updates
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_concurrent_reorders_keep_consistent_order(pool: PgPool) {
+    let (admin, password) = create_test_user(&pool, "queue_admin", 1).await;
+    let a = submit(&pool, admin.id, 0).await;
+    let b = submit(&pool, admin.id, 0).await;
+    let c = submit(&pool, admin.id, 0).await;
+    let d = submit(&pool, admin.id, 0).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "queue_admin", &password).await;
+
+    let snapshot = queue_order(app.clone(), &token).await;
+    assert_eq!(snapshot, vec![a, b, c, d]);
+
+    // Both reorders are based on the same snapshot: only one may apply.
+    let (first, second) = tokio::join!(
+        reorder(app.clone(), &token, d, 0, &snapshot),
+        reorder(app.clone(), &token, a, 3, &snapshot),
+    );
+    let mut statuses = [first.status(), second.status()];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::CONFLICT]);
+
+    let (winner, loser_move) = if first.status() == StatusCode::OK {
+        (first, (a, 3))
+    } else {
+        (second, (d, 0))
+    };
+    let applied = job_ids(&body_json(winner).await["data"]);
+    let current = queue_order(app.clone(), &token).await;
+    assert_eq!(current, applied);
+
+    // Retrying the rejected move against the fresh snapshot applies both.
+    let response = reorder(app.clone(), &token, loser_move.0, loser_move.1, &current).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(queue_order(app, &token).await, vec![d, b, c, a]);
+}
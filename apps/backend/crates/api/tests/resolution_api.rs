@@ -0,0 +1,120 @@
+//! Integration tests for batch scene upscaling (PRD-59).
+//!
+//! Seeds scenes at different resolution tiers and verifies
+//! `POST /scenes/batch-upscale` plans each scene on its own: scenes that can
+//! step up to the target tier are upscaled and reported as accepted, while a
+//! scene already at the target (or missing) is rejected with a reason.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, login_for_token, post_json_auth, seed_scenes,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_core::resolution::{
+    MAX_BATCH_UPSCALE_SCENES, TIER_ID_DRAFT, TIER_ID_PREVIEW, TIER_ID_PRODUCTION,
+};
+use x121_db::repositories::{ResolutionTierRepo, SceneRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Current resolution tier of a scene (draft when unset).
+async fn scene_tier(pool: &PgPool, scene_id: i64) -> i64 {
+    SceneRepo::find_by_id(pool, scene_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .resolution_tier_id
+        .unwrap_or(TIER_ID_DRAFT)
+}
+
+// ---------------------------------------------------------------------------
+// Test: scenes already at the target are rejected, the rest are upscaled
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_batch_upscale_splits_accepted_and_rejected(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "upscaler", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "upscaler", &password).await;
+
+    let scenes = seed_scenes(&pool, "upscale", 3, json!({})).await.scene_ids;
+    let (draft, preview, production) = (scenes[0], scenes[1], scenes[2]);
+    ResolutionTierRepo::update_scene_tier(&pool, preview, TIER_ID_PREVIEW)
+        .await
+        .unwrap();
+    ResolutionTierRepo::update_scene_tier(&pool, production, TIER_ID_PRODUCTION)
+        .await
+        .unwrap();
+    let missing = production + 1000;
+
+    let response = post_json_auth(
+        app,
+        "/api/v1/scenes/batch-upscale",
+        json!({
+            "scene_ids": [draft, production, preview, missing, draft],
+            "target_tier_id": TIER_ID_PRODUCTION,
+        }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = body_json(response).await;
+    let data = &body["data"];
+
+    assert_eq!(data["target_tier"], "production");
+    assert_eq!(data["accepted"], 2);
+    assert_eq!(data["rejected"], 2);
+
+    // Duplicates are dropped; results keep request order.
+    let results = data["results"].as_array().unwrap();
+    let ids: Vec<i64> = results
+        .iter()
+        .map(|r| r["scene_id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(ids, vec![draft, production, preview, missing]);
+
+    assert_eq!(results[0]["outcome"], "accepted");
+    assert_eq!(results[0]["plan"]["steps"].as_array().unwrap().len(), 2);
+    assert!(results[0]["reason"].is_null());
+
+    assert_eq!(results[1]["outcome"], "rejected");
+    assert_eq!(results[1]["reason"], "Scene is already at the target tier");
+    assert!(results[1]["plan"].is_null());
+
+    assert_eq!(results[2]["outcome"], "accepted");
+    assert_eq!(results[2]["plan"]["steps"].as_array().unwrap().len(), 1);
+
+    assert_eq!(results[3]["outcome"], "rejected");
+    assert_eq!(results[3]["reason"], "Scene not found");
+
+    // Only accepted scenes moved tiers.
+    for scene_id in [draft, preview, production] {
+        assert_eq!(scene_tier(&pool, scene_id).await, TIER_ID_PRODUCTION);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test: oversized batches are rejected up front
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_batch_upscale_enforces_max_batch_size(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "upscaler", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "upscaler", &password).await;
+
+    let scene_ids: Vec<i64> = (1..=MAX_BATCH_UPSCALE_SCENES as i64 + 1).collect();
+    let response = post_json_auth(
+        app,
+        "/api/v1/scenes/batch-upscale",
+        json!({ "scene_ids": scene_ids, "target_tier_id": TIER_ID_PRODUCTION }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,78 @@
+//! HTTP-level integration tests for scene type slug collision handling.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{body_json, post_json, put_json};
+use sqlx::PgPool;
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_create_duplicate_slug_returns_conflict_with_suggestion(pool: PgPool) {
+    let app = common::build_test_app(pool.clone()).await;
+    let response = post_json(
+        app,
+        "/api/v1/scene-types",
+        serde_json::json!({"name": "Slug Probe", "slug": "slug_probe"}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let app = common::build_test_app(pool).await;
+    let response = post_json(
+        app,
+        "/api/v1/scene-types",
+        serde_json::json!({"name": "Slug Probe Again", "slug": "slug_probe"}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let json = body_json(response).await;
+    assert_eq!(json["code"], "CONFLICT");
+    assert!(json["error"].as_str().unwrap().contains("'slug_probe_2'"));
+}
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_update_to_taken_slug_returns_conflict(pool: PgPool) {
+    for slug in ["slug_a", "slug_b"] {
+        let app = common::build_test_app(pool.clone()).await;
+        post_json(
+            app,
+            "/api/v1/scene-types",
+            serde_json::json!({"name": slug, "slug": slug}),
+        )
+        .await;
+    }
+
+    let app = common::build_test_app(pool.clone()).await;
+    let created = body_json(
+        post_json(
+            app,
+            "/api/v1/scene-types",
+            serde_json::json!({"name": "Slug C", "slug": "slug_c"}),
+        )
+        .await,
+    )
+    .await;
+    let id = created["data"]["id"].as_i64().unwrap();
+
+    // Keeping its own slug is not a conflict.
+    let app = common::build_test_app(pool.clone()).await;
+    let response = put_json(
+        app,
+        &format!("/api/v1/scene-types/{id}"),
+        serde_json::json!({"slug": "slug_c"}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let app = common::build_test_app(pool).await;
+    let response = put_json(
+        app,
+        &format!("/api/v1/scene-types/{id}"),
+        serde_json::json!({"slug": "slug_a"}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let json = body_json(response).await;
+    assert!(json["error"].as_str().unwrap().contains("'slug_a_2'"));
+}
@@ -10,7 +10,7 @@ use axum::http::StatusCode;
 use common::{body_json, build_test_app, delete, get, put_json};
 use sqlx::PgPool;
 use x121_db::models::avatar::CreateAvatar;
-use x121_db::models::image::CreateMediaVariant;
+use x121_db::models::media::CreateMediaVariant;
 use x121_db::models::project::CreateProject;
 use x121_db::models::scene::CreateScene;
 use x121_db::models::scene_type::CreateSceneType;
@@ -32,6 +32,7 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
             description: None,
             status_id: None,
             retention_days: None,
+            pipeline_id: 1,
         },
     )
     .await
@@ -44,6 +45,7 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
             status_id: None,
             metadata: None,
             settings: None,
+            group_id: None,
         },
     )
     .await
@@ -53,6 +55,7 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
         &CreateSceneType {
             project_id: Some(project.id),
             name: format!("ST_{suffix}"),
+            slug: format!("st-{suffix}"),
             status_id: None,
             workflow_json: None,
             lora_config: None,
@@ -72,6 +75,19 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
             sort_order: None,
             is_active: None,
             is_studio_level: None,
+            workflow_id: None,
+            has_clothes_off_transition: None,
+            parent_scene_type_id: None,
+            generation_strategy: None,
+            expected_chunks: None,
+            chunk_output_pattern: None,
+            auto_retry_enabled: None,
+            auto_retry_max_attempts: None,
+            auto_retry_trigger_checks: None,
+            auto_retry_seed_variation: None,
+            auto_retry_cfg_jitter: None,
+            target_fps: None,
+            target_resolution: None,
         },
     )
     .await
@@ -95,6 +111,7 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
             version: None,
             parent_variant_id: None,
             generation_params: None,
+            content_hash: None,
         },
     )
     .await
@@ -104,9 +121,16 @@ async fn setup_scene(pool: &PgPool, suffix: &str) -> i64 {
         &CreateScene {
             avatar_id: avatar.id,
             scene_type_id: scene_type.id,
-            media_variant_id: variant.id,
+            media_variant_id: Some(variant.id),
             status_id: None,
             transition_mode: None,
+            track_id: None,
+            total_segments_estimated: None,
+            total_segments_completed: None,
+            actual_duration_secs: None,
+            transition_segment_index: None,
+            generation_started_at: None,
+            generation_completed_at: None,
         },
     )
     .await
@@ -125,6 +149,8 @@ fn new_version(scene_id: i64) -> CreateSceneVideoVersion {
         notes: None,
         generation_snapshot: None,
         content_hash: None,
+        parent_version_id: None,
+        clip_index: None,
     }
 }
 
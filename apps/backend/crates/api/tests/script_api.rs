@@ -1,13 +1,16 @@
 //! Integration tests for the script orchestrator API (PRD-09, Phase 6).
 //!
 //! Tests cover script registration, listing, retrieval, deactivation,
-//! test execution, and execution history via the admin API endpoints.
+//! test execution, and execution history (with outcome filtering and
+//! pagination) via the admin API endpoints.
 
 mod common;
 
 use axum::http::StatusCode;
 use common::{body_json, delete_auth, get_auth, post_json_auth};
 use sqlx::PgPool;
+use x121_db::models::script::{CreateScript, CreateScriptExecution};
+use x121_db::repositories::{ScriptExecutionRepo, ScriptRepo};
 
 // ---------------------------------------------------------------------------
 // Test 1: Register a shell script via POST /admin/scripts
@@ -285,7 +288,10 @@ async fn execution_history_contains_run(pool: PgPool) {
 
     assert_eq!(history_resp.status(), StatusCode::OK);
     let json = body_json(history_resp).await;
-    let executions = json["data"].as_array().expect("data should be an array");
+    assert_eq!(json["data"]["total"], 1);
+    let executions = json["data"]["items"]
+        .as_array()
+        .expect("items should be an array");
     assert!(
         !executions.is_empty(),
         "execution history should contain at least one record"
@@ -294,6 +300,8 @@ async fn execution_history_contains_run(pool: PgPool) {
     assert_eq!(executions[0]["script_id"], script_id);
     // It should be completed (status_name = "completed").
     assert_eq!(executions[0]["status_name"], "completed");
+    assert_eq!(executions[0]["exit_code"], 0);
+    assert!(executions[0]["duration_ms"].is_number());
 }
 
 // ---------------------------------------------------------------------------
@@ -366,3 +374,135 @@ async fn get_nonexistent_script_returns_404(pool: PgPool) {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+// ---------------------------------------------------------------------------
+// Test 11: Execution history status filter combined with pagination
+// ---------------------------------------------------------------------------
+
+/// Filtering history by outcome narrows the total, and limit/offset page
+/// through the filtered rows only.
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn execution_history_filters_by_status_with_pagination(pool: PgPool) {
+    let (admin, password) = common::create_test_user(&pool, "script_admin_hist", 1).await;
+
+    let script = ScriptRepo::create(
+        &pool,
+        &CreateScript {
+            name: "flaky_script".to_string(),
+            description: None,
+            script_type_id: 1,
+            file_path: "/tmp/flaky.sh".to_string(),
+            working_directory: None,
+            requirements_path: None,
+            requirements_hash: None,
+            venv_path: None,
+            argument_schema: None,
+            output_schema: None,
+            timeout_secs: Some(10),
+            version: None,
+            created_by: Some(admin.id),
+        },
+    )
+    .await
+    .expect("create script");
+
+    // Three successes, two non-zero exits, one hard failure, one timeout,
+    // one cancellation.
+    let new_execution = CreateScriptExecution {
+        script_id: script.id,
+        job_id: None,
+        triggered_by: Some(admin.id),
+        input_data: None,
+    };
+    for exit_code in [0, 1, 0, 2, 0] {
+        let exec = ScriptExecutionRepo::create(&pool, &new_execution)
+            .await
+            .expect("create execution");
+        ScriptExecutionRepo::complete(&pool, exec.id, exit_code, "", "", 120, None)
+            .await
+            .expect("complete execution");
+    }
+    let exec = ScriptExecutionRepo::create(&pool, &new_execution)
+        .await
+        .expect("create execution");
+    ScriptExecutionRepo::fail(&pool, exec.id, "spawn failed")
+        .await
+        .expect("fail execution");
+    let exec = ScriptExecutionRepo::create(&pool, &new_execution)
+        .await
+        .expect("create execution");
+    ScriptExecutionRepo::timeout(&pool, exec.id, 10_000)
+        .await
+        .expect("time out execution");
+    let exec = ScriptExecutionRepo::create(&pool, &new_execution)
+        .await
+        .expect("create execution");
+    ScriptExecutionRepo::cancel(&pool, exec.id)
+        .await
+        .expect("cancel execution");
+
+    let app = common::build_test_app(pool.clone()).await;
+    let token = common::login_for_token(app, "script_admin_hist", &password).await;
+    let base = format!("/api/v1/admin/scripts/{}/executions", script.id);
+
+    // Failures: the two non-zero exits plus the hard failure, paged by two.
+    let app = common::build_test_app(pool.clone()).await;
+    let resp = get_auth(app, &format!("{base}?status=failure&limit=2"), &token).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp).await;
+    assert_eq!(json["data"]["total"], 3);
+    let first_page = json["data"]["items"].as_array().expect("items array");
+    assert_eq!(first_page.len(), 2);
+
+    let app = common::build_test_app(pool.clone()).await;
+    let resp = get_auth(
+        app,
+        &format!("{base}?status=failure&limit=2&offset=2"),
+        &token,
+    )
+    .await;
+    let json = body_json(resp).await;
+    assert_eq!(json["data"]["total"], 3);
+    let second_page = json["data"]["items"].as_array().expect("items array");
+    assert_eq!(second_page.len(), 1);
+
+    let mut seen: Vec<i64> = first_page
+        .iter()
+        .chain(second_page)
+        .map(|row| {
+            assert_ne!(row["exit_code"], 0, "successes must be filtered out");
+            row["id"].as_i64().expect("id")
+        })
+        .collect();
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen.len(), 3, "pages must not overlap");
+
+    // Successes carry exit code and duration.
+    let app = common::build_test_app(pool.clone()).await;
+    let resp = get_auth(app, &format!("{base}?status=success"), &token).await;
+    let json = body_json(resp).await;
+    assert_eq!(json["data"]["total"], 3);
+    for row in json["data"]["items"].as_array().expect("items array") {
+        assert_eq!(row["exit_code"], 0);
+        assert_eq!(row["duration_ms"], 120);
+    }
+
+    for (status, expected) in [("timeout", 1), ("cancelled", 1)] {
+        let app = common::build_test_app(pool.clone()).await;
+        let resp = get_auth(app, &format!("{base}?status={status}"), &token).await;
+        let json = body_json(resp).await;
+        assert_eq!(json["data"]["total"], expected, "status={status}");
+    }
+
+    // A time range in the future excludes everything.
+    let app = common::build_test_app(pool.clone()).await;
+    let resp = get_auth(app, &format!("{base}?from=2999-01-01T00:00:00Z"), &token).await;
+    let json = body_json(resp).await;
+    assert_eq!(json["data"]["total"], 0);
+
+    // Unknown status values are rejected.
+    let app = common::build_test_app(pool).await;
+    let resp = get_auth(app, &format!("{base}?status=completed"), &token).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
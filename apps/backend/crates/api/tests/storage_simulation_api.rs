@@ -0,0 +1,131 @@
+//! Integration tests for tiering policy simulation (PRD-48).
+//!
+//! Seeds assets across hot and cold backends, simulates a proposed
+//! hot-to-cold policy, and checks the reported relocations and totals.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{body_json, build_test_app, create_test_user, login_for_token, post_json_auth};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_core::types::DbId;
+use x121_db::models::storage::{CreateAssetLocation, CreateStorageBackend};
+use x121_db::repositories::{AssetLocationRepo, StorageBackendRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+async fn create_backend(pool: &PgPool, name: &str, tier: &str) -> DbId {
+    StorageBackendRepo::create(
+        pool,
+        &CreateStorageBackend {
+            name: name.to_string(),
+            backend_type_id: 1,
+            tier: Some(tier.to_string()),
+            config: json!({ "base_path": format!("/tmp/{name}") }),
+            is_default: Some(false),
+            total_capacity_bytes: None,
+            project_id: None,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+/// Store an asset on `backend_id`, backdated to `age_days` old.
+async fn create_asset(
+    pool: &PgPool,
+    entity_type: &str,
+    entity_id: DbId,
+    backend_id: DbId,
+    size: i64,
+    age_days: i32,
+) {
+    let location = AssetLocationRepo::create(
+        pool,
+        &CreateAssetLocation {
+            entity_type: entity_type.to_string(),
+            entity_id,
+            file_field: None,
+            backend_id,
+            storage_path: format!("{entity_type}/{entity_id}.mp4"),
+            file_size_bytes: Some(size),
+            checksum_sha256: None,
+        },
+    )
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE asset_locations SET created_at = NOW() - make_interval(days => $2) WHERE id = $1",
+    )
+    .bind(location.id)
+    .bind(age_days)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Test: simulation reports the assets a proposed policy would relocate
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_simulation_reports_relocations_and_bytes(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "storage_admin", 1).await;
+    let hot = create_backend(&pool, "hot-primary", "hot").await;
+    let archive = create_backend(&pool, "cold-archive", "cold").await;
+    let legacy = create_backend(&pool, "cold-legacy", "cold").await;
+
+    // Old enough on hot: moves.
+    create_asset(&pool, "segment", 1, hot, 1000, 100).await;
+    create_asset(&pool, "segment", 2, hot, 2500, 40).await;
+    // Too young: stays.
+    create_asset(&pool, "segment", 3, hot, 9000, 5).await;
+    // Already on the target backend: stays.
+    create_asset(&pool, "segment", 4, archive, 4000, 100).await;
+    // On another cold backend: consolidated onto the target.
+    create_asset(&pool, "segment", 5, legacy, 700, 100).await;
+    // Different entity type: out of scope.
+    create_asset(&pool, "image", 6, hot, 8000, 100).await;
+
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "storage_admin", &password).await;
+
+    let body = json!({
+        "entity_type": "segment",
+        "source_tier": "hot",
+        "target_tier": "cold",
+        "target_backend_id": archive,
+        "age_threshold_days": 30,
+    });
+    let response =
+        post_json_auth(app, "/api/v1/admin/storage/policies/simulate", body, &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let simulation = body_json(response).await["data"].clone();
+
+    let moved: Vec<i64> = simulation["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| {
+            assert_eq!(asset["to_backend_id"], archive);
+            assert_eq!(asset["to_tier"], "cold");
+            asset["entity_id"].as_i64().unwrap()
+        })
+        .collect();
+    assert_eq!(moved, vec![2, 1, 5], "largest first");
+    assert_eq!(simulation["total_assets"], 3);
+    assert_eq!(simulation["total_bytes"], 4200);
+
+    let by_backend = simulation["by_backend"].as_array().unwrap();
+    assert_eq!(by_backend.len(), 2);
+    assert_eq!(by_backend[0]["from_backend_id"], hot);
+    assert_eq!(by_backend[0]["asset_count"], 2);
+    assert_eq!(by_backend[0]["total_bytes"], 3500);
+    assert_eq!(by_backend[1]["from_backend_id"], legacy);
+    assert_eq!(by_backend[1]["asset_count"], 1);
+    assert_eq!(by_backend[1]["total_bytes"], 700);
+}
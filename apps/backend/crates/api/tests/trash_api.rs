@@ -23,6 +23,7 @@ fn new_project(name: &str) -> CreateProject {
         description: None,
         status_id: None,
         retention_days: None,
+        pipeline_id: 1,
     }
 }
 
@@ -33,6 +34,7 @@ fn new_avatar(project_id: i64, name: &str) -> CreateAvatar {
         status_id: None,
         metadata: None,
         settings: None,
+        group_id: None,
     }
 }
 
@@ -0,0 +1,128 @@
+//! Integration tests for batch segment trimming (PRD-78).
+//!
+//! Seeds a scene whose segments have different seed anchors and generated
+//! neighbours, then verifies `POST /trims/batch` checks each segment's seed
+//! impact first, skipping (and reporting) a trim that would cut the anchor
+//! of a generated next segment while applying the rest and reporting
+//! failures without aborting the batch.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, login_for_token, post_json_auth, seed_scenes,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::repositories::{SegmentRepo, SegmentTrimRepo};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create a segment, optionally generated and with a chosen seed anchor.
+async fn create_segment(
+    pool: &PgPool,
+    scene_id: i64,
+    sequence_index: i32,
+    seed_anchor: Option<i32>,
+    generated: bool,
+) -> i64 {
+    let segment = SegmentRepo::create(
+        pool,
+        &serde_json::from_value(json!({
+            "scene_id": scene_id,
+            "sequence_index": sequence_index,
+            "boundary_frame_index": seed_anchor,
+            "output_video_path": generated.then(|| format!("/videos/seg{sequence_index}.mp4")),
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    segment.id
+}
+
+// ---------------------------------------------------------------------------
+// Test: a trim crossing a generated seed anchor is reported, not applied
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_batch_trim_skips_seed_anchor_crossing(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "trimmer", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "trimmer", &password).await;
+
+    let scene_id = seed_scenes(&pool, "trim", 1, json!({})).await.scene_ids[0];
+    // Segment 0 seeds generated segment 1 from frame 95: trimming to 80 cuts it.
+    let crossing = create_segment(&pool, scene_id, 0, Some(95), true).await;
+    // Segment 1's next segment is not generated yet, so cutting its anchor is fine.
+    let undetermined = create_segment(&pool, scene_id, 1, None, true).await;
+    // Segment 2 seeds generated segment 3 from frame 50, which the trim keeps.
+    let retained = create_segment(&pool, scene_id, 2, Some(50), false).await;
+    create_segment(&pool, scene_id, 3, None, true).await;
+    let missing = 9_999_999;
+
+    let response = post_json_auth(
+        app,
+        "/api/v1/trims/batch",
+        json!({
+            "segment_ids": [crossing, undetermined, retained, missing],
+            "in_frame": 0,
+            "out_frame": 80,
+            "total_frames": 100,
+            "concurrency": 2,
+        }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let data = body_json(response).await["data"].clone();
+
+    assert_eq!(
+        data["summary"],
+        json!({ "total": 4, "trimmed": 2, "skipped": 1, "failed": 1 })
+    );
+    assert_eq!(data["count"], 2);
+
+    let results = data["results"].as_array().unwrap();
+    let statuses: Vec<_> = results
+        .iter()
+        .map(|r| {
+            (
+                r["segment_id"].as_i64().unwrap(),
+                r["status"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (crossing, "skipped"),
+            (undetermined, "trimmed"),
+            (retained, "trimmed"),
+            (missing, "failed"),
+        ]
+    );
+    assert!(results[0]["reason"]
+        .as_str()
+        .unwrap()
+        .contains("seed anchor frame 95"));
+    assert_eq!(results[0]["impact"]["regeneration_required"], true);
+    assert!(results[0]["trim_id"].is_null());
+    assert!(results[3]["reason"].is_string());
+
+    // Only the safe trims were applied.
+    assert!(SegmentTrimRepo::get_active_trim(&pool, crossing)
+        .await
+        .unwrap()
+        .is_none());
+    for segment_id in [undetermined, retained] {
+        let trim = SegmentTrimRepo::get_active_trim(&pool, segment_id)
+            .await
+            .unwrap()
+            .expect("trim applied");
+        assert_eq!((trim.in_frame, trim.out_frame), (0, 80));
+        assert_eq!(trim.total_original_frames, 100);
+    }
+}
@@ -0,0 +1,122 @@
+//! Integration tests for listing undo trees (PRD-51).
+//!
+//! Verifies `GET /user/undo-trees` returns paginated summaries (entity,
+//! node count, last-modified) instead of full trees, filters by entity
+//! type, and only lists the caller's own trees.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, put_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Save an undo tree with `node_count` nodes for an entity.
+async fn save_tree(
+    app: axum::Router,
+    token: &str,
+    entity_type: &str,
+    entity_id: i64,
+    node_count: usize,
+) {
+    let nodes: serde_json::Map<String, serde_json::Value> = (0..node_count)
+        .map(|i| (format!("n{i}"), json!({ "id": format!("n{i}") })))
+        .collect();
+    let response = put_json_auth(
+        app,
+        &format!("/api/v1/user/undo-tree/{entity_type}/{entity_id}"),
+        json!({
+            "tree_json": { "nodes": nodes, "rootId": "n0" },
+            "current_node_id": "n0",
+        }),
+        token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn list(app: axum::Router, token: &str, query: &str) -> Vec<serde_json::Value> {
+    let response = get_auth(app, &format!("/api/v1/user/undo-trees?{query}"), token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    body_json(response).await["data"]
+        .as_array()
+        .unwrap()
+        .clone()
+}
+
+fn entity_keys(trees: &[serde_json::Value]) -> Vec<(String, i64)> {
+    trees
+        .iter()
+        .map(|t| {
+            (
+                t["entity_type"].as_str().unwrap().to_string(),
+                t["entity_id"].as_i64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Test: summaries are paginated without duplicates
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_list_returns_paginated_summaries(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "undouser", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "undouser", &password).await;
+
+    save_tree(app.clone(), &token, "scene", 1, 3).await;
+    save_tree(app.clone(), &token, "scene", 2, 1).await;
+    save_tree(app.clone(), &token, "avatar", 1, 2).await;
+    save_tree(app.clone(), &token, "segment", 7, 4).await;
+
+    let all = list(app.clone(), &token, "").await;
+    assert_eq!(all.len(), 4);
+    // Most recently updated first.
+    assert_eq!(all[0]["entity_type"], "segment");
+    assert_eq!(all[0]["node_count"], 4);
+    assert!(all[0]["updated_at"].is_string());
+    assert!(all[0].get("tree_json").is_none());
+
+    let first = list(app.clone(), &token, "limit=2").await;
+    let second = list(app.clone(), &token, "limit=2&offset=2").await;
+    let mut paged = entity_keys(&first);
+    paged.extend(entity_keys(&second));
+    assert_eq!(paged, entity_keys(&all));
+}
+
+// ---------------------------------------------------------------------------
+// Test: entity-type filter and ownership
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_list_filters_by_entity_type(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "undouser", 2).await;
+    let (_, other_password) = create_test_user(&pool, "otheruser", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "undouser", &password).await;
+    let other_token = login_for_token(app.clone(), "otheruser", &other_password).await;
+
+    save_tree(app.clone(), &token, "scene", 1, 3).await;
+    save_tree(app.clone(), &token, "scene", 2, 1).await;
+    save_tree(app.clone(), &token, "avatar", 1, 2).await;
+    save_tree(app.clone(), &other_token, "scene", 3, 5).await;
+
+    let scenes = list(app.clone(), &token, "entity_type=scene").await;
+    let mut keys = entity_keys(&scenes);
+    keys.sort();
+    assert_eq!(
+        keys,
+        vec![("scene".to_string(), 1), ("scene".to_string(), 2)]
+    );
+
+    let response = get_auth(app, "/api/v1/user/undo-trees?entity_type=widget", &token).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
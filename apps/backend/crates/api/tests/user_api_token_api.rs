@@ -0,0 +1,65 @@
+//! Integration tests for user API token storage (PRD-104).
+//!
+//! Verifies that `POST /user/api-tokens` stores the token encrypted and
+//! tagged with the active key ID, that `GET /user/api-tokens` only ever
+//! returns a masked hint, and that the stored token decrypts with the
+//! configured keyring.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, post_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_core::crypto::{self, TokenKeyring};
+use x121_db::repositories::UserApiTokenRepo;
+
+const TOKEN_KEYS: &str = "k1:0001020304050607080910111213141516171819202122232425262728293031";
+
+// ---------------------------------------------------------------------------
+// Test: tokens are encrypted at rest and masked on list
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_api_token_encrypted_at_rest_and_masked(pool: PgPool) {
+    std::env::set_var("USER_TOKEN_ENCRYPTION_KEYS", TOKEN_KEYS);
+
+    let (user, password) = create_test_user(&pool, "token_owner", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "token_owner", &password).await;
+
+    let secret = "hf_abcdefghijklmnop1234";
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/user/api-tokens",
+        json!({ "service_name": "huggingface", "token": secret }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let stored = body_json(response).await["data"].clone();
+    assert_eq!(stored["token_hint"], "...1234");
+    assert!(!stored.to_string().contains(secret));
+
+    // The list only exposes the masked hint.
+    let response = get_auth(app, "/api/v1/user/api-tokens", &token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let listed = body_json(response).await["data"].clone();
+    assert_eq!(listed.as_array().unwrap().len(), 1);
+    assert_eq!(listed[0]["token_hint"], "...1234");
+    assert!(!listed.to_string().contains(secret));
+
+    // At rest the token is ciphertext tagged with the active key ID.
+    let row = UserApiTokenRepo::find_by_user_service(&pool, user.id, "huggingface")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.encryption_key_id.as_deref(), Some("k1"));
+    assert_ne!(row.encrypted_token, secret.as_bytes());
+
+    let keyring = TokenKeyring::parse(TOKEN_KEYS).unwrap();
+    let sealed = row.to_encrypted().unwrap();
+    assert_eq!(crypto::decrypt_token(&sealed, &keyring).unwrap(), secret);
+}
@@ -270,6 +270,141 @@ async fn test_commit_already_committed_returns_409(pool: PgPool) {
     assert_eq!(response.status(), StatusCode::CONFLICT);
 }
 
+// ---------------------------------------------------------------------------
+// Test: POST /api/v1/imports/{id}/commit commits in chunks and resumes
+// ---------------------------------------------------------------------------
+
+/// Name of a report's current status.
+async fn report_status(pool: &PgPool, report_id: i64) -> String {
+    sqlx::query_scalar(
+        "SELECT s.name FROM import_reports r \
+         JOIN import_report_statuses s ON s.id = r.status_id WHERE r.id = $1",
+    )
+    .bind(report_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// Outcomes of a report's entries in `record_index` order.
+fn entry_outcomes(report: &serde_json::Value) -> Vec<serde_json::Value> {
+    report["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["outcome"].clone())
+        .collect()
+}
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_commit_import_in_chunks_resumes_after_failure(pool: PgPool) {
+    // Seven records; the last one is invalid and will be rejected.
+    let mut records: Vec<_> = (0..6)
+        .map(|i| json!({"name": format!("Avatar {i}"), "project_id": 1}))
+        .collect();
+    records.push(json!({"name": null, "project_id": null}));
+
+    let app = build_test_app(pool.clone()).await;
+    let response = post_json(
+        app,
+        "/api/v1/validation/validate",
+        json!({ "entity_type": "avatars", "records": records }),
+    )
+    .await;
+    let report_id = body_json(response).await["data"]["report_id"]
+        .as_i64()
+        .unwrap();
+
+    // Simulate a failure while applying record 4, in the second chunk of 3.
+    sqlx::query(
+        "CREATE FUNCTION fail_import_entry() RETURNS trigger AS $$ \
+         BEGIN RAISE EXCEPTION 'simulated import failure'; END $$ LANGUAGE plpgsql",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "CREATE TRIGGER trg_fail_import_entry BEFORE UPDATE OF outcome ON import_report_entries \
+         FOR EACH ROW WHEN (NEW.record_index = 4) EXECUTE FUNCTION fail_import_entry()",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let commit_uri = format!("/api/v1/imports/{report_id}/commit");
+    let report_uri = format!("/api/v1/imports/{report_id}/report");
+
+    let app = build_test_app(pool.clone()).await;
+    let response = post_json(app, &commit_uri, json!({ "batch_size": 3 })).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    // The first chunk landed; the failed chunk left no trace.
+    let app = build_test_app(pool.clone()).await;
+    let partial = body_json(get(app, &report_uri).await).await["data"].clone();
+    assert_eq!(partial["report"]["committed_records"], 3);
+    assert!(partial["report"]["last_commit_error"].is_string());
+    let outcomes = entry_outcomes(&partial);
+    assert!(outcomes[..3].iter().all(|o| o == "created"));
+    assert!(outcomes[3..].iter().all(|o| o.is_null()));
+    assert_eq!(report_status(&pool, report_id).await, "committing");
+
+    // Once the fault clears, committing again resumes from record 3.
+    sqlx::query("DROP TRIGGER trg_fail_import_entry ON import_report_entries")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = build_test_app(pool.clone()).await;
+    let response = post_json(app, &commit_uri, json!({ "batch_size": 3 })).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let committed = body_json(response).await["data"].clone();
+    assert_eq!(committed["committed_records"], 7);
+    assert!(committed["last_commit_error"].is_null());
+    assert_eq!(report_status(&pool, report_id).await, "committed");
+
+    let app = build_test_app(pool.clone()).await;
+    let full = body_json(get(app, &report_uri).await).await["data"].clone();
+    let outcomes = entry_outcomes(&full);
+    assert_eq!(outcomes.len(), 7);
+    assert!(outcomes[..6].iter().all(|o| o == "created"));
+    assert_eq!(outcomes[6], "failed");
+
+    // A finished commit cannot be re-run.
+    let app = build_test_app(pool).await;
+    let response = post_json(app, &commit_uri, json!({})).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+// ---------------------------------------------------------------------------
+// Test: POST /api/v1/imports/{id}/commit rejects an invalid batch size
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_commit_import_rejects_invalid_batch_size(pool: PgPool) {
+    let app = build_test_app(pool.clone()).await;
+    let response = post_json(
+        app,
+        "/api/v1/validation/validate",
+        json!({
+            "entity_type": "avatars",
+            "records": [{"name": "Gina", "project_id": 1}]
+        }),
+    )
+    .await;
+    let report_id = body_json(response).await["data"]["report_id"]
+        .as_i64()
+        .unwrap();
+
+    let app = build_test_app(pool).await;
+    let response = post_json(
+        app,
+        &format!("/api/v1/imports/{report_id}/commit"),
+        json!({ "batch_size": 0 }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 // ---------------------------------------------------------------------------
 // Test: GET /api/v1/imports/{nonexistent}/report returns 404
 // ---------------------------------------------------------------------------
@@ -0,0 +1,141 @@
+//! Integration tests for `PUT /scenes/{scene_id}/versions/{id}/set-final`.
+//!
+//! Verifies that promoting a version demotes the previous final atomically,
+//! so concurrent requests for different versions never leave a scene with
+//! two final versions, and that deleted versions cannot be promoted.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, login_for_token, put_json_auth, seed_scenes,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::models::scene_video_version::CreateSceneVideoVersion;
+use x121_db::repositories::SceneVideoVersionRepo;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn new_version(scene_id: i64) -> CreateSceneVideoVersion {
+    serde_json::from_value(json!({
+        "scene_id": scene_id,
+        "source": "generated",
+        "file_path": "/videos/clip.mp4",
+    }))
+    .unwrap()
+}
+
+/// Ids of the scene's versions currently marked final.
+async fn final_ids(pool: &PgPool, scene_id: i64) -> Vec<i64> {
+    SceneVideoVersionRepo::list_by_scene(pool, scene_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|v| v.is_final)
+        .map(|v| v.id)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Test: concurrent set-final calls leave exactly one final version
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_concurrent_set_final_leaves_single_final(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "finalizer", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "finalizer", &password).await;
+
+    let scene_id = seed_scenes(&pool, "concurrent", 1, json!({}))
+        .await
+        .scene_ids[0];
+    let original = SceneVideoVersionRepo::create_as_final(&pool, &new_version(scene_id))
+        .await
+        .unwrap();
+
+    // Several rounds to give the two requests a chance to interleave.
+    for _ in 0..5 {
+        let a = SceneVideoVersionRepo::create(&pool, &new_version(scene_id))
+            .await
+            .unwrap();
+        let b = SceneVideoVersionRepo::create(&pool, &new_version(scene_id))
+            .await
+            .unwrap();
+
+        let uri_a = format!("/api/v1/scenes/{scene_id}/versions/{}/set-final", a.id);
+        let uri_b = format!("/api/v1/scenes/{scene_id}/versions/{}/set-final", b.id);
+        let (res_a, res_b) = tokio::join!(
+            put_json_auth(app.clone(), &uri_a, json!({}), &token),
+            put_json_auth(app.clone(), &uri_b, json!({}), &token),
+        );
+        assert_eq!(res_a.status(), StatusCode::OK);
+        assert_eq!(res_b.status(), StatusCode::OK);
+
+        let finals = final_ids(&pool, scene_id).await;
+        assert_eq!(
+            finals.len(),
+            1,
+            "expected exactly one final, got {finals:?}"
+        );
+        assert!(finals[0] == a.id || finals[0] == b.id);
+        assert_ne!(finals[0], original.id);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test: set-final demotes the previous final and rejects deleted versions
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_set_final_demotes_previous_and_rejects_deleted(pool: PgPool) {
+    let (_, password) = create_test_user(&pool, "finalizer", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "finalizer", &password).await;
+
+    let scene_id = seed_scenes(&pool, "demote", 1, json!({})).await.scene_ids[0];
+    let v1 = SceneVideoVersionRepo::create_as_final(&pool, &new_version(scene_id))
+        .await
+        .unwrap();
+    let v2 = SceneVideoVersionRepo::create(&pool, &new_version(scene_id))
+        .await
+        .unwrap();
+    let deleted = SceneVideoVersionRepo::create(&pool, &new_version(scene_id))
+        .await
+        .unwrap();
+    SceneVideoVersionRepo::soft_delete(&pool, deleted.id)
+        .await
+        .unwrap();
+
+    let response = put_json_auth(
+        app.clone(),
+        &format!("/api/v1/scenes/{scene_id}/versions/{}/set-final", v2.id),
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = body_json(response).await;
+    assert_eq!(json["data"]["is_final"], true);
+    assert_eq!(final_ids(&pool, scene_id).await, vec![v2.id]);
+    let v1 = SceneVideoVersionRepo::find_by_id(&pool, v1.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!v1.is_final, "previous final should be demoted");
+
+    let response = put_json_auth(
+        app,
+        &format!(
+            "/api/v1/scenes/{scene_id}/versions/{}/set-final",
+            deleted.id
+        ),
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(final_ids(&pool, scene_id).await, vec![v2.id]);
+}
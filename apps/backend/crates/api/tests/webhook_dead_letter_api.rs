@@ -0,0 +1,146 @@
+//! Integration tests for the webhook dead-letter queue (PRD-12).
+//!
+//! Runs the delivery worker against a local endpoint that always answers
+//! 500 and verifies the exhausted delivery is recorded in
+//! `webhook_dead_letters`, listed in the webhook's history as
+//! `dead_lettered`, and re-queued by the replay route.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, build_test_state, create_test_user, get_auth, login_for_token,
+    post_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use x121_api::background::webhook_delivery::run;
+use x121_db::repositories::WebhookRepo;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Serve an endpoint that answers every request with 500, returning its URL
+/// and a counter of the requests it received.
+async fn spawn_failing_endpoint() -> (String, Arc<AtomicUsize>) {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+    let app = axum::Router::new().fallback(move || {
+        let counter = Arc::clone(&counter);
+        async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (format!("http://{addr}/hook"), hits)
+}
+
+// ---------------------------------------------------------------------------
+// Test: exhausted deliveries are dead-lettered and replayable
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_exhausted_delivery_is_dead_lettered_and_replayable(pool: PgPool) {
+    let (admin, password) = create_test_user(&pool, "hook_admin", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "hook_admin", &password).await;
+
+    let (url, hits) = spawn_failing_endpoint().await;
+    let webhook = WebhookRepo::create(
+        &pool,
+        "Down endpoint",
+        &url,
+        None,
+        &json!(["job.completed"]),
+        true,
+        admin.id,
+    )
+    .await
+    .unwrap();
+
+    let payload = json!({ "event_type": "job.completed", "source_entity_id": 7 });
+    let delivery = WebhookRepo::create_delivery(&pool, webhook.id, None, &payload)
+        .await
+        .unwrap();
+    // One attempt left, so the worker's first failure exhausts it.
+    sqlx::query("UPDATE webhook_deliveries SET max_attempts = 1 WHERE id = $1")
+        .bind(delivery.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let cancel = CancellationToken::new();
+    let worker = tokio::spawn(run(build_test_state(pool.clone()).await, cancel.clone()));
+
+    let mut dead_letters = Vec::new();
+    for _ in 0..100 {
+        dead_letters = WebhookRepo::list_dead_letters_for_delivery(&pool, delivery.id)
+            .await
+            .unwrap();
+        if !dead_letters.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    cancel.cancel();
+    worker.await.unwrap();
+
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        dead_letters.len(),
+        1,
+        "exhausted delivery was not dead-lettered"
+    );
+    assert_eq!(dead_letters[0].webhook_id, Some(webhook.id));
+    assert_eq!(dead_letters[0].url, url);
+    assert_eq!(dead_letters[0].attempt_count, 1);
+    assert_eq!(dead_letters[0].last_error, "Webhook returned HTTP 500");
+    assert_eq!(dead_letters[0].payload["event_type"], "job.completed");
+    assert!(dead_letters[0].replayed_at.is_none());
+
+    // The exhausted delivery shows in the history as dead-lettered.
+    let response = get_auth(
+        app.clone(),
+        &format!(
+            "/api/v1/admin/webhooks/{}/deliveries?outcome=dead_lettered",
+            webhook.id
+        ),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let page = body_json(response).await["data"].clone();
+    assert_eq!(page["total"], 1);
+    let item = &page["items"][0];
+    assert_eq!(item["status"], "dead_lettered");
+    assert_eq!(item["attempt_count"], 1);
+    assert_eq!(item["response_status_code"], 500);
+    let delivery_id = item["id"].as_i64().unwrap();
+
+    // Replay re-queues the delivery and marks its dead letter as replayed.
+    let response = post_json_auth(
+        app,
+        &format!("/api/v1/admin/webhooks/deliveries/{delivery_id}/replay"),
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let replayed = body_json(response).await["data"].clone();
+    assert_eq!(replayed["status"], "pending");
+    assert_eq!(replayed["attempt_count"], 0);
+
+    let dead_letters = WebhookRepo::list_dead_letters_for_delivery(&pool, delivery_id)
+        .await
+        .unwrap();
+    assert!(dead_letters[0].replayed_at.is_some());
+}
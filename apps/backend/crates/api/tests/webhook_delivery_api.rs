@@ -0,0 +1,150 @@
+//! Integration tests for webhook delivery history (PRD-12).
+//!
+//! Seeds a webhook with deliveries of mixed outcomes and ages, then verifies
+//! `GET /admin/webhooks/{id}/deliveries` applies the outcome and time-range
+//! filters together with pagination, reporting the filtered total.
+
+mod common;
+
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use common::{body_json, build_test_app, create_test_user, get_auth, login_for_token};
+use serde_json::json;
+use sqlx::PgPool;
+use x121_db::repositories::WebhookRepo;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create a delivery and drive it to `outcome`, returning its id.
+async fn seed_delivery(pool: &PgPool, webhook_id: i64, outcome: &str) -> i64 {
+    let delivery = WebhookRepo::create_delivery(pool, webhook_id, None, &json!({ "n": 1 }))
+        .await
+        .unwrap();
+    match outcome {
+        "success" => WebhookRepo::mark_delivered(pool, delivery.id, 200, 120)
+            .await
+            .unwrap(),
+        "retrying" => WebhookRepo::schedule_retry(pool, delivery.id, Some(503), 1, 60, 900)
+            .await
+            .unwrap(),
+        "failed" => WebhookRepo::schedule_retry(pool, delivery.id, None, 3, 60, 10_000)
+            .await
+            .unwrap(),
+        other => panic!("unknown outcome {other}"),
+    }
+    delivery.id
+}
+
+/// Ids and statuses of a history page's items.
+fn page_items(page: &serde_json::Value) -> Vec<(i64, String)> {
+    page["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| {
+            (
+                d["id"].as_i64().unwrap(),
+                d["status"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Test: outcome and time filters combine with pagination
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_delivery_history_filters_and_paginates(pool: PgPool) {
+    let (admin, password) = create_test_user(&pool, "hook_admin", 1).await;
+    let app = build_test_app(pool.clone()).await;
+    let token = login_for_token(app.clone(), "hook_admin", &password).await;
+
+    let webhook = WebhookRepo::create(
+        &pool,
+        "Flaky endpoint",
+        "http://127.0.0.1:9/hook",
+        None,
+        &json!(["job.completed"]),
+        true,
+        admin.id,
+    )
+    .await
+    .unwrap();
+
+    let mut successes = Vec::new();
+    for _ in 0..4 {
+        successes.push(seed_delivery(&pool, webhook.id, "success").await);
+    }
+    seed_delivery(&pool, webhook.id, "failed").await;
+    seed_delivery(&pool, webhook.id, "retrying").await;
+    seed_delivery(&pool, webhook.id, "retrying").await;
+
+    // Age the oldest success out of the time window queried below.
+    sqlx::query(
+        "UPDATE webhook_deliveries SET created_at = NOW() - INTERVAL '3 days' WHERE id = $1",
+    )
+    .bind(successes[0])
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let base = format!("/api/v1/admin/webhooks/{}/deliveries", webhook.id);
+    let since = (Utc::now() - Duration::days(1)).format("%Y-%m-%dT%H:%M:%SZ");
+
+    // Recent successes, two per page.
+    let response = get_auth(
+        app.clone(),
+        &format!("{base}?outcome=success&since={since}&limit=2"),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let first = body_json(response).await["data"].clone();
+    assert_eq!(first["total"], 3);
+    let first_items = page_items(&first);
+    assert_eq!(first_items.len(), 2);
+    assert!(first_items.iter().all(|(_, status)| status == "delivered"));
+    let delivered = &first["items"][0];
+    assert_eq!(delivered["attempt_count"], 1);
+    assert_eq!(delivered["latency_ms"], 120);
+
+    let response = get_auth(
+        app.clone(),
+        &format!("{base}?outcome=success&since={since}&limit=2&offset=2"),
+        &token,
+    )
+    .await;
+    let second = body_json(response).await["data"].clone();
+    assert_eq!(second["total"], 3);
+    let second_items = page_items(&second);
+    assert_eq!(second_items.len(), 1);
+
+    // The pages are disjoint and exclude the aged delivery.
+    let mut seen: Vec<i64> = first_items
+        .iter()
+        .chain(&second_items)
+        .map(|(id, _)| *id)
+        .collect();
+    seen.sort();
+    assert_eq!(seen, successes[1..].to_vec());
+
+    // Retrying deliveries report their attempts so far.
+    let response = get_auth(app.clone(), &format!("{base}?outcome=retrying"), &token).await;
+    let retrying = body_json(response).await["data"].clone();
+    assert_eq!(retrying["total"], 2);
+    assert!(retrying["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|d| d["attempt_count"] == 1 && d["latency_ms"] == 900));
+
+    // Unfiltered history covers every delivery.
+    let response = get_auth(app.clone(), &base, &token).await;
+    assert_eq!(body_json(response).await["data"]["total"], 7);
+
+    let response = get_auth(app, &format!("{base}?outcome=pending"), &token).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
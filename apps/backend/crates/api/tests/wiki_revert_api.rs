@@ -0,0 +1,139 @@
+//! Integration tests for reverting wiki articles (PRD-56).
+//!
+//! Verifies `POST /wiki/articles/{slug}/revert/{version}` appends a new
+//! version with the reverted-to content instead of rewriting history, records
+//! who reverted and between which versions, and rejects unknown versions.
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{
+    body_json, build_test_app, create_test_user, get_auth, login_for_token, post_json_auth,
+    put_json_auth,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create `release-notes` at version 1 and edit its content twice, leaving
+/// it at version 3.
+async fn create_article_with_history(app: axum::Router, token: &str) {
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/wiki/articles",
+        json!({
+            "title": "Release Notes",
+            "slug": "release-notes",
+            "content_md": "Original notes.",
+        }),
+        token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    for content in ["Second draft.", "Third draft."] {
+        let response = put_json_auth(
+            app.clone(),
+            "/api/v1/wiki/articles/release-notes",
+            json!({ "content_md": content }),
+            token,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
+
+async fn list_versions(app: axum::Router, token: &str) -> Vec<serde_json::Value> {
+    let response = get_auth(app, "/api/v1/wiki/articles/release-notes/versions", token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    body_json(response).await["data"]
+        .as_array()
+        .unwrap()
+        .clone()
+}
+
+// ---------------------------------------------------------------------------
+// Test: reverting appends a new version with the old content
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_revert_creates_new_version_with_old_content(pool: PgPool) {
+    let (user, password) = create_test_user(&pool, "wikieditor", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "wikieditor", &password).await;
+    create_article_with_history(app.clone(), &token).await;
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/wiki/articles/release-notes/revert/1",
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let data = body_json(response).await["data"].clone();
+
+    assert_eq!(data["article"]["content_md"], "Original notes.");
+    let version = &data["version"];
+    assert_eq!(version["version"], 4);
+    assert_eq!(version["content_md"], "Original notes.");
+    assert_eq!(version["edited_by"], user.id);
+    assert_eq!(version["reverted_from_version"], 3);
+    assert_eq!(version["reverted_to_version"], 1);
+    assert_eq!(version["edit_summary"], "Reverted to version 1");
+
+    // Earlier versions are untouched; the revert is appended on top.
+    let versions = list_versions(app, &token).await;
+    let history: Vec<(i64, &str)> = versions
+        .iter()
+        .map(|v| {
+            (
+                v["version"].as_i64().unwrap(),
+                v["content_md"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        history,
+        vec![
+            (4, "Original notes."),
+            (3, "Third draft."),
+            (2, "Second draft."),
+            (1, "Original notes."),
+        ]
+    );
+    assert!(versions[1]["reverted_to_version"].is_null());
+}
+
+// ---------------------------------------------------------------------------
+// Test: reverting to a nonexistent version is rejected without changes
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_revert_to_nonexistent_version_is_rejected(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "wikieditor", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "wikieditor", &password).await;
+    create_article_with_history(app.clone(), &token).await;
+
+    let response = post_json_auth(
+        app.clone(),
+        "/api/v1/wiki/articles/release-notes/revert/99",
+        json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let versions = list_versions(app.clone(), &token).await;
+    assert_eq!(versions.len(), 3);
+
+    let response = get_auth(app, "/api/v1/wiki/articles/release-notes", &token).await;
+    assert_eq!(
+        body_json(response).await["data"]["content_md"],
+        "Third draft."
+    );
+}
@@ -0,0 +1,202 @@
+//! Integration tests for the wiki article search index (PRD-56).
+//!
+//! Exercises the incremental indexer against a local event bus, the chunked
+//! full reindex, and the admin guard on
+//! `POST /admin/maintenance/reindex-wiki`.
+
+mod common;
+
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use common::{body_json, build_test_app, create_test_user, login_for_token, post_json_auth};
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use x121_api::background::wiki_search_index::{reindex_wiki, run};
+use x121_core::wiki::EVENT_ARTICLE_UPDATED;
+use x121_db::models::bulk_operation::CreateBulkOperation;
+use x121_db::models::status::{BulkOperationStatusId, BulkOperationTypeId};
+use x121_db::models::wiki_article::{CreateWikiArticle, UpdateWikiArticle};
+use x121_db::repositories::{BulkOperationRepo, WikiArticleRepo};
+use x121_events::{EventBus, PlatformEvent};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+async fn create_article(pool: &PgPool, slug: &str, content_md: &str) -> i64 {
+    WikiArticleRepo::create(
+        pool,
+        &CreateWikiArticle {
+            title: format!("Article {slug}"),
+            slug: None,
+            content_md: content_md.to_string(),
+            category: None,
+            tags: None,
+            is_pinned: None,
+            pin_location: None,
+        },
+        slug,
+        None,
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+async fn search_slugs(pool: &PgPool, query: &str) -> Vec<String> {
+    WikiArticleRepo::search(pool, query, 20)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|article| article.slug)
+        .collect()
+}
+
+async fn create_reindex_operation(pool: &PgPool) -> i64 {
+    BulkOperationRepo::create(
+        pool,
+        &CreateBulkOperation {
+            operation_type_id: BulkOperationTypeId::ReindexWiki.id(),
+            status_id: BulkOperationStatusId::Executing.id(),
+            parameters: serde_json::json!({}),
+            scope_project_id: None,
+            affected_entity_type: Some("wiki_article".to_string()),
+            affected_field: None,
+            preview_count: 0,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+// ---------------------------------------------------------------------------
+// Test: an edited article's new content is searchable after the update event
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_incremental_update_indexes_edited_content(pool: PgPool) {
+    let article_id = create_article(&pool, "birds", "Notes about the flamingo.").await;
+    assert!(WikiArticleRepo::index_article(&pool, article_id)
+        .await
+        .unwrap());
+    assert_eq!(search_slugs(&pool, "flamingo").await, vec!["birds"]);
+
+    let bus = EventBus::default();
+    let cancel = CancellationToken::new();
+    let indexer = tokio::spawn(run(
+        pool.clone(),
+        bus.subscribe(),
+        cancel.clone(),
+        Duration::from_millis(50),
+    ));
+
+    WikiArticleRepo::update(
+        &pool,
+        "birds",
+        &UpdateWikiArticle {
+            title: None,
+            content_md: Some("Notes about the pelican.".to_string()),
+            category: None,
+            tags: None,
+            is_pinned: None,
+            pin_location: None,
+            edit_summary: None,
+        },
+        None,
+    )
+    .await
+    .unwrap();
+
+    // The stale index entry hides the new content until the indexer runs.
+    assert!(search_slugs(&pool, "pelican").await.is_empty());
+
+    bus.publish(PlatformEvent::new(EVENT_ARTICLE_UPDATED).with_source("wiki_article", article_id));
+
+    let mut found = false;
+    for _ in 0..50 {
+        if search_slugs(&pool, "pelican").await == vec!["birds"] {
+            found = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(found, "edited content was not indexed");
+    assert!(search_slugs(&pool, "flamingo").await.is_empty());
+
+    cancel.cancel();
+    indexer.await.unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Test: full reindex covers every article across chunks
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_reindex_wiki_indexes_all_articles(pool: PgPool) {
+    for slug in ["heron", "egret", "ibis"] {
+        create_article(&pool, slug, &format!("The {slug} wades in shallow water.")).await;
+    }
+    let operation_id = create_reindex_operation(&pool).await;
+
+    // Chunk size 2 forces the reindex to span multiple chunks.
+    let report = reindex_wiki(&pool, operation_id, 2, 2, |_| {})
+        .await
+        .unwrap();
+
+    assert!(!report.cancelled);
+    assert_eq!(report.articles_indexed, 3);
+    let mut slugs = search_slugs(&pool, "wades").await;
+    slugs.sort();
+    assert_eq!(slugs, vec!["egret", "heron", "ibis"]);
+
+    let op = BulkOperationRepo::find_by_id(&pool, operation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(op.affected_count, 3);
+}
+
+// ---------------------------------------------------------------------------
+// Test: a cancelled reindex indexes nothing
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_reindex_wiki_stops_when_cancelled(pool: PgPool) {
+    create_article(&pool, "heron", "The heron wades in shallow water.").await;
+    let operation_id = create_reindex_operation(&pool).await;
+    BulkOperationRepo::update_status(&pool, operation_id, BulkOperationStatusId::Cancelled.id())
+        .await
+        .unwrap();
+
+    let report = reindex_wiki(&pool, operation_id, 2, 2, |_| {})
+        .await
+        .unwrap();
+
+    assert!(report.cancelled);
+    assert_eq!(report.articles_indexed, 0);
+}
+
+// ---------------------------------------------------------------------------
+// Test: POST /admin/maintenance/reindex-wiki requires admin
+// ---------------------------------------------------------------------------
+
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_reindex_wiki_requires_admin(pool: PgPool) {
+    let (_user, password) = create_test_user(&pool, "wikireindexer", 2).await;
+    let app = build_test_app(pool).await;
+    let token = login_for_token(app.clone(), "wikireindexer", &password).await;
+
+    let response = post_json_auth(
+        app,
+        "/api/v1/admin/maintenance/reindex-wiki",
+        serde_json::json!({}),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let json = body_json(response).await;
+    assert!(json["error"].is_string());
+}
@@ -2,10 +2,24 @@
 //!
 //! These tests exercise the WebSocket connection manager directly, without
 //! performing any HTTP upgrades. They verify add/remove semantics, broadcast
-//! delivery, and graceful shutdown behaviour.
+//! delivery, topic subscriptions, typed frame serialization, idle-connection
+//! pruning, send-queue backpressure, and graceful shutdown behaviour.
+
+use std::time::{Duration, Instant};
 
 use axum::extract::ws::Message;
-use x121_api::ws::WsManager;
+use x121_api::ws::{
+    user_topic, HeartbeatConfig, InboundMessage, OnFull, OutboundMessage, SendQueueConfig,
+    WsManager, WsTarget, WS_SCHEMA_VERSION,
+};
+use x121_core::activity::{ActivityLogEntry, ActivityLogLevel, ActivityLogSource};
+
+fn control(action: &str) -> OutboundMessage {
+    OutboundMessage::Control {
+        action: action.to_string(),
+        reason: None,
+    }
+}
 
 // ---------------------------------------------------------------------------
 // Test: new manager starts with zero connections
@@ -98,7 +112,7 @@ async fn shutdown_all_sends_close_and_clears() {
 }
 
 // ---------------------------------------------------------------------------
-// Test: broadcast() sends message to all connected clients
+// Test: WsTarget::All sends a frame to all connected clients
 // ---------------------------------------------------------------------------
 
 #[tokio::test]
@@ -109,21 +123,22 @@ async fn broadcast_sends_to_all_connections() {
     let mut rx2 = manager.add("conn-2".to_string(), None).await;
     let mut rx3 = manager.add("conn-3".to_string(), None).await;
 
-    let payload = Message::Text("hello everyone".into());
-    manager.broadcast(payload).await;
+    manager
+        .send_typed(WsTarget::All, &control("hello everyone"))
+        .await;
 
     // All three receivers should get the same message.
     let msg1 = rx1.recv().await.expect("rx1 should receive broadcast");
     let msg2 = rx2.recv().await.expect("rx2 should receive broadcast");
     let msg3 = rx3.recv().await.expect("rx3 should receive broadcast");
 
-    assert!(matches!(&msg1, Message::Text(t) if *t == "hello everyone"));
-    assert!(matches!(&msg2, Message::Text(t) if *t == "hello everyone"));
-    assert!(matches!(&msg3, Message::Text(t) if *t == "hello everyone"));
+    assert_eq!(msg1, control("hello everyone").to_frame());
+    assert_eq!(msg2, control("hello everyone").to_frame());
+    assert_eq!(msg3, control("hello everyone").to_frame());
 }
 
 // ---------------------------------------------------------------------------
-// Test: broadcast() skips closed channels without panicking
+// Test: WsTarget::All skips closed channels without panicking
 // ---------------------------------------------------------------------------
 
 #[tokio::test]
@@ -137,12 +152,13 @@ async fn broadcast_skips_closed_channels() {
     drop(rx1);
 
     // Broadcast should not panic even though conn-1's channel is closed.
-    let payload = Message::Text("still alive".into());
-    manager.broadcast(payload).await;
+    manager
+        .send_typed(WsTarget::All, &control("still alive"))
+        .await;
 
     // conn-2 should still receive the message.
     let msg = rx2.recv().await.expect("rx2 should receive broadcast");
-    assert!(matches!(&msg, Message::Text(t) if *t == "still alive"));
+    assert_eq!(msg, control("still alive").to_frame());
 }
 
 // ---------------------------------------------------------------------------
@@ -184,7 +200,362 @@ async fn duplicate_id_replaces_previous_connection() {
     assert_eq!(manager.connection_count().await, 1);
 
     // Broadcast to verify the new receiver gets the message.
-    manager.broadcast(Message::Text("replaced".into())).await;
+    manager
+        .send_typed(WsTarget::All, &control("replaced"))
+        .await;
     let msg = rx_new.recv().await.expect("New rx should receive message");
-    assert!(matches!(&msg, Message::Text(t) if *t == "replaced"));
+    assert_eq!(msg, control("replaced").to_frame());
+}
+
+// ---------------------------------------------------------------------------
+// Test: send_typed() targets a single user's connections
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn send_typed_to_user_only_reaches_that_user() {
+    let manager = WsManager::new();
+
+    let mut rx_user = manager.add("conn-1".to_string(), Some(7)).await;
+    let mut rx_other = manager.add("conn-2".to_string(), Some(8)).await;
+
+    let sent = manager
+        .send_typed(WsTarget::User(7), &OutboundMessage::job_completed(42))
+        .await;
+    assert_eq!(sent, 1);
+
+    let msg = rx_user.recv().await.expect("user should receive frame");
+    let Message::Text(text) = msg else {
+        panic!("Expected text frame, got: {msg:?}");
+    };
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["type"], "progress");
+    assert_eq!(json["v"], WS_SCHEMA_VERSION);
+    assert_eq!(json["job_id"], 42);
+
+    assert!(rx_other.try_recv().is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Test: every OutboundMessage variant serializes with its `type` tag
+// ---------------------------------------------------------------------------
+
+#[test]
+fn outbound_variants_serialize_with_type_discriminator() {
+    let cases = [
+        (
+            OutboundMessage::Notification {
+                event_type: "project.published".to_string(),
+                payload: serde_json::json!({"id": 1}),
+                timestamp: chrono::Utc::now(),
+            },
+            "notification",
+        ),
+        (
+            OutboundMessage::job_progress(1, 50, Some("KSampler".to_string())),
+            "progress",
+        ),
+        (
+            OutboundMessage::Presence {
+                user_id: 3,
+                online: true,
+            },
+            "presence",
+        ),
+        (
+            OutboundMessage::Control {
+                action: "reconnect".to_string(),
+                reason: None,
+            },
+            "control",
+        ),
+        (
+            OutboundMessage::ActivityEntry {
+                entry: ActivityLogEntry::curated(
+                    ActivityLogLevel::Info,
+                    ActivityLogSource::Api,
+                    "connected",
+                ),
+            },
+            "entry",
+        ),
+        (OutboundMessage::ActivityLagged { skipped: 3 }, "lagged"),
+    ];
+
+    for (message, expected_type) in cases {
+        let json = message.to_json();
+        assert_eq!(json["type"], expected_type, "frame: {json}");
+        assert_eq!(json["v"], WS_SCHEMA_VERSION);
+    }
+}
+
+#[test]
+fn activity_entry_frame_is_flat() {
+    let entry = ActivityLogEntry::curated(
+        ActivityLogLevel::Warn,
+        ActivityLogSource::Worker,
+        "disk low",
+    )
+    .with_user(5);
+    let message = OutboundMessage::ActivityEntry { entry };
+    let json = message.to_json();
+    assert_eq!(json["message"], "disk low");
+    assert_eq!(json["level"], "warn");
+    assert_eq!(json["user_id"], 5);
+
+    let parsed: OutboundMessage = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, message);
+}
+
+#[test]
+fn progress_frame_carries_job_event_name() {
+    let json = OutboundMessage::job_failed(9, "OOM").to_json();
+    assert_eq!(json["event"], "job_failed");
+    assert_eq!(json["error"], "OOM");
+    assert!(json.get("percent").is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Test: unknown future variants do not break parsing of known ones
+// ---------------------------------------------------------------------------
+
+#[test]
+fn unknown_variant_parses_as_unknown() {
+    let frame = serde_json::json!({"v": 2, "type": "hologram", "depth": 3});
+    let parsed: OutboundMessage = serde_json::from_value(frame).unwrap();
+    assert_eq!(parsed, OutboundMessage::Unknown);
+}
+
+#[test]
+fn known_variant_with_extra_fields_still_parses() {
+    let frame = serde_json::json!({
+        "v": 2,
+        "type": "presence",
+        "user_id": 5,
+        "online": false,
+        "device": "tablet",
+    });
+    let parsed: OutboundMessage = serde_json::from_value(frame).unwrap();
+    assert_eq!(
+        parsed,
+        OutboundMessage::Presence {
+            user_id: 5,
+            online: false
+        }
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Test: connections that stop answering pings are closed
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn close_idle_prunes_non_responding_connections() {
+    let manager = WsManager::with_heartbeat(HeartbeatConfig {
+        ping_interval_secs: 1,
+        idle_timeout_secs: 1,
+    });
+
+    let mut silent_rx = manager.add("silent".to_string(), None).await;
+    let _responsive_rx = manager.add("responsive".to_string(), None).await;
+    let connected = manager.last_pong("silent").await.unwrap();
+
+    // Nothing is idle yet.
+    assert!(manager.close_idle(Instant::now()).await.is_empty());
+
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    manager.record_pong("responsive").await;
+    assert!(manager.last_pong("responsive").await.unwrap() > connected);
+
+    // Past the idle timeout for the silent connection only.
+    let closed = manager
+        .close_idle(connected + Duration::from_millis(1500))
+        .await;
+
+    assert_eq!(closed, vec!["silent".to_string()]);
+    assert_eq!(manager.connection_count().await, 1);
+    assert!(manager.last_pong("silent").await.is_none());
+    assert!(manager.last_pong("responsive").await.is_some());
+
+    let msg = silent_rx.recv().await.expect("silent should receive Close");
+    assert!(matches!(msg, Message::Close(None)));
+    assert!(silent_rx.recv().await.is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Test: a stalled receiver's queue stays bounded
+// ---------------------------------------------------------------------------
+
+fn frame(i: usize) -> OutboundMessage {
+    control(&format!("frame-{i}"))
+}
+
+#[tokio::test]
+async fn flooding_stalled_receiver_drops_oldest_frames() {
+    let manager = WsManager::new().with_send_queue(SendQueueConfig {
+        capacity: 4,
+        on_full: OnFull::DropOldest,
+    });
+    let mut stalled = manager.add("stalled".to_string(), None).await;
+
+    for i in 0..100 {
+        manager.send_typed(WsTarget::All, &frame(i)).await;
+        assert!(manager.queue_depth("stalled").await.unwrap() <= 4);
+    }
+    assert_eq!(manager.queue_depth("stalled").await, Some(4));
+
+    // Only the newest frames survive, in order.
+    for i in 96..100 {
+        let msg = stalled.try_recv().expect("queued frame");
+        assert_eq!(msg, frame(i).to_frame());
+    }
+    assert!(stalled.try_recv().is_err());
+    assert_eq!(manager.connection_count().await, 1);
+
+    // Close frames are never shed, even when the queue is full.
+    for i in 0..10 {
+        manager.send_typed(WsTarget::All, &frame(i)).await;
+    }
+    manager.shutdown_all().await;
+    let mut last = None;
+    while let Some(msg) = stalled.recv().await {
+        last = Some(msg);
+    }
+    assert!(matches!(last, Some(Message::Close(None))));
+}
+
+#[tokio::test]
+async fn flooding_stalled_receiver_disconnects_it() {
+    let manager = WsManager::new().with_send_queue(SendQueueConfig {
+        capacity: 4,
+        on_full: OnFull::Disconnect,
+    });
+    let mut stalled = manager.add("stalled".to_string(), None).await;
+    let mut healthy = manager.add("healthy".to_string(), None).await;
+
+    for i in 0..100 {
+        manager.send_typed(WsTarget::All, &frame(i)).await;
+        // The healthy client keeps up.
+        assert!(healthy.try_recv().is_ok());
+    }
+
+    assert_eq!(manager.queue_depth("stalled").await, None);
+    assert_eq!(manager.connection_count().await, 1);
+
+    // The backlog was discarded and the connection told to close.
+    let msg = stalled.recv().await.expect("stalled should receive Close");
+    assert!(matches!(msg, Message::Close(None)));
+    assert!(stalled.recv().await.is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Test: topic subscriptions
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn subscribe_and_unsubscribe_track_topics() {
+    let manager = WsManager::new();
+    let _rx = manager.add("conn-1".to_string(), Some(1)).await;
+
+    assert!(manager.subscribe("conn-1", "project:42").await);
+    assert!(manager.subscribe("conn-1", "project:42").await);
+    assert_eq!(manager.subscriber_count("project:42").await, 1);
+
+    assert!(!manager.subscribe("conn-1", "").await);
+    assert!(!manager.subscribe("missing", "project:42").await);
+
+    assert!(manager.unsubscribe("conn-1", "project:42").await);
+    assert!(!manager.unsubscribe("conn-1", "project:42").await);
+    assert_eq!(manager.subscriber_count("project:42").await, 0);
+}
+
+#[tokio::test]
+async fn topic_frames_only_reach_subscribers() {
+    let manager = WsManager::new();
+    let mut rx_project = manager.add("conn-1".to_string(), Some(1)).await;
+    let mut rx_scene = manager.add("conn-2".to_string(), Some(2)).await;
+    let mut rx_none = manager.add("conn-3".to_string(), Some(3)).await;
+
+    manager.subscribe("conn-1", "project:42").await;
+    manager.subscribe("conn-2", "scene:1001").await;
+
+    let sent = manager
+        .send_typed(
+            WsTarget::Topic("project:42".to_string()),
+            &control("for project"),
+        )
+        .await;
+    assert_eq!(sent, 1);
+
+    let msg = rx_project.recv().await.expect("subscriber should receive");
+    assert_eq!(msg, control("for project").to_frame());
+    assert!(rx_scene.try_recv().is_err());
+    assert!(rx_none.try_recv().is_err());
+
+    let sent = manager
+        .send_typed(WsTarget::Topic("project:7".to_string()), &control("nobody"))
+        .await;
+    assert_eq!(sent, 0);
+}
+
+#[tokio::test]
+async fn disconnect_removes_connection_from_all_topics() {
+    let manager = WsManager::new();
+    let _rx1 = manager.add("conn-1".to_string(), Some(1)).await;
+    let mut rx2 = manager.add("conn-2".to_string(), Some(2)).await;
+
+    for topic in ["project:42", "scene:1001"] {
+        manager.subscribe("conn-1", topic).await;
+        manager.subscribe("conn-2", topic).await;
+    }
+
+    manager.remove("conn-1").await;
+    assert_eq!(manager.subscriber_count("project:42").await, 1);
+    assert_eq!(manager.subscriber_count("scene:1001").await, 1);
+
+    let sent = manager
+        .send_typed(
+            WsTarget::Topic("scene:1001".to_string()),
+            &control("still here"),
+        )
+        .await;
+    assert_eq!(sent, 1);
+    assert!(rx2.recv().await.is_some());
+
+    // A reconnect under the same ID starts with no subscriptions.
+    let _rx1 = manager.add("conn-1".to_string(), Some(1)).await;
+    assert_eq!(manager.subscriber_count("project:42").await, 1);
+}
+
+#[tokio::test]
+async fn subscribe_is_authorized_against_connection_user() {
+    let manager = WsManager::new();
+    let _rx_user = manager.add("conn-1".to_string(), Some(1)).await;
+    let _rx_anon = manager.add("conn-2".to_string(), None).await;
+
+    assert!(manager.subscribe("conn-1", &user_topic(1)).await);
+    assert!(!manager.subscribe("conn-1", &user_topic(2)).await);
+    assert!(!manager.subscribe("conn-1", "admin:1").await);
+    assert!(!manager.subscribe("conn-1", "project:abc").await);
+
+    // Unauthenticated connections cannot subscribe to anything.
+    assert!(!manager.subscribe("conn-2", "project:42").await);
+    assert!(!manager.subscribe("conn-2", &user_topic(1)).await);
+}
+
+#[test]
+fn inbound_control_messages_parse() {
+    assert_eq!(
+        InboundMessage::parse(r#"{"type":"subscribe","topic":"project:42"}"#),
+        Some(InboundMessage::Subscribe {
+            topic: "project:42".to_string()
+        })
+    );
+    assert_eq!(
+        InboundMessage::parse(r#"{"type":"unsubscribe","topic":"scene:1001"}"#),
+        Some(InboundMessage::Unsubscribe {
+            topic: "scene:1001".to_string()
+        })
+    );
+    assert_eq!(InboundMessage::parse(r#"{"type":"subscribe"}"#), None);
+    assert_eq!(InboundMessage::parse("not json"), None);
 }
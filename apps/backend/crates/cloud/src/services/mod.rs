@@ -27,6 +27,14 @@ impl ServiceNudge {
         (Self(Arc::clone(&notify)), notify)
     }
 
+    /// A handle not attached to any service; nudging it does nothing.
+    ///
+    /// For callers that build an `AppState` without running the services,
+    /// such as integration tests.
+    pub fn detached() -> Self {
+        Self::new().0
+    }
+
     /// Wake the service to run its next evaluation immediately.
     pub fn nudge(&self) {
         self.0.notify_one();
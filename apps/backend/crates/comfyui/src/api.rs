@@ -49,6 +49,8 @@ pub struct SystemInfo {
     pub os: Option<String>,
     pub python_version: Option<String>,
     pub embedded_python: Option<bool>,
+    /// Reported by ComfyUI releases that expose their version; absent on older servers.
+    pub comfyui_version: Option<String>,
 }
 
 /// Response from `POST /upload/image`.
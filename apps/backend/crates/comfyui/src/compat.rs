@@ -0,0 +1,141 @@
+//! Per-instance capability tracking and workflow compatibility checks.
+//!
+//! Each ComfyUI instance reports its version and the node types it has
+//! installed when it connects. A workflow can only run on an instance
+//! that provides every `class_type` the workflow references, so the
+//! dispatcher uses [`supported_candidates`] to skip jobs an instance cannot run.
+
+use std::collections::{BTreeSet, HashSet};
+
+use serde::Serialize;
+use x121_core::types::DbId;
+
+/// What a connected ComfyUI instance can execute.
+#[derive(Debug, Clone, Default)]
+pub struct InstanceCapabilities {
+    /// Version string from `GET /system_stats`, if the server reports one.
+    pub version: Option<String>,
+    /// Node class types from `GET /object_info`.
+    pub node_types: HashSet<String>,
+}
+
+impl InstanceCapabilities {
+    /// Node types referenced by `workflow` that this instance lacks, sorted.
+    pub fn missing_node_types(&self, workflow: &serde_json::Value) -> Vec<String> {
+        required_node_types(workflow)
+            .into_iter()
+            .filter(|class_type| !self.node_types.contains(class_type))
+            .collect()
+    }
+
+    /// Whether every node in `workflow` is available on this instance.
+    pub fn supports(&self, workflow: &serde_json::Value) -> bool {
+        required_node_types(workflow)
+            .iter()
+            .all(|class_type| self.node_types.contains(class_type))
+    }
+}
+
+/// Version summary for one managed instance, as exposed to admins.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceVersion {
+    pub instance_id: DbId,
+    pub connected: bool,
+    pub version: Option<String>,
+    /// Number of node types detected, or `None` if detection has not succeeded.
+    pub node_type_count: Option<usize>,
+}
+
+/// Collect the `class_type` of every node in an API-format workflow.
+///
+/// API-format workflows are objects keyed by node id, each value holding a
+/// `class_type`. Anything else contributes no requirements.
+pub fn required_node_types(workflow: &serde_json::Value) -> BTreeSet<String> {
+    workflow
+        .as_object()
+        .map(|nodes| {
+            nodes
+                .values()
+                .filter_map(|node| node.get("class_type")?.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Candidates the instance can run, in their original order.
+///
+/// Candidates are expected in dispatch order (priority, then age), so
+/// incompatible jobs are skipped without reordering the rest.
+pub fn supported_candidates<'a, T>(
+    capabilities: &'a InstanceCapabilities,
+    candidates: &'a [T],
+    workflow_of: impl Fn(&T) -> &serde_json::Value + 'a,
+) -> impl Iterator<Item = &'a T> + 'a {
+    candidates
+        .iter()
+        .filter(move |candidate| capabilities.supports(workflow_of(candidate)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn capabilities(node_types: &[&str]) -> InstanceCapabilities {
+        InstanceCapabilities {
+            version: Some("0.3.10".to_string()),
+            node_types: node_types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn workflow(class_types: &[&str]) -> serde_json::Value {
+        let nodes: serde_json::Map<String, serde_json::Value> = class_types
+            .iter()
+            .enumerate()
+            .map(|(i, ct)| (i.to_string(), json!({ "class_type": ct, "inputs": {} })))
+            .collect();
+        serde_json::Value::Object(nodes)
+    }
+
+    #[test]
+    fn required_node_types_deduplicates_and_ignores_malformed_nodes() {
+        let wf = json!({
+            "1": { "class_type": "KSampler" },
+            "2": { "class_type": "KSampler" },
+            "3": { "inputs": {} },
+            "4": "not a node",
+        });
+        let required = required_node_types(&wf);
+        assert_eq!(required.into_iter().collect::<Vec<_>>(), vec!["KSampler"]);
+        assert!(required_node_types(&json!([1, 2])).is_empty());
+    }
+
+    #[test]
+    fn missing_node_types_lists_absent_nodes() {
+        let caps = capabilities(&["KSampler", "SaveImage"]);
+        let wf = workflow(&["KSampler", "VHS_VideoCombine", "SaveImage"]);
+        assert_eq!(caps.missing_node_types(&wf), vec!["VHS_VideoCombine"]);
+        assert!(!caps.supports(&wf));
+    }
+
+    #[test]
+    fn workflow_needing_unavailable_node_is_not_assigned() {
+        let caps = capabilities(&["KSampler", "SaveImage"]);
+        let needs_video = (1, workflow(&["KSampler", "VHS_VideoCombine"]));
+        let plain = (2, workflow(&["KSampler", "SaveImage"]));
+
+        let queue = [needs_video, plain];
+        let assignable: Vec<i32> = supported_candidates(&caps, &queue, |(_, wf)| wf)
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(assignable, vec![2]);
+
+        let richer = capabilities(&["KSampler", "SaveImage", "VHS_VideoCombine"]);
+        let assignable: Vec<i32> = supported_candidates(&richer, &queue, |(_, wf)| wf)
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(assignable, vec![1, 2]);
+    }
+}
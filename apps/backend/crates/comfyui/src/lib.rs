@@ -6,6 +6,7 @@
 
 pub mod api;
 pub mod client;
+pub mod compat;
 pub mod events;
 pub mod manager;
 pub mod messages;
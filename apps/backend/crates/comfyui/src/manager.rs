@@ -22,6 +22,7 @@ use x121_events::ActivityLogBroadcaster;
 
 use crate::api::ComfyUIApi;
 use crate::client::ComfyUIClient;
+use crate::compat::{InstanceCapabilities, InstanceVersion};
 use crate::events::ComfyUIEvent;
 use crate::processor::process_messages;
 use crate::reconnect::{reconnect_loop, ReconnectConfig};
@@ -58,6 +59,9 @@ struct ManagedInstance {
     /// connection loop on each (re)connect. Workflow submissions must use this
     /// same client_id so ComfyUI routes messages back to our WebSocket listener.
     ws_client_id: Arc<std::sync::RwLock<String>>,
+    /// Version and node types detected on the most recent connect. `None`
+    /// until detection succeeds; cleared again when the connection drops.
+    capabilities: Arc<std::sync::RwLock<Option<Arc<InstanceCapabilities>>>>,
 }
 
 impl ComfyUIManager {
@@ -133,6 +137,51 @@ impl ComfyUIManager {
             .collect()
     }
 
+    /// Get the detected capabilities of a specific instance.
+    ///
+    /// Returns `None` if the instance is unknown or detection has not
+    /// completed, in which case callers should not restrict dispatch.
+    pub async fn instance_capabilities(
+        &self,
+        instance_id: DbId,
+    ) -> Option<Arc<InstanceCapabilities>> {
+        self.connections
+            .read()
+            .await
+            .get(&instance_id)
+            .and_then(|m| {
+                m.capabilities
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone()
+            })
+    }
+
+    /// Summarise the detected version of every managed instance.
+    pub async fn instance_versions(&self) -> Vec<InstanceVersion> {
+        let mut versions: Vec<InstanceVersion> = self
+            .connections
+            .read()
+            .await
+            .iter()
+            .map(|(id, m)| {
+                let caps = m
+                    .capabilities
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone();
+                InstanceVersion {
+                    instance_id: *id,
+                    connected: m.connected.load(Ordering::Relaxed),
+                    version: caps.as_ref().and_then(|c| c.version.clone()),
+                    node_type_count: caps.as_ref().map(|c| c.node_types.len()),
+                }
+            })
+            .collect();
+        versions.sort_by_key(|v| v.instance_id);
+        versions
+    }
+
     /// Get the API client for a specific instance.
     ///
     /// Returns `None` if the instance is not connected.
@@ -424,6 +473,8 @@ impl ComfyUIManager {
         let connected_clone = Arc::clone(&connected);
         let ws_client_id = Arc::new(std::sync::RwLock::new(String::new()));
         let ws_client_id_clone = Arc::clone(&ws_client_id);
+        let capabilities = Arc::new(std::sync::RwLock::new(None));
+        let capabilities_clone = Arc::clone(&capabilities);
 
         let activity_clone = self.activity.clone();
         let task_handle = tokio::spawn(async move {
@@ -438,6 +489,7 @@ impl ComfyUIManager {
                 activity_clone.as_deref(),
                 &connected_clone,
                 &ws_client_id_clone,
+                &capabilities_clone,
             )
             .await;
             tracing::info!(instance_id, "Connection task exited");
@@ -450,6 +502,7 @@ impl ComfyUIManager {
             cancel: instance_cancel,
             connected,
             ws_client_id,
+            capabilities,
         };
 
         self.connections.write().await.insert(instance_id, managed);
@@ -469,6 +522,7 @@ async fn run_connection_loop(
     activity: Option<&ActivityLogBroadcaster>,
     connected: &AtomicBool,
     shared_client_id: &std::sync::RwLock<String>,
+    capabilities: &std::sync::RwLock<Option<Arc<InstanceCapabilities>>>,
 ) {
     let reconnect_config = ReconnectConfig::default();
 
@@ -501,6 +555,20 @@ async fn run_connection_loop(
         }
         let _ = event_tx.send(ComfyUIEvent::InstanceConnected { instance_id });
 
+        // Detect version and installed nodes before any work is dispatched here.
+        let detected = detect_capabilities(instance_id, client).await;
+        if let Some(ref caps) = detected {
+            if let Err(e) =
+                ComfyUIInstanceRepo::record_version(pool, instance_id, caps.version.as_deref())
+                    .await
+            {
+                tracing::error!(instance_id, error = %e, "Failed to record ComfyUI version");
+            }
+        }
+        if let Ok(mut slot) = capabilities.write() {
+            *slot = detected.map(Arc::new);
+        }
+
         if let Some(broadcaster) = activity {
             broadcaster.publish(
                 ActivityLogEntry::curated(
@@ -528,6 +596,9 @@ async fn run_connection_loop(
             id.clear();
         }
         connected.store(false, Ordering::Relaxed);
+        if let Ok(mut slot) = capabilities.write() {
+            *slot = None;
+        }
         if let Err(e) = ComfyUIInstanceRepo::record_disconnection(pool, instance_id).await {
             tracing::error!(instance_id, error = %e, "Failed to record disconnection");
         }
@@ -561,6 +632,44 @@ async fn run_connection_loop(
     }
 }
 
+/// Query an instance's version and available node types.
+///
+/// Returns `None` if the node list cannot be fetched; a missing version
+/// alone is tolerated since older ComfyUI releases do not report one.
+async fn detect_capabilities(
+    instance_id: DbId,
+    client: &ComfyUIClient,
+) -> Option<InstanceCapabilities> {
+    let api = ComfyUIApi::new(client.api_url().to_string());
+
+    let version = match api.health_check().await {
+        Ok(stats) => stats.system.comfyui_version,
+        Err(e) => {
+            tracing::warn!(instance_id, error = %e, "Failed to query ComfyUI version");
+            None
+        }
+    };
+
+    match api.get_available_node_types().await {
+        Ok(node_types) => {
+            tracing::info!(
+                instance_id,
+                version = version.as_deref().unwrap_or("unknown"),
+                node_types = node_types.len(),
+                "Detected ComfyUI capabilities",
+            );
+            Some(InstanceCapabilities {
+                version,
+                node_types,
+            })
+        }
+        Err(e) => {
+            tracing::warn!(instance_id, error = %e, "Failed to query ComfyUI node types");
+            None
+        }
+    }
+}
+
 /// Check for executions that completed on ComfyUI while we were disconnected.
 ///
 /// Queries the database for any executions in "submitted" or "running" status
@@ -133,7 +133,7 @@ impl ActivityLogCategory {
 ///
 /// This is the in-memory representation used by [`ActivityLogBroadcaster`].
 /// It is converted to/from the database model by the persistence layer.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActivityLogEntry {
     pub timestamp: DateTime<Utc>,
     pub level: ActivityLogLevel,
@@ -1,9 +1,13 @@
 //! On-Frame Annotation & Markup constants and validation (PRD-70).
 //!
 //! Provides drawing tool types, validation helpers for frame annotations,
-//! and summary utilities used by the API and pipeline layers.
+//! summary utilities, and frame export serialization used by the API and
+//! pipeline layers.
+
+use std::str::FromStr;
 
 use crate::error::CoreError;
+use crate::types::DbId;
 use serde::Serialize;
 
 // Re-export `validate_frame_number` from storyboard so callers that imported
@@ -29,6 +33,12 @@ pub const MAX_TEXT_LENGTH: usize = 500;
 /// Maximum number of path points in a freehand pen stroke.
 pub const MAX_PATH_POINTS: usize = 5000;
 
+/// Default maximum number of annotation objects in a single frame export.
+///
+/// A frame can hold several users' annotation records, each with up to
+/// [`MAX_ANNOTATIONS_PER_FRAME`] objects.
+pub const DEFAULT_MAX_ANNOTATIONS_PER_EXPORT: usize = 500;
+
 // ---------------------------------------------------------------------------
 // Drawing tool types
 // ---------------------------------------------------------------------------
@@ -213,6 +223,214 @@ pub fn summarize_annotations(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Frame export
+// ---------------------------------------------------------------------------
+
+/// Output format for a frame annotation export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationExportFormat {
+    /// Flat array of drawing objects, for client-side compositing.
+    #[default]
+    Json,
+    /// COCO-style document with images, categories, and annotations.
+    Coco,
+}
+
+impl FromStr for AnnotationExportFormat {
+    type Err = CoreError;
+
+    /// Parse an export format from a string slice.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "coco" => Ok(Self::Coco),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid export format '{s}'. Must be one of: json, coco"
+            ))),
+        }
+    }
+}
+
+/// Number of frames in a segment of `duration_secs` at `fps`.
+pub fn segment_frame_count(duration_secs: f64, fps: i32) -> i64 {
+    (duration_secs.max(0.0) * fps as f64).round() as i64
+}
+
+/// Validate that `frame` exists in a segment with `frame_count` frames.
+///
+/// Negative frames are a validation error; frames at or past the end are
+/// `NotFound`. When the frame count is unknown only the sign is checked.
+pub fn validate_export_frame(frame: i32, frame_count: Option<i64>) -> Result<(), CoreError> {
+    validate_frame_number(frame)?;
+    match frame_count {
+        Some(count) if i64::from(frame) >= count => Err(CoreError::NotFound {
+            entity: "Frame",
+            id: DbId::from(frame),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Flatten annotation records into one list of drawing objects.
+///
+/// Records that are not arrays contribute nothing. At most `max` objects are
+/// kept; the flag reports whether any were dropped.
+pub fn collect_export_objects<'a>(
+    annotations: impl IntoIterator<Item = &'a serde_json::Value>,
+    max: usize,
+) -> (Vec<serde_json::Value>, bool) {
+    let mut objects = annotations
+        .into_iter()
+        .filter_map(|a| a.as_array())
+        .flatten();
+    let kept: Vec<serde_json::Value> = objects.by_ref().take(max).cloned().collect();
+    let truncated = objects.next().is_some();
+    (kept, truncated)
+}
+
+/// A COCO-style frame annotation export.
+#[derive(Debug, Clone, Serialize)]
+pub struct CocoExport {
+    pub info: CocoInfo,
+    pub images: Vec<CocoImage>,
+    pub categories: Vec<CocoCategory>,
+    pub annotations: Vec<CocoAnnotation>,
+}
+
+/// Provenance of a COCO export.
+#[derive(Debug, Clone, Serialize)]
+pub struct CocoInfo {
+    pub segment_id: DbId,
+    pub frame_number: i32,
+    /// Whether objects were dropped to respect the export cap.
+    pub truncated: bool,
+}
+
+/// The exported frame, as a COCO image.
+#[derive(Debug, Clone, Serialize)]
+pub struct CocoImage {
+    pub id: i32,
+    pub frame_number: i32,
+}
+
+/// A drawing tool, as a COCO category.
+#[derive(Debug, Clone, Serialize)]
+pub struct CocoCategory {
+    pub id: usize,
+    pub name: &'static str,
+}
+
+/// A drawing object, as a COCO annotation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CocoAnnotation {
+    pub id: usize,
+    pub image_id: i32,
+    pub category_id: Option<usize>,
+    /// `[x, y, width, height]`, when the object's geometry is known.
+    pub bbox: Option<[f64; 4]>,
+    /// The original drawing object.
+    pub attributes: serde_json::Value,
+}
+
+/// Tools in COCO category order; category ids are 1-based indices.
+const EXPORT_CATEGORIES: &[DrawingToolType] = &[
+    DrawingToolType::Pen,
+    DrawingToolType::Circle,
+    DrawingToolType::Rectangle,
+    DrawingToolType::Arrow,
+    DrawingToolType::Highlight,
+    DrawingToolType::Text,
+];
+
+/// Build a COCO-style export of a frame's drawing objects.
+///
+/// Every drawing tool is listed as a category. Objects whose tool is not
+/// recognised get no category.
+pub fn build_coco_export(
+    segment_id: DbId,
+    frame_number: i32,
+    objects: Vec<serde_json::Value>,
+    truncated: bool,
+) -> CocoExport {
+    let categories = EXPORT_CATEGORIES
+        .iter()
+        .enumerate()
+        .map(|(i, tool)| CocoCategory {
+            id: i + 1,
+            name: tool.as_str(),
+        })
+        .collect();
+
+    let annotations = objects
+        .into_iter()
+        .enumerate()
+        .map(|(i, object)| {
+            let category_id = object
+                .get("tool")
+                .and_then(|t| t.as_str())
+                .and_then(|t| EXPORT_CATEGORIES.iter().position(|c| c.as_str() == t))
+                .map(|pos| pos + 1);
+            CocoAnnotation {
+                id: i + 1,
+                image_id: frame_number,
+                category_id,
+                bbox: object.get("data").and_then(object_bbox),
+                attributes: object,
+            }
+        })
+        .collect();
+
+    CocoExport {
+        info: CocoInfo {
+            segment_id,
+            frame_number,
+            truncated,
+        },
+        images: vec![CocoImage {
+            id: frame_number,
+            frame_number,
+        }],
+        categories,
+        annotations,
+    }
+}
+
+/// Bounding box of a drawing object's `data`.
+///
+/// Handles path points (`points`), drag shapes (`startX`/`startY`/`endX`/
+/// `endY`), and anchored text (`x`/`y`, zero-sized).
+fn object_bbox(data: &serde_json::Value) -> Option<[f64; 4]> {
+    let num = |v: &serde_json::Value, key: &str| v.get(key).and_then(|n| n.as_f64());
+
+    let points: Vec<(f64, f64)> = if let Some(points) = data.get("points") {
+        points
+            .as_array()?
+            .iter()
+            .filter_map(|p| Some((num(p, "x")?, num(p, "y")?)))
+            .collect()
+    } else if let (Some(x1), Some(y1), Some(x2), Some(y2)) = (
+        num(data, "startX"),
+        num(data, "startY"),
+        num(data, "endX"),
+        num(data, "endY"),
+    ) {
+        vec![(x1, y1), (x2, y2)]
+    } else {
+        vec![(num(data, "x")?, num(data, "y")?)]
+    };
+
+    let (first_x, first_y) = *points.first()?;
+    let (min_x, min_y, max_x, max_y) = points.iter().fold(
+        (first_x, first_y, first_x, first_y),
+        |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    );
+    Some([min_x, min_y, max_x - min_x, max_y - min_y])
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -456,4 +674,120 @@ mod tests {
         assert_eq!(entry.tool_count, 0);
         assert!(!entry.has_text);
     }
+
+    // -- Frame export ------------------------------------------------------
+
+    fn frame_records() -> Vec<serde_json::Value> {
+        vec![
+            json!([
+                {"tool": "pen", "data": {"points": [{"x": 10, "y": 40}, {"x": 30, "y": 20}]}, "color": "#FF0000", "strokeWidth": 2},
+                {"tool": "rectangle", "data": {"startX": 50, "startY": 60, "endX": 20, "endY": 10}, "color": "#00FF00", "strokeWidth": 1}
+            ]),
+            json!([
+                {"tool": "text", "data": {"x": 5, "y": 6, "content": "fix hand"}, "color": "#000000", "strokeWidth": 0}
+            ]),
+        ]
+    }
+
+    #[test]
+    fn export_format_parses() {
+        assert_eq!(
+            AnnotationExportFormat::from_str("json").unwrap(),
+            AnnotationExportFormat::Json
+        );
+        assert_eq!(
+            AnnotationExportFormat::from_str("coco").unwrap(),
+            AnnotationExportFormat::Coco
+        );
+        assert!(AnnotationExportFormat::from_str("xml").is_err());
+        assert_eq!(
+            AnnotationExportFormat::default(),
+            AnnotationExportFormat::Json
+        );
+    }
+
+    #[test]
+    fn export_frame_range_is_checked() {
+        let count = Some(segment_frame_count(2.0, 30));
+        assert_eq!(count, Some(60));
+        assert!(validate_export_frame(0, count).is_ok());
+        assert!(validate_export_frame(59, count).is_ok());
+        assert!(matches!(
+            validate_export_frame(60, count),
+            Err(CoreError::NotFound {
+                entity: "Frame",
+                id: 60
+            })
+        ));
+        assert!(matches!(
+            validate_export_frame(-1, count),
+            Err(CoreError::Validation(_))
+        ));
+        assert!(validate_export_frame(10_000, None).is_ok());
+    }
+
+    #[test]
+    fn json_export_is_flat_object_list() {
+        let records = frame_records();
+        let (objects, truncated) = collect_export_objects(&records, 10);
+        assert!(!truncated);
+        let tools: Vec<&str> = objects
+            .iter()
+            .map(|o| o["tool"].as_str().unwrap())
+            .collect();
+        assert_eq!(tools, vec!["pen", "rectangle", "text"]);
+        assert_eq!(objects[2]["data"]["content"], "fix hand");
+    }
+
+    #[test]
+    fn export_is_capped() {
+        let records = frame_records();
+        let (objects, truncated) = collect_export_objects(&records, 2);
+        assert_eq!(objects.len(), 2);
+        assert!(truncated);
+
+        let (objects, truncated) = collect_export_objects(&records, 3);
+        assert_eq!(objects.len(), 3);
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn coco_export_shape() {
+        let records = frame_records();
+        let (objects, truncated) = collect_export_objects(&records, 10);
+        let export = serde_json::to_value(build_coco_export(7, 12, objects, truncated)).unwrap();
+
+        assert_eq!(
+            export["info"],
+            json!({"segment_id": 7, "frame_number": 12, "truncated": false})
+        );
+        assert_eq!(export["images"], json!([{"id": 12, "frame_number": 12}]));
+        assert_eq!(export["categories"].as_array().unwrap().len(), 6);
+        assert_eq!(export["categories"][0], json!({"id": 1, "name": "pen"}));
+
+        let annotations = export["annotations"].as_array().unwrap();
+        assert_eq!(annotations.len(), 3);
+        assert_eq!(annotations[0]["id"], 1);
+        assert_eq!(annotations[0]["image_id"], 12);
+        assert_eq!(annotations[0]["category_id"], 1);
+        assert_eq!(annotations[0]["bbox"], json!([10.0, 20.0, 20.0, 20.0]));
+        assert_eq!(annotations[1]["category_id"], 3);
+        assert_eq!(annotations[1]["bbox"], json!([20.0, 10.0, 30.0, 50.0]));
+        assert_eq!(annotations[2]["category_id"], 6);
+        assert_eq!(annotations[2]["bbox"], json!([5.0, 6.0, 0.0, 0.0]));
+        assert_eq!(annotations[2]["attributes"]["color"], "#000000");
+    }
+
+    #[test]
+    fn coco_export_tolerates_unknown_objects() {
+        let export = build_coco_export(
+            1,
+            0,
+            vec![json!({"tool": "eraser", "data": {"radius": 3}})],
+            true,
+        );
+        assert!(export.info.truncated);
+        assert_eq!(export.annotations[0].category_id, None);
+        assert_eq!(export.annotations[0].bbox, None);
+    }
 }
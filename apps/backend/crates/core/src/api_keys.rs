@@ -1,12 +1,17 @@
-//! API key generation, hashing, and webhook HMAC signing utilities (PRD-12).
+//! API key generation, hashing, webhook HMAC signing, webhook circuit
+//! breaking, and delivery-history outcome utilities (PRD-12).
 //!
 //! This module lives in `core` (zero internal deps) so it can be used by both
 //! the API/repository layer and any future worker or CLI tooling.
 
+use std::str::FromStr;
+
 use hmac::{Hmac, Mac};
 use rand::Rng;
 use sha2::Sha256;
 
+use crate::error::CoreError;
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -29,6 +34,12 @@ pub const MAX_WEBHOOK_DELIVERY_ATTEMPTS: i16 = 3;
 /// Maximum backoff delay in seconds for webhook retries.
 pub const MAX_WEBHOOK_BACKOFF_SECS: i64 = 3600;
 
+/// Consecutive permanently failed deliveries before a webhook is suspended.
+pub const DEFAULT_WEBHOOK_FAILURE_THRESHOLD: i32 = 10;
+
+/// Seconds between recovery probes while a webhook is suspended.
+pub const DEFAULT_WEBHOOK_PROBE_INTERVAL_SECS: i64 = 300;
+
 // ---------------------------------------------------------------------------
 // Scope name constants
 // ---------------------------------------------------------------------------
@@ -99,6 +110,13 @@ pub fn extract_prefix(key: &str) -> &str {
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Request header carrying the webhook signature, `sha256=<hex>`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Trulience-Signature";
+
+/// Request header carrying the Unix timestamp (seconds) covered by the
+/// signature.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Trulience-Timestamp";
+
 /// Compute an HMAC-SHA256 signature for a webhook payload.
 ///
 /// The `secret` is the webhook-specific signing secret. The `payload` is the
@@ -111,6 +129,18 @@ pub fn compute_webhook_hmac(secret: &str, payload: &str) -> String {
     hex::encode(result.into_bytes())
 }
 
+/// Compute the [`WEBHOOK_SIGNATURE_HEADER`] value for a webhook request.
+///
+/// The signing string is `"{timestamp}.{body}"`: the decimal Unix timestamp
+/// sent in [`WEBHOOK_TIMESTAMP_HEADER`], a `.`, then the exact request body.
+/// Receivers recompute the HMAC-SHA256 with the shared secret, compare it in
+/// constant time, and reject stale timestamps to guard against replay.
+/// Returns `sha256=<hex>`.
+pub fn sign_webhook_request(secret: &str, timestamp: i64, body: &str) -> String {
+    let signing_string = format!("{timestamp}.{body}");
+    format!("sha256={}", compute_webhook_hmac(secret, &signing_string))
+}
+
 // ---------------------------------------------------------------------------
 // hex encoding helper (no extra dep)
 // ---------------------------------------------------------------------------
@@ -133,6 +163,136 @@ pub fn webhook_retry_delay_secs(attempt: i16) -> i64 {
     2i64.pow(attempt as u32).min(MAX_WEBHOOK_BACKOFF_SECS)
 }
 
+// ---------------------------------------------------------------------------
+// Webhook circuit breaking
+// ---------------------------------------------------------------------------
+
+/// Circuit state for a webhook endpoint.
+///
+/// `Active` webhooks receive deliveries. `Suspended` webhooks receive nothing
+/// except periodic recovery probes until a probe succeeds or an admin
+/// re-enables them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookCircuitState {
+    Active,
+    Suspended,
+}
+
+impl WebhookCircuitState {
+    /// Database-stored string representation.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+        }
+    }
+}
+
+impl FromStr for WebhookCircuitState {
+    type Err = CoreError;
+
+    /// Parse from database string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Self::Active),
+            "suspended" => Ok(Self::Suspended),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid webhook circuit state '{s}'. Must be one of: active, suspended"
+            ))),
+        }
+    }
+}
+
+/// Delivery outcome used to filter a webhook's delivery history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// Delivered with a 2xx response.
+    Success,
+    /// Retries exhausted without a 2xx response.
+    Failed,
+    /// Failed at least once and scheduled for another attempt.
+    Retrying,
+    /// Retries exhausted and moved to the dead-letter queue for replay.
+    DeadLettered,
+}
+
+impl DeliveryOutcome {
+    /// Parse a query-string value (`success`, `failed`, `retrying`,
+    /// `dead_lettered`).
+    pub fn parse(s: &str) -> Result<Self, CoreError> {
+        match s {
+            "success" => Ok(Self::Success),
+            "failed" => Ok(Self::Failed),
+            "retrying" => Ok(Self::Retrying),
+            "dead_lettered" => Ok(Self::DeadLettered),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid delivery outcome '{s}'. \
+                 Must be one of: success, failed, retrying, dead_lettered"
+            ))),
+        }
+    }
+
+    /// The `webhook_deliveries.status` value for this outcome.
+    pub fn delivery_status(self) -> &'static str {
+        match self {
+            Self::Success => "delivered",
+            Self::Failed => "failed",
+            Self::Retrying => "retrying",
+            Self::DeadLettered => "dead_lettered",
+        }
+    }
+}
+
+/// Outcome of a delivery or probe that counts towards the circuit.
+///
+/// Only permanent failures (retries exhausted, or a failed probe) count;
+/// individual retryable attempts do not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookOutcome {
+    Delivered,
+    PermanentFailure,
+}
+
+/// Circuit state and failure streak after applying an outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookCircuit {
+    pub state: WebhookCircuitState,
+    pub consecutive_failures: i32,
+}
+
+impl WebhookCircuit {
+    /// Apply a delivery outcome.
+    ///
+    /// Any success closes the circuit and clears the streak. A permanent
+    /// failure extends the streak and suspends the webhook once it reaches
+    /// `threshold` (values below 1 are treated as 1).
+    pub fn record(self, outcome: WebhookOutcome, threshold: i32) -> Self {
+        match outcome {
+            WebhookOutcome::Delivered => Self {
+                state: WebhookCircuitState::Active,
+                consecutive_failures: 0,
+            },
+            WebhookOutcome::PermanentFailure => {
+                let consecutive_failures = self.consecutive_failures.saturating_add(1);
+                let state = if consecutive_failures >= threshold.max(1) {
+                    WebhookCircuitState::Suspended
+                } else {
+                    self.state
+                };
+                Self {
+                    state,
+                    consecutive_failures,
+                }
+            }
+        }
+    }
+
+    /// Whether this transition moved the webhook from active to suspended.
+    pub fn tripped_from(self, previous: WebhookCircuitState) -> bool {
+        previous == WebhookCircuitState::Active && self.state == WebhookCircuitState::Suspended
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -249,6 +409,28 @@ mod tests {
         assert_ne!(a, b);
     }
 
+    #[test]
+    fn request_signature_matches_known_value() {
+        let sig = sign_webhook_request(
+            "whsec_test",
+            1_700_000_000,
+            r#"{"event_type":"job.completed"}"#,
+        );
+        assert_eq!(
+            sig,
+            "sha256=dedeabdfa7ef530c8ff5b4a634497ae0006ecf48139920d6bcf06d1e31dabdc2"
+        );
+    }
+
+    #[test]
+    fn request_signature_covers_timestamp() {
+        let body = r#"{"event_type":"job.completed"}"#;
+        assert_ne!(
+            sign_webhook_request("whsec_test", 1_700_000_000, body),
+            sign_webhook_request("whsec_test", 1_700_000_001, body)
+        );
+    }
+
     // -- Backoff computation -----------------------------------------------
 
     #[test]
@@ -263,4 +445,89 @@ mod tests {
     fn backoff_is_capped() {
         assert_eq!(webhook_retry_delay_secs(20), MAX_WEBHOOK_BACKOFF_SECS);
     }
+
+    // -- Delivery outcomes -------------------------------------------------
+
+    #[test]
+    fn delivery_outcome_maps_to_status() {
+        let statuses: Vec<&str> = ["success", "failed", "retrying", "dead_lettered"]
+            .iter()
+            .map(|s| DeliveryOutcome::parse(s).unwrap().delivery_status())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec!["delivered", "failed", "retrying", "dead_lettered"]
+        );
+        assert!(DeliveryOutcome::parse("pending").is_err());
+    }
+
+    // -- Circuit breaking --------------------------------------------------
+
+    fn active() -> WebhookCircuit {
+        WebhookCircuit {
+            state: WebhookCircuitState::Active,
+            consecutive_failures: 0,
+        }
+    }
+
+    #[test]
+    fn consecutive_failures_suspend_at_threshold() {
+        let mut circuit = active();
+        for _ in 0..2 {
+            circuit = circuit.record(WebhookOutcome::PermanentFailure, 3);
+            assert_eq!(circuit.state, WebhookCircuitState::Active);
+        }
+
+        let tripped = circuit.record(WebhookOutcome::PermanentFailure, 3);
+        assert_eq!(tripped.state, WebhookCircuitState::Suspended);
+        assert_eq!(tripped.consecutive_failures, 3);
+        assert!(tripped.tripped_from(circuit.state));
+    }
+
+    #[test]
+    fn success_resets_streak() {
+        let circuit = active()
+            .record(WebhookOutcome::PermanentFailure, 3)
+            .record(WebhookOutcome::PermanentFailure, 3)
+            .record(WebhookOutcome::Delivered, 3)
+            .record(WebhookOutcome::PermanentFailure, 3);
+        assert_eq!(circuit.state, WebhookCircuitState::Active);
+        assert_eq!(circuit.consecutive_failures, 1);
+    }
+
+    #[test]
+    fn failed_probe_stays_suspended_without_retripping() {
+        let suspended = active().record(WebhookOutcome::PermanentFailure, 1);
+        let after_probe = suspended.record(WebhookOutcome::PermanentFailure, 1);
+        assert_eq!(after_probe.state, WebhookCircuitState::Suspended);
+        assert!(!after_probe.tripped_from(suspended.state));
+    }
+
+    #[test]
+    fn successful_probe_reenables() {
+        let suspended = active()
+            .record(WebhookOutcome::PermanentFailure, 2)
+            .record(WebhookOutcome::PermanentFailure, 2);
+        assert_eq!(suspended.state, WebhookCircuitState::Suspended);
+
+        let recovered = suspended.record(WebhookOutcome::Delivered, 2);
+        assert_eq!(recovered, active());
+    }
+
+    #[test]
+    fn non_positive_threshold_trips_on_first_failure() {
+        let circuit = active().record(WebhookOutcome::PermanentFailure, 0);
+        assert_eq!(circuit.state, WebhookCircuitState::Suspended);
+    }
+
+    #[test]
+    fn circuit_state_round_trips() {
+        for state in [WebhookCircuitState::Active, WebhookCircuitState::Suspended] {
+            assert_eq!(
+                state.as_str().parse::<WebhookCircuitState>().ok(),
+                Some(state)
+            );
+        }
+        assert!("open".parse::<WebhookCircuitState>().is_err());
+    }
 }
@@ -1,7 +1,8 @@
 //! Scene assembly & delivery packaging constants and validators (PRD-39).
 //!
 //! Provides status constants, format validators, resolution parsing,
-//! and concat strategy determination for the delivery export pipeline.
+//! concat strategy determination, effective watermark resolution, and the
+//! pure assembly readiness validator for the delivery export pipeline.
 
 use serde::Serialize;
 
@@ -96,6 +97,80 @@ pub const VALID_WATERMARK_POSITIONS: &[&str] = &[
     "bottom_right",
 ];
 
+/// Watermark parameters applied to a delivery.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WatermarkSpec {
+    pub name: String,
+    pub watermark_type: String,
+    pub content: String,
+    pub position: String,
+    pub opacity: f32,
+    pub include_timecode: bool,
+}
+
+/// A watermark override at one level of the studio → profile → project
+/// hierarchy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum WatermarkOverride {
+    /// Defer to the level above.
+    #[default]
+    Inherit,
+    /// Turn the watermark off at this level.
+    Disabled,
+    /// Use this watermark at this level.
+    Enabled(WatermarkSpec),
+}
+
+/// The level a resolved watermark decision came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatermarkSource {
+    Studio,
+    Profile,
+    Project,
+}
+
+/// The watermark a delivery should carry after applying overrides.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EffectiveWatermark {
+    /// The watermark to apply, or `None` for no watermark.
+    pub watermark: Option<WatermarkSpec>,
+    /// Level that decided the outcome; `None` when no level configured one.
+    pub source: Option<WatermarkSource>,
+}
+
+/// Resolve the effective watermark for a delivery.
+///
+/// The most specific level that does not inherit wins: project, then
+/// output profile, then the studio default.
+pub fn resolve_watermark(
+    studio_default: Option<&WatermarkSpec>,
+    profile_override: &WatermarkOverride,
+    project_override: &WatermarkOverride,
+) -> EffectiveWatermark {
+    let decided = [
+        (WatermarkSource::Project, project_override),
+        (WatermarkSource::Profile, profile_override),
+    ]
+    .into_iter()
+    .find(|(_, o)| !matches!(o, WatermarkOverride::Inherit));
+
+    match decided {
+        Some((source, WatermarkOverride::Enabled(spec))) => EffectiveWatermark {
+            watermark: Some(spec.clone()),
+            source: Some(source),
+        },
+        Some((source, _)) => EffectiveWatermark {
+            watermark: None,
+            source: Some(source),
+        },
+        None => EffectiveWatermark {
+            watermark: studio_default.cloned(),
+            source: studio_default.map(|_| WatermarkSource::Studio),
+        },
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Validation issue types
 // ---------------------------------------------------------------------------
@@ -324,6 +399,245 @@ pub fn determine_concat_strategy(
     ConcatStrategy::StreamCopy
 }
 
+// ---------------------------------------------------------------------------
+// Assembly readiness
+// ---------------------------------------------------------------------------
+
+/// Containers each codec can be muxed into.
+const CODEC_CONTAINERS: &[(&str, &[&str])] = &[
+    ("h264", &["mp4", "mov", "mkv"]),
+    ("h265", &["mp4", "mov", "mkv"]),
+    ("hevc", &["mp4", "mov", "mkv"]),
+    ("mpeg4", &["mp4", "mov", "mkv"]),
+    ("prores", &["mov", "mkv"]),
+    ("vp9", &["webm", "mkv", "mp4"]),
+    ("av1", &["webm", "mkv", "mp4"]),
+];
+
+/// Whether `codec` can be muxed into `container`. Unknown codecs are
+/// never compatible.
+pub fn codec_fits_container(codec: &str, container: &str) -> bool {
+    CODEC_CONTAINERS
+        .iter()
+        .find(|(c, _)| *c == codec)
+        .is_some_and(|(_, containers)| containers.contains(&container))
+}
+
+/// Kind of problem that can prevent or degrade a delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryIssueKind {
+    /// A scene has no finalized video version.
+    MissingFinalVersion,
+    /// A scene's final version was rejected in QA review.
+    FailedQaGate,
+    /// A watermark was requested but no usable watermark setting exists.
+    MissingWatermarkSettings,
+    /// The output profile cannot be produced from the project's clips.
+    IncompatibleProfile,
+}
+
+impl DeliveryIssueKind {
+    /// Category string reported in validation responses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MissingFinalVersion => "missing_final_video",
+            Self::FailedQaGate => "qa_rejected",
+            Self::MissingWatermarkSettings => "missing_watermark",
+            Self::IncompatibleProfile => "incompatible_profile",
+        }
+    }
+}
+
+/// A single assembly readiness problem.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeliveryIssue {
+    pub kind: DeliveryIssueKind,
+    pub severity: IssueSeverity,
+    pub message: String,
+    pub entity_id: Option<DbId>,
+}
+
+impl DeliveryIssue {
+    /// Whether this issue must be resolved before assembly can start.
+    pub fn is_blocking(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl From<DeliveryIssue> for ValidationIssue {
+    fn from(issue: DeliveryIssue) -> Self {
+        Self {
+            severity: issue.severity,
+            category: issue.kind.as_str().to_string(),
+            message: issue.message,
+            entity_id: issue.entity_id,
+        }
+    }
+}
+
+/// The finalized video version of a scene, as relevant to delivery.
+#[derive(Debug, Clone)]
+pub struct DeliveryFinalVersion {
+    pub version_id: DbId,
+    pub version_number: i32,
+    /// Clip QA status (`pending`, `approved`, `rejected`).
+    pub qa_status: String,
+    pub video_codec: Option<String>,
+}
+
+/// A scene to be delivered.
+#[derive(Debug, Clone)]
+pub struct DeliveryScene {
+    pub scene_id: DbId,
+    /// Display name of the avatar the scene belongs to.
+    pub model_name: String,
+    /// `None` when the scene has no usable final version.
+    pub final_version: Option<DeliveryFinalVersion>,
+}
+
+/// Everything about a project that assembly readiness depends on.
+#[derive(Debug, Clone, Default)]
+pub struct DeliveryProjectState {
+    pub scenes: Vec<DeliveryScene>,
+    pub watermark_requested: bool,
+    /// Whether at least one watermark setting with content exists.
+    pub watermark_configured: bool,
+}
+
+/// The output format profile a delivery would be assembled with.
+#[derive(Debug, Clone)]
+pub struct DeliveryProfile {
+    pub name: String,
+    pub resolution: String,
+    pub codec: String,
+    pub container: String,
+    pub pixel_format: Option<String>,
+    pub is_passthrough: bool,
+}
+
+/// Check whether a project can be assembled with `profile`.
+///
+/// Returns every issue found rather than stopping at the first; issues for
+/// which [`DeliveryIssue::is_blocking`] is true must prevent assembly.
+pub fn validate_delivery(
+    project: &DeliveryProjectState,
+    profile: &DeliveryProfile,
+) -> Vec<DeliveryIssue> {
+    let mut issues = validate_delivery_content(project);
+    issues.extend(validate_output_profile(project, profile));
+    issues
+}
+
+/// Profile-independent readiness checks: final versions, QA, and watermark.
+pub fn validate_delivery_content(project: &DeliveryProjectState) -> Vec<DeliveryIssue> {
+    let mut issues = Vec::new();
+
+    for scene in &project.scenes {
+        let Some(version) = &scene.final_version else {
+            issues.push(DeliveryIssue {
+                kind: DeliveryIssueKind::MissingFinalVersion,
+                severity: IssueSeverity::Error,
+                message: format!(
+                    "Model '{}' — scene {} has no finalized video version",
+                    scene.model_name, scene.scene_id
+                ),
+                entity_id: Some(scene.scene_id),
+            });
+            continue;
+        };
+        if version.qa_status == crate::clip_qa::CLIP_QA_REJECTED {
+            issues.push(DeliveryIssue {
+                kind: DeliveryIssueKind::FailedQaGate,
+                severity: IssueSeverity::Error,
+                message: format!(
+                    "Model '{}' — scene {} final v{} was rejected in QA",
+                    scene.model_name, scene.scene_id, version.version_number
+                ),
+                entity_id: Some(version.version_id),
+            });
+        }
+    }
+
+    if project.watermark_requested && !project.watermark_configured {
+        issues.push(DeliveryIssue {
+            kind: DeliveryIssueKind::MissingWatermarkSettings,
+            severity: IssueSeverity::Error,
+            message: "Watermark requested but no watermark setting is configured".to_string(),
+            entity_id: None,
+        });
+    }
+
+    issues
+}
+
+/// Check that `profile` is well-formed and can be produced from the
+/// project's final clips.
+pub fn validate_output_profile(
+    project: &DeliveryProjectState,
+    profile: &DeliveryProfile,
+) -> Vec<DeliveryIssue> {
+    let incompatible = |message: String, entity_id: Option<DbId>| DeliveryIssue {
+        kind: DeliveryIssueKind::IncompatibleProfile,
+        severity: IssueSeverity::Error,
+        message,
+        entity_id,
+    };
+    let mut issues = Vec::new();
+
+    for check in [
+        validate_codec(&profile.codec),
+        validate_container(&profile.container),
+        validate_resolution_str(&profile.resolution),
+    ]
+    .into_iter()
+    .chain(profile.pixel_format.as_deref().map(validate_pixel_format))
+    {
+        if let Err(e) = check {
+            issues.push(incompatible(
+                format!("Profile '{}': {e}", profile.name),
+                None,
+            ));
+        }
+    }
+
+    if profile.is_passthrough {
+        // Passthrough stream-copies clips, so each clip's own codec must fit
+        // the profile container.
+        for scene in &project.scenes {
+            let Some(version) = &scene.final_version else {
+                continue;
+            };
+            let Some(codec) = version.video_codec.as_deref() else {
+                continue;
+            };
+            if !codec_fits_container(codec, &profile.container) {
+                issues.push(incompatible(
+                    format!(
+                        "Model '{}' — scene {} uses {codec}, which passthrough profile '{}' \
+                         cannot place in a {} container",
+                        scene.model_name, scene.scene_id, profile.name, profile.container
+                    ),
+                    Some(version.version_id),
+                ));
+            }
+        }
+    } else if VALID_CODECS.contains(&profile.codec.as_str())
+        && VALID_CONTAINERS.contains(&profile.container.as_str())
+        && !codec_fits_container(&profile.codec, &profile.container)
+    {
+        issues.push(incompatible(
+            format!(
+                "Profile '{}': codec {} cannot be placed in a {} container",
+                profile.name, profile.codec, profile.container
+            ),
+            None,
+        ));
+    }
+
+    issues
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -549,4 +863,267 @@ mod tests {
         assert_eq!(result.errors.len(), 1);
         assert_eq!(result.warnings.len(), 1);
     }
+
+    // -- Assembly readiness --
+
+    fn approved_scene(scene_id: DbId, codec: &str) -> DeliveryScene {
+        DeliveryScene {
+            scene_id,
+            model_name: "Alice".to_string(),
+            final_version: Some(DeliveryFinalVersion {
+                version_id: scene_id * 10,
+                version_number: 1,
+                qa_status: "approved".to_string(),
+                video_codec: Some(codec.to_string()),
+            }),
+        }
+    }
+
+    fn h264_profile() -> DeliveryProfile {
+        DeliveryProfile {
+            name: "Web".to_string(),
+            resolution: "1920x1080".to_string(),
+            codec: "h264".to_string(),
+            container: "mp4".to_string(),
+            pixel_format: Some("yuv420p".to_string()),
+            is_passthrough: false,
+        }
+    }
+
+    fn kinds(issues: &[DeliveryIssue]) -> Vec<DeliveryIssueKind> {
+        issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn delivery_clean_pass() {
+        let project = DeliveryProjectState {
+            scenes: vec![approved_scene(1, "h264"), approved_scene(2, "hevc")],
+            watermark_requested: true,
+            watermark_configured: true,
+        };
+        assert!(validate_delivery(&project, &h264_profile()).is_empty());
+    }
+
+    #[test]
+    fn delivery_missing_final_version() {
+        let project = DeliveryProjectState {
+            scenes: vec![DeliveryScene {
+                scene_id: 7,
+                model_name: "Alice".to_string(),
+                final_version: None,
+            }],
+            ..Default::default()
+        };
+        let issues = validate_delivery(&project, &h264_profile());
+        assert_eq!(kinds(&issues), vec![DeliveryIssueKind::MissingFinalVersion]);
+        assert_eq!(issues[0].entity_id, Some(7));
+        assert!(issues[0].is_blocking());
+    }
+
+    #[test]
+    fn delivery_failed_qa_gate() {
+        let mut scene = approved_scene(3, "h264");
+        scene.final_version.as_mut().unwrap().qa_status = "rejected".to_string();
+        let project = DeliveryProjectState {
+            scenes: vec![scene],
+            ..Default::default()
+        };
+        let issues = validate_delivery(&project, &h264_profile());
+        assert_eq!(kinds(&issues), vec![DeliveryIssueKind::FailedQaGate]);
+        assert_eq!(issues[0].entity_id, Some(30));
+    }
+
+    #[test]
+    fn delivery_pending_qa_is_not_an_issue() {
+        let mut scene = approved_scene(3, "h264");
+        scene.final_version.as_mut().unwrap().qa_status = "pending".to_string();
+        let project = DeliveryProjectState {
+            scenes: vec![scene],
+            ..Default::default()
+        };
+        assert!(validate_delivery(&project, &h264_profile()).is_empty());
+    }
+
+    #[test]
+    fn delivery_missing_watermark_settings() {
+        let project = DeliveryProjectState {
+            scenes: vec![approved_scene(1, "h264")],
+            watermark_requested: true,
+            watermark_configured: false,
+        };
+        let issues = validate_delivery(&project, &h264_profile());
+        assert_eq!(
+            kinds(&issues),
+            vec![DeliveryIssueKind::MissingWatermarkSettings]
+        );
+    }
+
+    #[test]
+    fn delivery_incompatible_codec_container() {
+        let profile = DeliveryProfile {
+            codec: "prores".to_string(),
+            container: "webm".to_string(),
+            ..h264_profile()
+        };
+        let project = DeliveryProjectState {
+            scenes: vec![approved_scene(1, "h264")],
+            ..Default::default()
+        };
+        let issues = validate_delivery(&project, &profile);
+        assert_eq!(kinds(&issues), vec![DeliveryIssueKind::IncompatibleProfile]);
+    }
+
+    #[test]
+    fn delivery_invalid_profile_fields() {
+        let profile = DeliveryProfile {
+            resolution: "wide".to_string(),
+            pixel_format: Some("cmyk".to_string()),
+            ..h264_profile()
+        };
+        let issues = validate_delivery(&DeliveryProjectState::default(), &profile);
+        assert_eq!(
+            kinds(&issues),
+            vec![
+                DeliveryIssueKind::IncompatibleProfile,
+                DeliveryIssueKind::IncompatibleProfile
+            ]
+        );
+    }
+
+    #[test]
+    fn delivery_passthrough_checks_clip_codecs() {
+        let profile = DeliveryProfile {
+            container: "webm".to_string(),
+            codec: "vp9".to_string(),
+            is_passthrough: true,
+            ..h264_profile()
+        };
+        let project = DeliveryProjectState {
+            scenes: vec![approved_scene(1, "vp9"), approved_scene(2, "h264")],
+            ..Default::default()
+        };
+        let issues = validate_delivery(&project, &profile);
+        assert_eq!(kinds(&issues), vec![DeliveryIssueKind::IncompatibleProfile]);
+        assert_eq!(issues[0].entity_id, Some(20));
+    }
+
+    #[test]
+    fn delivery_reports_all_issues() {
+        let mut rejected = approved_scene(2, "h264");
+        rejected.final_version.as_mut().unwrap().qa_status = "rejected".to_string();
+        let project = DeliveryProjectState {
+            scenes: vec![
+                DeliveryScene {
+                    scene_id: 1,
+                    model_name: "Bob".to_string(),
+                    final_version: None,
+                },
+                rejected,
+            ],
+            watermark_requested: true,
+            watermark_configured: false,
+        };
+        let profile = DeliveryProfile {
+            container: "webm".to_string(),
+            ..h264_profile()
+        };
+        let issues = validate_delivery(&project, &profile);
+        assert_eq!(
+            kinds(&issues),
+            vec![
+                DeliveryIssueKind::MissingFinalVersion,
+                DeliveryIssueKind::FailedQaGate,
+                DeliveryIssueKind::MissingWatermarkSettings,
+                DeliveryIssueKind::IncompatibleProfile,
+            ]
+        );
+        assert!(issues.iter().all(DeliveryIssue::is_blocking));
+    }
+
+    // -- resolve_watermark ---------------------------------------------------
+
+    fn spec(name: &str) -> WatermarkSpec {
+        WatermarkSpec {
+            name: name.to_string(),
+            watermark_type: WATERMARK_TYPE_TEXT.to_string(),
+            content: format!("{name} preview"),
+            position: "bottom_right".to_string(),
+            opacity: 0.5,
+            include_timecode: false,
+        }
+    }
+
+    #[test]
+    fn watermark_studio_default_applies_when_nothing_overrides() {
+        let studio = spec("studio");
+        let resolved = resolve_watermark(
+            Some(&studio),
+            &WatermarkOverride::Inherit,
+            &WatermarkOverride::Inherit,
+        );
+        assert_eq!(resolved.watermark, Some(studio));
+        assert_eq!(resolved.source, Some(WatermarkSource::Studio));
+    }
+
+    #[test]
+    fn watermark_profile_override_beats_studio() {
+        let resolved = resolve_watermark(
+            Some(&spec("studio")),
+            &WatermarkOverride::Enabled(spec("profile")),
+            &WatermarkOverride::Inherit,
+        );
+        assert_eq!(resolved.watermark, Some(spec("profile")));
+        assert_eq!(resolved.source, Some(WatermarkSource::Profile));
+    }
+
+    #[test]
+    fn watermark_project_override_beats_profile() {
+        let resolved = resolve_watermark(
+            Some(&spec("studio")),
+            &WatermarkOverride::Enabled(spec("profile")),
+            &WatermarkOverride::Enabled(spec("project")),
+        );
+        assert_eq!(resolved.watermark, Some(spec("project")));
+        assert_eq!(resolved.source, Some(WatermarkSource::Project));
+    }
+
+    #[test]
+    fn watermark_project_can_disable_profile_watermark() {
+        let resolved = resolve_watermark(
+            Some(&spec("studio")),
+            &WatermarkOverride::Enabled(spec("profile")),
+            &WatermarkOverride::Disabled,
+        );
+        assert_eq!(resolved.watermark, None);
+        assert_eq!(resolved.source, Some(WatermarkSource::Project));
+    }
+
+    #[test]
+    fn watermark_profile_can_reenable_over_studio() {
+        let resolved = resolve_watermark(
+            None,
+            &WatermarkOverride::Enabled(spec("profile")),
+            &WatermarkOverride::Inherit,
+        );
+        assert_eq!(resolved.watermark, Some(spec("profile")));
+        assert_eq!(resolved.source, Some(WatermarkSource::Profile));
+    }
+
+    #[test]
+    fn watermark_none_when_all_levels_disable_it() {
+        let resolved = resolve_watermark(
+            None,
+            &WatermarkOverride::Disabled,
+            &WatermarkOverride::Disabled,
+        );
+        assert_eq!(resolved.watermark, None);
+
+        let resolved = resolve_watermark(
+            None,
+            &WatermarkOverride::Inherit,
+            &WatermarkOverride::Inherit,
+        );
+        assert_eq!(resolved.watermark, None);
+        assert_eq!(resolved.source, None);
+    }
 }
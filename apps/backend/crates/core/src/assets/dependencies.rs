@@ -1,9 +1,25 @@
 //! Asset dependency checking (PRD-17).
 //!
-//! Pure domain logic for evaluating whether an asset can safely be deleted.
+//! Pure domain logic for evaluating whether an asset can safely be deleted
+//! and for walking asset-to-asset dependency chains.
+
+use std::collections::HashMap;
 
 use serde::Serialize;
 
+use super::AssetError;
+use crate::types::DbId;
+
+/// Entity type recorded when one asset depends on another. Only these
+/// edges are followed when traversing dependencies transitively.
+pub const ASSET_ENTITY_TYPE: &str = "asset";
+
+/// Default limit on how many asset-to-asset hops a traversal may follow.
+pub const DEFAULT_MAX_DEPENDENCY_DEPTH: usize = 16;
+
+/// Highest depth limit a caller may request.
+pub const MAX_DEPENDENCY_DEPTH: usize = 64;
+
 /// Result of checking whether an asset can safely be deleted.
 #[derive(Debug, Clone, Serialize)]
 pub struct DeletionCheck {
@@ -31,3 +47,165 @@ pub fn check_deletion_safe(dependent_count: i64) -> DeletionCheck {
         }
     }
 }
+
+/// Assets reached by walking dependency edges from a root asset.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DependencyTraversal {
+    /// Transitively dependent assets, excluding the root, in discovery order.
+    pub asset_ids: Vec<DbId>,
+    /// Length of the longest dependency chain below the root.
+    pub depth: usize,
+}
+
+/// Traversal bookkeeping for one asset.
+#[derive(Clone, Copy)]
+enum Visit {
+    /// On the current path; reaching it again closes a cycle.
+    OnPath,
+    /// Fully explored, with the length of its longest chain.
+    Done { height: usize },
+}
+
+/// Walk asset-to-asset dependencies from `root`.
+///
+/// `dependents` maps an asset to the assets that depend on it. The walk is
+/// an iterative depth-first search, so deep graphs cannot overflow the
+/// stack. It fails with [`AssetError::CycleDetected`], naming the assets on
+/// the cycle in order, if an asset transitively depends on itself, and with
+/// [`AssetError::DepthExceeded`] if any chain is longer than `max_depth`.
+pub fn traverse_asset_dependents(
+    root: DbId,
+    dependents: &HashMap<DbId, Vec<DbId>>,
+    max_depth: usize,
+) -> Result<DependencyTraversal, AssetError> {
+    let children_of = |id: DbId| dependents.get(&id).map(Vec::as_slice).unwrap_or(&[]);
+
+    let mut visits: HashMap<DbId, Visit> = HashMap::from([(root, Visit::OnPath)]);
+    let mut asset_ids = Vec::new();
+    // Each frame: (asset, index of its next child, longest chain below it so far).
+    let mut stack: Vec<(DbId, usize, usize)> = vec![(root, 0, 0)];
+
+    while let Some(&(asset, next, _)) = stack.last() {
+        let Some(&child) = children_of(asset).get(next) else {
+            let (done, _, height) = stack.pop().expect("stack is non-empty");
+            visits.insert(done, Visit::Done { height });
+            match stack.last_mut() {
+                Some(parent) => parent.2 = parent.2.max(height + 1),
+                None => {
+                    return Ok(DependencyTraversal {
+                        asset_ids,
+                        depth: height,
+                    })
+                }
+            }
+            continue;
+        };
+
+        // Depth of `child` below the root.
+        let depth = stack.len();
+        let last = stack.len() - 1;
+        stack[last].1 += 1;
+
+        match visits.get(&child) {
+            Some(Visit::OnPath) => {
+                let start = stack
+                    .iter()
+                    .position(|&(id, _, _)| id == child)
+                    .expect("on-path asset is on the stack");
+                return Err(AssetError::CycleDetected {
+                    asset_ids: stack[start..].iter().map(|&(id, _, _)| id).collect(),
+                });
+            }
+            Some(Visit::Done { height }) => {
+                if depth + height > max_depth {
+                    return Err(AssetError::DepthExceeded { max_depth });
+                }
+                stack[last].2 = stack[last].2.max(height + 1);
+            }
+            None => {
+                if depth > max_depth {
+                    return Err(AssetError::DepthExceeded { max_depth });
+                }
+                visits.insert(child, Visit::OnPath);
+                asset_ids.push(child);
+                stack.push((child, 0, 0));
+            }
+        }
+    }
+
+    unreachable!("the root frame returns when popped")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A straight chain 1 -> 2 -> ... -> len+1.
+    fn chain(len: i64) -> HashMap<DbId, Vec<DbId>> {
+        (1..=len).map(|id| (id, vec![id + 1])).collect()
+    }
+
+    #[test]
+    fn deep_chain_within_limit_is_fully_traversed() {
+        let graph = chain(40);
+
+        let traversal = traverse_asset_dependents(1, &graph, 40).unwrap();
+
+        assert_eq!(traversal.depth, 40);
+        assert_eq!(traversal.asset_ids, (2..=41).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn chain_exceeding_limit_is_rejected() {
+        let graph = chain(20);
+
+        let err = traverse_asset_dependents(1, &graph, 19).unwrap_err();
+
+        assert!(matches!(err, AssetError::DepthExceeded { max_depth: 19 }));
+    }
+
+    #[test]
+    fn very_deep_chain_does_not_overflow_the_stack() {
+        let graph = chain(200_000);
+
+        let traversal = traverse_asset_dependents(1, &graph, 200_000).unwrap();
+
+        assert_eq!(traversal.depth, 200_000);
+    }
+
+    #[test]
+    fn cycle_is_reported_with_its_assets() {
+        // 1 -> 2 -> 3 -> 4 -> 2
+        let graph = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![4]), (4, vec![2])]);
+
+        let err = traverse_asset_dependents(1, &graph, DEFAULT_MAX_DEPENDENCY_DEPTH).unwrap_err();
+
+        match err {
+            AssetError::CycleDetected { asset_ids } => assert_eq!(asset_ids, vec![2, 3, 4]),
+            other => panic!("expected a cycle, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn shared_dependents_are_not_mistaken_for_cycles() {
+        // Diamond: 1 -> {2, 3} -> 4, plus a longer route 3 -> 5 -> 4.
+        let graph = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4, 5]), (5, vec![4])]);
+
+        let traversal = traverse_asset_dependents(1, &graph, DEFAULT_MAX_DEPENDENCY_DEPTH).unwrap();
+
+        assert_eq!(traversal.asset_ids, vec![2, 4, 3, 5]);
+        assert_eq!(traversal.depth, 3);
+        // The longer route through an already explored asset still counts.
+        assert!(matches!(
+            traverse_asset_dependents(1, &graph, 2),
+            Err(AssetError::DepthExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn asset_without_dependents_has_depth_zero() {
+        let traversal = traverse_asset_dependents(7, &HashMap::new(), 1).unwrap();
+        assert!(traversal.asset_ids.is_empty());
+        assert_eq!(traversal.depth, 0);
+    }
+}
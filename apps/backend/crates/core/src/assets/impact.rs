@@ -11,10 +11,15 @@ use crate::types::DbId;
 pub struct UpdateImpact {
     /// The asset being analyzed.
     pub asset_id: DbId,
-    /// Number of entities that depend on this asset.
+    /// Number of distinct entities that depend on this asset, directly or
+    /// through other assets.
     pub total_dependents: i64,
     /// Breakdown of affected entities by type.
     pub affected_entities: Vec<AffectedGroup>,
+    /// Assets that depend on this asset transitively, in discovery order.
+    pub transitive_asset_ids: Vec<DbId>,
+    /// Length of the longest asset-to-asset chain below this asset.
+    pub dependency_depth: usize,
 }
 
 /// A group of affected entities of the same type.
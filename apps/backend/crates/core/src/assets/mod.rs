@@ -25,4 +25,10 @@ pub enum AssetError {
 
     #[error("Invalid rating: {0} (must be 1-5)")]
     InvalidRating(i16),
+
+    #[error("Dependency cycle detected among assets {asset_ids:?}")]
+    CycleDetected { asset_ids: Vec<i64> },
+
+    #[error("Dependency chain exceeds maximum depth of {max_depth}")]
+    DepthExceeded { max_depth: usize },
 }
@@ -3,7 +3,10 @@
 //! This module lives in `core` (zero internal deps) so it can be used by both
 //! the API/repository layer and any future worker or CLI tooling.
 
+use serde::Serialize;
+
 use crate::hashing;
+use crate::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
 // Action type constants
@@ -73,6 +76,136 @@ pub fn compute_integrity_hash(prev_hash: Option<&str>, entry_data: &str) -> Stri
     hashing::sha256_hex(combined.as_bytes())
 }
 
+// ---------------------------------------------------------------------------
+// Hash chain verification
+// ---------------------------------------------------------------------------
+
+/// Build the canonical string hashed for an audit log entry.
+///
+/// Fields are pipe-joined in a fixed order; absent optional fields become
+/// empty strings so the representation stays stable.
+pub fn canonical_entry_data(
+    timestamp: &Timestamp,
+    user_id: Option<DbId>,
+    action_type: &str,
+    entity_type: Option<&str>,
+    entity_id: Option<DbId>,
+) -> String {
+    format!(
+        "{}|{}|{}|{}|{}",
+        timestamp.to_rfc3339(),
+        user_id.map_or(String::new(), |id| id.to_string()),
+        action_type,
+        entity_type.unwrap_or(""),
+        entity_id.map_or(String::new(), |id| id.to_string()),
+    )
+}
+
+/// One audit log row as seen by the chain verifier.
+#[derive(Debug, Clone)]
+pub struct ChainRow {
+    /// Database ID of the entry.
+    pub id: DbId,
+    /// Canonical entry data (see [`canonical_entry_data`]).
+    pub entry_data: String,
+    /// Stored hash, or `None` for legacy entries written before hashing.
+    pub integrity_hash: Option<String>,
+}
+
+/// The first point at which the hash chain fails to verify.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainBreak {
+    /// Zero-based position of the entry within the verified sequence.
+    pub index: usize,
+    /// Database ID of the offending entry.
+    pub entry_id: DbId,
+    /// Hash recomputed from the previous entry and this entry's data.
+    pub expected_hash: String,
+    /// Hash stored on the entry.
+    pub actual_hash: String,
+}
+
+/// Outcome of verifying an audit log hash chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChainResult {
+    /// Number of entries verified before the chain ended or broke.
+    pub verified_entries: usize,
+    /// The first break, or `None` if the chain is intact.
+    pub first_break: Option<ChainBreak>,
+}
+
+impl ChainResult {
+    /// Whether every verified entry matched its expected hash.
+    pub fn is_valid(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
+
+/// Incremental hash chain verifier.
+///
+/// Rows are fed in ascending ID order, possibly across several batches, so
+/// large logs can be checked page by page while reporting progress. Once a
+/// break is found, further rows are ignored.
+#[derive(Debug, Default)]
+pub struct ChainVerifier {
+    prev_hash: Option<String>,
+    result: ChainResult,
+}
+
+impl ChainVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verify the next batch of rows. Returns `false` once the chain has
+    /// broken, signalling that the caller can stop fetching.
+    pub fn feed<'a>(&mut self, rows: impl IntoIterator<Item = &'a ChainRow>) -> bool {
+        if self.result.first_break.is_some() {
+            return false;
+        }
+
+        for row in rows {
+            let expected_hash = compute_integrity_hash(self.prev_hash.as_deref(), &row.entry_data);
+
+            // Entries without a hash (e.g., legacy entries) are skipped in
+            // chain validation but still counted.
+            if let Some(ref stored_hash) = row.integrity_hash {
+                if *stored_hash != expected_hash {
+                    self.result.first_break = Some(ChainBreak {
+                        index: self.result.verified_entries,
+                        entry_id: row.id,
+                        expected_hash,
+                        actual_hash: stored_hash.clone(),
+                    });
+                    return false;
+                }
+            }
+
+            self.result.verified_entries += 1;
+            self.prev_hash = row.integrity_hash.clone();
+        }
+
+        true
+    }
+
+    /// Number of entries verified so far.
+    pub fn verified_entries(&self) -> usize {
+        self.result.verified_entries
+    }
+
+    /// Consume the verifier and return the final result.
+    pub fn finish(self) -> ChainResult {
+        self.result
+    }
+}
+
+/// Verify a complete, ID-ordered sequence of audit log rows.
+pub fn verify_chain(rows: &[ChainRow]) -> ChainResult {
+    let mut verifier = ChainVerifier::new();
+    verifier.feed(rows);
+    verifier.finish()
+}
+
 // ---------------------------------------------------------------------------
 // Sensitive field redaction
 // ---------------------------------------------------------------------------
@@ -228,6 +361,78 @@ mod tests {
         assert_ne!(a, b);
     }
 
+    // -----------------------------------------------------------------------
+    // verify_chain
+    // -----------------------------------------------------------------------
+
+    fn build_chain(len: usize) -> Vec<ChainRow> {
+        let mut prev: Option<String> = None;
+        (0..len)
+            .map(|i| {
+                let entry_data = format!("2026-01-01T00:00:0{i}+00:00|1|login||");
+                let hash = compute_integrity_hash(prev.as_deref(), &entry_data);
+                prev = Some(hash.clone());
+                ChainRow {
+                    id: i as DbId + 100,
+                    entry_data,
+                    integrity_hash: Some(hash),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intact_chain_is_valid() {
+        let rows = build_chain(5);
+        let result = verify_chain(&rows);
+        assert!(result.is_valid());
+        assert_eq!(result.verified_entries, 5);
+    }
+
+    #[test]
+    fn tampered_middle_row_reports_break_at_its_index() {
+        let mut rows = build_chain(5);
+        let original_hash = rows[2].integrity_hash.clone().unwrap();
+        rows[2].entry_data = "2026-01-01T00:00:02+00:00|1|logout||".to_string();
+
+        let result = verify_chain(&rows);
+        assert!(!result.is_valid());
+        assert_eq!(result.verified_entries, 2);
+
+        let brk = result.first_break.unwrap();
+        assert_eq!(brk.index, 2);
+        assert_eq!(brk.entry_id, 102);
+        assert_eq!(brk.actual_hash, original_hash);
+        assert_eq!(
+            brk.expected_hash,
+            compute_integrity_hash(rows[1].integrity_hash.as_deref(), &rows[2].entry_data)
+        );
+    }
+
+    #[test]
+    fn batched_feed_matches_single_pass() {
+        let mut rows = build_chain(6);
+        rows[4].integrity_hash = Some("forged".to_string());
+
+        let mut verifier = ChainVerifier::new();
+        assert!(verifier.feed(&rows[..3]));
+        assert!(!verifier.feed(&rows[3..]));
+        assert_eq!(verifier.finish(), verify_chain(&rows));
+    }
+
+    #[test]
+    fn legacy_rows_without_hash_are_counted() {
+        let mut rows = build_chain(1);
+        rows.push(ChainRow {
+            id: 200,
+            entry_data: "legacy".to_string(),
+            integrity_hash: None,
+        });
+        let result = verify_chain(&rows);
+        assert!(result.is_valid());
+        assert_eq!(result.verified_entries, 2);
+    }
+
     // -----------------------------------------------------------------------
     // Sensitive field redaction
     // -----------------------------------------------------------------------
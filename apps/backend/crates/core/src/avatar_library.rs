@@ -1,10 +1,11 @@
 //! Avatar library validation and field sync utilities (PRD-60).
 //!
-//! Provides constants, validation helpers, and field-level synchronisation
-//! classification for the cross-project avatar library feature.
+//! Provides constants, validation helpers, field-level synchronisation
+//! classification, and project-fit scoring for the cross-project avatar
+//! library feature.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use crate::error::CoreError;
 
@@ -92,6 +93,28 @@ pub fn is_field_linkable(field: &str) -> bool {
     !NON_LINKABLE_FIELDS.contains(&field)
 }
 
+/// Refuse to delete a library avatar that projects still link to.
+///
+/// Like the asset registry's dependent check, deletion is only allowed once
+/// no project links remain. The error names the linked projects so the
+/// caller can unlink them, or retry with `cascade=true` to remove the links
+/// along with the avatar.
+pub fn check_library_deletion(
+    active_links: i64,
+    linked_projects: &[String],
+) -> Result<(), CoreError> {
+    if active_links <= 0 {
+        return Ok(());
+    }
+    let mut message =
+        format!("Cannot delete: library avatar has {active_links} active project link(s)");
+    if !linked_projects.is_empty() {
+        message.push_str(&format!(" ({})", linked_projects.join(", ")));
+    }
+    message.push_str(". Unlink them first or delete with cascade=true.");
+    Err(CoreError::Conflict(message))
+}
+
 /* --------------------------------------------------------------------------
 Field sync classification
 -------------------------------------------------------------------------- */
@@ -172,6 +195,116 @@ pub fn classify_field_sync(
         .collect()
 }
 
+/* --------------------------------------------------------------------------
+Project fit scoring
+-------------------------------------------------------------------------- */
+
+/// Weight of metadata field coverage in [`score_library_fit`].
+const FIT_COVERAGE_WEIGHT: f64 = 0.4;
+
+/// Weight of metadata value agreement in [`score_library_fit`].
+const FIT_AGREEMENT_WEIGHT: f64 = 0.4;
+
+/// Weight of source-avatar readiness in [`score_library_fit`].
+const FIT_READINESS_WEIGHT: f64 = 0.2;
+
+/// Metadata conventions of a project, derived from its existing avatars.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectFitProfile {
+    /// Metadata field -> every non-null value the project's avatars hold for it.
+    pub field_values: BTreeMap<String, Vec<serde_json::Value>>,
+}
+
+impl ProjectFitProfile {
+    /// Build a profile from the metadata objects of a project's avatars.
+    ///
+    /// Non-object metadata and null values are ignored.
+    pub fn from_metadata<'a>(metadata: impl IntoIterator<Item = &'a serde_json::Value>) -> Self {
+        let mut field_values: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
+        for obj in metadata.into_iter().filter_map(|m| m.as_object()) {
+            for (key, value) in obj {
+                if !value.is_null() {
+                    field_values
+                        .entry(key.clone())
+                        .or_default()
+                        .push(value.clone());
+                }
+            }
+        }
+        Self { field_values }
+    }
+
+    /// Whether the project has no metadata to compare against.
+    pub fn is_empty(&self) -> bool {
+        self.field_values.is_empty()
+    }
+}
+
+/// The library-side inputs to [`score_library_fit`].
+#[derive(Debug, Clone, Copy)]
+pub struct LibraryFitCandidate<'a> {
+    /// The library avatar's `master_metadata`.
+    pub master_metadata: &'a serde_json::Value,
+    /// Readiness percentage (0-100) of the library avatar's source avatar,
+    /// if known.
+    pub readiness_pct: Option<i32>,
+}
+
+/// Score how well a library avatar suits a project, from 0.0 to 1.0.
+///
+/// Combines three signals:
+/// - **coverage**: the share of the project's metadata usage (each field
+///   weighted by how many avatars fill it) that the candidate also fills;
+/// - **agreement**: for the fields both sides fill, how often the project's
+///   values match the candidate's (strings compare case-insensitively);
+/// - **readiness**: the source avatar's readiness, treated as 0 if unknown.
+///
+/// A project without any metadata has nothing to compare against, so the
+/// score then reflects readiness alone.
+pub fn score_library_fit(candidate: &LibraryFitCandidate<'_>, project: &ProjectFitProfile) -> f64 {
+    let readiness = f64::from(candidate.readiness_pct.unwrap_or(0).clamp(0, 100)) / 100.0;
+    if project.is_empty() {
+        return readiness;
+    }
+
+    let library = candidate.master_metadata.as_object();
+    let mut total_usage = 0usize;
+    let mut covered_usage = 0usize;
+    let mut agreement_sum = 0.0;
+    let mut shared_fields = 0usize;
+
+    for (field, values) in &project.field_values {
+        total_usage += values.len();
+        let Some(value) = library.and_then(|o| o.get(field)).filter(|v| !v.is_null()) else {
+            continue;
+        };
+        covered_usage += values.len();
+        shared_fields += 1;
+        let matching = values.iter().filter(|v| values_match(value, v)).count();
+        agreement_sum += matching as f64 / values.len() as f64;
+    }
+
+    let coverage = covered_usage as f64 / total_usage as f64;
+    let agreement = if shared_fields == 0 {
+        0.0
+    } else {
+        agreement_sum / shared_fields as f64
+    };
+
+    FIT_COVERAGE_WEIGHT * coverage
+        + FIT_AGREEMENT_WEIGHT * agreement
+        + FIT_READINESS_WEIGHT * readiness
+}
+
+/// Compare two metadata values, ignoring case and surrounding whitespace
+/// for strings.
+fn values_match(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a.as_str(), b.as_str()) {
+        (Some(a), Some(b)) => a.trim().eq_ignore_ascii_case(b.trim()),
+        _ => a == b,
+    }
+}
+
 /* --------------------------------------------------------------------------
 Tests
 -------------------------------------------------------------------------- */
@@ -228,6 +361,21 @@ mod tests {
         assert!(is_field_linkable("custom_field"));
     }
 
+    #[test]
+    fn check_library_deletion_allows_unlinked() {
+        assert!(check_library_deletion(0, &[]).is_ok());
+    }
+
+    #[test]
+    fn check_library_deletion_reports_links() {
+        let projects = vec!["Alpha".to_string(), "Beta".to_string()];
+        let err = check_library_deletion(2, &projects).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("2 active project link(s)"));
+        assert!(message.contains("Alpha, Beta"));
+        assert!(message.contains("cascade=true"));
+    }
+
     #[test]
     fn classify_in_sync_fields() {
         let lib = json!({"name": "Alice", "bio": "Hello"});
@@ -279,4 +427,102 @@ mod tests {
         assert_eq!(result[0].field, "name");
         assert_eq!(result[0].status, "library_only");
     }
+
+    fn project_profile() -> ProjectFitProfile {
+        let avatars = [
+            json!({"gender": "female", "hair": "red", "age_range": "20s"}),
+            json!({"gender": "female", "hair": "black", "age_range": "20s"}),
+            json!({"gender": "Female", "age_range": "30s", "notes": null}),
+        ];
+        ProjectFitProfile::from_metadata(avatars.iter())
+    }
+
+    #[test]
+    fn profile_collects_non_null_values_per_field() {
+        let profile = project_profile();
+        assert_eq!(profile.field_values["gender"].len(), 3);
+        assert_eq!(profile.field_values["hair"].len(), 2);
+        assert!(!profile.field_values.contains_key("notes"));
+    }
+
+    #[test]
+    fn well_matched_avatar_scores_higher_than_poorly_matched() {
+        let profile = project_profile();
+        let matched = json!({"gender": "female", "hair": "red", "age_range": "20s"});
+        let mismatched = json!({"gender": "male", "build": "stocky"});
+
+        let good = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &matched,
+                readiness_pct: Some(100),
+            },
+            &profile,
+        );
+        let poor = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &mismatched,
+                readiness_pct: Some(20),
+            },
+            &profile,
+        );
+
+        assert!(good > poor, "good={good} poor={poor}");
+        assert!((0.0..=1.0).contains(&good));
+        assert!((0.0..=1.0).contains(&poor));
+    }
+
+    #[test]
+    fn readiness_breaks_ties_between_equal_metadata() {
+        let profile = project_profile();
+        let metadata = json!({"gender": "female", "age_range": "20s"});
+
+        let ready = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &metadata,
+                readiness_pct: Some(90),
+            },
+            &profile,
+        );
+        let unknown = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &metadata,
+                readiness_pct: None,
+            },
+            &profile,
+        );
+
+        assert!(ready > unknown);
+    }
+
+    #[test]
+    fn perfect_match_with_full_readiness_scores_one() {
+        let avatars = [json!({"gender": "female", "hair": "red"})];
+        let profile = ProjectFitProfile::from_metadata(avatars.iter());
+
+        let score = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &avatars[0],
+                readiness_pct: Some(100),
+            },
+            &profile,
+        );
+
+        assert!((score - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_project_scores_by_readiness_only() {
+        let profile = ProjectFitProfile::default();
+        let metadata = json!({"gender": "female"});
+
+        let score = score_library_fit(
+            &LibraryFitCandidate {
+                master_metadata: &metadata,
+                readiness_pct: Some(40),
+            },
+            &profile,
+        );
+
+        assert!((score - 0.4).abs() < 1e-9);
+    }
 }
@@ -3,6 +3,8 @@
 //! Provides production run status constants, cell status classification,
 //! matrix configuration validation, and delivery readiness checks.
 
+use std::collections::{BTreeSet, HashSet};
+
 use serde::{Deserialize, Serialize};
 
 use crate::error::CoreError;
@@ -118,6 +120,9 @@ impl CellStatus {
 pub struct MatrixConfig {
     pub avatar_ids: Vec<i64>,
     pub scene_type_ids: Vec<i64>,
+    /// Cells explicitly left out of the run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_cells: Vec<MatrixCellKey>,
 }
 
 /// Validate that a matrix configuration is well-formed.
@@ -237,6 +242,66 @@ pub fn compute_cell_status(
     CellStatus::NotStarted
 }
 
+// ---------------------------------------------------------------------------
+// Matrix expansion
+// ---------------------------------------------------------------------------
+
+/// Coordinates of one cell in the production matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MatrixCellKey {
+    pub avatar_id: i64,
+    pub scene_type_id: i64,
+    pub track_id: Option<i64>,
+}
+
+/// Filters narrowing the full avatar x scene type x track product.
+#[derive(Debug, Clone, Default)]
+pub struct MatrixFilters {
+    /// When set, only these combinations may appear (e.g. the scene
+    /// settings each avatar has enabled).
+    pub allowed: Option<HashSet<MatrixCellKey>>,
+    /// Combinations explicitly left out of the run.
+    pub excluded: HashSet<MatrixCellKey>,
+}
+
+/// Expand avatars x scene types x tracks into the cells of a production run.
+///
+/// The result is deterministic: duplicate inputs are ignored and cells are
+/// ordered by avatar, then scene type, then track (no track first),
+/// whatever order the inputs arrive in. Cells not in `filters.allowed`
+/// (when set) or listed in `filters.excluded` are dropped.
+pub fn expand_matrix(
+    avatar_ids: &[i64],
+    scene_type_ids: &[i64],
+    track_ids: &[Option<i64>],
+    filters: &MatrixFilters,
+) -> Vec<MatrixCellKey> {
+    let avatars: BTreeSet<i64> = avatar_ids.iter().copied().collect();
+    let scene_types: BTreeSet<i64> = scene_type_ids.iter().copied().collect();
+    let tracks: BTreeSet<Option<i64>> = track_ids.iter().copied().collect();
+
+    let mut cells = Vec::new();
+    for &avatar_id in &avatars {
+        for &scene_type_id in &scene_types {
+            for &track_id in &tracks {
+                let cell = MatrixCellKey {
+                    avatar_id,
+                    scene_type_id,
+                    track_id,
+                };
+                let allowed = filters
+                    .allowed
+                    .as_ref()
+                    .is_none_or(|allowed| allowed.contains(&cell));
+                if allowed && !filters.excluded.contains(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+    cells
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -252,6 +317,7 @@ mod tests {
         let config = MatrixConfig {
             avatar_ids: vec![1, 2],
             scene_type_ids: vec![10, 20],
+            excluded_cells: vec![],
         };
         assert!(validate_matrix_config(&config).is_ok());
     }
@@ -261,6 +327,7 @@ mod tests {
         let config = MatrixConfig {
             avatar_ids: vec![],
             scene_type_ids: vec![10],
+            excluded_cells: vec![],
         };
         let err = validate_matrix_config(&config).unwrap_err();
         assert!(err.to_string().contains("at least one avatar"));
@@ -271,6 +338,7 @@ mod tests {
         let config = MatrixConfig {
             avatar_ids: vec![1],
             scene_type_ids: vec![],
+            excluded_cells: vec![],
         };
         let err = validate_matrix_config(&config).unwrap_err();
         assert!(err.to_string().contains("at least one scene type"));
@@ -452,4 +520,58 @@ mod tests {
             CellStatus::NotStarted,
         );
     }
+
+    // -- expand_matrix --------------------------------------------------------
+
+    fn cell(avatar_id: i64, scene_type_id: i64, track_id: Option<i64>) -> MatrixCellKey {
+        MatrixCellKey {
+            avatar_id,
+            scene_type_id,
+            track_id,
+        }
+    }
+
+    #[test]
+    fn matrix_is_full_product_minus_exclusions() {
+        let filters = MatrixFilters {
+            allowed: None,
+            excluded: HashSet::from([cell(1, 10, Some(100)), cell(2, 20, None)]),
+        };
+
+        let cells = expand_matrix(&[1, 2, 3], &[10, 20], &[None, Some(100)], &filters);
+
+        assert_eq!(cells.len(), 3 * 2 * 2 - 2);
+        assert!(!cells.contains(&cell(1, 10, Some(100))));
+        assert!(!cells.contains(&cell(2, 20, None)));
+    }
+
+    #[test]
+    fn matrix_respects_allowed_combinations() {
+        let filters = MatrixFilters {
+            allowed: Some(HashSet::from([
+                cell(1, 10, Some(100)),
+                cell(1, 20, Some(100)),
+                cell(2, 10, Some(200)),
+            ])),
+            excluded: HashSet::from([cell(1, 20, Some(100))]),
+        };
+
+        let cells = expand_matrix(&[1, 2], &[10, 20], &[Some(100), Some(200)], &filters);
+
+        assert_eq!(cells, vec![cell(1, 10, Some(100)), cell(2, 10, Some(200))]);
+    }
+
+    #[test]
+    fn matrix_ordering_is_stable_across_calls_and_input_order() {
+        let filters = MatrixFilters::default();
+        let first = expand_matrix(&[3, 1, 2], &[20, 10], &[Some(5), None], &filters);
+        let second = expand_matrix(&[2, 3, 1, 3], &[10, 20, 10], &[None, Some(5)], &filters);
+
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 12);
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(first, sorted);
+        assert_eq!(first[0], cell(1, 10, None));
+    }
 }
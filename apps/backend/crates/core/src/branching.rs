@@ -1,10 +1,15 @@
 //! Content Branching & Exploration constants, validation, and comparison logic (PRD-50).
 //!
 //! Provides limits for branch nesting and per-scene counts, name validation,
-//! and a key-by-key parameter diff used for side-by-side branch comparison.
+//! a key-by-key parameter diff used for side-by-side branch comparison,
+//! divergence detection run before a branch is promoted to default, and the
+//! stale-branch rules behind `/branches/stale`.
+
+use std::collections::BTreeMap;
 
 use crate::diff::DiffStatus;
 use crate::error::CoreError;
+use crate::types::Timestamp;
 use serde::Serialize;
 
 // ---------------------------------------------------------------------------
@@ -23,6 +28,17 @@ pub const MAX_NESTING_DEPTH: i32 = 3;
 /// Maximum number of branches allowed per scene.
 pub const MAX_BRANCHES_PER_SCENE: i64 = 20;
 
+/// Default days without activity before a branch counts as idle.
+pub const DEFAULT_STALE_INACTIVE_DAYS: i64 = 30;
+
+/// Default number of parent segment slots changed since the fork for an idle
+/// branch to count as diverged.
+pub const DEFAULT_STALE_MIN_PARENT_CHANGES: usize = 1;
+
+/// Default days without activity after which a branch is stale even if its
+/// parent has not moved.
+pub const DEFAULT_STALE_ABANDONED_DAYS: i64 = 90;
+
 // ---------------------------------------------------------------------------
 // Validation
 // ---------------------------------------------------------------------------
@@ -151,6 +167,187 @@ pub fn compare_branch_parameters(
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Promotion conflict types
+// ---------------------------------------------------------------------------
+
+/// Last-modified state of a single segment slot on one side of a branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentState {
+    pub sequence_index: i32,
+    pub updated_at: Timestamp,
+}
+
+/// A segment slot that was modified on both the default and the promoted
+/// branch after the fork point.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromotionConflict {
+    pub sequence_index: i32,
+    pub default_updated_at: Timestamp,
+    pub branch_updated_at: Timestamp,
+}
+
+/// Result of a pre-promotion divergence check.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromotionCheck {
+    /// `true` when the default has not changed since the fork, so the
+    /// branch can replace it without losing any work.
+    pub is_fast_forward: bool,
+    /// Sequence indices of default segments changed since the fork.
+    pub default_changed: Vec<i32>,
+    /// Sequence indices of branch segments changed since the fork.
+    pub branch_changed: Vec<i32>,
+    /// Slots changed on both sides; must be resolved before promotion.
+    pub conflicts: Vec<PromotionConflict>,
+}
+
+impl PromotionCheck {
+    /// Whether the branch can be promoted without resolving conflicts.
+    pub fn can_promote(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Promotion conflict detection
+// ---------------------------------------------------------------------------
+
+/// Collect the latest modification time per sequence index for segments
+/// changed strictly after `forked_at`.
+fn changed_since(forked_at: Timestamp, segments: &[SegmentState]) -> BTreeMap<i32, Timestamp> {
+    let mut changed = BTreeMap::new();
+    for seg in segments.iter().filter(|s| s.updated_at > forked_at) {
+        changed
+            .entry(seg.sequence_index)
+            .and_modify(|ts: &mut Timestamp| *ts = (*ts).max(seg.updated_at))
+            .or_insert(seg.updated_at);
+    }
+    changed
+}
+
+/// Detect whether the current default branch has diverged from a branch
+/// since the branch was forked at `forked_at`.
+///
+/// A segment slot counts as changed on a side when any of its segments on
+/// that side was updated after the fork point. Slots changed on both sides
+/// are reported as conflicts, sorted by sequence index. When the default
+/// has no changes at all the promotion is a fast-forward.
+pub fn check_promotion_conflicts(
+    forked_at: Timestamp,
+    default_segments: &[SegmentState],
+    branch_segments: &[SegmentState],
+) -> PromotionCheck {
+    let default_changed = changed_since(forked_at, default_segments);
+    let branch_changed = changed_since(forked_at, branch_segments);
+
+    let conflicts = default_changed
+        .iter()
+        .filter_map(|(&idx, &default_ts)| {
+            branch_changed
+                .get(&idx)
+                .map(|&branch_ts| PromotionConflict {
+                    sequence_index: idx,
+                    default_updated_at: default_ts,
+                    branch_updated_at: branch_ts,
+                })
+        })
+        .collect();
+
+    PromotionCheck {
+        is_fast_forward: default_changed.is_empty(),
+        default_changed: default_changed.into_keys().collect(),
+        branch_changed: branch_changed.into_keys().collect(),
+        conflicts,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Stale branch detection
+// ---------------------------------------------------------------------------
+
+/// Thresholds that define when a branch is stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleBranchConfig {
+    /// Days without activity before a branch is considered idle at all.
+    pub inactive_days: i64,
+    /// Parent segment slots that must have changed since the fork for an
+    /// idle branch to be stale. Zero makes every idle branch stale.
+    pub min_parent_changes: usize,
+    /// Days without activity after which an idle branch is stale even when
+    /// its parent has not advanced. `None` disables this rule.
+    pub abandoned_days: Option<i64>,
+}
+
+impl Default for StaleBranchConfig {
+    fn default() -> Self {
+        Self {
+            inactive_days: DEFAULT_STALE_INACTIVE_DAYS,
+            min_parent_changes: DEFAULT_STALE_MIN_PARENT_CHANGES,
+            abandoned_days: Some(DEFAULT_STALE_ABANDONED_DAYS),
+        }
+    }
+}
+
+/// The activity facts about a branch that staleness is judged on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BranchActivity {
+    /// Latest update to the branch or any of its segments.
+    pub last_activity_at: Timestamp,
+    /// Segment slots the parent changed after this branch forked.
+    pub parent_changed_segments: usize,
+}
+
+/// Why a branch was judged stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StaleReason {
+    /// Idle, and the parent has moved on without it.
+    ParentAdvanced {
+        idle_days: i64,
+        parent_changed_segments: usize,
+    },
+    /// Idle for longer than the abandonment threshold.
+    Abandoned { idle_days: i64 },
+}
+
+/// Number of segment slots changed strictly after `forked_at`.
+pub fn count_changed_since(forked_at: Timestamp, segments: &[SegmentState]) -> usize {
+    changed_since(forked_at, segments).len()
+}
+
+/// Every reason `branch` is stale at `now`; empty when it is not stale.
+///
+/// A branch that has been active within `inactive_days` is never stale.
+/// Past that, it is stale when its parent has advanced by at least
+/// `min_parent_changes` slots, or when it has been idle for `abandoned_days`.
+pub fn stale_reasons(
+    branch: &BranchActivity,
+    now: Timestamp,
+    config: &StaleBranchConfig,
+) -> Vec<StaleReason> {
+    let idle_days = (now - branch.last_activity_at).num_days();
+    if idle_days < config.inactive_days {
+        return Vec::new();
+    }
+
+    let mut reasons = Vec::new();
+    if branch.parent_changed_segments >= config.min_parent_changes {
+        reasons.push(StaleReason::ParentAdvanced {
+            idle_days,
+            parent_changed_segments: branch.parent_changed_segments,
+        });
+    }
+    if config.abandoned_days.is_some_and(|days| idle_days >= days) {
+        reasons.push(StaleReason::Abandoned { idle_days });
+    }
+    reasons
+}
+
+/// Whether `branch` is stale at `now` under `config`.
+pub fn is_stale(branch: &BranchActivity, now: Timestamp, config: &StaleBranchConfig) -> bool {
+    !stale_reasons(branch, now, config).is_empty()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -158,6 +355,7 @@ pub fn compare_branch_parameters(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{Duration, TimeZone, Utc};
     use serde_json::json;
 
     // -- validate_branch_name ------------------------------------------------
@@ -325,4 +523,133 @@ mod tests {
         assert_eq!(find("removed").status, DiffStatus::Removed);
         assert_eq!(find("unchanged").status, DiffStatus::Unchanged);
     }
+
+    // -- check_promotion_conflicts -------------------------------------------
+
+    fn ts(minutes: i64) -> Timestamp {
+        Utc.with_ymd_and_hms(2026, 2, 21, 9, 0, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    fn seg(sequence_index: i32, minutes: i64) -> SegmentState {
+        SegmentState {
+            sequence_index,
+            updated_at: ts(minutes),
+        }
+    }
+
+    #[test]
+    fn promotion_fast_forward_when_default_unchanged() {
+        let forked_at = ts(0);
+        let default = vec![seg(0, -10), seg(1, -5)];
+        let branch = vec![seg(0, 5), seg(1, 10)];
+        let check = check_promotion_conflicts(forked_at, &default, &branch);
+        assert!(check.is_fast_forward);
+        assert!(check.can_promote());
+        assert!(check.default_changed.is_empty());
+        assert_eq!(check.branch_changed, vec![0, 1]);
+    }
+
+    #[test]
+    fn promotion_divergent_reports_conflicts() {
+        let forked_at = ts(0);
+        let default = vec![seg(0, -10), seg(1, 3), seg(2, 4)];
+        let branch = vec![seg(1, 5), seg(2, -1), seg(3, 6)];
+        let check = check_promotion_conflicts(forked_at, &default, &branch);
+        assert!(!check.is_fast_forward);
+        assert!(!check.can_promote());
+        assert_eq!(check.default_changed, vec![1, 2]);
+        assert_eq!(check.branch_changed, vec![1, 3]);
+        assert_eq!(check.conflicts.len(), 1);
+        assert_eq!(check.conflicts[0].sequence_index, 1);
+        assert_eq!(check.conflicts[0].default_updated_at, ts(3));
+        assert_eq!(check.conflicts[0].branch_updated_at, ts(5));
+    }
+
+    #[test]
+    fn promotion_default_changed_without_overlap_is_clean() {
+        let forked_at = ts(0);
+        let default = vec![seg(0, 2)];
+        let branch = vec![seg(1, 3)];
+        let check = check_promotion_conflicts(forked_at, &default, &branch);
+        assert!(!check.is_fast_forward);
+        assert!(check.can_promote());
+    }
+
+    // -- stale_reasons -------------------------------------------------------
+
+    fn activity(idle_days: i64, parent_changed_segments: usize) -> BranchActivity {
+        BranchActivity {
+            last_activity_at: ts(0) - Duration::days(idle_days),
+            parent_changed_segments,
+        }
+    }
+
+    fn config(inactive_days: i64, min_parent_changes: usize) -> StaleBranchConfig {
+        StaleBranchConfig {
+            inactive_days,
+            min_parent_changes,
+            abandoned_days: None,
+        }
+    }
+
+    #[test]
+    fn recently_active_branch_is_never_stale() {
+        let diverged = activity(5, 10);
+        assert!(!is_stale(&diverged, ts(0), &StaleBranchConfig::default()));
+        assert!(!is_stale(&diverged, ts(0), &config(30, 0)));
+    }
+
+    #[test]
+    fn old_but_current_branch_is_not_stale() {
+        let current = activity(45, 0);
+        assert!(!is_stale(&current, ts(0), &config(30, 1)));
+        assert!(stale_reasons(&current, ts(0), &config(30, 1)).is_empty());
+    }
+
+    #[test]
+    fn old_and_diverged_branch_is_stale_with_reason() {
+        let diverged = activity(45, 3);
+        assert_eq!(
+            stale_reasons(&diverged, ts(0), &config(30, 1)),
+            vec![StaleReason::ParentAdvanced {
+                idle_days: 45,
+                parent_changed_segments: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn thresholds_change_the_verdict() {
+        let current = activity(45, 0);
+        let diverged = activity(45, 3);
+
+        // A longer inactivity window keeps both branches fresh.
+        assert!(!is_stale(&diverged, ts(0), &config(60, 1)));
+        // Requiring more parent changes spares a lightly diverged branch.
+        assert!(!is_stale(&diverged, ts(0), &config(30, 5)));
+        // With no divergence requirement, age alone decides.
+        assert!(is_stale(&current, ts(0), &config(30, 0)));
+        assert!(is_stale(&diverged, ts(0), &config(30, 0)));
+    }
+
+    #[test]
+    fn abandoned_branch_is_stale_even_when_current() {
+        let cfg = StaleBranchConfig {
+            abandoned_days: Some(90),
+            ..config(30, 1)
+        };
+        assert!(!is_stale(&activity(60, 0), ts(0), &cfg));
+        assert_eq!(
+            stale_reasons(&activity(120, 0), ts(0), &cfg),
+            vec![StaleReason::Abandoned { idle_days: 120 }]
+        );
+        assert_eq!(stale_reasons(&activity(120, 2), ts(0), &cfg).len(), 2);
+    }
+
+    #[test]
+    fn counts_parent_changes_after_fork() {
+        let parent = vec![seg(0, -5), seg(1, 3), seg(1, 4), seg(2, 1)];
+        assert_eq!(count_changed_since(ts(0), &parent), 2);
+        assert_eq!(count_changed_since(ts(10), &parent), 0);
+    }
 }
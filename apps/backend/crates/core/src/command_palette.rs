@@ -1,9 +1,13 @@
 //! Command palette constants and validation (PRD-31).
 //!
-//! Provides constants, entity type validation, frecency scoring, and limit
-//! clamping used by the API and repository layers for the Cmd+K palette.
+//! Provides constants, entity type validation, frecency scoring, recent-item
+//! ranking, search result ranking, and limit clamping used by the API and
+//! repository layers for the Cmd+K palette.
+
+use std::cmp::Ordering;
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 use crate::error::CoreError;
 
@@ -29,6 +33,10 @@ pub const FRECENCY_WEEK_WEIGHT: f64 = 2.0;
 /// Frecency weight for items accessed more than a week ago.
 pub const FRECENCY_OLD_WEIGHT: f64 = 1.0;
 
+/// Hours after which an access counts for half as much when ranking recent
+/// items.
+pub const RECENCY_HALF_LIFE_HOURS: f64 = 48.0;
+
 /// Valid entity types that can appear in the command palette.
 pub const VALID_PALETTE_ENTITY_TYPES: &[&str] =
     &["project", "avatar", "scene", "segment", "scene_type"];
@@ -97,6 +105,259 @@ fn get_recency_weight(last_accessed: DateTime<Utc>) -> f64 {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Recent-item ranking
+// ---------------------------------------------------------------------------
+
+/// An item whose access history can be ranked by [`rank_recent_items`].
+pub trait RecentlyAccessed {
+    /// How many times the item has been accessed.
+    fn access_count(&self) -> i32;
+    /// When the item was last accessed.
+    fn last_accessed_at(&self) -> DateTime<Utc>;
+}
+
+/// An item paired with its ranking score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scored<T> {
+    pub item: T,
+    pub score: f64,
+}
+
+/// Score an access history with exponential recency decay.
+///
+/// The score is `log2(access_count + 1) * 0.5^(age / RECENCY_HALF_LIFE_HOURS)`,
+/// so frequency grows logarithmically and its weight halves every half-life.
+/// Accesses in the future (clock skew) count as happening at `now`.
+pub fn decayed_frecency_score(
+    access_count: i32,
+    last_accessed: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> f64 {
+    let age_hours = ((now - last_accessed).num_seconds().max(0) as f64) / 3600.0;
+    let decay = 0.5_f64.powf(age_hours / RECENCY_HALF_LIFE_HOURS);
+    let frequency = (access_count.max(0) as f64 + 1.0).log2();
+    frequency * decay
+}
+
+/// Rank recent items by [`decayed_frecency_score`], highest first.
+///
+/// Ties go to the more recently accessed item. At most `MAX_RECENT_ITEMS`
+/// items are returned.
+pub fn rank_recent_items<T: RecentlyAccessed>(items: Vec<T>, now: DateTime<Utc>) -> Vec<Scored<T>> {
+    let mut scored: Vec<Scored<T>> = items
+        .into_iter()
+        .map(|item| Scored {
+            score: decayed_frecency_score(item.access_count(), item.last_accessed_at(), now),
+            item,
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.item.last_accessed_at().cmp(&a.item.last_accessed_at()))
+    });
+    scored.truncate(MAX_RECENT_ITEMS as usize);
+    scored
+}
+
+// ---------------------------------------------------------------------------
+// Search result ranking
+// ---------------------------------------------------------------------------
+
+/// Match score when the label equals the query (ignoring case).
+pub const MATCH_SCORE_EXACT: f64 = 1.0;
+
+/// Match score when the label starts with the query.
+pub const MATCH_SCORE_PREFIX: f64 = 0.9;
+
+/// Match score when the query starts a word inside the label.
+pub const MATCH_SCORE_WORD: f64 = 0.8;
+
+/// Match score when the query appears elsewhere inside the label.
+pub const MATCH_SCORE_SUBSTRING: f64 = 0.7;
+
+/// Lowest match score for a scattered (subsequence) match; tighter matches
+/// score up to `MATCH_SCORE_SUBSEQUENCE_BASE + MATCH_SCORE_SUBSEQUENCE_RANGE`.
+pub const MATCH_SCORE_SUBSEQUENCE_BASE: f64 = 0.3;
+
+/// Range added to [`MATCH_SCORE_SUBSEQUENCE_BASE`] for compact subsequences.
+pub const MATCH_SCORE_SUBSEQUENCE_RANGE: f64 = 0.2;
+
+/// Score bonus for commands over entities with the same match quality.
+pub const COMMAND_PRIORITY_BONUS: f64 = 0.1;
+
+/// Upper bound of the recent-usage boost. Kept below the gap between match
+/// tiers so usage reorders close results without overriding match quality.
+pub const MAX_RECENT_BOOST: f64 = 0.09;
+
+/// Kind of palette result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaletteItemKind {
+    Command,
+    Entity,
+}
+
+impl PaletteItemKind {
+    /// Score bonus for this kind.
+    fn priority_bonus(self) -> f64 {
+        match self {
+            Self::Command => COMMAND_PRIORITY_BONUS,
+            Self::Entity => 0.0,
+        }
+    }
+}
+
+/// A matched character range `[start, end)` in a label, for highlighting.
+///
+/// Offsets count Unicode scalar values (chars), not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MatchSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Result of matching a query against a label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    pub score: f64,
+    pub spans: Vec<MatchSpan>,
+}
+
+/// Match `query` against `label`, ignoring case.
+///
+/// Contiguous matches score by position (exact, prefix, word start, or
+/// anywhere); otherwise the query's characters must appear in order and the
+/// score grows as they sit closer together. Returns `None` when the label
+/// does not contain the query as a subsequence or the query is blank.
+pub fn fuzzy_match(query: &str, label: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.trim().chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = label.chars().collect();
+    if query.is_empty() || query.len() > text.len() {
+        return None;
+    }
+    let chars_eq = |t: char, q: char| t.to_lowercase().eq(std::iter::once(q));
+
+    let contiguous = (0..=text.len() - query.len()).find(|&start| {
+        query
+            .iter()
+            .enumerate()
+            .all(|(i, &q)| chars_eq(text[start + i], q))
+    });
+    if let Some(start) = contiguous {
+        let score = if query.len() == text.len() {
+            MATCH_SCORE_EXACT
+        } else if start == 0 {
+            MATCH_SCORE_PREFIX
+        } else if !text[start - 1].is_alphanumeric() {
+            MATCH_SCORE_WORD
+        } else {
+            MATCH_SCORE_SUBSTRING
+        };
+        return Some(FuzzyMatch {
+            score,
+            spans: vec![MatchSpan {
+                start,
+                end: start + query.len(),
+            }],
+        });
+    }
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut next = 0;
+    for &q in &query {
+        let offset = text[next..].iter().position(|&t| chars_eq(t, q))?;
+        positions.push(next + offset);
+        next += offset + 1;
+    }
+
+    let mut spans: Vec<MatchSpan> = Vec::new();
+    for &pos in &positions {
+        match spans.last_mut() {
+            Some(span) if span.end == pos => span.end += 1,
+            _ => spans.push(MatchSpan {
+                start: pos,
+                end: pos + 1,
+            }),
+        }
+    }
+    let extent = positions[positions.len() - 1] - positions[0] + 1;
+    let compactness = query.len() as f64 / extent as f64;
+    Some(FuzzyMatch {
+        score: MATCH_SCORE_SUBSEQUENCE_BASE + MATCH_SCORE_SUBSEQUENCE_RANGE * compactness,
+        spans,
+    })
+}
+
+/// A palette item to rank against a query.
+#[derive(Debug, Clone)]
+pub struct PaletteCandidate<T> {
+    pub item: T,
+    pub kind: PaletteItemKind,
+    /// Text matched against the query.
+    pub label: String,
+    /// The item's [`decayed_frecency_score`], or 0 if never used.
+    pub recent_score: f64,
+}
+
+/// A ranked palette result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedPaletteResult<T> {
+    pub item: T,
+    pub kind: PaletteItemKind,
+    pub score: f64,
+    pub match_spans: Vec<MatchSpan>,
+}
+
+/// Rank palette candidates for `query`, best first, dropping non-matches.
+///
+/// The score is the [`fuzzy_match`] score plus the kind's priority bonus
+/// plus a recent-usage boost that approaches [`MAX_RECENT_BOOST`] as the
+/// frecency grows. Equal scores fall back to kind (commands first), then the
+/// label, then input order, so the same inputs always rank the same way.
+pub fn rank_palette_results<T>(
+    query: &str,
+    candidates: Vec<PaletteCandidate<T>>,
+) -> Vec<RankedPaletteResult<T>> {
+    let mut ranked: Vec<(String, RankedPaletteResult<T>)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let matched = fuzzy_match(query, &candidate.label)?;
+            let recent = candidate.recent_score.max(0.0);
+            let score = matched.score
+                + candidate.kind.priority_bonus()
+                + MAX_RECENT_BOOST * recent / (recent + 1.0);
+            Some((
+                candidate.label.to_lowercase(),
+                RankedPaletteResult {
+                    item: candidate.item,
+                    kind: candidate.kind,
+                    score,
+                    match_spans: matched.spans,
+                },
+            ))
+        })
+        .collect();
+
+    ranked.sort_by(|(label_a, a), (label_b, b)| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| kind_order(a.kind, b.kind))
+            .then_with(|| label_a.cmp(label_b))
+    });
+    ranked.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Orders commands before entities.
+fn kind_order(a: PaletteItemKind, b: PaletteItemKind) -> Ordering {
+    let rank = |kind| match kind {
+        PaletteItemKind::Command => 0,
+        PaletteItemKind::Entity => 1,
+    };
+    rank(a).cmp(&rank(b))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -230,4 +491,202 @@ mod tests {
             "Recent item (score={score_a}) should beat older frequent item (score={score_b})"
         );
     }
+
+    // -- Recent-item ranking --
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Access {
+        id: i64,
+        access_count: i32,
+        last_accessed_at: DateTime<Utc>,
+    }
+
+    impl RecentlyAccessed for Access {
+        fn access_count(&self) -> i32 {
+            self.access_count
+        }
+
+        fn last_accessed_at(&self) -> DateTime<Utc> {
+            self.last_accessed_at
+        }
+    }
+
+    fn access(id: i64, access_count: i32, last_accessed_at: DateTime<Utc>) -> Access {
+        Access {
+            id,
+            access_count,
+            last_accessed_at,
+        }
+    }
+
+    fn ranked_ids(items: Vec<Access>, now: DateTime<Utc>) -> Vec<i64> {
+        rank_recent_items(items, now)
+            .into_iter()
+            .map(|scored| scored.item.id)
+            .collect()
+    }
+
+    #[test]
+    fn frequent_older_item_outranks_single_recent_access() {
+        let now = Utc::now();
+        let items = vec![
+            access(1, 1, now - Duration::minutes(5)),
+            access(2, 30, now - Duration::days(2)),
+        ];
+        assert_eq!(ranked_ids(items, now), vec![2, 1]);
+    }
+
+    #[test]
+    fn decay_eventually_overtakes_frequency() {
+        let now = Utc::now();
+        let items = vec![
+            access(1, 1, now - Duration::minutes(5)),
+            access(2, 30, now - Duration::days(30)),
+        ];
+        assert_eq!(ranked_ids(items, now), vec![1, 2]);
+    }
+
+    #[test]
+    fn score_halves_every_half_life() {
+        let now = Utc::now();
+        let fresh = decayed_frecency_score(3, now, now);
+        let aged = decayed_frecency_score(
+            3,
+            now - Duration::hours(RECENCY_HALF_LIFE_HOURS as i64),
+            now,
+        );
+        assert!(
+            (fresh - 2.0).abs() < 1e-9,
+            "log2(4) should be 2, got {fresh}"
+        );
+        assert!(
+            (aged - 1.0).abs() < 1e-9,
+            "one half-life should halve, got {aged}"
+        );
+    }
+
+    #[test]
+    fn equal_scores_prefer_most_recent() {
+        let now = Utc::now();
+        // Future accesses are clamped to `now`, so both score identically.
+        let items = vec![access(1, 2, now), access(2, 2, now + Duration::minutes(1))];
+        assert_eq!(ranked_ids(items, now), vec![2, 1]);
+    }
+
+    #[test]
+    fn ranking_is_capped_at_max_recent_items() {
+        let now = Utc::now();
+        let items: Vec<Access> = (0..(MAX_RECENT_ITEMS as i64 + 5))
+            .map(|i| access(i, 1, now - Duration::minutes(i)))
+            .collect();
+        let ranked = rank_recent_items(items, now);
+        assert_eq!(ranked.len(), MAX_RECENT_ITEMS as usize);
+        assert_eq!(ranked[0].item.id, 0);
+    }
+
+    // -- Search result ranking --
+
+    fn candidate(
+        id: i64,
+        kind: PaletteItemKind,
+        label: &str,
+        recent_score: f64,
+    ) -> PaletteCandidate<i64> {
+        PaletteCandidate {
+            item: id,
+            kind,
+            label: label.to_string(),
+            recent_score,
+        }
+    }
+
+    fn ranked_items(query: &str, candidates: Vec<PaletteCandidate<i64>>) -> Vec<i64> {
+        rank_palette_results(query, candidates)
+            .into_iter()
+            .map(|result| result.item)
+            .collect()
+    }
+
+    #[test]
+    fn fuzzy_match_tiers() {
+        let score = |label| fuzzy_match("scene", label).map(|m| m.score);
+        assert_eq!(score("Scene"), Some(MATCH_SCORE_EXACT));
+        assert_eq!(score("Scenes"), Some(MATCH_SCORE_PREFIX));
+        assert_eq!(score("New scene"), Some(MATCH_SCORE_WORD));
+        assert_eq!(score("Subscene"), Some(MATCH_SCORE_SUBSTRING));
+        assert!(score("Sub Cue Node").unwrap() < MATCH_SCORE_SUBSTRING);
+        assert_eq!(score("Project"), None);
+        assert_eq!(fuzzy_match("  ", "Scene"), None);
+    }
+
+    #[test]
+    fn fuzzy_match_reports_spans() {
+        let matched = fuzzy_match("nsc", "New scene").unwrap();
+        assert_eq!(
+            matched.spans,
+            vec![
+                MatchSpan { start: 0, end: 1 },
+                MatchSpan { start: 4, end: 6 },
+            ]
+        );
+
+        let matched = fuzzy_match("SCENE", "New scene").unwrap();
+        assert_eq!(matched.spans, vec![MatchSpan { start: 4, end: 9 }]);
+    }
+
+    #[test]
+    fn exact_command_outranks_fuzzy_entity() {
+        let candidates = vec![
+            candidate(1, PaletteItemKind::Entity, "Export Project Timeline", 50.0),
+            candidate(2, PaletteItemKind::Command, "Export", 0.0),
+        ];
+        let ranked = rank_palette_results("export", candidates);
+        assert_eq!(ranked[0].item, 2);
+        assert_eq!(ranked[0].kind, PaletteItemKind::Command);
+        assert_eq!(ranked[0].match_spans, vec![MatchSpan { start: 0, end: 6 }]);
+
+        let candidates = vec![
+            candidate(1, PaletteItemKind::Entity, "Exterior Port", 50.0),
+            candidate(2, PaletteItemKind::Command, "Export", 0.0),
+        ];
+        assert_eq!(ranked_items("export", candidates), vec![2, 1]);
+    }
+
+    #[test]
+    fn recent_usage_breaks_ties() {
+        let candidates = vec![
+            candidate(1, PaletteItemKind::Entity, "Alice", 0.0),
+            candidate(2, PaletteItemKind::Entity, "Alice", 1.5),
+        ];
+        assert_eq!(ranked_items("alice", candidates), vec![2, 1]);
+    }
+
+    #[test]
+    fn recent_usage_does_not_override_match_tier() {
+        let candidates = vec![
+            candidate(1, PaletteItemKind::Entity, "Alicent", 100.0),
+            candidate(2, PaletteItemKind::Entity, "Alice", 0.0),
+        ];
+        assert_eq!(ranked_items("alice", candidates), vec![2, 1]);
+    }
+
+    #[test]
+    fn ranking_is_deterministic_and_drops_non_matches() {
+        let candidates = || {
+            vec![
+                candidate(1, PaletteItemKind::Entity, "Beta", 0.0),
+                candidate(2, PaletteItemKind::Entity, "Alpha", 0.0),
+                candidate(3, PaletteItemKind::Command, "Zeta", 0.0),
+                candidate(4, PaletteItemKind::Entity, "Omega", 0.0),
+            ]
+        };
+        // "ta" matches Beta, Zeta (substring); Alpha/Omega do not contain it.
+        assert_eq!(ranked_items("ta", candidates()), vec![3, 1]);
+        assert_eq!(
+            ranked_items("ta", candidates()),
+            ranked_items("ta", candidates())
+        );
+        // Alpha is a prefix match; Beta and Omega tie and fall back to label order.
+        assert_eq!(ranked_items("a", candidates()), vec![2, 3, 1, 4]);
+    }
 }
@@ -0,0 +1,261 @@
+//! Metadata completeness trend over time (PRD-66).
+//!
+//! Completeness is recorded as per-avatar snapshots whenever it changes.
+//! [`completeness_trend`] replays those snapshots in time order and emits one
+//! point per time bucket describing the project as of the end of that bucket,
+//! so a sprint's worth of edits downsamples to a short series for charting.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Duration, DurationRound, Utc};
+use serde::Serialize;
+
+use crate::error::CoreError;
+
+// ---------------------------------------------------------------------------
+// Granularity
+// ---------------------------------------------------------------------------
+
+/// Bucket size for a completeness trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendGranularity {
+    Hour,
+    Day,
+    /// ISO weeks, starting Monday 00:00 UTC.
+    Week,
+}
+
+impl TrendGranularity {
+    /// Parse a query-string value (`hour`, `day`, `week`).
+    pub fn parse(s: &str) -> Result<Self, CoreError> {
+        match s {
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid granularity '{s}'. Must be one of: hour, day, week"
+            ))),
+        }
+    }
+
+    /// Start of the bucket containing `at`.
+    pub fn bucket_start(self, at: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::Hour => at.duration_trunc(Duration::hours(1)).unwrap_or(at),
+            Self::Day => at.duration_trunc(Duration::days(1)).unwrap_or(at),
+            Self::Week => {
+                let day = at.duration_trunc(Duration::days(1)).unwrap_or(at);
+                day - Duration::days(i64::from(day.weekday().num_days_from_monday()))
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Snapshots and points
+// ---------------------------------------------------------------------------
+
+/// One recorded completeness value for an avatar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletenessSnapshot {
+    pub avatar_id: i64,
+    /// Completeness percentage, 0-100.
+    pub percentage: f64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Project completeness as of the end of one bucket.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TrendPoint {
+    pub bucket_start: DateTime<Utc>,
+    /// Mean of each tracked avatar's latest percentage.
+    pub average_percentage: f64,
+    /// Tracked avatars at 100%.
+    pub complete_avatars: usize,
+    /// Avatars with at least one snapshot so far.
+    pub tracked_avatars: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Trend computation
+// ---------------------------------------------------------------------------
+
+/// Downsample completeness snapshots into one point per non-empty bucket.
+///
+/// Each avatar contributes its most recent snapshot at or before the end of
+/// the bucket, so avatars that were not edited during a bucket still count
+/// at their last known value. Input order does not matter; points are
+/// returned oldest first.
+pub fn completeness_trend(
+    snapshots: &[CompletenessSnapshot],
+    granularity: TrendGranularity,
+) -> Vec<TrendPoint> {
+    let mut ordered: Vec<&CompletenessSnapshot> = snapshots.iter().collect();
+    ordered.sort_by_key(|s| s.recorded_at);
+
+    let mut latest: HashMap<i64, f64> = HashMap::new();
+    let mut points: Vec<TrendPoint> = Vec::new();
+
+    let mut iter = ordered.into_iter().peekable();
+    while let Some(snapshot) = iter.next() {
+        let bucket = granularity.bucket_start(snapshot.recorded_at);
+        latest.insert(snapshot.avatar_id, snapshot.percentage);
+
+        let bucket_continues = iter
+            .peek()
+            .is_some_and(|next| granularity.bucket_start(next.recorded_at) == bucket);
+        if !bucket_continues {
+            points.push(summarize(bucket, &latest));
+        }
+    }
+
+    points
+}
+
+/// Summarize the current per-avatar state as a point for `bucket_start`.
+fn summarize(bucket_start: DateTime<Utc>, latest: &HashMap<i64, f64>) -> TrendPoint {
+    let tracked_avatars = latest.len();
+    let total: f64 = latest.values().sum();
+    TrendPoint {
+        bucket_start,
+        average_percentage: if tracked_avatars > 0 {
+            total / tracked_avatars as f64
+        } else {
+            0.0
+        },
+        complete_avatars: latest.values().filter(|p| **p >= 100.0).count(),
+        tracked_avatars,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn snap(avatar_id: i64, percentage: f64, recorded_at: &str) -> CompletenessSnapshot {
+        CompletenessSnapshot {
+            avatar_id,
+            percentage,
+            recorded_at: at(recorded_at),
+        }
+    }
+
+    #[test]
+    fn parses_granularity() {
+        assert_eq!(
+            TrendGranularity::parse("day").unwrap(),
+            TrendGranularity::Day
+        );
+        assert!(TrendGranularity::parse("month").is_err());
+    }
+
+    #[test]
+    fn bucket_start_truncates_to_granularity() {
+        let t = at("2026-03-05T14:35:10Z"); // Thursday
+        assert_eq!(
+            TrendGranularity::Hour.bucket_start(t),
+            at("2026-03-05T14:00:00Z")
+        );
+        assert_eq!(
+            TrendGranularity::Day.bucket_start(t),
+            at("2026-03-05T00:00:00Z")
+        );
+        assert_eq!(
+            TrendGranularity::Week.bucket_start(t),
+            at("2026-03-02T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn empty_input_yields_no_points() {
+        assert!(completeness_trend(&[], TrendGranularity::Day).is_empty());
+    }
+
+    #[test]
+    fn snapshots_in_same_bucket_collapse_to_last_value() {
+        let snapshots = [
+            snap(1, 20.0, "2026-03-02T09:00:00Z"),
+            snap(1, 40.0, "2026-03-02T11:00:00Z"),
+            snap(1, 60.0, "2026-03-02T17:00:00Z"),
+        ];
+        let trend = completeness_trend(&snapshots, TrendGranularity::Day);
+        assert_eq!(trend.len(), 1);
+        assert_eq!(trend[0].bucket_start, at("2026-03-02T00:00:00Z"));
+        assert_eq!(trend[0].average_percentage, 60.0);
+    }
+
+    #[test]
+    fn avatars_carry_their_last_value_into_later_buckets() {
+        let snapshots = [
+            snap(1, 100.0, "2026-03-02T09:00:00Z"),
+            snap(2, 0.0, "2026-03-02T10:00:00Z"),
+            snap(2, 50.0, "2026-03-03T10:00:00Z"),
+        ];
+        let trend = completeness_trend(&snapshots, TrendGranularity::Day);
+        assert_eq!(trend.len(), 2);
+
+        assert_eq!(trend[0].average_percentage, 50.0);
+        assert_eq!(trend[0].complete_avatars, 1);
+        assert_eq!(trend[0].tracked_avatars, 2);
+
+        // Avatar 1 was not touched on day two but still counts at 100%.
+        assert_eq!(trend[1].bucket_start, at("2026-03-03T00:00:00Z"));
+        assert_eq!(trend[1].average_percentage, 75.0);
+        assert_eq!(trend[1].complete_avatars, 1);
+    }
+
+    #[test]
+    fn weekly_buckets_group_by_monday() {
+        let snapshots = [
+            snap(1, 10.0, "2026-03-01T23:00:00Z"), // Sunday, previous week
+            snap(1, 30.0, "2026-03-02T00:00:00Z"), // Monday
+            snap(1, 50.0, "2026-03-08T23:59:59Z"), // Sunday, same week
+            snap(1, 70.0, "2026-03-09T00:00:00Z"), // next Monday
+        ];
+        let trend = completeness_trend(&snapshots, TrendGranularity::Week);
+        let buckets: Vec<_> = trend
+            .iter()
+            .map(|p| (p.bucket_start, p.average_percentage))
+            .collect();
+        assert_eq!(
+            buckets,
+            vec![
+                (at("2026-02-23T00:00:00Z"), 10.0),
+                (at("2026-03-02T00:00:00Z"), 50.0),
+                (at("2026-03-09T00:00:00Z"), 70.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn increasing_completeness_produces_rising_trend() {
+        // Out-of-order input over a sprint, each avatar only ever improving.
+        let snapshots = [
+            snap(2, 40.0, "2026-03-04T12:00:00Z"),
+            snap(1, 25.0, "2026-03-02T12:00:00Z"),
+            snap(3, 10.0, "2026-03-02T15:00:00Z"),
+            snap(1, 75.0, "2026-03-05T08:00:00Z"),
+            snap(2, 20.0, "2026-03-02T13:00:00Z"),
+            snap(3, 100.0, "2026-03-06T08:00:00Z"),
+            snap(1, 50.0, "2026-03-03T08:00:00Z"),
+        ];
+        let trend = completeness_trend(&snapshots, TrendGranularity::Day);
+
+        assert_eq!(trend.len(), 5);
+        assert!(trend
+            .windows(2)
+            .all(|w| w[0].bucket_start < w[1].bucket_start));
+        assert!(trend
+            .windows(2)
+            .all(|w| w[1].average_percentage > w[0].average_percentage));
+        assert_eq!(trend.last().unwrap().complete_avatars, 1);
+    }
+}
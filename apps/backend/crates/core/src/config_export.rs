@@ -2,12 +2,14 @@
 //!
 //! Defines the portable configuration snapshot structure, sensitive-key
 //! exclusion list, and section name constants used by the config management
-//! API handlers.
+//! API handlers, plus the import change set recorded in the audit log.
 
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::diff::DiffStatus;
+use crate::project_config::diff_named_entries;
 use crate::types::Timestamp;
 
 // ---------------------------------------------------------------------------
@@ -93,6 +95,115 @@ pub struct ConfigValidationResult {
     pub errors: Vec<String>,
 }
 
+// ---------------------------------------------------------------------------
+// Import change set
+// ---------------------------------------------------------------------------
+
+/// Row keys that differ between installations without being settings.
+pub const IGNORED_DIFF_KEYS: &[&str] = &["id", "created_at", "updated_at"];
+
+/// One setting changed by a configuration import, recorded for audit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// Section the entry belongs to (e.g. `"roles"`).
+    pub section: String,
+    /// The entry's `name`.
+    pub name: String,
+    /// `added` or `changed`.
+    pub status: DiffStatus,
+    /// Changed fields before the import; `None` for added entries.
+    pub before: Option<serde_json::Value>,
+    /// Changed fields after the import (the whole entry when added).
+    pub after: serde_json::Value,
+}
+
+/// Compute the settings an import of `sections` would change.
+///
+/// Entries are matched by `name` within each section. For changed entries
+/// only the differing fields are kept, ignoring [`IGNORED_DIFF_KEYS`].
+/// Unchanged entries are omitted. Sensitive values are redacted on both
+/// sides so the result is safe to store in the audit log.
+pub fn compute_import_changes(
+    current: &HashMap<String, serde_json::Value>,
+    incoming: &HashMap<String, serde_json::Value>,
+    sections: &[String],
+) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+
+    for section in sections {
+        let as_array = |map: &HashMap<String, serde_json::Value>| {
+            map.get(section)
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default()
+        };
+        let current_entries = as_array(current);
+        let incoming_entries = as_array(incoming);
+
+        for entry in diff_named_entries(&current_entries, &incoming_entries) {
+            let Some(mut after) = entry.incoming_value else {
+                continue;
+            };
+            let (status, mut before) = match entry.status {
+                DiffStatus::Added => (DiffStatus::Added, None),
+                DiffStatus::Changed => {
+                    let before = entry.current_value.unwrap_or_default();
+                    let Some((before, changed)) = changed_fields(&before, &after) else {
+                        continue;
+                    };
+                    after = changed;
+                    (DiffStatus::Changed, Some(before))
+                }
+                DiffStatus::Removed | DiffStatus::Unchanged => continue,
+            };
+
+            if let Some(before) = before.as_mut() {
+                redact_sensitive(before);
+            }
+            redact_sensitive(&mut after);
+            changes.push(ConfigChange {
+                section: section.clone(),
+                name: entry.name,
+                status,
+                before,
+                after,
+            });
+        }
+    }
+
+    changes
+}
+
+/// Reduce two objects to the top-level fields whose values differ.
+///
+/// Returns `None` when nothing but [`IGNORED_DIFF_KEYS`] differs. Non-object
+/// values are returned whole.
+fn changed_fields(
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+) -> Option<(serde_json::Value, serde_json::Value)> {
+    let (Some(before_map), Some(after_map)) = (before.as_object(), after.as_object()) else {
+        return (before != after).then(|| (before.clone(), after.clone()));
+    };
+
+    let mut old = serde_json::Map::new();
+    let mut new = serde_json::Map::new();
+    let keys = before_map.keys().chain(after_map.keys());
+    for key in keys {
+        if IGNORED_DIFF_KEYS.contains(&key.as_str()) || old.contains_key(key) {
+            continue;
+        }
+        let old_val = before_map.get(key).cloned().unwrap_or_default();
+        let new_val = after_map.get(key).cloned().unwrap_or_default();
+        if old_val != new_val {
+            old.insert(key.clone(), old_val);
+            new.insert(key.clone(), new_val);
+        }
+    }
+
+    (!old.is_empty()).then(|| (old.into(), new.into()))
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -217,4 +328,94 @@ mod tests {
         assert_eq!(parsed.exported_by, "admin");
         assert!(parsed.sections.contains_key(SECTION_THEMES));
     }
+
+    // -- compute_import_changes ----------------------------------------------
+
+    fn sections(value: serde_json::Value) -> HashMap<String, serde_json::Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn import_changes_report_changed_fields_only() {
+        let current = sections(serde_json::json!({
+            "roles": [
+                { "id": 1, "name": "admin", "max_jobs": 10, "label": "Admin" },
+                { "id": 2, "name": "creator", "max_jobs": 5, "label": "Creator" },
+                { "id": 3, "name": "viewer", "max_jobs": 0, "label": "Viewer" },
+            ],
+        }));
+        let incoming = sections(serde_json::json!({
+            "roles": [
+                { "id": 11, "name": "admin", "max_jobs": 20, "label": "Admin" },
+                { "id": 12, "name": "creator", "max_jobs": 5, "label": "Maker" },
+                { "id": 13, "name": "viewer", "max_jobs": 0, "label": "Viewer" },
+            ],
+        }));
+
+        let changes = compute_import_changes(&current, &incoming, &["roles".to_string()]);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].section, "roles");
+        assert_eq!(changes[0].name, "admin");
+        assert_eq!(changes[0].status, DiffStatus::Changed);
+        assert_eq!(
+            changes[0].before,
+            Some(serde_json::json!({ "max_jobs": 10 }))
+        );
+        assert_eq!(changes[0].after, serde_json::json!({ "max_jobs": 20 }));
+        assert_eq!(changes[1].name, "creator");
+        assert_eq!(
+            changes[1].before,
+            Some(serde_json::json!({ "label": "Creator" }))
+        );
+        assert_eq!(changes[1].after, serde_json::json!({ "label": "Maker" }));
+    }
+
+    #[test]
+    fn import_changes_include_added_entries() {
+        let current = sections(serde_json::json!({ "themes": [] }));
+        let incoming = sections(serde_json::json!({
+            "themes": [{ "name": "dark", "accent": "#000" }],
+        }));
+
+        let changes = compute_import_changes(&current, &incoming, &["themes".to_string()]);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].status, DiffStatus::Added);
+        assert_eq!(changes[0].before, None);
+        assert_eq!(changes[0].after["accent"], "#000");
+    }
+
+    #[test]
+    fn import_changes_only_cover_requested_sections() {
+        let current = sections(serde_json::json!({ "roles": [], "themes": [] }));
+        let incoming = sections(serde_json::json!({
+            "roles": [{ "name": "admin" }],
+            "themes": [{ "name": "dark" }],
+        }));
+
+        let changes = compute_import_changes(&current, &incoming, &["themes".to_string()]);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].section, "themes");
+    }
+
+    #[test]
+    fn import_changes_redact_sensitive_values() {
+        let current = sections(serde_json::json!({
+            "extensions": [{ "name": "hook", "api_key": "old-key" }],
+        }));
+        let incoming = sections(serde_json::json!({
+            "extensions": [{ "name": "hook", "api_key": "new-key" }],
+        }));
+
+        let changes = compute_import_changes(&current, &incoming, &["extensions".to_string()]);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].before.as_ref().unwrap()["api_key"],
+            REDACTED_VALUE
+        );
+        assert_eq!(changes[0].after["api_key"], REDACTED_VALUE);
+    }
 }
@@ -1,8 +1,17 @@
 //! AES-256-GCM encryption/decryption for API keys at rest (PRD-114).
+//!
+//! Also provides key-ID tagged encryption for users' third-party service
+//! tokens (PRD-104), so the token key can be rotated without losing access
+//! to tokens encrypted under an older key.
+
+use std::collections::HashMap;
 
 use aes_gcm::aead::{Aead, KeyInit};
 use aes_gcm::{Aes256Gcm, Nonce};
 
+/// Length in bytes of an AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
 /// Encrypt a plaintext API key using AES-256-GCM.
 ///
 /// Returns `(ciphertext, nonce)` where both are Vec<u8>.
@@ -14,7 +23,7 @@ pub fn encrypt_api_key(
     let cipher =
         Aes256Gcm::new_from_slice(master_key).map_err(|e| CryptoError::KeyError(e.to_string()))?;
 
-    let mut nonce_bytes = [0u8; 12];
+    let mut nonce_bytes = [0u8; NONCE_LEN];
     rand::fill(&mut nonce_bytes);
     let nonce = Nonce::from_slice(&nonce_bytes);
 
@@ -31,7 +40,7 @@ pub fn decrypt_api_key(
     nonce_bytes: &[u8],
     master_key: &[u8; 32],
 ) -> Result<String, CryptoError> {
-    if nonce_bytes.len() != 12 {
+    if nonce_bytes.len() != NONCE_LEN {
         return Err(CryptoError::InvalidNonce);
     }
 
@@ -66,6 +75,100 @@ pub fn parse_master_key(hex: &str) -> Result<[u8; 32], CryptoError> {
     Ok(key)
 }
 
+// ---------------------------------------------------------------------------
+// Key-ID tagged token encryption (PRD-104)
+// ---------------------------------------------------------------------------
+
+/// Encryption keys for user API tokens, by key ID.
+///
+/// New tokens are encrypted with the active key; older keys are kept so
+/// tokens encrypted before a rotation can still be decrypted.
+#[derive(Clone)]
+pub struct TokenKeyring {
+    active_key_id: String,
+    keys: HashMap<String, [u8; 32]>,
+}
+
+impl TokenKeyring {
+    /// Create a keyring whose active key is `key`, tagged `key_id`.
+    pub fn new(key_id: impl Into<String>, key: [u8; 32]) -> Self {
+        let key_id = key_id.into();
+        Self {
+            keys: HashMap::from([(key_id.clone(), key)]),
+            active_key_id: key_id,
+        }
+    }
+
+    /// Add a retired key, kept for decrypting older tokens only.
+    pub fn with_retired_key(mut self, key_id: impl Into<String>, key: [u8; 32]) -> Self {
+        self.keys.entry(key_id.into()).or_insert(key);
+        self
+    }
+
+    /// Parse a keyring from `id:hex[,id:hex...]`.
+    ///
+    /// The first entry is the active key; the rest are retired keys.
+    pub fn parse(spec: &str) -> Result<Self, CryptoError> {
+        let mut keyring: Option<Self> = None;
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (key_id, hex) = entry
+                .split_once(':')
+                .filter(|(id, _)| !id.trim().is_empty())
+                .ok_or_else(|| {
+                    CryptoError::KeyError(format!("expected 'id:hex' key entry, got '{entry}'"))
+                })?;
+            let key = parse_master_key(hex)?;
+            keyring = Some(match keyring {
+                None => Self::new(key_id.trim(), key),
+                Some(k) => k.with_retired_key(key_id.trim(), key),
+            });
+        }
+        keyring.ok_or_else(|| CryptoError::KeyError("no token keys configured".into()))
+    }
+
+    /// ID of the key used for new tokens.
+    pub fn active_key_id(&self) -> &str {
+        &self.active_key_id
+    }
+}
+
+/// A token encrypted at rest, tagged with the ID of the key that sealed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedToken {
+    pub key_id: String,
+    /// Nonce followed by the AES-256-GCM ciphertext.
+    pub ciphertext: Vec<u8>,
+}
+
+/// Encrypt a service token with the keyring's active key.
+pub fn encrypt_token(
+    plaintext: &str,
+    keyring: &TokenKeyring,
+) -> Result<EncryptedToken, CryptoError> {
+    let key = &keyring.keys[&keyring.active_key_id];
+    let (ciphertext, nonce) = encrypt_api_key(plaintext, key)?;
+    Ok(EncryptedToken {
+        key_id: keyring.active_key_id.clone(),
+        ciphertext: [nonce, ciphertext].concat(),
+    })
+}
+
+/// Decrypt a service token with the key it was tagged with.
+pub fn decrypt_token(
+    token: &EncryptedToken,
+    keyring: &TokenKeyring,
+) -> Result<String, CryptoError> {
+    let key = keyring
+        .keys
+        .get(&token.key_id)
+        .ok_or_else(|| CryptoError::UnknownKeyId(token.key_id.clone()))?;
+    if token.ciphertext.len() < NONCE_LEN {
+        return Err(CryptoError::InvalidNonce);
+    }
+    let (nonce, ciphertext) = token.ciphertext.split_at(NONCE_LEN);
+    decrypt_api_key(ciphertext, nonce, key)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CryptoError {
     #[error("Invalid key: {0}")]
@@ -85,6 +188,9 @@ pub enum CryptoError {
 
     #[error("Decrypted data is not valid UTF-8")]
     InvalidUtf8,
+
+    #[error("No key configured for key ID '{0}'")]
+    UnknownKeyId(String),
 }
 
 #[cfg(test)]
@@ -141,4 +247,70 @@ mod tests {
         let result = parse_master_key("abcd");
         assert!(result.is_err());
     }
+
+    // -- token encryption ----------------------------------------------------
+
+    #[test]
+    fn token_round_trip_is_tagged_with_active_key() {
+        let keyring = TokenKeyring::new("k1", test_key());
+
+        let sealed = encrypt_token("hf_secret_token", &keyring).unwrap();
+
+        assert_eq!(sealed.key_id, "k1");
+        assert!(!sealed
+            .ciphertext
+            .windows(b"hf_secret_token".len())
+            .any(|w| w == b"hf_secret_token"));
+        assert_eq!(decrypt_token(&sealed, &keyring).unwrap(), "hf_secret_token");
+    }
+
+    #[test]
+    fn rotated_keyring_decrypts_old_tokens_by_key_id() {
+        let old_key = test_key();
+        let mut new_key = test_key();
+        new_key[0] = 200;
+
+        let before = TokenKeyring::new("k1", old_key);
+        let old_token = encrypt_token("civitai_old", &before).unwrap();
+
+        let rotated = TokenKeyring::new("k2", new_key).with_retired_key("k1", old_key);
+        let new_token = encrypt_token("civitai_new", &rotated).unwrap();
+
+        assert_eq!(new_token.key_id, "k2");
+        assert_eq!(decrypt_token(&old_token, &rotated).unwrap(), "civitai_old");
+        assert_eq!(decrypt_token(&new_token, &rotated).unwrap(), "civitai_new");
+
+        // Once the old key is dropped its tokens can no longer be read.
+        let dropped = TokenKeyring::new("k2", new_key);
+        assert!(matches!(
+            decrypt_token(&old_token, &dropped),
+            Err(CryptoError::UnknownKeyId(id)) if id == "k1"
+        ));
+    }
+
+    #[test]
+    fn keyring_parse_uses_first_entry_as_active() {
+        let hex1 = "0001020304050607080910111213141516171819202122232425262728293031";
+        let hex2 = "1001020304050607080910111213141516171819202122232425262728293031";
+
+        let keyring = TokenKeyring::parse(&format!("k2:{hex2}, k1:{hex1}")).unwrap();
+        assert_eq!(keyring.active_key_id(), "k2");
+
+        assert!(TokenKeyring::parse("").is_err());
+        assert!(TokenKeyring::parse(hex1).is_err());
+        assert!(TokenKeyring::parse("k1:abcd").is_err());
+    }
+
+    #[test]
+    fn truncated_token_fails_decrypt() {
+        let keyring = TokenKeyring::new("k1", test_key());
+        let token = EncryptedToken {
+            key_id: "k1".to_string(),
+            ciphertext: vec![0u8; 4],
+        };
+        assert!(matches!(
+            decrypt_token(&token, &keyring),
+            Err(CryptoError::InvalidNonce)
+        ));
+    }
 }
@@ -1,13 +1,15 @@
 //! Dashboard Widget Customization pure logic (PRD-89).
 //!
 //! Provides widget catalogue, layout validation, overlap detection,
-//! basic widget settings validation, share token generation, and
-//! layout priority resolution. All functions are pure (no I/O).
+//! basic widget settings validation, share token generation, layout
+//! priority resolution, and effective dashboard resolution with role-based
+//! widget access. All functions are pure (no I/O).
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::error::CoreError;
+use crate::roles::ROLE_ADMIN;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -266,12 +268,25 @@ pub fn generate_share_token() -> String {
 // ---------------------------------------------------------------------------
 
 /// Default platform layout used when no user or role config exists.
+pub fn platform_default_layout_items() -> Vec<LayoutItem> {
+    let item = |widget_id: &str, x, y, w, h| LayoutItem {
+        widget_id: widget_id.to_string(),
+        instance_id: format!("{widget_id}-1"),
+        x,
+        y,
+        w,
+        h,
+    };
+    vec![
+        item("active-tasks", 0, 0, 6, 3),
+        item("project-progress", 6, 0, 6, 3),
+        item("activity-feed", 0, 3, 12, 4),
+    ]
+}
+
+/// [`platform_default_layout_items`] as layout JSON.
 fn platform_default_layout() -> serde_json::Value {
-    serde_json::json!([
-        {"widget_id": "active-tasks",     "instance_id": "active-tasks-1",     "x": 0, "y": 0, "w": 6, "h": 3},
-        {"widget_id": "project-progress", "instance_id": "project-progress-1", "x": 6, "y": 0, "w": 6, "h": 3},
-        {"widget_id": "activity-feed",    "instance_id": "activity-feed-1",    "x": 0, "y": 3, "w": 12, "h": 4}
-    ])
+    serde_json::json!(platform_default_layout_items())
 }
 
 /// Resolve the effective dashboard layout from the priority chain.
@@ -298,6 +313,73 @@ pub fn resolve_layout_priority(
     platform_default_layout()
 }
 
+// ---------------------------------------------------------------------------
+// Effective dashboard resolution
+// ---------------------------------------------------------------------------
+
+/// A widget placed on a resolved dashboard, with its catalogue details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WidgetInstance {
+    /// Placement on the grid (flattened into the instance on the wire).
+    #[serde(flatten)]
+    pub item: LayoutItem,
+    /// Display name from the widget catalogue.
+    pub name: String,
+    /// Catalogue category.
+    pub category: WidgetCategory,
+}
+
+/// Whether a user with `role` may see `widget`.
+///
+/// `system` widgets surface admin-only service data and are limited to
+/// admins; every other widget is visible to all roles.
+pub fn widget_available_to_role(widget: &WidgetDefinition, role: &str) -> bool {
+    widget.category != WidgetCategory::System || role == ROLE_ADMIN
+}
+
+/// Filter a widget catalogue down to the widgets `role` may see.
+pub fn available_widgets_for_role(
+    catalogue: Vec<WidgetDefinition>,
+    role: &str,
+) -> Vec<WidgetDefinition> {
+    catalogue
+        .into_iter()
+        .filter(|w| widget_available_to_role(w, role))
+        .collect()
+}
+
+/// Resolve the widgets a user sees on their dashboard.
+///
+/// The first present layout wins: the user's config, then the role
+/// default, then `system_default`. Items whose widget is not in
+/// `available_widgets` (unknown, or inaccessible to the user) are dropped,
+/// and the rest are ordered top-to-bottom, then left-to-right.
+pub fn resolve_dashboard(
+    user_config: Option<&[LayoutItem]>,
+    role_default: Option<&[LayoutItem]>,
+    system_default: &[LayoutItem],
+    available_widgets: &[WidgetDefinition],
+) -> Vec<WidgetInstance> {
+    let layout = user_config.or(role_default).unwrap_or(system_default);
+
+    let mut widgets: Vec<WidgetInstance> = layout
+        .iter()
+        .filter_map(|item| {
+            let widget = available_widgets.iter().find(|w| w.id == item.widget_id)?;
+            Some(WidgetInstance {
+                item: item.clone(),
+                name: widget.name.clone(),
+                category: widget.category,
+            })
+        })
+        .collect();
+
+    widgets.sort_by(|a, b| {
+        (a.item.y, a.item.x, &a.item.instance_id).cmp(&(b.item.y, b.item.x, &b.item.instance_id))
+    });
+    widgets
+}
+
 // ---------------------------------------------------------------------------
 // Native widget catalogue
 // ---------------------------------------------------------------------------
@@ -943,6 +1025,98 @@ mod tests {
         assert!(!arr.is_empty());
     }
 
+    // -- resolve_dashboard ---------------------------------------------------
+
+    fn item(widget_id: &str, x: i32, y: i32) -> LayoutItem {
+        LayoutItem {
+            widget_id: widget_id.into(),
+            instance_id: format!("{widget_id}-1"),
+            x,
+            y,
+            w: 4,
+            h: 3,
+        }
+    }
+
+    fn instance_ids(widgets: &[WidgetInstance]) -> Vec<&str> {
+        widgets
+            .iter()
+            .map(|w| w.item.instance_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn resolve_dashboard_user_config_overrides_role_default() {
+        let user = vec![item("review-queue", 0, 0), item("disk-health", 4, 0)];
+        let role = vec![item("active-tasks", 0, 0)];
+        let catalogue = get_native_widget_catalogue();
+
+        let widgets = resolve_dashboard(
+            Some(&user),
+            Some(&role),
+            &platform_default_layout_items(),
+            &catalogue,
+        );
+        assert_eq!(
+            instance_ids(&widgets),
+            vec!["review-queue-1", "disk-health-1"]
+        );
+        assert_eq!(widgets[0].name, "Review Queue");
+
+        let widgets = resolve_dashboard(
+            None,
+            Some(&role),
+            &platform_default_layout_items(),
+            &catalogue,
+        );
+        assert_eq!(instance_ids(&widgets), vec!["active-tasks-1"]);
+    }
+
+    #[test]
+    fn resolve_dashboard_falls_back_to_system_default() {
+        let widgets = resolve_dashboard(
+            None,
+            None,
+            &platform_default_layout_items(),
+            &get_native_widget_catalogue(),
+        );
+        assert_eq!(
+            instance_ids(&widgets),
+            vec!["active-tasks-1", "project-progress-1", "activity-feed-1"]
+        );
+    }
+
+    #[test]
+    fn resolve_dashboard_drops_inaccessible_widgets() {
+        let user = vec![item("system-health", 0, 0), item("active-tasks", 4, 0)];
+        let creator = available_widgets_for_role(get_native_widget_catalogue(), "creator");
+        let admin = available_widgets_for_role(get_native_widget_catalogue(), ROLE_ADMIN);
+
+        let widgets = resolve_dashboard(Some(&user), None, &[], &creator);
+        assert_eq!(instance_ids(&widgets), vec!["active-tasks-1"]);
+
+        let widgets = resolve_dashboard(Some(&user), None, &[], &admin);
+        assert_eq!(
+            instance_ids(&widgets),
+            vec!["system-health-1", "active-tasks-1"]
+        );
+    }
+
+    #[test]
+    fn resolve_dashboard_drops_unknown_widgets_and_orders_by_position() {
+        let user = vec![
+            item("activity-feed", 4, 3),
+            item("no-such-widget", 0, 0),
+            item("disk-health", 0, 3),
+            item("review-queue", 8, 0),
+        ];
+        let widgets = resolve_dashboard(Some(&user), None, &[], &get_native_widget_catalogue());
+        assert_eq!(
+            instance_ids(&widgets),
+            vec!["review-queue-1", "disk-health-1", "activity-feed-1"]
+        );
+    }
+
     // -- json_type_matches (via validate_widget_settings) --------------------
 
     #[test]
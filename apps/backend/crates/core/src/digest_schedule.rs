@@ -0,0 +1,298 @@
+//! Digest delivery window computation (PRD-10).
+//!
+//! Users receive digests at a preferred hour in their own IANA timezone.
+//! [`next_digest_at`] turns that preference into the next UTC send instant,
+//! stepping in local calendar days so the wall-clock hour survives DST
+//! transitions. Pure functions only; the scheduler lives in `x121-events`.
+
+use chrono::{DateTime, Days, Duration, LocalResult, NaiveDate, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::error::CoreError;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Digest interval: one digest per day.
+pub const DIGEST_DAILY: &str = "daily";
+
+/// Digest interval: one digest per week.
+pub const DIGEST_WEEKLY: &str = "weekly";
+
+/// All valid `digest_interval` values.
+pub const VALID_DIGEST_INTERVALS: &[&str] = &[DIGEST_DAILY, DIGEST_WEEKLY];
+
+/// Timezone used when a user has not chosen one.
+pub const DEFAULT_DIGEST_TIMEZONE: &str = "UTC";
+
+/// Local hour digests are sent at when a user has not chosen one.
+pub const DEFAULT_DIGEST_HOUR: u32 = 9;
+
+// ---------------------------------------------------------------------------
+// Cadence
+// ---------------------------------------------------------------------------
+
+/// How often a user receives a digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestCadence {
+    Daily,
+    Weekly,
+}
+
+impl DigestCadence {
+    /// Parse a stored `digest_interval` value.
+    pub fn parse(s: &str) -> Result<Self, CoreError> {
+        match s {
+            DIGEST_DAILY => Ok(Self::Daily),
+            DIGEST_WEEKLY => Ok(Self::Weekly),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid digest interval '{s}'. Must be one of: {}",
+                VALID_DIGEST_INTERVALS.join(", ")
+            ))),
+        }
+    }
+
+    /// Length of one cadence period in local calendar days.
+    fn days(self) -> u64 {
+        match self {
+            Self::Daily => 1,
+            Self::Weekly => 7,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+/// Parse an IANA timezone name such as `Europe/Berlin`.
+pub fn parse_timezone(tz: &str) -> Result<Tz, CoreError> {
+    tz.parse::<Tz>().map_err(|_| {
+        CoreError::Validation(format!(
+            "Invalid timezone '{tz}'. Use an IANA name such as 'UTC' or 'America/New_York'."
+        ))
+    })
+}
+
+/// Validate a preferred local send hour (0-23).
+pub fn validate_digest_hour(hour: i32) -> Result<u32, CoreError> {
+    u32::try_from(hour).ok().filter(|h| *h < 24).ok_or_else(|| {
+        CoreError::Validation(format!("Digest hour must be between 0 and 23, got {hour}"))
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Window computation
+// ---------------------------------------------------------------------------
+
+/// Compute the next UTC instant a digest should be sent.
+///
+/// Finds the first `preferred_hour:00` in `tz` strictly after `last_sent`,
+/// then advances it to fill out the cadence in local calendar days. A late
+/// send therefore does not push the schedule later: a daily digest sent at
+/// 09:05 is next due at 09:00 tomorrow, a weekly one at 09:00 in seven days.
+///
+/// `preferred_hour` must be in `0..24`; see [`validate_digest_hour`].
+pub fn next_digest_at(
+    last_sent: DateTime<Utc>,
+    cadence: DigestCadence,
+    tz: Tz,
+    preferred_hour: u32,
+) -> DateTime<Utc> {
+    let mut date = last_sent.with_timezone(&tz).date_naive();
+    while local_hour_to_utc(tz, date, preferred_hour) <= last_sent {
+        date = date + Days::new(1);
+    }
+    local_hour_to_utc(tz, date + Days::new(cadence.days() - 1), preferred_hour)
+}
+
+/// Whether a digest is due at `now`. A digest that was never sent is due
+/// immediately.
+pub fn is_digest_due(
+    last_sent: Option<DateTime<Utc>>,
+    cadence: DigestCadence,
+    tz: Tz,
+    preferred_hour: u32,
+    now: DateTime<Utc>,
+) -> bool {
+    match last_sent {
+        None => true,
+        Some(sent) => next_digest_at(sent, cadence, tz, preferred_hour) <= now,
+    }
+}
+
+/// Resolve `hour:00` local time on `date` to a UTC instant.
+///
+/// When clocks fall back the hour occurs twice and the earlier one is used.
+/// When clocks spring forward past the hour, the pre-transition offset is
+/// applied, which lands on the moment the local clock resumes.
+pub(crate) fn local_hour_to_utc(tz: Tz, date: NaiveDate, hour: u32) -> DateTime<Utc> {
+    let naive = date
+        .and_hms_opt(hour, 0, 0)
+        .expect("preferred hour must be in 0..24");
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => at.with_timezone(&Utc),
+        LocalResult::None => {
+            let before = tz.offset_from_utc_datetime(&(naive - Duration::days(1)));
+            let offset = Duration::seconds(i64::from(before.fix().local_minus_utc()));
+            Utc.from_utc_datetime(&(naive - offset))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn new_york() -> Tz {
+        parse_timezone("America/New_York").unwrap()
+    }
+
+    // -- validation --
+
+    #[test]
+    fn parses_cadences() {
+        assert_eq!(DigestCadence::parse("daily").unwrap(), DigestCadence::Daily);
+        assert_eq!(
+            DigestCadence::parse("weekly").unwrap(),
+            DigestCadence::Weekly
+        );
+        assert!(DigestCadence::parse("24h").is_err());
+    }
+
+    #[test]
+    fn validates_timezone_and_hour() {
+        assert!(parse_timezone("Europe/Berlin").is_ok());
+        assert!(parse_timezone("Mars/Olympus").is_err());
+        assert_eq!(validate_digest_hour(0).unwrap(), 0);
+        assert_eq!(validate_digest_hour(23).unwrap(), 23);
+        assert!(validate_digest_hour(24).is_err());
+        assert!(validate_digest_hour(-1).is_err());
+    }
+
+    // -- daily cadence --
+
+    #[test]
+    fn daily_next_is_preferred_hour_tomorrow() {
+        let next = next_digest_at(
+            utc("2026-06-01T09:00:00Z"),
+            DigestCadence::Daily,
+            Tz::UTC,
+            9,
+        );
+        assert_eq!(next, utc("2026-06-02T09:00:00Z"));
+    }
+
+    #[test]
+    fn daily_late_send_does_not_drift() {
+        let next = next_digest_at(
+            utc("2026-06-01T09:40:00Z"),
+            DigestCadence::Daily,
+            Tz::UTC,
+            9,
+        );
+        assert_eq!(next, utc("2026-06-02T09:00:00Z"));
+    }
+
+    #[test]
+    fn daily_sent_before_preferred_hour_is_due_same_day() {
+        let next = next_digest_at(
+            utc("2026-06-01T06:00:00Z"),
+            DigestCadence::Daily,
+            Tz::UTC,
+            9,
+        );
+        assert_eq!(next, utc("2026-06-01T09:00:00Z"));
+    }
+
+    #[test]
+    fn daily_uses_local_hour_of_user_timezone() {
+        let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+        // 09:00 JST is 00:00 UTC.
+        let next = next_digest_at(utc("2026-06-01T00:00:00Z"), DigestCadence::Daily, tokyo, 9);
+        assert_eq!(next, utc("2026-06-02T00:00:00Z"));
+    }
+
+    // -- weekly cadence --
+
+    #[test]
+    fn weekly_next_is_same_weekday_next_week() {
+        // Monday 2026-06-01 09:00 EDT.
+        let sent = utc("2026-06-01T13:00:00Z");
+        let next = next_digest_at(sent, DigestCadence::Weekly, new_york(), 9);
+        assert_eq!(next, utc("2026-06-08T13:00:00Z"));
+    }
+
+    #[test]
+    fn weekly_across_spring_forward_keeps_local_hour() {
+        // Monday 2026-03-02 09:00 EST (UTC-5) -> Monday 2026-03-09 09:00 EDT (UTC-4).
+        let sent = utc("2026-03-02T14:00:00Z");
+        let next = next_digest_at(sent, DigestCadence::Weekly, new_york(), 9);
+        assert_eq!(next, utc("2026-03-09T13:00:00Z"));
+    }
+
+    // -- DST boundaries --
+
+    #[test]
+    fn daily_across_spring_forward_is_23_hours_later() {
+        // DST starts 2026-03-08 in New York.
+        let sent = utc("2026-03-07T14:00:00Z"); // 09:00 EST
+        let next = next_digest_at(sent, DigestCadence::Daily, new_york(), 9);
+        assert_eq!(next, utc("2026-03-08T13:00:00Z")); // 09:00 EDT
+    }
+
+    #[test]
+    fn daily_across_fall_back_is_25_hours_later() {
+        // DST ends 2026-11-01 in New York.
+        let sent = utc("2026-10-31T13:00:00Z"); // 09:00 EDT
+        let next = next_digest_at(sent, DigestCadence::Daily, new_york(), 9);
+        assert_eq!(next, utc("2026-11-01T14:00:00Z")); // 09:00 EST
+    }
+
+    #[test]
+    fn skipped_hour_resolves_to_when_clocks_resume() {
+        // 02:00 does not exist on 2026-03-08; clocks jump to 03:00 EDT (07:00 UTC).
+        let sent = utc("2026-03-07T07:00:00Z"); // 02:00 EST
+        let next = next_digest_at(sent, DigestCadence::Daily, new_york(), 2);
+        assert_eq!(next, utc("2026-03-08T07:00:00Z"));
+    }
+
+    #[test]
+    fn repeated_hour_resolves_to_first_occurrence() {
+        // 01:00 occurs twice on 2026-11-01; the first is 01:00 EDT (05:00 UTC).
+        let sent = utc("2026-10-31T05:00:00Z"); // 01:00 EDT
+        let next = next_digest_at(sent, DigestCadence::Daily, new_york(), 1);
+        assert_eq!(next, utc("2026-11-01T05:00:00Z"));
+
+        // Sending during the first 01:00 must not schedule the repeated 01:00.
+        let next = next_digest_at(next, DigestCadence::Daily, new_york(), 1);
+        assert_eq!(next, utc("2026-11-02T06:00:00Z"));
+    }
+
+    // -- due check --
+
+    #[test]
+    fn due_when_never_sent_or_window_elapsed() {
+        let now = utc("2026-06-02T09:30:00Z");
+        assert!(is_digest_due(None, DigestCadence::Daily, Tz::UTC, 9, now));
+        assert!(is_digest_due(
+            Some(utc("2026-06-01T09:00:00Z")),
+            DigestCadence::Daily,
+            Tz::UTC,
+            9,
+            now
+        ));
+        assert!(!is_digest_due(
+            Some(utc("2026-06-02T09:00:00Z")),
+            DigestCadence::Daily,
+            Tz::UTC,
+            9,
+            now
+        ));
+    }
+}
@@ -123,40 +123,108 @@ pub fn validate_download_url(url: &str) -> Result<(), CoreError> {
 // Placement resolution
 // ---------------------------------------------------------------------------
 
-/// Resolve the target directory for a model based on placement rules.
-///
-/// Takes a slice of `(model_type, base_model, target_dir, priority)` tuples
-/// from the database. Returns the best matching directory (highest priority),
-/// falling back to `/models/{model_type}/` if no rules match.
-pub fn resolve_target_directory(
-    model_type: &str,
-    base_model: Option<&str>,
-    rules: &[(String, Option<String>, String, i32)],
-) -> String {
-    let mut best_dir: Option<&str> = None;
-    let mut best_priority = i32::MIN;
-
-    for (rule_type, rule_base, rule_dir, rule_priority) in rules {
-        if rule_type != model_type {
-            continue;
-        }
+/// A placement rule as evaluated by [`resolve_placement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacementRuleSpec {
+    pub model_type: String,
+    /// Restricts the rule to one base model; `None` applies to all.
+    pub base_model: Option<String>,
+    pub target_directory: String,
+    pub priority: i32,
+}
 
-        // Match: rule has no base_model constraint, or base_model matches.
-        let base_match = match (rule_base.as_deref(), base_model) {
-            (None, _) => true,                // rule applies to all base models
-            (Some(rb), Some(bm)) => rb == bm, // specific match
-            (Some(_), None) => false,         // rule requires base_model but none given
-        };
+/// The downloaded file being placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadFileMeta<'a> {
+    pub model_type: &'a str,
+    pub base_model: Option<&'a str>,
+    pub file_name: &'a str,
+}
 
-        if base_match && *rule_priority > best_priority {
-            best_priority = *rule_priority;
-            best_dir = Some(rule_dir.as_str());
-        }
+/// Where a downloaded file lands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedPath {
+    pub directory: String,
+    /// `directory` joined with the file name.
+    pub path: String,
+    /// Index into the rules of the rule that matched, or `None` when the
+    /// default directory was used.
+    pub matched_rule: Option<usize>,
+}
+
+/// Default directory for a model type when no placement rule matches.
+pub fn default_placement_directory(model_type: &str) -> String {
+    format!("/models/{model_type}/")
+}
+
+/// Validate a placement rule before it is stored.
+///
+/// The model type must be known, a base model (if given) must not be blank,
+/// and the target directory must be an absolute path without `..`
+/// components so downloads cannot escape the models tree.
+pub fn validate_placement_rule(rule: &PlacementRuleSpec) -> Result<(), CoreError> {
+    validate_model_type(&rule.model_type)?;
+    if rule
+        .base_model
+        .as_deref()
+        .is_some_and(|b| b.trim().is_empty())
+    {
+        return Err(CoreError::Validation(
+            "Placement rule base_model must not be blank".to_string(),
+        ));
+    }
+    let dir = rule.target_directory.trim();
+    if dir.is_empty() {
+        return Err(CoreError::Validation(
+            "Placement rule target_directory must not be empty".to_string(),
+        ));
     }
+    if !dir.starts_with('/') {
+        return Err(CoreError::Validation(format!(
+            "Placement rule target_directory must be an absolute path, got: '{dir}'"
+        )));
+    }
+    if dir.split('/').any(|part| part == "..") {
+        return Err(CoreError::Validation(format!(
+            "Placement rule target_directory must not contain '..', got: '{dir}'"
+        )));
+    }
+    Ok(())
+}
 
-    best_dir
-        .map(|d| d.to_string())
-        .unwrap_or_else(|| format!("/models/{model_type}/"))
+/// Resolve where a downloaded file lands based on placement rules.
+///
+/// Rules are tried in precedence order: highest priority first; at equal
+/// priority a rule for the file's specific base model beats a generic one;
+/// remaining ties keep their order in `rules`. The first rule whose model
+/// type and base model match wins. A rule restricted to a base model never
+/// matches a file without one. Falls back to
+/// [`default_placement_directory`] when no rule matches.
+pub fn resolve_placement(file: &DownloadFileMeta<'_>, rules: &[PlacementRuleSpec]) -> ResolvedPath {
+    let mut order: Vec<usize> = (0..rules.len()).collect();
+    order.sort_by_key(|&i| {
+        let rule = &rules[i];
+        (std::cmp::Reverse(rule.priority), rule.base_model.is_none())
+    });
+
+    let matched = order.into_iter().find(|&i| {
+        let rule = &rules[i];
+        rule.model_type == file.model_type
+            && match rule.base_model.as_deref() {
+                None => true,
+                Some(base) => file.base_model == Some(base),
+            }
+    });
+
+    let directory = matched
+        .map(|i| rules[i].target_directory.clone())
+        .unwrap_or_else(|| default_placement_directory(file.model_type));
+    let path = format!("{}/{}", directory.trim_end_matches('/'), file.file_name);
+    ResolvedPath {
+        directory,
+        path,
+        matched_rule: matched,
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -313,43 +381,105 @@ mod tests {
         assert!(validate_download_url("just-a-path").is_err());
     }
 
-    // -- resolve_target_directory --------------------------------------------
+    // -- validate_placement_rule ---------------------------------------------
+
+    fn rule(base_model: Option<&str>, target_directory: &str, priority: i32) -> PlacementRuleSpec {
+        PlacementRuleSpec {
+            model_type: MODEL_TYPE_CHECKPOINT.to_string(),
+            base_model: base_model.map(str::to_string),
+            target_directory: target_directory.to_string(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn valid_placement_rule_accepted() {
+        assert!(validate_placement_rule(&rule(None, "/models/checkpoints/", 0)).is_ok());
+        assert!(validate_placement_rule(&rule(Some("SDXL"), "/models/sdxl", 5)).is_ok());
+    }
+
+    #[test]
+    fn invalid_placement_rules_rejected() {
+        let mut unknown_type = rule(None, "/models/x/", 0);
+        unknown_type.model_type = "wand".to_string();
+        assert!(validate_placement_rule(&unknown_type).is_err());
+
+        assert!(validate_placement_rule(&rule(Some("  "), "/models/x/", 0)).is_err());
+        assert!(validate_placement_rule(&rule(None, "", 0)).is_err());
+        assert!(validate_placement_rule(&rule(None, "models/x", 0)).is_err());
+        let msg = validate_placement_rule(&rule(None, "/models/../etc", 0))
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("must not contain '..'"));
+    }
+
+    // -- resolve_placement ---------------------------------------------------
+
+    fn checkpoint(base_model: Option<&str>) -> DownloadFileMeta<'_> {
+        DownloadFileMeta {
+            model_type: MODEL_TYPE_CHECKPOINT,
+            base_model,
+            file_name: "model.safetensors",
+        }
+    }
 
     #[test]
     fn resolve_with_specific_base_model() {
         let rules = vec![
-            ("checkpoint".into(), None, "/models/checkpoints/".into(), 0),
-            (
-                "checkpoint".into(),
-                Some("SDXL".into()),
-                "/models/checkpoints/sdxl/".into(),
-                10,
-            ),
+            rule(None, "/models/checkpoints/", 0),
+            rule(Some("SDXL"), "/models/checkpoints/sdxl/", 10),
         ];
-        let dir = resolve_target_directory("checkpoint", Some("SDXL"), &rules);
-        assert_eq!(dir, "/models/checkpoints/sdxl/");
+        let resolved = resolve_placement(&checkpoint(Some("SDXL")), &rules);
+        assert_eq!(resolved.directory, "/models/checkpoints/sdxl/");
+        assert_eq!(resolved.path, "/models/checkpoints/sdxl/model.safetensors");
+        assert_eq!(resolved.matched_rule, Some(1));
     }
 
     #[test]
     fn resolve_falls_back_to_generic_rule() {
         let rules = vec![
-            ("checkpoint".into(), None, "/models/checkpoints/".into(), 0),
-            (
-                "checkpoint".into(),
-                Some("SDXL".into()),
-                "/models/checkpoints/sdxl/".into(),
-                10,
-            ),
+            rule(None, "/models/checkpoints/", 0),
+            rule(Some("SDXL"), "/models/checkpoints/sdxl/", 10),
+        ];
+        let resolved = resolve_placement(&checkpoint(Some("SD 1.5")), &rules);
+        assert_eq!(resolved.directory, "/models/checkpoints/");
+        assert_eq!(resolved.matched_rule, Some(0));
+
+        // A base-model rule never matches a file without a base model.
+        let resolved = resolve_placement(&checkpoint(None), &rules);
+        assert_eq!(resolved.matched_rule, Some(0));
+    }
+
+    #[test]
+    fn resolve_precedence_priority_then_specificity_then_order() {
+        let rules = vec![
+            rule(None, "/a", 5),
+            rule(Some("SDXL"), "/b", 5),
+            rule(Some("SDXL"), "/c", 5),
+            rule(None, "/d", 1),
+        ];
+        // Equal priority: the specific rule beats the generic one, and the
+        // earlier of two equally specific rules wins.
+        let resolved = resolve_placement(&checkpoint(Some("SDXL")), &rules);
+        assert_eq!(resolved.directory, "/b");
+
+        // Higher priority beats specificity.
+        let rules = vec![
+            rule(Some("SDXL"), "/specific", 1),
+            rule(None, "/generic", 9),
         ];
-        let dir = resolve_target_directory("checkpoint", Some("SD 1.5"), &rules);
-        assert_eq!(dir, "/models/checkpoints/");
+        let resolved = resolve_placement(&checkpoint(Some("SDXL")), &rules);
+        assert_eq!(resolved.directory, "/generic");
     }
 
     #[test]
     fn resolve_falls_back_to_default_when_no_rules_match() {
-        let rules: Vec<(String, Option<String>, String, i32)> = vec![];
-        let dir = resolve_target_directory("lora", None, &rules);
-        assert_eq!(dir, "/models/lora/");
+        let mut lora_rule = rule(None, "/models/loras/", 0);
+        lora_rule.model_type = MODEL_TYPE_LORA.to_string();
+        let resolved = resolve_placement(&checkpoint(None), &[lora_rule]);
+        assert_eq!(resolved.directory, "/models/checkpoint/");
+        assert_eq!(resolved.path, "/models/checkpoint/model.safetensors");
+        assert_eq!(resolved.matched_rule, None);
     }
 
     // -- extract_filename_from_url -------------------------------------------
@@ -169,6 +169,59 @@ pub fn find_cross_matches(embeddings: &[(i64, Vec<f32>)], threshold: f64) -> Vec
     matches
 }
 
+// ---------------------------------------------------------------------------
+// Batch input
+// ---------------------------------------------------------------------------
+
+/// Maximum number of distinct avatars accepted by one batch check.
+pub const MAX_BATCH_CHECK_SIZE: usize = 200;
+
+/// Deduplicate batch input IDs, keeping first-seen order, and enforce
+/// [`MAX_BATCH_CHECK_SIZE`] on the distinct IDs.
+pub fn prepare_batch_ids(ids: &[i64]) -> Result<Vec<i64>, CoreError> {
+    let mut seen = std::collections::HashSet::with_capacity(ids.len());
+    let unique: Vec<i64> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+    if unique.len() > MAX_BATCH_CHECK_SIZE {
+        return Err(CoreError::Validation(format!(
+            "Batch check accepts at most {MAX_BATCH_CHECK_SIZE} avatars, got {}",
+            unique.len()
+        )));
+    }
+    Ok(unique)
+}
+
+/// Why an avatar in a batch check was skipped instead of checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchSkipReason {
+    /// The avatar does not exist or has been deleted.
+    NotFound,
+    /// The avatar has no face embedding to compare yet.
+    NoEmbedding,
+}
+
+impl BatchSkipReason {
+    /// Human-readable explanation for API responses.
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::NotFound => "Avatar not found",
+            Self::NoEmbedding => "Avatar has no face embedding; extract one before checking",
+        }
+    }
+}
+
+/// Parse a pgvector text literal such as `[0.1,0.2,0.3]`.
+///
+/// Returns `None` for malformed or empty vectors.
+pub fn parse_vector_literal(s: &str) -> Option<Vec<f32>> {
+    let inner = s.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let values = inner
+        .split(',')
+        .map(|v| v.trim().parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    (!values.is_empty()).then_some(values)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -298,4 +351,38 @@ mod tests {
         // Should return exactly one pair (1,2), not also (2,1).
         assert_eq!(matches.len(), 1);
     }
+
+    // -- Batch input ---------------------------------------------------------
+
+    #[test]
+    fn prepare_batch_ids_dedupes_in_order() {
+        assert_eq!(prepare_batch_ids(&[3, 1, 3, 2, 1]).unwrap(), vec![3, 1, 2]);
+        assert!(prepare_batch_ids(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn prepare_batch_ids_limits_distinct_ids() {
+        let at_limit: Vec<i64> = (0..MAX_BATCH_CHECK_SIZE as i64).collect();
+        assert!(prepare_batch_ids(&at_limit).is_ok());
+
+        // Repeats do not count towards the limit.
+        let mut repeated = at_limit.clone();
+        repeated.extend(&at_limit);
+        assert!(prepare_batch_ids(&repeated).is_ok());
+
+        let over: Vec<i64> = (0..=MAX_BATCH_CHECK_SIZE as i64).collect();
+        assert!(prepare_batch_ids(&over).is_err());
+    }
+
+    #[test]
+    fn parse_vector_literal_reads_pgvector_text() {
+        assert_eq!(
+            parse_vector_literal("[0.5,-1,2.25]"),
+            Some(vec![0.5, -1.0, 2.25])
+        );
+        assert_eq!(parse_vector_literal(" [1, 2] "), Some(vec![1.0, 2.0]));
+        assert_eq!(parse_vector_literal("[]"), None);
+        assert_eq!(parse_vector_literal("[1,x]"), None);
+        assert_eq!(parse_vector_literal("1,2"), None);
+    }
 }
@@ -4,9 +4,12 @@
 //! `classify_extraction_result` determines the correct status after face
 //! detection finishes, based on face count and confidence.
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
 use crate::error::CoreError;
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -19,6 +22,15 @@ pub const DEFAULT_CONFIDENCE_THRESHOLD: f64 = 0.7;
 /// Dimensionality of face embeddings produced by the extraction model.
 pub const EMBEDDING_DIMENSION: usize = 512;
 
+/// Maximum number of avatars accepted by a single batch extraction.
+pub const MAX_BATCH_EXTRACTION_SIZE: usize = 500;
+
+/// Extractions run concurrently by a batch when none is requested.
+pub const DEFAULT_BATCH_EXTRACTION_CONCURRENCY: usize = 4;
+
+/// Upper bound on the concurrency a batch extraction may request.
+pub const MAX_BATCH_EXTRACTION_CONCURRENCY: usize = 16;
+
 // ---------------------------------------------------------------------------
 // EmbeddingStatus enum
 // ---------------------------------------------------------------------------
@@ -103,6 +115,36 @@ pub fn validate_confidence_threshold(threshold: f64) -> Result<(), CoreError> {
     crate::threshold_validation::validate_unit_range(threshold, "Confidence threshold")
 }
 
+/// Validate a batch extraction request and return its avatar IDs with
+/// duplicates removed, in first-seen order.
+pub fn prepare_batch_extraction(
+    avatar_ids: &[DbId],
+    concurrency: usize,
+) -> Result<Vec<DbId>, CoreError> {
+    if avatar_ids.is_empty() {
+        return Err(CoreError::Validation(
+            "avatar_ids must not be empty".to_string(),
+        ));
+    }
+    if avatar_ids.len() > MAX_BATCH_EXTRACTION_SIZE {
+        return Err(CoreError::Validation(format!(
+            "Cannot extract embeddings for more than {MAX_BATCH_EXTRACTION_SIZE} avatars at once"
+        )));
+    }
+    if !(1..=MAX_BATCH_EXTRACTION_CONCURRENCY).contains(&concurrency) {
+        return Err(CoreError::Validation(format!(
+            "Concurrency must be between 1 and {MAX_BATCH_EXTRACTION_CONCURRENCY}, got {concurrency}"
+        )));
+    }
+
+    let mut seen = HashSet::with_capacity(avatar_ids.len());
+    Ok(avatar_ids
+        .iter()
+        .copied()
+        .filter(|id| seen.insert(*id))
+        .collect())
+}
+
 // ---------------------------------------------------------------------------
 // Classification
 // ---------------------------------------------------------------------------
@@ -278,4 +320,30 @@ mod tests {
             EmbeddingStatus::MultiFacePending
         );
     }
+
+    // -- Batch extraction ----------------------------------------------------
+
+    #[test]
+    fn prepare_batch_removes_duplicates_in_order() {
+        let ids = prepare_batch_extraction(&[3, 1, 3, 2, 1], 4).unwrap();
+        assert_eq!(ids, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn prepare_batch_rejects_empty() {
+        assert!(prepare_batch_extraction(&[], 4).is_err());
+    }
+
+    #[test]
+    fn prepare_batch_rejects_oversized() {
+        let ids: Vec<DbId> = (0..=MAX_BATCH_EXTRACTION_SIZE as DbId).collect();
+        assert!(prepare_batch_extraction(&ids, 4).is_err());
+    }
+
+    #[test]
+    fn prepare_batch_rejects_out_of_range_concurrency() {
+        assert!(prepare_batch_extraction(&[1], 0).is_err());
+        assert!(prepare_batch_extraction(&[1], MAX_BATCH_EXTRACTION_CONCURRENCY + 1).is_err());
+        assert!(prepare_batch_extraction(&[1], MAX_BATCH_EXTRACTION_CONCURRENCY).is_ok());
+    }
 }
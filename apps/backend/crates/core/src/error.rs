@@ -1,10 +1,13 @@
-use crate::types::DbId;
+use crate::types::{DbId, RunId};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CoreError {
     #[error("Entity not found: {entity} with id {id}")]
     NotFound { entity: &'static str, id: DbId },
 
+    #[error("Run not found: {entity} with id {run_id}")]
+    RunNotFound { entity: &'static str, run_id: RunId },
+
     #[error("Validation failed: {0}")]
     Validation(String),
 
@@ -0,0 +1,320 @@
+//! Job failure classification for diagnostics (PRD-28, PRD-64).
+//!
+//! Turns a failed job's error message and logs into a [`Diagnosis`]: a
+//! broad category, the likely cause, and a suggested fix. Configured
+//! failure signatures identify the known pattern, built-in heuristics pick
+//! the category, and fixes recorded against the pattern (PRD-64) are
+//! attached via [`Diagnosis::attach_known_fixes`].
+
+use serde::Serialize;
+
+use crate::failure_tracking::{match_failure, EffectivenessRating, FailureSignature};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Broad class of a job failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureCategory {
+    /// GPU or host memory was exhausted.
+    Memory,
+    /// The job or a backend call exceeded its time limit.
+    Timeout,
+    /// A model, LoRA, or other asset referenced by the workflow is missing.
+    MissingModel,
+    /// ComfyUI rejected the workflow before running it.
+    WorkflowValidation,
+    /// The connection to the worker or ComfyUI instance failed.
+    Connection,
+    /// No heuristic recognised the failure.
+    Unknown,
+}
+
+/// A fix previously recorded against a failure pattern.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnownFix {
+    pub fix_id: i64,
+    pub description: String,
+    pub parameters: Option<serde_json::Value>,
+    /// `resolved`, `improved`, or `no_effect`; `None` if not yet rated.
+    pub effectiveness: Option<String>,
+}
+
+/// Structured explanation of why a job failed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnosis {
+    pub category: FailureCategory,
+    pub likely_cause: String,
+    pub suggested_fix: String,
+    /// Key of the failure signature that matched, if any.
+    pub pattern_key: Option<String>,
+    /// The span of the error text or logs that matched the signature.
+    pub matched_text: Option<String>,
+    /// Fixes recorded for the matched pattern, most effective first.
+    pub known_fixes: Vec<KnownFix>,
+}
+
+// ---------------------------------------------------------------------------
+// Heuristics
+// ---------------------------------------------------------------------------
+
+/// A built-in rule: any needle (lowercase substring) selects the category.
+struct Heuristic {
+    category: FailureCategory,
+    needles: &'static [&'static str],
+    likely_cause: &'static str,
+    suggested_fix: &'static str,
+}
+
+/// Built-in rules, tried in order.
+const HEURISTICS: &[Heuristic] = &[
+    Heuristic {
+        category: FailureCategory::Memory,
+        needles: &[
+            "out of memory",
+            "outofmemoryerror",
+            "cuda oom",
+            "oom-kill",
+            "cudaerrormemoryallocation",
+            "failed to allocate",
+        ],
+        likely_cause: "The GPU ran out of memory while running the workflow.",
+        suggested_fix: "Lower the resolution, batch size, or segment length, \
+                        or retry on a worker with more VRAM.",
+    },
+    Heuristic {
+        category: FailureCategory::Timeout,
+        needles: &["timed out", "timeout", "deadline exceeded"],
+        likely_cause: "The job exceeded its time limit.",
+        suggested_fix: "Reduce the number of sampling steps or segments, \
+                        or raise the job timeout.",
+    },
+    Heuristic {
+        category: FailureCategory::MissingModel,
+        needles: &[
+            "value not in list",
+            "model not found",
+            "checkpoint not found",
+            "lora not found",
+            "no such file or directory",
+        ],
+        likely_cause: "A model or file referenced by the workflow is not \
+                       available on the worker.",
+        suggested_fix: "Install the missing model on the worker or update \
+                        the workflow to reference an available one.",
+    },
+    Heuristic {
+        category: FailureCategory::WorkflowValidation,
+        needles: &[
+            "prompt outputs failed validation",
+            "invalid prompt",
+            "required input is missing",
+            "node does not exist",
+            "missing_node_type",
+        ],
+        likely_cause: "ComfyUI rejected the workflow as invalid.",
+        suggested_fix: "Validate the workflow against the worker's installed \
+                        nodes and fill in any missing inputs.",
+    },
+    Heuristic {
+        category: FailureCategory::Connection,
+        needles: &[
+            "connection refused",
+            "connection reset",
+            "broken pipe",
+            "network is unreachable",
+            "websocket",
+        ],
+        likely_cause: "The connection to the ComfyUI instance was lost.",
+        suggested_fix: "Check that the worker is online and retry the job.",
+    },
+];
+
+const UNKNOWN_CAUSE: &str = "The failure did not match any known pattern.";
+const UNKNOWN_FIX: &str = "Inspect the job logs and error details.";
+
+// ---------------------------------------------------------------------------
+// Classification
+// ---------------------------------------------------------------------------
+
+/// Classify a job failure from its `error` message and `logs`.
+///
+/// `signatures` are matched against the error first, then the logs; the
+/// first hit sets `pattern_key`. The category, likely cause, and suggested
+/// fix come from built-in heuristics over both texts. An empty `known_fixes`
+/// is returned; callers attach the matched pattern's fixes afterwards.
+pub fn classify_failure(logs: &str, error: &str, signatures: &[FailureSignature]) -> Diagnosis {
+    let pattern = match_failure(error, signatures).or_else(|| match_failure(logs, signatures));
+
+    let haystack = format!("{error}\n{logs}").to_lowercase();
+    let heuristic = HEURISTICS
+        .iter()
+        .find(|h| h.needles.iter().any(|needle| haystack.contains(needle)));
+
+    let (category, likely_cause, suggested_fix) = match (heuristic, &pattern) {
+        (Some(h), _) => (
+            h.category,
+            h.likely_cause.to_string(),
+            h.suggested_fix.to_string(),
+        ),
+        (None, Some(m)) => (
+            FailureCategory::Unknown,
+            format!("Matches known failure pattern '{}'.", m.pattern_key),
+            UNKNOWN_FIX.to_string(),
+        ),
+        (None, None) => (
+            FailureCategory::Unknown,
+            UNKNOWN_CAUSE.to_string(),
+            UNKNOWN_FIX.to_string(),
+        ),
+    };
+
+    Diagnosis {
+        category,
+        likely_cause,
+        suggested_fix,
+        matched_text: pattern.as_ref().map(|m| m.matched_text.clone()),
+        pattern_key: pattern.map(|m| m.pattern_key),
+        known_fixes: Vec::new(),
+    }
+}
+
+impl Diagnosis {
+    /// Attach the fixes recorded for the matched pattern.
+    ///
+    /// Fixes are ordered most effective first (resolved, improved, unrated,
+    /// no effect). When the best fix is known to help, its description
+    /// replaces the heuristic suggestion.
+    pub fn attach_known_fixes(&mut self, mut fixes: Vec<KnownFix>) {
+        fixes.sort_by_key(|fix| effectiveness_rank(fix.effectiveness.as_deref()));
+        if let Some(best) = fixes.first() {
+            if effectiveness_rank(best.effectiveness.as_deref()) < 2 {
+                self.suggested_fix = best.description.clone();
+            }
+        }
+        self.known_fixes = fixes;
+    }
+}
+
+/// Sort rank for a fix's effectiveness; lower is better.
+fn effectiveness_rank(effectiveness: Option<&str>) -> u8 {
+    match effectiveness.map(EffectivenessRating::from_str) {
+        Some(EffectivenessRating::Resolved) => 0,
+        Some(EffectivenessRating::Improved) => 1,
+        None => 2,
+        Some(EffectivenessRating::NoEffect) => 3,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::failure_tracking::SignatureKind;
+
+    fn oom_signature() -> FailureSignature {
+        FailureSignature {
+            pattern_key: "oom".to_string(),
+            kind: SignatureKind::Substring,
+            pattern: "out of memory".to_string(),
+            priority: 10,
+        }
+    }
+
+    fn fix(fix_id: i64, description: &str, effectiveness: Option<&str>) -> KnownFix {
+        KnownFix {
+            fix_id,
+            description: description.to_string(),
+            parameters: None,
+            effectiveness: effectiveness.map(str::to_string),
+        }
+    }
+
+    // -- classify_failure --
+
+    #[test]
+    fn oom_error_is_a_memory_diagnosis_with_fix() {
+        let diagnosis = classify_failure(
+            "",
+            "torch.cuda.OutOfMemoryError: CUDA out of memory. Tried to allocate 2.00 GiB",
+            &[oom_signature()],
+        );
+        assert_eq!(diagnosis.category, FailureCategory::Memory);
+        assert!(diagnosis.likely_cause.contains("out of memory"));
+        assert!(diagnosis.suggested_fix.contains("Lower the resolution"));
+        assert_eq!(diagnosis.pattern_key.as_deref(), Some("oom"));
+        assert_eq!(diagnosis.matched_text.as_deref(), Some("out of memory"));
+    }
+
+    #[test]
+    fn oom_in_logs_is_found_without_signatures() {
+        let diagnosis = classify_failure(
+            "step 12/30\nRuntimeError: CUDA error: out of memory",
+            "Execution failed",
+            &[],
+        );
+        assert_eq!(diagnosis.category, FailureCategory::Memory);
+        assert_eq!(diagnosis.pattern_key, None);
+    }
+
+    #[test]
+    fn missing_model_is_recognised() {
+        let diagnosis = classify_failure(
+            "",
+            "Prompt outputs failed validation: Value not in list: ckpt_name: 'x.safetensors'",
+            &[],
+        );
+        // Missing models are checked before generic validation failures.
+        assert_eq!(diagnosis.category, FailureCategory::MissingModel);
+    }
+
+    #[test]
+    fn unrecognised_failure_is_unknown() {
+        let diagnosis = classify_failure("", "Something odd happened", &[]);
+        assert_eq!(diagnosis.category, FailureCategory::Unknown);
+        assert_eq!(diagnosis.likely_cause, UNKNOWN_CAUSE);
+        assert!(diagnosis.known_fixes.is_empty());
+    }
+
+    #[test]
+    fn signature_without_heuristic_names_the_pattern() {
+        let signature = FailureSignature {
+            pattern_key: "face_lost".to_string(),
+            kind: SignatureKind::Substring,
+            pattern: "no face detected".to_string(),
+            priority: 0,
+        };
+        let diagnosis = classify_failure("", "No face detected in frame 40", &[signature]);
+        assert_eq!(diagnosis.category, FailureCategory::Unknown);
+        assert!(diagnosis.likely_cause.contains("'face_lost'"));
+    }
+
+    // -- attach_known_fixes --
+
+    #[test]
+    fn effective_known_fix_replaces_suggestion() {
+        let mut diagnosis = classify_failure("", "CUDA out of memory", &[oom_signature()]);
+        diagnosis.attach_known_fixes(vec![
+            fix(1, "Restart the worker", Some("no_effect")),
+            fix(2, "Cap segments at 4 seconds", Some("resolved")),
+            fix(3, "Enable tiled VAE decode", None),
+        ]);
+        assert_eq!(diagnosis.suggested_fix, "Cap segments at 4 seconds");
+        let ids: Vec<i64> = diagnosis.known_fixes.iter().map(|f| f.fix_id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn unproven_known_fixes_keep_heuristic_suggestion() {
+        let mut diagnosis = classify_failure("", "CUDA out of memory", &[oom_signature()]);
+        let heuristic_fix = diagnosis.suggested_fix.clone();
+        diagnosis.attach_known_fixes(vec![fix(1, "Restart the worker", Some("no_effect"))]);
+        assert_eq!(diagnosis.suggested_fix, heuristic_fix);
+        assert_eq!(diagnosis.known_fixes.len(), 1);
+    }
+}
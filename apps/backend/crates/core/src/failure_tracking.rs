@@ -2,7 +2,13 @@
 //!
 //! Provides severity classification, pattern key computation, heatmap matrix
 //! building, and trend data structures for correlating quality gate failures
-//! with generation parameters.
+//! with generation parameters, plus signature matching that classifies job
+//! error text into known failure types.
+
+use std::str::FromStr;
+
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
 
 use crate::error::CoreError;
 use crate::threshold_validation::validate_unit_range;
@@ -250,6 +256,241 @@ pub struct TrendPoint {
     pub sample_count: i32,
 }
 
+// ---------------------------------------------------------------------------
+// Rate-of-change alerting
+// ---------------------------------------------------------------------------
+
+/// Default number of trailing trend points forming the alert window.
+pub const DEFAULT_SPIKE_WINDOW_POINTS: usize = 1;
+
+/// Default factor by which the window rate must exceed the baseline.
+pub const DEFAULT_SPIKE_FACTOR: f64 = 2.0;
+
+/// Default minimum samples required in both the window and the baseline.
+pub const DEFAULT_SPIKE_MIN_SAMPLES: i32 = 20;
+
+/// Default minimum window failure rate; smaller rates never alert, so a
+/// 0% -> 1% move does not count as a spike.
+pub const DEFAULT_SPIKE_MIN_RATE: f64 = 0.05;
+
+/// Configuration for [`detect_failure_spike`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpikeConfig {
+    /// Number of trailing points compared against all earlier points.
+    pub window_points: usize,
+    /// Window rate must be at least `baseline * factor`.
+    pub factor: f64,
+    /// Minimum samples in the window and in the baseline.
+    pub min_samples: i32,
+    /// Minimum window failure rate (0.0–1.0).
+    pub min_rate: f64,
+}
+
+impl Default for SpikeConfig {
+    fn default() -> Self {
+        Self {
+            window_points: DEFAULT_SPIKE_WINDOW_POINTS,
+            factor: DEFAULT_SPIKE_FACTOR,
+            min_samples: DEFAULT_SPIKE_MIN_SAMPLES,
+            min_rate: DEFAULT_SPIKE_MIN_RATE,
+        }
+    }
+}
+
+/// A detected failure-rate spike.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FailureSpikeAlert {
+    /// Period label of the first point in the window.
+    pub window_start: String,
+    pub window_rate: f64,
+    pub window_samples: i32,
+    pub baseline_rate: f64,
+    pub baseline_samples: i32,
+}
+
+/// Validate a spike configuration.
+pub fn validate_spike_config(config: &SpikeConfig) -> Result<(), CoreError> {
+    if config.window_points == 0 {
+        return Err(CoreError::Validation(
+            "window must contain at least one trend point".to_string(),
+        ));
+    }
+    if !config.factor.is_finite() || config.factor <= 1.0 {
+        return Err(CoreError::Validation(format!(
+            "spike factor must be greater than 1.0, got {}",
+            config.factor
+        )));
+    }
+    if config.min_samples < 1 {
+        return Err(CoreError::Validation(
+            "min_samples must be at least 1".to_string(),
+        ));
+    }
+    validate_unit_range(config.min_rate, "min_rate")
+}
+
+/// Flag a spike when the failure rate over the trailing window exceeds the
+/// baseline (all earlier points) by `config.factor`.
+///
+/// `points` must be in chronological order. Rates are weighted by sample
+/// count. Returns `None` when either side has fewer than
+/// `config.min_samples` samples, so sparse data never alerts.
+pub fn detect_failure_spike(
+    points: &[TrendPoint],
+    config: &SpikeConfig,
+) -> Option<FailureSpikeAlert> {
+    if config.window_points == 0 || points.len() <= config.window_points {
+        return None;
+    }
+    let (baseline, window) = points.split_at(points.len() - config.window_points);
+
+    let (window_rate, window_samples) = weighted_rate(window);
+    let (baseline_rate, baseline_samples) = weighted_rate(baseline);
+    if window_samples < config.min_samples || baseline_samples < config.min_samples {
+        return None;
+    }
+    if window_rate < config.min_rate || window_rate < baseline_rate * config.factor {
+        return None;
+    }
+
+    Some(FailureSpikeAlert {
+        window_start: window[0].period.clone(),
+        window_rate,
+        window_samples,
+        baseline_rate,
+        baseline_samples,
+    })
+}
+
+/// Sample-weighted failure rate and total sample count of `points`.
+fn weighted_rate(points: &[TrendPoint]) -> (f64, i32) {
+    let samples: i32 = points.iter().map(|p| p.sample_count.max(0)).sum();
+    if samples == 0 {
+        return (0.0, 0);
+    }
+    let failures: f64 = points
+        .iter()
+        .map(|p| p.failure_rate * f64::from(p.sample_count.max(0)))
+        .sum();
+    (failures / f64::from(samples), samples)
+}
+
+// ---------------------------------------------------------------------------
+// Failure signatures
+// ---------------------------------------------------------------------------
+
+/// Maximum compiled size of a signature regex, in bytes.
+pub const MAX_SIGNATURE_REGEX_SIZE: usize = 1 << 16;
+
+/// How a signature's pattern is matched against error text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureKind {
+    /// Case-insensitive substring match.
+    Substring,
+    /// Case-insensitive regular expression match.
+    Regex,
+}
+
+impl SignatureKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Substring => "substring",
+            Self::Regex => "regex",
+        }
+    }
+}
+
+impl FromStr for SignatureKind {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "substring" => Ok(Self::Substring),
+            "regex" => Ok(Self::Regex),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid signature kind: '{s}'. Must be one of: substring, regex"
+            ))),
+        }
+    }
+}
+
+/// A configurable rule that recognises one failure type in error text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailureSignature {
+    /// Stable key recorded on matching jobs, e.g. `"oom"`.
+    pub pattern_key: String,
+    pub kind: SignatureKind,
+    pub pattern: String,
+    /// Higher priorities are tried first; ties keep input order.
+    pub priority: i32,
+}
+
+/// The signature that classified a failure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PatternMatch {
+    pub pattern_key: String,
+    /// The span of the error text that matched.
+    pub matched_text: String,
+}
+
+/// Validate a signature before it is stored.
+///
+/// The key and pattern must be non-empty and regex patterns must compile
+/// within [`MAX_SIGNATURE_REGEX_SIZE`].
+pub fn validate_signature(signature: &FailureSignature) -> Result<(), CoreError> {
+    if signature.pattern_key.trim().is_empty() {
+        return Err(CoreError::Validation(
+            "pattern_key must not be empty".to_string(),
+        ));
+    }
+    if signature.pattern.is_empty() {
+        return Err(CoreError::Validation(
+            "pattern must not be empty".to_string(),
+        ));
+    }
+    if signature.kind == SignatureKind::Regex {
+        RegexBuilder::new(&signature.pattern)
+            .case_insensitive(true)
+            .size_limit(MAX_SIGNATURE_REGEX_SIZE)
+            .build()
+            .map_err(|e| CoreError::Validation(format!("Invalid signature regex: {e}")))?;
+    }
+    Ok(())
+}
+
+/// Classify `error_text` against `signatures`, highest priority first.
+///
+/// Returns the first signature that matches, or `None` when the failure is
+/// not recognised. Regex signatures that fail to compile are skipped.
+pub fn match_failure(error_text: &str, signatures: &[FailureSignature]) -> Option<PatternMatch> {
+    let mut ordered: Vec<&FailureSignature> = signatures.iter().collect();
+    ordered.sort_by_key(|sig| std::cmp::Reverse(sig.priority));
+
+    let lowered = error_text.to_lowercase();
+    ordered.into_iter().find_map(|sig| {
+        let span = match sig.kind {
+            SignatureKind::Substring => {
+                let needle = sig.pattern.to_lowercase();
+                let start = lowered.find(&needle)?;
+                lowered.get(start..start + needle.len())?.to_string()
+            }
+            SignatureKind::Regex => {
+                let re = RegexBuilder::new(&sig.pattern)
+                    .case_insensitive(true)
+                    .size_limit(MAX_SIGNATURE_REGEX_SIZE)
+                    .build()
+                    .ok()?;
+                re.find(error_text)?.as_str().to_string()
+            }
+        };
+        Some(PatternMatch {
+            pattern_key: sig.pattern_key.clone(),
+            matched_text: span,
+        })
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -567,4 +808,184 @@ mod tests {
         assert_eq!(SEVERITY_THRESHOLD_HIGH, 0.5);
         assert_eq!(SEVERITY_THRESHOLD_MEDIUM, 0.2);
     }
+
+    // -- match_failure --
+
+    fn signatures() -> Vec<FailureSignature> {
+        vec![
+            FailureSignature {
+                pattern_key: "generic_runtime".to_string(),
+                kind: SignatureKind::Substring,
+                pattern: "RuntimeError".to_string(),
+                priority: 0,
+            },
+            FailureSignature {
+                pattern_key: "oom".to_string(),
+                kind: SignatureKind::Regex,
+                pattern: r"(cuda )?out of memory|OOM".to_string(),
+                priority: 100,
+            },
+            FailureSignature {
+                pattern_key: "node_missing".to_string(),
+                kind: SignatureKind::Regex,
+                pattern: r"node (type )?\S+ (does not exist|not found)".to_string(),
+                priority: 90,
+            },
+        ]
+    }
+
+    #[test]
+    fn matches_oom_signature() {
+        let m = match_failure(
+            "RuntimeError: CUDA out of memory. Tried to allocate 2.00 GiB",
+            &signatures(),
+        )
+        .expect("should match");
+        assert_eq!(m.pattern_key, "oom");
+        assert_eq!(m.matched_text, "CUDA out of memory");
+    }
+
+    #[test]
+    fn matches_node_missing_signature() {
+        let m = match_failure(
+            "Prompt validation failed: node type VHS_VideoCombine does not exist",
+            &signatures(),
+        )
+        .expect("should match");
+        assert_eq!(m.pattern_key, "node_missing");
+    }
+
+    #[test]
+    fn unmatched_error_returns_none() {
+        assert!(match_failure("connection reset by peer", &signatures()).is_none());
+    }
+
+    #[test]
+    fn higher_priority_wins_over_input_order() {
+        // Both "generic_runtime" and "oom" match; oom has higher priority.
+        let m = match_failure("RuntimeError: OOM", &signatures()).unwrap();
+        assert_eq!(m.pattern_key, "oom");
+    }
+
+    #[test]
+    fn substring_match_is_case_insensitive() {
+        let m = match_failure("runtimeerror in sampler", &signatures()).unwrap();
+        assert_eq!(m.pattern_key, "generic_runtime");
+    }
+
+    #[test]
+    fn invalid_regex_signature_is_skipped() {
+        let sigs = vec![FailureSignature {
+            pattern_key: "broken".to_string(),
+            kind: SignatureKind::Regex,
+            pattern: "(unclosed".to_string(),
+            priority: 10,
+        }];
+        assert!(match_failure("(unclosed", &sigs).is_none());
+        assert!(validate_signature(&sigs[0]).is_err());
+    }
+
+    #[test]
+    fn validate_signature_rejects_empty_fields() {
+        let mut sig = signatures().remove(0);
+        assert!(validate_signature(&sig).is_ok());
+        sig.pattern.clear();
+        assert!(validate_signature(&sig).is_err());
+    }
+
+    // -- detect_failure_spike --
+
+    fn point(period: &str, failure_rate: f64, sample_count: i32) -> TrendPoint {
+        TrendPoint {
+            period: period.to_string(),
+            failure_rate,
+            sample_count,
+        }
+    }
+
+    #[test]
+    fn clear_spike_is_detected() {
+        let points = vec![
+            point("2026-02-01", 0.10, 40),
+            point("2026-02-02", 0.08, 50),
+            point("2026-02-03", 0.40, 30),
+        ];
+        let alert = detect_failure_spike(&points, &SpikeConfig::default()).expect("spike");
+        assert_eq!(alert.window_start, "2026-02-03");
+        assert_eq!(alert.window_samples, 30);
+        assert_eq!(alert.baseline_samples, 90);
+        assert!((alert.window_rate - 0.40).abs() < 1e-9);
+        assert!((alert.baseline_rate - 0.08888).abs() < 1e-3);
+    }
+
+    #[test]
+    fn rise_below_factor_does_not_alert() {
+        let points = vec![
+            point("2026-02-01", 0.10, 40),
+            point("2026-02-02", 0.10, 50),
+            point("2026-02-03", 0.15, 30),
+        ];
+        assert!(detect_failure_spike(&points, &SpikeConfig::default()).is_none());
+    }
+
+    #[test]
+    fn insufficient_window_samples_do_not_alert() {
+        let points = vec![point("2026-02-01", 0.05, 100), point("2026-02-02", 1.0, 3)];
+        assert!(detect_failure_spike(&points, &SpikeConfig::default()).is_none());
+    }
+
+    #[test]
+    fn insufficient_baseline_samples_do_not_alert() {
+        let points = vec![point("2026-02-01", 0.0, 2), point("2026-02-02", 0.9, 50)];
+        assert!(detect_failure_spike(&points, &SpikeConfig::default()).is_none());
+    }
+
+    #[test]
+    fn rate_below_minimum_does_not_alert() {
+        // 0% -> 2% is an infinite ratio but below the minimum rate.
+        let points = vec![
+            point("2026-02-01", 0.0, 100),
+            point("2026-02-02", 0.02, 100),
+        ];
+        assert!(detect_failure_spike(&points, &SpikeConfig::default()).is_none());
+    }
+
+    #[test]
+    fn multi_point_window_is_weighted() {
+        let config = SpikeConfig {
+            window_points: 2,
+            ..SpikeConfig::default()
+        };
+        let points = vec![
+            point("2026-02-01", 0.10, 100),
+            point("2026-02-02", 0.30, 10),
+            point("2026-02-03", 0.30, 10),
+        ];
+        let alert = detect_failure_spike(&points, &config).expect("spike");
+        assert_eq!(alert.window_start, "2026-02-02");
+        assert_eq!(alert.window_samples, 20);
+    }
+
+    #[test]
+    fn too_few_points_do_not_alert() {
+        assert!(
+            detect_failure_spike(&[point("2026-02-01", 0.9, 100)], &SpikeConfig::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn validate_spike_config_rejects_bad_values() {
+        assert!(validate_spike_config(&SpikeConfig::default()).is_ok());
+        let bad_factor = SpikeConfig {
+            factor: 1.0,
+            ..SpikeConfig::default()
+        };
+        assert!(validate_spike_config(&bad_factor).is_err());
+        let empty_window = SpikeConfig {
+            window_points: 0,
+            ..SpikeConfig::default()
+        };
+        assert!(validate_spike_config(&empty_window).is_err());
+    }
 }
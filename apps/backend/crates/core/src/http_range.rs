@@ -0,0 +1,224 @@
+//! HTTP byte-range request resolution for media streaming.
+//!
+//! Turns a `Range` header (and optional `If-Range` validator) into the slice
+//! of a file to serve. Only single ranges are supported: multi-range requests
+//! would need a `multipart/byteranges` body, so they are rejected as
+//! unsatisfiable rather than silently answered with the whole file.
+
+use chrono::{DateTime, Utc};
+
+/// Default read buffer size when streaming a file or range (64 KiB).
+pub const DEFAULT_STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// An inclusive byte range within a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    /// Number of bytes in the range; always at least one.
+    pub fn byte_count(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// `Content-Range` header value for this range of a `total`-byte file.
+    pub fn content_range(&self, total: u64) -> String {
+        format!("bytes {}-{}/{total}", self.start, self.end)
+    }
+}
+
+/// How to answer a request given its `Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    /// Serve the whole file with 200.
+    Full,
+    /// Serve one range with 206.
+    Partial(ByteRange),
+    /// Answer 416 with `Content-Range: bytes */{total}`.
+    Unsatisfiable,
+}
+
+/// `Content-Range` header value for a 416 response.
+pub fn unsatisfiable_content_range(total: u64) -> String {
+    format!("bytes */{total}")
+}
+
+/// Resolve a `Range` header value against a file of `total` bytes.
+///
+/// Supports `bytes=start-end`, open-ended `bytes=start-`, and suffix
+/// `bytes=-count`. Headers in another unit or that fail to parse are
+/// ignored, as RFC 9110 requires, and yield [`RangeRequest::Full`].
+pub fn resolve_range(header: &str, total: u64) -> RangeRequest {
+    let Some(spec) = header
+        .trim()
+        .strip_prefix("bytes=")
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    else {
+        return RangeRequest::Full;
+    };
+    if spec.contains(',') {
+        return RangeRequest::Unsatisfiable;
+    }
+    let Some((first, last)) = spec.split_once('-') else {
+        return RangeRequest::Full;
+    };
+    let (first, last) = (first.trim(), last.trim());
+
+    // Suffix range: the final `count` bytes.
+    if first.is_empty() {
+        return match last.parse::<u64>() {
+            Ok(0) => RangeRequest::Unsatisfiable,
+            Ok(_) if total == 0 => RangeRequest::Unsatisfiable,
+            Ok(count) => RangeRequest::Partial(ByteRange {
+                start: total.saturating_sub(count),
+                end: total - 1,
+            }),
+            Err(_) => RangeRequest::Full,
+        };
+    }
+
+    let Ok(start) = first.parse::<u64>() else {
+        return RangeRequest::Full;
+    };
+    let end = if last.is_empty() {
+        None
+    } else {
+        match last.parse::<u64>() {
+            Ok(end) if end >= start => Some(end),
+            _ => return RangeRequest::Full,
+        }
+    };
+
+    if start >= total {
+        return RangeRequest::Unsatisfiable;
+    }
+    RangeRequest::Partial(ByteRange {
+        start,
+        end: end.map_or(total - 1, |e| e.min(total - 1)),
+    })
+}
+
+/// Whether a conditional range request may be answered partially.
+///
+/// With no `If-Range` header the range always applies. Otherwise the
+/// validator must match the current representation exactly: an entity tag
+/// must equal `etag` (weak tags never match), and a date must equal
+/// `last_modified`. On a mismatch the client should get the full file.
+pub fn if_range_matches(if_range: Option<&str>, etag: &str, last_modified: &str) -> bool {
+    match if_range.map(str::trim) {
+        None => true,
+        Some(v) if v.starts_with("W/") => false,
+        Some(v) if v.starts_with('"') => v == etag,
+        Some(v) => v == last_modified,
+    }
+}
+
+/// Strong entity tag for a file, derived from its size and modification time.
+pub fn file_etag(size: u64, modified: DateTime<Utc>) -> String {
+    format!(
+        "\"{size:x}-{:x}\"",
+        modified.timestamp_nanos_opt().unwrap_or(0)
+    )
+}
+
+/// Format a timestamp as an IMF-fixdate for `Last-Modified`.
+pub fn http_date(at: DateTime<Utc>) -> String {
+    at.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOTAL: u64 = 1000;
+
+    fn partial(start: u64, end: u64) -> RangeRequest {
+        RangeRequest::Partial(ByteRange { start, end })
+    }
+
+    #[test]
+    fn mid_file_range() {
+        let range = resolve_range("bytes=100-199", TOTAL);
+        assert_eq!(range, partial(100, 199));
+        let RangeRequest::Partial(r) = range else {
+            unreachable!()
+        };
+        assert_eq!(r.byte_count(), 100);
+        assert_eq!(r.content_range(TOTAL), "bytes 100-199/1000");
+    }
+
+    #[test]
+    fn open_ended_range_runs_to_end_of_file() {
+        assert_eq!(resolve_range("bytes=900-", TOTAL), partial(900, 999));
+    }
+
+    #[test]
+    fn end_past_file_is_clamped() {
+        assert_eq!(resolve_range("bytes=990-5000", TOTAL), partial(990, 999));
+    }
+
+    #[test]
+    fn suffix_range_serves_last_bytes() {
+        assert_eq!(resolve_range("bytes=-100", TOTAL), partial(900, 999));
+        assert_eq!(resolve_range("bytes=-5000", TOTAL), partial(0, 999));
+        assert_eq!(
+            resolve_range("bytes=-0", TOTAL),
+            RangeRequest::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn start_beyond_file_is_unsatisfiable() {
+        assert_eq!(
+            resolve_range("bytes=1000-", TOTAL),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(
+            resolve_range("bytes=2000-3000", TOTAL),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(resolve_range("bytes=0-", 0), RangeRequest::Unsatisfiable);
+        assert_eq!(unsatisfiable_content_range(TOTAL), "bytes */1000");
+    }
+
+    #[test]
+    fn multi_range_is_rejected() {
+        assert_eq!(
+            resolve_range("bytes=0-99,200-299", TOTAL),
+            RangeRequest::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn malformed_or_foreign_ranges_are_ignored() {
+        for header in ["items=0-10", "bytes=", "bytes=abc-", "bytes=50-10", "0-10"] {
+            assert_eq!(resolve_range(header, TOTAL), RangeRequest::Full, "{header}");
+        }
+    }
+
+    #[test]
+    fn if_range_requires_exact_validator_match() {
+        let etag = "\"3e8-1\"";
+        let date = "Tue, 03 Mar 2026 10:00:00 GMT";
+        assert!(if_range_matches(None, etag, date));
+        assert!(if_range_matches(Some(etag), etag, date));
+        assert!(if_range_matches(Some(date), etag, date));
+        assert!(!if_range_matches(Some("\"other\""), etag, date));
+        assert!(!if_range_matches(Some("W/\"3e8-1\""), etag, date));
+        assert!(!if_range_matches(
+            Some("Wed, 04 Mar 2026 10:00:00 GMT"),
+            etag,
+            date
+        ));
+    }
+
+    #[test]
+    fn formats_validators() {
+        let at: DateTime<Utc> = "2026-03-03T10:00:00Z".parse().unwrap();
+        assert_eq!(http_date(at), "Tue, 03 Mar 2026 10:00:00 GMT");
+        assert!(file_etag(1000, at).starts_with("\"3e8-"));
+    }
+}
@@ -3,6 +3,7 @@
 //! These match the seeded values in the `import_report_statuses` lookup table.
 
 pub const IMPORT_STATUS_PREVIEW: &str = "preview";
+pub const IMPORT_STATUS_COMMITTING: &str = "committing";
 pub const IMPORT_STATUS_COMMITTED: &str = "committed";
 pub const IMPORT_STATUS_PARTIAL: &str = "partial";
 pub const IMPORT_STATUS_FAILED: &str = "failed";
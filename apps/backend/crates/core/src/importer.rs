@@ -10,6 +10,7 @@
 //!   uniqueness conflict detection.
 
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 // ── Constants ────────────────────────────────────────────────────────
@@ -261,6 +262,63 @@ pub fn is_supported_extension(extension: &str) -> bool {
     SUPPORTED_EXTENSIONS.contains(&extension.to_lowercase().as_str())
 }
 
+// ── Resumable scanning ───────────────────────────────────────────────
+
+/// Default number of file metadata reads in flight during a folder scan.
+pub const DEFAULT_SCAN_CONCURRENCY: usize = 8;
+
+/// Number of files processed between persisted scan checkpoints.
+pub const SCAN_CHECKPOINT_INTERVAL: usize = 200;
+
+/// Compare two `/`-separated relative paths component by component.
+///
+/// This is the order in which a depth-first walk visits files when every
+/// directory's entries are sorted by name. Plain string order differs:
+/// `a-b/x` sorts before `a/x` as a string, but the walk visits `a` first.
+pub fn compare_scan_paths(a: &str, b: &str) -> Ordering {
+    a.split('/').cmp(b.split('/'))
+}
+
+/// Whether the file at `relative_path` still needs scanning, given the
+/// `cursor` (last scanned file) of an interrupted scan.
+pub fn is_after_scan_cursor(relative_path: &str, cursor: Option<&str>) -> bool {
+    cursor.is_none_or(|cursor| compare_scan_paths(relative_path, cursor) == Ordering::Greater)
+}
+
+/// Whether the directory at `relative_dir` can contain files that sort
+/// after `cursor`.
+///
+/// A resumed walk skips directories for which this is `false` without
+/// listing them.
+pub fn scan_dir_may_follow_cursor(relative_dir: &str, cursor: Option<&str>) -> bool {
+    let Some(cursor) = cursor else {
+        return true;
+    };
+    let depth = relative_dir.split('/').count();
+    relative_dir.split('/').cmp(cursor.split('/').take(depth)) != Ordering::Less
+}
+
+/// Build a [`ParsedFile`] from a `/`-separated path relative to the import
+/// root and its size on disk.
+pub fn parsed_file_from_relative(relative_path: &str, file_size_bytes: u64) -> ParsedFile {
+    let mut parts: Vec<&str> = relative_path.split('/').collect();
+    let file_name = parts.pop().unwrap_or_default().to_string();
+    let file_extension = match file_name.rfind('.') {
+        Some(pos) if pos > 0 => file_name[pos + 1..].to_lowercase(),
+        _ => String::new(),
+    };
+    let parent_folders: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+
+    ParsedFile {
+        relative_path: relative_path.to_string(),
+        file_name,
+        file_extension,
+        file_size_bytes,
+        depth: parent_folders.len(),
+        parent_folders,
+    }
+}
+
 // ── Private helpers ──────────────────────────────────────────────────
 
 /// Extract a stem (name without extension) from a filename.
@@ -575,4 +633,75 @@ mod tests {
         assert_eq!(stem_from_filename("noext"), "noext");
         assert_eq!(stem_from_filename(".hidden"), ".hidden");
     }
+
+    // -- resumable scanning tests --
+
+    #[test]
+    fn test_scan_order_is_component_wise() {
+        let mut paths = vec!["a-b/x.png", "a/y.png", "a/b/z.png", "b.png"];
+        paths.sort_by(|a, b| compare_scan_paths(a, b));
+        assert_eq!(paths, vec!["a/b/z.png", "a/y.png", "a-b/x.png", "b.png"]);
+    }
+
+    #[test]
+    fn test_without_cursor_everything_is_pending() {
+        assert!(is_after_scan_cursor("a/1.png", None));
+        assert!(scan_dir_may_follow_cursor("a", None));
+    }
+
+    #[test]
+    fn test_files_at_or_before_cursor_are_skipped() {
+        let cursor = Some("Bob/bio.json");
+        assert!(!is_after_scan_cursor("Alice/portrait.png", cursor));
+        assert!(!is_after_scan_cursor("Bob/bio.json", cursor));
+        assert!(is_after_scan_cursor("Bob/portrait.png", cursor));
+        assert!(is_after_scan_cursor("Carol/portrait.png", cursor));
+    }
+
+    #[test]
+    fn test_dirs_before_cursor_are_pruned() {
+        let cursor = Some("Bob/images/2.png");
+        assert!(!scan_dir_may_follow_cursor("Alice", cursor));
+        assert!(!scan_dir_may_follow_cursor("Bob/bio", cursor));
+        assert!(scan_dir_may_follow_cursor("Bob", cursor));
+        assert!(scan_dir_may_follow_cursor("Bob/images", cursor));
+        assert!(scan_dir_may_follow_cursor("Bob/video", cursor));
+        assert!(scan_dir_may_follow_cursor("Carol", cursor));
+    }
+
+    #[test]
+    fn test_interrupted_scan_resumes_after_cursor() {
+        let tree = [
+            "Alice/bio.json",
+            "Alice/portrait.png",
+            "Bob/bio.json",
+            "Carol/portrait.png",
+        ];
+
+        // First run scanned two files before being interrupted.
+        let cursor = Some("Alice/portrait.png");
+        let remaining: Vec<&str> = tree
+            .iter()
+            .copied()
+            .filter(|path| {
+                let dir = path.rsplit_once('/').map_or("", |(dir, _)| dir);
+                scan_dir_may_follow_cursor(dir, cursor) && is_after_scan_cursor(path, cursor)
+            })
+            .collect();
+        assert_eq!(remaining, vec!["Bob/bio.json", "Carol/portrait.png"]);
+    }
+
+    #[test]
+    fn test_parsed_file_from_relative() {
+        let file = parsed_file_from_relative("Alice/images/Portrait.PNG", 42);
+        assert_eq!(file.file_name, "Portrait.PNG");
+        assert_eq!(file.file_extension, "png");
+        assert_eq!(file.depth, 2);
+        assert_eq!(file.parent_folders, vec!["Alice", "images"]);
+        assert_eq!(file.file_size_bytes, 42);
+
+        let root_file = parsed_file_from_relative("readme", 1);
+        assert_eq!(root_file.depth, 0);
+        assert_eq!(root_file.file_extension, "");
+    }
 }
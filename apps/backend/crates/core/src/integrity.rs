@@ -1,7 +1,12 @@
 //! System integrity constants, validation, and health assessment (PRD-43).
 //!
 //! Provides scan-type constants, model-type constants, repair action
-//! constants, validation helpers, and a simple health assessment function.
+//! constants, validation helpers, checksum mismatch detection, per-file
+//! report tallying, and a simple health assessment function.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
 
 use crate::error::CoreError;
 
@@ -19,6 +24,23 @@ pub const SCAN_TYPE_FULL: &str = "full";
 /// All valid scan types.
 pub const VALID_SCAN_TYPES: &[&str] = &[SCAN_TYPE_MODELS, SCAN_TYPE_NODES, SCAN_TYPE_FULL];
 
+// ---------------------------------------------------------------------------
+// Scan file status constants
+// ---------------------------------------------------------------------------
+
+/// The file is present and its hash matches.
+pub const FILE_STATUS_OK: &str = "ok";
+/// The file was not found on the worker.
+pub const FILE_STATUS_MISSING: &str = "missing";
+/// The file is present but its hash differs from the expected checksum.
+pub const FILE_STATUS_CORRUPTED: &str = "corrupted";
+
+/// Default number of per-file records in a non-streaming scan report.
+pub const DEFAULT_REPORT_FILES_LIMIT: i64 = 100;
+/// Maximum number of per-file records in a non-streaming scan report;
+/// larger reports should be streamed.
+pub const MAX_REPORT_FILES_LIMIT: i64 = 1000;
+
 // ---------------------------------------------------------------------------
 // Health status constants
 // ---------------------------------------------------------------------------
@@ -63,6 +85,19 @@ pub const REPAIR_FULL: &str = "full_verify_repair";
 /// All valid repair actions.
 pub const VALID_REPAIR_ACTIONS: &[&str] = &[REPAIR_SYNC_MODELS, REPAIR_INSTALL_NODES, REPAIR_FULL];
 
+/// Steps of a full repair, in the order they run.
+pub const FULL_REPAIR_STEPS: &[&str] = &[REPAIR_SYNC_MODELS, REPAIR_INSTALL_NODES];
+
+/// The scan type that carries out a single repair step, or `None` for
+/// actions that are not single steps (such as [`REPAIR_FULL`]).
+pub fn repair_step_scan_type(step: &str) -> Option<&'static str> {
+    match step {
+        REPAIR_SYNC_MODELS => Some(SCAN_TYPE_MODELS),
+        REPAIR_INSTALL_NODES => Some(SCAN_TYPE_NODES),
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Validation functions
 // ---------------------------------------------------------------------------
@@ -103,6 +138,119 @@ pub fn validate_repair_action(action: &str) -> Result<(), CoreError> {
     }
 }
 
+/// Length of a hex-encoded SHA-256 digest.
+pub const CHECKSUM_HEX_LEN: usize = 64;
+
+/// Validate that an expected model checksum is a hex-encoded SHA-256 digest.
+///
+/// Either letter case is accepted; comparisons in [`detect_mismatches`] are
+/// case-insensitive.
+pub fn validate_checksum_format(s: &str) -> Result<(), String> {
+    if s.len() != CHECKSUM_HEX_LEN {
+        return Err(format!(
+            "Checksum must be {CHECKSUM_HEX_LEN} hex characters (SHA-256), got {}",
+            s.len()
+        ));
+    }
+    if let Some(c) = s.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("Checksum contains non-hex character '{c}'"));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Checksum mismatch detection
+// ---------------------------------------------------------------------------
+
+/// How a scanned model differs from its expected checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MismatchKind {
+    /// The model was not found by the scan.
+    Missing,
+    /// The model was found but its hash differs from the expected one.
+    Corrupted,
+}
+
+/// A model whose scanned checksum does not match the expected one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Mismatch {
+    pub model_name: String,
+    pub expected: String,
+    /// Hash reported by the scan, `None` when the model is missing.
+    pub actual: Option<String>,
+    pub kind: MismatchKind,
+}
+
+/// Compare expected checksums against the hashes reported by a scan.
+///
+/// Both maps are keyed by model name. Models present in `scanned` but not
+/// in `expected` are ignored, since there is nothing to verify them against.
+/// Hashes are compared case-insensitively. The result is sorted by model
+/// name.
+pub fn detect_mismatches(
+    expected: &HashMap<String, String>,
+    scanned: &HashMap<String, String>,
+) -> Vec<Mismatch> {
+    let mut mismatches: Vec<Mismatch> = expected
+        .iter()
+        .filter_map(|(name, hash)| {
+            let kind = match scanned.get(name) {
+                None => MismatchKind::Missing,
+                Some(actual) if actual.eq_ignore_ascii_case(hash) => return None,
+                Some(_) => MismatchKind::Corrupted,
+            };
+            Some(Mismatch {
+                model_name: name.clone(),
+                expected: hash.clone(),
+                actual: scanned.get(name).cloned(),
+                kind,
+            })
+        })
+        .collect();
+    mismatches.sort_by(|a, b| a.model_name.cmp(&b.model_name));
+    mismatches
+}
+
+// ---------------------------------------------------------------------------
+// Report tally
+// ---------------------------------------------------------------------------
+
+/// Per-status file counts closing a scan report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ScanReportSummary {
+    pub total_files: u64,
+    pub ok: u64,
+    pub missing: u64,
+    pub corrupted: u64,
+}
+
+/// Running tally of per-file statuses, fed one record at a time so a
+/// report can be summarized while it is streamed.
+#[derive(Debug, Clone, Default)]
+pub struct ScanReportTally {
+    summary: ScanReportSummary,
+}
+
+impl ScanReportTally {
+    /// Count one file with the given `FILE_STATUS_*` status. Unknown
+    /// statuses only count towards the total.
+    pub fn record(&mut self, status: &str) {
+        self.summary.total_files += 1;
+        match status {
+            FILE_STATUS_OK => self.summary.ok += 1,
+            FILE_STATUS_MISSING => self.summary.missing += 1,
+            FILE_STATUS_CORRUPTED => self.summary.corrupted += 1,
+            _ => {}
+        }
+    }
+
+    /// Counts recorded so far.
+    pub fn summary(&self) -> ScanReportSummary {
+        self.summary
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Health assessment
 // ---------------------------------------------------------------------------
@@ -186,6 +334,123 @@ mod tests {
         assert!(validate_repair_action("").is_err());
     }
 
+    // -- validate_checksum_format ---------------------------------------------
+
+    #[test]
+    fn valid_checksum_accepted() {
+        assert!(validate_checksum_format(&"a".repeat(64)).is_ok());
+        assert!(validate_checksum_format(&"0123456789ABCDEFabcdef".repeat(3)[..64]).is_ok());
+    }
+
+    #[test]
+    fn malformed_checksum_rejected() {
+        assert!(validate_checksum_format("").is_err());
+        assert!(validate_checksum_format("abc123def456").is_err());
+        assert!(validate_checksum_format(&"a".repeat(65)).is_err());
+        let err = validate_checksum_format(&format!("{}g", "a".repeat(63))).unwrap_err();
+        assert!(err.contains("'g'"));
+    }
+
+    // -- detect_mismatches ----------------------------------------------------
+
+    fn hashes(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, hash)| (name.to_string(), hash.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn mismatches_computed_from_two_maps() {
+        let expected = hashes(&[
+            ("vae.safetensors", "aa11"),
+            ("base.ckpt", "bb22"),
+            ("detail.lora", "cc33"),
+            ("upscale.pth", "dd44"),
+        ]);
+        let scanned = hashes(&[
+            ("base.ckpt", "BB22"),
+            ("detail.lora", "ffff"),
+            ("vae.safetensors", "0000"),
+            ("extra.ckpt", "ee55"),
+        ]);
+
+        let mismatches = detect_mismatches(&expected, &scanned);
+        assert_eq!(
+            mismatches,
+            vec![
+                Mismatch {
+                    model_name: "detail.lora".to_string(),
+                    expected: "cc33".to_string(),
+                    actual: Some("ffff".to_string()),
+                    kind: MismatchKind::Corrupted,
+                },
+                Mismatch {
+                    model_name: "upscale.pth".to_string(),
+                    expected: "dd44".to_string(),
+                    actual: None,
+                    kind: MismatchKind::Missing,
+                },
+                Mismatch {
+                    model_name: "vae.safetensors".to_string(),
+                    expected: "aa11".to_string(),
+                    actual: Some("0000".to_string()),
+                    kind: MismatchKind::Corrupted,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_mismatches_when_all_match() {
+        let expected = hashes(&[("base.ckpt", "bb22")]);
+        assert!(detect_mismatches(&expected, &expected.clone()).is_empty());
+        assert!(detect_mismatches(&HashMap::new(), &expected).is_empty());
+    }
+
+    // -- ScanReportTally ------------------------------------------------------
+
+    #[test]
+    fn tally_counts_each_status() {
+        let mut tally = ScanReportTally::default();
+        for status in [
+            FILE_STATUS_OK,
+            FILE_STATUS_OK,
+            FILE_STATUS_MISSING,
+            FILE_STATUS_CORRUPTED,
+            "unknown",
+        ] {
+            tally.record(status);
+        }
+        assert_eq!(
+            tally.summary(),
+            ScanReportSummary {
+                total_files: 5,
+                ok: 2,
+                missing: 1,
+                corrupted: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn empty_tally_is_zero() {
+        assert_eq!(
+            ScanReportTally::default().summary(),
+            ScanReportSummary::default()
+        );
+    }
+
+    // -- repair_step_scan_type -----------------------------------------------
+
+    #[test]
+    fn every_full_repair_step_has_a_scan_type() {
+        for step in FULL_REPAIR_STEPS {
+            assert!(repair_step_scan_type(step).is_some(), "{step}");
+        }
+        assert_eq!(repair_step_scan_type(REPAIR_FULL), None);
+    }
+
     // -- assess_health --------------------------------------------------------
 
     #[test]
@@ -1,7 +1,8 @@
 //! WebSocket message type constants for background job events (PRD-07).
 //!
-//! Used in `api/src/engine/progress.rs` when broadcasting job lifecycle
-//! updates to connected WebSocket clients.
+//! Carried as the `event` field of `progress` frames
+//! (`api/src/ws/messages.rs`) when broadcasting job lifecycle updates to
+//! connected WebSocket clients.
 
 /// Progress update during job execution (percentage + current node).
 pub const MSG_TYPE_JOB_PROGRESS: &str = "job_progress";
@@ -0,0 +1,158 @@
+//! Effective panel layout resolution (PRD-30).
+//!
+//! A layout is a JSON array of panel objects, each identified by a string
+//! `id`. Admin presets supply the base arrangement for a role; a user's saved
+//! layout overlays it. [`resolve_effective_layout`] merges the two and
+//! reports which panels came from the user.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+/// The merged layout a user actually sees.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EffectiveLayout {
+    /// Panels in display order: preset panels first (replaced in place where
+    /// the user overrode them), then panels only the user layout has.
+    pub panels: Vec<serde_json::Value>,
+    /// Ids of preset panels replaced by the user's version.
+    pub overridden_panels: Vec<String>,
+    /// Ids of panels that exist only in the user layout.
+    pub added_panels: Vec<String>,
+}
+
+/// Overlay `user_layout` on `preset`, matching panels by `id`.
+///
+/// With no user layout the preset is returned unchanged; with no preset the
+/// user layout is returned as-is. Entries that are not objects with a string
+/// `id` cannot be matched and are kept from whichever side supplied them.
+pub fn resolve_effective_layout(
+    preset: Option<&serde_json::Value>,
+    user_layout: Option<&serde_json::Value>,
+) -> EffectiveLayout {
+    let preset_panels = panels_of(preset);
+    let user_panels = panels_of(user_layout);
+
+    let user_by_id: HashMap<&str, &serde_json::Value> = user_panels
+        .iter()
+        .filter_map(|panel| Some((panel_id(panel)?, *panel)))
+        .collect();
+
+    let mut overridden_panels = Vec::new();
+    let mut panels: Vec<serde_json::Value> = preset_panels
+        .iter()
+        .map(|panel| {
+            let user_panel = panel_id(panel).and_then(|id| Some((id, *user_by_id.get(id)?)));
+            match user_panel {
+                Some((id, user_panel)) => {
+                    overridden_panels.push(id.to_string());
+                    user_panel.clone()
+                }
+                None => (*panel).clone(),
+            }
+        })
+        .collect();
+
+    let mut added_panels = Vec::new();
+    for panel in user_panels {
+        match panel_id(panel) {
+            Some(id) if overridden_panels.iter().any(|o| o == id) => {}
+            Some(id) => {
+                added_panels.push(id.to_string());
+                panels.push(panel.clone());
+            }
+            None => panels.push(panel.clone()),
+        }
+    }
+
+    EffectiveLayout {
+        panels,
+        overridden_panels,
+        added_panels,
+    }
+}
+
+/// The panel array of a layout, or empty if absent or not an array.
+fn panels_of(layout: Option<&serde_json::Value>) -> Vec<&serde_json::Value> {
+    layout
+        .and_then(|l| l.as_array())
+        .map(|panels| panels.iter().collect())
+        .unwrap_or_default()
+}
+
+fn panel_id(panel: &serde_json::Value) -> Option<&str> {
+    panel.get("id")?.as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn panel(id: &str, module: &str, collapsed: bool) -> serde_json::Value {
+        json!({ "id": id, "viewModule": module, "collapsed": collapsed })
+    }
+
+    #[test]
+    fn user_override_replaces_preset_panel_in_place() {
+        let preset = json!([
+            panel("library", "library", false),
+            panel("review", "review", false),
+            panel("queue", "queue", false),
+        ]);
+        let user = json!([panel("review", "review", true)]);
+
+        let effective = resolve_effective_layout(Some(&preset), Some(&user));
+
+        assert_eq!(
+            effective.panels,
+            vec![
+                panel("library", "library", false),
+                panel("review", "review", true),
+                panel("queue", "queue", false),
+            ]
+        );
+        assert_eq!(effective.overridden_panels, vec!["review"]);
+        assert!(effective.added_panels.is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_preset_without_user_layout() {
+        let preset = json!([panel("library", "library", false)]);
+
+        let effective = resolve_effective_layout(Some(&preset), None);
+
+        assert_eq!(effective.panels, vec![panel("library", "library", false)]);
+        assert!(effective.overridden_panels.is_empty());
+        assert!(effective.added_panels.is_empty());
+    }
+
+    #[test]
+    fn user_only_panels_are_appended() {
+        let preset = json!([panel("library", "library", false)]);
+        let user = json!([panel("notes", "notes", false)]);
+
+        let effective = resolve_effective_layout(Some(&preset), Some(&user));
+
+        assert_eq!(
+            effective.panels,
+            vec![
+                panel("library", "library", false),
+                panel("notes", "notes", false)
+            ]
+        );
+        assert_eq!(effective.added_panels, vec!["notes"]);
+    }
+
+    #[test]
+    fn without_preset_user_layout_is_used_as_is() {
+        let user = json!([panel("review", "review", true), json!("malformed")]);
+
+        let effective = resolve_effective_layout(None, Some(&user));
+
+        assert_eq!(effective.panels.len(), 2);
+        assert_eq!(effective.added_panels, vec!["review"]);
+        assert!(resolve_effective_layout(None, None).panels.is_empty());
+    }
+}
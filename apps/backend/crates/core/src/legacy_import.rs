@@ -29,6 +29,9 @@ pub const MAX_MATCH_KEY_LENGTH: usize = 64;
 /// Default match key used for entity matching.
 pub const DEFAULT_MATCH_KEY: &str = "name";
 
+/// Prefix marking a mapping rule pattern as a regular expression.
+pub const REGEX_PATTERN_PREFIX: &str = "re:";
+
 // ---------------------------------------------------------------------------
 // Import Run Status
 // ---------------------------------------------------------------------------
@@ -203,12 +206,85 @@ pub fn default_mapping_rules() -> Vec<PathMappingRule> {
 // Pattern Matching
 // ---------------------------------------------------------------------------
 
-/// Attempt to match a file path against a simple pattern with `{key}`
-/// placeholders. Returns captured key-value pairs on match.
+/// A parsed mapping rule pattern.
+#[derive(Debug, Clone)]
+pub enum PathPattern {
+    /// Segment pattern with `{key}` captures, `*`, and a trailing `**`.
+    Placeholder(String),
+    /// Regular expression (`re:` prefix) whose named groups are captured.
+    Regex {
+        regex: regex::Regex,
+        /// Whether the pattern ended in `/**`, allowing any suffix.
+        globstar: bool,
+    },
+}
+
+impl PathPattern {
+    /// Parse a rule pattern, compiling it if it has the `re:` prefix.
+    ///
+    /// A regex pattern may end in `/**` to match any further path segments,
+    /// like the placeholder syntax; the regex itself must then match whole
+    /// leading segments.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let Some(body) = pattern.strip_prefix(REGEX_PATTERN_PREFIX) else {
+            return Ok(Self::Placeholder(pattern.to_string()));
+        };
+        let (body, globstar) = match body.strip_suffix("/**") {
+            Some(prefix) => (prefix, true),
+            None => (body, false),
+        };
+        let regex = regex::Regex::new(&format!("^(?:{body})$"))
+            .map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+        Ok(Self::Regex { regex, globstar })
+    }
+
+    /// Match `path`, returning the captured key-value pairs on a match.
+    pub fn matches(&self, path: &str) -> Option<HashMap<String, String>> {
+        match self {
+            Self::Placeholder(pattern) => match_placeholder_pattern(path, pattern),
+            Self::Regex { regex, globstar } => {
+                if *globstar {
+                    // Try the shortest run of leading segments first.
+                    path.match_indices('/')
+                        .map(|(i, _)| &path[..i])
+                        .chain(std::iter::once(path))
+                        .find_map(|prefix| regex_captures(regex, prefix))
+                } else {
+                    regex_captures(regex, path)
+                }
+            }
+        }
+    }
+}
+
+/// Named captures of a full-string regex match.
+fn regex_captures(regex: &regex::Regex, text: &str) -> Option<HashMap<String, String>> {
+    let caps = regex.captures(text)?;
+    Some(
+        regex
+            .capture_names()
+            .flatten()
+            .filter_map(|name| Some((name.to_string(), caps.name(name)?.as_str().to_string())))
+            .collect(),
+    )
+}
+
+/// Attempt to match a file path against a mapping rule pattern. Returns
+/// captured key-value pairs on match.
 ///
 /// Patterns use `{key}` for single path segments and `**` for any suffix.
 /// For example, `{name}/scenes/{scene}/**` matches `Alice/scenes/intro/file.png`.
+///
+/// Patterns prefixed with `re:` are regular expressions whose named groups
+/// become the captures, e.g. `re:char_\d+_(?P<name>.+)/**` captures `alice`
+/// from `char_0042_alice/portrait.png`. An invalid regex never matches;
+/// [`validate_mapping_config`] rejects such rules up front.
 pub fn match_path_pattern(path: &str, pattern: &str) -> Option<HashMap<String, String>> {
+    PathPattern::parse(pattern).ok()?.matches(path)
+}
+
+/// Match a `{key}` placeholder pattern segment by segment.
+fn match_placeholder_pattern(path: &str, pattern: &str) -> Option<HashMap<String, String>> {
     let path_parts: Vec<&str> = path.split('/').collect();
     let pattern_parts: Vec<&str> = pattern.split('/').collect();
     let mut captures = HashMap::new();
@@ -335,7 +411,7 @@ pub fn validate_source_path(path: &str) -> Result<(), String> {
 ///
 /// Expects either an empty object (use defaults) or an object with a
 /// `rules` array, where each rule has `pattern`, `entity_type`, and
-/// optional `captures`.
+/// optional `captures`. `re:` patterns must compile as regexes.
 pub fn validate_mapping_config(config: &serde_json::Value) -> Result<(), String> {
     if config.is_null() {
         return Ok(());
@@ -368,6 +444,9 @@ pub fn validate_mapping_config(config: &serde_json::Value) -> Result<(), String>
             if !rule_obj.contains_key("pattern") {
                 return Err(format!("Rule at index {i} is missing 'pattern' field"));
             }
+            if let Some(pattern) = rule_obj.get("pattern").and_then(|p| p.as_str()) {
+                PathPattern::parse(pattern).map_err(|e| format!("Rule at index {i}: {e}"))?;
+            }
             if !rule_obj.contains_key("entity_type") {
                 return Err(format!("Rule at index {i} is missing 'entity_type' field"));
             }
@@ -652,6 +731,49 @@ mod tests {
         assert!(captures.is_empty());
     }
 
+    #[test]
+    fn regex_named_capture_match() {
+        let pattern = r"re:char_\d+_(?P<name>.+)/**";
+        let captures = match_path_pattern("char_0042_alice/portrait.png", pattern).unwrap();
+        assert_eq!(captures.len(), 1);
+        assert_eq!(captures.get("name").unwrap(), "alice");
+
+        // The capture stops at the first segment boundary that matches.
+        let captures =
+            match_path_pattern("char_0042_alice/scenes/intro/file.png", pattern).unwrap();
+        assert_eq!(captures.get("name").unwrap(), "alice");
+
+        assert!(match_path_pattern("npc_0042_alice/portrait.png", pattern).is_none());
+    }
+
+    #[test]
+    fn regex_without_globstar_matches_whole_path() {
+        let pattern = r"re:(?P<name>[a-z]+)/(?P<scene>\w+)\.mp4";
+        let captures = match_path_pattern("alice/intro.mp4", pattern).unwrap();
+        assert_eq!(captures.get("name").unwrap(), "alice");
+        assert_eq!(captures.get("scene").unwrap(), "intro");
+        assert!(match_path_pattern("alice/intro.mp4/extra", pattern).is_none());
+    }
+
+    #[test]
+    fn regex_rule_accepted_in_config() {
+        let config = serde_json::json!({
+            "rules": [{ "pattern": r"re:char_\d+_(?P<name>.+)/**", "entity_type": "character" }]
+        });
+        assert!(validate_mapping_config(&config).is_ok());
+    }
+
+    #[test]
+    fn invalid_regex_rule_rejected() {
+        let config = serde_json::json!({
+            "rules": [{ "pattern": "re:char_(?P<name>.+/**", "entity_type": "character" }]
+        });
+        let err = validate_mapping_config(&config).unwrap_err();
+        assert!(err.contains("Rule at index 0"));
+        assert!(err.contains("Invalid regex pattern"));
+        assert!(match_path_pattern("char_alice/x.png", "re:char_(?P<name>.+/**").is_none());
+    }
+
     // -- default_mapping_rules tests ------------------------------------------
 
     #[test]
@@ -26,6 +26,7 @@ pub mod cloud;
 pub mod cloud_scaling;
 pub mod collaboration;
 pub mod command_palette;
+pub mod completeness_trend;
 pub mod compliance;
 pub mod config_export;
 pub mod consistency_report;
@@ -34,6 +35,7 @@ pub mod crypto;
 pub mod dashboard_customization;
 pub mod delivery;
 pub mod diff;
+pub mod digest_schedule;
 pub mod directors_view;
 pub mod directory_scanner;
 pub mod download_manager;
@@ -42,12 +44,14 @@ pub mod embedding;
 pub mod error;
 pub mod estimation;
 pub mod extensions;
+pub mod failure_diagnosis;
 pub mod failure_tracking;
 pub mod ffmpeg;
 pub mod generation;
 pub mod gpu_power;
 pub mod hardware;
 pub mod hashing;
+pub mod http_range;
 pub mod images;
 pub mod import_rules;
 pub mod import_status;
@@ -57,6 +61,7 @@ pub mod job_debug;
 pub mod job_events;
 pub mod job_scheduling;
 pub mod job_status;
+pub mod layout;
 pub mod legacy_import;
 pub mod llm_refinement;
 pub mod maintenance;
@@ -77,6 +82,7 @@ pub mod poster_frame;
 pub mod preset;
 pub mod production_notes;
 pub mod production_report;
+pub mod proficiency;
 pub mod project_config;
 pub mod project_lifecycle;
 pub mod prompt_editor;
@@ -85,6 +91,7 @@ pub mod provenance;
 pub mod qa_ruleset;
 pub mod qa_status;
 pub mod quality_gate;
+pub mod quota_reset;
 pub mod readiness;
 pub mod reclamation;
 pub mod regression;
@@ -114,8 +121,10 @@ pub mod storage;
 pub mod storage_visualizer;
 pub mod storyboard;
 pub mod system_health;
+pub mod tags;
 pub mod temporal_continuity;
 pub mod test_shot;
+pub mod theme;
 pub mod threshold_validation;
 pub mod trigger_workflow;
 pub mod trimming;
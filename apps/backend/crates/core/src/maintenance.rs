@@ -2,9 +2,14 @@
 //!
 //! Provides operation type/status enums, input validators, advisory lock
 //! constants, and a registry of searchable/path fields for find/replace
-//! and re-pathing operations.
+//! and re-pathing operations, plus safe pattern compilation and
+//! before/after previews for find/replace.
+
+use regex::{NoExpand, Regex, RegexBuilder};
+use serde::Serialize;
 
 use crate::error::CoreError;
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // Operation type constants
@@ -16,9 +21,19 @@ pub const OP_TYPE_FIND_REPLACE: &str = "find_replace";
 pub const OP_TYPE_REPATH: &str = "repath";
 /// Batch field update across entities.
 pub const OP_TYPE_BATCH_UPDATE: &str = "batch_update";
+/// Studio-wide recomputation of completeness and readiness caches.
+pub const OP_TYPE_REBUILD_CACHES: &str = "rebuild_caches";
+/// Rebuild of the wiki article search index.
+pub const OP_TYPE_REINDEX_WIKI: &str = "reindex_wiki";
 
 /// All valid operation types.
-pub const VALID_OP_TYPES: &[&str] = &[OP_TYPE_FIND_REPLACE, OP_TYPE_REPATH, OP_TYPE_BATCH_UPDATE];
+pub const VALID_OP_TYPES: &[&str] = &[
+    OP_TYPE_FIND_REPLACE,
+    OP_TYPE_REPATH,
+    OP_TYPE_BATCH_UPDATE,
+    OP_TYPE_REBUILD_CACHES,
+    OP_TYPE_REINDEX_WIKI,
+];
 
 // ---------------------------------------------------------------------------
 // Operation status constants
@@ -34,6 +49,8 @@ pub const STATUS_COMPLETED: &str = "completed";
 pub const STATUS_FAILED: &str = "failed";
 /// Operation was undone by admin.
 pub const STATUS_UNDONE: &str = "undone";
+/// Operation was cancelled by admin before finishing.
+pub const STATUS_CANCELLED: &str = "cancelled";
 
 /// All valid operation statuses.
 pub const VALID_STATUSES: &[&str] = &[
@@ -42,6 +59,7 @@ pub const VALID_STATUSES: &[&str] = &[
     STATUS_COMPLETED,
     STATUS_FAILED,
     STATUS_UNDONE,
+    STATUS_CANCELLED,
 ];
 
 // ---------------------------------------------------------------------------
@@ -62,6 +80,25 @@ pub const MAX_SEARCH_TERM_LEN: usize = 1_000;
 /// Maximum length for a replacement string.
 pub const MAX_REPLACEMENT_LEN: usize = 10_000;
 
+/// Maximum compiled size of a find/replace pattern in bytes.
+///
+/// The `regex` engine never backtracks (matching is linear in the input),
+/// so bounding the compiled program is the remaining execution budget.
+pub const MAX_REGEX_COMPILED_SIZE: usize = 1 << 20;
+
+/// Maximum nesting depth of groups and repetitions in a find/replace pattern.
+pub const MAX_REGEX_NEST_DEPTH: u32 = 32;
+
+/// Maximum rows scanned per field when previewing a find/replace.
+pub const MAX_FIND_REPLACE_SCAN_ROWS: i64 = 10_000;
+
+/// Maximum before/after samples returned in a find/replace preview.
+pub const MAX_PREVIEW_SAMPLES: usize = 100;
+
+/// Avatars recomputed per chunk during a cache rebuild. Progress is
+/// recorded and cancellation checked between chunks.
+pub const REBUILD_CACHES_CHUNK_SIZE: i64 = 200;
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -72,6 +109,8 @@ pub enum BulkOperationType {
     FindReplace,
     Repath,
     BatchUpdate,
+    RebuildCaches,
+    ReindexWiki,
 }
 
 impl BulkOperationType {
@@ -81,6 +120,8 @@ impl BulkOperationType {
             Self::FindReplace => OP_TYPE_FIND_REPLACE,
             Self::Repath => OP_TYPE_REPATH,
             Self::BatchUpdate => OP_TYPE_BATCH_UPDATE,
+            Self::RebuildCaches => OP_TYPE_REBUILD_CACHES,
+            Self::ReindexWiki => OP_TYPE_REINDEX_WIKI,
         }
     }
 
@@ -90,6 +131,8 @@ impl BulkOperationType {
             OP_TYPE_FIND_REPLACE => Ok(Self::FindReplace),
             OP_TYPE_REPATH => Ok(Self::Repath),
             OP_TYPE_BATCH_UPDATE => Ok(Self::BatchUpdate),
+            OP_TYPE_REBUILD_CACHES => Ok(Self::RebuildCaches),
+            OP_TYPE_REINDEX_WIKI => Ok(Self::ReindexWiki),
             other => Err(CoreError::Validation(format!(
                 "Unknown operation type: '{other}'. Valid types: {}",
                 VALID_OP_TYPES.join(", ")
@@ -106,6 +149,7 @@ pub enum BulkOperationStatus {
     Completed,
     Failed,
     Undone,
+    Cancelled,
 }
 
 impl BulkOperationStatus {
@@ -117,6 +161,7 @@ impl BulkOperationStatus {
             Self::Completed => STATUS_COMPLETED,
             Self::Failed => STATUS_FAILED,
             Self::Undone => STATUS_UNDONE,
+            Self::Cancelled => STATUS_CANCELLED,
         }
     }
 
@@ -128,6 +173,7 @@ impl BulkOperationStatus {
             STATUS_COMPLETED => Ok(Self::Completed),
             STATUS_FAILED => Ok(Self::Failed),
             STATUS_UNDONE => Ok(Self::Undone),
+            STATUS_CANCELLED => Ok(Self::Cancelled),
             other => Err(CoreError::Validation(format!(
                 "Unknown operation status: '{other}'. Valid statuses: {}",
                 VALID_STATUSES.join(", ")
@@ -155,16 +201,51 @@ pub fn validate_search_term(term: &str) -> Result<(), CoreError> {
     Ok(())
 }
 
-/// Validate that a regex pattern compiles successfully.
+/// Validate that a regex pattern compiles within the find/replace budget.
+///
+/// See [`compile_find_pattern`] for the rules applied.
 pub fn validate_regex_pattern(pattern: &str) -> Result<(), CoreError> {
     if pattern.is_empty() {
         return Err(CoreError::Validation(
             "Regex pattern must not be empty".to_string(),
         ));
     }
-    regex::Regex::new(pattern)
+    compile_find_pattern(pattern, true, true).map(|_| ())
+}
+
+/// Compile a find/replace search term into a matcher.
+///
+/// Literal terms are escaped. Regex patterns are compiled with a bounded
+/// program size ([`MAX_REGEX_COMPILED_SIZE`]) and nesting depth
+/// ([`MAX_REGEX_NEST_DEPTH`]); constructs that require backtracking
+/// (look-around, backreferences) are not supported by the engine and are
+/// rejected as invalid. Patterns that match the empty string are rejected
+/// because they would insert the replacement between every character.
+pub fn compile_find_pattern(
+    term: &str,
+    use_regex: bool,
+    case_sensitive: bool,
+) -> Result<Regex, CoreError> {
+    validate_search_term(term)?;
+
+    let pattern = if use_regex {
+        term.to_string()
+    } else {
+        regex::escape(term)
+    };
+    let re = RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .size_limit(MAX_REGEX_COMPILED_SIZE)
+        .nest_limit(MAX_REGEX_NEST_DEPTH)
+        .build()
         .map_err(|e| CoreError::Validation(format!("Invalid regex pattern: {e}")))?;
-    Ok(())
+
+    if re.is_match("") {
+        return Err(CoreError::Validation(
+            "Regex pattern must not match empty text".to_string(),
+        ));
+    }
+    Ok(re)
 }
 
 /// Validate that a path prefix is non-empty and starts with `/`.
@@ -204,6 +285,12 @@ pub fn can_execute_operation(status: &BulkOperationStatus) -> bool {
     matches!(status, BulkOperationStatus::Preview)
 }
 
+/// Check whether an operation in the given status can be cancelled.
+/// Only executing operations can be cancelled.
+pub fn can_cancel_operation(status: &BulkOperationStatus) -> bool {
+    matches!(status, BulkOperationStatus::Executing)
+}
+
 /// Validate an operation type string is one of the known types.
 pub fn validate_operation_type(op_type: &str) -> Result<(), CoreError> {
     if VALID_OP_TYPES.contains(&op_type) {
@@ -289,6 +376,34 @@ pub fn get_searchable_fields(entity_type: Option<&str>) -> Vec<SearchableField>
     fields
 }
 
+/// Resolve the fields a find/replace may touch.
+///
+/// Only fields in the [`get_searchable_fields`] allow-list are eligible;
+/// naming an unknown entity type or a field outside the allow-list is a
+/// validation error rather than an empty scope.
+pub fn resolve_find_replace_fields(
+    entity_type: Option<&str>,
+    field_name: Option<&str>,
+) -> Result<Vec<SearchableField>, CoreError> {
+    let mut fields = get_searchable_fields(entity_type);
+    if fields.is_empty() {
+        return Err(CoreError::Validation(format!(
+            "Entity type '{}' does not support find/replace",
+            entity_type.unwrap_or_default()
+        )));
+    }
+
+    if let Some(name) = field_name {
+        fields.retain(|f| f.column_name == name);
+        if fields.is_empty() {
+            return Err(CoreError::Validation(format!(
+                "Field '{name}' is not allowed for find/replace"
+            )));
+        }
+    }
+    Ok(fields)
+}
+
 /// Return all file-path fields that can be re-pathed.
 ///
 /// If `entity_type` is `Some`, only fields for that entity type are returned.
@@ -332,6 +447,62 @@ pub fn get_path_fields(entity_type: Option<&str>) -> Vec<SearchableField> {
     fields
 }
 
+// ---------------------------------------------------------------------------
+// Find/replace preview
+// ---------------------------------------------------------------------------
+
+/// The exact change a find/replace would make to one field value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplacementPreview {
+    pub entity_type: &'static str,
+    pub column_name: &'static str,
+    pub entity_id: DbId,
+    pub before: String,
+    pub after: String,
+}
+
+/// Apply a compiled find/replace to `text`.
+///
+/// In regex mode `$1`/`${name}` in the replacement expand to capture
+/// groups; in literal mode the replacement is inserted verbatim. Returns
+/// `None` when the text does not change.
+pub fn apply_replacement(
+    re: &Regex,
+    text: &str,
+    replacement: &str,
+    use_regex: bool,
+) -> Option<String> {
+    let replaced = if use_regex {
+        re.replace_all(text, replacement)
+    } else {
+        re.replace_all(text, NoExpand(replacement))
+    };
+    (replaced != text).then(|| replaced.into_owned())
+}
+
+/// Compute before/after previews for the rows of one field.
+///
+/// `rows` are `(entity_id, value)` pairs; rows left unchanged are omitted.
+pub fn preview_replacements(
+    re: &Regex,
+    replacement: &str,
+    use_regex: bool,
+    field: &SearchableField,
+    rows: &[(DbId, String)],
+) -> Vec<ReplacementPreview> {
+    rows.iter()
+        .filter_map(|(id, before)| {
+            apply_replacement(re, before, replacement, use_regex).map(|after| ReplacementPreview {
+                entity_type: field.entity_type,
+                column_name: field.column_name,
+                entity_id: *id,
+                before: before.clone(),
+                after,
+            })
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -347,6 +518,8 @@ mod tests {
         assert_eq!(BulkOperationType::FindReplace.as_str(), "find_replace");
         assert_eq!(BulkOperationType::Repath.as_str(), "repath");
         assert_eq!(BulkOperationType::BatchUpdate.as_str(), "batch_update");
+        assert_eq!(BulkOperationType::RebuildCaches.as_str(), "rebuild_caches");
+        assert_eq!(BulkOperationType::ReindexWiki.as_str(), "reindex_wiki");
     }
 
     #[test]
@@ -363,6 +536,14 @@ mod tests {
             BulkOperationType::from_str("batch_update").unwrap(),
             BulkOperationType::BatchUpdate
         );
+        assert_eq!(
+            BulkOperationType::from_str("rebuild_caches").unwrap(),
+            BulkOperationType::RebuildCaches
+        );
+        assert_eq!(
+            BulkOperationType::from_str("reindex_wiki").unwrap(),
+            BulkOperationType::ReindexWiki
+        );
     }
 
     #[test]
@@ -380,6 +561,7 @@ mod tests {
         assert_eq!(BulkOperationStatus::Completed.as_str(), "completed");
         assert_eq!(BulkOperationStatus::Failed.as_str(), "failed");
         assert_eq!(BulkOperationStatus::Undone.as_str(), "undone");
+        assert_eq!(BulkOperationStatus::Cancelled.as_str(), "cancelled");
     }
 
     #[test]
@@ -404,6 +586,10 @@ mod tests {
             BulkOperationStatus::from_str("undone").unwrap(),
             BulkOperationStatus::Undone
         );
+        assert_eq!(
+            BulkOperationStatus::from_str("cancelled").unwrap(),
+            BulkOperationStatus::Cancelled
+        );
     }
 
     #[test]
@@ -545,6 +731,20 @@ mod tests {
         assert!(!can_execute_operation(&BulkOperationStatus::Failed));
     }
 
+    // -- can_cancel_operation -------------------------------------------------
+
+    #[test]
+    fn can_cancel_executing() {
+        assert!(can_cancel_operation(&BulkOperationStatus::Executing));
+    }
+
+    #[test]
+    fn cannot_cancel_finished() {
+        assert!(!can_cancel_operation(&BulkOperationStatus::Completed));
+        assert!(!can_cancel_operation(&BulkOperationStatus::Cancelled));
+        assert!(!can_cancel_operation(&BulkOperationStatus::Preview));
+    }
+
     // -- validate_operation_type ----------------------------------------------
 
     #[test]
@@ -552,6 +752,7 @@ mod tests {
         assert!(validate_operation_type("find_replace").is_ok());
         assert!(validate_operation_type("repath").is_ok());
         assert!(validate_operation_type("batch_update").is_ok());
+        assert!(validate_operation_type("rebuild_caches").is_ok());
     }
 
     #[test]
@@ -657,4 +858,90 @@ mod tests {
     fn max_replacement_len_is_10000() {
         assert_eq!(MAX_REPLACEMENT_LEN, 10_000);
     }
+
+    // -- compile_find_pattern -------------------------------------------------
+
+    #[test]
+    fn literal_term_is_escaped() {
+        let re = compile_find_pattern("a.b", false, true).unwrap();
+        assert!(re.is_match("a.b"));
+        assert!(!re.is_match("axb"));
+    }
+
+    #[test]
+    fn case_insensitive_matching() {
+        let re = compile_find_pattern("Hero", false, false).unwrap();
+        assert!(re.is_match("the HERO shot"));
+    }
+
+    #[test]
+    fn backreference_regex_rejected() {
+        let err = compile_find_pattern(r"(a)\1", true, true).unwrap_err();
+        assert!(err.to_string().contains("Invalid regex pattern"));
+    }
+
+    #[test]
+    fn empty_matching_regex_rejected() {
+        let err = compile_find_pattern("x*", true, true).unwrap_err();
+        assert!(err.to_string().contains("must not match empty text"));
+    }
+
+    #[test]
+    fn oversized_regex_rejected() {
+        assert!(compile_find_pattern(r"\w{1000}{1000}", true, true).is_err());
+    }
+
+    // -- resolve_find_replace_fields ------------------------------------------
+
+    #[test]
+    fn allowed_field_resolves() {
+        let fields =
+            resolve_find_replace_fields(Some("scene_type"), Some("prompt_template")).unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].table_name, "scene_types");
+    }
+
+    #[test]
+    fn disallowed_field_rejected() {
+        let err = resolve_find_replace_fields(Some("avatar"), Some("password_hash")).unwrap_err();
+        assert!(err.to_string().contains("not allowed"));
+    }
+
+    #[test]
+    fn unknown_entity_type_rejected() {
+        assert!(resolve_find_replace_fields(Some("users"), None).is_err());
+    }
+
+    // -- preview_replacements -------------------------------------------------
+
+    #[test]
+    fn preview_shows_exact_before_and_after() {
+        let field = &get_searchable_fields(Some("avatar"))[0];
+        let re = compile_find_pattern(r"take_(\d+)", true, true).unwrap();
+        let rows = vec![
+            (1, "hero take_01".to_string()),
+            (2, "no match here".to_string()),
+        ];
+
+        let previews = preview_replacements(&re, "shot_$1", true, field, &rows);
+        assert_eq!(
+            previews,
+            vec![ReplacementPreview {
+                entity_type: "avatar",
+                column_name: "name",
+                entity_id: 1,
+                before: "hero take_01".to_string(),
+                after: "hero shot_01".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn literal_replacement_does_not_expand_groups() {
+        let re = compile_find_pattern("cost", false, true).unwrap();
+        assert_eq!(
+            apply_replacement(&re, "cost", "$1", false).as_deref(),
+            Some("$1")
+        );
+    }
 }
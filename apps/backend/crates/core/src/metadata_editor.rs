@@ -69,6 +69,12 @@ pub struct MetadataFieldDef {
     /// Allowed values for Select / MultiSelect fields.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub options: Vec<String>,
+    /// Inclusive lower bound for Number fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// Inclusive upper bound for Number fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
 }
 
 // ---------------------------------------------------------------------------
@@ -89,6 +95,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Biographical,
             is_required: true,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "description".into(),
@@ -97,6 +105,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Biographical,
             is_required: true,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "age".into(),
@@ -105,6 +115,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Biographical,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "gender".into(),
@@ -118,6 +130,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "Non-Binary".into(),
                 "Other".into(),
             ],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "date_of_birth".into(),
@@ -126,6 +140,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Biographical,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         // --- Physical ---
         MetadataFieldDef {
@@ -135,6 +151,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Physical,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "weight".into(),
@@ -143,6 +161,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Physical,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "hair_color".into(),
@@ -159,6 +179,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "White".into(),
                 "Other".into(),
             ],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "eye_color".into(),
@@ -174,6 +196,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "Gray".into(),
                 "Other".into(),
             ],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "build".into(),
@@ -187,6 +211,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "Athletic".into(),
                 "Heavy".into(),
             ],
+            min: None,
+            max: None,
         },
         // --- Preferences ---
         MetadataFieldDef {
@@ -203,6 +229,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "Empathetic".into(),
                 "Assertive".into(),
             ],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "voice_type".into(),
@@ -217,6 +245,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
                 "Baritone".into(),
                 "Bass".into(),
             ],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "accent".into(),
@@ -225,6 +255,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Preferences,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         // --- Production ---
         MetadataFieldDef {
@@ -234,6 +266,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Production,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
         MetadataFieldDef {
             name: "special_requirements".into(),
@@ -242,6 +276,8 @@ pub fn standard_field_defs() -> Vec<MetadataFieldDef> {
             category: FieldCategory::Production,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         },
     ]
 }
@@ -387,6 +423,56 @@ pub fn calculate_project_completeness(
     }
 }
 
+/// Change in required-field completeness between two versions of metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletenessDelta {
+    /// Required fields that were filled before and are missing now.
+    pub newly_missing: Vec<String>,
+    /// Required fields that were missing before and are filled now.
+    pub newly_filled: Vec<String>,
+    pub percentage_before: f64,
+    pub percentage_after: f64,
+}
+
+impl CompletenessDelta {
+    /// Whether the edit cleared at least one previously filled required field.
+    pub fn regressed(&self) -> bool {
+        !self.newly_missing.is_empty()
+    }
+}
+
+/// Compare required-field completeness of `old` and `new` metadata.
+///
+/// Field order follows `fields`, so the result is stable for display.
+pub fn completeness_delta(
+    old: &serde_json::Map<String, serde_json::Value>,
+    new: &serde_json::Map<String, serde_json::Value>,
+    fields: &[MetadataFieldDef],
+) -> CompletenessDelta {
+    let before = calculate_completeness(0, old, fields);
+    let after = calculate_completeness(0, new, fields);
+
+    let newly_missing = after
+        .missing_fields
+        .iter()
+        .filter(|f| !before.missing_fields.contains(f))
+        .cloned()
+        .collect();
+    let newly_filled = before
+        .missing_fields
+        .iter()
+        .filter(|f| !after.missing_fields.contains(f))
+        .cloned()
+        .collect();
+
+    CompletenessDelta {
+        newly_missing,
+        newly_filled,
+        percentage_before: before.percentage,
+        percentage_after: after.percentage,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Metadata field validation (local, not PRD-014)
 // ---------------------------------------------------------------------------
@@ -403,6 +489,7 @@ pub struct MetadataFieldError {
 /// Checks that:
 /// - Select fields have values within the allowed options.
 /// - Number fields have numeric values.
+/// - Number fields fall within the optional `min` / `max` bounds.
 ///
 /// Unknown fields (not in the template) are allowed — this supports
 /// user-added custom fields. Only type/option violations are flagged.
@@ -429,14 +516,34 @@ pub fn validate_metadata_fields(
         }
 
         match def.field_type {
-            FieldType::Number => {
-                if !value.is_number() && !value.is_null() {
+            FieldType::Number => match value.as_f64() {
+                Some(n) => {
+                    if let Some(min) = def.min.filter(|min| n < *min) {
+                        errors.push(MetadataFieldError {
+                            field: key.clone(),
+                            message: format!(
+                                "Field '{}' must be at least {min}, got {n}",
+                                def.label
+                            ),
+                        });
+                    }
+                    if let Some(max) = def.max.filter(|max| n > *max) {
+                        errors.push(MetadataFieldError {
+                            field: key.clone(),
+                            message: format!(
+                                "Field '{}' must be at most {max}, got {n}",
+                                def.label
+                            ),
+                        });
+                    }
+                }
+                None => {
                     errors.push(MetadataFieldError {
                         field: key.clone(),
                         message: format!("Field '{}' must be a number", def.label),
                     });
                 }
-            }
+            },
             FieldType::Select => {
                 if let Some(s) = value.as_str() {
                     if !def.options.is_empty() && !def.options.iter().any(|o| o == s) {
@@ -483,9 +590,32 @@ pub fn validate_metadata_fields(
 // CSV helpers
 // ---------------------------------------------------------------------------
 
-/// Escape a value for CSV: wrap in quotes if it contains comma, quote, or newline.
-fn csv_escape(value: &str) -> String {
-    if value.contains(',') || value.contains('"') || value.contains('\n') {
+/// UTF-8 byte order mark, stripped from the start of imported CSV files.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Default CSV field delimiter.
+pub const DEFAULT_CSV_DELIMITER: char = ',';
+
+/// Separator between multi-select items inside one CSV cell.
+const MULTI_SELECT_SEPARATOR: char = ';';
+
+/// Multi-select separator used when the field delimiter is itself `;`
+/// (the list separator in many European Excel locales).
+const ALT_MULTI_SELECT_SEPARATOR: char = '|';
+
+/// The multi-select separator that does not clash with `delimiter`.
+fn multi_select_separator(delimiter: char) -> char {
+    if delimiter == MULTI_SELECT_SEPARATOR {
+        ALT_MULTI_SELECT_SEPARATOR
+    } else {
+        MULTI_SELECT_SEPARATOR
+    }
+}
+
+/// Escape a value for CSV: wrap in quotes if it contains the delimiter, a
+/// quote, or a newline.
+fn csv_escape(value: &str, delimiter: char) -> String {
+    if value.contains(delimiter) || value.contains('"') || value.contains('\n') {
         format!("\"{}\"", value.replace('"', "\"\""))
     } else {
         value.to_string()
@@ -493,51 +623,66 @@ fn csv_escape(value: &str) -> String {
 }
 
 /// Convert a JSON value to a CSV-friendly string.
-fn json_value_to_csv(value: &serde_json::Value) -> String {
+fn json_value_to_csv(value: &serde_json::Value, delimiter: char) -> String {
     match value {
         serde_json::Value::Null => String::new(),
         serde_json::Value::String(s) => s.clone(),
         serde_json::Value::Number(n) => n.to_string(),
         serde_json::Value::Bool(b) => b.to_string(),
         serde_json::Value::Array(arr) => {
-            // Join multi-select values with semicolons.
+            // Join multi-select values with the separator for this delimiter.
             let items: Vec<String> = arr
                 .iter()
                 .filter_map(|v| v.as_str().map(String::from))
                 .collect();
-            items.join(";")
+            items.join(&multi_select_separator(delimiter).to_string())
         }
         serde_json::Value::Object(_) => serde_json::to_string(value).unwrap_or_default(),
     }
 }
 
-/// Build a CSV string from a list of avatars.
+/// Build a comma-delimited CSV string from a list of avatars.
+///
+/// See [`build_csv_with`].
+pub fn build_csv(
+    avatars: &[(i64, String, serde_json::Map<String, serde_json::Value>)],
+    fields: &[MetadataFieldDef],
+) -> String {
+    build_csv_with(avatars, fields, DEFAULT_CSV_DELIMITER)
+}
+
+/// Build a CSV string from a list of avatars, separating fields with
+/// `delimiter`.
 ///
 /// Each avatar is represented as `(id, name, metadata_map)`.
 /// The first row is a header with `id, name, <field_names...>`.
-pub fn build_csv(
+/// Multi-select items are joined with `;`, or with `|` when `delimiter` is
+/// `;`.
+pub fn build_csv_with(
     avatars: &[(i64, String, serde_json::Map<String, serde_json::Value>)],
     fields: &[MetadataFieldDef],
+    delimiter: char,
 ) -> String {
+    let separator = delimiter.to_string();
     let mut lines = Vec::with_capacity(avatars.len() + 1);
 
     // Header row
     let mut header_parts = vec!["id".to_string(), "name".to_string()];
     for field in fields {
-        header_parts.push(csv_escape(&field.name));
+        header_parts.push(csv_escape(&field.name, delimiter));
     }
-    lines.push(header_parts.join(","));
+    lines.push(header_parts.join(&separator));
 
     // Data rows
     for (id, name, metadata) in avatars {
-        let mut row_parts = vec![id.to_string(), csv_escape(name)];
+        let mut row_parts = vec![id.to_string(), csv_escape(name, delimiter)];
         for field in fields {
             let value = metadata
                 .get(&field.name)
                 .unwrap_or(&serde_json::Value::Null);
-            row_parts.push(csv_escape(&json_value_to_csv(value)));
+            row_parts.push(csv_escape(&json_value_to_csv(value, delimiter), delimiter));
         }
-        lines.push(row_parts.join(","));
+        lines.push(row_parts.join(&separator));
     }
 
     lines.join("\n")
@@ -564,15 +709,28 @@ pub struct CsvDiffEntry {
     pub new_value: serde_json::Value,
 }
 
-/// Parse raw CSV bytes into a list of records.
+/// Parse raw comma-delimited CSV bytes into a list of records.
 ///
-/// Expects the first line to be a header. Handles basic quoting.
+/// See [`parse_csv_with`].
 pub fn parse_csv(data: &[u8]) -> Result<Vec<CsvRecord>, String> {
+    parse_csv_with(data, DEFAULT_CSV_DELIMITER)
+}
+
+/// Parse raw CSV bytes whose fields are separated by `delimiter` into a
+/// list of records.
+///
+/// Expects the first line to be a header. Handles basic quoting. Cells
+/// containing the multi-select separator (`;`, or `|` when `delimiter` is
+/// `;`) are split into arrays.
+pub fn parse_csv_with(data: &[u8], delimiter: char) -> Result<Vec<CsvRecord>, String> {
+    // Excel on Windows prefixes UTF-8 exports with a byte order mark.
+    let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
     let text = std::str::from_utf8(data).map_err(|e| format!("Invalid UTF-8: {e}"))?;
     let mut lines = text.lines();
+    let multi_separator = multi_select_separator(delimiter);
 
     let header_line = lines.next().ok_or("CSV is empty")?;
-    let headers = parse_csv_line(header_line);
+    let headers = parse_csv_line(header_line, delimiter);
 
     if headers.is_empty() {
         return Err("CSV header row is empty".into());
@@ -584,7 +742,7 @@ pub fn parse_csv(data: &[u8]) -> Result<Vec<CsvRecord>, String> {
         if line.trim().is_empty() {
             continue;
         }
-        let values = parse_csv_line(line);
+        let values = parse_csv_line(line, delimiter);
         let mut id: Option<i64> = None;
         let mut name: Option<String> = None;
         let mut fields = serde_json::Map::new();
@@ -601,10 +759,10 @@ pub fn parse_csv(data: &[u8]) -> Result<Vec<CsvRecord>, String> {
                 field_name => {
                     if value.is_empty() {
                         fields.insert(field_name.to_string(), serde_json::Value::Null);
-                    } else if value.contains(';') {
-                        // Multi-select: split on semicolons.
+                    } else if value.contains(multi_separator) {
+                        // Multi-select: split on the multi-select separator.
                         let items: Vec<serde_json::Value> = value
-                            .split(';')
+                            .split(multi_separator)
                             .map(|s| serde_json::Value::String(s.trim().to_string()))
                             .collect();
                         fields.insert(field_name.to_string(), serde_json::Value::Array(items));
@@ -632,8 +790,10 @@ pub fn parse_csv(data: &[u8]) -> Result<Vec<CsvRecord>, String> {
     Ok(records)
 }
 
-/// Parse a single CSV line, handling quoted fields.
-fn parse_csv_line(line: &str) -> Vec<String> {
+/// Parse a single CSV line split on `delimiter`, handling quoted fields.
+///
+/// A trailing `\r` left by CRLF line endings is trimmed from each field.
+fn parse_csv_line(line: &str, delimiter: char) -> Vec<String> {
     let mut result = Vec::new();
     let mut current = String::new();
     let mut in_quotes = false;
@@ -654,14 +814,14 @@ fn parse_csv_line(line: &str) -> Vec<String> {
             }
         } else if ch == '"' {
             in_quotes = true;
-        } else if ch == ',' {
-            result.push(current.clone());
+        } else if ch == delimiter {
+            result.push(current.trim_end_matches('\r').to_string());
             current.clear();
         } else {
             current.push(ch);
         }
     }
-    result.push(current);
+    result.push(current.trim_end_matches('\r').to_string());
     result
 }
 
@@ -751,6 +911,8 @@ mod tests {
             category: FieldCategory::Biographical,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         }];
         let metadata = serde_json::Map::new();
         let result = calculate_completeness(1, &metadata, &fields);
@@ -786,6 +948,46 @@ mod tests {
         assert!(result.per_avatar[2].percentage < 1.0);
     }
 
+    // --- Completeness delta tests ---
+
+    #[test]
+    fn delta_reports_cleared_required_field() {
+        let fields = sample_fields();
+        let old = make_metadata(&[
+            ("full_name", serde_json::Value::String("Alice".into())),
+            ("description", serde_json::Value::String("A hero".into())),
+        ]);
+        let new = make_metadata(&[
+            ("full_name", serde_json::Value::String("Alice".into())),
+            ("description", serde_json::Value::String("  ".into())),
+        ]);
+
+        let delta = completeness_delta(&old, &new, &fields);
+
+        assert!(delta.regressed());
+        assert_eq!(delta.newly_missing, vec!["description".to_string()]);
+        assert!(delta.newly_filled.is_empty());
+        assert!((delta.percentage_before - 100.0).abs() < f64::EPSILON);
+        assert!((delta.percentage_after - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn delta_reports_filled_missing_field_without_regression() {
+        let fields = sample_fields();
+        let old = make_metadata(&[("full_name", serde_json::Value::String("Alice".into()))]);
+        let new = make_metadata(&[
+            ("full_name", serde_json::Value::String("Alice".into())),
+            ("description", serde_json::Value::String("A hero".into())),
+        ]);
+
+        let delta = completeness_delta(&old, &new, &fields);
+
+        assert!(!delta.regressed());
+        assert!(delta.newly_missing.is_empty());
+        assert_eq!(delta.newly_filled, vec!["description".to_string()]);
+        assert!((delta.percentage_after - 100.0).abs() < f64::EPSILON);
+    }
+
     // --- Validation tests ---
 
     #[test]
@@ -819,6 +1021,8 @@ mod tests {
             category: FieldCategory::Production,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         }];
         let updates = make_metadata(&[("score", serde_json::Value::String("not a number".into()))]);
         let errors = validate_metadata_fields(&updates, &fields);
@@ -826,6 +1030,66 @@ mod tests {
         assert!(errors[0].message.contains("number"));
     }
 
+    fn bounded_number_field() -> Vec<MetadataFieldDef> {
+        vec![MetadataFieldDef {
+            name: "age_years".into(),
+            label: "Age".into(),
+            field_type: FieldType::Number,
+            category: FieldCategory::Biographical,
+            is_required: false,
+            options: vec![],
+            min: Some(0.0),
+            max: Some(200.0),
+        }]
+    }
+
+    #[test]
+    fn validate_number_below_min_reports_bound() {
+        let fields = bounded_number_field();
+        let updates = make_metadata(&[("age_years", serde_json::json!(-1))]);
+        let errors = validate_metadata_fields(&updates, &fields);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "age_years");
+        assert!(errors[0].message.contains("at least 0"));
+    }
+
+    #[test]
+    fn validate_number_above_max_reports_bound() {
+        let fields = bounded_number_field();
+        let updates = make_metadata(&[("age_years", serde_json::json!(250))]);
+        let errors = validate_metadata_fields(&updates, &fields);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("at most 200"));
+    }
+
+    #[test]
+    fn validate_number_in_range_passes() {
+        let fields = bounded_number_field();
+        let updates = make_metadata(&[("age_years", serde_json::json!(42))]);
+        assert!(validate_metadata_fields(&updates, &fields).is_empty());
+    }
+
+    #[test]
+    fn validate_number_range_errors_collected_across_fields() {
+        let mut fields = bounded_number_field();
+        fields.push(MetadataFieldDef {
+            name: "height_cm".into(),
+            label: "Height".into(),
+            field_type: FieldType::Number,
+            category: FieldCategory::Physical,
+            is_required: false,
+            options: vec![],
+            min: Some(30.0),
+            max: None,
+        });
+        let updates = make_metadata(&[
+            ("age_years", serde_json::json!(-5)),
+            ("height_cm", serde_json::json!(10)),
+        ]);
+        let errors = validate_metadata_fields(&updates, &fields);
+        assert_eq!(errors.len(), 2);
+    }
+
     #[test]
     fn validate_select_field_rejects_invalid_option() {
         let fields = sample_fields();
@@ -921,6 +1185,81 @@ mod tests {
         assert_eq!(records[1].name.as_deref(), Some("Bob"));
     }
 
+    /// One avatar with a multi-select field and a description containing
+    /// `delimiter`.
+    fn delimiter_sample(
+        delimiter: char,
+    ) -> Vec<(i64, String, serde_json::Map<String, serde_json::Value>)> {
+        vec![(
+            7,
+            "Chloé".to_string(),
+            make_metadata(&[
+                (
+                    "description",
+                    serde_json::Value::String(format!("Calm{delimiter} focused")),
+                ),
+                (
+                    "personality_traits",
+                    serde_json::json!(["Creative", "Empathetic", "Bold"]),
+                ),
+            ]),
+        )]
+    }
+
+    fn assert_delimiter_round_trip(delimiter: char) {
+        let fields = sample_fields();
+        let avatars = delimiter_sample(delimiter);
+
+        let csv = build_csv_with(&avatars, &fields, delimiter);
+        assert!(csv.starts_with(&format!("id{delimiter}name{delimiter}")));
+
+        let records = parse_csv_with(csv.as_bytes(), delimiter).expect("parse should succeed");
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.id, Some(7));
+        assert_eq!(record.name.as_deref(), Some("Chloé"));
+        assert_eq!(
+            record.fields.get("description").and_then(|v| v.as_str()),
+            Some(format!("Calm{delimiter} focused").as_str())
+        );
+        assert_eq!(
+            record.fields.get("personality_traits"),
+            Some(&serde_json::json!(["Creative", "Empathetic", "Bold"]))
+        );
+    }
+
+    #[test]
+    fn csv_round_trip_with_comma_delimiter() {
+        assert_delimiter_round_trip(',');
+    }
+
+    #[test]
+    fn csv_round_trip_with_semicolon_delimiter() {
+        assert_delimiter_round_trip(';');
+    }
+
+    #[test]
+    fn semicolon_delimiter_switches_multi_select_separator() {
+        let csv = build_csv_with(&delimiter_sample(';'), &sample_fields(), ';');
+        assert!(csv.contains("Creative|Empathetic|Bold"));
+        // The comma-delimited wrappers keep `;` for multi-select items.
+        let csv = build_csv(&delimiter_sample(','), &sample_fields());
+        assert!(csv.contains("Creative;Empathetic;Bold"));
+    }
+
+    #[test]
+    fn csv_strips_bom_and_crlf() {
+        let csv = "\u{feff}id,name,full_name\r\n1,Alice,Alice\r\n";
+        let records = parse_csv(csv.as_bytes()).expect("parse should succeed");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, Some(1));
+        assert_eq!(records[0].name.as_deref(), Some("Alice"));
+        assert_eq!(
+            records[0].fields.get("full_name").and_then(|v| v.as_str()),
+            Some("Alice")
+        );
+    }
+
     #[test]
     fn csv_handles_commas_in_values() {
         let fields = vec![MetadataFieldDef {
@@ -930,6 +1269,8 @@ mod tests {
             category: FieldCategory::Production,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         }];
 
         let avatars = vec![(
@@ -957,6 +1298,8 @@ mod tests {
             category: FieldCategory::Production,
             is_required: false,
             options: vec![],
+            min: None,
+            max: None,
         }];
 
         let avatars = vec![(
@@ -1097,6 +1440,8 @@ mod tests {
                 category: FieldCategory::Biographical,
                 is_required: true,
                 options: vec![],
+                min: None,
+                max: None,
             },
             MetadataFieldDef {
                 name: "appearance.hair".into(),
@@ -1105,6 +1450,8 @@ mod tests {
                 category: FieldCategory::Physical,
                 is_required: true,
                 options: vec![],
+                min: None,
+                max: None,
             },
         ];
         // Metadata stored in nested format
@@ -1,8 +1,8 @@
 //! Onboarding wizard constants and validation (PRD-67).
 //!
-//! Defines the wizard step definitions, status enumeration, and validation
-//! helpers used by the API and repository layers for the bulk avatar
-//! onboarding wizard.
+//! Defines the wizard step definitions, status enumeration and transitions,
+//! and validation helpers used by the API and repository layers for the bulk
+//! avatar onboarding wizard.
 
 use serde::{Deserialize, Serialize};
 
@@ -44,6 +44,30 @@ impl OnboardingStatus {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Session history events
+// ---------------------------------------------------------------------------
+
+/// Kinds of transition recorded in an onboarding session's history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingEventType {
+    Advance,
+    GoBack,
+    StepData,
+}
+
+impl OnboardingEventType {
+    /// Convert to a database-compatible string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Advance => "advance",
+            Self::GoBack => "go_back",
+            Self::StepData => "step_data",
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Onboarding steps
 // ---------------------------------------------------------------------------
@@ -264,14 +288,38 @@ pub fn can_complete_session(current_step: u8) -> Result<(), CoreError> {
     Ok(())
 }
 
-/// Check if a session can be abandoned (must be in_progress).
-pub fn can_abandon_session(status: &str) -> Result<(), CoreError> {
-    if status != OnboardingStatus::InProgress.as_str() {
-        return Err(CoreError::Validation(format!(
-            "Cannot abandon session with status '{status}'. Only 'in_progress' sessions can be abandoned."
-        )));
+// ---------------------------------------------------------------------------
+// Status transitions
+// ---------------------------------------------------------------------------
+
+/// What to do when a session is asked to move to a status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusTransition {
+    /// Move the session to the requested status.
+    Apply,
+    /// The session is already in the requested status; nothing to do.
+    AlreadyInState,
+}
+
+/// Validate moving a session from `current` to `target`.
+///
+/// Sessions only leave `in_progress`, and only for one of the two terminal
+/// statuses. Repeating the transition a session already made is accepted as
+/// a no-op so clients can retry safely; any other move is a conflict.
+pub fn check_status_transition(
+    current: OnboardingStatus,
+    target: OnboardingStatus,
+) -> Result<StatusTransition, CoreError> {
+    use OnboardingStatus::*;
+    match (current, target) {
+        (from, to) if from == to && to != InProgress => Ok(StatusTransition::AlreadyInState),
+        (InProgress, Completed | Abandoned) => Ok(StatusTransition::Apply),
+        (from, to) => Err(CoreError::Conflict(format!(
+            "Invalid onboarding session transition from '{}' to '{}'",
+            from.as_str(),
+            to.as_str()
+        ))),
     }
-    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -319,6 +367,19 @@ mod tests {
         }
     }
 
+    // -- OnboardingEventType --
+
+    #[test]
+    fn event_type_as_str_matches_serde() {
+        for event in [
+            OnboardingEventType::Advance,
+            OnboardingEventType::GoBack,
+            OnboardingEventType::StepData,
+        ] {
+            assert_eq!(serde_json::to_value(event).unwrap(), json!(event.as_str()));
+        }
+    }
+
     // -- OnboardingStep --
 
     #[test]
@@ -535,20 +596,47 @@ mod tests {
         }
     }
 
-    // -- can_abandon_session --
+    // -- check_status_transition --
+
+    #[test]
+    fn in_progress_can_complete_or_abandon() {
+        for target in [OnboardingStatus::Completed, OnboardingStatus::Abandoned] {
+            assert_eq!(
+                check_status_transition(OnboardingStatus::InProgress, target).unwrap(),
+                StatusTransition::Apply
+            );
+        }
+    }
 
     #[test]
-    fn can_abandon_in_progress() {
-        assert!(can_abandon_session("in_progress").is_ok());
+    fn repeating_a_terminal_transition_is_a_no_op() {
+        for status in [OnboardingStatus::Completed, OnboardingStatus::Abandoned] {
+            assert_eq!(
+                check_status_transition(status, status).unwrap(),
+                StatusTransition::AlreadyInState
+            );
+        }
     }
 
     #[test]
-    fn cannot_abandon_completed() {
-        assert!(can_abandon_session("completed").is_err());
+    fn switching_terminal_status_is_a_conflict() {
+        let err = check_status_transition(OnboardingStatus::Abandoned, OnboardingStatus::Completed)
+            .unwrap_err();
+        assert!(matches!(err, CoreError::Conflict(_)));
+        assert!(
+            check_status_transition(OnboardingStatus::Completed, OnboardingStatus::Abandoned)
+                .is_err()
+        );
     }
 
     #[test]
-    fn cannot_abandon_already_abandoned() {
-        assert!(can_abandon_session("abandoned").is_err());
+    fn sessions_never_return_to_in_progress() {
+        for status in [
+            OnboardingStatus::InProgress,
+            OnboardingStatus::Completed,
+            OnboardingStatus::Abandoned,
+        ] {
+            assert!(check_status_transition(status, OnboardingStatus::InProgress).is_err());
+        }
     }
 }
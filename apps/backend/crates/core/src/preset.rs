@@ -134,12 +134,15 @@ pub fn validate_scope_project_consistency(
 }
 
 /* --------------------------------------------------------------------------
-Override diff
+Preset diff
 -------------------------------------------------------------------------- */
 
-/// A single field difference between current parameters and preset parameters.
+/// A single field change that applying a preset would make.
+///
+/// Serialized with the same shape the preset diff preview has always
+/// returned (`field`, `current_value`, `preset_value`).
 #[derive(Debug, Clone, Serialize, PartialEq)]
-pub struct OverrideDiff {
+pub struct FieldChange {
     /// The JSON field key that differs.
     pub field: String,
     /// The current value for this field.
@@ -148,16 +151,19 @@ pub struct OverrideDiff {
     pub preset_value: serde_json::Value,
 }
 
-/// Compare two JSON objects field-by-field and return a list of fields
-/// where the preset value differs from the current value.
+/// Compute the changes applying `preset_params` to `current_params` would make.
+///
+/// This is the single source of truth for both the diff preview and
+/// [`apply_field_changes`], so the preview always matches what apply does.
 ///
 /// Only top-level keys are compared. Fields present in `preset_params` but
 /// absent from `current_params` are included (current_value = null). Fields
-/// present only in `current_params` are ignored (the preset does not touch them).
-pub fn compute_override_diff(
-    current_params: &serde_json::Value,
+/// present only in `current_params` are ignored (the preset does not touch
+/// them). The result is sorted by field name.
+pub fn preset_diff(
     preset_params: &serde_json::Value,
-) -> Vec<OverrideDiff> {
+    current_params: &serde_json::Value,
+) -> Vec<FieldChange> {
     let preset_obj = match preset_params.as_object() {
         Some(obj) => obj,
         None => return Vec::new(),
@@ -165,7 +171,7 @@ pub fn compute_override_diff(
 
     let current_obj = current_params.as_object();
 
-    let mut diffs = Vec::new();
+    let mut changes = Vec::new();
 
     for (key, preset_val) in preset_obj {
         let current_val = current_obj
@@ -173,7 +179,7 @@ pub fn compute_override_diff(
             .unwrap_or(&serde_json::Value::Null);
 
         if current_val != preset_val {
-            diffs.push(OverrideDiff {
+            changes.push(FieldChange {
                 field: key.clone(),
                 current_value: current_val.clone(),
                 preset_value: preset_val.clone(),
@@ -182,8 +188,23 @@ pub fn compute_override_diff(
     }
 
     // Sort for deterministic output.
-    diffs.sort_by(|a, b| a.field.cmp(&b.field));
-    diffs
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+/// Apply previously computed [`FieldChange`]s to `current_params`.
+///
+/// A non-object `current_params` is treated as an empty object. Fields not
+/// named in `changes` are left untouched.
+pub fn apply_field_changes(
+    current_params: &serde_json::Value,
+    changes: &[FieldChange],
+) -> serde_json::Value {
+    let mut obj = current_params.as_object().cloned().unwrap_or_default();
+    for change in changes {
+        obj.insert(change.field.clone(), change.preset_value.clone());
+    }
+    serde_json::Value::Object(obj)
 }
 
 /* --------------------------------------------------------------------------
@@ -282,14 +303,14 @@ mod tests {
         assert!(err.to_string().contains("must not have a project_id"));
     }
 
-    // --- Override diff ---
+    // --- Preset diff ---
 
     #[test]
-    fn compute_override_diff_detects_changed_fields() {
+    fn preset_diff_detects_changed_fields() {
         let current = json!({"brightness": 50, "contrast": 70});
         let preset = json!({"brightness": 80, "contrast": 70});
 
-        let diffs = compute_override_diff(&current, &preset);
+        let diffs = preset_diff(&preset, &current);
         assert_eq!(diffs.len(), 1);
         assert_eq!(diffs[0].field, "brightness");
         assert_eq!(diffs[0].current_value, json!(50));
@@ -297,11 +318,11 @@ mod tests {
     }
 
     #[test]
-    fn compute_override_diff_includes_new_fields() {
+    fn preset_diff_includes_new_fields() {
         let current = json!({"brightness": 50});
         let preset = json!({"brightness": 50, "saturation": 100});
 
-        let diffs = compute_override_diff(&current, &preset);
+        let diffs = preset_diff(&preset, &current);
         assert_eq!(diffs.len(), 1);
         assert_eq!(diffs[0].field, "saturation");
         assert_eq!(diffs[0].current_value, serde_json::Value::Null);
@@ -309,17 +330,53 @@ mod tests {
     }
 
     #[test]
-    fn compute_override_diff_returns_empty_when_identical() {
+    fn preset_diff_returns_empty_when_identical() {
         let params = json!({"brightness": 50, "contrast": 70});
-        let diffs = compute_override_diff(&params, &params);
+        let diffs = preset_diff(&params, &params);
         assert!(diffs.is_empty());
     }
 
     #[test]
-    fn compute_override_diff_handles_non_object_preset() {
+    fn preset_diff_handles_non_object_preset() {
         let current = json!({"brightness": 50});
         let preset = json!("not an object");
-        let diffs = compute_override_diff(&current, &preset);
+        let diffs = preset_diff(&preset, &current);
         assert!(diffs.is_empty());
     }
+
+    #[test]
+    fn preset_diff_is_sorted_by_field() {
+        let current = json!({"zoom": 1, "alpha": 1, "mid": 1});
+        let preset = json!({"mid": 2, "zoom": 2, "alpha": 2, "unchanged": null});
+        let fields: Vec<String> = preset_diff(&preset, &current)
+            .into_iter()
+            .map(|c| c.field)
+            .collect();
+        assert_eq!(fields, vec!["alpha", "mid", "zoom"]);
+    }
+
+    #[test]
+    fn apply_field_changes_yields_exactly_the_diff() {
+        let current = json!({"cfg": 7, "steps": 20, "sampler": "euler"});
+        let preset = json!({"cfg": 7, "steps": 30, "seed": 42});
+
+        let changes = preset_diff(&preset, &current);
+        let applied = apply_field_changes(&current, &changes);
+
+        assert_eq!(
+            applied,
+            json!({"cfg": 7, "steps": 30, "sampler": "euler", "seed": 42})
+        );
+        // Re-diffing after apply finds nothing left to change.
+        assert!(preset_diff(&preset, &applied).is_empty());
+    }
+
+    #[test]
+    fn apply_field_changes_on_non_object_starts_empty() {
+        let changes = preset_diff(&json!({"cfg": 5}), &serde_json::Value::Null);
+        assert_eq!(
+            apply_field_changes(&serde_json::Value::Null, &changes),
+            json!({"cfg": 5})
+        );
+    }
 }
@@ -1,9 +1,16 @@
 //! Production notes constants and validation functions (PRD-95).
 //!
 //! Provides visibility levels, category labels, entity type validation,
-//! content validation, and mention extraction for the freeform note system.
+//! content validation, mention extraction, and reply thread assembly for the
+//! freeform note system.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 use crate::roles::{ROLE_ADMIN, ROLE_REVIEWER};
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -165,6 +172,104 @@ pub fn extract_mentions(content: &str) -> Vec<String> {
     mentions
 }
 
+// ---------------------------------------------------------------------------
+// Threads
+// ---------------------------------------------------------------------------
+
+/// A note that can be arranged into a reply thread by [`build_thread`].
+pub trait ThreadedNote {
+    fn id(&self) -> DbId;
+    fn parent_note_id(&self) -> Option<DbId>;
+    fn created_at(&self) -> DateTime<Utc>;
+}
+
+/// A note and its replies.
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadNode<T> {
+    /// The note's id, or the missing parent's id for a tombstone.
+    pub id: DbId,
+    /// The note, or `None` for a tombstone standing in for a deleted parent.
+    pub note: Option<T>,
+    /// Direct replies, oldest first.
+    pub replies: Vec<ThreadNode<T>>,
+}
+
+/// A reply thread rooted at one note.
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadTree<T> {
+    pub root: ThreadNode<T>,
+    /// Ids of replies whose parent is missing, surfaced under tombstones.
+    pub orphaned_reply_ids: Vec<DbId>,
+}
+
+/// Assemble `notes` into the reply thread rooted at `root_id`.
+///
+/// Siblings are ordered by creation time, then id, so the same notes always
+/// produce the same tree. Replies whose parent is not among `notes` (the
+/// parent was deleted) are grouped under a tombstone node for that parent,
+/// placed among the root's replies by its oldest orphan. If the root itself
+/// is missing it becomes a tombstone too. Notes not connected to the thread
+/// are ignored.
+pub fn build_thread<T: ThreadedNote>(root_id: DbId, notes: Vec<T>) -> ThreadTree<T> {
+    let known: HashSet<DbId> = notes.iter().map(ThreadedNote::id).collect();
+    let mut root_note = None;
+    let mut children: HashMap<DbId, Vec<T>> = HashMap::new();
+    for note in notes {
+        if note.id() == root_id {
+            root_note = Some(note);
+        } else if let Some(parent_id) = note.parent_note_id() {
+            children.entry(parent_id).or_default().push(note);
+        }
+    }
+
+    let mut root = build_node(root_id, root_note, &mut children);
+
+    let mut missing_parents: Vec<DbId> = children
+        .keys()
+        .copied()
+        .filter(|parent_id| !known.contains(parent_id) && *parent_id != root_id)
+        .collect();
+    missing_parents.sort_unstable();
+
+    let mut orphaned_reply_ids = Vec::new();
+    for parent_id in missing_parents {
+        let tombstone = build_node(parent_id, None, &mut children);
+        orphaned_reply_ids.extend(tombstone.replies.iter().map(|reply| reply.id));
+        root.replies.push(tombstone);
+    }
+    root.replies.sort_by_key(node_order);
+    orphaned_reply_ids.sort_unstable();
+
+    ThreadTree {
+        root,
+        orphaned_reply_ids,
+    }
+}
+
+/// Build the node for `id`, taking its replies out of `children`.
+fn build_node<T: ThreadedNote>(
+    id: DbId,
+    note: Option<T>,
+    children: &mut HashMap<DbId, Vec<T>>,
+) -> ThreadNode<T> {
+    let mut replies: Vec<ThreadNode<T>> = children
+        .remove(&id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reply| build_node(reply.id(), Some(reply), children))
+        .collect();
+    replies.sort_by_key(node_order);
+    ThreadNode { id, note, replies }
+}
+
+/// Sibling order: creation time then id. A tombstone sorts by its first reply.
+fn node_order<T: ThreadedNote>(node: &ThreadNode<T>) -> Option<(DateTime<Utc>, DbId)> {
+    match &node.note {
+        Some(note) => Some((note.created_at(), note.id())),
+        None => node.replies.first().and_then(node_order),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -366,4 +471,107 @@ mod tests {
     fn max_content_length_is_ten_thousand() {
         assert_eq!(MAX_NOTE_CONTENT_LENGTH, 10_000);
     }
+
+    // -- build_thread --------------------------------------------------------
+
+    #[derive(Debug, Clone)]
+    struct Note {
+        id: DbId,
+        parent: Option<DbId>,
+        minute: i64,
+    }
+
+    impl ThreadedNote for Note {
+        fn id(&self) -> DbId {
+            self.id
+        }
+
+        fn parent_note_id(&self) -> Option<DbId> {
+            self.parent
+        }
+
+        fn created_at(&self) -> DateTime<Utc> {
+            DateTime::<Utc>::UNIX_EPOCH + chrono::Duration::minutes(self.minute)
+        }
+    }
+
+    fn note(id: DbId, parent: Option<DbId>, minute: i64) -> Note {
+        Note { id, parent, minute }
+    }
+
+    /// Flatten a node into `(depth, id, is_tombstone)` in display order.
+    fn outline(node: &ThreadNode<Note>, depth: usize, out: &mut Vec<(usize, DbId, bool)>) {
+        out.push((depth, node.id, node.note.is_none()));
+        for reply in &node.replies {
+            outline(reply, depth + 1, out);
+        }
+    }
+
+    fn thread_outline(tree: &ThreadTree<Note>) -> Vec<(usize, DbId, bool)> {
+        let mut out = Vec::new();
+        outline(&tree.root, 0, &mut out);
+        out
+    }
+
+    #[test]
+    fn multi_level_thread_orders_siblings_by_creation() {
+        // Input order is scrambled; 5 and 6 share a timestamp (id breaks the tie).
+        let notes = vec![
+            note(6, Some(2), 30),
+            note(4, Some(1), 20),
+            note(3, Some(2), 15),
+            note(1, None, 0),
+            note(5, Some(2), 30),
+            note(2, Some(1), 10),
+            note(7, Some(3), 40),
+        ];
+        let tree = build_thread(1, notes);
+        assert_eq!(
+            thread_outline(&tree),
+            vec![
+                (0, 1, false),
+                (1, 2, false),
+                (2, 3, false),
+                (3, 7, false),
+                (2, 5, false),
+                (2, 6, false),
+                (1, 4, false),
+            ]
+        );
+        assert!(tree.orphaned_reply_ids.is_empty());
+    }
+
+    #[test]
+    fn orphaned_reply_surfaces_under_tombstone() {
+        // Note 2 was deleted; its replies 3 and 5 (and 3's reply 4) remain.
+        let notes = vec![
+            note(1, None, 0),
+            note(3, Some(2), 20),
+            note(4, Some(3), 25),
+            note(5, Some(2), 30),
+            note(6, Some(1), 5),
+            note(8, Some(1), 40),
+        ];
+        let tree = build_thread(1, notes);
+        assert_eq!(
+            thread_outline(&tree),
+            vec![
+                (0, 1, false),
+                (1, 6, false),
+                (1, 2, true),
+                (2, 3, false),
+                (3, 4, false),
+                (2, 5, false),
+                (1, 8, false),
+            ]
+        );
+        assert_eq!(tree.orphaned_reply_ids, vec![3, 5]);
+    }
+
+    #[test]
+    fn missing_root_becomes_tombstone() {
+        let tree = build_thread(1, vec![note(2, Some(1), 10)]);
+        assert_eq!(thread_outline(&tree), vec![(0, 1, true), (1, 2, false)]);
+        assert!(tree.orphaned_reply_ids.is_empty());
+    }
 }
@@ -0,0 +1,287 @@
+//! Feature visibility from proficiency and focus mode (PRD-32).
+//!
+//! A user's per-area proficiency decides which advanced features are
+//! revealed, and an active focus mode narrows the UI to the features that
+//! mode is for. [`resolve_feature_visibility`] combines both into one map so
+//! the client does not have to re-derive the rules per screen.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::error::CoreError;
+
+// ---------------------------------------------------------------------------
+// Levels and modes
+// ---------------------------------------------------------------------------
+
+/// A user's proficiency in one feature area, ordered from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProficiencyLevel {
+    Beginner,
+    Intermediate,
+    Expert,
+}
+
+impl ProficiencyLevel {
+    /// Parse a stored level (`beginner`, `intermediate`, `expert`).
+    pub fn parse(s: &str) -> Result<Self, CoreError> {
+        match s {
+            "beginner" => Ok(Self::Beginner),
+            "intermediate" => Ok(Self::Intermediate),
+            "expert" => Ok(Self::Expert),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid proficiency level '{s}'. Must be one of: beginner, intermediate, expert"
+            ))),
+        }
+    }
+}
+
+/// A distraction-free task mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusMode {
+    /// Video player and approval controls only.
+    Review,
+    /// Workflow canvas and generation parameters only.
+    Generation,
+}
+
+impl FocusMode {
+    /// Parse a stored focus mode (`review`, `generation`).
+    pub fn parse(s: &str) -> Result<Self, CoreError> {
+        match s {
+            "review" => Ok(Self::Review),
+            "generation" => Ok(Self::Generation),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid focus mode '{s}'. Must be one of: review, generation"
+            ))),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Feature definitions
+// ---------------------------------------------------------------------------
+
+/// A UI feature whose visibility is governed by progressive disclosure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureDef {
+    pub key: &'static str,
+    /// Proficiency area the feature is gated on.
+    pub feature_area: &'static str,
+    /// Lowest proficiency in `feature_area` that reveals the feature.
+    pub min_level: ProficiencyLevel,
+    /// Focus modes in which the feature stays visible.
+    pub focus_modes: &'static [FocusMode],
+}
+
+/// Built-in feature catalogue served by the visibility endpoint.
+pub const FEATURE_DEFS: &[FeatureDef] = &[
+    FeatureDef {
+        key: "video_player",
+        feature_area: "review",
+        min_level: ProficiencyLevel::Beginner,
+        focus_modes: &[FocusMode::Review],
+    },
+    FeatureDef {
+        key: "approval_controls",
+        feature_area: "review",
+        min_level: ProficiencyLevel::Beginner,
+        focus_modes: &[FocusMode::Review],
+    },
+    FeatureDef {
+        key: "review_annotations",
+        feature_area: "review",
+        min_level: ProficiencyLevel::Intermediate,
+        focus_modes: &[],
+    },
+    FeatureDef {
+        key: "workflow_canvas",
+        feature_area: "generation",
+        min_level: ProficiencyLevel::Beginner,
+        focus_modes: &[FocusMode::Generation],
+    },
+    FeatureDef {
+        key: "generation_parameters",
+        feature_area: "generation",
+        min_level: ProficiencyLevel::Beginner,
+        focus_modes: &[FocusMode::Generation],
+    },
+    FeatureDef {
+        key: "advanced_parameters",
+        feature_area: "generation",
+        min_level: ProficiencyLevel::Intermediate,
+        focus_modes: &[FocusMode::Generation],
+    },
+    FeatureDef {
+        key: "node_editor",
+        feature_area: "generation",
+        min_level: ProficiencyLevel::Expert,
+        focus_modes: &[FocusMode::Generation],
+    },
+    FeatureDef {
+        key: "batch_operations",
+        feature_area: "library",
+        min_level: ProficiencyLevel::Intermediate,
+        focus_modes: &[],
+    },
+];
+
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+/// Why a feature is hidden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HiddenBy {
+    /// The user's proficiency in the feature's area is below its minimum.
+    Proficiency,
+    /// The active focus mode does not include the feature.
+    FocusMode,
+}
+
+/// Visibility of a single feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FeatureVisibility {
+    pub visible: bool,
+    pub hidden_by: Option<HiddenBy>,
+}
+
+impl FeatureVisibility {
+    const VISIBLE: Self = Self {
+        visible: true,
+        hidden_by: None,
+    };
+
+    fn hidden(by: HiddenBy) -> Self {
+        Self {
+            visible: false,
+            hidden_by: Some(by),
+        }
+    }
+}
+
+/// Visibility per feature key.
+pub type VisibilityMap = BTreeMap<String, FeatureVisibility>;
+
+/// Decide the visibility of every feature in `feature_defs`.
+///
+/// `proficiency` maps feature areas to the user's level; areas without an
+/// entry count as [`ProficiencyLevel::Beginner`]. An active focus mode hides
+/// every feature not listed for it, regardless of proficiency; otherwise a
+/// feature is visible once the user reaches its minimum level.
+pub fn resolve_feature_visibility(
+    proficiency: &HashMap<String, ProficiencyLevel>,
+    focus_mode: Option<FocusMode>,
+    feature_defs: &[FeatureDef],
+) -> VisibilityMap {
+    feature_defs
+        .iter()
+        .map(|def| {
+            let level = proficiency
+                .get(def.feature_area)
+                .copied()
+                .unwrap_or(ProficiencyLevel::Beginner);
+            let visibility = match focus_mode {
+                Some(mode) if !def.focus_modes.contains(&mode) => {
+                    FeatureVisibility::hidden(HiddenBy::FocusMode)
+                }
+                _ if level < def.min_level => FeatureVisibility::hidden(HiddenBy::Proficiency),
+                _ => FeatureVisibility::VISIBLE,
+            };
+            (def.key.to_string(), visibility)
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(entries: &[(&str, ProficiencyLevel)]) -> HashMap<String, ProficiencyLevel> {
+        entries
+            .iter()
+            .map(|(area, level)| (area.to_string(), *level))
+            .collect()
+    }
+
+    fn visible_keys(map: &VisibilityMap) -> Vec<&str> {
+        map.iter()
+            .filter(|(_, v)| v.visible)
+            .map(|(k, _)| k.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn parses_levels_and_modes() {
+        assert_eq!(
+            ProficiencyLevel::parse("expert").unwrap(),
+            ProficiencyLevel::Expert
+        );
+        assert!(ProficiencyLevel::parse("guru").is_err());
+        assert_eq!(FocusMode::parse("review").unwrap(), FocusMode::Review);
+        assert!(FocusMode::parse("zen").is_err());
+        assert!(ProficiencyLevel::Beginner < ProficiencyLevel::Expert);
+    }
+
+    #[test]
+    fn beginner_in_generation_focus_sees_only_basic_generation_features() {
+        let map =
+            resolve_feature_visibility(&HashMap::new(), Some(FocusMode::Generation), FEATURE_DEFS);
+
+        assert_eq!(
+            visible_keys(&map),
+            vec!["generation_parameters", "workflow_canvas"]
+        );
+        assert_eq!(
+            map["advanced_parameters"].hidden_by,
+            Some(HiddenBy::Proficiency)
+        );
+        assert_eq!(map["node_editor"].hidden_by, Some(HiddenBy::Proficiency));
+        assert_eq!(map["video_player"].hidden_by, Some(HiddenBy::FocusMode));
+    }
+
+    #[test]
+    fn expert_without_focus_sees_everything() {
+        let expert = levels(&[
+            ("review", ProficiencyLevel::Expert),
+            ("generation", ProficiencyLevel::Expert),
+            ("library", ProficiencyLevel::Expert),
+        ]);
+        let map = resolve_feature_visibility(&expert, None, FEATURE_DEFS);
+
+        assert_eq!(map.len(), FEATURE_DEFS.len());
+        assert!(map.values().all(|v| *v == FeatureVisibility::VISIBLE));
+    }
+
+    #[test]
+    fn focus_mode_hides_features_even_for_experts() {
+        let expert = levels(&[("generation", ProficiencyLevel::Expert)]);
+        let map = resolve_feature_visibility(&expert, Some(FocusMode::Review), FEATURE_DEFS);
+
+        assert_eq!(
+            visible_keys(&map),
+            vec!["approval_controls", "video_player"]
+        );
+        assert_eq!(map["node_editor"].hidden_by, Some(HiddenBy::FocusMode));
+    }
+
+    #[test]
+    fn proficiency_is_judged_per_area() {
+        let mixed = levels(&[("generation", ProficiencyLevel::Intermediate)]);
+        let map = resolve_feature_visibility(&mixed, None, FEATURE_DEFS);
+
+        assert!(map["advanced_parameters"].visible);
+        assert!(!map["node_editor"].visible);
+        // No library proficiency recorded: treated as beginner.
+        assert!(!map["batch_operations"].visible);
+        assert!(!map["review_annotations"].visible);
+    }
+}
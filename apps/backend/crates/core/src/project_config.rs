@@ -115,6 +115,15 @@ pub struct ConfigDiffEntry {
 // Diff computation
 // ---------------------------------------------------------------------------
 
+/// A single entry in a diff between two arrays of `name`-keyed objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedDiffEntry {
+    pub name: String,
+    pub status: DiffStatus,
+    pub current_value: Option<serde_json::Value>,
+    pub incoming_value: Option<serde_json::Value>,
+}
+
 /// Compare the scene_types arrays from two configs and return a structured diff.
 ///
 /// - Scene types present only in `incoming_json` are marked `Added`.
@@ -136,50 +145,56 @@ pub fn compute_config_diff(
         .and_then(|v| v.as_array())
         .unwrap_or(&empty_vec);
 
-    // Build lookup: name -> value for current scene types
+    diff_named_entries(current_scene_types, incoming_scene_types)
+        .into_iter()
+        .map(|entry| ConfigDiffEntry {
+            scene_type_name: entry.name,
+            status: entry.status,
+            current_value: entry.current_value,
+            incoming_value: entry.incoming_value,
+        })
+        .collect()
+}
+
+/// Diff two arrays of objects, matching entries by their `name` field.
+///
+/// Entries are reported in `incoming` order; entries without a string
+/// `name` are skipped. Entries present only in `current` are not reported,
+/// since an import never removes them.
+pub fn diff_named_entries(
+    current: &[serde_json::Value],
+    incoming: &[serde_json::Value],
+) -> Vec<NamedDiffEntry> {
+    // Build lookup: name -> value for current entries
     let mut current_map: std::collections::HashMap<String, &serde_json::Value> =
         std::collections::HashMap::new();
-    for st in current_scene_types {
-        if let Some(name) = st.get("name").and_then(|n| n.as_str()) {
-            current_map.insert(name.to_string(), st);
+    for entry in current {
+        if let Some(name) = entry.get("name").and_then(|n| n.as_str()) {
+            current_map.insert(name.to_string(), entry);
         }
     }
 
-    let mut entries: Vec<ConfigDiffEntry> = Vec::new();
+    let mut entries: Vec<NamedDiffEntry> = Vec::new();
 
-    for incoming_st in incoming_scene_types {
-        let name = match incoming_st.get("name").and_then(|n| n.as_str()) {
+    for incoming_entry in incoming {
+        let name = match incoming_entry.get("name").and_then(|n| n.as_str()) {
             Some(n) => n.to_string(),
             None => continue,
         };
 
-        match current_map.get(&name) {
-            Some(current_st) => {
-                if *current_st == incoming_st {
-                    entries.push(ConfigDiffEntry {
-                        scene_type_name: name,
-                        status: DiffStatus::Unchanged,
-                        current_value: Some((*current_st).clone()),
-                        incoming_value: Some(incoming_st.clone()),
-                    });
-                } else {
-                    entries.push(ConfigDiffEntry {
-                        scene_type_name: name,
-                        status: DiffStatus::Changed,
-                        current_value: Some((*current_st).clone()),
-                        incoming_value: Some(incoming_st.clone()),
-                    });
-                }
-            }
-            None => {
-                entries.push(ConfigDiffEntry {
-                    scene_type_name: name,
-                    status: DiffStatus::Added,
-                    current_value: None,
-                    incoming_value: Some(incoming_st.clone()),
-                });
+        let (status, current_value) = match current_map.get(&name) {
+            Some(current_entry) if *current_entry == incoming_entry => {
+                (DiffStatus::Unchanged, Some((*current_entry).clone()))
             }
-        }
+            Some(current_entry) => (DiffStatus::Changed, Some((*current_entry).clone())),
+            None => (DiffStatus::Added, None),
+        };
+        entries.push(NamedDiffEntry {
+            name,
+            status,
+            current_value,
+            incoming_value: Some(incoming_entry.clone()),
+        });
     }
 
     entries
@@ -1,8 +1,10 @@
 //! Prompt editor types and validation (PRD-63).
 //!
 //! Provides constants, validation functions, placeholder extraction,
-//! token estimation, and diff computation for prompt versioning.
+//! token estimation, and diff computation for prompt versioning, plus the
+//! ranking used to order the shared prompt library.
 
+use std::str::FromStr;
 use std::sync::LazyLock;
 
 use regex::Regex;
@@ -10,6 +12,7 @@ use serde::Serialize;
 
 use crate::error::CoreError;
 use crate::provenance::MAX_PROMPT_LENGTH;
+use crate::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -37,6 +40,15 @@ static PLACEHOLDER_RE: LazyLock<Regex> =
 /// Multiplier for rough CLIP token estimation from word count.
 const TOKEN_ESTIMATE_MULTIPLIER: f64 = 1.3;
 
+/// Prior mean rating assumed for library entries with few ratings.
+pub const RATING_PRIOR_MEAN: f64 = 3.0;
+
+/// Weight of the rating prior, in number of virtual ratings.
+pub const RATING_PRIOR_WEIGHT: f64 = 5.0;
+
+/// Age in days at which an entry's trending score has halved.
+pub const TRENDING_HALF_LIFE_DAYS: f64 = 14.0;
+
 // ---------------------------------------------------------------------------
 // Validation
 // ---------------------------------------------------------------------------
@@ -211,6 +223,112 @@ fn word_diff_counts(old_text: &str, new_text: &str) -> (usize, usize) {
     (additions, removals)
 }
 
+// ---------------------------------------------------------------------------
+// Library ranking
+// ---------------------------------------------------------------------------
+
+/// Ordering for prompt library listings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibrarySort {
+    /// Highest confidence-adjusted rating first.
+    Top,
+    /// Newest entries first.
+    Recent,
+    /// Confidence-adjusted rating decayed by age.
+    Trending,
+}
+
+impl LibrarySort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Recent => "recent",
+            Self::Trending => "trending",
+        }
+    }
+}
+
+impl FromStr for LibrarySort {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "top" => Ok(Self::Top),
+            "recent" => Ok(Self::Recent),
+            "trending" => Ok(Self::Trending),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid sort: '{s}'. Must be one of: top, recent, trending"
+            ))),
+        }
+    }
+}
+
+/// The library entry fields needed for ranking.
+#[derive(Debug, Clone)]
+pub struct RankableEntry {
+    pub id: DbId,
+    pub avg_rating: Option<f64>,
+    pub rating_count: i32,
+    pub created_at: Timestamp,
+}
+
+/// A library entry id with its ranking score (higher ranks first).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoredEntry {
+    pub id: DbId,
+    pub score: f64,
+}
+
+/// Bayesian average of an entry's rating.
+///
+/// Blends the observed average with [`RATING_PRIOR_MEAN`] weighted by
+/// [`RATING_PRIOR_WEIGHT`] virtual ratings, so a single 5-star vote does
+/// not outrank a consistently well-rated entry.
+pub fn bayesian_rating(avg_rating: Option<f64>, rating_count: i32) -> f64 {
+    let count = f64::from(rating_count.max(0));
+    let avg = avg_rating.unwrap_or(RATING_PRIOR_MEAN);
+    (RATING_PRIOR_WEIGHT * RATING_PRIOR_MEAN + count * avg) / (RATING_PRIOR_WEIGHT + count)
+}
+
+/// Score and order library entries for `sort`, highest score first.
+///
+/// - `top`: [`bayesian_rating`].
+/// - `recent`: creation time (seconds since the epoch).
+/// - `trending`: [`bayesian_rating`] halved every
+///   [`TRENDING_HALF_LIFE_DAYS`] of age relative to `now`.
+///
+/// Ties are broken by newer creation time, then by higher id.
+pub fn rank_prompt_entries(
+    entries: &[RankableEntry],
+    sort: LibrarySort,
+    now: Timestamp,
+) -> Vec<ScoredEntry> {
+    let mut scored: Vec<(ScoredEntry, Timestamp)> = entries
+        .iter()
+        .map(|e| {
+            let score = match sort {
+                LibrarySort::Top => bayesian_rating(e.avg_rating, e.rating_count),
+                LibrarySort::Recent => e.created_at.timestamp() as f64,
+                LibrarySort::Trending => {
+                    let age_days = ((now - e.created_at).num_seconds().max(0) as f64) / 86_400.0;
+                    bayesian_rating(e.avg_rating, e.rating_count)
+                        * 0.5_f64.powf(age_days / TRENDING_HALF_LIFE_DAYS)
+                }
+            };
+            (ScoredEntry { id: e.id, score }, e.created_at)
+        })
+        .collect();
+
+    scored.sort_by(|(a, a_created), (b, b_created)| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b_created.cmp(a_created))
+            .then_with(|| b.id.cmp(&a.id))
+    });
+
+    scored.into_iter().map(|(s, _)| s).collect()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -435,4 +553,68 @@ mod tests {
         assert!(!diff.positive_changed);
         assert!(diff.negative_changed);
     }
+
+    // -- rank_prompt_entries --
+
+    fn rankable(id: DbId, avg: Option<f64>, count: i32, days_ago: i64) -> RankableEntry {
+        RankableEntry {
+            id,
+            avg_rating: avg,
+            rating_count: count,
+            created_at: fixed_now() - chrono::Duration::days(days_ago),
+        }
+    }
+
+    fn fixed_now() -> Timestamp {
+        chrono::DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    fn ranked_ids(entries: &[RankableEntry], sort: LibrarySort) -> Vec<DbId> {
+        rank_prompt_entries(entries, sort, fixed_now())
+            .into_iter()
+            .map(|s| s.id)
+            .collect()
+    }
+
+    #[test]
+    fn many_votes_outrank_single_five_star() {
+        let entries = vec![rankable(1, Some(5.0), 1, 0), rankable(2, Some(4.6), 40, 0)];
+        assert_eq!(ranked_ids(&entries, LibrarySort::Top), vec![2, 1]);
+    }
+
+    #[test]
+    fn unrated_entry_scores_prior_mean() {
+        assert_eq!(bayesian_rating(None, 0), RATING_PRIOR_MEAN);
+    }
+
+    #[test]
+    fn recent_orders_by_creation_time() {
+        let entries = vec![
+            rankable(1, Some(5.0), 100, 30),
+            rankable(2, None, 0, 1),
+            rankable(3, Some(2.0), 3, 10),
+        ];
+        assert_eq!(ranked_ids(&entries, LibrarySort::Recent), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn trending_prefers_newer_at_equal_rating() {
+        let entries = vec![
+            rankable(1, Some(4.5), 20, 60),
+            rankable(2, Some(4.5), 20, 2),
+        ];
+        assert_eq!(ranked_ids(&entries, LibrarySort::Trending), vec![2, 1]);
+    }
+
+    #[test]
+    fn library_sort_parses() {
+        assert_eq!(
+            LibrarySort::from_str("trending").unwrap(),
+            LibrarySort::Trending
+        );
+        assert_eq!(LibrarySort::Top.as_str(), "top");
+        assert!(LibrarySort::from_str("popular").is_err());
+    }
 }
@@ -3,6 +3,10 @@
 //! Provides check-type constants, threshold evaluation functions,
 //! and summary computation for the Automated Quality Gates feature.
 
+use std::collections::HashMap;
+
+use serde::Serialize;
+
 use crate::error::CoreError;
 use crate::qa_status::{QA_FAIL, QA_PASS, QA_WARN};
 
@@ -182,6 +186,124 @@ pub fn compute_summary(statuses: &[&str]) -> QaSummary {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Gate decision
+// ---------------------------------------------------------------------------
+
+/// A single check score fed into a gate decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GateScore {
+    pub check_type: String,
+    pub score: f64,
+}
+
+/// Effective thresholds for one check type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GateThreshold {
+    pub check_type: String,
+    pub warn: f64,
+    pub fail: f64,
+    pub is_enabled: bool,
+}
+
+/// How individual check results combine into a gate decision.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GatePolicy {
+    /// Every check must stay at or above its fail threshold.
+    AllMustPass,
+    /// The weighted mean score must reach `min_score`. Check types missing
+    /// from `weights` weigh `1.0`. Technical checks still fail the gate on
+    /// their own.
+    Weighted {
+        weights: HashMap<String, f64>,
+        min_score: f64,
+    },
+}
+
+/// Outcome of evaluating a QA gate.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GateDecision {
+    pub pass: bool,
+    /// Check types with at least one score below the fail threshold, in
+    /// first-seen order.
+    pub failing_checks: Vec<String>,
+    /// Weighted mean score (only for [`GatePolicy::Weighted`]).
+    pub weighted_score: Option<f64>,
+}
+
+/// Resolve `(warn, fail)` for a check type, falling back to the studio
+/// defaults. Returns `None` for disabled or unknown checks.
+fn gate_threshold_for(check_type: &str, thresholds: &[GateThreshold]) -> Option<(f64, f64)> {
+    match thresholds.iter().find(|t| t.check_type == check_type) {
+        Some(t) if t.is_enabled => Some((t.warn, t.fail)),
+        Some(_) => None,
+        None => DEFAULT_THRESHOLDS
+            .iter()
+            .find(|d| d.check_type == check_type)
+            .map(|d| (d.warn, d.fail)),
+    }
+}
+
+/// Decide whether a set of QA scores clears the gate under `policy`.
+///
+/// Scores for disabled or unknown check types are ignored. This is the one
+/// decision function shared by every caller that gates on QA results.
+pub fn evaluate_gate(
+    scores: &[GateScore],
+    thresholds: &[GateThreshold],
+    policy: &GatePolicy,
+) -> GateDecision {
+    let mut failing_checks: Vec<String> = Vec::new();
+    let mut technical_failure = false;
+    let mut weighted_sum = 0.0;
+    let mut weight_total = 0.0;
+
+    for s in scores {
+        let Some((warn, fail)) = gate_threshold_for(&s.check_type, thresholds) else {
+            continue;
+        };
+        let technical = is_technical_check(&s.check_type);
+        let status = if technical {
+            evaluate_technical_score(s.score)
+        } else {
+            evaluate_score(s.score, warn, fail)
+        };
+
+        if status == QA_FAIL {
+            technical_failure |= technical;
+            if !failing_checks.contains(&s.check_type) {
+                failing_checks.push(s.check_type.clone());
+            }
+        }
+
+        if let GatePolicy::Weighted { weights, .. } = policy {
+            let weight = weights.get(&s.check_type).copied().unwrap_or(1.0);
+            weighted_sum += s.score * weight;
+            weight_total += weight;
+        }
+    }
+
+    match policy {
+        GatePolicy::AllMustPass => GateDecision {
+            pass: failing_checks.is_empty(),
+            failing_checks,
+            weighted_score: None,
+        },
+        GatePolicy::Weighted { min_score, .. } => {
+            let weighted_score = if weight_total > 0.0 {
+                weighted_sum / weight_total
+            } else {
+                1.0
+            };
+            GateDecision {
+                pass: !technical_failure && weighted_score >= *min_score,
+                failing_checks,
+                weighted_score: Some(weighted_score),
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -316,4 +438,99 @@ mod tests {
             }
         );
     }
+
+    // -- evaluate_gate --------------------------------------------------------
+
+    fn gate_score(check_type: &str, score: f64) -> GateScore {
+        GateScore {
+            check_type: check_type.to_string(),
+            score,
+        }
+    }
+
+    #[test]
+    fn gate_all_pass() {
+        let scores = vec![
+            gate_score(CHECK_FACE_CONFIDENCE, 0.9),
+            gate_score(CHECK_MOTION, 0.2), // warn still clears the gate
+            gate_score(CHECK_RESOLUTION, 1.0),
+        ];
+        let decision = evaluate_gate(&scores, &[], &GatePolicy::AllMustPass);
+
+        assert!(decision.pass);
+        assert!(decision.failing_checks.is_empty());
+        assert_eq!(decision.weighted_score, None);
+    }
+
+    #[test]
+    fn gate_one_hard_fail() {
+        let scores = vec![
+            gate_score(CHECK_FACE_CONFIDENCE, 0.9),
+            gate_score(CHECK_RESOLUTION, 0.5),
+        ];
+
+        let decision = evaluate_gate(&scores, &[], &GatePolicy::AllMustPass);
+        assert!(!decision.pass);
+        assert_eq!(decision.failing_checks, vec![CHECK_RESOLUTION.to_string()]);
+
+        // A technical failure blocks even a generous weighted policy.
+        let weighted = GatePolicy::Weighted {
+            weights: HashMap::new(),
+            min_score: 0.1,
+        };
+        assert!(!evaluate_gate(&scores, &[], &weighted).pass);
+    }
+
+    #[test]
+    fn gate_weighted_borderline() {
+        let scores = vec![
+            gate_score(CHECK_FACE_CONFIDENCE, 0.75),
+            gate_score(CHECK_MOTION, 0.5),
+        ];
+        let weights = HashMap::from([(CHECK_FACE_CONFIDENCE.to_string(), 3.0)]);
+
+        // (0.75 * 3 + 0.5 * 1) / 4 = 0.6875
+        let at_min = GatePolicy::Weighted {
+            weights: weights.clone(),
+            min_score: 0.6875,
+        };
+        let decision = evaluate_gate(&scores, &[], &at_min);
+        assert!(decision.pass);
+        assert_eq!(decision.weighted_score, Some(0.6875));
+
+        let just_above = GatePolicy::Weighted {
+            weights,
+            min_score: 0.69,
+        };
+        assert!(!evaluate_gate(&scores, &[], &just_above).pass);
+    }
+
+    #[test]
+    fn gate_uses_overrides_and_skips_disabled_checks() {
+        let scores = vec![
+            gate_score(CHECK_FACE_CONFIDENCE, 0.5),
+            gate_score(CHECK_MOTION, 0.0),
+        ];
+        let thresholds = vec![
+            GateThreshold {
+                check_type: CHECK_FACE_CONFIDENCE.to_string(),
+                warn: 0.9,
+                fail: 0.6,
+                is_enabled: true,
+            },
+            GateThreshold {
+                check_type: CHECK_MOTION.to_string(),
+                warn: 0.3,
+                fail: 0.1,
+                is_enabled: false,
+            },
+        ];
+        let decision = evaluate_gate(&scores, &thresholds, &GatePolicy::AllMustPass);
+
+        assert!(!decision.pass);
+        assert_eq!(
+            decision.failing_checks,
+            vec![CHECK_FACE_CONFIDENCE.to_string()]
+        );
+    }
 }
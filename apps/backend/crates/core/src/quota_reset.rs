@@ -0,0 +1,229 @@
+//! GPU quota reset schedule (PRD-08).
+//!
+//! Quota usage is counted from the most recent period boundary: local
+//! midnight for daily quotas, Monday midnight for weekly ones and midnight
+//! on the 1st for monthly ones, all in the quota's IANA timezone.
+//! [`next_quota_reset`] finds the next boundary as a UTC instant, stepping
+//! in local calendar days so a reset stays at local midnight across DST
+//! transitions. Pure functions only; the reset task lives in `x121-api`.
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::digest_schedule::{local_hour_to_utc, parse_timezone};
+
+/// How often a quota's usage counter starts over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl QuotaPeriod {
+    /// First local date of the period containing `date`.
+    fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Daily => date,
+            Self::Weekly => date - Days::new(u64::from(date.weekday().num_days_from_monday())),
+            Self::Monthly => date.with_day(1).expect("day 1 exists in every month"),
+        }
+    }
+
+    /// First local date of the period after the one starting on `start`.
+    fn following(self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Daily => start + Days::new(1),
+            Self::Weekly => start + Days::new(7),
+            Self::Monthly => start + Months::new(1),
+        }
+    }
+}
+
+/// Compute the first reset strictly after `last_reset`.
+///
+/// Resets happen at local midnight at the start of each period in `tz`, so
+/// a daily period spanning a DST change lasts 23 or 25 hours. If midnight
+/// is skipped by a DST jump, the reset happens when the local clock resumes.
+pub fn next_quota_reset(period: QuotaPeriod, last_reset: DateTime<Utc>, tz: Tz) -> DateTime<Utc> {
+    let mut date = period.start_of(last_reset.with_timezone(&tz).date_naive());
+    loop {
+        let at = local_hour_to_utc(tz, date, 0);
+        if at > last_reset {
+            return at;
+        }
+        date = period.following(date);
+    }
+}
+
+/// The most recent reset at or before `now`: the start of the current period.
+pub fn current_period_start(period: QuotaPeriod, now: DateTime<Utc>, tz: Tz) -> DateTime<Utc> {
+    let start = period.start_of(now.with_timezone(&tz).date_naive());
+    local_hour_to_utc(tz, start, 0)
+}
+
+/// The reset a quota should count usage from at `now`.
+///
+/// Returns `last_reset` while it is still current, or the start of the
+/// current period once a reset has fallen due. Callers use this both to
+/// apply due resets and to avoid counting stale usage before they land.
+pub fn effective_quota_reset(
+    period: QuotaPeriod,
+    last_reset: DateTime<Utc>,
+    tz: Tz,
+    now: DateTime<Utc>,
+) -> DateTime<Utc> {
+    if next_quota_reset(period, last_reset, tz) <= now {
+        current_period_start(period, now, tz)
+    } else {
+        last_reset
+    }
+}
+
+/// Usage windows of a quota with daily and weekly limits at one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaWindows {
+    /// Daily usage is counted from here.
+    pub daily_start: DateTime<Utc>,
+    /// When daily usage next starts over.
+    pub daily_resets_at: DateTime<Utc>,
+    /// Weekly usage is counted from here.
+    pub weekly_start: DateTime<Utc>,
+    /// When weekly usage next starts over.
+    pub weekly_resets_at: DateTime<Utc>,
+}
+
+/// Resolve a quota's daily and weekly windows at `now` from its stored
+/// timezone and last reset instants. An unrecognised timezone falls back to
+/// UTC rather than failing the quota check.
+pub fn quota_windows(
+    timezone: &str,
+    daily_reset_at: DateTime<Utc>,
+    weekly_reset_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> QuotaWindows {
+    let tz = parse_timezone(timezone).unwrap_or(Tz::UTC);
+    let daily_start = effective_quota_reset(QuotaPeriod::Daily, daily_reset_at, tz, now);
+    let weekly_start = effective_quota_reset(QuotaPeriod::Weekly, weekly_reset_at, tz, now);
+    QuotaWindows {
+        daily_start,
+        daily_resets_at: next_quota_reset(QuotaPeriod::Daily, daily_start, tz),
+        weekly_start,
+        weekly_resets_at: next_quota_reset(QuotaPeriod::Weekly, weekly_start, tz),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn new_york() -> Tz {
+        parse_timezone("America/New_York").unwrap()
+    }
+
+    // -- month boundaries --
+
+    #[test]
+    fn monthly_reset_is_first_of_next_month() {
+        let next = next_quota_reset(QuotaPeriod::Monthly, utc("2026-01-01T00:00:00Z"), Tz::UTC);
+        assert_eq!(next, utc("2026-02-01T00:00:00Z"));
+
+        // A reset applied mid-month still lands on the 1st.
+        let next = next_quota_reset(QuotaPeriod::Monthly, utc("2026-01-31T18:00:00Z"), Tz::UTC);
+        assert_eq!(next, utc("2026-02-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn daily_reset_crosses_month_end_in_local_time() {
+        // 23:30 on Jan 31 in New York is already Feb 1 in UTC.
+        let next = next_quota_reset(QuotaPeriod::Daily, utc("2026-02-01T04:30:00Z"), new_york());
+        assert_eq!(next, utc("2026-02-01T05:00:00Z"));
+    }
+
+    #[test]
+    fn monthly_reset_handles_short_months() {
+        let next = next_quota_reset(QuotaPeriod::Monthly, utc("2026-02-01T00:00:00Z"), Tz::UTC);
+        assert_eq!(next, utc("2026-03-01T00:00:00Z"));
+    }
+
+    // -- DST --
+
+    #[test]
+    fn daily_reset_stays_at_local_midnight_across_spring_forward() {
+        // Clocks jump 02:00 -> 03:00 on 2026-03-08; that day is 23 hours.
+        let next = next_quota_reset(QuotaPeriod::Daily, utc("2026-03-08T05:00:00Z"), new_york());
+        assert_eq!(next, utc("2026-03-09T04:00:00Z"));
+    }
+
+    #[test]
+    fn daily_reset_stays_at_local_midnight_across_fall_back() {
+        // Clocks fall back 02:00 -> 01:00 on 2026-11-01; that day is 25 hours.
+        let next = next_quota_reset(QuotaPeriod::Daily, utc("2026-11-01T04:00:00Z"), new_york());
+        assert_eq!(next, utc("2026-11-02T05:00:00Z"));
+    }
+
+    #[test]
+    fn weekly_reset_is_monday_midnight_across_dst() {
+        // Monday 2026-03-02 00:00 EST to Monday 2026-03-09 00:00 EDT.
+        let next = next_quota_reset(QuotaPeriod::Weekly, utc("2026-03-02T05:00:00Z"), new_york());
+        assert_eq!(next, utc("2026-03-09T04:00:00Z"));
+    }
+
+    // -- effective reset --
+
+    #[test]
+    fn effective_reset_catches_up_to_current_period() {
+        let last = utc("2026-03-02T05:00:00Z");
+        let tz = new_york();
+
+        // Still inside the week that started at `last`.
+        let now = utc("2026-03-05T12:00:00Z");
+        assert_eq!(
+            effective_quota_reset(QuotaPeriod::Weekly, last, tz, now),
+            last
+        );
+
+        // Two weeks later, the reset jumps straight to the current Monday.
+        let now = utc("2026-03-18T12:00:00Z");
+        assert_eq!(
+            effective_quota_reset(QuotaPeriod::Weekly, last, tz, now),
+            utc("2026-03-16T04:00:00Z")
+        );
+    }
+
+    #[test]
+    fn current_period_start_uses_local_date() {
+        // 03:00 UTC on Mar 10 is still Mar 9 in New York.
+        assert_eq!(
+            current_period_start(QuotaPeriod::Daily, utc("2026-03-10T03:00:00Z"), new_york()),
+            utc("2026-03-09T04:00:00Z")
+        );
+    }
+
+    #[test]
+    fn quota_windows_report_next_resets() {
+        let windows = quota_windows(
+            "America/New_York",
+            utc("2026-03-07T05:00:00Z"),
+            utc("2026-03-02T05:00:00Z"),
+            utc("2026-03-08T15:00:00Z"),
+        );
+        assert_eq!(windows.daily_start, utc("2026-03-08T05:00:00Z"));
+        assert_eq!(windows.daily_resets_at, utc("2026-03-09T04:00:00Z"));
+        assert_eq!(windows.weekly_start, utc("2026-03-02T05:00:00Z"));
+        assert_eq!(windows.weekly_resets_at, utc("2026-03-09T04:00:00Z"));
+
+        // An unknown timezone is treated as UTC.
+        let windows = quota_windows(
+            "Mars/Olympus",
+            utc("2026-03-08T00:00:00Z"),
+            utc("2026-03-02T00:00:00Z"),
+            utc("2026-03-08T15:00:00Z"),
+        );
+        assert_eq!(windows.daily_resets_at, utc("2026-03-09T00:00:00Z"));
+    }
+}
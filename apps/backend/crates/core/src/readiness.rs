@@ -391,6 +391,151 @@ pub fn parse_criteria_json(json: &serde_json::Value) -> Result<ReadinessCriteria
     }
 }
 
+// ---------------------------------------------------------------------------
+// Criteria inheritance
+// ---------------------------------------------------------------------------
+
+/// The scope that supplied an effective criteria field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CriteriaSource {
+    /// Built-in [`ReadinessCriteria::default`].
+    Default,
+    /// Studio-wide criteria row.
+    Studio,
+    /// Project-level override row.
+    Project,
+}
+
+/// Criteria as stored at a single scope. `None` fields inherit from the
+/// next scope up (project -> studio -> built-in default).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopedReadinessCriteria {
+    pub source_media: Option<bool>,
+    pub approved_variant: Option<bool>,
+    pub metadata_complete: Option<bool>,
+    pub metadata_approved: Option<bool>,
+    pub settings: Option<Vec<String>>,
+}
+
+/// Per-field source attribution for effective criteria.
+#[derive(Debug, Clone, Serialize)]
+pub struct CriteriaSources {
+    pub source_media: CriteriaSource,
+    pub approved_variant: CriteriaSource,
+    pub metadata_complete: CriteriaSource,
+    pub metadata_approved: CriteriaSource,
+    pub settings: CriteriaSource,
+}
+
+/// Effective criteria after inheritance, with the scope of each field.
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveReadinessCriteria {
+    pub criteria: ReadinessCriteria,
+    pub sources: CriteriaSources,
+}
+
+/// Parse the fields explicitly set in a `criteria_json` value.
+///
+/// Unlike [`parse_criteria_json`], absent fields are left as `None` so they
+/// can be inherited rather than defaulting to `false`.
+pub fn parse_scoped_criteria_json(
+    json: &serde_json::Value,
+) -> Result<ScopedReadinessCriteria, String> {
+    let obj = json
+        .as_object()
+        .ok_or_else(|| "criteria_json must be a JSON object".to_string())?;
+
+    let Some(rf) = obj.get("required_fields").and_then(|v| v.as_object()) else {
+        return Ok(ScopedReadinessCriteria::default());
+    };
+
+    let flag = |key: &str| rf.get(key).and_then(|v| v.as_bool());
+
+    Ok(ScopedReadinessCriteria {
+        source_media: flag("source_media"),
+        approved_variant: flag("approved_variant"),
+        metadata_complete: flag("metadata_complete"),
+        metadata_approved: flag("metadata_approved"),
+        settings: rf.get("settings").and_then(|v| v.as_array()).map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        }),
+    })
+}
+
+/// Pick the most specific value for one field, recording where it came from.
+fn inherit<T: Clone>(project: Option<&T>, studio: Option<&T>, default: T) -> (T, CriteriaSource) {
+    match (project, studio) {
+        (Some(v), _) => (v.clone(), CriteriaSource::Project),
+        (None, Some(v)) => (v.clone(), CriteriaSource::Studio),
+        (None, None) => (default, CriteriaSource::Default),
+    }
+}
+
+/// Resolve effective criteria field-by-field with source attribution.
+///
+/// Each field takes the project value if set, otherwise the studio value,
+/// otherwise the built-in default.
+pub fn resolve_effective_criteria_with_sources(
+    studio: Option<&ScopedReadinessCriteria>,
+    project: Option<&ScopedReadinessCriteria>,
+) -> EffectiveReadinessCriteria {
+    let defaults = ReadinessCriteria::default();
+
+    let (source_media, source_media_src) = inherit(
+        project.and_then(|p| p.source_media.as_ref()),
+        studio.and_then(|s| s.source_media.as_ref()),
+        defaults.source_media,
+    );
+    let (approved_variant, approved_variant_src) = inherit(
+        project.and_then(|p| p.approved_variant.as_ref()),
+        studio.and_then(|s| s.approved_variant.as_ref()),
+        defaults.approved_variant,
+    );
+    let (metadata_complete, metadata_complete_src) = inherit(
+        project.and_then(|p| p.metadata_complete.as_ref()),
+        studio.and_then(|s| s.metadata_complete.as_ref()),
+        defaults.metadata_complete,
+    );
+    let (metadata_approved, metadata_approved_src) = inherit(
+        project.and_then(|p| p.metadata_approved.as_ref()),
+        studio.and_then(|s| s.metadata_approved.as_ref()),
+        defaults.metadata_approved,
+    );
+    let (settings, settings_src) = inherit(
+        project.and_then(|p| p.settings.as_ref()),
+        studio.and_then(|s| s.settings.as_ref()),
+        defaults.settings,
+    );
+
+    EffectiveReadinessCriteria {
+        criteria: ReadinessCriteria {
+            source_media,
+            approved_variant,
+            metadata_complete,
+            metadata_approved,
+            settings,
+        },
+        sources: CriteriaSources {
+            source_media: source_media_src,
+            approved_variant: approved_variant_src,
+            metadata_complete: metadata_complete_src,
+            metadata_approved: metadata_approved_src,
+            settings: settings_src,
+        },
+    }
+}
+
+/// Resolve the criteria that apply to an avatar after inheritance.
+pub fn resolve_effective_criteria(
+    studio: Option<&ScopedReadinessCriteria>,
+    project: Option<&ScopedReadinessCriteria>,
+) -> ReadinessCriteria {
+    resolve_effective_criteria_with_sources(studio, project).criteria
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -833,4 +978,64 @@ mod tests {
     fn readiness_states_complete() {
         assert_eq!(VALID_READINESS_STATES.len(), 3);
     }
+
+    // -- resolve_effective_criteria -------------------------------------------
+
+    #[test]
+    fn effective_criteria_falls_back_to_defaults() {
+        let effective = resolve_effective_criteria_with_sources(None, None);
+        let defaults = ReadinessCriteria::default();
+        assert_eq!(effective.criteria.source_media, defaults.source_media);
+        assert_eq!(effective.criteria.settings, defaults.settings);
+        assert_eq!(effective.sources.source_media, CriteriaSource::Default);
+        assert_eq!(effective.sources.settings, CriteriaSource::Default);
+    }
+
+    #[test]
+    fn effective_criteria_project_overrides_studio() {
+        let studio = ScopedReadinessCriteria {
+            source_media: Some(true),
+            approved_variant: Some(true),
+            settings: Some(vec!["studio_key".to_string()]),
+            ..Default::default()
+        };
+        let project = ScopedReadinessCriteria {
+            source_media: Some(false),
+            settings: Some(vec!["project_key".to_string()]),
+            ..Default::default()
+        };
+        let effective = resolve_effective_criteria_with_sources(Some(&studio), Some(&project));
+
+        assert!(!effective.criteria.source_media);
+        assert_eq!(effective.sources.source_media, CriteriaSource::Project);
+        assert_eq!(effective.criteria.settings, vec!["project_key".to_string()]);
+        assert_eq!(effective.sources.settings, CriteriaSource::Project);
+
+        assert!(effective.criteria.approved_variant);
+        assert_eq!(effective.sources.approved_variant, CriteriaSource::Studio);
+
+        assert_eq!(effective.sources.metadata_complete, CriteriaSource::Default);
+    }
+
+    #[test]
+    fn scoped_criteria_json_leaves_absent_fields_unset() {
+        let json = serde_json::json!({
+            "required_fields": {"source_media": false, "settings": ["a"]}
+        });
+        let scoped = parse_scoped_criteria_json(&json).unwrap();
+        assert_eq!(scoped.source_media, Some(false));
+        assert_eq!(scoped.approved_variant, None);
+        assert_eq!(scoped.settings, Some(vec!["a".to_string()]));
+    }
+
+    #[test]
+    fn resolve_effective_criteria_matches_with_sources() {
+        let project = ScopedReadinessCriteria {
+            metadata_approved: Some(false),
+            ..Default::default()
+        };
+        let criteria = resolve_effective_criteria(None, Some(&project));
+        assert!(!criteria.metadata_approved);
+        assert!(criteria.source_media);
+    }
 }
@@ -24,6 +24,9 @@ pub enum ReclamationError {
     #[error("Cannot restore: {reason}")]
     CannotRestore { reason: String },
 
+    #[error("Invalid protection rule: {}", .0.join("; "))]
+    InvalidRule(Vec<String>),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
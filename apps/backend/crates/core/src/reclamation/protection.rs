@@ -3,6 +3,16 @@
 //! Given a set of protection rule definitions (loaded from the DB by the caller),
 //! this module evaluates whether a specific condition is met. This is pure logic
 //! with no database dependencies.
+//!
+//! [`ProtectionRule`] is the validated, typed form of a rule: it is built via
+//! [`ProtectionRule::new`] before a rule is persisted, and
+//! [`evaluate_protection`] reports which rule (if any) protects an asset.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::ReclamationError;
 
 /// A simplified representation of a protection rule for evaluation.
 #[derive(Debug, Clone)]
@@ -46,6 +56,179 @@ where
     })
 }
 
+// ---------------------------------------------------------------------------
+// Typed rules
+// ---------------------------------------------------------------------------
+
+/// Operators a protection rule may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtectionOperator {
+    Eq,
+    Neq,
+    IsNotNull,
+    IsNull,
+}
+
+/// Valid operator strings, in the form stored in `condition_operator`.
+pub const VALID_OPERATORS: &[&str] = &["eq", "neq", "is_not_null", "is_null"];
+
+impl ProtectionOperator {
+    /// Parse from the stored string. Returns `None` for unknown operators.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "eq" => Some(Self::Eq),
+            "neq" => Some(Self::Neq),
+            "is_not_null" => Some(Self::IsNotNull),
+            "is_null" => Some(Self::IsNull),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Neq => "neq",
+            Self::IsNotNull => "is_not_null",
+            Self::IsNull => "is_null",
+        }
+    }
+
+    /// Whether the operator compares against `condition_value`.
+    pub fn uses_value(self) -> bool {
+        matches!(self, Self::Eq | Self::Neq)
+    }
+}
+
+/// A well-formed protection rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectionRule {
+    pub name: String,
+    pub entity_type: String,
+    pub condition_field: String,
+    pub operator: ProtectionOperator,
+    pub condition_value: String,
+}
+
+/// Whether `s` is a lowercase snake_case identifier, as used for entity
+/// types and field names.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+impl ProtectionRule {
+    /// Validate raw rule fields and build a typed rule.
+    ///
+    /// Every problem is reported, not just the first, so the admin UI can
+    /// show them all at once.
+    pub fn new(
+        name: &str,
+        entity_type: &str,
+        condition_field: &str,
+        condition_operator: &str,
+        condition_value: &str,
+    ) -> Result<Self, ReclamationError> {
+        let mut issues = Vec::new();
+
+        if name.trim().is_empty() {
+            issues.push("name must not be empty".to_string());
+        }
+        if !is_identifier(entity_type) {
+            issues.push(format!(
+                "entity_type '{entity_type}' must be a snake_case identifier"
+            ));
+        }
+        if !is_identifier(condition_field) {
+            issues.push(format!(
+                "condition_field '{condition_field}' must be a snake_case identifier"
+            ));
+        }
+        let operator = ProtectionOperator::parse(condition_operator);
+        match operator {
+            None => issues.push(format!(
+                "condition_operator '{condition_operator}' must be one of: {}",
+                VALID_OPERATORS.join(", ")
+            )),
+            Some(op) if op.uses_value() && condition_value.is_empty() => issues.push(format!(
+                "condition_value is required for operator '{}'",
+                op.as_str()
+            )),
+            Some(_) => {}
+        }
+
+        match operator {
+            Some(operator) if issues.is_empty() => Ok(Self {
+                name: name.to_string(),
+                entity_type: entity_type.to_string(),
+                condition_field: condition_field.to_string(),
+                operator,
+                condition_value: condition_value.to_string(),
+            }),
+            _ => Err(ReclamationError::InvalidRule(issues)),
+        }
+    }
+
+    /// Whether the rule protects an asset whose field has `field_value`.
+    pub fn matches(&self, field_value: Option<&str>) -> bool {
+        let present = field_value.is_some_and(|v| !v.is_empty());
+        match self.operator {
+            ProtectionOperator::Eq => field_value == Some(self.condition_value.as_str()),
+            ProtectionOperator::Neq => field_value != Some(self.condition_value.as_str()),
+            ProtectionOperator::IsNotNull => present,
+            ProtectionOperator::IsNull => !present,
+        }
+    }
+}
+
+/// An asset considered for reclamation, with the field values rules test.
+#[derive(Debug, Clone, Default)]
+pub struct ProtectableAsset {
+    pub entity_type: String,
+    pub fields: HashMap<String, String>,
+}
+
+/// The rule that protected an asset, with a human-readable reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MatchedRule {
+    pub rule_name: String,
+    pub condition_field: String,
+    pub reason: String,
+}
+
+/// Return the first rule that protects `asset`, or `None` if it may be
+/// reclaimed. Only rules for the asset's entity type are considered.
+pub fn evaluate_protection(
+    asset: &ProtectableAsset,
+    rules: &[ProtectionRule],
+) -> Option<MatchedRule> {
+    rules
+        .iter()
+        .filter(|rule| rule.entity_type == asset.entity_type)
+        .find(|rule| {
+            let value = asset.fields.get(&rule.condition_field);
+            rule.matches(value.map(String::as_str))
+        })
+        .map(|rule| {
+            let reason = if rule.operator.uses_value() {
+                format!(
+                    "{} {} '{}'",
+                    rule.condition_field,
+                    rule.operator.as_str(),
+                    rule.condition_value
+                )
+            } else {
+                format!("{} {}", rule.condition_field, rule.operator.as_str())
+            };
+            MatchedRule {
+                rule_name: rule.name.clone(),
+                condition_field: rule.condition_field.clone(),
+                reason,
+            }
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +318,69 @@ mod tests {
             }
         }));
     }
+
+    // -- Typed rules --
+
+    fn approved_variants_rule() -> ProtectionRule {
+        ProtectionRule::new(
+            "protect_approved_variants",
+            "image_variant",
+            "status",
+            "eq",
+            "approved",
+        )
+        .unwrap()
+    }
+
+    fn asset(entity_type: &str, fields: &[(&str, &str)]) -> ProtectableAsset {
+        ProtectableAsset {
+            entity_type: entity_type.into(),
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_protection_reports_matching_rule() {
+        let rules = vec![approved_variants_rule()];
+        let matched =
+            evaluate_protection(&asset("image_variant", &[("status", "approved")]), &rules)
+                .expect("asset should be protected");
+        assert_eq!(matched.rule_name, "protect_approved_variants");
+        assert_eq!(matched.reason, "status eq 'approved'");
+    }
+
+    #[test]
+    fn test_evaluate_protection_no_match() {
+        let rules = vec![approved_variants_rule()];
+        assert_eq!(
+            evaluate_protection(&asset("image_variant", &[("status", "rejected")]), &rules),
+            None
+        );
+        // Same field value on another entity type is not covered by the rule.
+        assert_eq!(
+            evaluate_protection(&asset("scene", &[("status", "approved")]), &rules),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_rule_rejected_with_specifics() {
+        let err = ProtectionRule::new("", "Image Variant", "status", "like", "app%").unwrap_err();
+        let ReclamationError::InvalidRule(issues) = err else {
+            panic!("expected InvalidRule");
+        };
+        assert_eq!(issues.len(), 3);
+        assert!(issues[0].contains("name"));
+        assert!(issues[1].contains("entity_type 'Image Variant'"));
+        assert!(issues[2].contains("condition_operator 'like'"));
+    }
+
+    #[test]
+    fn test_value_operator_requires_value() {
+        assert!(ProtectionRule::new("r", "scene", "status", "eq", "").is_err());
+        assert!(ProtectionRule::new("r", "scene", "file_path", "is_null", "").is_ok());
+    }
 }
@@ -3,6 +3,10 @@
 //! Provides named constants for tier IDs and names, plus validation functions
 //! for dimensions, speed factors, upscale eligibility, and delivery readiness.
 
+use std::collections::HashSet;
+
+use serde::Serialize;
+
 use crate::error::CoreError;
 
 /* --------------------------------------------------------------------------
@@ -33,6 +37,9 @@ pub const ALL_TIER_NAMES: &[&str] = &[TIER_DRAFT, TIER_PREVIEW, TIER_PRODUCTION]
 /// Maximum dimension (width or height) allowed.
 const MAX_DIMENSION: i32 = 7680;
 
+/// Maximum number of scenes in one batch upscale request.
+pub const MAX_BATCH_UPSCALE_SCENES: usize = 100;
+
 /* --------------------------------------------------------------------------
 Validation functions
 -------------------------------------------------------------------------- */
@@ -106,6 +113,160 @@ pub fn validate_delivery_tier(tier_id: i64) -> Result<(), CoreError> {
     Ok(())
 }
 
+/* --------------------------------------------------------------------------
+Upscale planning
+-------------------------------------------------------------------------- */
+
+/// The parts of a resolution tier needed to plan an upscale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierSpec {
+    pub id: i64,
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    /// Position in the tier ladder; lower is lower quality.
+    pub sort_order: i32,
+}
+
+/// One hop of an upscale, between two adjacent tiers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpscaleStep {
+    pub from_tier_id: i64,
+    pub to_tier_id: i64,
+    pub to_tier_name: String,
+    /// Factor applied to the larger-growing axis for this hop.
+    pub scale_factor: f64,
+}
+
+/// The sequence of tier hops taking a scene from one tier to another.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpscalePlan {
+    pub from_tier_id: i64,
+    pub to_tier_id: i64,
+    /// Hops in order; empty when the scene is already at the target tier.
+    pub steps: Vec<UpscaleStep>,
+    /// Product of the step scale factors (1.0 for a no-op).
+    pub total_scale_factor: f64,
+}
+
+impl UpscalePlan {
+    /// Whether the plan leaves the scene at its current tier.
+    pub fn is_noop(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Plan an upscale from `current_tier_id` to `target_tier_id`.
+///
+/// Tiers are ordered by `sort_order` (then ID), and the plan steps through
+/// every tier in between so each hop stays a modest scale factor. Planning
+/// to the current tier yields an empty, no-op plan. Unknown tiers and
+/// targets ordered below the current tier are rejected, as is any hop that
+/// would shrink the frame.
+pub fn plan_upscale(
+    current_tier_id: i64,
+    target_tier_id: i64,
+    tiers: &[TierSpec],
+) -> Result<UpscalePlan, CoreError> {
+    let mut ladder: Vec<&TierSpec> = tiers.iter().collect();
+    ladder.sort_by_key(|t| (t.sort_order, t.id));
+
+    let position = |id: i64| {
+        ladder
+            .iter()
+            .position(|t| t.id == id)
+            .ok_or_else(|| CoreError::Validation(format!("Unknown resolution tier: {id}")))
+    };
+    let from = position(current_tier_id)?;
+    let to = position(target_tier_id)?;
+    if to < from {
+        return Err(CoreError::Validation(format!(
+            "Cannot upscale from '{}' to '{}': target must be a higher tier",
+            ladder[from].name, ladder[to].name
+        )));
+    }
+
+    let mut steps = Vec::with_capacity(to - from);
+    for pair in ladder[from..=to].windows(2) {
+        let (lower, higher) = (pair[0], pair[1]);
+        if lower.width <= 0 || lower.height <= 0 {
+            return Err(CoreError::Validation(format!(
+                "Resolution tier '{}' has invalid dimensions {}x{}",
+                lower.name, lower.width, lower.height
+            )));
+        }
+        let width_factor = f64::from(higher.width) / f64::from(lower.width);
+        let height_factor = f64::from(higher.height) / f64::from(lower.height);
+        if width_factor < 1.0 || height_factor < 1.0 {
+            return Err(CoreError::Validation(format!(
+                "Cannot upscale from '{}' ({}x{}) to '{}' ({}x{}): the step would downscale",
+                lower.name, lower.width, lower.height, higher.name, higher.width, higher.height
+            )));
+        }
+        steps.push(UpscaleStep {
+            from_tier_id: lower.id,
+            to_tier_id: higher.id,
+            to_tier_name: higher.name.clone(),
+            scale_factor: width_factor.max(height_factor),
+        });
+    }
+
+    let total_scale_factor = steps.iter().map(|s| s.scale_factor).product();
+    Ok(UpscalePlan {
+        from_tier_id: current_tier_id,
+        to_tier_id: target_tier_id,
+        steps,
+        total_scale_factor,
+    })
+}
+
+/* --------------------------------------------------------------------------
+Batch upscale
+-------------------------------------------------------------------------- */
+
+/// Validate a batch upscale request and return its scene IDs with
+/// duplicates removed, in first-seen order.
+pub fn prepare_batch_upscale(scene_ids: &[i64]) -> Result<Vec<i64>, CoreError> {
+    if scene_ids.is_empty() {
+        return Err(CoreError::Validation(
+            "scene_ids must not be empty".to_string(),
+        ));
+    }
+    if scene_ids.len() > MAX_BATCH_UPSCALE_SCENES {
+        return Err(CoreError::Validation(format!(
+            "Cannot upscale more than {MAX_BATCH_UPSCALE_SCENES} scenes at once"
+        )));
+    }
+
+    let mut seen = HashSet::with_capacity(scene_ids.len());
+    Ok(scene_ids
+        .iter()
+        .copied()
+        .filter(|id| seen.insert(*id))
+        .collect())
+}
+
+/// Plan one scene of a batch upscale, returning the rejection reason if it
+/// cannot be upscaled.
+///
+/// Unlike a single-scene upscale, a scene already at the target tier is
+/// rejected rather than treated as a no-op, so the batch result only
+/// reports scenes that actually change tier as accepted.
+pub fn plan_batch_upscale_item(
+    current_tier_id: i64,
+    target_tier_id: i64,
+    tiers: &[TierSpec],
+) -> Result<UpscalePlan, String> {
+    let plan = plan_upscale(current_tier_id, target_tier_id, tiers).map_err(|e| match e {
+        CoreError::Validation(msg) => msg,
+        other => other.to_string(),
+    })?;
+    if plan.is_noop() {
+        return Err("Scene is already at the target tier".to_string());
+    }
+    Ok(plan)
+}
+
 /* --------------------------------------------------------------------------
 Tests
 -------------------------------------------------------------------------- */
@@ -219,4 +380,113 @@ mod tests {
         assert!(validate_delivery_tier(TIER_ID_DRAFT).is_err());
         assert!(validate_delivery_tier(TIER_ID_PREVIEW).is_err());
     }
+
+    // -- plan_upscale --
+
+    fn tier(id: i64, name: &str, width: i32, height: i32) -> TierSpec {
+        TierSpec {
+            id,
+            name: name.to_string(),
+            width,
+            height,
+            sort_order: id as i32,
+        }
+    }
+
+    fn ladder() -> Vec<TierSpec> {
+        vec![
+            tier(TIER_ID_PRODUCTION, TIER_PRODUCTION, 1920, 1080),
+            tier(TIER_ID_DRAFT, TIER_DRAFT, 480, 270),
+            tier(TIER_ID_PREVIEW, TIER_PREVIEW, 960, 540),
+        ]
+    }
+
+    #[test]
+    fn multi_step_upscale_walks_every_tier() {
+        let plan = plan_upscale(TIER_ID_DRAFT, TIER_ID_PRODUCTION, &ladder()).unwrap();
+
+        assert!(!plan.is_noop());
+        let hops: Vec<_> = plan
+            .steps
+            .iter()
+            .map(|s| (s.from_tier_id, s.to_tier_id, s.scale_factor))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![
+                (TIER_ID_DRAFT, TIER_ID_PREVIEW, 2.0),
+                (TIER_ID_PREVIEW, TIER_ID_PRODUCTION, 2.0),
+            ]
+        );
+        assert_eq!(plan.steps[1].to_tier_name, TIER_PRODUCTION);
+        assert_eq!(plan.total_scale_factor, 4.0);
+    }
+
+    #[test]
+    fn same_tier_upscale_is_noop() {
+        let plan = plan_upscale(TIER_ID_PREVIEW, TIER_ID_PREVIEW, &ladder()).unwrap();
+        assert!(plan.is_noop());
+        assert_eq!(plan.total_scale_factor, 1.0);
+    }
+
+    #[test]
+    fn downscale_plan_rejected() {
+        let msg = plan_upscale(TIER_ID_PRODUCTION, TIER_ID_DRAFT, &ladder())
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("target must be a higher tier"));
+    }
+
+    #[test]
+    fn unknown_tier_plan_rejected() {
+        let msg = plan_upscale(TIER_ID_DRAFT, 99, &ladder())
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("Unknown resolution tier: 99"));
+    }
+
+    // -- batch upscale --
+
+    #[test]
+    fn batch_upscale_dedupes_in_order() {
+        assert_eq!(
+            prepare_batch_upscale(&[3, 1, 3, 2, 1]).unwrap(),
+            vec![3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn batch_upscale_rejects_empty_and_oversized() {
+        assert!(prepare_batch_upscale(&[]).is_err());
+        let too_many: Vec<i64> = (1..=MAX_BATCH_UPSCALE_SCENES as i64 + 1).collect();
+        let msg = prepare_batch_upscale(&too_many).unwrap_err().to_string();
+        assert!(msg.contains("more than 100 scenes"));
+    }
+
+    #[test]
+    fn batch_item_at_target_is_rejected() {
+        let reason =
+            plan_batch_upscale_item(TIER_ID_PRODUCTION, TIER_ID_PRODUCTION, &ladder()).unwrap_err();
+        assert_eq!(reason, "Scene is already at the target tier");
+
+        let plan = plan_batch_upscale_item(TIER_ID_DRAFT, TIER_ID_PRODUCTION, &ladder()).unwrap();
+        assert_eq!(plan.steps.len(), 2);
+    }
+
+    #[test]
+    fn batch_item_reason_omits_error_prefix() {
+        let reason =
+            plan_batch_upscale_item(TIER_ID_PRODUCTION, TIER_ID_DRAFT, &ladder()).unwrap_err();
+        assert!(reason.starts_with("Cannot upscale from"));
+    }
+
+    #[test]
+    fn ladder_step_that_shrinks_is_rejected() {
+        let mut tiers = ladder();
+        tiers[2].width = 320;
+        let msg = plan_upscale(TIER_ID_DRAFT, TIER_ID_PREVIEW, &tiers)
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("would downscale"));
+    }
 }
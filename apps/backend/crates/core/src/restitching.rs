@@ -4,7 +4,10 @@
 //! classification, and downstream impact estimation for the Incremental
 //! Re-stitching & Smoothing feature.
 
+use serde::Serialize;
+
 use crate::error::CoreError;
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // SSIM threshold constants
@@ -126,6 +129,92 @@ pub fn estimate_downstream_impact(segment_index: i32, total_segments: i32) -> i3
     }
 }
 
+// ---------------------------------------------------------------------------
+// Scene-level re-stitch planning
+// ---------------------------------------------------------------------------
+
+/// Boundary scores of one active segment, as input to [`plan_scene_restitch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentBoundaries {
+    pub segment_id: DbId,
+    pub sequence_index: i32,
+    /// SSIM of the transition from the previous segment into this one.
+    pub ssim_before: Option<f64>,
+    /// SSIM of the transition from this segment into the next one.
+    pub ssim_after: Option<f64>,
+}
+
+/// What a scene re-stitch does with a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestitchAction {
+    /// All boundaries into the segment pass; it is left untouched.
+    Keep,
+    /// A boundary into the segment is discontinuous; it is regenerated.
+    Regenerate,
+}
+
+/// Planned action for a single segment of a scene re-stitch.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SegmentRestitchPlan {
+    pub segment_id: DbId,
+    pub sequence_index: i32,
+    pub action: RestitchAction,
+    /// The lowest failing SSIM that triggered regeneration, if any.
+    pub failing_ssim: Option<f64>,
+}
+
+/// Plan a scene re-stitch from the boundary scores of its active segments.
+///
+/// The boundary between two adjacent segments is scored on both sides (the
+/// earlier segment's `ssim_after` and the later segment's `ssim_before`).
+/// When either side is a discontinuity under `threshold`, the later segment
+/// is regenerated so it picks up from the earlier one's last frame. Missing
+/// scores are treated as passing.
+///
+/// Segments with a `sequence_index` below `resume_from` were handled by an
+/// earlier, interrupted run and are left out of the plan. The result is
+/// ordered by `sequence_index`.
+pub fn plan_scene_restitch(
+    segments: &[SegmentBoundaries],
+    threshold: f64,
+    resume_from: Option<i32>,
+) -> Result<Vec<SegmentRestitchPlan>, CoreError> {
+    validate_ssim_threshold(threshold)?;
+
+    let mut ordered = segments.to_vec();
+    ordered.sort_by_key(|s| s.sequence_index);
+
+    let failing = |ssim: Option<f64>| {
+        ssim.filter(|&v| classify_boundary_quality(v, threshold) == BoundaryQuality::Discontinuity)
+    };
+
+    let mut plan = Vec::with_capacity(ordered.len());
+    let mut previous_after = None;
+    for segment in &ordered {
+        let worst = [failing(segment.ssim_before), failing(previous_after)]
+            .into_iter()
+            .flatten()
+            .reduce(f64::min);
+        previous_after = segment.ssim_after;
+
+        if resume_from.is_some_and(|from| segment.sequence_index < from) {
+            continue;
+        }
+        plan.push(SegmentRestitchPlan {
+            segment_id: segment.segment_id,
+            sequence_index: segment.sequence_index,
+            action: if worst.is_some() {
+                RestitchAction::Regenerate
+            } else {
+                RestitchAction::Keep
+            },
+            failing_ssim: worst,
+        });
+    }
+    Ok(plan)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -240,4 +329,80 @@ mod tests {
     fn impact_single_segment_scene() {
         assert_eq!(estimate_downstream_impact(0, 1), 0);
     }
+
+    // -- plan_scene_restitch ------------------------------------------------
+
+    fn boundaries(
+        sequence_index: i32,
+        ssim_before: Option<f64>,
+        ssim_after: Option<f64>,
+    ) -> SegmentBoundaries {
+        SegmentBoundaries {
+            segment_id: 100 + DbId::from(sequence_index),
+            sequence_index,
+            ssim_before,
+            ssim_after,
+        }
+    }
+
+    fn regenerated(plan: &[SegmentRestitchPlan]) -> Vec<i32> {
+        plan.iter()
+            .filter(|p| p.action == RestitchAction::Regenerate)
+            .map(|p| p.sequence_index)
+            .collect()
+    }
+
+    #[test]
+    fn plan_regenerates_only_segment_after_failing_boundary() {
+        let segments = [
+            boundaries(2, Some(0.95), None),
+            boundaries(0, None, Some(0.97)),
+            boundaries(1, Some(0.96), Some(0.60)),
+        ];
+
+        let plan = plan_scene_restitch(&segments, DEFAULT_SSIM_THRESHOLD, None).unwrap();
+
+        assert_eq!(
+            plan.iter().map(|p| p.sequence_index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(regenerated(&plan), vec![2]);
+        assert_eq!(plan[2].segment_id, 102);
+        assert_eq!(plan[2].failing_ssim, Some(0.60));
+        assert_eq!(plan[1].failing_ssim, None);
+    }
+
+    #[test]
+    fn plan_reports_lowest_failing_side() {
+        let segments = [
+            boundaries(0, None, Some(0.70)),
+            boundaries(1, Some(0.50), None),
+        ];
+        let plan = plan_scene_restitch(&segments, DEFAULT_SSIM_THRESHOLD, None).unwrap();
+        assert_eq!(plan[1].failing_ssim, Some(0.50));
+    }
+
+    #[test]
+    fn plan_treats_missing_and_warning_scores_as_passing() {
+        let segments = [boundaries(0, None, None), boundaries(1, Some(0.88), None)];
+        let plan = plan_scene_restitch(&segments, DEFAULT_SSIM_THRESHOLD, None).unwrap();
+        assert!(regenerated(&plan).is_empty());
+    }
+
+    #[test]
+    fn plan_resumes_from_checkpoint() {
+        let segments = [
+            boundaries(0, None, Some(0.10)),
+            boundaries(1, None, Some(0.10)),
+            boundaries(2, None, None),
+        ];
+        let plan = plan_scene_restitch(&segments, DEFAULT_SSIM_THRESHOLD, Some(2)).unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(regenerated(&plan), vec![2]);
+    }
+
+    #[test]
+    fn plan_rejects_invalid_threshold() {
+        assert!(plan_scene_restitch(&[], 1.5, None).is_err());
+    }
 }
@@ -4,6 +4,10 @@
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+use crate::auto_retry::{MAX_RETRY_ATTEMPTS, MIN_RETRY_ATTEMPTS};
+use crate::error::CoreError;
+use crate::types::DbId;
+
 /// Regex matching `{placeholder}` tokens in prompt templates.
 static PLACEHOLDER_RE: LazyLock<regex::Regex> =
     LazyLock::new(|| regex::Regex::new(r"\{(\w+)\}").expect("valid regex"));
@@ -163,6 +167,131 @@ pub fn select_prompt_for_position<'a>(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Effective scene type (studio + project)
+// ---------------------------------------------------------------------------
+
+/// The prompt templates stored on one scene type row.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScenePrompts {
+    pub prompt_template: Option<String>,
+    pub negative_prompt_template: Option<String>,
+    pub prompt_start_clip: Option<String>,
+    pub negative_prompt_start_clip: Option<String>,
+    pub prompt_continuation_clip: Option<String>,
+    pub negative_prompt_continuation_clip: Option<String>,
+}
+
+/// A project's customisation of a studio scene type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectSceneTypeOverride {
+    /// Prompts of the project-level scene type sharing the studio type's
+    /// slug, if the project defines one.
+    pub scene_type: Option<ScenePrompts>,
+    /// Project prompt override text replacing the positive prompt.
+    pub positive_override: Option<String>,
+    /// Project prompt override text replacing the negative prompt.
+    pub negative_override: Option<String>,
+}
+
+/// Where an effective prompt template came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneTypeSource {
+    /// The studio-level scene type.
+    Studio,
+    /// The project-level scene type shadowing the studio one.
+    ProjectSceneType,
+    /// A project prompt override's full replacement text.
+    ProjectOverride,
+}
+
+/// A resolved prompt template with its source.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct EffectivePrompt {
+    pub text: String,
+    pub source: SceneTypeSource,
+}
+
+/// The prompt templates a project actually uses for a scene type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EffectiveSceneType {
+    pub prompt_template: Option<EffectivePrompt>,
+    pub negative_prompt_template: Option<EffectivePrompt>,
+    pub prompt_start_clip: Option<EffectivePrompt>,
+    pub negative_prompt_start_clip: Option<EffectivePrompt>,
+    pub prompt_continuation_clip: Option<EffectivePrompt>,
+    pub negative_prompt_continuation_clip: Option<EffectivePrompt>,
+}
+
+impl EffectiveSceneType {
+    /// The positive and negative templates for a clip position, falling back
+    /// to the full-clip templates like [`select_prompt_for_position`].
+    pub fn prompts_for_position(
+        &self,
+        position: ClipPosition,
+    ) -> (Option<&EffectivePrompt>, Option<&EffectivePrompt>) {
+        let (positive, negative) = match position {
+            ClipPosition::FullClip => (None, None),
+            ClipPosition::StartClip => (
+                self.prompt_start_clip.as_ref(),
+                self.negative_prompt_start_clip.as_ref(),
+            ),
+            ClipPosition::ContinuationClip => (
+                self.prompt_continuation_clip.as_ref(),
+                self.negative_prompt_continuation_clip.as_ref(),
+            ),
+        };
+        (
+            positive.or(self.prompt_template.as_ref()),
+            negative.or(self.negative_prompt_template.as_ref()),
+        )
+    }
+}
+
+/// Resolve the prompts a project sees for a studio scene type.
+///
+/// Each template is taken from the project-level scene type when it sets
+/// one, otherwise from the studio scene type. A project override text then
+/// replaces every position of its prompt (positive or negative), matching
+/// how `override_text` replaces the base prompt during generation. Blank
+/// templates count as unset.
+pub fn resolve_effective_scene_type(
+    studio: &ScenePrompts,
+    project_override: &ProjectSceneTypeOverride,
+) -> EffectiveSceneType {
+    let project = project_override.scene_type.as_ref();
+    let pick = |field: fn(&ScenePrompts) -> &Option<String>,
+                replacement: &Option<String>|
+     -> Option<EffectivePrompt> {
+        let tagged = |value: &Option<String>, source| {
+            non_blank(value).map(|text| EffectivePrompt {
+                text: text.to_string(),
+                source,
+            })
+        };
+        tagged(replacement, SceneTypeSource::ProjectOverride)
+            .or_else(|| project.and_then(|p| tagged(field(p), SceneTypeSource::ProjectSceneType)))
+            .or_else(|| tagged(field(studio), SceneTypeSource::Studio))
+    };
+
+    let positive = &project_override.positive_override;
+    let negative = &project_override.negative_override;
+    EffectiveSceneType {
+        prompt_template: pick(|p| &p.prompt_template, positive),
+        negative_prompt_template: pick(|p| &p.negative_prompt_template, negative),
+        prompt_start_clip: pick(|p| &p.prompt_start_clip, positive),
+        negative_prompt_start_clip: pick(|p| &p.negative_prompt_start_clip, negative),
+        prompt_continuation_clip: pick(|p| &p.prompt_continuation_clip, positive),
+        negative_prompt_continuation_clip: pick(|p| &p.negative_prompt_continuation_clip, negative),
+    }
+}
+
+/// The trimmed text of a template, or `None` if absent or blank.
+fn non_blank(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|t| !t.is_empty())
+}
+
 // ---------------------------------------------------------------------------
 // Duration validation
 // ---------------------------------------------------------------------------
@@ -229,6 +358,236 @@ pub fn validate_placeholders(template: &str) -> Vec<String> {
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Slug collision detection
+// ---------------------------------------------------------------------------
+
+/// A requested scene type slug is already taken by another live scene type.
+///
+/// Carries a free alternative so callers can offer it back to the user
+/// instead of surfacing a raw unique-constraint violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugConflict {
+    pub slug: String,
+    pub suggestion: String,
+}
+
+impl std::fmt::Display for SlugConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Slug '{}' is already in use; try '{}'",
+            self.slug, self.suggestion
+        )
+    }
+}
+
+impl From<SlugConflict> for CoreError {
+    fn from(conflict: SlugConflict) -> Self {
+        CoreError::Conflict(conflict.to_string())
+    }
+}
+
+/// Suggest a slug derived from `base` that does not appear in `existing`.
+///
+/// Returns `base` unchanged when it is free; otherwise appends the lowest
+/// numeric suffix (`_2`, `_3`, ...) that is not taken.
+pub fn suggest_unique_slug(base: &str, existing: &[String]) -> String {
+    if !existing.iter().any(|s| s == base) {
+        return base.to_string();
+    }
+    (2u32..)
+        .map(|n| format!("{base}_{n}"))
+        .find(|candidate| !existing.contains(candidate))
+        .expect("unbounded suffix range always yields a free slug")
+}
+
+/// Check `slug` against `existing` and return a [`SlugConflict`] with a
+/// suggested alternative when it is already taken.
+pub fn check_slug_available(slug: &str, existing: &[String]) -> Result<(), SlugConflict> {
+    if existing.iter().any(|s| s == slug) {
+        return Err(SlugConflict {
+            slug: slug.to_string(),
+            suggestion: suggest_unique_slug(slug, existing),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Full config validation
+// ---------------------------------------------------------------------------
+
+/// Matches any `{...}` group so malformed placeholders can be told apart
+/// from well-formed ones.
+static BRACE_GROUP_RE: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\{[^{}]*\}").expect("valid regex"));
+
+/// Whether an issue blocks saving the config or is advisory only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+/// A single problem found in a scene type configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ConfigIssue {
+    pub field: &'static str,
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn error(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            severity: IssueSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            severity: IssueSeverity::Warning,
+            message: message.into(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// The parts of a scene type configuration checked by
+/// [`validate_scene_type_config`].
+///
+/// Workflow existence is resolved by the caller (it needs the database) and
+/// passed in as `workflow_exists`.
+#[derive(Debug, Clone, Default)]
+pub struct SceneTypeConfig<'a> {
+    pub name: &'a str,
+    /// Prompt templates keyed by their field name.
+    pub prompt_templates: Vec<(&'static str, &'a str)>,
+    pub workflow_id: Option<DbId>,
+    pub workflow_exists: bool,
+    pub target_duration_secs: Option<i32>,
+    pub segment_duration_secs: Option<i32>,
+    pub duration_tolerance_secs: Option<i32>,
+    pub transition_segment_index: Option<i32>,
+    pub expected_chunks: Option<i32>,
+    pub auto_retry_max_attempts: Option<i32>,
+    pub auto_retry_cfg_jitter: Option<f64>,
+    pub target_fps: Option<i32>,
+}
+
+/// Upper bound for `target_fps`.
+pub const MAX_TARGET_FPS: i32 = 120;
+
+/// Validate a scene type configuration and return every issue found.
+///
+/// Errors cover malformed prompt placeholders (which can never be
+/// substituted and would leak verbatim into the prompt), a `workflow_id`
+/// that does not reference an existing workflow, and out-of-range numeric
+/// parameters. Unknown but well-formed placeholders are warnings since they
+/// may still resolve from avatar metadata.
+pub fn validate_scene_type_config(config: &SceneTypeConfig<'_>) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+
+    if config.name.trim().is_empty() {
+        issues.push(ConfigIssue::error("name", "name is required"));
+    }
+
+    for &(field, template) in &config.prompt_templates {
+        for group in BRACE_GROUP_RE.find_iter(template) {
+            if !PLACEHOLDER_RE.is_match(group.as_str()) {
+                issues.push(ConfigIssue::error(
+                    field,
+                    format!(
+                        "Malformed placeholder '{}' can never be resolved",
+                        group.as_str()
+                    ),
+                ));
+            }
+        }
+        let stripped = BRACE_GROUP_RE.replace_all(template, "");
+        if stripped.contains('{') || stripped.contains('}') {
+            issues.push(ConfigIssue::error(
+                field,
+                "Unbalanced '{' or '}' in template",
+            ));
+        }
+        for p in validate_placeholders(template) {
+            issues.push(ConfigIssue::warning(
+                field,
+                format!("Unknown placeholder '{{{p}}}' — may not resolve"),
+            ));
+        }
+    }
+
+    if let Some(id) = config.workflow_id {
+        if !config.workflow_exists {
+            issues.push(ConfigIssue::error(
+                "workflow_id",
+                format!("Workflow {id} does not exist"),
+            ));
+        }
+    }
+
+    if let Err(e) = validate_duration_config(
+        config.target_duration_secs,
+        config.segment_duration_secs,
+        config.duration_tolerance_secs,
+    ) {
+        issues.push(ConfigIssue::error("duration", e));
+    }
+
+    if config.transition_segment_index.is_some_and(|i| i < 0) {
+        issues.push(ConfigIssue::error(
+            "transition_segment_index",
+            "must be non-negative",
+        ));
+    }
+    if config.expected_chunks.is_some_and(|n| n <= 0) {
+        issues.push(ConfigIssue::error("expected_chunks", "must be positive"));
+    }
+    if let Some(n) = config.auto_retry_max_attempts {
+        if !(MIN_RETRY_ATTEMPTS..=MAX_RETRY_ATTEMPTS).contains(&n) {
+            issues.push(ConfigIssue::error(
+                "auto_retry_max_attempts",
+                format!("must be between {MIN_RETRY_ATTEMPTS} and {MAX_RETRY_ATTEMPTS}"),
+            ));
+        }
+    }
+    if config
+        .auto_retry_cfg_jitter
+        .is_some_and(|j| !j.is_finite() || j < 0.0)
+    {
+        issues.push(ConfigIssue::error(
+            "auto_retry_cfg_jitter",
+            "must be a non-negative number",
+        ));
+    }
+    if let Some(fps) = config.target_fps {
+        if !(1..=MAX_TARGET_FPS).contains(&fps) {
+            issues.push(ConfigIssue::error(
+                "target_fps",
+                format!("must be between 1 and {MAX_TARGET_FPS}"),
+            ));
+        }
+    }
+
+    issues
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -422,4 +781,195 @@ mod tests {
         let keys = extract_placeholders("{a} and {b} and {a}");
         assert_eq!(keys, vec!["a", "b", "a"]);
     }
+
+    // -- Slug collision detection --
+
+    fn slugs(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn suggest_slug_returns_base_when_free() {
+        assert_eq!(suggest_unique_slug("idle", &slugs(&["intro"])), "idle");
+    }
+
+    #[test]
+    fn suggest_slug_appends_first_suffix() {
+        assert_eq!(suggest_unique_slug("idle", &slugs(&["idle"])), "idle_2");
+    }
+
+    #[test]
+    fn suggest_slug_skips_taken_suffixes() {
+        let existing = slugs(&["idle", "idle_2", "idle_3", "idle_5"]);
+        assert_eq!(suggest_unique_slug("idle", &existing), "idle_4");
+    }
+
+    #[test]
+    fn check_slug_available_reports_conflict_with_suggestion() {
+        let err = check_slug_available("bj", &slugs(&["bj"])).unwrap_err();
+        assert_eq!(err.slug, "bj");
+        assert_eq!(err.suggestion, "bj_2");
+        let core: CoreError = err.into();
+        assert!(matches!(core, CoreError::Conflict(msg) if msg.contains("'bj_2'")));
+    }
+
+    #[test]
+    fn check_slug_available_ok_when_free() {
+        assert!(check_slug_available("dance", &slugs(&["bj"])).is_ok());
+    }
+
+    // -- Full config validation (PRD-23) --
+
+    fn valid_config() -> SceneTypeConfig<'static> {
+        SceneTypeConfig {
+            name: "Dance",
+            prompt_templates: vec![(
+                "prompt_template",
+                "{avatar_name} dancing, {hair_color} hair",
+            )],
+            workflow_id: Some(7),
+            workflow_exists: true,
+            target_duration_secs: Some(16),
+            segment_duration_secs: Some(4),
+            target_fps: Some(30),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        assert!(validate_scene_type_config(&valid_config()).is_empty());
+    }
+
+    #[test]
+    fn unresolved_variable_template_is_rejected() {
+        let config = SceneTypeConfig {
+            prompt_templates: vec![("prompt_template", "{avatar name} in a {location")],
+            ..valid_config()
+        };
+        let issues = validate_scene_type_config(&config);
+        let errors: Vec<_> = issues.iter().filter(|i| i.is_error()).collect();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|i| i.field == "prompt_template"));
+        assert!(errors[0].message.contains("{avatar name}"));
+    }
+
+    #[test]
+    fn unknown_placeholder_is_only_a_warning() {
+        let config = SceneTypeConfig {
+            prompt_templates: vec![("prompt_start_clip", "{avatar_name} wearing {outfit}")],
+            ..valid_config()
+        };
+        let issues = validate_scene_type_config(&config);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[0].field, "prompt_start_clip");
+    }
+
+    #[test]
+    fn missing_workflow_reference_is_rejected() {
+        let config = SceneTypeConfig {
+            workflow_exists: false,
+            ..valid_config()
+        };
+        let issues = validate_scene_type_config(&config);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "workflow_id");
+        assert!(issues[0].is_error());
+    }
+
+    #[test]
+    fn all_out_of_range_parameters_are_reported() {
+        let config = SceneTypeConfig {
+            segment_duration_secs: Some(32),
+            expected_chunks: Some(0),
+            auto_retry_max_attempts: Some(50),
+            target_fps: Some(0),
+            ..valid_config()
+        };
+        let fields: Vec<_> = validate_scene_type_config(&config)
+            .into_iter()
+            .map(|i| i.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "duration",
+                "expected_chunks",
+                "auto_retry_max_attempts",
+                "target_fps"
+            ]
+        );
+    }
+
+    fn studio_prompts() -> ScenePrompts {
+        ScenePrompts {
+            prompt_template: Some("studio {avatar_name} dancing".to_string()),
+            negative_prompt_template: Some("blurry".to_string()),
+            prompt_start_clip: Some("studio intro".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn project_override_text_wins_over_scene_types() {
+        let project = ProjectSceneTypeOverride {
+            scene_type: Some(ScenePrompts {
+                prompt_template: Some("project {avatar_name} dancing".to_string()),
+                ..Default::default()
+            }),
+            positive_override: Some("override {avatar_name}".to_string()),
+            negative_override: None,
+        };
+
+        let effective = resolve_effective_scene_type(&studio_prompts(), &project);
+
+        let (positive, negative) = effective.prompts_for_position(ClipPosition::StartClip);
+        let positive = positive.unwrap();
+        assert_eq!(positive.text, "override {avatar_name}");
+        assert_eq!(positive.source, SceneTypeSource::ProjectOverride);
+        let negative = negative.unwrap();
+        assert_eq!(negative.text, "blurry");
+        assert_eq!(negative.source, SceneTypeSource::Studio);
+    }
+
+    #[test]
+    fn project_scene_type_fields_shadow_studio_fields() {
+        let project = ProjectSceneTypeOverride {
+            scene_type: Some(ScenePrompts {
+                prompt_template: Some("project {avatar_name} dancing".to_string()),
+                negative_prompt_template: Some("  ".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let effective = resolve_effective_scene_type(&studio_prompts(), &project);
+
+        let template = effective.prompt_template.as_ref().unwrap();
+        assert_eq!(template.text, "project {avatar_name} dancing");
+        assert_eq!(template.source, SceneTypeSource::ProjectSceneType);
+        // Blank project templates fall through to the studio.
+        let negative = effective.negative_prompt_template.as_ref().unwrap();
+        assert_eq!(negative.source, SceneTypeSource::Studio);
+        let start = effective.prompt_start_clip.as_ref().unwrap();
+        assert_eq!(start.text, "studio intro");
+        assert_eq!(start.source, SceneTypeSource::Studio);
+    }
+
+    #[test]
+    fn without_project_override_studio_config_is_used() {
+        let effective =
+            resolve_effective_scene_type(&studio_prompts(), &ProjectSceneTypeOverride::default());
+
+        let (positive, negative) = effective.prompts_for_position(ClipPosition::FullClip);
+        assert_eq!(positive.unwrap().text, "studio {avatar_name} dancing");
+        assert_eq!(positive.unwrap().source, SceneTypeSource::Studio);
+        assert_eq!(negative.unwrap().source, SceneTypeSource::Studio);
+
+        // Continuation has no template of its own and falls back to full clip.
+        let (positive, _) = effective.prompts_for_position(ClipPosition::ContinuationClip);
+        assert_eq!(positive.unwrap().text, "studio {avatar_name} dancing");
+        assert!(effective.prompt_continuation_clip.is_none());
+    }
 }
@@ -3,6 +3,9 @@
 //! This module lives in `core` (zero internal deps) so it can be used by both
 //! the API/repository layer and any future worker or CLI tooling.
 
+use crate::error::CoreError;
+use crate::types::DbId;
+
 // ---------------------------------------------------------------------------
 // Priority constants
 // ---------------------------------------------------------------------------
@@ -16,6 +19,105 @@ pub const PRIORITY_NORMAL: i32 = 0;
 /// Priority value for background jobs. Dispatched last.
 pub const PRIORITY_BACKGROUND: i32 = -10;
 
+// ---------------------------------------------------------------------------
+// Queue ordering
+// ---------------------------------------------------------------------------
+
+/// Rank offset used when a job moves to the edge of a priority band.
+pub const QUEUE_RANK_STEP: f64 = 1.0;
+
+/// Smallest rank gap left between neighbours when a band is renumbered.
+pub const MIN_QUEUE_RANK_GAP: f64 = 1e-3;
+
+/// PostgreSQL advisory lock ID serializing queue reorders.
+pub const QUEUE_REORDER_LOCK_ID: i64 = 918_273_646;
+
+/// A queued job's sort key.
+///
+/// The queue orders by `priority` descending, then `rank` ascending.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueueSlot {
+    pub job_id: DbId,
+    pub priority: i32,
+    pub rank: f64,
+}
+
+/// Where a reordered job lands in the queue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueuePlacement {
+    /// Give the moved job this priority and rank; no other job changes.
+    At { priority: i32, rank: f64 },
+    /// No rank fits between the new neighbours in this priority band, so
+    /// the band must be renumbered before placing the job.
+    RenumberBand(i32),
+}
+
+/// Check that `expected` lists the queued jobs in their current order.
+///
+/// Rejects with a conflict when the queue changed since the caller read it.
+pub fn validate_queue_snapshot(queue: &[QueueSlot], expected: &[DbId]) -> Result<(), CoreError> {
+    if queue.iter().map(|s| s.job_id).eq(expected.iter().copied()) {
+        Ok(())
+    } else {
+        Err(CoreError::Conflict(
+            "The queue changed since it was read; refresh and retry".to_string(),
+        ))
+    }
+}
+
+/// Compute the placement that moves `job_id` to zero-based `position`.
+///
+/// The job takes the priority of the neighbour it lands next to, and a rank
+/// between its new neighbours, so only the moved job is rewritten.
+pub fn place_in_queue(
+    queue: &[QueueSlot],
+    job_id: DbId,
+    position: usize,
+) -> Result<QueuePlacement, CoreError> {
+    let job = queue
+        .iter()
+        .find(|s| s.job_id == job_id)
+        .ok_or_else(|| CoreError::Validation(format!("Job {job_id} is not queued")))?;
+    if position >= queue.len() {
+        return Err(CoreError::Validation(format!(
+            "position must be less than the queue length ({})",
+            queue.len()
+        )));
+    }
+
+    let others: Vec<&QueueSlot> = queue.iter().filter(|s| s.job_id != job_id).collect();
+    let prev = position.checked_sub(1).map(|i| others[i]);
+    let next = others.get(position).copied();
+
+    let placement = match (prev, next) {
+        (None, None) => QueuePlacement::At {
+            priority: job.priority,
+            rank: job.rank,
+        },
+        (None, Some(next)) => QueuePlacement::At {
+            priority: next.priority,
+            rank: next.rank - QUEUE_RANK_STEP,
+        },
+        (Some(prev), Some(next)) if prev.priority == next.priority => {
+            let rank = prev.rank + (next.rank - prev.rank) / 2.0;
+            if rank > prev.rank && rank < next.rank {
+                QueuePlacement::At {
+                    priority: prev.priority,
+                    rank,
+                }
+            } else {
+                QueuePlacement::RenumberBand(prev.priority)
+            }
+        }
+        // `prev` is the last job of its band: land just after it.
+        (Some(prev), _) => QueuePlacement::At {
+            priority: prev.priority,
+            rank: prev.rank + QUEUE_RANK_STEP,
+        },
+    };
+    Ok(placement)
+}
+
 // ---------------------------------------------------------------------------
 // State machine
 // ---------------------------------------------------------------------------
@@ -94,6 +196,107 @@ pub mod state_machine {
 #[cfg(test)]
 mod tests {
     use super::state_machine::*;
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // Queue ordering
+    // -----------------------------------------------------------------------
+
+    fn slot(job_id: DbId, priority: i32, rank: f64) -> QueueSlot {
+        QueueSlot {
+            job_id,
+            priority,
+            rank,
+        }
+    }
+
+    /// Jobs 1..=4: one urgent job, then three normal jobs.
+    fn sample_queue() -> Vec<QueueSlot> {
+        vec![
+            slot(1, PRIORITY_URGENT, 100.0),
+            slot(2, PRIORITY_NORMAL, 100.0),
+            slot(3, PRIORITY_NORMAL, 200.0),
+            slot(4, PRIORITY_NORMAL, 300.0),
+        ]
+    }
+
+    #[test]
+    fn place_between_neighbours_in_same_band() {
+        let placement = place_in_queue(&sample_queue(), 4, 2).unwrap();
+        assert_eq!(
+            placement,
+            QueuePlacement::At {
+                priority: PRIORITY_NORMAL,
+                rank: 150.0
+            }
+        );
+    }
+
+    #[test]
+    fn place_at_top_takes_first_job_priority() {
+        let placement = place_in_queue(&sample_queue(), 3, 0).unwrap();
+        assert_eq!(
+            placement,
+            QueuePlacement::At {
+                priority: PRIORITY_URGENT,
+                rank: 100.0 - QUEUE_RANK_STEP
+            }
+        );
+    }
+
+    #[test]
+    fn place_at_end_follows_last_job() {
+        let placement = place_in_queue(&sample_queue(), 1, 3).unwrap();
+        assert_eq!(
+            placement,
+            QueuePlacement::At {
+                priority: PRIORITY_NORMAL,
+                rank: 300.0 + QUEUE_RANK_STEP
+            }
+        );
+    }
+
+    #[test]
+    fn place_across_bands_joins_end_of_higher_band() {
+        let placement = place_in_queue(&sample_queue(), 3, 1).unwrap();
+        assert_eq!(
+            placement,
+            QueuePlacement::At {
+                priority: PRIORITY_URGENT,
+                rank: 100.0 + QUEUE_RANK_STEP
+            }
+        );
+    }
+
+    #[test]
+    fn place_in_exhausted_gap_requests_renumber() {
+        let queue = vec![
+            slot(1, PRIORITY_NORMAL, 1.0),
+            slot(2, PRIORITY_NORMAL, 1.0 + f64::EPSILON),
+            slot(3, PRIORITY_NORMAL, 5.0),
+        ];
+        assert_eq!(
+            place_in_queue(&queue, 3, 1).unwrap(),
+            QueuePlacement::RenumberBand(PRIORITY_NORMAL)
+        );
+    }
+
+    #[test]
+    fn place_rejects_unknown_job_and_bad_position() {
+        assert!(place_in_queue(&sample_queue(), 99, 0).is_err());
+        assert!(place_in_queue(&sample_queue(), 1, 4).is_err());
+    }
+
+    #[test]
+    fn snapshot_must_match_current_order() {
+        let queue = sample_queue();
+        assert!(validate_queue_snapshot(&queue, &[1, 2, 3, 4]).is_ok());
+        assert!(matches!(
+            validate_queue_snapshot(&queue, &[1, 3, 2, 4]),
+            Err(CoreError::Conflict(_))
+        ));
+        assert!(validate_queue_snapshot(&queue, &[1, 2, 3]).is_err());
+    }
 
     // -----------------------------------------------------------------------
     // Valid transitions
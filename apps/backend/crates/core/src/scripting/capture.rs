@@ -0,0 +1,146 @@
+//! Size-bounded capture of process output.
+//!
+//! Output beyond the capture limit is dropped, but never silently: the
+//! kept prefix is followed by a `…[truncated N bytes]` marker and the
+//! original length is reported so callers can persist it.
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Output captured from a stream, possibly truncated.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapturedOutput {
+    /// Captured text. When truncated, at most `max` bytes of the original
+    /// followed by the truncation marker.
+    pub text: String,
+    /// Whether any bytes were dropped.
+    pub truncated: bool,
+    /// Length of the original stream in bytes.
+    pub original_len: usize,
+}
+
+/// Capture `stream` as text, keeping at most `max` bytes.
+///
+/// Invalid UTF-8 is replaced lossily. The cut is moved back to a character
+/// boundary, so slightly fewer than `max` bytes may be kept.
+pub fn capture_output(stream: &[u8], max: usize) -> CapturedOutput {
+    build(stream, stream.len(), max)
+}
+
+/// Read `reader` to the end, buffering at most `max` bytes.
+///
+/// Bytes past the limit are drained and counted but not kept, so memory use
+/// stays bounded however much the process writes.
+pub async fn capture_stream<R: AsyncRead + Unpin>(reader: &mut R, max: usize) -> CapturedOutput {
+    // Keep a few bytes past `max` so the cut can find a character boundary.
+    let mut kept = Vec::new();
+    let _ = (&mut *reader)
+        .take(max as u64 + 3)
+        .read_to_end(&mut kept)
+        .await;
+
+    let mut original_len = kept.len();
+    let mut scratch = [0u8; 8192];
+    while let Ok(n) = reader.read(&mut scratch).await {
+        if n == 0 {
+            break;
+        }
+        original_len += n;
+    }
+
+    build(&kept, original_len, max)
+}
+
+/// Capture a prefix of a stream whose full length is `original_len`,
+/// keeping at most `max` bytes.
+///
+/// For callers that stop reading at the limit; pass a few bytes past `max`
+/// so the cut can find a character boundary.
+pub fn capture_prefix(prefix: &[u8], original_len: usize, max: usize) -> CapturedOutput {
+    build(prefix, original_len.max(prefix.len()), max)
+}
+
+/// Build a capture from the available bytes and the original stream length.
+fn build(bytes: &[u8], original_len: usize, max: usize) -> CapturedOutput {
+    if original_len <= max {
+        return CapturedOutput {
+            text: String::from_utf8_lossy(bytes).into_owned(),
+            truncated: false,
+            original_len,
+        };
+    }
+
+    // Never split a multi-byte character: back off while the byte at the
+    // cut is a UTF-8 continuation byte.
+    let mut end = max.min(bytes.len());
+    while end > 0 && end < bytes.len() && bytes[end] & 0xC0 == 0x80 {
+        end -= 1;
+    }
+    let mut text = String::from_utf8_lossy(&bytes[..end]).into_owned();
+    text.push_str(&format!("…[truncated {} bytes]", original_len - end));
+
+    CapturedOutput {
+        text,
+        truncated: true,
+        original_len,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn under_limit_is_kept_whole() {
+        let out = capture_output(b"hello", 10);
+        assert_eq!(out.text, "hello");
+        assert!(!out.truncated);
+        assert_eq!(out.original_len, 5);
+    }
+
+    #[test]
+    fn exactly_limit_is_not_truncated() {
+        let out = capture_output(b"0123456789", 10);
+        assert_eq!(out.text, "0123456789");
+        assert!(!out.truncated);
+        assert_eq!(out.original_len, 10);
+    }
+
+    #[test]
+    fn over_limit_is_truncated_with_marker() {
+        let out = capture_output(b"0123456789abcdef", 10);
+        assert_eq!(out.text, "0123456789…[truncated 6 bytes]");
+        assert!(out.truncated);
+        assert_eq!(out.original_len, 16);
+    }
+
+    #[test]
+    fn truncation_backs_off_to_char_boundary() {
+        // "é" is two bytes; a 3-byte limit would split the second one.
+        let out = capture_output("éé".as_bytes(), 3);
+        assert_eq!(out.text, "é…[truncated 2 bytes]");
+        assert!(out.truncated);
+    }
+
+    #[test]
+    fn prefix_capture_reports_full_length() {
+        let out = capture_prefix(b"0123456789abc", 1_000, 10);
+        assert_eq!(out.text, "0123456789…[truncated 990 bytes]");
+        assert!(out.truncated);
+        assert_eq!(out.original_len, 1_000);
+    }
+
+    #[tokio::test]
+    async fn stream_capture_counts_drained_bytes() {
+        let data = vec![b'x'; 20_000];
+        let out = capture_stream(&mut data.as_slice(), 100).await;
+        assert!(out.truncated);
+        assert_eq!(out.original_len, 20_000);
+        assert!(out.text.starts_with(&"x".repeat(100)));
+        assert!(out.text.ends_with("…[truncated 19900 bytes]"));
+    }
+}
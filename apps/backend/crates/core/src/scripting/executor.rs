@@ -9,6 +9,16 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Default number of script executions allowed to run at once.
+pub const DEFAULT_MAX_CONCURRENT_EXECUTIONS: usize = 4;
+
+/// Default number of executions allowed to wait for a free slot before
+/// further requests are rejected.
+pub const DEFAULT_MAX_QUEUED_EXECUTIONS: usize = 16;
+
+/// Default maximum stdout or stderr size captured per stream (10 MiB).
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 10 * 1024 * 1024;
+
 /// Input data passed to a script executor.
 #[derive(Debug, Clone)]
 pub struct ScriptInput {
@@ -20,13 +30,21 @@ pub struct ScriptInput {
     pub working_directory: Option<String>,
     /// Maximum wall-clock time before the process is killed.
     pub timeout: Duration,
+    /// Maximum bytes captured per output stream; the rest is counted but
+    /// dropped (see [`capture_stream`](super::capture::capture_stream)).
+    pub max_output_bytes: usize,
 }
 
 /// Captured output from a script execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScriptOutput {
-    /// Complete stdout captured from the process.
+    /// Stdout captured from the process, ending in a truncation marker
+    /// when cut at `max_output_bytes`.
     pub stdout: String,
+    /// Whether stdout was truncated.
+    pub stdout_truncated: bool,
+    /// Length of the full stdout in bytes.
+    pub stdout_original_len: usize,
     /// Complete stderr captured from the process.
     pub stderr: String,
     /// Process exit code (`-1` if killed by signal).
@@ -5,6 +5,7 @@
 //! the `core` crate for isolation and testability.
 
 pub mod binary;
+pub mod capture;
 pub mod executor;
 pub mod python;
 pub mod shell;
@@ -29,6 +30,7 @@ pub(crate) mod test_helpers {
             env_vars: vec![],
             working_directory: None,
             timeout: Duration::from_secs(5),
+            max_output_bytes: super::executor::DEFAULT_MAX_OUTPUT_BYTES,
         }
     }
 }
@@ -30,6 +30,7 @@ mod tests {
     use std::time::Duration;
 
     use super::*;
+    use crate::scripting::executor::DEFAULT_MAX_OUTPUT_BYTES;
     use crate::scripting::test_helpers::default_input;
 
     /// Helper to create a temporary shell script from the given body.
@@ -64,6 +65,7 @@ mod tests {
             env_vars: vec![("MY_VAR".to_string(), "hello_world".to_string())],
             working_directory: None,
             timeout: Duration::from_secs(5),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
         };
         let output = ShellExecutor
             .execute(script.path().to_str().expect("path"), input)
@@ -92,6 +94,7 @@ mod tests {
             env_vars: vec![],
             working_directory: None,
             timeout: Duration::from_millis(200),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
         };
         let result = ShellExecutor
             .execute(script.path().to_str().expect("path"), input)
@@ -121,6 +124,7 @@ mod tests {
             env_vars: vec![],
             working_directory: Some(dir.path().to_str().expect("path").to_string()),
             timeout: Duration::from_secs(5),
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
         };
         let output = ShellExecutor
             .execute(script.path().to_str().expect("path"), input)
@@ -145,4 +149,20 @@ mod tests {
             expected
         );
     }
+
+    #[tokio::test]
+    async fn test_shell_output_truncation_is_reported() {
+        let script = write_temp_script("printf '0123456789'\n");
+        let input = ScriptInput {
+            max_output_bytes: 4,
+            ..default_input()
+        };
+        let output = ShellExecutor
+            .execute(script.path().to_str().expect("path"), input)
+            .await
+            .expect("execute");
+        assert!(output.stdout_truncated);
+        assert_eq!(output.stdout_original_len, 10);
+        assert!(output.stdout.starts_with("0123…[truncated 6 bytes]"));
+    }
 }
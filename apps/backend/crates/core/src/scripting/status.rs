@@ -1,7 +1,12 @@
 //! Well-known execution status ID constants for `script_executions`.
 //!
 //! These must match the seed data in
-//! `20260221000005_create_script_executions_table.sql`.
+//! `20260221000005_create_script_executions_table.sql` and
+//! `20260418000006_add_script_execution_history_filters.sql`.
+
+use std::str::FromStr;
+
+use crate::error::CoreError;
 
 /// Execution has been created but not yet started.
 pub const EXECUTION_PENDING: i16 = 1;
@@ -18,6 +23,52 @@ pub const EXECUTION_FAILED: i16 = 4;
 /// Script was killed because it exceeded its configured timeout.
 pub const EXECUTION_TIMEOUT: i16 = 5;
 
+/// Execution was cancelled before it finished.
+pub const EXECUTION_CANCELLED: i16 = 6;
+
+// ---------------------------------------------------------------------------
+// Execution outcome filter
+// ---------------------------------------------------------------------------
+
+/// Terminal outcome used to filter execution history.
+///
+/// `Success` and `Failure` are derived from status and exit code: a
+/// completed run with a non-zero exit code counts as a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    Success,
+    Failure,
+    Timeout,
+    Cancelled,
+}
+
+impl ExecutionOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+            Self::Timeout => "timeout",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl FromStr for ExecutionOutcome {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            "timeout" => Ok(Self::Timeout),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(CoreError::Validation(format!(
+                "Invalid execution status: '{s}'. Must be one of: success, failure, timeout, cancelled"
+            ))),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -43,6 +94,7 @@ mod tests {
             EXECUTION_COMPLETED,
             EXECUTION_FAILED,
             EXECUTION_TIMEOUT,
+            EXECUTION_CANCELLED,
         ];
         let mut unique = statuses.to_vec();
         unique.sort();
@@ -60,5 +112,22 @@ mod tests {
         assert_eq!(EXECUTION_RUNNING + 1, EXECUTION_COMPLETED);
         assert_eq!(EXECUTION_COMPLETED + 1, EXECUTION_FAILED);
         assert_eq!(EXECUTION_FAILED + 1, EXECUTION_TIMEOUT);
+        assert_eq!(EXECUTION_TIMEOUT + 1, EXECUTION_CANCELLED);
+    }
+
+    #[test]
+    fn execution_outcome_roundtrip() {
+        for outcome in [
+            ExecutionOutcome::Success,
+            ExecutionOutcome::Failure,
+            ExecutionOutcome::Timeout,
+            ExecutionOutcome::Cancelled,
+        ] {
+            assert_eq!(
+                ExecutionOutcome::from_str(outcome.as_str()).unwrap(),
+                outcome
+            );
+        }
+        assert!(ExecutionOutcome::from_str("completed").is_err());
     }
 }
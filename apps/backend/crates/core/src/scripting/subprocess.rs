@@ -8,17 +8,12 @@
 use std::process::Stdio;
 use std::time::Instant;
 
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncWriteExt};
 use tokio::process::Command;
 
+use super::capture::{capture_stream, CapturedOutput};
 use super::executor::{ScriptError, ScriptInput, ScriptOutput};
 
-/// Maximum stdout or stderr size captured per stream (10 MiB).
-///
-/// Output exceeding this limit is truncated to prevent memory exhaustion
-/// from extremely verbose scripts.
-const MAX_OUTPUT_BYTES: usize = 10 * 1024 * 1024;
-
 /// Spawn `cmd` as a child process, pipe JSON input to stdin, capture
 /// stdout/stderr, and enforce the configured timeout.
 ///
@@ -63,8 +58,11 @@ pub async fn run_command(
     let stdout_handle = child.stdout.take();
     let stderr_handle = child.stderr.take();
 
-    let stdout_task = tokio::spawn(async move { read_stream(stdout_handle).await });
-    let stderr_task = tokio::spawn(async move { read_stream(stderr_handle).await });
+    let max_output_bytes = input.max_output_bytes;
+    let stdout_task =
+        tokio::spawn(async move { read_stream(stdout_handle, max_output_bytes).await });
+    let stderr_task =
+        tokio::spawn(async move { read_stream(stderr_handle, max_output_bytes).await });
 
     // Wait for the child process with a timeout. If the timeout fires,
     // `child` is dropped with `kill_on_drop(true)`, killing the process.
@@ -73,16 +71,16 @@ pub async fn run_command(
     match wait_result {
         Ok(Ok(status)) => {
             let duration_ms = start.elapsed().as_millis() as u64;
-            let stdout_bytes = stdout_task.await.unwrap_or_default();
-            let stderr_bytes = stderr_task.await.unwrap_or_default();
-            let stdout = String::from_utf8_lossy(&stdout_bytes).into_owned();
-            let stderr = String::from_utf8_lossy(&stderr_bytes).into_owned();
+            let stdout = stdout_task.await.unwrap_or_default();
+            let stderr = stderr_task.await.unwrap_or_default();
             let exit_code = status.code().unwrap_or(-1);
-            let parsed_output = serde_json::from_str(stdout.trim()).ok();
+            let parsed_output = serde_json::from_str(stdout.text.trim()).ok();
 
             Ok(ScriptOutput {
-                stdout,
-                stderr,
+                stdout: stdout.text,
+                stdout_truncated: stdout.truncated,
+                stdout_original_len: stdout.original_len,
+                stderr: stderr.text,
                 exit_code,
                 duration_ms,
                 parsed_output,
@@ -99,14 +97,14 @@ pub async fn run_command(
     }
 }
 
-/// Read an entire output stream into a byte buffer, capped at [`MAX_OUTPUT_BYTES`].
-async fn read_stream<R: AsyncRead + Unpin>(handle: Option<R>) -> Vec<u8> {
-    let mut buf = Vec::new();
-    if let Some(mut h) = handle {
-        let _ = (&mut h)
-            .take(MAX_OUTPUT_BYTES as u64)
-            .read_to_end(&mut buf)
-            .await;
+/// Read an entire output stream, keeping at most `max` bytes.
+///
+/// Output exceeding the limit is truncated (with a marker, see
+/// [`capture_stream`]) to prevent memory exhaustion from extremely verbose
+/// scripts.
+async fn read_stream<R: AsyncRead + Unpin>(handle: Option<R>, max: usize) -> CapturedOutput {
+    match handle {
+        Some(mut h) => capture_stream(&mut h, max).await,
+        None => CapturedOutput::default(),
     }
-    buf
 }
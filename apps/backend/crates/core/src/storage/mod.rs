@@ -1,11 +1,13 @@
 //! Storage backend constants, validation, enums, and provider trait (PRD-48, PRD-122).
 //!
 //! Provides tier validation, backend config validation, type/status enums,
-//! a rough retrieval-time estimator, and the `StorageProvider` trait for
-//! pluggable storage backends (local filesystem, S3-compatible, etc.).
+//! a rough retrieval-time estimator, tiering policy resolution, and the
+//! `StorageProvider` trait for pluggable storage backends (local
+//! filesystem, S3-compatible, etc.).
 
 pub mod factory;
 pub mod local;
+pub mod policy;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
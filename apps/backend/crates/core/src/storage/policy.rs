@@ -0,0 +1,380 @@
+//! Effective storage policy resolution (PRD-48).
+//!
+//! Decides which backend an asset belongs on from the active tiering
+//! policies, so the stream and migration paths agree on where it lives.
+//! This is pure logic: the caller loads the policies and asset attributes.
+//! The same resolver backs policy simulation, which reports what a proposed
+//! policy would relocate before it is applied.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::reclamation::protection::{evaluate_condition, ProtectionCondition};
+use crate::types::DbId;
+
+/// What policy matching needs to know about an asset.
+#[derive(Debug, Clone, Default)]
+pub struct AssetAttributes {
+    pub entity_type: String,
+    pub project_id: Option<DbId>,
+    /// Tier of the backend currently holding the asset.
+    pub tier: String,
+    /// Whole days since the asset was stored.
+    pub age_days: i64,
+    /// Whole days since the asset was last accessed; `None` if never.
+    pub idle_days: Option<i64>,
+    /// Values for policy conditions, keyed by field name (e.g. `status`).
+    pub fields: HashMap<String, String>,
+}
+
+/// A tiering policy in the form needed for resolution.
+#[derive(Debug, Clone)]
+pub struct StoragePolicy {
+    pub id: DbId,
+    pub entity_type: String,
+    pub source_tier: String,
+    pub target_tier: String,
+    pub target_backend_id: DbId,
+    pub condition_field: Option<String>,
+    pub condition_operator: Option<String>,
+    pub condition_value: Option<String>,
+    pub age_threshold_days: Option<i32>,
+    pub access_threshold_days: Option<i32>,
+    /// Restricts the policy to one project; `None` applies studio-wide.
+    pub project_id: Option<DbId>,
+    pub is_active: bool,
+}
+
+/// Where an asset should live.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedPolicy {
+    pub backend_id: DbId,
+    pub tier: String,
+    /// The policy that placed the asset; `None` for the default backend.
+    pub policy_id: Option<DbId>,
+}
+
+impl StoragePolicy {
+    /// Whether this policy applies to `asset`.
+    ///
+    /// An asset already on the policy's target tier still matches, so an
+    /// asset the policy has moved keeps resolving to where it was moved.
+    /// When both thresholds are unset the policy matches regardless of age;
+    /// otherwise exceeding either one is enough.
+    pub fn matches(&self, asset: &AssetAttributes) -> bool {
+        if !self.is_active || self.entity_type != asset.entity_type {
+            return false;
+        }
+        if self.project_id.is_some() && self.project_id != asset.project_id {
+            return false;
+        }
+        if asset.tier != self.source_tier && asset.tier != self.target_tier {
+            return false;
+        }
+
+        let old_enough = self
+            .age_threshold_days
+            .is_some_and(|days| asset.age_days >= i64::from(days));
+        let idle_enough = self
+            .access_threshold_days
+            .is_some_and(|days| asset.idle_days.is_none_or(|idle| idle >= i64::from(days)));
+        let has_threshold =
+            self.age_threshold_days.is_some() || self.access_threshold_days.is_some();
+        if has_threshold && !old_enough && !idle_enough {
+            return false;
+        }
+
+        match (&self.condition_field, &self.condition_operator) {
+            (Some(field), Some(operator)) => {
+                let condition = ProtectionCondition {
+                    entity_type: self.entity_type.clone(),
+                    condition_field: field.clone(),
+                    condition_operator: operator.clone(),
+                    condition_value: self.condition_value.clone().unwrap_or_default(),
+                };
+                evaluate_condition(&condition, asset.fields.get(field).map(String::as_str))
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Resolve the backend for `asset`.
+///
+/// Project-scoped policies take precedence over studio-wide ones; within
+/// each scope the first matching policy in `policies` wins. An asset that
+/// matches no policy stays on `default`.
+pub fn resolve_storage_policy(
+    asset: &AssetAttributes,
+    policies: &[StoragePolicy],
+    default: &ResolvedPolicy,
+) -> ResolvedPolicy {
+    let project_scoped = policies.iter().filter(|p| p.project_id.is_some());
+    let studio_wide = policies.iter().filter(|p| p.project_id.is_none());
+    project_scoped
+        .chain(studio_wide)
+        .find(|policy| policy.matches(asset))
+        .map(|policy| ResolvedPolicy {
+            backend_id: policy.target_backend_id,
+            tier: policy.target_tier.clone(),
+            policy_id: Some(policy.id),
+        })
+        .unwrap_or_else(|| default.clone())
+}
+
+/// Simulated relocations from one backend to another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackendMoveSummary {
+    pub from_backend_id: DbId,
+    pub to_backend_id: DbId,
+    pub asset_count: usize,
+    pub total_bytes: i64,
+}
+
+/// Where `asset`, currently at `current`, would go if `proposed` were
+/// applied on its own. Returns `None` when the asset would stay put.
+pub fn simulate_move(
+    asset: &AssetAttributes,
+    current: &ResolvedPolicy,
+    proposed: &StoragePolicy,
+) -> Option<ResolvedPolicy> {
+    let resolved = resolve_storage_policy(asset, std::slice::from_ref(proposed), current);
+    (resolved.backend_id != current.backend_id).then_some(resolved)
+}
+
+/// Group `(from_backend_id, to_backend_id, bytes)` moves by backend pair,
+/// ordered by source then target backend.
+pub fn summarize_moves(
+    moves: impl IntoIterator<Item = (DbId, DbId, i64)>,
+) -> Vec<BackendMoveSummary> {
+    let mut by_pair: BTreeMap<(DbId, DbId), (usize, i64)> = BTreeMap::new();
+    for (from, to, bytes) in moves {
+        let entry = by_pair.entry((from, to)).or_default();
+        entry.0 += 1;
+        entry.1 += bytes;
+    }
+    by_pair
+        .into_iter()
+        .map(|((from, to), (count, bytes))| BackendMoveSummary {
+            from_backend_id: from,
+            to_backend_id: to,
+            asset_count: count,
+            total_bytes: bytes,
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{TIER_COLD, TIER_HOT};
+
+    const HOT_BACKEND: DbId = 1;
+
+    fn default_backend() -> ResolvedPolicy {
+        ResolvedPolicy {
+            backend_id: HOT_BACKEND,
+            tier: TIER_HOT.to_string(),
+            policy_id: None,
+        }
+    }
+
+    fn segment(project_id: DbId, age_days: i64) -> AssetAttributes {
+        AssetAttributes {
+            entity_type: "segment".to_string(),
+            project_id: Some(project_id),
+            tier: TIER_HOT.to_string(),
+            age_days,
+            idle_days: Some(0),
+            fields: HashMap::from([("status".to_string(), "approved".to_string())]),
+        }
+    }
+
+    /// A hot-to-cold policy for segments older than `age_days`.
+    fn to_cold(id: DbId, backend_id: DbId, age_days: i32) -> StoragePolicy {
+        StoragePolicy {
+            id,
+            entity_type: "segment".to_string(),
+            source_tier: TIER_HOT.to_string(),
+            target_tier: TIER_COLD.to_string(),
+            target_backend_id: backend_id,
+            condition_field: None,
+            condition_operator: None,
+            condition_value: None,
+            age_threshold_days: Some(age_days),
+            access_threshold_days: None,
+            project_id: None,
+            is_active: true,
+        }
+    }
+
+    // -- precedence -----------------------------------------------------------
+
+    #[test]
+    fn first_matching_policy_wins() {
+        let policies = [to_cold(10, 20, 30), to_cold(11, 21, 7)];
+        let resolved = resolve_storage_policy(&segment(5, 40), &policies, &default_backend());
+        assert_eq!(resolved.policy_id, Some(10));
+        assert_eq!(resolved.backend_id, 20);
+        assert_eq!(resolved.tier, TIER_COLD);
+
+        // Only the second policy's threshold is met.
+        let resolved = resolve_storage_policy(&segment(5, 10), &policies, &default_backend());
+        assert_eq!(resolved.policy_id, Some(11));
+    }
+
+    #[test]
+    fn project_policy_overrides_studio_policy() {
+        let studio = to_cold(10, 20, 7);
+        let project = StoragePolicy {
+            project_id: Some(5),
+            ..to_cold(11, 21, 7)
+        };
+        let policies = [studio, project];
+
+        let resolved = resolve_storage_policy(&segment(5, 10), &policies, &default_backend());
+        assert_eq!(resolved.policy_id, Some(11));
+
+        // Other projects only see the studio-wide policy.
+        let resolved = resolve_storage_policy(&segment(6, 10), &policies, &default_backend());
+        assert_eq!(resolved.policy_id, Some(10));
+    }
+
+    #[test]
+    fn moved_asset_keeps_resolving_to_target() {
+        let policies = [to_cold(10, 20, 30)];
+        let moved = AssetAttributes {
+            tier: TIER_COLD.to_string(),
+            ..segment(5, 40)
+        };
+        let resolved = resolve_storage_policy(&moved, &policies, &default_backend());
+        assert_eq!(resolved.backend_id, 20);
+    }
+
+    // -- default fallback -----------------------------------------------------
+
+    #[test]
+    fn no_policies_falls_back_to_default() {
+        let resolved = resolve_storage_policy(&segment(5, 400), &[], &default_backend());
+        assert_eq!(resolved, default_backend());
+    }
+
+    #[test]
+    fn asset_matching_no_policy_stays_on_default() {
+        let inactive = StoragePolicy {
+            is_active: false,
+            ..to_cold(10, 20, 0)
+        };
+        let other_entity = StoragePolicy {
+            entity_type: "image".to_string(),
+            ..to_cold(11, 21, 0)
+        };
+        let other_project = StoragePolicy {
+            project_id: Some(9),
+            ..to_cold(12, 22, 0)
+        };
+        let too_young = to_cold(13, 23, 30);
+        let unapproved_only = StoragePolicy {
+            condition_field: Some("status".to_string()),
+            condition_operator: Some("neq".to_string()),
+            condition_value: Some("approved".to_string()),
+            ..to_cold(14, 24, 0)
+        };
+        let policies = [
+            inactive,
+            other_entity,
+            other_project,
+            too_young,
+            unapproved_only,
+        ];
+
+        let resolved = resolve_storage_policy(&segment(5, 10), &policies, &default_backend());
+        assert_eq!(resolved, default_backend());
+    }
+
+    // -- thresholds and conditions --------------------------------------------
+
+    #[test]
+    fn idle_threshold_matches_never_accessed_assets() {
+        let idle = StoragePolicy {
+            age_threshold_days: None,
+            access_threshold_days: Some(14),
+            ..to_cold(10, 20, 0)
+        };
+        let mut asset = segment(5, 1);
+        assert!(!idle.matches(&asset));
+        asset.idle_days = None;
+        assert!(idle.matches(&asset));
+        asset.idle_days = Some(14);
+        assert!(idle.matches(&asset));
+    }
+
+    #[test]
+    fn condition_is_evaluated_against_asset_fields() {
+        let approved = StoragePolicy {
+            condition_field: Some("status".to_string()),
+            condition_operator: Some("eq".to_string()),
+            condition_value: Some("approved".to_string()),
+            ..to_cold(10, 20, 30)
+        };
+        assert!(approved.matches(&segment(5, 30)));
+        let mut draft = segment(5, 30);
+        draft
+            .fields
+            .insert("status".to_string(), "draft".to_string());
+        assert!(!approved.matches(&draft));
+    }
+
+    // -- simulation -----------------------------------------------------------
+
+    #[test]
+    fn simulate_move_reports_only_relocations() {
+        let proposed = to_cold(0, 20, 30);
+        let hot = default_backend();
+
+        let moved = simulate_move(&segment(5, 40), &hot, &proposed).unwrap();
+        assert_eq!(moved.backend_id, 20);
+        assert_eq!(moved.tier, TIER_COLD);
+        assert_eq!(simulate_move(&segment(5, 10), &hot, &proposed), None);
+
+        // Already on the target backend: nothing to do.
+        let on_target = ResolvedPolicy {
+            backend_id: 20,
+            tier: TIER_COLD.to_string(),
+            policy_id: None,
+        };
+        let asset = AssetAttributes {
+            tier: TIER_COLD.to_string(),
+            ..segment(5, 40)
+        };
+        assert_eq!(simulate_move(&asset, &on_target, &proposed), None);
+    }
+
+    #[test]
+    fn moves_are_summarized_per_backend_pair() {
+        let summary = summarize_moves([(2, 20, 300), (1, 20, 1000), (1, 20, 2500)]);
+        assert_eq!(
+            summary,
+            vec![
+                BackendMoveSummary {
+                    from_backend_id: 1,
+                    to_backend_id: 20,
+                    asset_count: 2,
+                    total_bytes: 3500,
+                },
+                BackendMoveSummary {
+                    from_backend_id: 2,
+                    to_backend_id: 20,
+                    asset_count: 1,
+                    total_bytes: 300,
+                },
+            ]
+        );
+        assert!(summarize_moves([]).is_empty());
+    }
+}
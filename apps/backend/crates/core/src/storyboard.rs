@@ -1,9 +1,11 @@
 //! Storyboard View & Scene Thumbnails constants and validation (PRD-62).
 //!
 //! Provides constants for keyframe extraction intervals, thumbnail sizes,
-//! limits, and validation functions used by the API and pipeline layers.
+//! limits, content-hash thumbnail cache keys, and validation functions used
+//! by the API and pipeline layers.
 
 use crate::error::CoreError;
+use crate::hashing::sha256_hex;
 
 // ---------------------------------------------------------------------------
 // Keyframe interval constants
@@ -22,6 +24,27 @@ pub const MIN_KEYFRAME_INTERVAL_SECS: f64 = 0.5;
 /// Default thumbnail height in pixels (width auto-scales to aspect ratio).
 pub const DEFAULT_THUMBNAIL_HEIGHT: i32 = 200;
 
+/// Storage key prefix for content-addressed keyframe thumbnails.
+pub const KEYFRAME_THUMBNAIL_PREFIX: &str = "thumbnails/keyframes";
+
+// ---------------------------------------------------------------------------
+// Content-hash cache keys
+// ---------------------------------------------------------------------------
+
+/// Hash the source frame's content (SHA-256, lowercase hex).
+pub fn keyframe_source_hash(frame: &[u8]) -> String {
+    sha256_hex(frame)
+}
+
+/// Storage key of the thumbnail derived from a source frame.
+///
+/// Keyed on the source content hash rather than the keyframe id, so
+/// regenerated content gets a new thumbnail while unchanged content
+/// reuses the existing one.
+pub fn thumbnail_cache_key(source_hash: &str, height: i32) -> String {
+    format!("{KEYFRAME_THUMBNAIL_PREFIX}/{source_hash}_h{height}.jpg")
+}
+
 // ---------------------------------------------------------------------------
 // Limits
 // ---------------------------------------------------------------------------
@@ -63,6 +86,16 @@ pub fn validate_frame_number(frame: i32) -> Result<(), CoreError> {
     Ok(())
 }
 
+/// Validate a client-supplied source hash: 64 hex characters.
+pub fn validate_source_hash(hash: &str) -> Result<(), CoreError> {
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(CoreError::Validation(
+            "source_hash must be a 64-character hex SHA-256 digest".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -134,4 +167,50 @@ mod tests {
     fn rejects_large_negative_frame_number() {
         assert!(validate_frame_number(-999).is_err());
     }
+
+    // -- thumbnail_cache_key -------------------------------------------------
+
+    #[test]
+    fn unchanged_content_reuses_cache_key() {
+        let a = keyframe_source_hash(b"frame pixels");
+        let b = keyframe_source_hash(b"frame pixels");
+        assert_eq!(
+            thumbnail_cache_key(&a, DEFAULT_THUMBNAIL_HEIGHT),
+            thumbnail_cache_key(&b, DEFAULT_THUMBNAIL_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn changed_content_yields_new_cache_key() {
+        let before = keyframe_source_hash(b"frame pixels v1");
+        let after = keyframe_source_hash(b"frame pixels v2");
+        assert_ne!(before, after);
+        assert_ne!(
+            thumbnail_cache_key(&before, DEFAULT_THUMBNAIL_HEIGHT),
+            thumbnail_cache_key(&after, DEFAULT_THUMBNAIL_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn cache_key_includes_height() {
+        let hash = keyframe_source_hash(b"frame");
+        assert_ne!(
+            thumbnail_cache_key(&hash, 200),
+            thumbnail_cache_key(&hash, 400)
+        );
+        assert!(thumbnail_cache_key(&hash, 200).starts_with(KEYFRAME_THUMBNAIL_PREFIX));
+    }
+
+    // -- validate_source_hash ------------------------------------------------
+
+    #[test]
+    fn accepts_sha256_hex_source_hash() {
+        assert!(validate_source_hash(&keyframe_source_hash(b"frame")).is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_source_hash() {
+        assert!(validate_source_hash("abc").is_err());
+        assert!(validate_source_hash(&"z".repeat(64)).is_err());
+    }
 }
@@ -0,0 +1,87 @@
+//! Tag bulk-operation limits and input normalization (PRD-47).
+//!
+//! Provides default element-count limits for the bulk apply/remove
+//! endpoints and helpers to dedupe and bound their input lists before any
+//! database work happens. No database dependencies.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Default maximum number of entity IDs in one bulk tag operation.
+pub const DEFAULT_BULK_MAX_ENTITIES: usize = 1000;
+
+/// Default maximum number of tags (names or IDs) in one bulk tag operation.
+pub const DEFAULT_BULK_MAX_TAGS: usize = 50;
+
+/// Default maximum request body size for one bulk tag operation (256 KiB).
+pub const DEFAULT_BULK_MAX_BODY_BYTES: usize = 256 * 1024;
+
+// ---------------------------------------------------------------------------
+// Input normalization
+// ---------------------------------------------------------------------------
+
+/// Remove duplicate entries, keeping the first occurrence of each.
+pub fn dedupe_preserving_order<T: Eq + Hash + Clone>(items: &[T]) -> Vec<T> {
+    let mut seen = HashSet::with_capacity(items.len());
+    items
+        .iter()
+        .filter(|item| seen.insert((*item).clone()))
+        .cloned()
+        .collect()
+}
+
+/// Validate that a bulk input list has at most `max` elements.
+pub fn validate_bulk_count(field: &str, count: usize, max: usize) -> Result<(), String> {
+    if count > max {
+        return Err(format!(
+            "{field} has {count} elements, exceeding the maximum of {max} per request"
+        ));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedupe_removes_duplicates_keeping_first_order() {
+        let ids = vec![3, 1, 3, 2, 1];
+        assert_eq!(dedupe_preserving_order(&ids), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn dedupe_strings() {
+        let names = vec!["hero".to_string(), "night".to_string(), "hero".to_string()];
+        assert_eq!(
+            dedupe_preserving_order(&names),
+            vec!["hero".to_string(), "night".to_string()]
+        );
+    }
+
+    #[test]
+    fn bulk_count_within_limit_ok() {
+        assert!(validate_bulk_count("entity_ids", 10, 10).is_ok());
+    }
+
+    #[test]
+    fn bulk_count_over_limit_rejected() {
+        let err = validate_bulk_count("entity_ids", 11, 10).unwrap_err();
+        assert!(err.contains("maximum of 10"));
+    }
+
+    #[test]
+    fn duplicates_do_not_count_toward_limit() {
+        let ids = vec![1, 1, 1, 2];
+        let deduped = dedupe_preserving_order(&ids);
+        assert!(validate_bulk_count("entity_ids", deduped.len(), 2).is_ok());
+    }
+}
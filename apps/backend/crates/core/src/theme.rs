@@ -0,0 +1,150 @@
+//! Effective theme token resolution (PRD-29).
+//!
+//! Theme tokens are a JSON object of sections (`surface`, `text`, `action`,
+//! ...) holding individual token values. An admin-managed studio theme
+//! supplies the base set; a user's own overrides are layered on top.
+//! [`resolve_effective_theme`] merges the two and reports which tokens the
+//! user changed.
+
+use serde::Serialize;
+
+/// The merged token set a user actually sees.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EffectiveTheme {
+    /// Studio tokens with the user's overrides applied.
+    pub tokens: serde_json::Value,
+    /// Dotted paths (e.g. `action.primary`) of tokens taken from the user's
+    /// overrides, in the order they were found.
+    pub overridden_tokens: Vec<String>,
+}
+
+/// Overlay `user_overrides` on `studio_tokens`.
+///
+/// Objects are merged key by key, recursively; any other user value replaces
+/// the studio value at that path, so a user can change one color without
+/// restating its section. Tokens the user does not mention fall back to the
+/// studio theme. Inputs that are not JSON objects are treated as empty.
+pub fn resolve_effective_theme(
+    studio_tokens: Option<&serde_json::Value>,
+    user_overrides: Option<&serde_json::Value>,
+) -> EffectiveTheme {
+    let mut tokens = serde_json::Value::Object(object_of(studio_tokens));
+    let mut overridden_tokens = Vec::new();
+    merge_into(
+        &mut tokens,
+        &object_of(user_overrides),
+        "",
+        &mut overridden_tokens,
+    );
+    EffectiveTheme {
+        tokens,
+        overridden_tokens,
+    }
+}
+
+/// The object behind a token set, or an empty one if absent or not an object.
+fn object_of(tokens: Option<&serde_json::Value>) -> serde_json::Map<String, serde_json::Value> {
+    tokens
+        .and_then(|t| t.as_object())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Merge `overrides` into `target`, recording each replaced leaf path.
+fn merge_into(
+    target: &mut serde_json::Value,
+    overrides: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    overridden: &mut Vec<String>,
+) {
+    let Some(target) = target.as_object_mut() else {
+        return;
+    };
+    for (key, value) in overrides {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (target.get_mut(key), value.as_object()) {
+            (Some(existing), Some(nested)) if existing.is_object() => {
+                merge_into(existing, nested, &path, overridden);
+            }
+            (_, Some(nested)) => {
+                let mut section = serde_json::Value::Object(serde_json::Map::new());
+                merge_into(&mut section, nested, &path, overridden);
+                target.insert(key.clone(), section);
+            }
+            (_, None) => {
+                target.insert(key.clone(), value.clone());
+                overridden.push(path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn studio() -> serde_json::Value {
+        json!({
+            "action": { "primary": "#3366ff", "danger": "#cc0000" },
+            "surface": { "base": "#101014" },
+            "font": { "family": "Inter" },
+        })
+    }
+
+    #[test]
+    fn user_color_override_wins_and_others_fall_back() {
+        let user = json!({ "action": { "primary": "#ff8800" } });
+
+        let effective = resolve_effective_theme(Some(&studio()), Some(&user));
+
+        assert_eq!(
+            effective.tokens,
+            json!({
+                "action": { "primary": "#ff8800", "danger": "#cc0000" },
+                "surface": { "base": "#101014" },
+                "font": { "family": "Inter" },
+            })
+        );
+        assert_eq!(effective.overridden_tokens, vec!["action.primary"]);
+    }
+
+    #[test]
+    fn without_overrides_studio_tokens_are_used_as_is() {
+        let effective = resolve_effective_theme(Some(&studio()), None);
+
+        assert_eq!(effective.tokens, studio());
+        assert!(effective.overridden_tokens.is_empty());
+    }
+
+    #[test]
+    fn user_can_add_tokens_the_studio_theme_lacks() {
+        let user = json!({ "border": { "radius": "4px" }, "surface": { "raised": "#1c1c22" } });
+
+        let effective = resolve_effective_theme(Some(&studio()), Some(&user));
+
+        assert_eq!(effective.tokens["border"], json!({ "radius": "4px" }));
+        assert_eq!(effective.tokens["surface"]["base"], "#101014");
+        assert_eq!(effective.tokens["surface"]["raised"], "#1c1c22");
+        assert_eq!(
+            effective.overridden_tokens,
+            vec!["border.radius", "surface.raised"]
+        );
+    }
+
+    #[test]
+    fn non_object_inputs_are_treated_as_empty() {
+        let effective = resolve_effective_theme(Some(&json!("broken")), Some(&json!(null)));
+        assert_eq!(effective.tokens, json!({}));
+
+        let user = json!({ "text": { "muted": "#888" } });
+        let effective = resolve_effective_theme(None, Some(&user));
+        assert_eq!(effective.tokens, user);
+        assert_eq!(effective.overridden_tokens, vec!["text.muted"]);
+    }
+}
@@ -1,7 +1,10 @@
 //! Segment Trimming & Frame-Level Editing constants and validation (PRD-78).
 //!
 //! Provides constants for trim bounds, preset definitions, and validation
-//! functions used by the API layer for in/out point trimming of segments.
+//! functions used by the API layer for in/out point trimming of segments,
+//! plus the seed-impact check run before a trim is applied.
+
+use serde::Serialize;
 
 use crate::error::CoreError;
 use crate::threshold_validation::validate_count_range;
@@ -22,6 +25,12 @@ pub const DEFAULT_TRIM_PRESET: i32 = 5;
 /// Maximum number of segments in a single batch trim request.
 pub const MAX_BATCH_TRIM_SIZE: usize = 100;
 
+/// Segments trimmed at once by a batch trim when no concurrency is given.
+pub const DEFAULT_BATCH_TRIM_CONCURRENCY: usize = 4;
+
+/// Upper bound on the concurrency a batch trim may request.
+pub const MAX_BATCH_TRIM_CONCURRENCY: usize = 16;
+
 // ---------------------------------------------------------------------------
 // Trim preset
 // ---------------------------------------------------------------------------
@@ -123,6 +132,16 @@ pub fn validate_batch_trim_size(count: usize) -> Result<(), CoreError> {
     validate_count_range(count, MAX_BATCH_TRIM_SIZE, "Batch trim")
 }
 
+/// Validate that a batch trim's concurrency is within allowed bounds.
+pub fn validate_batch_trim_concurrency(concurrency: usize) -> Result<(), CoreError> {
+    if !(1..=MAX_BATCH_TRIM_CONCURRENCY).contains(&concurrency) {
+        return Err(CoreError::Validation(format!(
+            "Concurrency must be between 1 and {MAX_BATCH_TRIM_CONCURRENCY}, got {concurrency}"
+        )));
+    }
+    Ok(())
+}
+
 /// Validate that a preset frame value is positive.
 pub fn validate_preset_value(frames: i32) -> Result<(), CoreError> {
     if frames <= 0 {
@@ -146,6 +165,127 @@ pub fn seed_frame_after_trim(out_frame: i32) -> i32 {
     out_frame - 1
 }
 
+// ---------------------------------------------------------------------------
+// Seed impact
+// ---------------------------------------------------------------------------
+
+/// Frame layout of a segment relevant to seed continuity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentFrames {
+    /// Frame count of the untrimmed segment.
+    pub total_frames: i32,
+    /// Frame the next segment was seeded from, if a boundary was chosen.
+    /// Defaults to the last frame.
+    pub seed_anchor_frame: Option<i32>,
+    /// Whether the next segment has already been generated from the anchor.
+    pub downstream_generated: bool,
+}
+
+/// Frame range kept by a trim: `[in_frame, out_frame)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrimRange {
+    pub in_frame: i32,
+    pub out_frame: i32,
+}
+
+/// Effect of a trim on the seed frame handed to the next segment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeedImpactReport {
+    /// Frame the next segment currently depends on.
+    pub seed_anchor_frame: i32,
+    /// Frame the next segment would be seeded from after the trim.
+    pub new_seed_frame: i32,
+    /// Whether the trim keeps the anchor frame.
+    pub anchor_retained: bool,
+    /// Distance in frames between the anchor and the new seed frame.
+    pub displaced_frames: i32,
+    /// Whether the next segment has already been generated.
+    pub downstream_generated: bool,
+    /// Whether applying the trim invalidates the generated next segment.
+    pub regeneration_required: bool,
+}
+
+/// Describe how trimming `frames` to `trim` affects the downstream seed.
+///
+/// A trim that keeps the anchor frame leaves the next segment's seed
+/// unchanged. A trim that cuts it moves the seed to the new last frame, so a
+/// next segment generated from the old anchor would have to be regenerated.
+pub fn compute_seed_impact(frames: SegmentFrames, trim: TrimRange) -> SeedImpactReport {
+    let seed_anchor_frame = frames.seed_anchor_frame.unwrap_or(frames.total_frames - 1);
+    let anchor_retained = (trim.in_frame..trim.out_frame).contains(&seed_anchor_frame);
+    let new_seed_frame = if anchor_retained {
+        seed_anchor_frame
+    } else {
+        seed_frame_after_trim(trim.out_frame)
+    };
+
+    SeedImpactReport {
+        seed_anchor_frame,
+        new_seed_frame,
+        anchor_retained,
+        displaced_frames: (seed_anchor_frame - new_seed_frame).abs(),
+        downstream_generated: frames.downstream_generated,
+        regeneration_required: !anchor_retained && frames.downstream_generated,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Batch trim outcomes
+// ---------------------------------------------------------------------------
+
+/// Outcome of trimming one segment within a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchTrimStatus {
+    /// The trim was applied.
+    Trimmed,
+    /// The trim was not applied because of its seed impact.
+    Skipped,
+    /// The trim could not be applied (missing segment, storage error, ...).
+    Failed,
+}
+
+/// Counts of each outcome across a batch trim.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct BatchTrimSummary {
+    pub total: usize,
+    pub trimmed: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+impl BatchTrimSummary {
+    /// Tally the given per-segment outcomes.
+    pub fn from_statuses(statuses: impl IntoIterator<Item = BatchTrimStatus>) -> Self {
+        statuses
+            .into_iter()
+            .fold(Self::default(), |mut summary, status| {
+                summary.total += 1;
+                match status {
+                    BatchTrimStatus::Trimmed => summary.trimmed += 1,
+                    BatchTrimStatus::Skipped => summary.skipped += 1,
+                    BatchTrimStatus::Failed => summary.failed += 1,
+                }
+                summary
+            })
+    }
+}
+
+/// Reason a batch should leave a segment untrimmed, if any.
+///
+/// A batch applies one trim to many segments without a per-segment review,
+/// so a trim that would invalidate an already generated next segment is
+/// reported instead of applied; it can still be applied individually.
+pub fn batch_trim_skip_reason(impact: &SeedImpactReport) -> Option<String> {
+    impact.regeneration_required.then(|| {
+        format!(
+            "Trim cuts seed anchor frame {} used by the generated next segment \
+             (new seed frame would be {})",
+            impact.seed_anchor_frame, impact.new_seed_frame
+        )
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -321,4 +461,137 @@ mod tests {
     fn preset_parse_invalid_number() {
         assert!(TrimPreset::parse("first_abc").is_err());
     }
+
+    // -- compute_seed_impact -------------------------------------------------
+
+    fn frames(anchor: Option<i32>, downstream_generated: bool) -> SegmentFrames {
+        SegmentFrames {
+            total_frames: 100,
+            seed_anchor_frame: anchor,
+            downstream_generated,
+        }
+    }
+
+    #[test]
+    fn seed_impact_trim_crossing_anchor_requires_regeneration() {
+        let report = compute_seed_impact(
+            frames(None, true),
+            TrimRange {
+                in_frame: 0,
+                out_frame: 90,
+            },
+        );
+        assert_eq!(report.seed_anchor_frame, 99);
+        assert_eq!(report.new_seed_frame, 89);
+        assert!(!report.anchor_retained);
+        assert_eq!(report.displaced_frames, 10);
+        assert!(report.regeneration_required);
+    }
+
+    #[test]
+    fn seed_impact_head_trim_is_safe() {
+        let report = compute_seed_impact(
+            frames(None, true),
+            TrimRange {
+                in_frame: 10,
+                out_frame: 100,
+            },
+        );
+        assert!(report.anchor_retained);
+        assert_eq!(report.new_seed_frame, 99);
+        assert_eq!(report.displaced_frames, 0);
+        assert!(!report.regeneration_required);
+    }
+
+    #[test]
+    fn seed_impact_tail_trim_after_chosen_boundary_is_safe() {
+        let report = compute_seed_impact(
+            frames(Some(80), true),
+            TrimRange {
+                in_frame: 0,
+                out_frame: 85,
+            },
+        );
+        assert!(report.anchor_retained);
+        assert_eq!(report.new_seed_frame, 80);
+        assert!(!report.regeneration_required);
+    }
+
+    #[test]
+    fn seed_impact_head_trim_past_anchor_loses_it() {
+        let report = compute_seed_impact(
+            frames(Some(20), true),
+            TrimRange {
+                in_frame: 30,
+                out_frame: 100,
+            },
+        );
+        assert!(!report.anchor_retained);
+        assert_eq!(report.new_seed_frame, 99);
+        assert!(report.regeneration_required);
+    }
+
+    #[test]
+    fn seed_impact_without_downstream_is_safe() {
+        let report = compute_seed_impact(
+            frames(None, false),
+            TrimRange {
+                in_frame: 0,
+                out_frame: 50,
+            },
+        );
+        assert!(!report.anchor_retained);
+        assert!(!report.regeneration_required);
+    }
+
+    // -- batch trim outcomes -------------------------------------------------
+
+    #[test]
+    fn batch_trim_concurrency_bounds() {
+        assert!(validate_batch_trim_concurrency(1).is_ok());
+        assert!(validate_batch_trim_concurrency(MAX_BATCH_TRIM_CONCURRENCY).is_ok());
+        assert!(validate_batch_trim_concurrency(0).is_err());
+        assert!(validate_batch_trim_concurrency(MAX_BATCH_TRIM_CONCURRENCY + 1).is_err());
+    }
+
+    #[test]
+    fn batch_skips_trim_that_invalidates_downstream() {
+        let crossing = compute_seed_impact(
+            frames(Some(95), true),
+            TrimRange {
+                in_frame: 0,
+                out_frame: 80,
+            },
+        );
+        let reason = batch_trim_skip_reason(&crossing).unwrap();
+        assert!(reason.contains("seed anchor frame 95"), "{reason}");
+
+        let not_generated = compute_seed_impact(
+            frames(Some(95), false),
+            TrimRange {
+                in_frame: 0,
+                out_frame: 80,
+            },
+        );
+        assert_eq!(batch_trim_skip_reason(&not_generated), None);
+    }
+
+    #[test]
+    fn batch_summary_tallies_outcomes() {
+        let summary = BatchTrimSummary::from_statuses([
+            BatchTrimStatus::Trimmed,
+            BatchTrimStatus::Skipped,
+            BatchTrimStatus::Trimmed,
+            BatchTrimStatus::Failed,
+        ]);
+        assert_eq!(
+            summary,
+            BatchTrimSummary {
+                total: 4,
+                trimmed: 2,
+                skipped: 1,
+                failed: 1,
+            }
+        );
+    }
 }
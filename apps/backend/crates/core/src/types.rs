@@ -1,5 +1,36 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
 /// All database primary keys are PostgreSQL BIGSERIAL.
 pub type DbId = i64;
 
 /// All timestamps are UTC.
 pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// Identifier of an in-memory background run (embedding batches, scene
+/// re-stitches, worker repairs). Runs are never persisted, so they have no
+/// [`DbId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RunId(Uuid);
+
+impl RunId {
+    /// Generate a fresh random run ID.
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for RunId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for RunId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
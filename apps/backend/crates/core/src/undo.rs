@@ -8,6 +8,12 @@ pub const MAX_TREE_DEPTH: usize = 500;
 /// Maximum number of branches (children) any single node may have.
 pub const MAX_BRANCHES_PER_NODE: usize = 50;
 
+/// Default page size when listing a user's undo trees.
+pub const DEFAULT_UNDO_TREE_LIST_LIMIT: i64 = 50;
+
+/// Default upper bound on the page size when listing a user's undo trees.
+pub const DEFAULT_MAX_UNDO_TREE_LIST_LIMIT: i64 = 200;
+
 /// Entity types that support undo/redo trees.
 pub const VALID_ENTITY_TYPES: &[&str] = &["avatar", "scene", "segment", "project"];
 
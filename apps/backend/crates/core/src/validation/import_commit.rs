@@ -0,0 +1,70 @@
+//! Chunked import commit.
+//!
+//! A commit walks a report's pending entries in `record_index` order and
+//! applies them in fixed-size chunks, each in its own transaction. A failure
+//! therefore leaves every earlier chunk committed and the rest pending, and
+//! re-running the commit resumes from the first pending entry.
+
+use super::import_preview::ImportAction;
+use crate::error::CoreError;
+use crate::legacy_import::EntityAction;
+
+/// Entries applied per transaction when the caller does not choose.
+pub const DEFAULT_COMMIT_BATCH_SIZE: i64 = 500;
+
+/// Upper bound on entries applied per transaction.
+pub const MAX_COMMIT_BATCH_SIZE: i64 = 5_000;
+
+/// Validate a requested chunk size, falling back to the default.
+pub fn resolve_commit_batch_size(requested: Option<i64>) -> Result<i64, CoreError> {
+    match requested {
+        None => Ok(DEFAULT_COMMIT_BATCH_SIZE),
+        Some(n) if (1..=MAX_COMMIT_BATCH_SIZE).contains(&n) => Ok(n),
+        Some(n) => Err(CoreError::Validation(format!(
+            "batch_size must be between 1 and {MAX_COMMIT_BATCH_SIZE}, got {n}"
+        ))),
+    }
+}
+
+/// Outcome recorded for an entry when its chunk is committed.
+///
+/// `action` is the previewed [`ImportAction`] stored on the entry. Rejected
+/// records, and any action this build does not recognise, are recorded as
+/// [`EntityAction::Failed`] rather than applied.
+pub fn commit_outcome(action: &str) -> EntityAction {
+    match ImportAction::parse(action) {
+        Some(ImportAction::Create) => EntityAction::Created,
+        Some(ImportAction::Update) => EntityAction::Updated,
+        Some(ImportAction::Skip) => EntityAction::Skipped,
+        Some(ImportAction::Reject) | None => EntityAction::Failed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_size_defaults_and_is_bounded() {
+        assert_eq!(
+            resolve_commit_batch_size(None).unwrap(),
+            DEFAULT_COMMIT_BATCH_SIZE
+        );
+        assert_eq!(resolve_commit_batch_size(Some(1)).unwrap(), 1);
+        assert_eq!(
+            resolve_commit_batch_size(Some(MAX_COMMIT_BATCH_SIZE)).unwrap(),
+            MAX_COMMIT_BATCH_SIZE
+        );
+        assert!(resolve_commit_batch_size(Some(0)).is_err());
+        assert!(resolve_commit_batch_size(Some(MAX_COMMIT_BATCH_SIZE + 1)).is_err());
+    }
+
+    #[test]
+    fn outcomes_follow_previewed_action() {
+        assert_eq!(commit_outcome("create"), EntityAction::Created);
+        assert_eq!(commit_outcome("update"), EntityAction::Updated);
+        assert_eq!(commit_outcome("skip"), EntityAction::Skipped);
+        assert_eq!(commit_outcome("reject"), EntityAction::Failed);
+        assert_eq!(commit_outcome("merge"), EntityAction::Failed);
+    }
+}
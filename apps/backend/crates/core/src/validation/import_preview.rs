@@ -26,6 +26,17 @@ impl ImportAction {
             Self::Reject => "reject",
         }
     }
+
+    /// Parse the string form produced by [`ImportAction::as_str`].
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "create" => Some(Self::Create),
+            "update" => Some(Self::Update),
+            "skip" => Some(Self::Skip),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
 }
 
 /// Field-level diff between current and incoming value.
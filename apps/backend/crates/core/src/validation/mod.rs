@@ -5,5 +5,6 @@
 
 pub mod conflict;
 pub mod evaluator;
+pub mod import_commit;
 pub mod import_preview;
 pub mod rules;
@@ -1,9 +1,14 @@
-//! Wiki article validation, slug generation, and line-diff utilities (PRD-56).
+//! Wiki article validation, slug generation, line- and word-diff utilities,
+//! and search index scheduling (PRD-56).
 //!
 //! This module lives in `core` (zero internal deps) so it can be used by both
 //! the API/repository layer and any future CLI or worker tooling.
 
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
 use crate::error::CoreError;
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // Category constants
@@ -226,6 +231,281 @@ pub fn compute_line_diff(old: &str, new: &str) -> Vec<DiffLine> {
     result
 }
 
+// ---------------------------------------------------------------------------
+// Word-level version diff
+// ---------------------------------------------------------------------------
+
+/// Upper bound on the LCS table (changed old tokens × changed new tokens)
+/// for a word diff. Larger changed regions are reported as one removal
+/// followed by one addition.
+pub const MAX_WORD_DIFF_CELLS: usize = 4_000_000;
+
+/// A run of consecutive words (and the whitespace between them) sharing
+/// one diff type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffSpan {
+    pub span_type: DiffLineType,
+    pub text: String,
+}
+
+/// A title change between two article versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleChange {
+    pub from: String,
+    pub to: String,
+}
+
+/// The fields of one article version compared by [`compute_version_diff`].
+#[derive(Debug, Clone, Copy)]
+pub struct VersionSnapshot<'a> {
+    pub version: i32,
+    /// `None` for versions recorded before titles were snapshotted.
+    pub title: Option<&'a str>,
+    pub content_md: &'a str,
+    pub edited_by: Option<DbId>,
+    pub edit_summary: Option<&'a str>,
+}
+
+/// Word-level diff between two article versions, with edit metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDiff {
+    pub from_version: i32,
+    pub to_version: i32,
+    /// Set when both versions recorded a title and the titles differ.
+    pub title_change: Option<TitleChange>,
+    /// Editor of the later version.
+    pub author: Option<DbId>,
+    /// Edit summary of the later version.
+    pub edit_summary: Option<String>,
+    pub words_added: usize,
+    pub words_removed: usize,
+    pub spans: Vec<DiffSpan>,
+}
+
+/// Compare two article versions word by word.
+///
+/// `from` is treated as the older version; the author and edit summary are
+/// taken from `to`.
+pub fn compute_version_diff(from: &VersionSnapshot<'_>, to: &VersionSnapshot<'_>) -> VersionDiff {
+    let title_change = match (from.title, to.title) {
+        (Some(old), Some(new)) if old != new => Some(TitleChange {
+            from: old.to_string(),
+            to: new.to_string(),
+        }),
+        _ => None,
+    };
+
+    let spans = compute_word_diff(from.content_md, to.content_md);
+    let count_words = |span_type: DiffLineType| -> usize {
+        spans
+            .iter()
+            .filter(|span| span.span_type == span_type)
+            .map(|span| span.text.split_whitespace().count())
+            .sum()
+    };
+
+    VersionDiff {
+        from_version: from.version,
+        to_version: to.version,
+        title_change,
+        author: to.edited_by,
+        edit_summary: to.edit_summary.map(str::to_string),
+        words_added: count_words(DiffLineType::Added),
+        words_removed: count_words(DiffLineType::Removed),
+        spans,
+    }
+}
+
+/// Compute a word-level diff between two texts using LCS.
+///
+/// Whitespace is kept in the output so concatenating the unchanged and
+/// added spans reproduces `new`, and the unchanged and removed spans
+/// reproduce `old`. Adjacent tokens of the same type are merged into one
+/// [`DiffSpan`].
+pub fn compute_word_diff(old: &str, new: &str) -> Vec<DiffSpan> {
+    let old_tokens = tokenize_words(old);
+    let new_tokens = tokenize_words(new);
+
+    // Only the region between the common prefix and suffix needs an LCS.
+    let prefix = old_tokens
+        .iter()
+        .zip(&new_tokens)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_tokens[prefix..]
+        .iter()
+        .rev()
+        .zip(new_tokens[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old_tokens[prefix..old_tokens.len() - suffix];
+    let new_mid = &new_tokens[prefix..new_tokens.len() - suffix];
+
+    let mut tokens: Vec<(DiffLineType, &str)> = old_tokens[..prefix]
+        .iter()
+        .map(|t| (DiffLineType::Unchanged, *t))
+        .collect();
+    tokens.extend(diff_tokens(old_mid, new_mid));
+    tokens.extend(
+        old_tokens[old_tokens.len() - suffix..]
+            .iter()
+            .map(|t| (DiffLineType::Unchanged, *t)),
+    );
+
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    for (span_type, text) in tokens {
+        match spans.last_mut() {
+            Some(last) if last.span_type == span_type => last.text.push_str(text),
+            _ => spans.push(DiffSpan {
+                span_type,
+                text: text.to_string(),
+            }),
+        }
+    }
+    spans
+}
+
+/// Split text into alternating runs of whitespace and non-whitespace.
+fn tokenize_words(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut prev_is_space: Option<bool> = None;
+    for (idx, ch) in text.char_indices() {
+        let is_space = ch.is_whitespace();
+        if prev_is_space.is_some_and(|prev| prev != is_space) {
+            tokens.push(&text[start..idx]);
+            start = idx;
+        }
+        prev_is_space = Some(is_space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+/// LCS diff of two token slices, in order.
+fn diff_tokens<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(DiffLineType, &'a str)> {
+    let m = old.len();
+    let n = new.len();
+
+    if (m + 1).saturating_mul(n + 1) > MAX_WORD_DIFF_CELLS {
+        return old
+            .iter()
+            .map(|t| (DiffLineType::Removed, *t))
+            .chain(new.iter().map(|t| (DiffLineType::Added, *t)))
+            .collect();
+    }
+
+    let mut lcs = vec![vec![0u32; n + 1]; m + 1];
+    for i in 1..=m {
+        for j in 1..=n {
+            if old[i - 1] == new[j - 1] {
+                lcs[i][j] = lcs[i - 1][j - 1] + 1;
+            } else {
+                lcs[i][j] = lcs[i - 1][j].max(lcs[i][j - 1]);
+            }
+        }
+    }
+
+    let mut result = Vec::with_capacity(m + n);
+    let mut i = m;
+    let mut j = n;
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && old[i - 1] == new[j - 1] {
+            result.push((DiffLineType::Unchanged, old[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if j > 0 && (i == 0 || lcs[i][j - 1] >= lcs[i - 1][j]) {
+            result.push((DiffLineType::Added, new[j - 1]));
+            j -= 1;
+        } else {
+            result.push((DiffLineType::Removed, old[i - 1]));
+            i -= 1;
+        }
+    }
+
+    result.reverse();
+    result
+}
+
+// ---------------------------------------------------------------------------
+// Search index
+// ---------------------------------------------------------------------------
+
+/// Event published when a wiki article is created.
+pub const EVENT_ARTICLE_CREATED: &str = "wiki.article.created";
+/// Event published when a wiki article's fields or content change.
+pub const EVENT_ARTICLE_UPDATED: &str = "wiki.article.updated";
+/// Event published when a wiki article is deleted.
+pub const EVENT_ARTICLE_DELETED: &str = "wiki.article.deleted";
+
+/// Articles listed per chunk during a full reindex. Progress is recorded
+/// and cancellation checked between chunks.
+pub const REINDEX_CHUNK_SIZE: i64 = 100;
+
+/// Default number of articles indexed in parallel within a chunk.
+pub const DEFAULT_REINDEX_CONCURRENCY: usize = 4;
+
+/// Default quiet period after an article change before it is reindexed.
+pub const DEFAULT_INDEX_DEBOUNCE_MS: u64 = 2_000;
+
+/// Collects article changes and releases each article for reindexing once
+/// it has gone `window` without another change.
+///
+/// Rapid edits to one article therefore cause a single reindex.
+#[derive(Debug, Clone)]
+pub struct ReindexDebouncer {
+    window: Duration,
+    pending: HashMap<DbId, Instant>,
+}
+
+impl ReindexDebouncer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Record a change to `article_id` at `now`, restarting its quiet period.
+    pub fn touch(&mut self, article_id: DbId, now: Instant) {
+        self.pending.insert(article_id, now + self.window);
+    }
+
+    /// Remove and return the articles whose quiet period has ended, in ID
+    /// order.
+    pub fn take_due(&mut self, now: Instant) -> Vec<DbId> {
+        let mut due: Vec<DbId> = self
+            .pending
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        due.sort_unstable();
+        for id in &due {
+            self.pending.remove(id);
+        }
+        due
+    }
+
+    /// Earliest time an article becomes due, if any are pending.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().min().copied()
+    }
+
+    /// Remove and return every pending article regardless of its deadline.
+    pub fn drain(&mut self) -> Vec<DbId> {
+        let mut ids: Vec<DbId> = self.pending.drain().map(|(id, _)| id).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -377,4 +657,176 @@ mod tests {
         assert!(types.contains(&&DiffLineType::Removed));
         assert!(types.contains(&&DiffLineType::Added));
     }
+
+    // -- compute_version_diff ------------------------------------------------
+
+    fn snapshot<'a>(version: i32, title: &'a str, content_md: &'a str) -> VersionSnapshot<'a> {
+        VersionSnapshot {
+            version,
+            title: Some(title),
+            content_md,
+            edited_by: Some(version as DbId * 10),
+            edit_summary: None,
+        }
+    }
+
+    fn span(span_type: DiffLineType, text: &str) -> DiffSpan {
+        DiffSpan {
+            span_type,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn word_diff_inserted_paragraph() {
+        let from = snapshot(1, "Guide", "Intro text.\n\nClosing text.");
+        let to = snapshot(
+            2,
+            "Guide",
+            "Intro text.\n\nNew middle paragraph.\n\nClosing text.",
+        );
+        let diff = compute_version_diff(&from, &to);
+
+        assert_eq!(
+            diff.spans,
+            vec![
+                span(DiffLineType::Unchanged, "Intro text.\n\n"),
+                span(DiffLineType::Added, "New middle paragraph.\n\n"),
+                span(DiffLineType::Unchanged, "Closing text."),
+            ]
+        );
+        assert_eq!(diff.words_added, 3);
+        assert_eq!(diff.words_removed, 0);
+        assert_eq!(diff.title_change, None);
+        assert_eq!((diff.from_version, diff.to_version), (1, 2));
+        assert_eq!(diff.author, Some(20));
+    }
+
+    #[test]
+    fn word_diff_deleted_sentence() {
+        let from = snapshot(
+            1,
+            "Guide",
+            "First sentence. Second sentence. Third sentence.",
+        );
+        let to = snapshot(2, "Guide", "First sentence. Third sentence.");
+        let diff = compute_version_diff(&from, &to);
+
+        assert_eq!(
+            diff.spans,
+            vec![
+                span(DiffLineType::Unchanged, "First sentence. "),
+                span(DiffLineType::Removed, "Second sentence. "),
+                span(DiffLineType::Unchanged, "Third sentence."),
+            ]
+        );
+        assert_eq!(diff.words_added, 0);
+        assert_eq!(diff.words_removed, 2);
+    }
+
+    #[test]
+    fn word_diff_title_only_change() {
+        let from = snapshot(1, "Old Title", "Same body.");
+        let mut to = snapshot(2, "New Title", "Same body.");
+        to.edit_summary = Some("Rename");
+        let diff = compute_version_diff(&from, &to);
+
+        assert_eq!(
+            diff.title_change,
+            Some(TitleChange {
+                from: "Old Title".to_string(),
+                to: "New Title".to_string(),
+            })
+        );
+        assert_eq!(
+            diff.spans,
+            vec![span(DiffLineType::Unchanged, "Same body.")]
+        );
+        assert_eq!((diff.words_added, diff.words_removed), (0, 0));
+        assert_eq!(diff.edit_summary.as_deref(), Some("Rename"));
+    }
+
+    #[test]
+    fn word_diff_unknown_title_is_not_a_change() {
+        let mut from = snapshot(1, "Guide", "Body.");
+        from.title = None;
+        let to = snapshot(2, "Renamed Guide", "Body.");
+        assert_eq!(compute_version_diff(&from, &to).title_change, None);
+    }
+
+    #[test]
+    fn word_diff_replaced_word_reconstructs_both_texts() {
+        let old = "the big cat sat";
+        let new = "the small cat sat down";
+        let spans = compute_word_diff(old, new);
+
+        let rebuild = |skip: DiffLineType| -> String {
+            spans
+                .iter()
+                .filter(|s| s.span_type != skip)
+                .map(|s| s.text.as_str())
+                .collect()
+        };
+        assert_eq!(rebuild(DiffLineType::Added), old);
+        assert_eq!(rebuild(DiffLineType::Removed), new);
+        assert!(spans.contains(&span(DiffLineType::Removed, "big")));
+        assert!(spans.contains(&span(DiffLineType::Added, "small")));
+    }
+
+    // -- ReindexDebouncer ----------------------------------------------------
+
+    #[test]
+    fn debouncer_releases_article_after_quiet_period() {
+        let start = Instant::now();
+        let mut debouncer = ReindexDebouncer::new(Duration::from_secs(2));
+        debouncer.touch(7, start);
+
+        assert!(debouncer
+            .take_due(start + Duration::from_secs(1))
+            .is_empty());
+        assert_eq!(debouncer.take_due(start + Duration::from_secs(2)), vec![7]);
+        assert!(debouncer.is_empty());
+    }
+
+    #[test]
+    fn debouncer_coalesces_rapid_edits() {
+        let start = Instant::now();
+        let mut debouncer = ReindexDebouncer::new(Duration::from_secs(2));
+        debouncer.touch(7, start);
+        debouncer.touch(7, start + Duration::from_secs(1));
+        debouncer.touch(7, start + Duration::from_millis(2_500));
+
+        // Each edit restarts the quiet period, so the first deadline passes
+        // without a reindex.
+        assert!(debouncer
+            .take_due(start + Duration::from_secs(3))
+            .is_empty());
+        assert_eq!(
+            debouncer.next_deadline(),
+            Some(start + Duration::from_millis(4_500))
+        );
+        assert_eq!(
+            debouncer.take_due(start + Duration::from_millis(4_500)),
+            vec![7]
+        );
+        assert!(debouncer
+            .take_due(start + Duration::from_secs(10))
+            .is_empty());
+    }
+
+    #[test]
+    fn debouncer_tracks_articles_independently() {
+        let start = Instant::now();
+        let mut debouncer = ReindexDebouncer::new(Duration::from_secs(2));
+        debouncer.touch(9, start);
+        debouncer.touch(3, start + Duration::from_secs(1));
+        debouncer.touch(5, start + Duration::from_secs(5));
+
+        assert_eq!(
+            debouncer.take_due(start + Duration::from_secs(3)),
+            vec![3, 9]
+        );
+        assert_eq!(debouncer.drain(), vec![5]);
+        assert_eq!(debouncer.next_deadline(), None);
+    }
 }
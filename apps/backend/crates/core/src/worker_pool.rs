@@ -1,9 +1,15 @@
-//! Worker pool management constants, scoring, and validation (PRD-46).
+//! Worker pool management constants, scoring, validation, and fleet
+//! statistics (PRD-46).
 //!
 //! Pure functions and constants used by both the API and (future) worker agent.
 //! Lives in `core` to maintain zero internal dependency constraint.
 
+use std::collections::HashSet;
+
+use serde::Serialize;
+
 use crate::error::CoreError;
+use crate::types::DbId;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -28,6 +34,12 @@ pub const LOAD_WEIGHT_JOBS: f64 = 0.4;
 /// Used to normalise the job count into a 0..1 range for scoring.
 pub const MAX_JOBS_FOR_SCORING: u32 = 8;
 
+/// Worker status IDs (mirror the `worker_statuses` lookup table).
+pub const WORKER_STATUS_IDLE: i16 = 1;
+pub const WORKER_STATUS_BUSY: i16 = 2;
+pub const WORKER_STATUS_OFFLINE: i16 = 3;
+pub const WORKER_STATUS_DRAINING: i16 = 4;
+
 /// Maximum length of a worker name.
 const MAX_NAME_LEN: usize = 128;
 
@@ -68,6 +80,110 @@ pub fn count_matching_tags(worker_tags: &[String], required: &[String]) -> usize
         .count()
 }
 
+// ---------------------------------------------------------------------------
+// Fleet statistics
+// ---------------------------------------------------------------------------
+
+/// The fields of a worker that feed into [`compute_fleet_stats`].
+#[derive(Debug, Clone)]
+pub struct FleetWorker {
+    pub worker_id: DbId,
+    pub status_id: i16,
+    pub gpu_count: i16,
+    pub is_approved: bool,
+    pub is_enabled: bool,
+    pub is_decommissioned: bool,
+}
+
+/// The most recent utilization reading for one GPU of a worker.
+#[derive(Debug, Clone)]
+pub struct GpuUtilizationSample {
+    pub worker_id: DbId,
+    pub utilization_percent: Option<i16>,
+}
+
+/// Aggregate statistics for the worker fleet.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FleetStats {
+    pub total_workers: i64,
+    pub idle_workers: i64,
+    pub busy_workers: i64,
+    pub offline_workers: i64,
+    pub draining_workers: i64,
+    pub approved_workers: i64,
+    pub enabled_workers: i64,
+    /// GPUs on workers that are not offline.
+    pub total_capacity: i64,
+    /// GPUs on idle, approved, enabled workers, i.e. ready to take a job.
+    pub available_capacity: i64,
+    /// Mean GPU utilization (0-100) across the reported GPUs of workers that
+    /// are not offline; `None` when no such GPU has reported.
+    pub utilization_pct: Option<f64>,
+}
+
+/// Roll up the fleet into [`FleetStats`].
+///
+/// Decommissioned workers are excluded entirely. `recent_metrics` should hold
+/// at most one sample per GPU; samples for offline, decommissioned, or
+/// unknown workers and samples without a utilization reading are ignored.
+pub fn compute_fleet_stats(
+    workers: &[FleetWorker],
+    recent_metrics: &[GpuUtilizationSample],
+) -> FleetStats {
+    let mut stats = FleetStats {
+        total_workers: 0,
+        idle_workers: 0,
+        busy_workers: 0,
+        offline_workers: 0,
+        draining_workers: 0,
+        approved_workers: 0,
+        enabled_workers: 0,
+        total_capacity: 0,
+        available_capacity: 0,
+        utilization_pct: None,
+    };
+    let mut online = HashSet::new();
+
+    for worker in workers.iter().filter(|w| !w.is_decommissioned) {
+        stats.total_workers += 1;
+        match worker.status_id {
+            WORKER_STATUS_IDLE => stats.idle_workers += 1,
+            WORKER_STATUS_BUSY => stats.busy_workers += 1,
+            WORKER_STATUS_OFFLINE => stats.offline_workers += 1,
+            WORKER_STATUS_DRAINING => stats.draining_workers += 1,
+            _ => {}
+        }
+        if worker.is_approved {
+            stats.approved_workers += 1;
+        }
+        if worker.is_enabled {
+            stats.enabled_workers += 1;
+        }
+
+        if worker.status_id == WORKER_STATUS_OFFLINE {
+            continue;
+        }
+        online.insert(worker.worker_id);
+        let gpus = i64::from(worker.gpu_count.max(0));
+        stats.total_capacity += gpus;
+        if worker.status_id == WORKER_STATUS_IDLE && worker.is_approved && worker.is_enabled {
+            stats.available_capacity += gpus;
+        }
+    }
+
+    let readings: Vec<f64> = recent_metrics
+        .iter()
+        .filter(|m| online.contains(&m.worker_id))
+        .filter_map(|m| m.utilization_percent)
+        .map(|pct| f64::from(pct).clamp(0.0, 100.0))
+        .collect();
+    if !readings.is_empty() {
+        stats.utilization_pct = Some(readings.iter().sum::<f64>() / readings.len() as f64);
+    }
+
+    stats
+}
+
 // ---------------------------------------------------------------------------
 // Validation
 // ---------------------------------------------------------------------------
@@ -201,6 +317,104 @@ mod tests {
         assert_eq!(count_matching_tags(&worker, &[]), 0);
     }
 
+    // -- compute_fleet_stats --------------------------------------------------
+
+    fn worker(worker_id: DbId, status_id: i16, gpu_count: i16) -> FleetWorker {
+        FleetWorker {
+            worker_id,
+            status_id,
+            gpu_count,
+            is_approved: true,
+            is_enabled: true,
+            is_decommissioned: false,
+        }
+    }
+
+    fn sample(worker_id: DbId, utilization_percent: Option<i16>) -> GpuUtilizationSample {
+        GpuUtilizationSample {
+            worker_id,
+            utilization_percent,
+        }
+    }
+
+    #[test]
+    fn fleet_stats_roll_up_mixed_fleet() {
+        let workers = vec![
+            worker(1, WORKER_STATUS_IDLE, 2),
+            worker(2, WORKER_STATUS_BUSY, 4),
+            worker(3, WORKER_STATUS_OFFLINE, 2),
+            worker(4, WORKER_STATUS_DRAINING, 1),
+            FleetWorker {
+                is_enabled: false,
+                ..worker(5, WORKER_STATUS_IDLE, 1)
+            },
+            FleetWorker {
+                is_approved: false,
+                ..worker(6, WORKER_STATUS_IDLE, 2)
+            },
+            FleetWorker {
+                is_decommissioned: true,
+                ..worker(7, WORKER_STATUS_OFFLINE, 8)
+            },
+        ];
+        let metrics = vec![
+            sample(1, Some(10)),
+            sample(1, Some(30)),
+            sample(2, Some(90)),
+            sample(2, Some(100)),
+            sample(2, None),
+            // Offline and decommissioned workers do not count.
+            sample(3, Some(100)),
+            sample(7, Some(100)),
+            sample(4, Some(20)),
+        ];
+
+        let stats = compute_fleet_stats(&workers, &metrics);
+        assert_eq!(
+            stats,
+            FleetStats {
+                total_workers: 6,
+                idle_workers: 3,
+                busy_workers: 1,
+                offline_workers: 1,
+                draining_workers: 1,
+                approved_workers: 5,
+                enabled_workers: 5,
+                total_capacity: 10,
+                available_capacity: 2,
+                utilization_pct: Some(50.0),
+            }
+        );
+    }
+
+    #[test]
+    fn fleet_stats_utilization_clamped() {
+        let workers = vec![worker(1, WORKER_STATUS_BUSY, 2)];
+        let metrics = vec![sample(1, Some(150)), sample(1, Some(-10))];
+        let stats = compute_fleet_stats(&workers, &metrics);
+        assert_eq!(stats.utilization_pct, Some(50.0));
+    }
+
+    #[test]
+    fn fleet_stats_without_samples_has_no_utilization() {
+        let workers = vec![
+            worker(1, WORKER_STATUS_IDLE, 2),
+            worker(2, WORKER_STATUS_OFFLINE, 2),
+        ];
+        let stats = compute_fleet_stats(&workers, &[sample(2, Some(80))]);
+        assert_eq!(stats.total_capacity, 2);
+        assert_eq!(stats.available_capacity, 2);
+        assert_eq!(stats.utilization_pct, None);
+    }
+
+    #[test]
+    fn fleet_stats_empty_fleet() {
+        let stats = compute_fleet_stats(&[], &[]);
+        assert_eq!(stats.total_workers, 0);
+        assert_eq!(stats.total_capacity, 0);
+        assert_eq!(stats.utilization_pct, None);
+    }
+
     // -- validate_worker_name -------------------------------------------------
 
     #[test]
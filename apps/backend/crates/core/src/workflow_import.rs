@@ -4,6 +4,8 @@
 //! validates workflow metadata, and computes content hashes for
 //! duplicate detection.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use serde::{Deserialize, Serialize};
 
 use crate::error::CoreError;
@@ -42,6 +44,9 @@ pub const MAX_WORKFLOW_JSON_SIZE: usize = 10_000_000;
 /// Maximum number of discovered parameters per workflow.
 pub const MAX_DISCOVERED_PARAMS: usize = 100;
 
+/// Default maximum number of generation parameter overrides.
+pub const DEFAULT_MAX_PARAMETER_OVERRIDES: usize = 50;
+
 /// Default dry-run timeout in seconds.
 pub const DRY_RUN_DEFAULT_TIMEOUT_SECS: u64 = 300;
 
@@ -106,6 +111,68 @@ const LOAD_CHECKPOINT_CLASS: &str = "CheckpointLoaderSimple";
 /// Load LoRA node class type.
 const LOAD_LORA_CLASS: &str = "LoraLoader";
 
+/// Load ControlNet model node class type.
+const LOAD_CONTROLNET_CLASS: &str = "ControlNetLoader";
+
+/// Load upscale model node class type.
+const LOAD_UPSCALE_MODEL_CLASS: &str = "UpscaleModelLoader";
+
+/// UI-format node types that only annotate the canvas and never run.
+const UI_ONLY_NODE_TYPES: &[&str] = &["Note", "MarkdownNote"];
+
+/// Input names for the positional `widgets_values` of UI-format nodes.
+///
+/// `None` marks UI-only widgets (such as the seed's "control after generate"
+/// toggle) that have no matching API input. Classes not listed here keep
+/// only their linked inputs.
+const UI_WIDGET_NAMES: &[(&str, &[Option<&str>])] = &[
+    (
+        KSAMPLER_CLASS,
+        &[
+            Some("seed"),
+            None,
+            Some("steps"),
+            Some("cfg"),
+            Some("sampler_name"),
+            Some("scheduler"),
+            Some("denoise"),
+        ],
+    ),
+    (
+        KSAMPLER_ADVANCED_CLASS,
+        &[
+            Some("add_noise"),
+            Some("noise_seed"),
+            None,
+            Some("steps"),
+            Some("cfg"),
+            Some("sampler_name"),
+            Some("scheduler"),
+            Some("start_at_step"),
+            Some("end_at_step"),
+            Some("return_with_leftover_noise"),
+        ],
+    ),
+    (CLIP_TEXT_ENCODE_CLASS, &[Some("text")]),
+    (LOAD_CHECKPOINT_CLASS, &[Some("ckpt_name")]),
+    (
+        LOAD_LORA_CLASS,
+        &[
+            Some("lora_name"),
+            Some("strength_model"),
+            Some("strength_clip"),
+        ],
+    ),
+    (LOAD_CONTROLNET_CLASS, &[Some("control_net_name")]),
+    (LOAD_UPSCALE_MODEL_CLASS, &[Some("model_name")]),
+    (LOAD_IMAGE_CLASS, &[Some("image"), None]),
+    (
+        "EmptyLatentImage",
+        &[Some("width"), Some("height"), Some("batch_size")],
+    ),
+    ("SaveImage", &[Some("filename_prefix")]),
+];
+
 // ---------------------------------------------------------------------------
 // Data structures
 // ---------------------------------------------------------------------------
@@ -134,9 +201,23 @@ pub struct NodeConnection {
     pub to_input: String,
 }
 
+/// Which ComfyUI export format a workflow JSON was saved in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowFormat {
+    /// "Save (API Format)": an object keyed by node ID with `class_type`
+    /// and named `inputs`.
+    Api,
+    /// Plain "Save" from the web UI: a `nodes` array with positional
+    /// `widgets_values` plus a separate `links` array.
+    Ui,
+}
+
 /// Result of parsing a ComfyUI workflow JSON.
 #[derive(Debug, Serialize)]
 pub struct ParsedWorkflow {
+    /// Export format the workflow was detected as.
+    pub workflow_format: WorkflowFormat,
     /// All nodes in the workflow.
     pub nodes: Vec<WorkflowNode>,
     /// All connections between nodes.
@@ -145,10 +226,45 @@ pub struct ParsedWorkflow {
     pub referenced_models: Vec<String>,
     /// LoRA filenames referenced by LoRA loaders.
     pub referenced_loras: Vec<String>,
+    /// ControlNet model filenames referenced by ControlNet loaders.
+    pub referenced_controlnets: Vec<String>,
+    /// Upscale model filenames referenced by upscale model loaders.
+    pub referenced_upscale_models: Vec<String>,
     /// Custom node class types not in the standard ComfyUI set.
     pub referenced_custom_nodes: Vec<String>,
 }
 
+impl ParsedWorkflow {
+    /// Number of nodes of each class type, keyed in sorted order.
+    pub fn class_type_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for node in &self.nodes {
+            *counts.entry(node.class_type.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Total number of nodes in the workflow.
+    pub fn total_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Total number of node-to-node connections in the workflow.
+    pub fn total_connections(&self) -> usize {
+        self.connections.len()
+    }
+
+    /// Every model file the workflow depends on: checkpoints, LoRAs,
+    /// ControlNets, then upscale models.
+    pub fn model_references(&self) -> impl Iterator<Item = &String> {
+        self.referenced_models
+            .iter()
+            .chain(&self.referenced_loras)
+            .chain(&self.referenced_controlnets)
+            .chain(&self.referenced_upscale_models)
+    }
+}
+
 /// Type of a discovered parameter.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -181,6 +297,14 @@ pub struct DiscoveredParameter {
     pub category: String,
 }
 
+impl DiscoveredParameter {
+    /// Stable key identifying this parameter: `"{node_id}.{input_name}"`,
+    /// the same form used by `generation_params` overrides.
+    pub fn param_key(&self) -> String {
+        format!("{}.{}", self.node_id, self.input_name)
+    }
+}
+
 /// Result of validating a single node type against available nodes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeValidationResult {
@@ -292,8 +416,9 @@ const STANDARD_NODE_TYPES: &[&str] = &[
 
 /// Parse a ComfyUI workflow JSON into structured data.
 ///
-/// The expected format is an object where each key is a node ID and each
-/// value is an object with `class_type` and `inputs` fields:
+/// Accepts both ComfyUI export formats. The API format ("Save (API Format)")
+/// is an object where each key is a node ID and each value is an object with
+/// `class_type` and `inputs` fields:
 ///
 /// ```json
 /// {
@@ -303,11 +428,27 @@ const STANDARD_NODE_TYPES: &[&str] = &[
 ///   }
 /// }
 /// ```
+///
+/// The UI format (plain "Save") has a top-level `nodes` array whose entries
+/// carry `type` and positional `widgets_values`, plus a `links` array. It is
+/// normalized into the API shape first; see [`WorkflowFormat`].
 pub fn parse_workflow(json: &serde_json::Value) -> Result<ParsedWorkflow, CoreError> {
     let obj = json
         .as_object()
         .ok_or_else(|| CoreError::Validation("Workflow JSON must be an object".to_string()))?;
 
+    if is_ui_format(obj) {
+        let normalized = ui_to_api_nodes(obj)?;
+        return parse_api_nodes(&normalized, WorkflowFormat::Ui);
+    }
+    parse_api_nodes(obj, WorkflowFormat::Api)
+}
+
+/// Parse an API-format node map.
+fn parse_api_nodes(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    workflow_format: WorkflowFormat,
+) -> Result<ParsedWorkflow, CoreError> {
     if obj.is_empty() {
         return Err(CoreError::Validation(
             "Workflow JSON must contain at least one node".to_string(),
@@ -318,6 +459,8 @@ pub fn parse_workflow(json: &serde_json::Value) -> Result<ParsedWorkflow, CoreEr
     let mut connections = Vec::new();
     let mut referenced_models = Vec::new();
     let mut referenced_loras = Vec::new();
+    let mut referenced_controlnets = Vec::new();
+    let mut referenced_upscale_models = Vec::new();
     let mut custom_node_set = Vec::new();
 
     for (node_id, node_value) in obj {
@@ -387,6 +530,24 @@ pub fn parse_workflow(json: &serde_json::Value) -> Result<ParsedWorkflow, CoreEr
             }
         }
 
+        // Extract ControlNet references from ControlNet loaders.
+        if class_type == LOAD_CONTROLNET_CLASS {
+            if let Some(name) = inputs.get("control_net_name").and_then(|v| v.as_str()) {
+                if !referenced_controlnets.contains(&name.to_string()) {
+                    referenced_controlnets.push(name.to_string());
+                }
+            }
+        }
+
+        // Extract upscale model references from upscale model loaders.
+        if class_type == LOAD_UPSCALE_MODEL_CLASS {
+            if let Some(name) = inputs.get("model_name").and_then(|v| v.as_str()) {
+                if !referenced_upscale_models.contains(&name.to_string()) {
+                    referenced_upscale_models.push(name.to_string());
+                }
+            }
+        }
+
         // Detect custom nodes not in the standard set.
         if !STANDARD_NODE_TYPES.contains(&class_type.as_str())
             && !custom_node_set.contains(&class_type)
@@ -405,10 +566,13 @@ pub fn parse_workflow(json: &serde_json::Value) -> Result<ParsedWorkflow, CoreEr
     nodes.sort_by(|a, b| a.id.cmp(&b.id));
 
     Ok(ParsedWorkflow {
+        workflow_format,
         nodes,
         connections,
         referenced_models,
         referenced_loras,
+        referenced_controlnets,
+        referenced_upscale_models,
         referenced_custom_nodes: custom_node_set,
     })
 }
@@ -474,6 +638,120 @@ pub fn validate_workflow_json_size(json: &serde_json::Value) -> Result<(), CoreE
     Ok(())
 }
 
+/// Reject a workflow whose connections form a cycle.
+///
+/// Runs a depth-first search over `parsed.connections` (source node to
+/// destination node). ComfyUI executes workflows in topological order, so a
+/// cycle can never run. The error names the nodes in the first cycle found,
+/// e.g. `"3 -> 5 -> 3"`. Disconnected subgraphs are fine.
+pub fn detect_cycles(parsed: &ParsedWorkflow) -> Result<(), CoreError> {
+    // Sorted for a deterministic search order and error message.
+    let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for node in &parsed.nodes {
+        adjacency.entry(node.id.as_str()).or_default();
+    }
+    for conn in &parsed.connections {
+        adjacency
+            .entry(conn.from_node.as_str())
+            .or_default()
+            .push(conn.to_node.as_str());
+        adjacency.entry(conn.to_node.as_str()).or_default();
+    }
+    for targets in adjacency.values_mut() {
+        targets.sort_unstable();
+        targets.dedup();
+    }
+
+    // Nodes on the current DFS path map to `false`; finished nodes to `true`.
+    let mut visited: HashMap<&str, bool> = HashMap::new();
+    for &root in adjacency.keys() {
+        if visited.contains_key(root) {
+            continue;
+        }
+        visited.insert(root, false);
+        // Each entry is a node on the path and the index of its next edge.
+        let mut path: Vec<(&str, usize)> = vec![(root, 0)];
+
+        while let Some(&(node, next)) = path.last() {
+            let Some(&target) = adjacency[node].get(next) else {
+                visited.insert(node, true);
+                path.pop();
+                continue;
+            };
+            if let Some(last) = path.last_mut() {
+                last.1 += 1;
+            }
+
+            match visited.get(target) {
+                None => {
+                    visited.insert(target, false);
+                    path.push((target, 0));
+                }
+                Some(false) => {
+                    let start = path.iter().position(|&(n, _)| n == target).unwrap_or(0);
+                    let mut cycle: Vec<&str> = path[start..].iter().map(|&(n, _)| n).collect();
+                    cycle.push(target);
+                    return Err(CoreError::Validation(format!(
+                        "Workflow graph contains a cycle: {}",
+                        cycle.join(" -> ")
+                    )));
+                }
+                Some(true) => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate generation parameter overrides against a discovered-parameter
+/// set.
+///
+/// `overrides` must be a JSON object keyed by [`DiscoveredParameter::param_key`].
+/// When `discovered` is `None` (no workflow to check against) only the
+/// count limit applies. All unknown keys are reported in a single error,
+/// alongside the count violation if there is one.
+pub fn validate_parameter_overrides(
+    overrides: &serde_json::Value,
+    discovered: Option<&[DiscoveredParameter]>,
+    max_overrides: usize,
+) -> Result<(), CoreError> {
+    let Some(map) = overrides.as_object() else {
+        return Err(CoreError::Validation(
+            "Parameter overrides must be a JSON object".to_string(),
+        ));
+    };
+
+    let mut problems = Vec::new();
+    if let Some(discovered) = discovered {
+        let known: HashSet<String> = discovered.iter().map(|p| p.param_key()).collect();
+        let mut unknown: Vec<&str> = map
+            .keys()
+            .filter(|key| !known.contains(key.as_str()))
+            .map(String::as_str)
+            .collect();
+        unknown.sort_unstable();
+        if !unknown.is_empty() {
+            problems.push(format!("unknown override keys: {}", unknown.join(", ")));
+        }
+    }
+    if map.len() > max_overrides {
+        problems.push(format!(
+            "{} overrides exceeds the maximum of {max_overrides}",
+            map.len()
+        ));
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(CoreError::Validation(format!(
+            "Invalid parameter overrides: {}",
+            problems.join("; ")
+        )))
+    }
+}
+
 /// A media-loading node discovered during workflow import.
 ///
 /// Used by PRD-146 to auto-create workflow media slots for image, video,
@@ -593,6 +871,142 @@ pub fn compute_workflow_hash(json: &serde_json::Value) -> String {
 // Private helpers
 // ---------------------------------------------------------------------------
 
+/// Whether a workflow object is a UI-format export: a top-level `nodes`
+/// array whose entries carry `type` or `widgets_values`.
+///
+/// An API-format node is always an object, so an empty `nodes` array is
+/// also treated as a (node-less) UI export.
+fn is_ui_format(obj: &serde_json::Map<String, serde_json::Value>) -> bool {
+    obj.get("nodes")
+        .and_then(|v| v.as_array())
+        .is_some_and(|nodes| {
+            nodes.is_empty()
+                || nodes
+                    .iter()
+                    .any(|n| n.get("type").is_some() || n.get("widgets_values").is_some())
+        })
+}
+
+/// Normalize a UI-format export into an API-format node map.
+///
+/// Widget values are named via [`UI_WIDGET_NAMES`] (or taken as-is when a
+/// node stores them as an object), and each `links` entry
+/// `[link_id, from_node, from_slot, to_node, to_slot, type]` becomes a
+/// `[from_node, from_slot]` reference on the target input, as in the API
+/// format.
+fn ui_to_api_nodes(
+    obj: &serde_json::Map<String, serde_json::Value>,
+) -> Result<serde_json::Map<String, serde_json::Value>, CoreError> {
+    let ui_nodes = obj
+        .get("nodes")
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut nodes = serde_json::Map::new();
+    // Input names by node ID, indexed by slot, to resolve link targets.
+    let mut input_slots: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (index, node) in ui_nodes.iter().enumerate() {
+        let node_id = node.get("id").and_then(json_id).ok_or_else(|| {
+            CoreError::Validation(format!(
+                "Node at index {index} is missing required 'id' field"
+            ))
+        })?;
+        let class_type = node.get("type").and_then(|v| v.as_str()).ok_or_else(|| {
+            CoreError::Validation(format!("Node '{node_id}' is missing required 'type' field"))
+        })?;
+        if UI_ONLY_NODE_TYPES.contains(&class_type) {
+            continue;
+        }
+
+        let mut inputs = serde_json::Map::new();
+        match node.get("widgets_values") {
+            Some(serde_json::Value::Array(values)) => {
+                let names = UI_WIDGET_NAMES
+                    .iter()
+                    .find(|(class, _)| *class == class_type)
+                    .map(|(_, names)| *names)
+                    .unwrap_or_default();
+                for (name, value) in names.iter().zip(values) {
+                    if let Some(name) = name {
+                        inputs.insert((*name).to_string(), value.clone());
+                    }
+                }
+            }
+            Some(serde_json::Value::Object(values)) => {
+                inputs.extend(values.clone());
+            }
+            _ => {}
+        }
+
+        let slots = node
+            .get("inputs")
+            .and_then(|v| v.as_array())
+            .map(|slots| {
+                slots
+                    .iter()
+                    .map(|slot| {
+                        slot.get("name")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or_default()
+                            .to_string()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        input_slots.insert(node_id.clone(), slots);
+
+        nodes.insert(
+            node_id,
+            serde_json::json!({ "class_type": class_type, "inputs": inputs }),
+        );
+    }
+
+    let links = obj
+        .get("links")
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for link in links {
+        let Some(link) = link.as_array().filter(|l| l.len() >= 5) else {
+            continue;
+        };
+        let (Some(from_node), Some(from_slot), Some(to_node), Some(to_slot)) = (
+            json_id(&link[1]),
+            link[2].as_u64(),
+            json_id(&link[3]),
+            link[4].as_u64(),
+        ) else {
+            continue;
+        };
+        let Some(to_input) = input_slots
+            .get(&to_node)
+            .and_then(|slots| slots.get(to_slot as usize))
+            .filter(|name| !name.is_empty())
+        else {
+            continue;
+        };
+        if let Some(inputs) = nodes
+            .get_mut(&to_node)
+            .and_then(|n| n.get_mut("inputs"))
+            .and_then(|i| i.as_object_mut())
+        {
+            inputs.insert(to_input.clone(), serde_json::json!([from_node, from_slot]));
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// Read a UI-format node ID, which may be a number or a string.
+fn json_id(value: &serde_json::Value) -> Option<String> {
+    value
+        .as_u64()
+        .map(|n| n.to_string())
+        .or_else(|| value.as_str().map(str::to_string))
+}
+
 /// Extract configurable parameters from a KSampler node.
 fn discover_ksampler_params(params: &mut Vec<DiscoveredParameter>, node: &WorkflowNode) {
     let input_mappings: &[(&str, ParamType, &str)] = &[
@@ -861,6 +1275,96 @@ mod tests {
         assert_eq!(parsed.referenced_models, vec!["model_v1.safetensors"]);
     }
 
+    /// An image-to-image upscale graph guided by two ControlNets, one of
+    /// them loaded twice.
+    fn workflow_with_controlnet_and_upscale() -> serde_json::Value {
+        json!({
+            "1": {
+                "class_type": "CheckpointLoaderSimple",
+                "inputs": { "ckpt_name": "model_v1.safetensors" }
+            },
+            "2": {
+                "class_type": "ControlNetLoader",
+                "inputs": { "control_net_name": "control_openpose.safetensors" }
+            },
+            "3": {
+                "class_type": "ControlNetLoader",
+                "inputs": { "control_net_name": "control_depth.safetensors" }
+            },
+            "4": {
+                "class_type": "ControlNetLoader",
+                "inputs": { "control_net_name": "control_openpose.safetensors" }
+            },
+            "5": {
+                "class_type": "UpscaleModelLoader",
+                "inputs": { "model_name": "4x-UltraSharp.pth" }
+            },
+            "6": {
+                "class_type": "ImageUpscaleWithModel",
+                "inputs": { "upscale_model": ["5", 0], "image": ["7", 0] }
+            },
+            "7": {
+                "class_type": "LoadImage",
+                "inputs": { "image": "input.png" }
+            }
+        })
+    }
+
+    #[test]
+    fn parse_workflow_extracts_controlnet_and_upscale_references() {
+        let parsed = parse_workflow(&workflow_with_controlnet_and_upscale()).unwrap();
+        // Deduplicated, in node ID order.
+        assert_eq!(
+            parsed.referenced_controlnets,
+            vec!["control_openpose.safetensors", "control_depth.safetensors"]
+        );
+        assert_eq!(parsed.referenced_upscale_models, vec!["4x-UltraSharp.pth"]);
+        assert_eq!(parsed.referenced_models, vec!["model_v1.safetensors"]);
+        assert!(parsed.referenced_loras.is_empty());
+        assert!(parsed.referenced_custom_nodes.is_empty());
+    }
+
+    #[test]
+    fn model_references_covers_all_loader_kinds() {
+        let parsed = parse_workflow(&workflow_with_controlnet_and_upscale()).unwrap();
+        let refs: Vec<&str> = parsed.model_references().map(String::as_str).collect();
+        assert_eq!(
+            refs,
+            vec![
+                "model_v1.safetensors",
+                "control_openpose.safetensors",
+                "control_depth.safetensors",
+                "4x-UltraSharp.pth",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_ui_workflow_extracts_controlnet_and_upscale_references() {
+        let json = json!({
+            "nodes": [
+                {
+                    "id": 1,
+                    "type": "ControlNetLoader",
+                    "widgets_values": ["control_canny.safetensors"]
+                },
+                {
+                    "id": 2,
+                    "type": "UpscaleModelLoader",
+                    "widgets_values": ["RealESRGAN_x4.pth"]
+                }
+            ],
+            "links": []
+        });
+        let parsed = parse_workflow(&json).unwrap();
+        assert_eq!(parsed.workflow_format, WorkflowFormat::Ui);
+        assert_eq!(
+            parsed.referenced_controlnets,
+            vec!["control_canny.safetensors"]
+        );
+        assert_eq!(parsed.referenced_upscale_models, vec!["RealESRGAN_x4.pth"]);
+    }
+
     #[test]
     fn parse_workflow_detects_custom_nodes() {
         let parsed = parse_workflow(&workflow_with_custom_node()).unwrap();
@@ -903,7 +1407,159 @@ mod tests {
         assert_eq!(parsed.nodes[0].inputs, json!({}));
     }
 
-    // -- discover_parameters --------------------------------------------------
+    #[test]
+    fn parse_api_format_reports_api() {
+        let parsed = parse_workflow(&sample_workflow_json()).unwrap();
+        assert_eq!(parsed.workflow_format, WorkflowFormat::Api);
+    }
+
+    // -- parse_workflow (UI format) -------------------------------------------
+
+    /// The `workflow_with_lora` graph as saved by the web UI's plain "Save".
+    fn ui_workflow_json() -> serde_json::Value {
+        json!({
+            "last_node_id": 7,
+            "last_link_id": 4,
+            "nodes": [
+                {
+                    "id": 1,
+                    "type": "CheckpointLoaderSimple",
+                    "inputs": [],
+                    "outputs": [{ "name": "MODEL" }, { "name": "CLIP" }, { "name": "VAE" }],
+                    "widgets_values": ["model_v1.safetensors"]
+                },
+                {
+                    "id": 2,
+                    "type": "LoraLoader",
+                    "inputs": [
+                        { "name": "model", "type": "MODEL", "link": 1 },
+                        { "name": "clip", "type": "CLIP", "link": 2 }
+                    ],
+                    "widgets_values": ["detail_enhancer.safetensors", 0.8, 1.0]
+                },
+                {
+                    "id": 3,
+                    "type": "KSampler",
+                    "inputs": [
+                        { "name": "model", "type": "MODEL", "link": 3 },
+                        { "name": "positive", "type": "CONDITIONING", "link": 4 }
+                    ],
+                    "widgets_values": [123, "randomize", 30, 8.0, "dpmpp_2m", "karras", 1.0]
+                },
+                {
+                    "id": 4,
+                    "type": "CLIPTextEncode",
+                    "inputs": [{ "name": "clip", "type": "CLIP", "link": null }],
+                    "widgets_values": ["portrait photo"]
+                },
+                {
+                    "id": 7,
+                    "type": "Note",
+                    "widgets_values": ["Remember to bump the seed"]
+                }
+            ],
+            "links": [
+                [1, 1, 0, 2, 0, "MODEL"],
+                [2, 1, 1, 2, 1, "CLIP"],
+                [3, 2, 0, 3, 0, "MODEL"],
+                [4, 4, 0, 3, 1, "CONDITIONING"]
+            ],
+            "version": 0.4
+        })
+    }
+
+    #[test]
+    fn parse_ui_format_normalizes_nodes() {
+        let parsed = parse_workflow(&ui_workflow_json()).unwrap();
+        assert_eq!(parsed.workflow_format, WorkflowFormat::Ui);
+
+        // The note is dropped; the rest keep their IDs and class types.
+        let ids: Vec<&str> = parsed.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3", "4"]);
+        assert!(parsed.referenced_custom_nodes.is_empty());
+
+        let ksampler = parsed.nodes.iter().find(|n| n.id == "3").unwrap();
+        assert_eq!(ksampler.class_type, "KSampler");
+        assert_eq!(ksampler.inputs["seed"], 123);
+        assert_eq!(ksampler.inputs["steps"], 30);
+        assert_eq!(ksampler.inputs["cfg"], 8.0);
+        assert_eq!(ksampler.inputs["sampler_name"], "dpmpp_2m");
+        assert_eq!(ksampler.inputs["model"], json!(["2", 0]));
+    }
+
+    #[test]
+    fn parse_ui_format_maps_links_to_connections() {
+        let parsed = parse_workflow(&ui_workflow_json()).unwrap();
+        assert_eq!(parsed.connections.len(), 4);
+
+        let clip = parsed
+            .connections
+            .iter()
+            .find(|c| c.to_node == "2" && c.to_input == "clip")
+            .unwrap();
+        assert_eq!(clip.from_node, "1");
+        assert_eq!(clip.from_output, "1");
+    }
+
+    #[test]
+    fn parse_ui_format_extracts_positional_model_and_lora() {
+        let parsed = parse_workflow(&ui_workflow_json()).unwrap();
+        assert_eq!(parsed.referenced_models, vec!["model_v1.safetensors"]);
+        assert_eq!(parsed.referenced_loras, vec!["detail_enhancer.safetensors"]);
+    }
+
+    #[test]
+    fn parse_ui_format_discovers_parameters() {
+        let parsed = parse_workflow(&ui_workflow_json()).unwrap();
+        let keys: Vec<String> = discover_parameters(&parsed)
+            .iter()
+            .map(DiscoveredParameter::param_key)
+            .collect();
+        assert!(keys.contains(&"3.seed".to_string()));
+        assert!(keys.contains(&"3.cfg".to_string()));
+        assert!(keys.contains(&"4.text".to_string()));
+    }
+
+    #[test]
+    fn parse_ui_format_node_missing_type_returns_error() {
+        let json = json!({ "nodes": [{ "id": 1, "widgets_values": [] }], "links": [] });
+        let err = parse_workflow(&json).unwrap_err();
+        assert!(err.to_string().contains("'type'"));
+    }
+
+    #[test]
+    fn parse_ui_format_without_nodes_returns_error() {
+        let json = json!({ "nodes": [], "links": [] });
+        assert!(parse_workflow(&json).is_err());
+    }
+
+    // -- class_type_counts ----------------------------------------------------
+
+    #[test]
+    fn class_type_counts_sample_workflow() {
+        let parsed = parse_workflow(&sample_workflow_json()).unwrap();
+        let counts = parsed.class_type_counts();
+
+        let expected: Vec<(&str, usize)> = vec![
+            ("CLIPTextEncode", 2),
+            ("CheckpointLoaderSimple", 1),
+            ("EmptyLatentImage", 1),
+            ("KSampler", 1),
+            ("SaveImage", 1),
+            ("VAEDecode", 1),
+        ];
+        let actual: Vec<(&str, usize)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        assert_eq!(actual, expected);
+        assert_eq!(counts.values().sum::<usize>(), parsed.total_nodes());
+        assert_eq!(parsed.total_nodes(), 7);
+    }
+
+    #[test]
+    fn total_connections_sample_workflow() {
+        let parsed = parse_workflow(&sample_workflow_json()).unwrap();
+        assert_eq!(parsed.total_connections(), parsed.connections.len());
+        assert_eq!(parsed.total_connections(), 9);
+    }
 
     #[test]
     fn discover_ksampler_params() {
@@ -1014,6 +1670,71 @@ mod tests {
         assert!(validate_workflow_name(&name).is_ok());
     }
 
+    // -- detect_cycles --------------------------------------------------------
+
+    /// Build a workflow of pass-through nodes from `(from, to)` edges.
+    fn workflow_with_edges(node_ids: &[&str], edges: &[(&str, &str)]) -> ParsedWorkflow {
+        let mut json = serde_json::Map::new();
+        for id in node_ids {
+            let inputs: serde_json::Map<String, serde_json::Value> = edges
+                .iter()
+                .filter(|(_, to)| to == id)
+                .enumerate()
+                .map(|(i, (from, _))| (format!("in_{i}"), json!([from, 0])))
+                .collect();
+            json.insert(
+                (*id).to_string(),
+                json!({ "class_type": "Passthrough", "inputs": inputs }),
+            );
+        }
+        parse_workflow(&serde_json::Value::Object(json)).unwrap()
+    }
+
+    #[test]
+    fn detect_cycles_accepts_sample_workflow() {
+        let parsed = parse_workflow(&sample_workflow_json()).unwrap();
+        assert!(detect_cycles(&parsed).is_ok());
+    }
+
+    #[test]
+    fn detect_cycles_accepts_dag_with_shared_inputs() {
+        let parsed = workflow_with_edges(
+            &["1", "2", "3", "4"],
+            &[("1", "2"), ("1", "3"), ("2", "4"), ("3", "4")],
+        );
+        assert!(detect_cycles(&parsed).is_ok());
+    }
+
+    #[test]
+    fn detect_cycles_accepts_disconnected_subgraphs() {
+        let parsed = workflow_with_edges(&["1", "2", "3", "4", "5"], &[("1", "2"), ("3", "4")]);
+        assert!(detect_cycles(&parsed).is_ok());
+    }
+
+    #[test]
+    fn detect_cycles_rejects_self_loop() {
+        let parsed = workflow_with_edges(&["1", "2"], &[("1", "2"), ("2", "2")]);
+        let err = detect_cycles(&parsed).unwrap_err();
+        assert!(err.to_string().contains("2 -> 2"), "got: {err}");
+    }
+
+    #[test]
+    fn detect_cycles_rejects_two_node_cycle() {
+        let parsed = workflow_with_edges(&["1", "2", "3"], &[("1", "2"), ("2", "1"), ("3", "1")]);
+        let err = detect_cycles(&parsed).unwrap_err();
+        assert!(err.to_string().contains("1 -> 2 -> 1"), "got: {err}");
+    }
+
+    #[test]
+    fn detect_cycles_rejects_cycle_in_one_subgraph() {
+        let parsed = workflow_with_edges(
+            &["1", "2", "7", "8", "9"],
+            &[("1", "2"), ("7", "8"), ("8", "9"), ("9", "7")],
+        );
+        let err = detect_cycles(&parsed).unwrap_err();
+        assert!(err.to_string().contains("7 -> 8 -> 9 -> 7"), "got: {err}");
+    }
+
     // -- validate_workflow_json_size -------------------------------------------
 
     #[test]
@@ -1130,4 +1851,69 @@ mod tests {
         let nodes = discover_media_nodes(&json!("not an object"));
         assert!(nodes.is_empty());
     }
+
+    // -- validate_parameter_overrides -----------------------------------------
+
+    fn sample_discovered() -> Vec<DiscoveredParameter> {
+        let parsed = parse_workflow(&sample_workflow_json()).unwrap();
+        discover_parameters(&parsed)
+    }
+
+    #[test]
+    fn param_key_joins_node_and_input() {
+        let params = sample_discovered();
+        let keys: Vec<String> = params.iter().map(|p| p.param_key()).collect();
+        assert!(keys.contains(&"3.cfg".to_string()));
+        assert!(keys.contains(&"6.text".to_string()));
+    }
+
+    #[test]
+    fn known_overrides_are_accepted() {
+        let discovered = sample_discovered();
+        let overrides = json!({"3.cfg": 6.0, "3.steps": 30});
+        assert!(validate_parameter_overrides(&overrides, Some(&discovered), 10).is_ok());
+    }
+
+    #[test]
+    fn unknown_override_keys_are_all_reported() {
+        let discovered = sample_discovered();
+        let overrides = json!({"3.cfg": 6.0, "99.seed": 1, "3.bogus": true});
+        let err = validate_parameter_overrides(&overrides, Some(&discovered), 10).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("3.bogus, 99.seed"), "got: {msg}");
+        assert!(!msg.contains("3.cfg"));
+    }
+
+    #[test]
+    fn over_limit_override_set_is_rejected() {
+        let discovered = sample_discovered();
+        let overrides = json!({"3.cfg": 6.0, "3.steps": 30, "3.seed": 7});
+        let err = validate_parameter_overrides(&overrides, Some(&discovered), 2).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("3 overrides exceeds the maximum of 2"));
+    }
+
+    #[test]
+    fn unknown_keys_and_limit_reported_together() {
+        let discovered = sample_discovered();
+        let overrides = json!({"3.cfg": 6.0, "x.y": 1});
+        let msg = validate_parameter_overrides(&overrides, Some(&discovered), 1)
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("unknown override keys: x.y"));
+        assert!(msg.contains("exceeds the maximum of 1"));
+    }
+
+    #[test]
+    fn without_discovered_set_only_limit_applies() {
+        let overrides = json!({"anything.goes": 1});
+        assert!(validate_parameter_overrides(&overrides, None, 1).is_ok());
+        assert!(validate_parameter_overrides(&overrides, None, 0).is_err());
+    }
+
+    #[test]
+    fn non_object_overrides_are_rejected() {
+        assert!(validate_parameter_overrides(&json!([1, 2]), None, 10).is_err());
+    }
 }
@@ -8,11 +8,15 @@ use sqlx::postgres::PgPoolOptions;
 pub type DbPool = sqlx::PgPool;
 
 /// Connection pool configuration with sensible defaults.
+#[derive(Debug, Clone)]
 pub struct PoolConfig {
     pub max_connections: u32,
     pub min_connections: u32,
     pub idle_timeout_secs: u64,
     pub acquire_timeout_secs: u64,
+    /// Maximum connections of the read-replica pool; the other settings are
+    /// shared with the primary pool.
+    pub replica_max_connections: u32,
 }
 
 impl Default for PoolConfig {
@@ -22,10 +26,38 @@ impl Default for PoolConfig {
             min_connections: 2,
             idle_timeout_secs: 300,
             acquire_timeout_secs: 5,
+            replica_max_connections: 10,
         }
     }
 }
 
+/// A primary (writer) pool and a read-replica (reader) pool.
+///
+/// Without a replica, `reader` is a handle to the writer pool, so callers
+/// can always send read-only queries to `reader` without branching.
+#[derive(Debug, Clone)]
+pub struct DbPools {
+    pub writer: DbPool,
+    pub reader: DbPool,
+    has_replica: bool,
+}
+
+impl DbPools {
+    /// Use a single pool for both reads and writes.
+    pub fn single(pool: DbPool) -> Self {
+        Self {
+            reader: pool.clone(),
+            writer: pool,
+            has_replica: false,
+        }
+    }
+
+    /// Whether `reader` is a separate replica pool.
+    pub fn has_replica(&self) -> bool {
+        self.has_replica
+    }
+}
+
 /// Create a connection pool from a database URL with default settings.
 pub async fn create_pool(database_url: &str) -> Result<DbPool, sqlx::Error> {
     create_pool_with_config(database_url, PoolConfig::default()).await
@@ -45,6 +77,35 @@ pub async fn create_pool_with_config(
         .await
 }
 
+/// Create the writer pool and, when `replica_url` is non-empty, a reader
+/// pool sized by `replica_max_connections`.
+///
+/// An empty `replica_url` makes the reader alias the writer.
+pub async fn create_read_write_pools(
+    primary_url: &str,
+    replica_url: &str,
+    config: PoolConfig,
+) -> Result<DbPools, sqlx::Error> {
+    let writer = create_pool_with_config(primary_url, config.clone()).await?;
+    if replica_url.trim().is_empty() {
+        return Ok(DbPools::single(writer));
+    }
+
+    let reader = create_pool_with_config(
+        replica_url,
+        PoolConfig {
+            max_connections: config.replica_max_connections,
+            ..config
+        },
+    )
+    .await?;
+    Ok(DbPools {
+        writer,
+        reader,
+        has_replica: true,
+    })
+}
+
 /// Verify database connectivity by executing a simple query.
 pub async fn health_check(pool: &DbPool) -> Result<(), sqlx::Error> {
     sqlx::query_scalar::<_, i32>("SELECT 1")
@@ -53,6 +114,15 @@ pub async fn health_check(pool: &DbPool) -> Result<(), sqlx::Error> {
     Ok(())
 }
 
+/// Verify connectivity of the writer and, if separate, the reader pool.
+pub async fn health_check_pools(pools: &DbPools) -> Result<(), sqlx::Error> {
+    health_check(&pools.writer).await?;
+    if pools.has_replica {
+        health_check(&pools.reader).await?;
+    }
+    Ok(())
+}
+
 /// Run all pending migrations from `apps/db/migrations/`.
 pub async fn run_migrations(pool: &DbPool) -> Result<(), sqlx::migrate::MigrateError> {
     sqlx::migrate!("../../../db/migrations").run(pool).await
@@ -110,7 +110,9 @@ pub struct UpdateApiKey {
 // ---------------------------------------------------------------------------
 
 /// A row from the `webhooks` table.
-#[derive(Debug, Clone, FromRow, Serialize)]
+///
+/// `Debug` is implemented by hand so the signing secret never reaches logs.
+#[derive(Clone, FromRow, Serialize)]
 pub struct Webhook {
     pub id: DbId,
     pub name: String,
@@ -122,10 +124,37 @@ pub struct Webhook {
     pub created_by: DbId,
     pub last_triggered_at: Option<Timestamp>,
     pub failure_count: i32,
+    /// `active` or `suspended` (circuit open after repeated failures).
+    pub circuit_state: String,
+    pub consecutive_failures: i32,
+    pub suspended_at: Option<Timestamp>,
+    pub next_probe_at: Option<Timestamp>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
 
+impl std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Webhook")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("url", &self.url)
+            .field("secret", &self.secret.as_ref().map(|_| "[REDACTED]"))
+            .field("event_types", &self.event_types)
+            .field("is_enabled", &self.is_enabled)
+            .field("created_by", &self.created_by)
+            .field("last_triggered_at", &self.last_triggered_at)
+            .field("failure_count", &self.failure_count)
+            .field("circuit_state", &self.circuit_state)
+            .field("consecutive_failures", &self.consecutive_failures)
+            .field("suspended_at", &self.suspended_at)
+            .field("next_probe_at", &self.next_probe_at)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
 /// DTO for creating a new webhook.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateWebhook {
@@ -165,10 +194,40 @@ pub struct WebhookDelivery {
     pub max_attempts: i16,
     pub next_retry_at: Option<Timestamp>,
     pub delivered_at: Option<Timestamp>,
+    /// Duration of the most recent attempt in milliseconds.
+    pub latency_ms: Option<i32>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
 
+/// Filter for a webhook's delivery history. Omitted fields match everything.
+#[derive(Debug, Default)]
+pub struct WebhookDeliveryFilter {
+    /// Only deliveries with this `status`.
+    pub status: Option<&'static str>,
+    /// Only deliveries created at or after this instant.
+    pub since: Option<Timestamp>,
+    /// Only deliveries created strictly before this instant.
+    pub until: Option<Timestamp>,
+}
+
+/// A row from the `webhook_dead_letters` table.
+///
+/// `webhook_id` and `delivery_id` are `None` for deliveries to an ad-hoc
+/// URL that is not a registered webhook.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct WebhookDeadLetter {
+    pub id: DbId,
+    pub webhook_id: Option<DbId>,
+    pub delivery_id: Option<DbId>,
+    pub url: String,
+    pub payload: serde_json::Value,
+    pub last_error: String,
+    pub attempt_count: i16,
+    pub replayed_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
 // ---------------------------------------------------------------------------
 // API Audit Log
 // ---------------------------------------------------------------------------
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::audit::{canonical_entry_data, ChainBreak, ChainResult, ChainRow};
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -28,6 +29,23 @@ pub struct AuditLog {
     pub created_at: Timestamp,
 }
 
+impl AuditLog {
+    /// Project this entry into the form consumed by the hash chain verifier.
+    pub fn to_chain_row(&self) -> ChainRow {
+        ChainRow {
+            id: self.id,
+            entry_data: canonical_entry_data(
+                &self.timestamp,
+                self.user_id,
+                &self.action_type,
+                self.entity_type.as_deref(),
+                self.entity_id,
+            ),
+            integrity_hash: self.integrity_hash.clone(),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Create DTO (batch-friendly)
 // ---------------------------------------------------------------------------
@@ -110,4 +128,17 @@ pub struct IntegrityCheckResult {
     pub chain_valid: bool,
     /// ID of the first entry where the chain breaks, if any.
     pub first_break: Option<DbId>,
+    /// Position and expected vs. stored hash of the first break, if any.
+    pub break_details: Option<ChainBreak>,
+}
+
+impl From<ChainResult> for IntegrityCheckResult {
+    fn from(result: ChainResult) -> Self {
+        Self {
+            verified_entries: result.verified_entries as i64,
+            chain_valid: result.is_valid(),
+            first_break: result.first_break.as_ref().map(|b| b.entry_id),
+            break_details: result.first_break,
+        }
+    }
 }
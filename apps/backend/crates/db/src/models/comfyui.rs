@@ -22,6 +22,8 @@ pub struct ComfyUIInstance {
     pub drain_mode: bool,
     pub metadata: serde_json::Value,
     pub cloud_instance_id: Option<DbId>,
+    /// Version reported by the server on its last connection (PRD-05).
+    pub comfyui_version: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
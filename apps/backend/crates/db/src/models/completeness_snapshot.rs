@@ -0,0 +1,29 @@
+//! Avatar completeness snapshot model (PRD-66).
+
+use serde::Serialize;
+use sqlx::FromRow;
+use x121_core::completeness_trend::CompletenessSnapshot;
+use x121_core::types::{DbId, Timestamp};
+
+/// A row from the `avatar_completeness_snapshots` table.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct AvatarCompletenessSnapshot {
+    pub id: DbId,
+    pub avatar_id: DbId,
+    pub project_id: DbId,
+    pub filled: i32,
+    pub total_required: i32,
+    pub percentage: f64,
+    pub recorded_at: Timestamp,
+}
+
+impl AvatarCompletenessSnapshot {
+    /// Convert to the core type consumed by the trend calculator.
+    pub fn to_snapshot(&self) -> CompletenessSnapshot {
+        CompletenessSnapshot {
+            avatar_id: self.avatar_id,
+            percentage: self.percentage,
+            recorded_at: self.recorded_at,
+        }
+    }
+}
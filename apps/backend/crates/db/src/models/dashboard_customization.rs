@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::dashboard_customization::WidgetInstance;
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -71,7 +72,8 @@ pub struct UpdateDashboardRoleDefault {
 /// Resolved effective dashboard layout for a user.
 #[derive(Debug, Clone, Serialize)]
 pub struct EffectiveDashboardResponse {
-    pub layout: serde_json::Value,
+    /// Widgets the user can access, ordered top-to-bottom, left-to-right.
+    pub layout: Vec<WidgetInstance>,
     pub widget_settings: serde_json::Value,
     /// Which priority level provided the layout: "preset", "role_default", or "platform_default".
     pub source: &'static str,
@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::duplicate_detection::BatchSkipReason;
 use x121_core::types::{DbId, Timestamp};
 
 use crate::models::status::StatusId;
@@ -57,6 +58,28 @@ pub struct DuplicateMatchResponse {
     pub similarity_score: f64,
 }
 
+/// Per-avatar result of a batch duplicate check.
+#[derive(Debug, Serialize)]
+pub struct BatchCheckItem {
+    pub avatar_id: DbId,
+    #[serde(flatten)]
+    pub outcome: BatchCheckOutcome,
+}
+
+/// What a batch check did for one avatar.
+#[derive(Debug, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum BatchCheckOutcome {
+    /// Compared against the rest of the batch. Holds the check records
+    /// involving this avatar: one per match, or a single `no_match` record.
+    Checked { checks: Vec<DuplicateCheck> },
+    /// Not compared; the rest of the batch is unaffected.
+    Skipped {
+        reason: BatchSkipReason,
+        message: &'static str,
+    },
+}
+
 // ---------------------------------------------------------------------------
 // Request types for API
 // ---------------------------------------------------------------------------
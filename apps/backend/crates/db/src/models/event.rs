@@ -27,3 +27,17 @@ pub struct Event {
     pub payload: serde_json::Value,
     pub created_at: Timestamp,
 }
+
+/// A row from the `events` table joined with its event type name.
+///
+/// Used to replay persisted events back into their bus representation.
+#[derive(Debug, Clone, FromRow)]
+pub struct EventWithTypeName {
+    pub id: DbId,
+    pub event_type: String,
+    pub source_entity_type: Option<String>,
+    pub source_entity_id: Option<DbId>,
+    pub actor_user_id: Option<DbId>,
+    pub payload: serde_json::Value,
+    pub created_at: Timestamp,
+}
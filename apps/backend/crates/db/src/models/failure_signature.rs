@@ -0,0 +1,56 @@
+//! Failure signature models and DTOs (PRD-64).
+//!
+//! Maps to the `failure_signatures` table, the configurable rules that
+//! classify job error text into known failure types.
+
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use x121_core::error::CoreError;
+use x121_core::failure_tracking::FailureSignature;
+use x121_core::types::{DbId, Timestamp};
+
+// ---------------------------------------------------------------------------
+// Entity
+// ---------------------------------------------------------------------------
+
+/// A row from the `failure_signatures` table.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct FailureSignatureRow {
+    pub id: DbId,
+    pub pattern_key: String,
+    pub description: Option<String>,
+    /// `substring` or `regex`.
+    pub kind: String,
+    pub pattern: String,
+    pub priority: i32,
+    pub is_enabled: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl FailureSignatureRow {
+    /// Convert to the core matcher's signature type.
+    pub fn to_signature(&self) -> Result<FailureSignature, CoreError> {
+        Ok(FailureSignature {
+            pattern_key: self.pattern_key.clone(),
+            kind: self.kind.parse()?,
+            pattern: self.pattern.clone(),
+            priority: self.priority,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Create DTO
+// ---------------------------------------------------------------------------
+
+/// DTO for creating a new failure signature.
+#[derive(Debug, Deserialize)]
+pub struct CreateFailureSignature {
+    pub pattern_key: String,
+    pub description: Option<String>,
+    pub kind: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub priority: i32,
+}
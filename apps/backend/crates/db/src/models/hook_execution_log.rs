@@ -19,6 +19,8 @@ pub struct HookExecutionLog {
     pub job_id: Option<DbId>,
     pub input_json: Option<serde_json::Value>,
     pub output_text: Option<String>,
+    pub output_truncated: bool,
+    pub output_original_len: Option<i64>,
     pub exit_code: Option<i32>,
     pub duration_ms: Option<i64>,
     pub success: bool,
@@ -37,6 +39,8 @@ pub struct CreateHookExecutionLog {
     pub job_id: Option<DbId>,
     pub input_json: Option<serde_json::Value>,
     pub output_text: Option<String>,
+    pub output_truncated: bool,
+    pub output_original_len: Option<i64>,
     pub exit_code: Option<i32>,
     pub duration_ms: Option<i64>,
     pub success: bool,
@@ -1,6 +1,7 @@
 //! Integrity scan models and DTOs (PRD-43).
 //!
-//! Maps to the `integrity_scans` table introduced in migration 000083.
+//! Maps to the `integrity_scans` table introduced in migration 000083 and
+//! its per-file `integrity_scan_files` results.
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -32,6 +33,21 @@ pub struct IntegrityScan {
     pub updated_at: Timestamp,
 }
 
+/// A row from the `integrity_scan_files` table: one file checked by a scan.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct IntegrityScanFile {
+    pub id: DbId,
+    pub scan_id: DbId,
+    pub file_path: String,
+    pub model_name: Option<String>,
+    /// One of the `FILE_STATUS_*` constants in `x121_core::integrity`.
+    pub status: String,
+    pub expected_hash: Option<String>,
+    pub actual_hash: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
 // ---------------------------------------------------------------------------
 // Create DTO
 // ---------------------------------------------------------------------------
@@ -59,3 +75,17 @@ pub struct UpdateIntegrityScanResults {
     pub nodes_missing: i32,
     pub completed_at: Option<Timestamp>,
 }
+
+// ---------------------------------------------------------------------------
+// Per-file result DTO
+// ---------------------------------------------------------------------------
+
+/// DTO for recording the result of checking one file during a scan.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateIntegrityScanFile {
+    pub file_path: String,
+    pub model_name: Option<String>,
+    pub status: String,
+    pub expected_hash: Option<String>,
+    pub actual_hash: Option<String>,
+}
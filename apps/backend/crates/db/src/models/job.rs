@@ -47,6 +47,8 @@ pub struct Job {
     pub comfyui_instance_id: Option<DbId>,
     /// How many times this job was reset to pending due to instance death.
     pub orphan_retry_count: i16,
+    /// Failure signature matched by the error message (PRD-64).
+    pub failure_pattern_key: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
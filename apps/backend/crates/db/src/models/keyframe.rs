@@ -20,6 +20,9 @@ pub struct Keyframe {
     pub timestamp_secs: f64,
     pub thumbnail_path: String,
     pub full_res_path: Option<String>,
+    /// SHA-256 of the source frame content; keys the thumbnail cache.
+    /// `None` for keyframes created before content hashing.
+    pub source_hash: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
@@ -36,6 +39,7 @@ pub struct CreateKeyframe {
     pub timestamp_secs: f64,
     pub thumbnail_path: String,
     pub full_res_path: Option<String>,
+    pub source_hash: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
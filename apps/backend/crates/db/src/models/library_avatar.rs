@@ -75,6 +75,18 @@ pub struct CreateProjectAvatarLink {
     pub linked_fields: Option<serde_json::Value>,
 }
 
+/// Outcome of [`LibraryAvatarRepo::delete`](crate::repositories::LibraryAvatarRepo::delete).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryAvatarDeletion {
+    /// The library avatar was deleted, along with `unlinked` project links
+    /// when cascading.
+    Deleted { unlinked: u64 },
+    /// No library avatar with that ID exists.
+    NotFound,
+    /// Deletion was refused because projects still link to the avatar.
+    HasLinks { active_links: i64 },
+}
+
 /* --------------------------------------------------------------------------
 Import Request (handler-level DTO)
 -------------------------------------------------------------------------- */
@@ -35,6 +35,7 @@ pub mod checkpoint;
 pub mod cloud_provider;
 pub mod collaboration;
 pub mod comfyui;
+pub mod completeness_snapshot;
 pub mod compliance;
 pub mod consistency_report;
 pub mod contact_sheet;
@@ -51,6 +52,7 @@ pub mod event;
 pub mod export_job;
 pub mod extension;
 pub mod failure_pattern;
+pub mod failure_signature;
 pub mod frame_annotation;
 pub mod generation;
 pub mod generation_metric;
@@ -41,10 +41,55 @@ pub struct UserNotificationSettings {
     pub digest_enabled: bool,
     pub digest_interval: String,
     pub digest_last_sent_at: Option<Timestamp>,
+    /// IANA timezone digests are scheduled in (e.g. `Europe/Berlin`).
+    pub timezone: String,
+    /// Local hour (0-23) digests are sent at.
+    pub digest_hour: i16,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
 
+/// A row from the `email_dead_letters` table: a notification email whose
+/// retries were exhausted.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct EmailDeadLetter {
+    pub id: DbId,
+    pub to_address: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub last_error: String,
+    pub attempt_count: i16,
+    /// Every failed attempt, oldest first.
+    pub attempts: serde_json::Value,
+    pub created_at: Timestamp,
+}
+
+/// DTO for recording an exhausted notification email.
+#[derive(Debug)]
+pub struct CreateEmailDeadLetter {
+    pub to_address: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub last_error: String,
+    pub attempt_count: i16,
+    pub attempts: serde_json::Value,
+}
+
+/// Filter for bulk-marking notifications read.
+///
+/// Omitted fields match everything, so an empty filter marks all of the
+/// user's unread notifications.
+#[derive(Debug, Default, Deserialize)]
+pub struct MarkReadFilter {
+    /// Only notifications whose event belongs to this project: either the
+    /// event's source is the project itself or its payload has `project_id`.
+    pub project_id: Option<DbId>,
+    /// Only notifications for this event type name (e.g. `job.completed`).
+    pub event_type: Option<String>,
+    /// Only notifications created strictly before this instant.
+    pub before: Option<Timestamp>,
+}
+
 /// DTO for updating a notification preference.
 #[derive(Debug, Deserialize)]
 pub struct UpdatePreference {
@@ -60,4 +105,6 @@ pub struct UpdateNotificationSettings {
     pub dnd_until: Option<Timestamp>,
     pub digest_enabled: Option<bool>,
     pub digest_interval: Option<String>,
+    pub timezone: Option<String>,
+    pub digest_hour: Option<i16>,
 }
@@ -18,6 +18,21 @@ pub struct OnboardingSession {
     pub updated_at: Timestamp,
 }
 
+/// A row from the `onboarding_session_events` table: one wizard transition.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct OnboardingSessionEvent {
+    pub id: DbId,
+    pub session_id: DbId,
+    /// `advance`, `go_back`, or `step_data`.
+    pub event_type: String,
+    pub from_step: i32,
+    pub to_step: i32,
+    /// Submitted payload for `step_data` events; `None` for navigation.
+    pub step_data: Option<serde_json::Value>,
+    pub actor_id: Option<DbId>,
+    pub created_at: Timestamp,
+}
+
 /// DTO for creating a new onboarding session.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateOnboardingSession {
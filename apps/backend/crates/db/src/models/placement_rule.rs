@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::download_manager::PlacementRuleSpec;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `placement_rules` table.
@@ -17,6 +18,18 @@ pub struct PlacementRule {
     pub updated_at: Timestamp,
 }
 
+impl PlacementRule {
+    /// Convert to the core rule used for validation and placement.
+    pub fn to_spec(&self) -> PlacementRuleSpec {
+        PlacementRuleSpec {
+            model_type: self.model_type.clone(),
+            base_model: self.base_model.clone(),
+            target_directory: self.target_directory.clone(),
+            priority: self.priority,
+        }
+    }
+}
+
 /// DTO for creating a new placement rule.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreatePlacementRule {
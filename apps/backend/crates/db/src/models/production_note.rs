@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::production_notes::ThreadedNote;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `production_notes` table.
@@ -22,6 +23,20 @@ pub struct ProductionNote {
     pub updated_at: Timestamp,
 }
 
+impl ThreadedNote for ProductionNote {
+    fn id(&self) -> DbId {
+        self.id
+    }
+
+    fn parent_note_id(&self) -> Option<DbId> {
+        self.parent_note_id
+    }
+
+    fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+}
+
 /// DTO for creating a new production note.
 #[derive(Debug, Deserialize)]
 pub struct CreateProductionNote {
@@ -42,8 +57,31 @@ pub struct UpdateProductionNote {
 }
 
 /// Query parameters for note search.
-#[derive(Debug, Deserialize)]
+///
+/// Every filter is optional and they combine with AND; `q` is a web-style
+/// full-text query over the note content.
+#[derive(Debug, Default, Deserialize)]
 pub struct NoteSearchParams {
-    pub q: String,
+    pub q: Option<String>,
     pub entity_type: Option<String>,
+    pub entity_id: Option<DbId>,
+    pub category_id: Option<DbId>,
+    /// Author of the note.
+    pub user_id: Option<DbId>,
+    pub resolved: Option<bool>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// A note matched by search, with its relevance and a content snippet.
+///
+/// `rank` and `snippet` are `None` when the search had no text query.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct NoteSearchResult {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub note: ProductionNote,
+    pub rank: Option<f32>,
+    /// Content excerpt with matched terms wrapped in `<b>` tags.
+    pub snippet: Option<String>,
 }
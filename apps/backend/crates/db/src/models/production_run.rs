@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::batch_production::MatrixCellKey;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `production_runs` table.
@@ -107,6 +108,9 @@ pub struct CreateProductionRunRequest {
     /// When true, check for existing approved scenes and pre-mark those cells as completed.
     #[serde(default)]
     pub retrospective: bool,
+    /// Cells to leave out of the run.
+    #[serde(default)]
+    pub excluded_cells: Vec<MatrixCellKey>,
 }
 
 /// Request body for submitting cells (all or a subset).
@@ -23,6 +23,7 @@ pub struct PromptLibraryEntry {
     pub model_compatibility: Option<Vec<String>>,
     pub usage_count: i32,
     pub avg_rating: Option<f64>,
+    pub rating_count: i32,
     pub owner_id: DbId,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
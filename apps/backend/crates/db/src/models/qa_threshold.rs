@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::quality_gate::GateThreshold;
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -23,6 +24,18 @@ pub struct QaThreshold {
     pub updated_at: Timestamp,
 }
 
+impl QaThreshold {
+    /// Convert to the core threshold used for gate decisions.
+    pub fn to_spec(&self) -> GateThreshold {
+        GateThreshold {
+            check_type: self.check_type.clone(),
+            warn: self.warn_threshold,
+            fail: self.fail_threshold,
+            is_enabled: self.is_enabled,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Create / Update DTOs
 // ---------------------------------------------------------------------------
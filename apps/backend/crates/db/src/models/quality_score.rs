@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::quality_gate::GateScore;
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -24,6 +25,16 @@ pub struct QualityScore {
     pub updated_at: Timestamp,
 }
 
+impl QualityScore {
+    /// Convert to the core score used for gate decisions.
+    pub fn to_spec(&self) -> GateScore {
+        GateScore {
+            check_type: self.check_type.clone(),
+            score: self.score,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Create DTO
 // ---------------------------------------------------------------------------
@@ -22,3 +22,15 @@ pub struct UpsertReadinessCache {
     pub missing_items: serde_json::Value,
     pub readiness_pct: i32,
 }
+
+/// Per-avatar inputs to readiness evaluation, loaded in bulk for cache rebuilds.
+#[derive(Debug, Clone, FromRow)]
+pub struct AvatarReadinessInputs {
+    pub avatar_id: DbId,
+    pub project_id: DbId,
+    pub metadata: Option<serde_json::Value>,
+    pub settings: serde_json::Value,
+    pub has_source_media: bool,
+    pub has_approved_variant: bool,
+    pub has_metadata_approved: bool,
+}
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::command_palette::{MatchSpan, PaletteItemKind, RecentlyAccessed};
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `user_recent_items` table.
@@ -17,6 +18,16 @@ pub struct UserRecentItem {
     pub updated_at: Timestamp,
 }
 
+impl RecentlyAccessed for UserRecentItem {
+    fn access_count(&self) -> i32 {
+        self.access_count
+    }
+
+    fn last_accessed_at(&self) -> Timestamp {
+        self.last_accessed_at
+    }
+}
+
 /// DTO for recording an entity access.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RecordAccessRequest {
@@ -30,3 +41,15 @@ pub struct PaletteSearchParams {
     pub q: Option<String>,
     pub limit: Option<i32>,
 }
+
+/// A ranked command palette search result.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaletteSearchResult {
+    pub kind: PaletteItemKind,
+    pub entity_type: String,
+    pub entity_id: DbId,
+    pub name: String,
+    pub score: f64,
+    /// Character ranges of `name` matched by the query, for highlighting.
+    pub match_spans: Vec<MatchSpan>,
+}
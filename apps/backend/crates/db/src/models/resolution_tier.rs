@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::resolution::UpscalePlan;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `resolution_tiers` table.
@@ -45,4 +46,42 @@ pub struct UpscaleResponse {
     pub original_scene_id: DbId,
     pub new_scene_id: DbId,
     pub target_tier: String,
+    /// The tier hops applied (empty if the scene was already at the target).
+    pub plan: UpscalePlan,
+}
+
+/// Request body for upscaling several scenes to the same tier.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchUpscaleRequest {
+    pub scene_ids: Vec<DbId>,
+    pub target_tier_id: DbId,
+}
+
+/// Whether a scene in a batch upscale was upscaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchUpscaleOutcome {
+    Accepted,
+    Rejected,
+}
+
+/// Per-scene result of a batch upscale.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchUpscaleItem {
+    pub scene_id: DbId,
+    pub outcome: BatchUpscaleOutcome,
+    /// Why the scene was rejected (`None` when accepted).
+    pub reason: Option<String>,
+    /// The tier hops applied (`None` when rejected).
+    pub plan: Option<UpscalePlan>,
+}
+
+/// Response payload for a batch upscale.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchUpscaleResponse {
+    pub target_tier_id: DbId,
+    pub target_tier: String,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub results: Vec<BatchUpscaleItem>,
 }
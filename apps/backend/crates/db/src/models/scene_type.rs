@@ -6,6 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::scene_type_config::SceneTypeSource;
 use x121_core::types::{DbId, Timestamp};
 
 use crate::models::status::StatusId;
@@ -205,6 +206,10 @@ pub struct PromptPreviewResponse {
     pub negative_prompt: String,
     pub unresolved_placeholders: Vec<String>,
     pub source: String,
+    /// Where the positive template came from, if there is one.
+    pub positive_source: Option<SceneTypeSource>,
+    /// Where the negative template came from, if there is one.
+    pub negative_source: Option<SceneTypeSource>,
 }
 
 /// Validation result for a scene type configuration.
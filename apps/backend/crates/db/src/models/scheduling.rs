@@ -47,6 +47,12 @@ pub struct GpuQuota {
     pub daily_limit_secs: Option<i32>,
     pub weekly_limit_secs: Option<i32>,
     pub is_enabled: bool,
+    /// IANA timezone whose local midnight starts each quota period.
+    pub timezone: String,
+    /// Start of the current daily usage window.
+    pub daily_reset_at: Timestamp,
+    /// Start of the current weekly usage window.
+    pub weekly_reset_at: Timestamp,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
@@ -58,6 +64,9 @@ pub struct SetGpuQuota {
     pub weekly_limit_secs: Option<i32>,
     #[serde(default = "default_true")]
     pub is_enabled: bool,
+    /// IANA timezone for period resets. Keeps the current value (or `UTC`
+    /// for a new quota) when omitted.
+    pub timezone: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -94,6 +103,10 @@ pub enum QuotaStatus {
         daily_limit_secs: Option<i32>,
         used_this_week_secs: i64,
         weekly_limit_secs: Option<i32>,
+        /// When daily usage next starts over.
+        daily_resets_at: Timestamp,
+        /// When weekly usage next starts over.
+        weekly_resets_at: Timestamp,
     },
     /// Quota exceeded.
     #[serde(rename = "exceeded")]
@@ -102,6 +115,8 @@ pub enum QuotaStatus {
         daily_limit_secs: Option<i32>,
         used_this_week_secs: i64,
         weekly_limit_secs: Option<i32>,
+        daily_resets_at: Timestamp,
+        weekly_resets_at: Timestamp,
         exceeded_type: String,
     },
 }
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::scripting::status::ExecutionOutcome;
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -128,6 +129,42 @@ pub struct ScriptExecution {
     pub updated_at: Timestamp,
 }
 
+/// A script execution history row: outcome, exit code, and timing without
+/// the captured I/O (see [`ScriptExecution`] for the full record).
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ScriptExecutionSummary {
+    pub id: DbId,
+    pub script_id: DbId,
+    pub job_id: Option<DbId>,
+    pub triggered_by: Option<DbId>,
+    pub status_id: i16,
+    /// Joined from `execution_statuses.name`.
+    pub status_name: String,
+    pub exit_code: Option<i32>,
+    pub duration_ms: Option<i32>,
+    pub error_message: Option<String>,
+    pub started_at: Option<Timestamp>,
+    pub completed_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
+/// Filters for listing a script's execution history.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptExecutionFilter {
+    pub outcome: Option<ExecutionOutcome>,
+    /// Inclusive lower bound on `created_at`.
+    pub from: Option<Timestamp>,
+    /// Inclusive upper bound on `created_at`.
+    pub to: Option<Timestamp>,
+}
+
+/// One page of execution history with the total matching count.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptExecutionPage {
+    pub items: Vec<ScriptExecutionSummary>,
+    pub total: i64,
+}
+
 /// DTO for creating a new execution record.
 #[derive(Debug, Clone)]
 pub struct CreateScriptExecution {
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::trimming::{BatchTrimStatus, BatchTrimSummary, SeedImpactReport};
 use x121_core::types::{DbId, Timestamp};
 
 // ---------------------------------------------------------------------------
@@ -51,6 +52,10 @@ pub struct BatchTrimRequest {
     pub segment_ids: Vec<DbId>,
     pub in_frame: i32,
     pub out_frame: i32,
+    /// Untrimmed frame count of the segments (default: `out_frame`).
+    pub total_frames: Option<i32>,
+    /// Segments trimmed at once (default: 4, max: 16).
+    pub concurrency: Option<usize>,
 }
 
 /// Request body for applying a quick trim preset to a segment.
@@ -68,8 +73,25 @@ pub struct ApplyPresetRequest {
 /// Response returned after a batch trim operation.
 #[derive(Debug, Clone, Serialize)]
 pub struct BatchTrimResponse {
+    /// IDs of the trims created, in request order.
     pub trim_ids: Vec<DbId>,
     pub count: usize,
+    /// Outcome for every requested segment, in request order.
+    pub results: Vec<BatchTrimResult>,
+    pub summary: BatchTrimSummary,
+}
+
+/// Outcome of one segment within a batch trim.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchTrimResult {
+    pub segment_id: DbId,
+    pub status: BatchTrimStatus,
+    /// The created trim, when the status is `trimmed`.
+    pub trim_id: Option<DbId>,
+    /// Why the segment was skipped or failed.
+    pub reason: Option<String>,
+    /// Seed impact checked before applying the trim, if it could be computed.
+    pub impact: Option<SeedImpactReport>,
 }
 
 /// Response describing the seed frame impact of a trim on downstream segments.
@@ -79,4 +101,6 @@ pub struct SeedFrameUpdate {
     pub new_seed_frame: i32,
     pub downstream_segment_id: Option<DbId>,
     pub downstream_invalidated: bool,
+    /// Full impact report; `None` when there is no trim to evaluate.
+    pub impact: Option<SeedImpactReport>,
 }
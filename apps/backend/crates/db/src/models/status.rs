@@ -206,6 +206,8 @@ define_status_enum! {
         FindReplace = 1,
         Repath = 2,
         BatchUpdate = 3,
+        RebuildCaches = 4,
+        ReindexWiki = 5,
     }
 }
 
@@ -217,6 +219,7 @@ define_status_enum! {
         Completed = 3,
         Failed = 4,
         Undone = 5,
+        Cancelled = 6,
     }
 }
 
@@ -382,6 +385,8 @@ mod tests {
         assert_eq!(BulkOperationTypeId::FindReplace.id(), 1);
         assert_eq!(BulkOperationTypeId::Repath.id(), 2);
         assert_eq!(BulkOperationTypeId::BatchUpdate.id(), 3);
+        assert_eq!(BulkOperationTypeId::RebuildCaches.id(), 4);
+        assert_eq!(BulkOperationTypeId::ReindexWiki.id(), 5);
     }
 
     #[test]
@@ -391,6 +396,7 @@ mod tests {
         assert_eq!(BulkOperationStatusId::Completed.id(), 3);
         assert_eq!(BulkOperationStatusId::Failed.id(), 4);
         assert_eq!(BulkOperationStatusId::Undone.id(), 5);
+        assert_eq!(BulkOperationStatusId::Cancelled.id(), 6);
     }
 
     #[test]
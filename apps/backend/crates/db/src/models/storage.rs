@@ -172,16 +172,20 @@ pub struct UpdateTieringPolicy {
     pub is_active: Option<bool>,
 }
 
-/// Result DTO for tiering simulation: an asset that would be moved.
+/// An asset location with the attributes tiering policies match on.
 #[derive(Debug, Clone, FromRow, Serialize)]
-pub struct TieringCandidate {
+pub struct AssetPlacement {
     pub entity_type: String,
     pub entity_id: DbId,
     pub file_field: String,
     pub file_size_bytes: i64,
-    pub current_backend_id: DbId,
-    pub last_accessed_at: Option<Timestamp>,
-    pub access_count: i32,
+    pub backend_id: DbId,
+    /// Tier of the backend holding the asset.
+    pub tier: String,
+    /// Whole days since the asset was stored.
+    pub age_days: i64,
+    /// Whole days since the asset was last accessed; `None` if never.
+    pub idle_days: Option<i64>,
 }
 
 // ---------------------------------------------------------------------------
@@ -30,6 +30,8 @@ pub struct UserThemePreference {
     pub brand_palette: String,
     pub high_contrast: bool,
     pub custom_theme_id: Option<DbId>,
+    /// Tokens overriding the studio theme, in the same shape as its tokens.
+    pub token_overrides: serde_json::Value,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
@@ -58,6 +60,8 @@ pub struct UpsertThemePreference {
     pub brand_palette: String,
     pub high_contrast: bool,
     pub custom_theme_id: Option<DbId>,
+    /// Replaces the stored overrides when present; kept otherwise.
+    pub token_overrides: Option<serde_json::Value>,
 }
 
 /// DTO for creating a new custom theme.
@@ -17,6 +17,27 @@ pub struct UndoTree {
     pub updated_at: Timestamp,
 }
 
+/// A lightweight projection of an undo tree for listing, without the tree
+/// itself.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct UndoTreeSummary {
+    pub id: DbId,
+    pub entity_type: String,
+    pub entity_id: DbId,
+    /// Number of entries in the tree's `nodes` object.
+    pub node_count: i64,
+    pub current_node_id: Option<String>,
+    pub updated_at: Timestamp,
+}
+
+/// Query parameters for listing a user's undo trees.
+#[derive(Debug, Deserialize)]
+pub struct UndoTreeListParams {
+    pub entity_type: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 /// DTO for saving (upserting) an undo tree.
 #[derive(Debug, Deserialize)]
 pub struct SaveUndoTree {
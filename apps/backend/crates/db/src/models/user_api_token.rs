@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::crypto::EncryptedToken;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `user_api_tokens` table.
@@ -13,6 +14,9 @@ pub struct UserApiToken {
     /// Encrypted token bytes. Skipped during serialization to prevent exposure.
     #[serde(skip_serializing)]
     pub encrypted_token: Vec<u8>,
+    /// ID of the key that encrypted the token; `None` for cleared legacy rows.
+    #[serde(skip_serializing)]
+    pub encryption_key_id: Option<String>,
     pub token_hint: String,
     pub is_valid: bool,
     pub last_used_at: Option<Timestamp>,
@@ -20,6 +24,20 @@ pub struct UserApiToken {
     pub updated_at: Timestamp,
 }
 
+impl UserApiToken {
+    /// The stored token as the core encryption helpers expect it.
+    ///
+    /// Returns `None` for legacy rows that were never encrypted.
+    pub fn to_encrypted(&self) -> Option<EncryptedToken> {
+        self.encryption_key_id
+            .as_ref()
+            .map(|key_id| EncryptedToken {
+                key_id: key_id.clone(),
+                ciphertext: self.encrypted_token.clone(),
+            })
+    }
+}
+
 /// Safe API-facing token info (never exposes the encrypted token).
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct ApiTokenInfo {
@@ -88,6 +88,10 @@ pub struct ImportReport {
     pub skipped: i32,
     pub report_data: serde_json::Value,
     pub created_by: Option<DbId>,
+    /// Entries applied so far by a (possibly interrupted) commit.
+    pub committed_records: i32,
+    /// Error that interrupted the most recent commit attempt, if any.
+    pub last_commit_error: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
@@ -122,6 +126,9 @@ pub struct ImportReportEntry {
     pub field_warnings: serde_json::Value,
     pub field_diffs: serde_json::Value,
     pub conflict_resolutions: serde_json::Value,
+    /// `EntityAction` applied at commit; `None` while the entry is pending.
+    pub outcome: Option<String>,
+    pub committed_at: Option<Timestamp>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
 }
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use x121_core::assembly::WatermarkSpec;
 use x121_core::types::{DbId, Timestamp};
 
 /// A row from the `watermark_settings` table.
@@ -18,6 +19,20 @@ pub struct WatermarkSetting {
     pub updated_at: Timestamp,
 }
 
+impl WatermarkSetting {
+    /// Convert to the core watermark parameters used for resolution.
+    pub fn to_spec(&self) -> WatermarkSpec {
+        WatermarkSpec {
+            name: self.name.clone(),
+            watermark_type: self.watermark_type.clone(),
+            content: self.content.clone(),
+            position: self.position.clone(),
+            opacity: self.opacity,
+            include_timecode: self.include_timecode,
+        }
+    }
+}
+
 /// DTO for creating a new watermark setting.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateWatermarkSetting {
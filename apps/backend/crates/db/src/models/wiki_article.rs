@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use x121_core::types::{DbId, Timestamp};
 
+use crate::models::wiki_version::WikiVersion;
+
 /// A row from the `wiki_articles` table.
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct WikiArticle {
@@ -46,6 +48,14 @@ pub struct UpdateWikiArticle {
     pub edit_summary: Option<String>,
 }
 
+/// Result of reverting an article: the updated article and the version the
+/// revert appended.
+#[derive(Debug, Serialize)]
+pub struct WikiRevertResult {
+    pub article: WikiArticle,
+    pub version: WikiVersion,
+}
+
 /// Query params for comparing two article versions.
 #[derive(Debug, Deserialize)]
 pub struct DiffRequest {
@@ -70,6 +80,38 @@ pub struct DiffLineDto {
     pub content: String,
 }
 
+/// Response for a word-level version diff.
+#[derive(Debug, Serialize)]
+pub struct WordDiffResponse {
+    pub article_id: DbId,
+    pub slug: String,
+    pub v1: i32,
+    pub v2: i32,
+    /// Set when both versions recorded a title and the titles differ.
+    pub title_change: Option<TitleChangeDto>,
+    /// Editor of version `v2`.
+    pub author: Option<DbId>,
+    /// Edit summary of version `v2`.
+    pub edit_summary: Option<String>,
+    pub words_added: usize,
+    pub words_removed: usize,
+    pub spans: Vec<DiffSpanDto>,
+}
+
+/// A run of words in a word diff response (serializable).
+#[derive(Debug, Serialize)]
+pub struct DiffSpanDto {
+    pub span_type: String,
+    pub text: String,
+}
+
+/// A title change between two versions (serializable).
+#[derive(Debug, Serialize)]
+pub struct TitleChangeDto {
+    pub from: String,
+    pub to: String,
+}
+
 /// Response for contextual help lookups.
 #[derive(Debug, Serialize)]
 pub struct ContextualHelpResponse {
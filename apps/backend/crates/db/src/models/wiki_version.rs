@@ -1,6 +1,7 @@
 //! Wiki article version model (PRD-56).
 //!
-//! Versions are immutable snapshots of article content, created on every edit.
+//! Versions are immutable snapshots of article title and content, created on
+//! every edit to either.
 
 use serde::Serialize;
 use sqlx::FromRow;
@@ -12,8 +13,14 @@ pub struct WikiVersion {
     pub id: DbId,
     pub article_id: DbId,
     pub version: i32,
+    /// `None` for versions recorded before titles were snapshotted.
+    pub title: Option<String>,
     pub content_md: String,
     pub edited_by: Option<DbId>,
     pub edit_summary: Option<String>,
+    /// For a revert, the version that was current when it was made.
+    pub reverted_from_version: Option<i32>,
+    /// For a revert, the version whose title and content it restored.
+    pub reverted_to_version: Option<i32>,
     pub created_at: Timestamp,
 }
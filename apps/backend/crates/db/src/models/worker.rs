@@ -84,19 +84,3 @@ pub struct CreateHealthLogEntry {
     pub to_status_id: StatusId,
     pub reason: Option<String>,
 }
-
-// ---------------------------------------------------------------------------
-// Aggregate DTOs
-// ---------------------------------------------------------------------------
-
-/// Aggregate fleet statistics.
-#[derive(Debug, Clone, FromRow, Serialize)]
-pub struct FleetStats {
-    pub total_workers: i64,
-    pub idle_workers: i64,
-    pub busy_workers: i64,
-    pub offline_workers: i64,
-    pub draining_workers: i64,
-    pub approved_workers: i64,
-    pub enabled_workers: i64,
-}
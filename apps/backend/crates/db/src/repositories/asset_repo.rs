@@ -4,6 +4,7 @@
 //! and ratings.
 
 use sqlx::PgPool;
+use x121_core::assets::dependencies::ASSET_ENTITY_TYPE;
 use x121_core::types::DbId;
 
 use crate::models::asset::{
@@ -274,6 +275,32 @@ impl AssetRepo {
             .await
     }
 
+    /// Get all dependency links for any of the given assets.
+    pub async fn get_dependents_for_assets(
+        pool: &PgPool,
+        asset_ids: &[DbId],
+    ) -> Result<Vec<AssetDependency>, sqlx::Error> {
+        let query = format!(
+            "SELECT {DEP_COLUMNS} FROM asset_dependencies \
+             WHERE asset_id = ANY($1) ORDER BY created_at"
+        );
+        sqlx::query_as::<_, AssetDependency>(&query)
+            .bind(asset_ids)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// List every asset-to-asset dependency edge as `(asset_id, dependent_asset_id)`.
+    pub async fn list_asset_edges(pool: &PgPool) -> Result<Vec<(DbId, DbId)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT asset_id, dependent_entity_id FROM asset_dependencies \
+             WHERE dependent_entity_type = $1",
+        )
+        .bind(ASSET_ENTITY_TYPE)
+        .fetch_all(pool)
+        .await
+    }
+
     /// Get all assets linked to a specific entity.
     pub async fn get_entity_assets(
         pool: &PgPool,
@@ -188,6 +188,28 @@ impl AuditLogRepo {
             (None, None) => sqlx::query_as::<_, AuditLog>(&query).fetch_all(pool).await,
         }
     }
+
+    /// Fetch the next page of entries after `after_id` for chunked integrity
+    /// verification.
+    ///
+    /// Keyset-paginated on `id` so each page is an index range scan regardless
+    /// of how far into the log the check has progressed.
+    pub async fn fetch_integrity_batch(
+        pool: &PgPool,
+        after_id: Option<DbId>,
+        limit: i64,
+    ) -> Result<Vec<AuditLog>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM audit_logs \
+             WHERE ($1::BIGINT IS NULL OR id > $1) \
+             ORDER BY id ASC LIMIT $2"
+        );
+        sqlx::query_as::<_, AuditLog>(&query)
+            .bind(after_id)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -1,7 +1,7 @@
 //! Repository for the `branches` table (PRD-50).
 
 use sqlx::PgPool;
-use x121_core::types::DbId;
+use x121_core::types::{DbId, Timestamp};
 
 use crate::models::branch::{Branch, CreateBranch, UpdateBranch};
 
@@ -159,6 +159,22 @@ impl BranchRepo {
         Ok(row.0)
     }
 
+    /// List `(sequence_index, updated_at)` for the live segments of a branch,
+    /// used for pre-promotion divergence checks.
+    pub async fn list_segment_states(
+        pool: &PgPool,
+        branch_id: DbId,
+    ) -> Result<Vec<(i32, Timestamp)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT sequence_index, updated_at FROM segments
+             WHERE branch_id = $1 AND deleted_at IS NULL
+             ORDER BY sequence_index ASC",
+        )
+        .bind(branch_id)
+        .fetch_all(pool)
+        .await
+    }
+
     /// List stale branches (not updated in `older_than_days` days, not default).
     pub async fn list_stale_branches(
         pool: &PgPool,
@@ -1,6 +1,7 @@
 //! Repository for the `bulk_operations` table (PRD-18).
 
 use sqlx::PgPool;
+use x121_core::maintenance::SearchableField;
 use x121_core::types::{DbId, Timestamp};
 
 use crate::models::bulk_operation::{BulkOperation, CreateBulkOperation};
@@ -64,6 +65,70 @@ impl BulkOperationRepo {
             .await
     }
 
+    /// Move an operation to `to_status_id` only if it is still in
+    /// `from_status_id`. Returns `None` if the operation has already moved on.
+    pub async fn transition_status(
+        pool: &PgPool,
+        id: DbId,
+        from_status_id: StatusId,
+        to_status_id: StatusId,
+    ) -> Result<Option<BulkOperation>, sqlx::Error> {
+        let query = format!(
+            "UPDATE bulk_operations SET status_id = $3 \
+             WHERE id = $1 AND status_id = $2 \
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, BulkOperation>(&query)
+            .bind(id)
+            .bind(from_status_id)
+            .bind(to_status_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Record the final status of a background operation only if it is
+    /// still in `from_status_id`, so a concurrent cancellation is not
+    /// overwritten. Returns `None` if the operation has already moved on.
+    pub async fn finish_execution(
+        pool: &PgPool,
+        id: DbId,
+        from_status_id: StatusId,
+        to_status_id: StatusId,
+        affected_count: i32,
+        executed_by: Option<DbId>,
+        executed_at: Option<Timestamp>,
+    ) -> Result<Option<BulkOperation>, sqlx::Error> {
+        let query = format!(
+            "UPDATE bulk_operations \
+             SET status_id = $3, affected_count = $4, executed_by = $5, executed_at = $6 \
+             WHERE id = $1 AND status_id = $2 \
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, BulkOperation>(&query)
+            .bind(id)
+            .bind(from_status_id)
+            .bind(to_status_id)
+            .bind(affected_count)
+            .bind(executed_by)
+            .bind(executed_at)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Record in-flight progress for a long-running operation.
+    pub async fn update_affected_count(
+        pool: &PgPool,
+        id: DbId,
+        affected_count: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE bulk_operations SET affected_count = $2 WHERE id = $1")
+            .bind(id)
+            .bind(affected_count)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     /// Update execution results (status, affected count, undo data, executed_by/at).
     pub async fn update_execution(
         pool: &PgPool,
@@ -197,4 +262,27 @@ impl BulkOperationRepo {
             .fetch_one(pool)
             .await
     }
+
+    /// Load `(id, value)` pairs for a find/replace field, lowest id first.
+    ///
+    /// The table and column come from the static find/replace allow-list
+    /// ([`SearchableField`]), so interpolating them is safe.
+    pub async fn scan_text_field(
+        pool: &PgPool,
+        field: &SearchableField,
+        limit: i64,
+    ) -> Result<Vec<(DbId, String)>, sqlx::Error> {
+        let query = format!(
+            "SELECT id, {column} FROM {table} \
+             WHERE {column} IS NOT NULL \
+             ORDER BY id \
+             LIMIT $1",
+            column = field.column_name,
+            table = field.table_name,
+        );
+        sqlx::query_as::<_, (DbId, String)>(&query)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
 }
@@ -10,7 +10,8 @@ use crate::models::status::JobStatus;
 const COLUMNS: &str = "\
     id, name, ws_url, api_url, status_id, \
     last_connected_at, last_disconnected_at, reconnect_attempts, \
-    is_enabled, drain_mode, metadata, cloud_instance_id, created_at, updated_at";
+    is_enabled, drain_mode, metadata, cloud_instance_id, comfyui_version, \
+    created_at, updated_at";
 
 /// Column list for `comfyui_instance_statuses` queries.
 const STATUS_COLUMNS: &str = "id, name, description, created_at, updated_at";
@@ -120,6 +121,20 @@ impl ComfyUIInstanceRepo {
         Ok(())
     }
 
+    /// Store the ComfyUI version reported by an instance on connect.
+    pub async fn record_version(
+        pool: &PgPool,
+        id: DbId,
+        version: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE comfyui_instances SET comfyui_version = $2 WHERE id = $1")
+            .bind(id)
+            .bind(version)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     /// Record a disconnection (sets status to disconnected and `last_disconnected_at`).
     pub async fn record_disconnection(pool: &PgPool, id: DbId) -> Result<(), sqlx::Error> {
         sqlx::query(
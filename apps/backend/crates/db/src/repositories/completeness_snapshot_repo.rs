@@ -0,0 +1,64 @@
+//! Repository for the `avatar_completeness_snapshots` table (PRD-66).
+
+use sqlx::PgPool;
+use x121_core::types::DbId;
+
+use crate::models::completeness_snapshot::AvatarCompletenessSnapshot;
+
+/// Column list for `avatar_completeness_snapshots` queries.
+const COLUMNS: &str = "id, avatar_id, project_id, filled, total_required, percentage, recorded_at";
+
+/// Provides append and read operations for completeness snapshots.
+pub struct CompletenessSnapshotRepo;
+
+impl CompletenessSnapshotRepo {
+    /// Record a snapshot unless the avatar's latest one has the same percentage.
+    ///
+    /// Returns `None` when nothing changed and no row was written.
+    pub async fn record_if_changed(
+        pool: &PgPool,
+        avatar_id: DbId,
+        project_id: DbId,
+        filled: i32,
+        total_required: i32,
+        percentage: f64,
+    ) -> Result<Option<AvatarCompletenessSnapshot>, sqlx::Error> {
+        let query = format!(
+            "INSERT INTO avatar_completeness_snapshots \
+                 (avatar_id, project_id, filled, total_required, percentage) \
+             SELECT $1, $2, $3, $4, $5 \
+             WHERE NOT EXISTS ( \
+                 SELECT 1 FROM ( \
+                     SELECT percentage FROM avatar_completeness_snapshots \
+                     WHERE avatar_id = $1 \
+                     ORDER BY recorded_at DESC, id DESC LIMIT 1 \
+                 ) latest WHERE latest.percentage = $5 \
+             ) \
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, AvatarCompletenessSnapshot>(&query)
+            .bind(avatar_id)
+            .bind(project_id)
+            .bind(filled)
+            .bind(total_required)
+            .bind(percentage)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// List all snapshots for a project, oldest first.
+    pub async fn list_by_project(
+        pool: &PgPool,
+        project_id: DbId,
+    ) -> Result<Vec<AvatarCompletenessSnapshot>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM avatar_completeness_snapshots \
+             WHERE project_id = $1 \
+             ORDER BY recorded_at, id"
+        );
+        sqlx::query_as::<_, AvatarCompletenessSnapshot>(&query)
+            .bind(project_id)
+            .fetch_all(pool)
+            .await
+    }
+}
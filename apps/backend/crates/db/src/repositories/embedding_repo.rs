@@ -49,6 +49,30 @@ impl EmbeddingRepo {
         Ok(())
     }
 
+    /// Move an avatar to `extracting_status_id` unless it is already in one of
+    /// `skip_status_ids`.
+    ///
+    /// The check and update are a single statement, so concurrent callers
+    /// never both claim the same avatar. Returns `true` if this call claimed it.
+    pub async fn claim_for_extraction(
+        pool: &PgPool,
+        avatar_id: DbId,
+        extracting_status_id: StatusId,
+        skip_status_ids: &[StatusId],
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE avatars SET embedding_status_id = $2 \
+             WHERE id = $1 AND deleted_at IS NULL \
+               AND embedding_status_id <> ALL($3)",
+        )
+        .bind(avatar_id)
+        .bind(extracting_status_id)
+        .bind(skip_status_ids)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Update a avatar's embedding metadata (confidence, bounding box, status)
     /// and set the extraction timestamp to now.
     pub async fn update_avatar_embedding(
@@ -233,6 +257,24 @@ impl EmbeddingRepo {
         })
     }
 
+    /// Load the face embeddings of several avatars as pgvector text.
+    ///
+    /// Returns one `(avatar_id, embedding)` pair per live avatar in `ids`;
+    /// avatars without an embedding have `None`, and missing or deleted
+    /// avatars are omitted.
+    pub async fn list_avatar_embeddings(
+        pool: &PgPool,
+        ids: &[DbId],
+    ) -> Result<Vec<(DbId, Option<String>)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT id, face_embedding::text FROM avatars \
+             WHERE id = ANY($1) AND deleted_at IS NULL",
+        )
+        .bind(ids)
+        .fetch_all(pool)
+        .await
+    }
+
     // -----------------------------------------------------------------------
     // Embedding history / archive
     // -----------------------------------------------------------------------
@@ -1,9 +1,10 @@
 //! Repository for the `events` and `event_types` tables.
 
+use futures::stream::BoxStream;
 use sqlx::PgPool;
-use x121_core::types::DbId;
+use x121_core::types::{DbId, Timestamp};
 
-use crate::models::event::{Event, EventType};
+use crate::models::event::{Event, EventType, EventWithTypeName};
 
 /// Column list for `event_types` queries.
 const EVENT_TYPE_COLUMNS: &str =
@@ -86,4 +87,27 @@ impl EventRepo {
             .fetch_all(pool)
             .await
     }
+
+    /// Stream events created in `[start, end)` in creation order, with
+    /// their event type names. A `None` end leaves the range open.
+    ///
+    /// Rows that fail to decode surface as individual `Err` items; the
+    /// stream continues with the next row.
+    pub fn stream_in_range(
+        pool: &PgPool,
+        start: Timestamp,
+        end: Option<Timestamp>,
+    ) -> BoxStream<'_, Result<EventWithTypeName, sqlx::Error>> {
+        sqlx::query_as::<_, EventWithTypeName>(
+            "SELECT e.id, et.name AS event_type, e.source_entity_type, e.source_entity_id, \
+                    e.actor_user_id, e.payload, e.created_at \
+             FROM events e \
+             JOIN event_types et ON et.id = e.event_type_id \
+             WHERE e.created_at >= $1 AND ($2::TIMESTAMPTZ IS NULL OR e.created_at < $2) \
+             ORDER BY e.created_at, e.id",
+        )
+        .bind(start)
+        .bind(end)
+        .fetch(pool)
+    }
 }
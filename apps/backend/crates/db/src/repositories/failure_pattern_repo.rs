@@ -5,6 +5,7 @@ use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEA
 use x121_core::types::DbId;
 
 use crate::models::failure_pattern::{FailurePattern, UpsertFailurePattern};
+use crate::models::status::JobStatus;
 
 /// Column list shared across queries to avoid repetition.
 const COLUMNS: &str = "\
@@ -69,6 +70,18 @@ impl FailurePatternRepo {
             .await
     }
 
+    /// Find a single failure pattern by its `pattern_key`.
+    pub async fn find_by_key(
+        pool: &PgPool,
+        pattern_key: &str,
+    ) -> Result<Option<FailurePattern>, sqlx::Error> {
+        let query = format!("SELECT {COLUMNS} FROM failure_patterns WHERE pattern_key = $1");
+        sqlx::query_as::<_, FailurePattern>(&query)
+            .bind(pattern_key)
+            .fetch_optional(pool)
+            .await
+    }
+
     /// List failure patterns with optional severity filter.
     ///
     /// Results are ordered by `failure_rate DESC` so the most problematic
@@ -179,6 +192,37 @@ impl FailurePatternRepo {
         }
     }
 
+    /// Daily job failure rate over the last `period_days`, oldest first.
+    ///
+    /// Each point is `(day, failure_rate, finished_job_count)` over jobs
+    /// that completed or failed that day. With `pattern_key`, only failures
+    /// classified with that signature count towards the rate.
+    pub async fn get_job_failure_trend(
+        pool: &PgPool,
+        pattern_key: Option<&str>,
+        period_days: i32,
+    ) -> Result<Vec<(String, f64, i32)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, f64, i32)>(
+            "SELECT to_char(date_trunc('day', completed_at), 'YYYY-MM-DD') AS period,
+                    COUNT(*) FILTER (
+                        WHERE status_id = $2
+                          AND ($4::TEXT IS NULL OR failure_pattern_key = $4)
+                    )::FLOAT8 / COUNT(*)::FLOAT8 AS failure_rate,
+                    COUNT(*)::INT4 AS sample_count
+             FROM jobs
+             WHERE status_id IN ($2, $3)
+               AND completed_at >= NOW() - make_interval(days => $1)
+             GROUP BY 1
+             ORDER BY 1",
+        )
+        .bind(period_days)
+        .bind(JobStatus::Failed.id())
+        .bind(JobStatus::Completed.id())
+        .bind(pattern_key)
+        .fetch_all(pool)
+        .await
+    }
+
     /// Delete a failure pattern by ID.
     pub async fn delete(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
         let result = sqlx::query("DELETE FROM failure_patterns WHERE id = $1")
@@ -0,0 +1,83 @@
+//! Repository for the `failure_signatures` table (PRD-64).
+
+use sqlx::PgPool;
+use x121_core::types::DbId;
+
+use crate::models::failure_signature::{CreateFailureSignature, FailureSignatureRow};
+
+/// Column list shared across queries to avoid repetition.
+const COLUMNS: &str = "\
+    id, pattern_key, description, kind, pattern, priority, \
+    is_enabled, created_at, updated_at";
+
+/// Provides CRUD operations for failure signatures.
+pub struct FailureSignatureRepo;
+
+impl FailureSignatureRepo {
+    /// Insert a new signature, returning the created row.
+    pub async fn create(
+        pool: &PgPool,
+        input: &CreateFailureSignature,
+    ) -> Result<FailureSignatureRow, sqlx::Error> {
+        let query = format!(
+            "INSERT INTO failure_signatures
+                (pattern_key, description, kind, pattern, priority)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, FailureSignatureRow>(&query)
+            .bind(&input.pattern_key)
+            .bind(&input.description)
+            .bind(&input.kind)
+            .bind(&input.pattern)
+            .bind(input.priority)
+            .fetch_one(pool)
+            .await
+    }
+
+    /// List all signatures, highest priority first.
+    pub async fn list(pool: &PgPool) -> Result<Vec<FailureSignatureRow>, sqlx::Error> {
+        let query =
+            format!("SELECT {COLUMNS} FROM failure_signatures ORDER BY priority DESC, id ASC");
+        sqlx::query_as::<_, FailureSignatureRow>(&query)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// List enabled signatures, highest priority first.
+    pub async fn list_enabled(pool: &PgPool) -> Result<Vec<FailureSignatureRow>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM failure_signatures
+             WHERE is_enabled
+             ORDER BY priority DESC, id ASC"
+        );
+        sqlx::query_as::<_, FailureSignatureRow>(&query)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Enable or disable a signature. Returns the updated row, if any.
+    pub async fn set_enabled(
+        pool: &PgPool,
+        id: DbId,
+        is_enabled: bool,
+    ) -> Result<Option<FailureSignatureRow>, sqlx::Error> {
+        let query = format!(
+            "UPDATE failure_signatures SET is_enabled = $2 WHERE id = $1 RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, FailureSignatureRow>(&query)
+            .bind(id)
+            .bind(is_enabled)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Delete a signature. Returns `true` if a row was removed.
+    pub async fn delete(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM failure_signatures WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -6,8 +6,8 @@ use x121_core::types::DbId;
 use crate::models::hook_execution_log::{CreateHookExecutionLog, HookExecutionLog};
 
 /// Column list for hook_execution_logs queries.
-const COLUMNS: &str = "id, hook_id, job_id, input_json, output_text, exit_code, \
-    duration_ms, success, error_message, executed_at";
+const COLUMNS: &str = "id, hook_id, job_id, input_json, output_text, output_truncated, \
+    output_original_len, exit_code, duration_ms, success, error_message, executed_at";
 
 /// Provides data-access methods for hook execution logs.
 pub struct HookExecutionLogRepo;
@@ -20,9 +20,9 @@ impl HookExecutionLogRepo {
     ) -> Result<HookExecutionLog, sqlx::Error> {
         let query = format!(
             "INSERT INTO hook_execution_logs
-                (hook_id, job_id, input_json, output_text, exit_code,
-                 duration_ms, success, error_message)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                (hook_id, job_id, input_json, output_text, output_truncated,
+                 output_original_len, exit_code, duration_ms, success, error_message)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
              RETURNING {COLUMNS}"
         );
         sqlx::query_as::<_, HookExecutionLog>(&query)
@@ -30,6 +30,8 @@ impl HookExecutionLogRepo {
             .bind(input.job_id)
             .bind(&input.input_json)
             .bind(&input.output_text)
+            .bind(input.output_truncated)
+            .bind(input.output_original_len)
             .bind(input.exit_code)
             .bind(input.duration_ms)
             .bind(input.success)
@@ -1,7 +1,9 @@
 //! Repository for import reports and report entries.
 
 use sqlx::PgPool;
+use x121_core::import_status::{IMPORT_STATUS_COMMITTING, IMPORT_STATUS_PREVIEW};
 use x121_core::types::DbId;
+use x121_core::validation::import_commit::commit_outcome;
 
 use crate::models::validation::{
     CreateImportReport, CreateImportReportEntry, ImportReport, ImportReportEntry,
@@ -11,12 +13,12 @@ use crate::models::validation::{
 const REPORT_COLUMNS: &str =
     "id, status_id, source_type, source_reference, entity_type, project_id, \
      total_records, accepted, rejected, auto_corrected, skipped, report_data, \
-     created_by, created_at, updated_at";
+     created_by, committed_records, last_commit_error, created_at, updated_at";
 
 /// Column list for `import_report_entries` queries.
 const ENTRY_COLUMNS: &str =
     "id, report_id, record_index, entity_id, action, field_errors, field_warnings, \
-     field_diffs, conflict_resolutions, created_at, updated_at";
+     field_diffs, conflict_resolutions, outcome, committed_at, created_at, updated_at";
 
 /// Provides CRUD operations for import reports and their entries.
 pub struct ImportReportRepo;
@@ -103,6 +105,104 @@ impl ImportReportRepo {
             .await
     }
 
+    // ── Commit ───────────────────────────────────────────────────────
+
+    /// Move a report into `committing`, clearing any previous commit error.
+    ///
+    /// Only reports in `preview`, or already `committing` after an
+    /// interrupted attempt, qualify. Returns `None` otherwise.
+    pub async fn begin_commit(
+        pool: &PgPool,
+        id: DbId,
+    ) -> Result<Option<ImportReport>, sqlx::Error> {
+        let sql = format!(
+            "UPDATE import_reports SET \
+                status_id = (SELECT id FROM import_report_statuses WHERE name = $2), \
+                last_commit_error = NULL \
+             WHERE id = $1 \
+               AND status_id IN (SELECT id FROM import_report_statuses WHERE name IN ($2, $3)) \
+             RETURNING {REPORT_COLUMNS}"
+        );
+        sqlx::query_as::<_, ImportReport>(&sql)
+            .bind(id)
+            .bind(IMPORT_STATUS_COMMITTING)
+            .bind(IMPORT_STATUS_PREVIEW)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Apply up to `batch_size` pending entries of a report, in
+    /// `record_index` order, within a single transaction.
+    ///
+    /// Each entry gets its commit outcome and the report's
+    /// `committed_records` advances by the same count, so either the whole
+    /// chunk is applied or none of it is. Returns the number of entries
+    /// applied; `0` means none were pending.
+    pub async fn commit_next_chunk(
+        pool: &PgPool,
+        report_id: DbId,
+        batch_size: i64,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let pending: Vec<(DbId, String)> = sqlx::query_as(
+            "SELECT id, action FROM import_report_entries \
+             WHERE report_id = $1 AND outcome IS NULL \
+             ORDER BY record_index \
+             LIMIT $2 \
+             FOR UPDATE",
+        )
+        .bind(report_id)
+        .bind(batch_size)
+        .fetch_all(&mut *tx)
+        .await?;
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let (ids, outcomes): (Vec<DbId>, Vec<&str>) = pending
+            .iter()
+            .map(|(id, action)| (*id, commit_outcome(action).as_str()))
+            .unzip();
+
+        sqlx::query(
+            "UPDATE import_report_entries e \
+             SET outcome = u.outcome, committed_at = NOW() \
+             FROM UNNEST($1::BIGINT[], $2::TEXT[]) AS u(id, outcome) \
+             WHERE e.id = u.id",
+        )
+        .bind(&ids)
+        .bind(&outcomes)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "UPDATE import_reports SET committed_records = committed_records + $2 WHERE id = $1",
+        )
+        .bind(report_id)
+        .bind(ids.len() as i32)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(ids.len() as u64)
+    }
+
+    /// Record the error that interrupted a commit. The report stays in
+    /// `committing` so the commit can be resumed.
+    pub async fn record_commit_error(
+        pool: &PgPool,
+        id: DbId,
+        error: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE import_reports SET last_commit_error = $2 WHERE id = $1")
+            .bind(id)
+            .bind(error)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     // ── Report Entries ───────────────────────────────────────────────
 
     /// Insert a single report entry, returning the created row.
@@ -187,7 +287,7 @@ impl ImportReportRepo {
             }
         }
 
-        let mut csv = String::from("record_index,entity_id,action,errors,warnings\n");
+        let mut csv = String::from("record_index,entity_id,action,outcome,errors,warnings\n");
         for entry in &entries {
             let errors = entry.field_errors.as_array().map(|a| a.len()).unwrap_or(0);
             let warnings = entry
@@ -197,8 +297,13 @@ impl ImportReportRepo {
                 .unwrap_or(0);
             let entity_id = entry.entity_id.map(|id| id.to_string()).unwrap_or_default();
             csv.push_str(&format!(
-                "{},{},{},{},{}\n",
-                entry.record_index, entity_id, entry.action, errors, warnings
+                "{},{},{},{},{},{}\n",
+                entry.record_index,
+                entity_id,
+                entry.action,
+                entry.outcome.as_deref().unwrap_or_default(),
+                errors,
+                warnings
             ));
         }
         Ok(Some(csv))
@@ -105,6 +105,78 @@ impl ImportSessionRepo {
             .await
     }
 
+    /// Load the scan cursor (last scanned relative path) of an interrupted
+    /// folder scan.
+    pub async fn get_scan_cursor(pool: &PgPool, id: DbId) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, Option<String>>(
+            "SELECT scan_cursor FROM import_sessions WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map(Option::flatten)
+    }
+
+    /// Append a batch of scanned files and advance the scan cursor to the
+    /// last of them, atomically. `paths` and `sizes` are parallel slices.
+    pub async fn append_scan_files(
+        pool: &PgPool,
+        id: DbId,
+        paths: &[String],
+        sizes: &[i64],
+    ) -> Result<(), sqlx::Error> {
+        let Some(cursor) = paths.last() else {
+            return Ok(());
+        };
+
+        let mut tx = pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO import_scan_files (session_id, relative_path, file_size_bytes) \
+             SELECT $1, * FROM UNNEST($2::text[], $3::bigint[]) \
+             ON CONFLICT (session_id, relative_path) DO NOTHING",
+        )
+        .bind(id)
+        .bind(paths)
+        .bind(sizes)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE import_sessions SET scan_cursor = $2 WHERE id = $1")
+            .bind(id)
+            .bind(cursor)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await
+    }
+
+    /// List the `(relative_path, file_size_bytes)` pairs scanned so far, in
+    /// scan order.
+    pub async fn list_scan_files(
+        pool: &PgPool,
+        id: DbId,
+    ) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, i64)>(
+            "SELECT relative_path, file_size_bytes FROM import_scan_files \
+             WHERE session_id = $1 ORDER BY id",
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Discard a finished scan's files and cursor.
+    pub async fn clear_scan(pool: &PgPool, id: DbId) -> Result<(), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        sqlx::query("DELETE FROM import_scan_files WHERE session_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE import_sessions SET scan_cursor = NULL WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await
+    }
+
     /// List all session statuses.
     pub async fn list_statuses(pool: &PgPool) -> Result<Vec<ImportSessionStatus>, sqlx::Error> {
         let sql = format!("SELECT {STATUS_COLUMNS} FROM import_session_statuses ORDER BY id");
@@ -4,7 +4,8 @@ use sqlx::PgPool;
 use x121_core::types::DbId;
 
 use crate::models::integrity_scan::{
-    CreateIntegrityScan, IntegrityScan, UpdateIntegrityScanResults,
+    CreateIntegrityScan, CreateIntegrityScanFile, IntegrityScan, IntegrityScanFile,
+    UpdateIntegrityScanResults,
 };
 use crate::models::status::StatusId;
 
@@ -13,6 +14,10 @@ const COLUMNS: &str = "id, worker_id, scan_type, status_id, results_json, \
     models_found, models_missing, models_corrupted, nodes_found, nodes_missing, \
     started_at, completed_at, triggered_by, created_at, updated_at";
 
+/// Column list for `integrity_scan_files` queries.
+const FILE_COLUMNS: &str = "id, scan_id, file_path, model_name, status, expected_hash, \
+    actual_hash, created_at, updated_at";
+
 /// Provides CRUD operations for integrity scans.
 pub struct IntegrityScanRepo;
 
@@ -132,4 +137,90 @@ impl IntegrityScanRepo {
             .fetch_one(pool)
             .await
     }
+
+    /// Record per-file results for a scan, returning the number of rows
+    /// inserted.
+    pub async fn insert_files(
+        pool: &PgPool,
+        scan_id: DbId,
+        files: &[CreateIntegrityScanFile],
+    ) -> Result<u64, sqlx::Error> {
+        if files.is_empty() {
+            return Ok(0);
+        }
+
+        let paths: Vec<&str> = files.iter().map(|f| f.file_path.as_str()).collect();
+        let names: Vec<Option<&str>> = files.iter().map(|f| f.model_name.as_deref()).collect();
+        let statuses: Vec<&str> = files.iter().map(|f| f.status.as_str()).collect();
+        let expected: Vec<Option<&str>> =
+            files.iter().map(|f| f.expected_hash.as_deref()).collect();
+        let actual: Vec<Option<&str>> = files.iter().map(|f| f.actual_hash.as_deref()).collect();
+
+        let result = sqlx::query(
+            "INSERT INTO integrity_scan_files \
+                (scan_id, file_path, model_name, status, expected_hash, actual_hash) \
+             SELECT $1, * FROM UNNEST($2::text[], $3::text[], $4::text[], $5::text[], $6::text[])",
+        )
+        .bind(scan_id)
+        .bind(&paths)
+        .bind(&names)
+        .bind(&statuses)
+        .bind(&expected)
+        .bind(&actual)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// List per-file results for a scan in insertion order.
+    pub async fn list_files(
+        pool: &PgPool,
+        scan_id: DbId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<IntegrityScanFile>, sqlx::Error> {
+        let query = format!(
+            "SELECT {FILE_COLUMNS} FROM integrity_scan_files
+             WHERE scan_id = $1
+             ORDER BY id
+             LIMIT $2 OFFSET $3"
+        );
+        sqlx::query_as::<_, IntegrityScanFile>(&query)
+            .bind(scan_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// List up to `limit` per-file results for a scan with IDs greater than
+    /// `after_id`, in ID order. Used to page through large reports without
+    /// holding them in memory or re-scanning skipped rows.
+    pub async fn list_files_after(
+        pool: &PgPool,
+        scan_id: DbId,
+        after_id: DbId,
+        limit: i64,
+    ) -> Result<Vec<IntegrityScanFile>, sqlx::Error> {
+        let query = format!(
+            "SELECT {FILE_COLUMNS} FROM integrity_scan_files
+             WHERE scan_id = $1 AND id > $2
+             ORDER BY id
+             LIMIT $3"
+        );
+        sqlx::query_as::<_, IntegrityScanFile>(&query)
+            .bind(scan_id)
+            .bind(after_id)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Count the per-file results recorded for a scan.
+    pub async fn count_files(pool: &PgPool, scan_id: DbId) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM integrity_scan_files WHERE scan_id = $1")
+            .bind(scan_id)
+            .fetch_one(pool)
+            .await
+    }
 }
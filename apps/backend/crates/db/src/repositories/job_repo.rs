@@ -4,13 +4,15 @@
 //! No magic numbers — every status literal is a named constant.
 
 use sqlx::PgPool;
-use x121_core::scheduling::state_machine;
+use x121_core::failure_tracking::{self, FailureSignature};
+use x121_core::scheduling::{state_machine, QueueSlot, QUEUE_REORDER_LOCK_ID};
 use x121_core::types::DbId;
 
 use serde::{Deserialize, Deserializer};
 
 use crate::models::job::{AdminQueueJob, Job, JobListQuery, QueuedJobView, SubmitJob};
 use crate::models::status::{JobStatus, StatusId};
+use crate::repositories::FailureSignatureRepo;
 
 /// Deserialize a comma-separated string (e.g. `"1,2,5"`) into `Option<Vec<T>>`.
 ///
@@ -74,7 +76,7 @@ const COLUMNS: &str = "\
     scheduled_start_at, is_off_peak_only, is_paused, paused_at, resumed_at, queue_position, \
     failure_stage_index, failure_stage_name, failure_diagnostics, \
     last_checkpoint_id, resumed_from_checkpoint_id, original_job_id, \
-    comfyui_instance_id, orphan_retry_count, \
+    comfyui_instance_id, orphan_retry_count, failure_pattern_key, \
     created_at, updated_at";
 
 /// Columns for the lightweight queue view.
@@ -82,6 +84,9 @@ const QUEUE_VIEW_COLUMNS: &str = "\
     id, job_type, priority, submitted_by, submitted_at, \
     queue_position, scheduled_start_at, is_off_peak_only, is_paused";
 
+/// Dispatch order of queued jobs: priority (descending), then queue rank.
+const QUEUE_ORDER: &str = "priority DESC, queue_rank ASC, id ASC";
+
 /// Maximum page size for job listing.
 const MAX_LIMIT: i64 = 100;
 
@@ -204,7 +209,7 @@ impl JobRepo {
                  WHERE status_id = $3 \
                    AND is_paused = false \
                    AND (is_off_peak_only = false OR $4 = true) \
-                 ORDER BY priority DESC, submitted_at ASC \
+                 ORDER BY {QUEUE_ORDER} \
                  LIMIT 1 \
                  FOR UPDATE SKIP LOCKED \
              ) \
@@ -230,7 +235,7 @@ impl JobRepo {
              WHERE id = ( \
                  SELECT id FROM jobs \
                  WHERE status_id = $3 AND claimed_at IS NULL \
-                 ORDER BY priority DESC, submitted_at ASC \
+                 ORDER BY {QUEUE_ORDER} \
                  LIMIT 1 \
                  FOR UPDATE SKIP LOCKED \
              ) \
@@ -244,20 +249,147 @@ impl JobRepo {
             .await
     }
 
-    /// List the current queue: pending + scheduled jobs ordered by priority.
-    pub async fn list_queue(pool: &PgPool) -> Result<Vec<QueuedJobView>, sqlx::Error> {
+    /// List unclaimed pending jobs in dispatch order without claiming them.
+    ///
+    /// Used when a worker can only run a subset of jobs; follow up with
+    /// [`JobRepo::claim_by_id`] to claim the chosen one.
+    pub async fn list_claimable(pool: &PgPool, limit: i64) -> Result<Vec<Job>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM jobs \
+             WHERE status_id = $1 AND claimed_at IS NULL \
+             ORDER BY {QUEUE_ORDER} \
+             LIMIT $2"
+        );
+        sqlx::query_as::<_, Job>(&query)
+            .bind(JobStatus::Pending.id())
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Atomically claim a specific pending job for a worker.
+    ///
+    /// Returns `None` if the job was claimed by someone else in the meantime.
+    pub async fn claim_by_id(
+        pool: &PgPool,
+        job_id: DbId,
+        worker_id: DbId,
+    ) -> Result<Option<Job>, sqlx::Error> {
+        let query = format!(
+            "UPDATE jobs \
+             SET worker_id = $1, claimed_at = NOW(), status_id = $2 \
+             WHERE id = $3 AND status_id = $4 AND claimed_at IS NULL \
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, Job>(&query)
+            .bind(worker_id)
+            .bind(JobStatus::Running.id())
+            .bind(job_id)
+            .bind(JobStatus::Pending.id())
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// List the current queue: pending + scheduled jobs in dispatch order.
+    pub async fn list_queue<'e>(
+        executor: impl sqlx::PgExecutor<'e>,
+    ) -> Result<Vec<QueuedJobView>, sqlx::Error> {
         let query = format!(
             "SELECT {QUEUE_VIEW_COLUMNS} FROM jobs \
              WHERE status_id IN ($1, $2) \
-             ORDER BY priority DESC, submitted_at ASC"
+             ORDER BY {QUEUE_ORDER}"
         );
         sqlx::query_as::<_, QueuedJobView>(&query)
             .bind(JobStatus::Pending.id())
             .bind(JobStatus::Scheduled.id())
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
     }
 
+    /// Lock the queue for reordering inside `tx` and return its sort keys in
+    /// dispatch order.
+    ///
+    /// Concurrent reorders serialize on an advisory lock held until `tx`
+    /// ends, and the queued rows are locked so they cannot change underneath
+    /// the reorder.
+    pub async fn lock_queue(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Vec<QueueSlot>, sqlx::Error> {
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(QUEUE_REORDER_LOCK_ID)
+            .execute(&mut **tx)
+            .await?;
+
+        let query = format!(
+            "SELECT id, priority, queue_rank FROM jobs \
+             WHERE status_id IN ($1, $2) \
+             ORDER BY {QUEUE_ORDER} \
+             FOR UPDATE"
+        );
+        let rows: Vec<(DbId, i32, f64)> = sqlx::query_as(&query)
+            .bind(JobStatus::Pending.id())
+            .bind(JobStatus::Scheduled.id())
+            .fetch_all(&mut **tx)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(job_id, priority, rank)| QueueSlot {
+                job_id,
+                priority,
+                rank,
+            })
+            .collect())
+    }
+
+    /// Set a queued job's priority and rank inside `tx`.
+    pub async fn set_queue_placement(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        job_id: DbId,
+        priority: i32,
+        rank: f64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE jobs SET priority = $2, queue_rank = $3 WHERE id = $1")
+            .bind(job_id)
+            .bind(priority)
+            .bind(rank)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    /// Re-space the ranks of the queued jobs in one priority band inside `tx`.
+    ///
+    /// Keeps the band's order and its first rank, spreading the rest evenly
+    /// across the band's current span with at least `min_gap` between
+    /// neighbours. Other bands are untouched.
+    pub async fn renumber_queue_band(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        priority: i32,
+        min_gap: f64,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "WITH band AS ( \
+                 SELECT id, \
+                        ROW_NUMBER() OVER (ORDER BY queue_rank, id) - 1 AS idx, \
+                        MIN(queue_rank) OVER () AS lo, \
+                        MAX(queue_rank) OVER () AS hi, \
+                        COUNT(*) OVER () AS n \
+                 FROM jobs \
+                 WHERE status_id IN ($1, $2) AND priority = $3 \
+             ) \
+             UPDATE jobs SET queue_rank = band.lo + band.idx * \
+                 GREATEST((band.hi - band.lo) / GREATEST(band.n - 1, 1), $4) \
+             FROM band WHERE jobs.id = band.id",
+        )
+        .bind(JobStatus::Pending.id())
+        .bind(JobStatus::Scheduled.id())
+        .bind(priority)
+        .bind(min_gap)
+        .execute(&mut **tx)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     /// Count jobs in each queue-relevant status.
     pub async fn queue_counts(pool: &PgPool) -> Result<(i64, i64, i64), sqlx::Error> {
         let row: (i64, i64, i64) = sqlx::query_as(
@@ -337,6 +469,9 @@ impl JobRepo {
 
     /// Mark a job as failed with an error message and optional details.
     ///
+    /// The error is classified against the enabled failure signatures and
+    /// the matched pattern key, if any, is recorded on the job (PRD-64).
+    ///
     /// No automatic retry is performed. The job stays in `Failed` status
     /// until the user explicitly retries via `POST /jobs/:id/retry`.
     pub async fn fail(
@@ -345,9 +480,11 @@ impl JobRepo {
         error: &str,
         details: Option<&serde_json::Value>,
     ) -> Result<(), sqlx::Error> {
+        let pattern_key = Self::classify_failure(pool, error).await?;
         sqlx::query(
             "UPDATE jobs \
              SET status_id = $2, error_message = $3, error_details = $4, \
+                 failure_pattern_key = $5, \
                  completed_at = NOW(), \
                  actual_duration_secs = EXTRACT(EPOCH FROM \
                      COALESCE(NOW() - started_at, INTERVAL '0'))::INTEGER \
@@ -357,11 +494,25 @@ impl JobRepo {
         .bind(JobStatus::Failed.id())
         .bind(error)
         .bind(details)
+        .bind(pattern_key)
         .execute(pool)
         .await?;
         Ok(())
     }
 
+    /// Match `error` against the enabled failure signatures.
+    ///
+    /// Rows with an unknown `kind` are ignored rather than failing the job
+    /// update.
+    async fn classify_failure(pool: &PgPool, error: &str) -> Result<Option<String>, sqlx::Error> {
+        let signatures: Vec<FailureSignature> = FailureSignatureRepo::list_enabled(pool)
+            .await?
+            .iter()
+            .filter_map(|row| row.to_signature().ok())
+            .collect();
+        Ok(failure_tracking::match_failure(error, &signatures).map(|m| m.pattern_key))
+    }
+
     /// Cancel a job if it is not already in a terminal state.
     ///
     /// Returns `true` if the job was cancelled, `false` if it was already
@@ -576,7 +727,7 @@ impl JobRepo {
              WHERE status_id = $1 \
                AND comfyui_instance_id IS NULL \
                AND is_paused = false \
-             ORDER BY priority DESC, submitted_at ASC"
+             ORDER BY {QUEUE_ORDER}"
         );
         sqlx::query_as::<_, Job>(&query)
             .bind(JobStatus::Pending.id())
@@ -7,7 +7,7 @@ use crate::models::keyframe::{CreateKeyframe, Keyframe};
 
 /// Column list for keyframes queries.
 const COLUMNS: &str = "id, segment_id, frame_number, timestamp_secs, \
-    thumbnail_path, full_res_path, created_at, updated_at";
+    thumbnail_path, full_res_path, source_hash, created_at, updated_at";
 
 /// Provides CRUD operations for keyframes.
 pub struct KeyframeRepo;
@@ -18,8 +18,8 @@ impl KeyframeRepo {
         let query = format!(
             "INSERT INTO keyframes
                 (segment_id, frame_number, timestamp_secs,
-                 thumbnail_path, full_res_path)
-             VALUES ($1, $2, $3, $4, $5)
+                 thumbnail_path, full_res_path, source_hash)
+             VALUES ($1, $2, $3, $4, $5, $6)
              RETURNING {COLUMNS}"
         );
         sqlx::query_as::<_, Keyframe>(&query)
@@ -28,6 +28,7 @@ impl KeyframeRepo {
             .bind(input.timestamp_secs)
             .bind(&input.thumbnail_path)
             .bind(&input.full_res_path)
+            .bind(&input.source_hash)
             .fetch_one(pool)
             .await
     }
@@ -68,7 +69,8 @@ impl KeyframeRepo {
     ) -> Result<Vec<Keyframe>, sqlx::Error> {
         let query = format!(
             "SELECT k.id, k.segment_id, k.frame_number, k.timestamp_secs,
-                    k.thumbnail_path, k.full_res_path, k.created_at, k.updated_at
+                    k.thumbnail_path, k.full_res_path, k.source_hash,
+                    k.created_at, k.updated_at
              FROM keyframes k
              JOIN segments s ON s.id = k.segment_id
              WHERE s.scene_id = $1
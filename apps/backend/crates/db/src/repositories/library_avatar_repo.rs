@@ -4,8 +4,8 @@ use sqlx::PgPool;
 use x121_core::types::DbId;
 
 use crate::models::library_avatar::{
-    CreateLibraryAvatar, CreateProjectAvatarLink, LibraryAvatar, LibraryUsageEntry,
-    ProjectAvatarLink, UpdateLibraryAvatar,
+    CreateLibraryAvatar, CreateProjectAvatarLink, LibraryAvatar, LibraryAvatarDeletion,
+    LibraryUsageEntry, ProjectAvatarLink, UpdateLibraryAvatar,
 };
 
 /* --------------------------------------------------------------------------
@@ -173,13 +173,55 @@ impl LibraryAvatarRepo {
             .await
     }
 
-    /// Delete a library avatar by ID. Returns `true` if a row was removed.
-    pub async fn delete(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
-        let result = sqlx::query("DELETE FROM library_avatars WHERE id = $1")
+    /// Delete a library avatar by ID.
+    ///
+    /// Projects keep their imported avatars, but their links to the library
+    /// avatar must go first: without `cascade` the delete is refused while
+    /// any link remains, with it the links are removed in the same
+    /// transaction.
+    pub async fn delete(
+        pool: &PgPool,
+        id: DbId,
+        cascade: bool,
+    ) -> Result<LibraryAvatarDeletion, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        // Lock the row so no import can link to it while we decide.
+        let exists: Option<(DbId,)> =
+            sqlx::query_as("SELECT id FROM library_avatars WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        if exists.is_none() {
+            return Ok(LibraryAvatarDeletion::NotFound);
+        }
+
+        let unlinked = if cascade {
+            sqlx::query("DELETE FROM project_avatar_links WHERE library_avatar_id = $1")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected()
+        } else {
+            let (active_links,): (i64,) = sqlx::query_as(
+                "SELECT COUNT(*) FROM project_avatar_links WHERE library_avatar_id = $1",
+            )
             .bind(id)
-            .execute(pool)
+            .fetch_one(&mut *tx)
             .await?;
-        Ok(result.rows_affected() > 0)
+            if active_links > 0 {
+                return Ok(LibraryAvatarDeletion::HasLinks { active_links });
+            }
+            0
+        };
+
+        sqlx::query("DELETE FROM library_avatars WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(LibraryAvatarDeletion::Deleted { unlinked })
     }
 
     /// Search library avatars by name (case-insensitive ILIKE).
@@ -231,6 +273,61 @@ impl ProjectAvatarLinkRepo {
             .await
     }
 
+    /// Import a library avatar into a project as a new draft avatar and link
+    /// the two, atomically.
+    ///
+    /// The project avatar takes the library avatar's name and master
+    /// metadata. If the link cannot be created (e.g. the avatar is already
+    /// imported into the project) the new avatar is rolled back with it.
+    /// Returns `None` if the library avatar does not exist.
+    pub async fn import_avatar(
+        pool: &PgPool,
+        library_avatar_id: DbId,
+        project_id: DbId,
+        linked_fields: Option<&serde_json::Value>,
+    ) -> Result<Option<ProjectAvatarLink>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        // Share-lock the library avatar so it cannot be deleted mid-import.
+        let source: Option<(String, serde_json::Value)> = sqlx::query_as(
+            "SELECT name, master_metadata FROM library_avatars WHERE id = $1 FOR SHARE",
+        )
+        .bind(library_avatar_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((name, master_metadata)) = source else {
+            return Ok(None);
+        };
+
+        let (project_avatar_id,): (DbId,) = sqlx::query_as(
+            "INSERT INTO avatars (project_id, name, status_id, metadata) \
+             VALUES ($1, $2, 1, $3) \
+             RETURNING id",
+        )
+        .bind(project_id)
+        .bind(&name)
+        .bind(&master_metadata)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let query = format!(
+            "INSERT INTO project_avatar_links \
+                (project_id, library_avatar_id, project_avatar_id, linked_fields) \
+             VALUES ($1, $2, $3, COALESCE($4, '[]'::jsonb)) \
+             RETURNING {PCL_COLUMNS}"
+        );
+        let link = sqlx::query_as::<_, ProjectAvatarLink>(&query)
+            .bind(project_id)
+            .bind(library_avatar_id)
+            .bind(project_avatar_id)
+            .bind(linked_fields)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(link))
+    }
+
     /// Find a link by project and library avatar (unique constraint).
     pub async fn find_by_project_and_library(
         pool: &PgPool,
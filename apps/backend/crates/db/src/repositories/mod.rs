@@ -40,6 +40,7 @@ pub mod cloud_scaling_rule_repo;
 pub mod collaboration_repo;
 pub mod comfyui_execution_repo;
 pub mod comfyui_instance_repo;
+pub mod completeness_snapshot_repo;
 pub mod compliance_repo;
 pub mod consistency_report_repo;
 pub mod contact_sheet_repo;
@@ -57,6 +58,7 @@ pub mod event_repo;
 pub mod export_job_repo;
 pub mod extension_repo;
 pub mod failure_pattern_repo;
+pub mod failure_signature_repo;
 pub mod frame_annotation_repo;
 pub mod generation_metric_repo;
 pub mod generation_receipt_repo;
@@ -245,6 +247,7 @@ pub use collaboration_repo::EntityLockRepo;
 pub use collaboration_repo::UserPresenceRepo;
 pub use comfyui_execution_repo::ComfyUIExecutionRepo;
 pub use comfyui_instance_repo::ComfyUIInstanceRepo;
+pub use completeness_snapshot_repo::CompletenessSnapshotRepo;
 pub use compliance_repo::ComplianceRepo;
 pub use consistency_report_repo::ConsistencyReportRepo;
 pub use contact_sheet_repo::ContactSheetRepo;
@@ -264,6 +267,7 @@ pub use event_repo::EventRepo;
 pub use export_job_repo::ExportJobRepo;
 pub use extension_repo::ExtensionRepo;
 pub use failure_pattern_repo::FailurePatternRepo;
+pub use failure_signature_repo::FailureSignatureRepo;
 pub use frame_annotation_repo::FrameAnnotationRepo;
 pub use generation_metric_repo::GenerationMetricRepo;
 pub use generation_receipt_repo::GenerationReceiptRepo;
@@ -4,7 +4,7 @@ use sqlx::PgPool;
 use x121_core::types::DbId;
 
 use crate::models::notification::{
-    NotificationPreference, UpdateNotificationSettings, UserNotificationSettings,
+    NotificationPreference, UpdateNotificationSettings, UpdatePreference, UserNotificationSettings,
 };
 
 /// Column list for `notification_preferences` queries.
@@ -13,7 +13,7 @@ const PREF_COLUMNS: &str =
 
 /// Column list for `user_notification_settings` queries.
 const SETTINGS_COLUMNS: &str = "id, user_id, dnd_enabled, dnd_until, digest_enabled, \
-    digest_interval, digest_last_sent_at, created_at, updated_at";
+    digest_interval, digest_last_sent_at, timezone, digest_hour, created_at, updated_at";
 
 /// Provides CRUD operations for notification preferences and user settings.
 pub struct NotificationPreferenceRepo;
@@ -54,33 +54,35 @@ impl NotificationPreferenceRepo {
 
     /// Insert or update a notification preference.
     ///
-    /// Uses `INSERT ... ON CONFLICT (user_id, event_type_id) DO UPDATE` to
-    /// upsert in a single round-trip.
+    /// Uses a single `INSERT ... ON CONFLICT (user_id, event_type_id) DO UPDATE`
+    /// so concurrent upserts of the same preference never race on the insert.
+    /// Only fields that are `Some` are overwritten; on insert, missing fields
+    /// take the column defaults. Concurrent partial updates therefore each
+    /// apply their own fields without clobbering one another.
     pub async fn upsert(
         pool: &PgPool,
         user_id: DbId,
         event_type_id: DbId,
-        is_enabled: bool,
-        channels: &serde_json::Value,
-        scope: &str,
+        input: &UpdatePreference,
     ) -> Result<NotificationPreference, sqlx::Error> {
         let query = format!(
             "INSERT INTO notification_preferences \
                 (user_id, event_type_id, is_enabled, channels, scope) \
-             VALUES ($1, $2, $3, $4, $5) \
+             VALUES ($1, $2, COALESCE($3, true), COALESCE($4, '[\"in_app\"]'::jsonb), \
+                COALESCE($5, 'all')) \
              ON CONFLICT (user_id, event_type_id) DO UPDATE SET \
-                is_enabled = EXCLUDED.is_enabled, \
-                channels = EXCLUDED.channels, \
-                scope = EXCLUDED.scope, \
+                is_enabled = COALESCE($3, notification_preferences.is_enabled), \
+                channels = COALESCE($4, notification_preferences.channels), \
+                scope = COALESCE($5, notification_preferences.scope), \
                 updated_at = NOW() \
              RETURNING {PREF_COLUMNS}"
         );
         sqlx::query_as::<_, NotificationPreference>(&query)
             .bind(user_id)
             .bind(event_type_id)
-            .bind(is_enabled)
-            .bind(channels)
-            .bind(scope)
+            .bind(input.is_enabled)
+            .bind(&input.channels)
+            .bind(&input.scope)
             .fetch_one(pool)
             .await
     }
@@ -108,13 +110,17 @@ impl NotificationPreferenceRepo {
     ) -> Result<UserNotificationSettings, sqlx::Error> {
         let query = format!(
             "INSERT INTO user_notification_settings \
-                (user_id, dnd_enabled, dnd_until, digest_enabled, digest_interval) \
-             VALUES ($1, COALESCE($2, false), $3, COALESCE($4, false), COALESCE($5, '24h')) \
+                (user_id, dnd_enabled, dnd_until, digest_enabled, digest_interval, \
+                 timezone, digest_hour) \
+             VALUES ($1, COALESCE($2, false), $3, COALESCE($4, false), COALESCE($5, 'daily'), \
+                     COALESCE($6, 'UTC'), COALESCE($7, 9)) \
              ON CONFLICT (user_id) DO UPDATE SET \
                 dnd_enabled = COALESCE($2, user_notification_settings.dnd_enabled), \
                 dnd_until = COALESCE($3, user_notification_settings.dnd_until), \
                 digest_enabled = COALESCE($4, user_notification_settings.digest_enabled), \
                 digest_interval = COALESCE($5, user_notification_settings.digest_interval), \
+                timezone = COALESCE($6, user_notification_settings.timezone), \
+                digest_hour = COALESCE($7, user_notification_settings.digest_hour), \
                 updated_at = NOW() \
              RETURNING {SETTINGS_COLUMNS}"
         );
@@ -124,23 +130,23 @@ impl NotificationPreferenceRepo {
             .bind(settings.dnd_until)
             .bind(settings.digest_enabled)
             .bind(&settings.digest_interval)
+            .bind(&settings.timezone)
+            .bind(settings.digest_hour)
             .fetch_one(pool)
             .await
     }
 
-    /// List users whose digest is enabled and due to be sent.
+    /// List settings for every user with digests enabled.
     ///
-    /// A digest is "due" when the user has `digest_enabled = true` and either:
-    /// - `digest_last_sent_at` is NULL (never sent), or
-    /// - enough time has elapsed since the last send (based on `digest_interval`).
-    pub async fn list_users_due_for_digest(
+    /// Whether each digest is due depends on the user's timezone and
+    /// preferred hour, so the caller decides with
+    /// `x121_core::digest_schedule::is_digest_due`.
+    pub async fn list_digest_enabled(
         pool: &PgPool,
     ) -> Result<Vec<UserNotificationSettings>, sqlx::Error> {
         let query = format!(
             "SELECT {SETTINGS_COLUMNS} FROM user_notification_settings \
              WHERE digest_enabled = true \
-               AND (digest_last_sent_at IS NULL \
-                    OR NOW() - digest_last_sent_at > digest_interval::interval) \
              ORDER BY id"
         );
         sqlx::query_as::<_, UserNotificationSettings>(&query)
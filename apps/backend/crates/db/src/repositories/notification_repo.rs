@@ -1,34 +1,43 @@
-//! Repository for the `notifications` table.
+//! Repository for the `notifications` and `email_dead_letters` tables.
 
 use sqlx::PgPool;
 use x121_core::types::DbId;
 
-use crate::models::notification::Notification;
+use crate::models::notification::{
+    CreateEmailDeadLetter, EmailDeadLetter, MarkReadFilter, Notification,
+};
 
 /// Column list for `notifications` queries.
 const COLUMNS: &str =
     "id, event_id, user_id, channel, is_read, read_at, is_delivered, delivered_at, created_at";
 
+/// Column list for `email_dead_letters` queries.
+const EMAIL_DEAD_LETTER_COLUMNS: &str = "\
+    id, to_address, event_type, payload, last_error, attempt_count, attempts, created_at";
+
 /// Provides CRUD operations for notifications.
 pub struct NotificationRepo;
 
 impl NotificationRepo {
     /// Create a notification for a user, returning the generated ID.
+    ///
+    /// Returns `None` if the user already has a notification for the event.
     pub async fn create(
         pool: &PgPool,
         event_id: DbId,
         user_id: DbId,
         channel: &str,
-    ) -> Result<DbId, sqlx::Error> {
+    ) -> Result<Option<DbId>, sqlx::Error> {
         sqlx::query_scalar(
             "INSERT INTO notifications (event_id, user_id, channel) \
              VALUES ($1, $2, $3) \
+             ON CONFLICT (event_id, user_id) DO NOTHING \
              RETURNING id",
         )
         .bind(event_id)
         .bind(user_id)
         .bind(channel)
-        .fetch_one(pool)
+        .fetch_optional(pool)
         .await
     }
 
@@ -98,6 +107,37 @@ impl NotificationRepo {
         Ok(result.rows_affected())
     }
 
+    /// Mark the user's unread notifications matching `filter` as read in a
+    /// single statement.
+    ///
+    /// Returns the number of notifications that were marked read.
+    pub async fn mark_read_matching(
+        pool: &PgPool,
+        user_id: DbId,
+        filter: &MarkReadFilter,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE notifications n \
+             SET is_read = true, read_at = NOW() \
+             FROM events e \
+             JOIN event_types et ON et.id = e.event_type_id \
+             WHERE n.event_id = e.id \
+               AND n.user_id = $1 AND n.is_read = false \
+               AND ($2::BIGINT IS NULL \
+                    OR (e.source_entity_type = 'project' AND e.source_entity_id = $2) \
+                    OR e.payload->>'project_id' = $2::TEXT) \
+               AND ($3::TEXT IS NULL OR et.name = $3) \
+               AND ($4::TIMESTAMPTZ IS NULL OR n.created_at < $4)",
+        )
+        .bind(user_id)
+        .bind(filter.project_id)
+        .bind(filter.event_type.as_deref())
+        .bind(filter.before)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     /// Get the number of unread notifications for a user.
     pub async fn unread_count(pool: &PgPool, user_id: DbId) -> Result<i64, sqlx::Error> {
         let count: Option<i64> = sqlx::query_scalar(
@@ -158,4 +198,46 @@ impl NotificationRepo {
         .await?;
         Ok(result.rows_affected())
     }
+
+    // -----------------------------------------------------------------------
+    // Email dead letters
+    // -----------------------------------------------------------------------
+
+    /// Record a notification email whose retries are exhausted.
+    pub async fn create_email_dead_letter(
+        pool: &PgPool,
+        input: &CreateEmailDeadLetter,
+    ) -> Result<EmailDeadLetter, sqlx::Error> {
+        let query = format!(
+            "INSERT INTO email_dead_letters \
+                 (to_address, event_type, payload, last_error, attempt_count, attempts) \
+             VALUES ($1, $2, $3, $4, $5, $6) \
+             RETURNING {EMAIL_DEAD_LETTER_COLUMNS}"
+        );
+        sqlx::query_as::<_, EmailDeadLetter>(&query)
+            .bind(&input.to_address)
+            .bind(&input.event_type)
+            .bind(&input.payload)
+            .bind(&input.last_error)
+            .bind(input.attempt_count)
+            .bind(&input.attempts)
+            .fetch_one(pool)
+            .await
+    }
+
+    /// List the dead-lettered emails for a recipient, newest first.
+    pub async fn list_email_dead_letters_for_address(
+        pool: &PgPool,
+        to_address: &str,
+    ) -> Result<Vec<EmailDeadLetter>, sqlx::Error> {
+        let query = format!(
+            "SELECT {EMAIL_DEAD_LETTER_COLUMNS} FROM email_dead_letters \
+             WHERE to_address = $1 \
+             ORDER BY id DESC"
+        );
+        sqlx::query_as::<_, EmailDeadLetter>(&query)
+            .bind(to_address)
+            .fetch_all(pool)
+            .await
+    }
 }
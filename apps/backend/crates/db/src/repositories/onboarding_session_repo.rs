@@ -1,14 +1,20 @@
-//! Repository for the `onboarding_sessions` table (PRD-67).
+//! Repository for the `onboarding_sessions` and `onboarding_session_events`
+//! tables (PRD-67).
 
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
+use x121_core::onboarding_wizard::OnboardingEventType;
 use x121_core::types::DbId;
 
-use crate::models::onboarding_session::OnboardingSession;
+use crate::models::onboarding_session::{OnboardingSession, OnboardingSessionEvent};
 
 /// Column list for `onboarding_sessions` queries.
 const COLUMNS: &str = "id, project_id, created_by_id, current_step, step_data, \
      avatar_ids, status, created_at, updated_at";
 
+/// Column list for `onboarding_session_events` queries.
+const EVENT_COLUMNS: &str = "id, session_id, event_type, from_step, to_step, step_data, \
+     actor_id, created_at";
+
 /// Provides CRUD operations for onboarding sessions.
 pub struct OnboardingSessionRepo;
 
@@ -61,58 +67,98 @@ impl OnboardingSessionRepo {
             .await
     }
 
-    /// Update the current step of a session.
+    /// Move a session to `step`, recording the transition in its history.
+    ///
+    /// The session row is locked while the step changes so the recorded
+    /// `from_step` is exactly the step being left.
     pub async fn update_step(
         pool: &PgPool,
         id: DbId,
         step: i32,
+        event_type: OnboardingEventType,
+        actor_id: DbId,
     ) -> Result<Option<OnboardingSession>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let Some(from_step) = Self::lock_current_step(&mut tx, id).await? else {
+            return Ok(None);
+        };
+
         let query = format!(
             "UPDATE onboarding_sessions SET current_step = $2 \
              WHERE id = $1 \
              RETURNING {COLUMNS}"
         );
-        sqlx::query_as::<_, OnboardingSession>(&query)
+        let session = sqlx::query_as::<_, OnboardingSession>(&query)
             .bind(id)
             .bind(step)
-            .fetch_optional(pool)
-            .await
+            .fetch_one(&mut *tx)
+            .await?;
+
+        Self::record_event(&mut tx, id, event_type, from_step, step, None, actor_id).await?;
+        tx.commit().await?;
+        Ok(Some(session))
     }
 
-    /// Update the status of a session.
-    pub async fn update_status(
+    /// Move a session from status `from` to `to`.
+    ///
+    /// The update only applies while the session is still in `from`, so two
+    /// racing transitions cannot both succeed. Returns `None` when the
+    /// session is missing or has already left `from`.
+    pub async fn transition_status(
         pool: &PgPool,
         id: DbId,
-        status: &str,
+        from: &str,
+        to: &str,
     ) -> Result<Option<OnboardingSession>, sqlx::Error> {
         let query = format!(
-            "UPDATE onboarding_sessions SET status = $2 \
-             WHERE id = $1 \
+            "UPDATE onboarding_sessions SET status = $3 \
+             WHERE id = $1 AND status = $2 \
              RETURNING {COLUMNS}"
         );
         sqlx::query_as::<_, OnboardingSession>(&query)
             .bind(id)
-            .bind(status)
+            .bind(from)
+            .bind(to)
             .fetch_optional(pool)
             .await
     }
 
-    /// Update the step data for a session.
+    /// Update the step data for a session, recording the submission in its
+    /// history against the step it was made on.
     pub async fn update_step_data(
         pool: &PgPool,
         id: DbId,
         step_data: &serde_json::Value,
+        actor_id: DbId,
     ) -> Result<Option<OnboardingSession>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let Some(step) = Self::lock_current_step(&mut tx, id).await? else {
+            return Ok(None);
+        };
+
         let query = format!(
             "UPDATE onboarding_sessions SET step_data = $2 \
              WHERE id = $1 \
              RETURNING {COLUMNS}"
         );
-        sqlx::query_as::<_, OnboardingSession>(&query)
+        let session = sqlx::query_as::<_, OnboardingSession>(&query)
             .bind(id)
             .bind(step_data)
-            .fetch_optional(pool)
-            .await
+            .fetch_one(&mut *tx)
+            .await?;
+
+        Self::record_event(
+            &mut tx,
+            id,
+            OnboardingEventType::StepData,
+            step,
+            step,
+            Some(step_data),
+            actor_id,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(Some(session))
     }
 
     /// Append avatar IDs to the session's avatar_ids array.
@@ -164,4 +210,74 @@ impl OnboardingSessionRepo {
                 .await?;
         Ok(row.0)
     }
+
+    // ── Event history ────────────────────────────────────────────────
+
+    /// List a session's transition history, oldest first.
+    pub async fn list_events(
+        pool: &PgPool,
+        session_id: DbId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<OnboardingSessionEvent>, sqlx::Error> {
+        let query = format!(
+            "SELECT {EVENT_COLUMNS} FROM onboarding_session_events \
+             WHERE session_id = $1 \
+             ORDER BY id ASC \
+             LIMIT $2 OFFSET $3"
+        );
+        sqlx::query_as::<_, OnboardingSessionEvent>(&query)
+            .bind(session_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Count the transitions recorded for a session.
+    pub async fn count_events(pool: &PgPool, session_id: DbId) -> Result<i64, sqlx::Error> {
+        let row: (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM onboarding_session_events WHERE session_id = $1")
+                .bind(session_id)
+                .fetch_one(pool)
+                .await?;
+        Ok(row.0)
+    }
+
+    /// Lock a session row for the rest of the transaction, returning its step.
+    async fn lock_current_step(
+        tx: &mut Transaction<'_, Postgres>,
+        id: DbId,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        sqlx::query_scalar("SELECT current_step FROM onboarding_sessions WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(&mut **tx)
+            .await
+    }
+
+    /// Append one transition to a session's history within `tx`.
+    async fn record_event(
+        tx: &mut Transaction<'_, Postgres>,
+        session_id: DbId,
+        event_type: OnboardingEventType,
+        from_step: i32,
+        to_step: i32,
+        step_data: Option<&serde_json::Value>,
+        actor_id: DbId,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO onboarding_session_events \
+                (session_id, event_type, from_step, to_step, step_data, actor_id) \
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(session_id)
+        .bind(event_type.as_str())
+        .bind(from_step)
+        .bind(to_step)
+        .bind(step_data)
+        .bind(actor_id)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 //! Repository for the `placement_rules` table (PRD-104).
 
 use sqlx::PgPool;
+use x121_core::download_manager::{self, DownloadFileMeta, PlacementRuleSpec, ResolvedPath};
 use x121_core::types::DbId;
 
 use crate::models::placement_rule::{CreatePlacementRule, PlacementRule, UpdatePlacementRule};
@@ -97,23 +98,18 @@ impl PlacementRuleRepo {
         Ok(result.rows_affected() > 0)
     }
 
-    /// Resolve the target path for a given model type and optional base model.
-    /// Returns the best matching directory from active rules, falling back to
-    /// `/models/{model_type}/` if no rules match.
-    pub async fn resolve_path(
+    /// Resolve where a downloaded file lands using the active rules.
+    ///
+    /// Falls back to `/models/{model_type}/` if no rules match.
+    pub async fn resolve_placement(
         pool: &PgPool,
-        model_type: &str,
-        base_model: Option<&str>,
-    ) -> Result<String, sqlx::Error> {
-        let rules = Self::list_active(pool).await?;
-        let rule_tuples: Vec<(String, Option<String>, String, i32)> = rules
-            .into_iter()
-            .map(|r| (r.model_type, r.base_model, r.target_directory, r.priority))
+        file: &DownloadFileMeta<'_>,
+    ) -> Result<ResolvedPath, sqlx::Error> {
+        let rules: Vec<PlacementRuleSpec> = Self::list_active(pool)
+            .await?
+            .iter()
+            .map(PlacementRule::to_spec)
             .collect();
-        Ok(x121_core::download_manager::resolve_target_directory(
-            model_type,
-            base_model,
-            &rule_tuples,
-        ))
+        Ok(download_manager::resolve_placement(file, &rules))
     }
 }
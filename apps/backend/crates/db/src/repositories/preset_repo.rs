@@ -142,6 +142,19 @@ impl PresetRepo {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Increment the usage counter within `tx`, so it only counts once the
+    /// surrounding apply commits.
+    pub async fn increment_usage_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: DbId,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE presets SET usage_count = usage_count + 1 WHERE id = $1")
+            .bind(id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Soft-deactivate a preset (set is_active = false).
     pub async fn deactivate(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
         let result =
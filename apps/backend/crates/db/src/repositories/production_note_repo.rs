@@ -4,7 +4,9 @@ use sqlx::PgPool;
 use x121_core::search::{clamp_limit, clamp_offset, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
 use x121_core::types::DbId;
 
-use crate::models::production_note::{CreateProductionNote, ProductionNote, UpdateProductionNote};
+use crate::models::production_note::{
+    CreateProductionNote, NoteSearchParams, NoteSearchResult, ProductionNote, UpdateProductionNote,
+};
 
 /// Column list for production_notes queries.
 const COLUMNS: &str = "id, entity_type, entity_id, user_id, content_md, category_id, \
@@ -94,18 +96,24 @@ impl ProductionNoteRepo {
             .await
     }
 
-    /// List child notes (replies) for a parent note.
+    /// List a note and all of its replies, at any depth, oldest first.
     pub async fn list_thread(
         pool: &PgPool,
-        parent_note_id: DbId,
+        root_note_id: DbId,
     ) -> Result<Vec<ProductionNote>, sqlx::Error> {
         let query = format!(
-            "SELECT {COLUMNS} FROM production_notes
-             WHERE parent_note_id = $1
-             ORDER BY created_at ASC"
+            "WITH RECURSIVE thread AS (
+                SELECT id FROM production_notes WHERE id = $1
+                UNION ALL
+                SELECT n.id FROM production_notes n
+                INNER JOIN thread t ON n.parent_note_id = t.id
+            )
+            SELECT {COLUMNS} FROM production_notes
+             WHERE id IN (SELECT id FROM thread)
+             ORDER BY created_at ASC, id ASC"
         );
         sqlx::query_as::<_, ProductionNote>(&query)
-            .bind(parent_note_id)
+            .bind(root_note_id)
             .fetch_all(pool)
             .await
     }
@@ -180,46 +188,51 @@ impl ProductionNoteRepo {
             .await
     }
 
-    /// Search notes by content (ILIKE), optionally filtered by entity type.
+    /// Search notes with combined filters and optional full-text ranking.
+    ///
+    /// With a text query, notes must match `websearch_to_tsquery` and are
+    /// ordered by `ts_rank` with a `ts_headline` snippet; otherwise they are
+    /// ordered newest first. The caller trims `q` and drops it when empty.
     pub async fn search(
         pool: &PgPool,
-        query_str: &str,
-        entity_type_filter: Option<&str>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<ProductionNote>, sqlx::Error> {
-        let limit = clamp_limit(limit, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT);
-        let offset = clamp_offset(offset);
-        let pattern = format!("%{query_str}%");
-
-        if let Some(entity_type) = entity_type_filter {
-            let query = format!(
-                "SELECT {COLUMNS} FROM production_notes
-                 WHERE content_md ILIKE $1 AND entity_type = $2
-                 ORDER BY created_at DESC
-                 LIMIT $3 OFFSET $4"
-            );
-            sqlx::query_as::<_, ProductionNote>(&query)
-                .bind(&pattern)
-                .bind(entity_type)
-                .bind(limit)
-                .bind(offset)
-                .fetch_all(pool)
-                .await
-        } else {
-            let query = format!(
-                "SELECT {COLUMNS} FROM production_notes
-                 WHERE content_md ILIKE $1
-                 ORDER BY created_at DESC
-                 LIMIT $2 OFFSET $3"
-            );
-            sqlx::query_as::<_, ProductionNote>(&query)
-                .bind(&pattern)
-                .bind(limit)
-                .bind(offset)
-                .fetch_all(pool)
-                .await
-        }
+        params: &NoteSearchParams,
+    ) -> Result<Vec<NoteSearchResult>, sqlx::Error> {
+        let limit = clamp_limit(params.limit, DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT);
+        let offset = clamp_offset(params.offset);
+
+        let query = format!(
+            "SELECT {COLUMNS},
+                    CASE WHEN $1::TEXT IS NULL THEN NULL
+                         ELSE ts_rank(to_tsvector('english', content_md),
+                                      websearch_to_tsquery('english', $1))
+                    END AS rank,
+                    CASE WHEN $1::TEXT IS NULL THEN NULL
+                         ELSE ts_headline('english', content_md,
+                                          websearch_to_tsquery('english', $1),
+                                          'MaxWords=35, MinWords=10')
+                    END AS snippet
+             FROM production_notes
+             WHERE ($1::TEXT IS NULL
+                    OR to_tsvector('english', content_md) @@ websearch_to_tsquery('english', $1))
+               AND ($2::TEXT IS NULL OR entity_type = $2)
+               AND ($3::BIGINT IS NULL OR entity_id = $3)
+               AND ($4::BIGINT IS NULL OR category_id = $4)
+               AND ($5::BIGINT IS NULL OR user_id = $5)
+               AND ($6::BOOLEAN IS NULL OR (resolved_at IS NOT NULL) = $6)
+             ORDER BY rank DESC NULLS LAST, created_at DESC, id DESC
+             LIMIT $7 OFFSET $8"
+        );
+        sqlx::query_as::<_, NoteSearchResult>(&query)
+            .bind(params.q.as_deref())
+            .bind(params.entity_type.as_deref())
+            .bind(params.entity_id)
+            .bind(params.category_id)
+            .bind(params.user_id)
+            .bind(params.resolved)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
     }
 
     /// Delete a production note by ID. Returns `true` if a row was deleted.
@@ -283,7 +283,7 @@ impl ProductionRunRepo {
         let query = format!(
             "SELECT {CELL_COLUMNS} FROM production_run_cells \
              WHERE run_id = $1 \
-             ORDER BY character_id, scene_type_id, variant_label"
+             ORDER BY character_id, scene_type_id, track_id NULLS FIRST, variant_label"
         );
         sqlx::query_as::<_, ProductionRunCell>(&query)
             .bind(run_id)
@@ -374,7 +374,7 @@ impl ProductionRunRepo {
         let query = format!(
             "SELECT {CELL_COLUMNS} FROM production_run_cells \
              WHERE run_id = $1 AND id = ANY($2) \
-             ORDER BY character_id, scene_type_id, variant_label"
+             ORDER BY character_id, scene_type_id, track_id NULLS FIRST, variant_label"
         );
         sqlx::query_as::<_, ProductionRunCell>(&query)
             .bind(run_id)
@@ -391,7 +391,7 @@ impl ProductionRunRepo {
         let query = format!(
             "SELECT {CELL_COLUMNS} FROM production_run_cells \
              WHERE run_id = $1 AND status_id = $2 \
-             ORDER BY character_id, scene_type_id, variant_label"
+             ORDER BY character_id, scene_type_id, track_id NULLS FIRST, variant_label"
         );
         sqlx::query_as::<_, ProductionRunCell>(&query)
             .bind(run_id)
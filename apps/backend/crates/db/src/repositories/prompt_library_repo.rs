@@ -9,7 +9,7 @@ use crate::models::prompt_library_entry::{
 
 /// Column list for prompt_library queries.
 const COLUMNS: &str = "id, name, description, positive_prompt, negative_prompt, \
-    tags, model_compatibility, usage_count, avg_rating, owner_id, \
+    tags, model_compatibility, usage_count, avg_rating, rating_count, owner_id, \
     created_at, updated_at";
 
 /// Provides CRUD operations for prompt library entries.
@@ -87,6 +87,24 @@ impl PromptLibraryRepo {
         }
     }
 
+    /// List every prompt library entry matching an optional name search.
+    ///
+    /// Used for ranked listings, which score entries in memory before
+    /// paginating.
+    pub async fn list_all(
+        pool: &PgPool,
+        search: Option<&str>,
+    ) -> Result<Vec<PromptLibraryEntry>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM prompt_library
+             WHERE $1::TEXT IS NULL OR name ILIKE $1"
+        );
+        sqlx::query_as::<_, PromptLibraryEntry>(&query)
+            .bind(search.map(|term| format!("%{term}%")))
+            .fetch_all(pool)
+            .await
+    }
+
     /// Update a prompt library entry. Only provided fields are updated.
     /// Returns `None` if the entry does not exist.
     pub async fn update(
@@ -136,13 +154,19 @@ impl PromptLibraryRepo {
         Ok(result.rows_affected() > 0)
     }
 
-    /// Update the average rating for a library entry. Returns `true` if updated.
-    pub async fn update_rating(pool: &PgPool, id: DbId, new_avg: f64) -> Result<bool, sqlx::Error> {
-        let result = sqlx::query("UPDATE prompt_library SET avg_rating = $1 WHERE id = $2")
-            .bind(new_avg)
-            .bind(id)
-            .execute(pool)
-            .await?;
+    /// Fold a new rating into the running average and bump the rating
+    /// count. Returns `true` if updated.
+    pub async fn record_rating(pool: &PgPool, id: DbId, rating: f64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE prompt_library SET
+                avg_rating   = (COALESCE(avg_rating, 0) * rating_count + $1) / (rating_count + 1),
+                rating_count = rating_count + 1
+             WHERE id = $2",
+        )
+        .bind(rating)
+        .bind(id)
+        .execute(pool)
+        .await?;
         Ok(result.rows_affected() > 0)
     }
 }
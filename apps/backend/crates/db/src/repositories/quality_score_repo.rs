@@ -64,6 +64,22 @@ impl QualityScoreRepo {
             .await
     }
 
+    /// List all quality scores across the segments of a scene.
+    pub async fn find_by_scene(
+        pool: &PgPool,
+        scene_id: DbId,
+    ) -> Result<Vec<QualityScore>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM quality_scores
+             WHERE segment_id IN (SELECT id FROM segments WHERE scene_id = $1)
+             ORDER BY segment_id, check_type"
+        );
+        sqlx::query_as::<_, QualityScore>(&query)
+            .bind(scene_id)
+            .fetch_all(pool)
+            .await
+    }
+
     /// Find a quality score for a specific segment + check type combination.
     pub async fn find_by_segment_and_type(
         pool: &PgPool,
@@ -3,7 +3,9 @@
 use sqlx::PgPool;
 use x121_core::types::DbId;
 
-use crate::models::readiness_cache::{AvatarReadinessCache, UpsertReadinessCache};
+use crate::models::readiness_cache::{
+    AvatarReadinessCache, AvatarReadinessInputs, UpsertReadinessCache,
+};
 
 /// Column list for avatar_readiness_cache queries.
 const COLUMNS: &str = "avatar_id, state, missing_items, readiness_pct, computed_at";
@@ -37,6 +39,41 @@ impl ReadinessCacheRepo {
             .await
     }
 
+    /// Load readiness inputs for the next chunk of live avatars with
+    /// `id > after_avatar_id`, ordered by id (keyset pagination).
+    pub async fn list_inputs_after(
+        pool: &PgPool,
+        after_avatar_id: DbId,
+        limit: i64,
+    ) -> Result<Vec<AvatarReadinessInputs>, sqlx::Error> {
+        sqlx::query_as::<_, AvatarReadinessInputs>(
+            "SELECT a.id AS avatar_id, a.project_id, a.metadata, a.settings,
+                EXISTS (
+                    SELECT 1 FROM media_variants mv
+                    WHERE mv.avatar_id = a.id AND mv.provenance = 'manual_upload'
+                      AND mv.deleted_at IS NULL
+                ) AS has_source_media,
+                EXISTS (
+                    SELECT 1 FROM media_variants mv
+                    WHERE mv.avatar_id = a.id AND mv.status_id = 2
+                      AND mv.deleted_at IS NULL
+                ) AS has_approved_variant,
+                EXISTS (
+                    SELECT 1 FROM avatar_metadata_versions v
+                    WHERE v.avatar_id = a.id AND v.is_active = true
+                      AND v.approval_status = 'approved' AND v.deleted_at IS NULL
+                ) AS has_metadata_approved
+             FROM avatars a
+             WHERE a.deleted_at IS NULL AND a.id > $1
+             ORDER BY a.id
+             LIMIT $2",
+        )
+        .bind(after_avatar_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
     /// Find a cached readiness entry for a single avatar.
     pub async fn find_by_avatar_id(
         pool: &PgPool,
@@ -79,6 +79,18 @@ impl ReclamationRepo {
             .await
     }
 
+    /// Find a protection rule by ID.
+    pub async fn find_protection_rule(
+        pool: &PgPool,
+        id: DbId,
+    ) -> Result<Option<AssetProtectionRule>, sqlx::Error> {
+        let query = format!("SELECT {RULE_COLUMNS} FROM asset_protection_rules WHERE id = $1");
+        sqlx::query_as::<_, AssetProtectionRule>(&query)
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
     /// Update an existing protection rule. Returns `None` if not found.
     pub async fn update_protection_rule(
         pool: &PgPool,
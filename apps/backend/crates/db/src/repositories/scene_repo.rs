@@ -74,6 +74,29 @@ impl SceneRepo {
             .await
     }
 
+    /// List all scenes of a project's avatars, ordered by ID.
+    /// Excludes soft-deleted scenes and scenes of soft-deleted avatars.
+    pub async fn list_by_project(
+        pool: &PgPool,
+        project_id: DbId,
+    ) -> Result<Vec<Scene>, sqlx::Error> {
+        let cols = COLUMNS
+            .split(", ")
+            .map(|c| format!("s.{}", c.trim()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT {cols} FROM scenes s
+             JOIN avatars a ON s.avatar_id = a.id
+             WHERE a.project_id = $1 AND s.deleted_at IS NULL AND a.deleted_at IS NULL
+             ORDER BY s.id"
+        );
+        sqlx::query_as::<_, Scene>(&query)
+            .bind(project_id)
+            .fetch_all(pool)
+            .await
+    }
+
     /// List scenes for a avatar with the best video version ID and version count.
     ///
     /// Uses a LATERAL subquery to pick the best version per scene:
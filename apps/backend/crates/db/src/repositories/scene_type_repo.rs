@@ -153,6 +153,27 @@ impl SceneTypeRepo {
         }
     }
 
+    /// List live slugs equal to `base` or of the form `{base}_*`, excluding
+    /// the scene type with id `exclude_id` (used when renaming in place).
+    ///
+    /// Feeds slug collision detection and suffix suggestion.
+    pub async fn list_slugs_with_base(
+        pool: &PgPool,
+        base: &str,
+        exclude_id: Option<DbId>,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT slug FROM scene_types \
+             WHERE (slug = $1 OR starts_with(slug, $1 || '_')) \
+               AND deleted_at IS NULL \
+               AND ($2::BIGINT IS NULL OR id <> $2)",
+        )
+        .bind(base)
+        .bind(exclude_id)
+        .fetch_all(pool)
+        .await
+    }
+
     /// List scene types scoped to a specific project, ordered by most recently created first.
     /// Excludes soft-deleted rows.
     pub async fn list_by_project(
@@ -301,6 +322,38 @@ impl SceneTypeRepo {
             .await
     }
 
+    /// Lock a live scene type row for the rest of the transaction, returning
+    /// its `generation_params` (`None` when the scene type does not exist).
+    pub async fn lock_generation_params(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: DbId,
+    ) -> Result<Option<Option<serde_json::Value>>, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT generation_params FROM scene_types \
+             WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut **tx)
+        .await
+    }
+
+    /// Overwrite a scene type's `generation_params` within `tx`.
+    pub async fn set_generation_params(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: DbId,
+        generation_params: &serde_json::Value,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE scene_types SET generation_params = $2 \
+             WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(generation_params)
+        .execute(&mut **tx)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     /// List studio-level AND project-level scene types for a project.
     /// Ordered by sort_order, then name.
     pub async fn list_for_project(
@@ -219,6 +219,22 @@ impl SceneVideoVersionRepo {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Soft-delete a version only if it is not the scene's final version.
+    ///
+    /// The check and the delete are a single statement, so a concurrent
+    /// [`set_final`](Self::set_final) cannot promote the row in between.
+    /// Returns `true` if a row was deleted.
+    pub async fn soft_delete_unless_final(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE scene_video_versions SET deleted_at = NOW() \
+             WHERE id = $1 AND deleted_at IS NULL AND is_final = false",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Restore a soft-deleted scene video version. Returns `true` if a row was restored.
     pub async fn restore(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
         let result = sqlx::query(
@@ -387,7 +403,13 @@ impl SceneVideoVersionRepo {
     /// Mark a version as final, un-marking any previously final version for the
     /// same scene. Uses a transaction to ensure atomicity.
     ///
-    /// Returns `None` if `version_id` does not exist for the given `scene_id`.
+    /// The scene row is locked first so concurrent calls for the same scene
+    /// run one after another and can never leave two versions final. The
+    /// target version is locked too, so a concurrent soft-delete either
+    /// completes first (and this returns `None`) or waits until this commits.
+    ///
+    /// Returns `None` if `version_id` does not exist for the given `scene_id`
+    /// or has been deleted.
     pub async fn set_final(
         pool: &PgPool,
         scene_id: DbId,
@@ -395,29 +417,48 @@ impl SceneVideoVersionRepo {
     ) -> Result<Option<SceneVideoVersion>, sqlx::Error> {
         let mut tx = pool.begin().await?;
 
+        sqlx::query("SELECT id FROM scenes WHERE id = $1 FOR UPDATE")
+            .bind(scene_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let target: Option<(DbId,)> = sqlx::query_as(
+            "SELECT id FROM scene_video_versions \
+             WHERE id = $1 AND scene_id = $2 AND deleted_at IS NULL \
+             FOR UPDATE",
+        )
+        .bind(version_id)
+        .bind(scene_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if target.is_none() {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
         // Unmark current final (if any)
         sqlx::query(
             "UPDATE scene_video_versions SET is_final = false \
-             WHERE scene_id = $1 AND is_final = true AND deleted_at IS NULL",
+             WHERE scene_id = $1 AND is_final = true AND id <> $2 AND deleted_at IS NULL",
         )
         .bind(scene_id)
+        .bind(version_id)
         .execute(&mut *tx)
         .await?;
 
         // Mark the specified version as final
         let query = format!(
             "UPDATE scene_video_versions SET is_final = true \
-             WHERE id = $1 AND scene_id = $2 AND deleted_at IS NULL \
+             WHERE id = $1 \
              RETURNING {COLUMNS}"
         );
         let result = sqlx::query_as::<_, SceneVideoVersion>(&query)
             .bind(version_id)
-            .bind(scene_id)
-            .fetch_optional(&mut *tx)
+            .fetch_one(&mut *tx)
             .await?;
 
         tx.commit().await?;
-        Ok(result)
+        Ok(Some(result))
     }
 
     /// Find the current final version for a scene (if any).
@@ -547,6 +588,36 @@ impl SceneVideoVersionRepo {
             .await
     }
 
+    /// List the non-empty final versions of every live scene in a project.
+    ///
+    /// Uses the same "non-empty" rule as [`Self::find_scenes_missing_final`],
+    /// so a scene appears here exactly when it is absent from that list.
+    pub async fn list_finals_for_project(
+        pool: &PgPool,
+        project_id: DbId,
+    ) -> Result<Vec<SceneVideoVersion>, sqlx::Error> {
+        let prefixed = COLUMNS
+            .split(", ")
+            .map(|c| format!("svv.{}", c.trim()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT {prefixed} FROM scene_video_versions svv \
+             JOIN scenes s ON s.id = svv.scene_id AND s.deleted_at IS NULL \
+             JOIN avatars c ON c.id = s.avatar_id AND c.deleted_at IS NULL \
+             WHERE c.project_id = $1 \
+               AND svv.is_final = true \
+               AND svv.deleted_at IS NULL \
+               AND svv.file_size_bytes IS NOT NULL \
+               AND svv.file_size_bytes > 0 \
+             ORDER BY svv.scene_id"
+        );
+        sqlx::query_as::<_, SceneVideoVersion>(&query)
+            .bind(project_id)
+            .fetch_all(pool)
+            .await
+    }
+
     /// Find scene IDs in a project that have no final video version with
     /// actual content (non-empty file).
     ///
@@ -2,8 +2,10 @@
 //!
 //! Covers: `scheduling_policies`, `gpu_quotas`, `job_state_transitions`.
 
+use chrono::Utc;
 use sqlx::PgPool;
-use x121_core::types::DbId;
+use x121_core::quota_reset::quota_windows;
+use x121_core::types::{DbId, Timestamp};
 
 use crate::models::scheduling::{
     GpuQuota, JobStateTransition, QuotaStatus, SchedulingPolicy, SetGpuQuota,
@@ -104,7 +106,7 @@ impl SchedulingPolicyRepo {
 
 const QUOTA_COLUMNS: &str = "\
     id, user_id, project_id, daily_limit_secs, weekly_limit_secs, \
-    is_enabled, created_at, updated_at";
+    is_enabled, timezone, daily_reset_at, weekly_reset_at, created_at, updated_at";
 
 /// CRUD for the `gpu_quotas` table.
 pub struct GpuQuotaRepo;
@@ -133,10 +135,12 @@ impl GpuQuotaRepo {
         input: &SetGpuQuota,
     ) -> Result<GpuQuota, sqlx::Error> {
         let query = format!(
-            "INSERT INTO gpu_quotas (user_id, daily_limit_secs, weekly_limit_secs, is_enabled) \
-             VALUES ($1, $2, $3, $4) \
+            "INSERT INTO gpu_quotas \
+                (user_id, daily_limit_secs, weekly_limit_secs, is_enabled, timezone) \
+             VALUES ($1, $2, $3, $4, COALESCE($5, 'UTC')) \
              ON CONFLICT (user_id) WHERE project_id IS NULL \
-             DO UPDATE SET daily_limit_secs = $2, weekly_limit_secs = $3, is_enabled = $4 \
+             DO UPDATE SET daily_limit_secs = $2, weekly_limit_secs = $3, is_enabled = $4, \
+                timezone = COALESCE($5, gpu_quotas.timezone) \
              RETURNING {QUOTA_COLUMNS}"
         );
         // Note: The ON CONFLICT needs a partial unique index. Fall back to
@@ -146,6 +150,7 @@ impl GpuQuotaRepo {
             .bind(input.daily_limit_secs)
             .bind(input.weekly_limit_secs)
             .bind(input.is_enabled)
+            .bind(&input.timezone)
             .fetch_optional(pool)
             .await;
 
@@ -168,7 +173,8 @@ impl GpuQuotaRepo {
         if let Some(existing) = existing {
             let query = format!(
                 "UPDATE gpu_quotas \
-                 SET daily_limit_secs = $2, weekly_limit_secs = $3, is_enabled = $4 \
+                 SET daily_limit_secs = $2, weekly_limit_secs = $3, is_enabled = $4, \
+                     timezone = COALESCE($5, timezone) \
                  WHERE id = $1 \
                  RETURNING {QUOTA_COLUMNS}"
             );
@@ -177,12 +183,14 @@ impl GpuQuotaRepo {
                 .bind(input.daily_limit_secs)
                 .bind(input.weekly_limit_secs)
                 .bind(input.is_enabled)
+                .bind(&input.timezone)
                 .fetch_one(pool)
                 .await
         } else {
             let query = format!(
-                "INSERT INTO gpu_quotas (user_id, daily_limit_secs, weekly_limit_secs, is_enabled) \
-                 VALUES ($1, $2, $3, $4) \
+                "INSERT INTO gpu_quotas \
+                    (user_id, daily_limit_secs, weekly_limit_secs, is_enabled, timezone) \
+                 VALUES ($1, $2, $3, $4, COALESCE($5, 'UTC')) \
                  RETURNING {QUOTA_COLUMNS}"
             );
             sqlx::query_as::<_, GpuQuota>(&query)
@@ -190,12 +198,47 @@ impl GpuQuotaRepo {
                 .bind(input.daily_limit_secs)
                 .bind(input.weekly_limit_secs)
                 .bind(input.is_enabled)
+                .bind(&input.timezone)
                 .fetch_one(pool)
                 .await
         }
     }
 
-    /// Check a user's quota status by summing completed job durations.
+    /// List all enabled quotas, for applying period resets.
+    pub async fn list_enabled(pool: &PgPool) -> Result<Vec<GpuQuota>, sqlx::Error> {
+        let query = format!("SELECT {QUOTA_COLUMNS} FROM gpu_quotas WHERE is_enabled = true");
+        sqlx::query_as::<_, GpuQuota>(&query).fetch_all(pool).await
+    }
+
+    /// Move a quota's usage windows forward to new reset instants.
+    ///
+    /// Only ever advances: a reset older than the stored one is ignored, so a
+    /// slow task cannot reopen a window. Returns `true` if the row changed.
+    pub async fn apply_resets(
+        pool: &PgPool,
+        id: DbId,
+        daily_reset_at: Timestamp,
+        weekly_reset_at: Timestamp,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE gpu_quotas \
+             SET daily_reset_at = GREATEST(daily_reset_at, $2), \
+                 weekly_reset_at = GREATEST(weekly_reset_at, $3) \
+             WHERE id = $1 AND (daily_reset_at < $2 OR weekly_reset_at < $3)",
+        )
+        .bind(id)
+        .bind(daily_reset_at)
+        .bind(weekly_reset_at)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Check a user's quota status by summing completed job durations since
+    /// the start of the current daily and weekly windows.
+    ///
+    /// Windows are resolved at call time, so a reset that has fallen due is
+    /// honoured even before the reset task has recorded it.
     pub async fn check_quota(pool: &PgPool, user_id: DbId) -> Result<QuotaStatus, sqlx::Error> {
         let quota = Self::find_by_user(pool, user_id).await?;
 
@@ -203,29 +246,27 @@ impl GpuQuotaRepo {
             return Ok(QuotaStatus::NoQuota);
         };
 
-        // Sum actual_duration_secs for completed jobs today.
-        let today_used: i64 = sqlx::query_scalar(
-            "SELECT COALESCE(SUM(actual_duration_secs), 0)::BIGINT FROM jobs \
-             WHERE submitted_by = $1 \
-               AND completed_at >= CURRENT_DATE \
-               AND status_id = $2",
-        )
-        .bind(user_id)
-        .bind(JobStatus::Completed.id())
-        .fetch_one(pool)
-        .await?;
+        let windows = quota_windows(
+            &quota.timezone,
+            quota.daily_reset_at,
+            quota.weekly_reset_at,
+            Utc::now(),
+        );
 
-        // Sum actual_duration_secs for completed jobs this week (Monday start).
-        let week_used: i64 = sqlx::query_scalar(
-            "SELECT COALESCE(SUM(actual_duration_secs), 0)::BIGINT FROM jobs \
-             WHERE submitted_by = $1 \
-               AND completed_at >= date_trunc('week', CURRENT_DATE) \
-               AND status_id = $2",
-        )
-        .bind(user_id)
-        .bind(JobStatus::Completed.id())
-        .fetch_one(pool)
-        .await?;
+        let used_since = |since: Timestamp| {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COALESCE(SUM(actual_duration_secs), 0)::BIGINT FROM jobs \
+                 WHERE submitted_by = $1 \
+                   AND completed_at >= $2 \
+                   AND status_id = $3",
+            )
+            .bind(user_id)
+            .bind(since)
+            .bind(JobStatus::Completed.id())
+            .fetch_one(pool)
+        };
+        let today_used = used_since(windows.daily_start).await?;
+        let week_used = used_since(windows.weekly_start).await?;
 
         // Check daily limit exceeded.
         if let Some(daily_limit) = quota.daily_limit_secs {
@@ -235,6 +276,8 @@ impl GpuQuotaRepo {
                     daily_limit_secs: quota.daily_limit_secs,
                     used_this_week_secs: week_used,
                     weekly_limit_secs: quota.weekly_limit_secs,
+                    daily_resets_at: windows.daily_resets_at,
+                    weekly_resets_at: windows.weekly_resets_at,
                     exceeded_type: "daily".into(),
                 });
             }
@@ -248,6 +291,8 @@ impl GpuQuotaRepo {
                     daily_limit_secs: quota.daily_limit_secs,
                     used_this_week_secs: week_used,
                     weekly_limit_secs: quota.weekly_limit_secs,
+                    daily_resets_at: windows.daily_resets_at,
+                    weekly_resets_at: windows.weekly_resets_at,
                     exceeded_type: "weekly".into(),
                 });
             }
@@ -258,6 +303,8 @@ impl GpuQuotaRepo {
             daily_limit_secs: quota.daily_limit_secs,
             used_this_week_secs: week_used,
             weekly_limit_secs: quota.weekly_limit_secs,
+            daily_resets_at: windows.daily_resets_at,
+            weekly_resets_at: windows.weekly_resets_at,
         })
     }
 }
@@ -2,11 +2,14 @@
 
 use sqlx::PgPool;
 use x121_core::scripting::status::{
-    EXECUTION_COMPLETED, EXECUTION_FAILED, EXECUTION_RUNNING, EXECUTION_TIMEOUT,
+    ExecutionOutcome, EXECUTION_CANCELLED, EXECUTION_COMPLETED, EXECUTION_FAILED,
+    EXECUTION_RUNNING, EXECUTION_TIMEOUT,
 };
 use x121_core::types::DbId;
 
-use crate::models::script::{CreateScriptExecution, ScriptExecution};
+use crate::models::script::{
+    CreateScriptExecution, ScriptExecution, ScriptExecutionFilter, ScriptExecutionSummary,
+};
 
 /// Column list for `script_executions` SELECT queries, including joined status name.
 const COLUMNS: &str = "\
@@ -18,6 +21,22 @@ const COLUMNS: &str = "\
     se.started_at, se.completed_at, \
     se.created_at, se.updated_at";
 
+/// Column list for execution history rows (no captured I/O).
+const SUMMARY_COLUMNS: &str = "\
+    se.id, se.script_id, se.job_id, se.triggered_by, \
+    se.status_id, es.name AS status_name, \
+    se.exit_code, se.duration_ms, se.error_message, \
+    se.started_at, se.completed_at, se.created_at";
+
+/// WHERE clause shared by the history list and count queries.
+///
+/// `$1` is the script id, `$2`/`$3` the optional time range. The outcome
+/// predicate is a static fragment chosen by [`outcome_predicate`].
+const HISTORY_FILTER: &str = "\
+    se.script_id = $1 \
+    AND ($2::TIMESTAMPTZ IS NULL OR se.created_at >= $2) \
+    AND ($3::TIMESTAMPTZ IS NULL OR se.created_at <= $3)";
+
 /// Join clause used in all read queries to include the execution status name.
 const JOIN: &str = "\
     script_executions se \
@@ -135,6 +154,22 @@ impl ScriptExecutionRepo {
         Ok(())
     }
 
+    /// Mark an execution as cancelled before it finished.
+    pub async fn cancel(pool: &PgPool, id: DbId) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE script_executions SET \
+                status_id = $2, \
+                completed_at = now() \
+             WHERE id = $1",
+        )
+        .bind(id)
+        .bind(EXECUTION_CANCELLED)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Find an execution by its ID, including the joined status name.
     pub async fn find_by_id(
         pool: &PgPool,
@@ -169,4 +204,62 @@ impl ScriptExecutionRepo {
             .fetch_all(pool)
             .await
     }
+
+    /// List execution history rows for a script, most recent first.
+    pub async fn list_history(
+        pool: &PgPool,
+        script_id: DbId,
+        filter: &ScriptExecutionFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ScriptExecutionSummary>, sqlx::Error> {
+        let query = format!(
+            "SELECT {SUMMARY_COLUMNS} FROM {JOIN} \
+             WHERE {HISTORY_FILTER} AND {} \
+             ORDER BY se.created_at DESC, se.id DESC \
+             LIMIT $4 OFFSET $5",
+            outcome_predicate(filter.outcome)
+        );
+        sqlx::query_as::<_, ScriptExecutionSummary>(&query)
+            .bind(script_id)
+            .bind(filter.from)
+            .bind(filter.to)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Count execution history rows for a script matching `filter`.
+    pub async fn count_history(
+        pool: &PgPool,
+        script_id: DbId,
+        filter: &ScriptExecutionFilter,
+    ) -> Result<i64, sqlx::Error> {
+        let query = format!(
+            "SELECT COUNT(*) FROM {JOIN} WHERE {HISTORY_FILTER} AND {}",
+            outcome_predicate(filter.outcome)
+        );
+        sqlx::query_scalar::<_, i64>(&query)
+            .bind(script_id)
+            .bind(filter.from)
+            .bind(filter.to)
+            .fetch_one(pool)
+            .await
+    }
+}
+
+/// SQL predicate selecting executions with the given outcome.
+///
+/// A completed run with a non-zero exit code is a failure.
+fn outcome_predicate(outcome: Option<ExecutionOutcome>) -> &'static str {
+    match outcome {
+        None => "TRUE",
+        Some(ExecutionOutcome::Success) => "(es.name = 'completed' AND se.exit_code = 0)",
+        Some(ExecutionOutcome::Failure) => {
+            "(es.name = 'failed' OR (es.name = 'completed' AND se.exit_code IS DISTINCT FROM 0))"
+        }
+        Some(ExecutionOutcome::Timeout) => "es.name = 'timeout'",
+        Some(ExecutionOutcome::Cancelled) => "es.name = 'cancelled'",
+    }
 }
@@ -258,7 +258,7 @@ impl SearchRepo {
                 FROM scene_types \
                 WHERE search_vector @@ to_tsquery('english', $1) AND deleted_at IS NULL \
             ) sub \
-            ORDER BY rank DESC \
+            ORDER BY rank DESC, entity_type, entity_id \
             LIMIT $2";
 
         sqlx::query_as::<_, TypeaheadResult>(sql)
@@ -6,8 +6,8 @@ use x121_core::types::DbId;
 
 use crate::models::status::StatusId;
 use crate::models::storage::{
-    AssetLocation, CreateAssetLocation, CreateStorageBackend, CreateStorageMigration,
-    CreateTieringPolicy, StorageBackend, StorageMigration, TieringCandidate, TieringPolicy,
+    AssetLocation, AssetPlacement, CreateAssetLocation, CreateStorageBackend,
+    CreateStorageMigration, CreateTieringPolicy, StorageBackend, StorageMigration, TieringPolicy,
     UpdateStorageBackend, UpdateTieringPolicy,
 };
 
@@ -230,37 +230,27 @@ impl AssetLocationRepo {
         Ok(())
     }
 
-    /// Find assets that match tiering-policy criteria for migration.
-    ///
-    /// Returns assets in the given tier that have not been accessed within
-    /// `access_threshold_days` or were created more than `age_threshold_days` ago.
-    pub async fn find_tiering_candidates(
+    /// List `entity_type` assets held on backends in any of `tiers`, largest
+    /// first, with their age and idle time for policy matching.
+    pub async fn list_placements(
         pool: &PgPool,
         entity_type: &str,
-        source_tier: &str,
-        age_threshold_days: Option<i32>,
-        access_threshold_days: Option<i32>,
-    ) -> Result<Vec<TieringCandidate>, sqlx::Error> {
+        tiers: &[String],
+    ) -> Result<Vec<AssetPlacement>, sqlx::Error> {
         let query = "\
             SELECT \
                 al.entity_type, al.entity_id, al.file_field, al.file_size_bytes, \
-                al.backend_id AS current_backend_id, al.last_accessed_at, al.access_count \
+                al.backend_id, sb.tier, \
+                FLOOR(EXTRACT(EPOCH FROM NOW() - al.created_at) / 86400)::BIGINT AS age_days, \
+                FLOOR(EXTRACT(EPOCH FROM NOW() - al.last_accessed_at) / 86400)::BIGINT AS idle_days \
             FROM asset_locations al \
             JOIN storage_backends sb ON sb.id = al.backend_id \
             WHERE al.entity_type = $1 \
-              AND sb.tier = $2 \
-              AND ( \
-                ($3::int IS NOT NULL AND al.created_at < NOW() - ($3 || ' days')::interval) \
-                OR \
-                ($4::int IS NOT NULL AND (al.last_accessed_at IS NULL OR al.last_accessed_at < NOW() - ($4 || ' days')::interval)) \
-              ) \
-            ORDER BY al.file_size_bytes DESC \
-            LIMIT 500";
-        sqlx::query_as::<_, TieringCandidate>(query)
+              AND sb.tier = ANY($2) \
+            ORDER BY al.file_size_bytes DESC, al.id ASC";
+        sqlx::query_as::<_, AssetPlacement>(query)
             .bind(entity_type)
-            .bind(source_tier)
-            .bind(age_threshold_days)
-            .bind(access_threshold_days)
+            .bind(tiers)
             .fetch_all(pool)
             .await
     }
@@ -13,7 +13,7 @@ use crate::models::theme::{
 /// Column list for `user_theme_preferences` queries.
 const PREF_COLUMNS: &str = "\
     id, user_id, color_scheme, brand_palette, high_contrast, \
-    custom_theme_id, created_at, updated_at";
+    custom_theme_id, token_overrides, created_at, updated_at";
 
 /// Column list for `custom_themes` queries.
 const THEME_COLUMNS: &str = "\
@@ -45,6 +45,7 @@ impl ThemeRepo {
     /// Insert or update the theme preference for a user.
     ///
     /// Uses `ON CONFLICT (user_id) DO UPDATE` to ensure idempotent upserts.
+    /// Token overrides are only replaced when the DTO supplies them.
     pub async fn upsert_user_preference(
         pool: &PgPool,
         user_id: DbId,
@@ -52,13 +53,15 @@ impl ThemeRepo {
     ) -> Result<UserThemePreference, sqlx::Error> {
         let query = format!(
             "INSERT INTO user_theme_preferences \
-                 (user_id, color_scheme, brand_palette, high_contrast, custom_theme_id) \
-             VALUES ($1, $2, $3, $4, $5) \
+                 (user_id, color_scheme, brand_palette, high_contrast, custom_theme_id, \
+                  token_overrides) \
+             VALUES ($1, $2, $3, $4, $5, COALESCE($6, '{{}}'::JSONB)) \
              ON CONFLICT (user_id) DO UPDATE SET \
                  color_scheme = EXCLUDED.color_scheme, \
                  brand_palette = EXCLUDED.brand_palette, \
                  high_contrast = EXCLUDED.high_contrast, \
-                 custom_theme_id = EXCLUDED.custom_theme_id \
+                 custom_theme_id = EXCLUDED.custom_theme_id, \
+                 token_overrides = COALESCE($6, user_theme_preferences.token_overrides) \
              RETURNING {PREF_COLUMNS}"
         );
         sqlx::query_as::<_, UserThemePreference>(&query)
@@ -67,6 +70,7 @@ impl ThemeRepo {
             .bind(&dto.brand_palette)
             .bind(dto.high_contrast)
             .bind(dto.custom_theme_id)
+            .bind(&dto.token_overrides)
             .fetch_one(pool)
             .await
     }
@@ -3,7 +3,7 @@
 use sqlx::PgPool;
 use x121_core::types::DbId;
 
-use crate::models::undo_tree::{SaveUndoTree, UndoTree};
+use crate::models::undo_tree::{SaveUndoTree, UndoTree, UndoTreeSummary};
 
 /// Column list for `undo_trees` queries.
 const COLUMNS: &str =
@@ -80,18 +80,35 @@ impl UndoTreeRepo {
         Ok(())
     }
 
-    /// List all undo trees belonging to a user, ordered by most recently updated.
-    pub async fn list_trees_for_user(
+    /// List summaries of a user's undo trees, most recently updated first,
+    /// optionally restricted to one entity type.
+    ///
+    /// Ties on `updated_at` are broken by `id` so pages neither repeat nor
+    /// skip trees.
+    pub async fn list_summaries_for_user(
         pool: &PgPool,
         user_id: DbId,
-    ) -> Result<Vec<UndoTree>, sqlx::Error> {
-        let sql = format!(
-            "SELECT {COLUMNS} FROM undo_trees \
-             WHERE user_id = $1 ORDER BY updated_at DESC"
-        );
-        sqlx::query_as::<_, UndoTree>(&sql)
-            .bind(user_id)
-            .fetch_all(pool)
-            .await
+        entity_type: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<UndoTreeSummary>, sqlx::Error> {
+        sqlx::query_as::<_, UndoTreeSummary>(
+            "SELECT id, entity_type, entity_id, \
+                    CASE WHEN jsonb_typeof(tree_json -> 'nodes') = 'object' \
+                         THEN (SELECT COUNT(*) FROM jsonb_object_keys(tree_json -> 'nodes')) \
+                         ELSE 0 \
+                    END AS node_count, \
+                    current_node_id, updated_at \
+             FROM undo_trees \
+             WHERE user_id = $1 AND ($2::TEXT IS NULL OR entity_type = $2) \
+             ORDER BY updated_at DESC, id DESC \
+             LIMIT $3 OFFSET $4",
+        )
+        .bind(user_id)
+        .bind(entity_type)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
     }
 }
@@ -1,13 +1,14 @@
 //! Repository for the `user_api_tokens` table (PRD-104).
 
 use sqlx::PgPool;
+use x121_core::crypto::EncryptedToken;
 use x121_core::types::DbId;
 
 use crate::models::user_api_token::{ApiTokenInfo, UserApiToken};
 
 /// Column list shared across queries to avoid repetition.
-const COLUMNS: &str = "id, user_id, service_name, encrypted_token, token_hint, \
-    is_valid, last_used_at, created_at, updated_at";
+const COLUMNS: &str = "id, user_id, service_name, encrypted_token, encryption_key_id, \
+    token_hint, is_valid, last_used_at, created_at, updated_at";
 
 /// Provides CRUD operations for user API tokens.
 pub struct UserApiTokenRepo;
@@ -18,14 +19,16 @@ impl UserApiTokenRepo {
         pool: &PgPool,
         user_id: DbId,
         service_name: &str,
-        encrypted_token: &[u8],
+        token: &EncryptedToken,
         token_hint: &str,
     ) -> Result<UserApiToken, sqlx::Error> {
         let query = format!(
-            "INSERT INTO user_api_tokens (user_id, service_name, encrypted_token, token_hint)
-             VALUES ($1, $2, $3, $4)
+            "INSERT INTO user_api_tokens
+                (user_id, service_name, encrypted_token, encryption_key_id, token_hint)
+             VALUES ($1, $2, $3, $4, $5)
              ON CONFLICT (user_id, service_name) DO UPDATE SET
                 encrypted_token = EXCLUDED.encrypted_token,
+                encryption_key_id = EXCLUDED.encryption_key_id,
                 token_hint = EXCLUDED.token_hint,
                 is_valid = true
              RETURNING {COLUMNS}"
@@ -33,7 +36,8 @@ impl UserApiTokenRepo {
         sqlx::query_as::<_, UserApiToken>(&query)
             .bind(user_id)
             .bind(service_name)
-            .bind(encrypted_token)
+            .bind(&token.ciphertext)
+            .bind(&token.key_id)
             .bind(token_hint)
             .fetch_one(pool)
             .await
@@ -156,4 +156,24 @@ impl UserRepo {
             .await?;
         Ok(result.rows_affected() > 0)
     }
+
+    /// Replace a user's password hash only if it is still `current_hash`.
+    ///
+    /// Returns `false` if the hash changed in the meantime (e.g. an admin
+    /// reset), in which case nothing is written.
+    pub async fn replace_password_hash(
+        pool: &PgPool,
+        id: DbId,
+        current_hash: &str,
+        new_hash: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let result =
+            sqlx::query("UPDATE users SET password_hash = $2 WHERE id = $1 AND password_hash = $3")
+                .bind(id)
+                .bind(new_hash)
+                .bind(current_hash)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -1,9 +1,10 @@
-//! Repository for the `webhooks` and `webhook_deliveries` tables (PRD-12).
+//! Repository for the `webhooks`, `webhook_deliveries`, and
+//! `webhook_dead_letters` tables (PRD-12).
 
 use sqlx::PgPool;
 use x121_core::types::DbId;
 
-use crate::models::api_key::{Webhook, WebhookDelivery};
+use crate::models::api_key::{Webhook, WebhookDeadLetter, WebhookDelivery, WebhookDeliveryFilter};
 
 // ---------------------------------------------------------------------------
 // Column lists
@@ -11,12 +12,24 @@ use crate::models::api_key::{Webhook, WebhookDelivery};
 
 const WEBHOOK_COLUMNS: &str = "\
     id, name, url, secret, event_types, is_enabled, created_by, \
-    last_triggered_at, failure_count, created_at, updated_at";
+    last_triggered_at, failure_count, circuit_state, consecutive_failures, \
+    suspended_at, next_probe_at, created_at, updated_at";
 
 const DELIVERY_COLUMNS: &str = "\
     id, webhook_id, event_id, payload, status, response_status_code, \
     response_body, attempt_count, max_attempts, next_retry_at, \
-    delivered_at, created_at, updated_at";
+    delivered_at, latency_ms, created_at, updated_at";
+
+const DEAD_LETTER_COLUMNS: &str = "\
+    id, webhook_id, delivery_id, url, payload, last_error, attempt_count, \
+    replayed_at, created_at";
+
+/// Delivery-history conditions bound as `$2` (status), `$3` (since) and
+/// `$4` (until); a NULL parameter disables its condition.
+const DELIVERY_FILTER: &str = "\
+    AND ($2::TEXT IS NULL OR status = $2) \
+    AND ($3::TIMESTAMPTZ IS NULL OR created_at >= $3) \
+    AND ($4::TIMESTAMPTZ IS NULL OR created_at < $4)";
 
 /// Provides CRUD operations for webhooks and webhook deliveries.
 pub struct WebhookRepo;
@@ -134,7 +147,8 @@ impl WebhookRepo {
     /// List pending deliveries ready for processing.
     ///
     /// Returns deliveries that are pending/retrying, past their retry time,
-    /// and under the max attempt count.
+    /// and under the max attempt count. Deliveries for disabled or
+    /// suspended webhooks are held back.
     pub async fn list_pending_deliveries(
         pool: &PgPool,
         limit: i64,
@@ -144,6 +158,10 @@ impl WebhookRepo {
              WHERE (status = 'pending' OR status = 'retrying') \
                AND (next_retry_at IS NULL OR next_retry_at <= NOW()) \
                AND attempt_count < max_attempts \
+               AND webhook_id IN ( \
+                   SELECT id FROM webhooks \
+                   WHERE is_enabled = true AND circuit_state = 'active' \
+               ) \
              ORDER BY created_at ASC LIMIT $1"
         );
         sqlx::query_as::<_, WebhookDelivery>(&query)
@@ -152,39 +170,47 @@ impl WebhookRepo {
             .await
     }
 
-    /// Mark a delivery as successfully delivered.
+    /// Mark a delivery as successfully delivered, counting the final attempt.
     pub async fn mark_delivered(
         pool: &PgPool,
         delivery_id: DbId,
         response_status_code: i16,
+        latency_ms: i32,
     ) -> Result<(), sqlx::Error> {
         sqlx::query(
             "UPDATE webhook_deliveries SET \
                  status = 'delivered', \
+                 attempt_count = attempt_count + 1, \
                  response_status_code = $2, \
+                 latency_ms = $3, \
                  delivered_at = NOW() \
              WHERE id = $1",
         )
         .bind(delivery_id)
         .bind(response_status_code)
+        .bind(latency_ms)
         .execute(pool)
         .await?;
         Ok(())
     }
 
     /// Schedule a retry with exponential backoff.
+    ///
+    /// `response_status_code` is `None` when no HTTP response was received.
     pub async fn schedule_retry(
         pool: &PgPool,
         delivery_id: DbId,
-        response_status_code: i16,
+        response_status_code: Option<i16>,
         attempt_count: i16,
         delay_secs: i64,
+        latency_ms: i32,
     ) -> Result<(), sqlx::Error> {
         sqlx::query(
             "UPDATE webhook_deliveries SET \
                  status = CASE WHEN $3 >= max_attempts THEN 'failed' ELSE 'retrying' END, \
                  attempt_count = $3, \
                  response_status_code = $2, \
+                 latency_ms = $5, \
                  next_retry_at = NOW() + ($4 || ' seconds')::INTERVAL \
              WHERE id = $1",
         )
@@ -192,31 +218,55 @@ impl WebhookRepo {
         .bind(response_status_code)
         .bind(attempt_count)
         .bind(delay_secs.to_string())
+        .bind(latency_ms)
         .execute(pool)
         .await?;
         Ok(())
     }
 
-    /// List deliveries for a specific webhook with pagination.
+    /// List a webhook's deliveries matching `filter`, newest first.
     pub async fn list_deliveries_for_webhook(
         pool: &PgPool,
         webhook_id: DbId,
+        filter: &WebhookDeliveryFilter,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
         let query = format!(
             "SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries \
-             WHERE webhook_id = $1 \
-             ORDER BY created_at DESC LIMIT $2 OFFSET $3"
+             WHERE webhook_id = $1 {DELIVERY_FILTER} \
+             ORDER BY created_at DESC, id DESC LIMIT $5 OFFSET $6"
         );
         sqlx::query_as::<_, WebhookDelivery>(&query)
             .bind(webhook_id)
+            .bind(filter.status)
+            .bind(filter.since)
+            .bind(filter.until)
             .bind(limit)
             .bind(offset)
             .fetch_all(pool)
             .await
     }
 
+    /// Count a webhook's deliveries matching `filter`.
+    pub async fn count_deliveries_for_webhook(
+        pool: &PgPool,
+        webhook_id: DbId,
+        filter: &WebhookDeliveryFilter,
+    ) -> Result<i64, sqlx::Error> {
+        let query = format!(
+            "SELECT COUNT(*) FROM webhook_deliveries \
+             WHERE webhook_id = $1 {DELIVERY_FILTER}"
+        );
+        sqlx::query_scalar(&query)
+            .bind(webhook_id)
+            .bind(filter.status)
+            .bind(filter.since)
+            .bind(filter.until)
+            .fetch_one(pool)
+            .await
+    }
+
     /// Find a delivery by ID.
     pub async fn find_delivery_by_id(
         pool: &PgPool,
@@ -230,10 +280,15 @@ impl WebhookRepo {
     }
 
     /// Reset a delivery for replay: set status back to 'pending', clear response data.
+    ///
+    /// A dead-lettered delivery is re-queued the same way, and its dead
+    /// letter is stamped as replayed.
     pub async fn replay_delivery(
         pool: &PgPool,
         delivery_id: DbId,
     ) -> Result<Option<WebhookDelivery>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
         let query = format!(
             "UPDATE webhook_deliveries SET \
                  status = 'pending', \
@@ -245,9 +300,93 @@ impl WebhookRepo {
              WHERE id = $1 \
              RETURNING {DELIVERY_COLUMNS}"
         );
-        sqlx::query_as::<_, WebhookDelivery>(&query)
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(&query)
             .bind(delivery_id)
-            .fetch_optional(pool)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        if delivery.is_some() {
+            sqlx::query(
+                "UPDATE webhook_dead_letters SET replayed_at = NOW() \
+                 WHERE delivery_id = $1 AND replayed_at IS NULL",
+            )
+            .bind(delivery_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(delivery)
+    }
+
+    // -----------------------------------------------------------------------
+    // Dead letters
+    // -----------------------------------------------------------------------
+
+    /// Move a delivery whose retries are exhausted to the dead-letter queue.
+    ///
+    /// Marks the delivery `dead_lettered`, counting the final attempt, and
+    /// records the webhook URL, payload, and `last_error`. Returns `None`
+    /// if the delivery does not exist.
+    pub async fn dead_letter_delivery(
+        pool: &PgPool,
+        delivery_id: DbId,
+        response_status_code: Option<i16>,
+        attempt_count: i16,
+        latency_ms: i32,
+        last_error: &str,
+    ) -> Result<Option<WebhookDeadLetter>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let updated = sqlx::query(
+            "UPDATE webhook_deliveries SET \
+                 status = 'dead_lettered', \
+                 attempt_count = $2, \
+                 response_status_code = $3, \
+                 latency_ms = $4, \
+                 next_retry_at = NULL \
+             WHERE id = $1",
+        )
+        .bind(delivery_id)
+        .bind(attempt_count)
+        .bind(response_status_code)
+        .bind(latency_ms)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let query = format!(
+            "INSERT INTO webhook_dead_letters \
+                 (webhook_id, delivery_id, url, payload, last_error, attempt_count) \
+             SELECT d.webhook_id, d.id, w.url, d.payload, $2, d.attempt_count \
+             FROM webhook_deliveries d JOIN webhooks w ON w.id = d.webhook_id \
+             WHERE d.id = $1 \
+             RETURNING {DEAD_LETTER_COLUMNS}"
+        );
+        let dead_letter = sqlx::query_as::<_, WebhookDeadLetter>(&query)
+            .bind(delivery_id)
+            .bind(last_error)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(dead_letter))
+    }
+
+    /// List the dead letters recorded for a delivery, newest first.
+    pub async fn list_dead_letters_for_delivery(
+        pool: &PgPool,
+        delivery_id: DbId,
+    ) -> Result<Vec<WebhookDeadLetter>, sqlx::Error> {
+        let query = format!(
+            "SELECT {DEAD_LETTER_COLUMNS} FROM webhook_dead_letters \
+             WHERE delivery_id = $1 ORDER BY created_at DESC, id DESC"
+        );
+        sqlx::query_as::<_, WebhookDeadLetter>(&query)
+            .bind(delivery_id)
+            .fetch_all(pool)
             .await
     }
 
@@ -271,4 +410,54 @@ impl WebhookRepo {
             .await?;
         Ok(())
     }
+
+    // -----------------------------------------------------------------------
+    // Circuit breaking
+    // -----------------------------------------------------------------------
+
+    /// Persist a webhook's circuit state and failure streak.
+    ///
+    /// `suspended_at` is stamped on the first transition to `suspended` and
+    /// cleared on return to `active`. `next_probe_in_secs` schedules the
+    /// next recovery probe; `None` clears it.
+    pub async fn update_circuit(
+        pool: &PgPool,
+        webhook_id: DbId,
+        circuit_state: &str,
+        consecutive_failures: i32,
+        next_probe_in_secs: Option<i64>,
+    ) -> Result<Option<Webhook>, sqlx::Error> {
+        let query = format!(
+            "UPDATE webhooks SET \
+                 circuit_state = $2, \
+                 consecutive_failures = $3, \
+                 suspended_at = CASE WHEN $2 = 'suspended' \
+                     THEN COALESCE(suspended_at, NOW()) ELSE NULL END, \
+                 next_probe_at = CASE WHEN $4::TEXT IS NULL \
+                     THEN NULL ELSE NOW() + ($4 || ' seconds')::INTERVAL END \
+             WHERE id = $1 \
+             RETURNING {WEBHOOK_COLUMNS}"
+        );
+        sqlx::query_as::<_, Webhook>(&query)
+            .bind(webhook_id)
+            .bind(circuit_state)
+            .bind(consecutive_failures)
+            .bind(next_probe_in_secs.map(|s| s.to_string()))
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// List enabled, suspended webhooks whose next recovery probe is due.
+    pub async fn list_due_probes(pool: &PgPool, limit: i64) -> Result<Vec<Webhook>, sqlx::Error> {
+        let query = format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks \
+             WHERE is_enabled = true AND circuit_state = 'suspended' \
+               AND next_probe_at <= NOW() \
+             ORDER BY next_probe_at ASC LIMIT $1"
+        );
+        sqlx::query_as::<_, Webhook>(&query)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
 }
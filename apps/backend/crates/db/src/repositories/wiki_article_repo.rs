@@ -5,14 +5,20 @@
 use sqlx::PgPool;
 use x121_core::types::DbId;
 
-use crate::models::wiki_article::{CreateWikiArticle, UpdateWikiArticle, WikiArticle};
-use crate::models::wiki_version::WikiVersion;
+use crate::models::wiki_article::{
+    CreateWikiArticle, UpdateWikiArticle, WikiArticle, WikiRevertResult,
+};
 use crate::repositories::wiki_version_repo::WikiVersionRepo;
 
 /// Column list for wiki_articles queries.
 const COLUMNS: &str = "id, title, slug, content_md, category, tags, \
     is_builtin, is_pinned, pin_location, created_by, created_at, updated_at";
 
+/// [`COLUMNS`] qualified with the `a` alias, for queries joining the search
+/// index.
+const PREFIXED_COLUMNS: &str = "a.id, a.title, a.slug, a.content_md, a.category, a.tags, \
+    a.is_builtin, a.is_pinned, a.pin_location, a.created_by, a.created_at, a.updated_at";
+
 /// Provides CRUD operations for wiki articles.
 pub struct WikiArticleRepo;
 
@@ -48,6 +54,7 @@ impl WikiArticleRepo {
             pool,
             article.id,
             1,
+            &input.title,
             &input.content_md,
             user_id,
             Some("Initial version"),
@@ -102,7 +109,8 @@ impl WikiArticleRepo {
             .await
     }
 
-    /// Update a wiki article and create a new version if content changed.
+    /// Update a wiki article and create a new version if its title or content
+    /// changed.
     pub async fn update(
         pool: &PgPool,
         slug: &str,
@@ -131,14 +139,15 @@ impl WikiArticleRepo {
             .fetch_one(pool)
             .await?;
 
-        // Create a new version if content was changed.
-        if input.content_md.is_some() {
+        // Create a new version if title or content was changed.
+        if input.title.is_some() || input.content_md.is_some() {
             let next_version =
                 WikiVersionRepo::get_latest_version_number(pool, article.id).await? + 1;
             WikiVersionRepo::create(
                 pool,
                 article.id,
                 next_version,
+                &article.title,
                 &article.content_md,
                 user_id,
                 input.edit_summary.as_deref(),
@@ -158,7 +167,11 @@ impl WikiArticleRepo {
         Ok(())
     }
 
-    /// Full-text search across title and content_md using ILIKE.
+    /// Search articles by title, content, and tags.
+    ///
+    /// Indexed articles are matched against their search vector with
+    /// `websearch_to_tsquery` and ranked by relevance. Articles not yet in
+    /// the index fall back to an ILIKE match on title and content.
     pub async fn search(
         pool: &PgPool,
         query_str: &str,
@@ -166,18 +179,63 @@ impl WikiArticleRepo {
     ) -> Result<Vec<WikiArticle>, sqlx::Error> {
         let pattern = format!("%{query_str}%");
         let query = format!(
-            "SELECT {COLUMNS} FROM wiki_articles
-             WHERE title ILIKE $1 OR content_md ILIKE $1
-             ORDER BY updated_at DESC
-             LIMIT $2"
+            "SELECT {PREFIXED_COLUMNS} FROM wiki_articles a
+             LEFT JOIN wiki_article_search_index si ON si.article_id = a.id
+             WHERE (si.article_id IS NOT NULL
+                    AND si.search_vector @@ websearch_to_tsquery('english', $1))
+                OR (si.article_id IS NULL
+                    AND (a.title ILIKE $2 OR a.content_md ILIKE $2))
+             ORDER BY ts_rank(si.search_vector, websearch_to_tsquery('english', $1))
+                          DESC NULLS LAST,
+                      a.updated_at DESC
+             LIMIT $3"
         );
         sqlx::query_as::<_, WikiArticle>(&query)
+            .bind(query_str)
             .bind(&pattern)
             .bind(limit)
             .fetch_all(pool)
             .await
     }
 
+    /// Write or refresh the search index entry for one article from its
+    /// current title (weight A), content (B), and tags (C).
+    ///
+    /// Returns `false` if the article no longer exists.
+    pub async fn index_article(pool: &PgPool, id: DbId) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO wiki_article_search_index (article_id, search_vector)
+             SELECT id,
+                    setweight(to_tsvector('english', title), 'A')
+                    || setweight(to_tsvector('english', content_md), 'B')
+                    || setweight(to_tsvector('english',
+                           array_to_string(COALESCE(tags, '{}'), ' ')), 'C')
+             FROM wiki_articles
+             WHERE id = $1
+             ON CONFLICT (article_id) DO UPDATE
+                SET search_vector = EXCLUDED.search_vector,
+                    indexed_at = NOW()",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List article IDs greater than `cursor` in ascending order, for
+    /// keyset-paginated reindexing.
+    pub async fn list_ids_after(
+        pool: &PgPool,
+        cursor: DbId,
+        limit: i64,
+    ) -> Result<Vec<DbId>, sqlx::Error> {
+        sqlx::query_scalar("SELECT id FROM wiki_articles WHERE id > $1 ORDER BY id ASC LIMIT $2")
+            .bind(cursor)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
+
     /// List all pinned wiki articles.
     pub async fn list_pinned(pool: &PgPool) -> Result<Vec<WikiArticle>, sqlx::Error> {
         let query = format!(
@@ -190,35 +248,65 @@ impl WikiArticleRepo {
             .await
     }
 
-    /// Revert an article to a previous version's content, creating a new version.
+    /// Revert an article to a previous version by appending a new version
+    /// with that version's content.
+    ///
+    /// Runs in one transaction with the article row locked, so concurrent
+    /// edits cannot interleave version numbers. The target's title is
+    /// restored too when it was recorded. Existing versions are never
+    /// modified. Returns `None` if the article or `target_version` does not
+    /// exist.
     pub async fn revert_to_version(
         pool: &PgPool,
         article_id: DbId,
-        old_version: &WikiVersion,
+        target_version: i32,
         user_id: Option<DbId>,
-    ) -> Result<WikiArticle, sqlx::Error> {
-        // Update article content.
-        let query =
-            format!("UPDATE wiki_articles SET content_md = $1 WHERE id = $2 RETURNING {COLUMNS}");
+    ) -> Result<Option<WikiRevertResult>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let locked: Option<(DbId,)> =
+            sqlx::query_as("SELECT id FROM wiki_articles WHERE id = $1 FOR UPDATE")
+                .bind(article_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        if locked.is_none() {
+            return Ok(None);
+        }
+
+        let Some(target) =
+            WikiVersionRepo::find_by_article_and_version_in_tx(&mut tx, article_id, target_version)
+                .await?
+        else {
+            return Ok(None);
+        };
+        let current_version =
+            WikiVersionRepo::get_latest_version_number_in_tx(&mut tx, article_id).await?;
+
+        let query = format!(
+            "UPDATE wiki_articles SET
+                title = COALESCE($1, title),
+                content_md = $2
+             WHERE id = $3
+             RETURNING {COLUMNS}"
+        );
         let article = sqlx::query_as::<_, WikiArticle>(&query)
-            .bind(&old_version.content_md)
+            .bind(&target.title)
+            .bind(&target.content_md)
             .bind(article_id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
 
-        // Create a new version for the revert.
-        let next_version = WikiVersionRepo::get_latest_version_number(pool, article_id).await? + 1;
-        let summary = format!("Reverted to version {}", old_version.version);
-        WikiVersionRepo::create(
-            pool,
-            article_id,
-            next_version,
-            &old_version.content_md,
+        let version = WikiVersionRepo::create_revert(
+            &mut tx,
+            current_version + 1,
+            current_version,
+            &target,
+            &article.title,
             user_id,
-            Some(&summary),
         )
         .await?;
 
-        Ok(article)
+        tx.commit().await?;
+        Ok(Some(WikiRevertResult { article, version }))
     }
 }
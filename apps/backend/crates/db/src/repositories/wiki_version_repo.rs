@@ -8,7 +8,8 @@ use x121_core::types::DbId;
 use crate::models::wiki_version::WikiVersion;
 
 /// Column list for wiki_versions queries.
-const COLUMNS: &str = "id, article_id, version, content_md, edited_by, edit_summary, created_at";
+const COLUMNS: &str = "id, article_id, version, title, content_md, edited_by, edit_summary, \
+    reverted_from_version, reverted_to_version, created_at";
 
 /// Provides read and create operations for wiki article versions.
 pub struct WikiVersionRepo;
@@ -19,18 +20,21 @@ impl WikiVersionRepo {
         pool: &PgPool,
         article_id: DbId,
         version: i32,
+        title: &str,
         content_md: &str,
         edited_by: Option<DbId>,
         edit_summary: Option<&str>,
     ) -> Result<WikiVersion, sqlx::Error> {
         let query = format!(
-            "INSERT INTO wiki_versions (article_id, version, content_md, edited_by, edit_summary)
-             VALUES ($1, $2, $3, $4, $5)
+            "INSERT INTO wiki_versions
+                (article_id, version, title, content_md, edited_by, edit_summary)
+             VALUES ($1, $2, $3, $4, $5, $6)
              RETURNING {COLUMNS}"
         );
         sqlx::query_as::<_, WikiVersion>(&query)
             .bind(article_id)
             .bind(version)
+            .bind(title)
             .bind(content_md)
             .bind(edited_by)
             .bind(edit_summary)
@@ -38,6 +42,39 @@ impl WikiVersionRepo {
             .await
     }
 
+    /// Append a version recording a revert to `target` inside `tx`.
+    ///
+    /// The new version copies `target`'s content, takes `title` as the
+    /// article's title after the revert, and records `reverted_from` (the
+    /// version current before the revert) and `target.version`.
+    pub async fn create_revert(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        next_version: i32,
+        reverted_from: i32,
+        target: &WikiVersion,
+        title: &str,
+        edited_by: Option<DbId>,
+    ) -> Result<WikiVersion, sqlx::Error> {
+        let query = format!(
+            "INSERT INTO wiki_versions
+                (article_id, version, title, content_md, edited_by, edit_summary,
+                 reverted_from_version, reverted_to_version)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+             RETURNING {COLUMNS}"
+        );
+        sqlx::query_as::<_, WikiVersion>(&query)
+            .bind(target.article_id)
+            .bind(next_version)
+            .bind(title)
+            .bind(&target.content_md)
+            .bind(edited_by)
+            .bind(format!("Reverted to version {}", target.version))
+            .bind(reverted_from)
+            .bind(target.version)
+            .fetch_one(&mut **tx)
+            .await
+    }
+
     /// List all versions for an article, ordered newest first.
     pub async fn list_by_article(
         pool: &PgPool,
@@ -71,6 +108,23 @@ impl WikiVersionRepo {
             .await
     }
 
+    /// Find a specific version of an article inside `tx`.
+    pub async fn find_by_article_and_version_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        article_id: DbId,
+        version: i32,
+    ) -> Result<Option<WikiVersion>, sqlx::Error> {
+        let query = format!(
+            "SELECT {COLUMNS} FROM wiki_versions
+             WHERE article_id = $1 AND version = $2"
+        );
+        sqlx::query_as::<_, WikiVersion>(&query)
+            .bind(article_id)
+            .bind(version)
+            .fetch_optional(&mut **tx)
+            .await
+    }
+
     /// Get the latest version number for an article (0 if none exist).
     pub async fn get_latest_version_number(
         pool: &PgPool,
@@ -85,4 +139,19 @@ impl WikiVersionRepo {
 
         Ok(result.map(|(v,)| v).unwrap_or(0))
     }
+
+    /// Get the latest version number for an article inside `tx` (0 if none
+    /// exist).
+    pub async fn get_latest_version_number_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        article_id: DbId,
+    ) -> Result<i32, sqlx::Error> {
+        let (latest,): (i32,) = sqlx::query_as(
+            "SELECT COALESCE(MAX(version), 0) FROM wiki_versions WHERE article_id = $1",
+        )
+        .bind(article_id)
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(latest)
+    }
 }
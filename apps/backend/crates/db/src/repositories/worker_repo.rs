@@ -2,10 +2,13 @@
 
 use sqlx::PgPool;
 use x121_core::types::DbId;
+use x121_core::worker_pool::{
+    compute_fleet_stats, FleetStats, FleetWorker, GpuUtilizationSample, HEARTBEAT_TIMEOUT_SECS,
+};
 
 use crate::models::status::{StatusId, WorkerStatus};
 use crate::models::worker::{
-    CreateHealthLogEntry, CreateWorker, FleetStats, UpdateWorker, Worker, WorkerHealthLogEntry,
+    CreateHealthLogEntry, CreateWorker, UpdateWorker, Worker, WorkerHealthLogEntry,
 };
 
 /// Column list for `workers` queries.
@@ -207,18 +210,53 @@ impl WorkerRepo {
     // ── Fleet stats ──────────────────────────────────────────────────────
 
     /// Aggregate fleet-level statistics.
+    ///
+    /// Loads each worker and the latest utilization of every GPU that has
+    /// reported within the heartbeat timeout, then rolls them up with
+    /// [`compute_fleet_stats`].
     pub async fn fleet_stats(pool: &PgPool) -> Result<FleetStats, sqlx::Error> {
-        let query = "\
-            SELECT \
-                COUNT(*) AS total_workers, \
-                COUNT(*) FILTER (WHERE status_id = 1) AS idle_workers, \
-                COUNT(*) FILTER (WHERE status_id = 2) AS busy_workers, \
-                COUNT(*) FILTER (WHERE status_id = 3) AS offline_workers, \
-                COUNT(*) FILTER (WHERE status_id = 4) AS draining_workers, \
-                COUNT(*) FILTER (WHERE is_approved = true) AS approved_workers, \
-                COUNT(*) FILTER (WHERE is_enabled = true) AS enabled_workers \
-            FROM workers";
-        sqlx::query_as::<_, FleetStats>(query).fetch_one(pool).await
+        let workers: Vec<(DbId, StatusId, i16, bool, bool, bool)> = sqlx::query_as(
+            "SELECT id, status_id, gpu_count, is_approved, is_enabled, \
+                    decommissioned_at IS NOT NULL \
+             FROM workers",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let metrics: Vec<(DbId, Option<i16>)> = sqlx::query_as(
+            "SELECT DISTINCT ON (worker_id, gpu_index) worker_id, utilization_percent \
+             FROM gpu_metrics \
+             WHERE recorded_at > NOW() - make_interval(secs => $1) \
+             ORDER BY worker_id, gpu_index, recorded_at DESC",
+        )
+        .bind(HEARTBEAT_TIMEOUT_SECS as f64)
+        .fetch_all(pool)
+        .await?;
+
+        let workers: Vec<FleetWorker> = workers
+            .into_iter()
+            .map(
+                |(worker_id, status_id, gpu_count, is_approved, is_enabled, is_decommissioned)| {
+                    FleetWorker {
+                        worker_id,
+                        status_id,
+                        gpu_count,
+                        is_approved,
+                        is_enabled,
+                        is_decommissioned,
+                    }
+                },
+            )
+            .collect();
+        let metrics: Vec<GpuUtilizationSample> = metrics
+            .into_iter()
+            .map(|(worker_id, utilization_percent)| GpuUtilizationSample {
+                worker_id,
+                utilization_percent,
+            })
+            .collect();
+
+        Ok(compute_fleet_stats(&workers, &metrics))
     }
 
     // ── Health log ───────────────────────────────────────────────────────
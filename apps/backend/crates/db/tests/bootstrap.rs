@@ -37,3 +37,19 @@ async fn test_pgvector_available(pool: PgPool) {
         .unwrap();
     assert_eq!(result.0, "[1,2,3]");
 }
+
+/// Without a replica URL the reader is a handle to the writer pool.
+#[sqlx::test]
+async fn test_missing_replica_reader_aliases_writer() {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pools = x121_db::create_read_write_pools(&url, "", x121_db::PoolConfig::default())
+        .await
+        .unwrap();
+
+    assert!(!pools.has_replica());
+    x121_db::health_check_pools(&pools).await.unwrap();
+
+    // Closing the writer closes the reader too: both share one pool.
+    pools.writer.close().await;
+    assert!(pools.reader.is_closed());
+}
@@ -9,7 +9,7 @@
 
 use sqlx::PgPool;
 use x121_db::models::avatar::CreateAvatar;
-use x121_db::models::image::{CreateMediaVariant, CreateSourceMedia};
+use x121_db::models::media::{CreateMediaVariant, CreateSourceMedia};
 use x121_db::models::project::{CreateProject, UpdateProject};
 use x121_db::models::scene::CreateScene;
 use x121_db::models::scene_type::CreateSceneType;
@@ -29,6 +29,7 @@ fn new_project(name: &str) -> CreateProject {
         description: None,
         status_id: None,
         retention_days: None,
+        pipeline_id: 1,
     }
 }
 
@@ -39,6 +40,7 @@ fn new_avatar(project_id: i64, name: &str) -> CreateAvatar {
         status_id: None,
         metadata: None,
         settings: None,
+        group_id: None,
     }
 }
 
@@ -46,6 +48,7 @@ fn new_scene_type(project_id: Option<i64>, name: &str) -> CreateSceneType {
     CreateSceneType {
         project_id,
         name: name.to_string(),
+        slug: name.to_lowercase(),
         status_id: None,
         workflow_json: None,
         lora_config: None,
@@ -65,6 +68,19 @@ fn new_scene_type(project_id: Option<i64>, name: &str) -> CreateSceneType {
         sort_order: None,
         is_active: None,
         is_studio_level: None,
+        workflow_id: None,
+        has_clothes_off_transition: None,
+        parent_scene_type_id: None,
+        generation_strategy: None,
+        expected_chunks: None,
+        chunk_output_pattern: None,
+        auto_retry_enabled: None,
+        auto_retry_max_attempts: None,
+        auto_retry_trigger_checks: None,
+        auto_retry_seed_variation: None,
+        auto_retry_cfg_jitter: None,
+        target_fps: None,
+        target_resolution: None,
     }
 }
 
@@ -95,6 +111,7 @@ fn new_image_variant(avatar_id: i64, label: &str, path: &str) -> CreateMediaVari
         version: None,
         parent_variant_id: None,
         generation_params: None,
+        content_hash: None,
     }
 }
 
@@ -102,9 +119,16 @@ fn new_scene(avatar_id: i64, scene_type_id: i64, media_variant_id: i64) -> Creat
     CreateScene {
         avatar_id,
         scene_type_id,
-        media_variant_id,
+        media_variant_id: Some(media_variant_id),
         status_id: None,
         transition_mode: None,
+        track_id: None,
+        total_segments_estimated: None,
+        total_segments_completed: None,
+        actual_duration_secs: None,
+        transition_segment_index: None,
+        generation_started_at: None,
+        generation_completed_at: None,
     }
 }
 
@@ -117,6 +141,15 @@ fn new_segment(scene_id: i64, index: i32) -> CreateSegment {
         output_video_path: None,
         last_frame_path: None,
         quality_scores: None,
+        duration_secs: None,
+        cumulative_duration_secs: None,
+        boundary_frame_index: None,
+        boundary_selection_mode: None,
+        generation_started_at: None,
+        generation_completed_at: None,
+        worker_id: None,
+        prompt_type: None,
+        prompt_text: None,
     }
 }
 
@@ -310,6 +343,9 @@ async fn test_update_project(pool: PgPool) {
             description: Some("A description".to_string()),
             status_id: None,
             retention_days: Some(30),
+            auto_deliver_on_final: None,
+            blocking_deliverables: None,
+            default_format_profile_id: None,
         },
     )
     .await
@@ -335,6 +371,9 @@ async fn test_update_nonexistent_returns_none(pool: PgPool) {
             description: None,
             status_id: None,
             retention_days: None,
+            auto_deliver_on_final: None,
+            blocking_deliverables: None,
+            default_format_profile_id: None,
         },
     )
     .await
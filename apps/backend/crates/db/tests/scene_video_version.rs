@@ -12,7 +12,7 @@
 
 use sqlx::PgPool;
 use x121_db::models::avatar::CreateAvatar;
-use x121_db::models::image::CreateMediaVariant;
+use x121_db::models::media::CreateMediaVariant;
 use x121_db::models::project::CreateProject;
 use x121_db::models::scene::CreateScene;
 use x121_db::models::scene_type::CreateSceneType;
@@ -31,6 +31,7 @@ fn new_project(name: &str) -> CreateProject {
         description: None,
         status_id: None,
         retention_days: None,
+        pipeline_id: 1,
     }
 }
 
@@ -41,6 +42,7 @@ fn new_avatar(project_id: i64, name: &str) -> CreateAvatar {
         status_id: None,
         metadata: None,
         settings: None,
+        group_id: None,
     }
 }
 
@@ -48,6 +50,7 @@ fn new_scene_type(project_id: Option<i64>, name: &str) -> CreateSceneType {
     CreateSceneType {
         project_id,
         name: name.to_string(),
+        slug: name.to_lowercase(),
         status_id: None,
         workflow_json: None,
         lora_config: None,
@@ -67,6 +70,19 @@ fn new_scene_type(project_id: Option<i64>, name: &str) -> CreateSceneType {
         sort_order: None,
         is_active: None,
         is_studio_level: None,
+        workflow_id: None,
+        has_clothes_off_transition: None,
+        parent_scene_type_id: None,
+        generation_strategy: None,
+        expected_chunks: None,
+        chunk_output_pattern: None,
+        auto_retry_enabled: None,
+        auto_retry_max_attempts: None,
+        auto_retry_trigger_checks: None,
+        auto_retry_seed_variation: None,
+        auto_retry_cfg_jitter: None,
+        target_fps: None,
+        target_resolution: None,
     }
 }
 
@@ -88,6 +104,7 @@ fn new_image_variant(avatar_id: i64, label: &str, path: &str) -> CreateMediaVari
         version: None,
         parent_variant_id: None,
         generation_params: None,
+        content_hash: None,
     }
 }
 
@@ -95,9 +112,16 @@ fn new_scene(avatar_id: i64, scene_type_id: i64, media_variant_id: i64) -> Creat
     CreateScene {
         avatar_id,
         scene_type_id,
-        media_variant_id,
+        media_variant_id: Some(media_variant_id),
         status_id: None,
         transition_mode: None,
+        track_id: None,
+        total_segments_estimated: None,
+        total_segments_completed: None,
+        actual_duration_secs: None,
+        transition_segment_index: None,
+        generation_started_at: None,
+        generation_completed_at: None,
     }
 }
 
@@ -112,6 +136,8 @@ fn new_version(scene_id: i64) -> CreateSceneVideoVersion {
         notes: None,
         generation_snapshot: None,
         content_hash: None,
+        parent_version_id: None,
+        clip_index: None,
     }
 }
 
@@ -4,6 +4,22 @@
 //! notification emails for platform events. Configuration is loaded from
 //! environment variables; if `SMTP_HOST` is not set, [`EmailConfig::from_env`]
 //! returns `None` and no mailer should be constructed.
+//!
+//! Failed sends are classified as transient (timeouts, connection drops, 4xx
+//! replies such as greylisting) or permanent (bad addresses, 5xx replies).
+//! Transient failures are retried with exponential backoff within an
+//! [`EmailRetryPolicy`]; permanent failures are returned immediately.
+//! A delivery whose retries run out is recorded in `email_dead_letters`
+//! when a pool is attached with [`EmailDelivery::with_dead_letters`].
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use lettre::Message;
+use serde::Serialize;
+use x121_db::models::notification::CreateEmailDeadLetter;
+use x121_db::repositories::NotificationRepo;
+use x121_db::DbPool;
 
 use crate::bus::PlatformEvent;
 
@@ -18,6 +34,10 @@ pub enum EmailError {
     #[error("SMTP transport error: {0}")]
     Transport(#[from] lettre::transport::smtp::Error),
 
+    /// The SMTP server answered with a negative reply code.
+    #[error("SMTP server replied {code}: {message}")]
+    Rejected { code: u16, message: String },
+
     /// The recipient or sender address could not be parsed.
     #[error("Email address parse error: {0}")]
     Address(#[from] lettre::address::AddressError),
@@ -27,6 +47,172 @@ pub enum EmailError {
     Build(String),
 }
 
+/// Whether a failed send may succeed if retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureClass {
+    Transient,
+    Permanent,
+}
+
+impl EmailError {
+    /// Classify this error for retry purposes.
+    ///
+    /// 4xx replies and network-level failures are transient; 5xx replies,
+    /// TLS and client errors, and malformed messages are permanent.
+    pub fn class(&self) -> FailureClass {
+        match self {
+            Self::Rejected { code, .. } if (400..500).contains(code) => FailureClass::Transient,
+            Self::Rejected { .. } => FailureClass::Permanent,
+            Self::Transport(e) if e.is_permanent() || e.is_client() || e.is_tls() => {
+                FailureClass::Permanent
+            }
+            Self::Transport(_) => FailureClass::Transient,
+            Self::Address(_) | Self::Build(_) => FailureClass::Permanent,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Transport
+// ---------------------------------------------------------------------------
+
+/// Sends a fully built message. Abstracted so retry behaviour can be
+/// exercised without an SMTP server.
+#[async_trait::async_trait]
+pub trait EmailTransport: Send + Sync {
+    async fn send(&self, message: Message) -> Result<(), EmailError>;
+}
+
+/// [`EmailTransport`] backed by a STARTTLS SMTP relay.
+pub struct SmtpTransport {
+    config: EmailConfig,
+}
+
+#[async_trait::async_trait]
+impl EmailTransport for SmtpTransport {
+    async fn send(&self, message: Message) -> Result<(), EmailError> {
+        use lettre::{
+            transport::smtp::authentication::Credentials, AsyncSmtpTransport, AsyncTransport,
+            Tokio1Executor,
+        };
+
+        let mut transport_builder =
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.config.smtp_host)?
+                .port(self.config.smtp_port);
+
+        if let (Some(user), Some(pass)) = (&self.config.smtp_user, &self.config.smtp_password) {
+            transport_builder =
+                transport_builder.credentials(Credentials::new(user.clone(), pass.clone()));
+        }
+
+        let mailer = transport_builder.build();
+        match mailer.send(message).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e.status() {
+                Some(code) => Err(EmailError::Rejected {
+                    code: code.into(),
+                    message: e.to_string(),
+                }),
+                None => Err(EmailError::Transport(e)),
+            },
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Retry policy
+// ---------------------------------------------------------------------------
+
+/// Default maximum number of send attempts, including the first.
+const DEFAULT_MAX_ATTEMPTS: u32 = 4;
+
+/// Default delay before the first retry; doubles after each attempt.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Default upper bound on a single backoff delay.
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Default wall-clock budget for one delivery, across all attempts.
+const DEFAULT_RETRY_BUDGET: Duration = Duration::from_secs(120);
+
+/// Bounds on how hard [`EmailDelivery`] retries a transient failure.
+#[derive(Debug, Clone)]
+pub struct EmailRetryPolicy {
+    /// Maximum number of send attempts, including the first (at least 1).
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound on a single backoff delay.
+    pub max_backoff: Duration,
+    /// No retry is started if its backoff would end past this budget,
+    /// measured from the first attempt.
+    pub budget: Duration,
+}
+
+impl Default for EmailRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            budget: DEFAULT_RETRY_BUDGET,
+        }
+    }
+}
+
+impl EmailRetryPolicy {
+    /// Backoff before attempt `attempt + 1`, where `attempt` is 1-based.
+    fn backoff_after(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Attempt records
+// ---------------------------------------------------------------------------
+
+/// One failed send attempt.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailAttempt {
+    /// 1-based attempt number.
+    pub attempt: u32,
+    pub error: String,
+    pub class: FailureClass,
+}
+
+/// Outcome of a successful delivery.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailReceipt {
+    /// Total attempts made, including the successful one.
+    pub attempts: u32,
+    /// Failed attempts that preceded the success.
+    pub failures: Vec<EmailAttempt>,
+}
+
+/// A delivery that did not succeed.
+#[derive(Debug, thiserror::Error)]
+#[error("{error} (after {} attempt(s))", .attempts.len())]
+pub struct EmailDeliveryFailure {
+    /// The error from the last attempt.
+    pub error: EmailError,
+    /// Every failed attempt, oldest first. Empty if the message could not
+    /// be built.
+    pub attempts: Vec<EmailAttempt>,
+}
+
+impl EmailDeliveryFailure {
+    /// Whether delivery stopped because the retry policy ran out rather than
+    /// because of a permanent error. Exhausted deliveries are the ones worth
+    /// dead-lettering for a later replay.
+    pub fn retries_exhausted(&self) -> bool {
+        self.error.class() == FailureClass::Transient
+    }
+}
+
 // ---------------------------------------------------------------------------
 // EmailConfig
 // ---------------------------------------------------------------------------
@@ -88,20 +274,156 @@ impl EmailConfig {
 /// Sends notification emails for platform events via SMTP.
 pub struct EmailDelivery {
     config: EmailConfig,
+    transport: Arc<dyn EmailTransport>,
+    retry_policy: EmailRetryPolicy,
+    dead_letters: Option<DbPool>,
 }
 
 impl EmailDelivery {
     /// Create a new email delivery service with the given configuration.
     pub fn new(config: EmailConfig) -> Self {
-        Self { config }
+        let transport = Arc::new(SmtpTransport {
+            config: config.clone(),
+        });
+        Self::with_transport(config, transport)
+    }
+
+    /// Create a delivery service that sends through a custom transport.
+    pub fn with_transport(config: EmailConfig, transport: Arc<dyn EmailTransport>) -> Self {
+        Self {
+            config,
+            transport,
+            retry_policy: EmailRetryPolicy::default(),
+            dead_letters: None,
+        }
+    }
+
+    /// Replace the default retry policy.
+    pub fn with_retry_policy(mut self, retry_policy: EmailRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Record deliveries whose retries are exhausted in `email_dead_letters`.
+    pub fn with_dead_letters(mut self, pool: DbPool) -> Self {
+        self.dead_letters = Some(pool);
+        self
     }
 
     /// Send a notification email for the given event to the specified address.
-    pub async fn deliver(&self, to_email: &str, event: &PlatformEvent) -> Result<(), EmailError> {
-        use lettre::{
-            message::header::ContentType, transport::smtp::authentication::Credentials,
-            AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    ///
+    /// Transient failures are retried according to the retry policy; a
+    /// permanent failure stops immediately. Every failed attempt is recorded
+    /// in the returned receipt or failure.
+    pub async fn deliver(
+        &self,
+        to_email: &str,
+        event: &PlatformEvent,
+    ) -> Result<EmailReceipt, EmailDeliveryFailure> {
+        let email = self
+            .build_message(to_email, event)
+            .map_err(|error| EmailDeliveryFailure {
+                error,
+                attempts: Vec::new(),
+            })?;
+
+        let policy = &self.retry_policy;
+        let started = Instant::now();
+        let mut failures: Vec<EmailAttempt> = Vec::new();
+        let mut attempt = 1;
+
+        loop {
+            let error = match self.transport.send(email.clone()).await {
+                Ok(()) => {
+                    tracing::info!(
+                        to = to_email,
+                        event_type = %event.event_type,
+                        attempts = attempt,
+                        "Notification email sent"
+                    );
+                    return Ok(EmailReceipt {
+                        attempts: attempt,
+                        failures,
+                    });
+                }
+                Err(e) => e,
+            };
+
+            let class = error.class();
+            failures.push(EmailAttempt {
+                attempt,
+                error: error.to_string(),
+                class,
+            });
+
+            let backoff = policy.backoff_after(attempt);
+            let can_retry = class == FailureClass::Transient
+                && attempt < policy.max_attempts
+                && started.elapsed() + backoff <= policy.budget;
+            if !can_retry {
+                tracing::error!(
+                    to = to_email,
+                    event_type = %event.event_type,
+                    attempts = attempt,
+                    class = ?class,
+                    error = %error,
+                    "Notification email delivery failed"
+                );
+                let failure = EmailDeliveryFailure {
+                    error,
+                    attempts: failures,
+                };
+                if failure.retries_exhausted() {
+                    self.dead_letter(to_email, event, &failure).await;
+                }
+                return Err(failure);
+            }
+
+            tracing::warn!(
+                to = to_email,
+                attempt,
+                error = %error,
+                "Transient email delivery failure, retrying"
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Persist an exhausted delivery, if a dead-letter pool is attached.
+    ///
+    /// A failure to record is logged rather than returned, so it does not
+    /// mask the delivery error.
+    async fn dead_letter(
+        &self,
+        to_email: &str,
+        event: &PlatformEvent,
+        failure: &EmailDeliveryFailure,
+    ) {
+        let Some(pool) = &self.dead_letters else {
+            return;
         };
+        let input = CreateEmailDeadLetter {
+            to_address: to_email.to_string(),
+            event_type: event.event_type.clone(),
+            payload: event.payload.clone(),
+            last_error: failure.error.to_string(),
+            attempt_count: i16::try_from(failure.attempts.len()).unwrap_or(i16::MAX),
+            attempts: serde_json::to_value(&failure.attempts).unwrap_or_default(),
+        };
+        if let Err(e) = NotificationRepo::create_email_dead_letter(pool, &input).await {
+            tracing::error!(
+                to = to_email,
+                event_type = %event.event_type,
+                error = %e,
+                "Failed to dead-letter notification email"
+            );
+        }
+    }
+
+    /// Assemble the plain-text notification message.
+    fn build_message(&self, to_email: &str, event: &PlatformEvent) -> Result<Message, EmailError> {
+        use lettre::message::header::ContentType;
 
         let subject = format!("[X121] {}", event.event_type);
         let body = format!(
@@ -111,28 +433,13 @@ impl EmailDelivery {
             serde_json::to_string_pretty(&event.payload).unwrap_or_default()
         );
 
-        let email = Message::builder()
+        Message::builder()
             .from(self.config.from_address.parse()?)
             .to(to_email.parse()?)
             .subject(subject)
             .header(ContentType::TEXT_PLAIN)
             .body(body)
-            .map_err(|e| EmailError::Build(e.to_string()))?;
-
-        let mut transport_builder =
-            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.config.smtp_host)?
-                .port(self.config.smtp_port);
-
-        if let (Some(user), Some(pass)) = (&self.config.smtp_user, &self.config.smtp_password) {
-            transport_builder =
-                transport_builder.credentials(Credentials::new(user.clone(), pass.clone()));
-        }
-
-        let mailer = transport_builder.build();
-        mailer.send(email).await?;
-
-        tracing::info!(to = to_email, event_type = %event.event_type, "Notification email sent");
-        Ok(())
+            .map_err(|e| EmailError::Build(e.to_string()))
     }
 }
 
@@ -142,8 +449,179 @@ impl EmailDelivery {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
     use super::*;
 
+    /// Transport that replays scripted results and counts calls.
+    struct MockTransport {
+        results: Mutex<VecDeque<Result<(), EmailError>>>,
+        calls: Mutex<u32>,
+    }
+
+    impl MockTransport {
+        fn new(results: Vec<Result<(), EmailError>>) -> Arc<Self> {
+            Arc::new(Self {
+                results: Mutex::new(results.into()),
+                calls: Mutex::new(0),
+            })
+        }
+
+        fn calls(&self) -> u32 {
+            *self.calls.lock().unwrap()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl EmailTransport for MockTransport {
+        async fn send(&self, _message: Message) -> Result<(), EmailError> {
+            *self.calls.lock().unwrap() += 1;
+            self.results.lock().unwrap().pop_front().unwrap_or(Ok(()))
+        }
+    }
+
+    fn rejected(code: u16) -> EmailError {
+        EmailError::Rejected {
+            code,
+            message: format!("{code} mock reply"),
+        }
+    }
+
+    fn test_config() -> EmailConfig {
+        EmailConfig {
+            smtp_host: "smtp.test".to_string(),
+            smtp_port: DEFAULT_SMTP_PORT,
+            from_address: DEFAULT_FROM_ADDRESS.to_string(),
+            smtp_user: None,
+            smtp_password: None,
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> EmailRetryPolicy {
+        EmailRetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            budget: Duration::from_secs(5),
+        }
+    }
+
+    fn delivery(transport: Arc<MockTransport>, max_attempts: u32) -> EmailDelivery {
+        EmailDelivery::with_transport(test_config(), transport)
+            .with_retry_policy(fast_policy(max_attempts))
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried_until_success() {
+        let transport = MockTransport::new(vec![Err(rejected(421)), Err(rejected(451)), Ok(())]);
+        let event = PlatformEvent::new("job.completed");
+
+        let receipt = delivery(transport.clone(), 4)
+            .deliver("user@example.com", &event)
+            .await
+            .unwrap();
+
+        assert_eq!(transport.calls(), 3);
+        assert_eq!(receipt.attempts, 3);
+        assert_eq!(receipt.failures.len(), 2);
+        assert!(receipt
+            .failures
+            .iter()
+            .all(|a| a.class == FailureClass::Transient));
+        assert_eq!(receipt.failures[1].attempt, 2);
+    }
+
+    #[tokio::test]
+    async fn permanent_failure_is_not_retried() {
+        let transport = MockTransport::new(vec![Err(rejected(550)), Ok(())]);
+        let event = PlatformEvent::new("job.completed");
+
+        let failure = delivery(transport.clone(), 4)
+            .deliver("user@example.com", &event)
+            .await
+            .unwrap_err();
+
+        assert_eq!(transport.calls(), 1);
+        assert_eq!(failure.attempts.len(), 1);
+        assert_eq!(failure.attempts[0].class, FailureClass::Permanent);
+        assert!(!failure.retries_exhausted());
+    }
+
+    #[tokio::test]
+    async fn retries_stop_at_max_attempts() {
+        let transport = MockTransport::new((0..10).map(|_| Err(rejected(421))).collect());
+        let event = PlatformEvent::new("job.completed");
+
+        let failure = delivery(transport.clone(), 3)
+            .deliver("user@example.com", &event)
+            .await
+            .unwrap_err();
+
+        assert_eq!(transport.calls(), 3);
+        assert_eq!(failure.attempts.len(), 3);
+        assert!(failure.retries_exhausted());
+    }
+
+    #[tokio::test]
+    async fn retries_stop_when_budget_is_spent() {
+        let transport = MockTransport::new((0..10).map(|_| Err(rejected(421))).collect());
+        let event = PlatformEvent::new("job.completed");
+        let policy = EmailRetryPolicy {
+            budget: Duration::ZERO,
+            ..fast_policy(10)
+        };
+
+        let failure = EmailDelivery::with_transport(test_config(), transport.clone())
+            .with_retry_policy(policy)
+            .deliver("user@example.com", &event)
+            .await
+            .unwrap_err();
+
+        assert_eq!(transport.calls(), 1);
+        assert!(failure.retries_exhausted());
+    }
+
+    #[tokio::test]
+    async fn invalid_recipient_fails_before_sending() {
+        let transport = MockTransport::new(vec![]);
+        let event = PlatformEvent::new("job.completed");
+
+        let failure = delivery(transport.clone(), 4)
+            .deliver("not-an-email", &event)
+            .await
+            .unwrap_err();
+
+        assert_eq!(transport.calls(), 0);
+        assert!(failure.attempts.is_empty());
+        assert!(matches!(failure.error, EmailError::Address(_)));
+    }
+
+    #[test]
+    fn classifies_reply_codes() {
+        assert_eq!(rejected(421).class(), FailureClass::Transient);
+        assert_eq!(rejected(450).class(), FailureClass::Transient);
+        assert_eq!(rejected(550).class(), FailureClass::Permanent);
+        assert_eq!(rejected(554).class(), FailureClass::Permanent);
+        assert_eq!(
+            EmailError::Build("x".to_string()).class(),
+            FailureClass::Permanent
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_cap() {
+        let policy = EmailRetryPolicy {
+            initial_backoff: Duration::from_secs(2),
+            max_backoff: Duration::from_secs(10),
+            ..EmailRetryPolicy::default()
+        };
+        assert_eq!(policy.backoff_after(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff_after(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff_after(3), Duration::from_secs(8));
+        assert_eq!(policy.backoff_after(4), Duration::from_secs(10));
+    }
+
     #[test]
     fn from_env_returns_none_without_smtp_host() {
         // Ensure SMTP_HOST is not set in the test environment.
@@ -1,7 +1,7 @@
 //! External delivery channels for platform notifications.
 //!
-//! This module provides webhook and email delivery services used by the
-//! notification router to push events outside the platform.
+//! This module provides email delivery and the webhook signing helpers used
+//! to push events outside the platform.
 
 pub mod email;
 pub mod webhook;
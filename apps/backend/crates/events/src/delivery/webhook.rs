@@ -1,18 +1,15 @@
-//! Webhook delivery with exponential-backoff retry.
+//! Webhook request signing and delivery errors.
 //!
-//! [`WebhookDelivery`] sends a JSON-encoded [`PlatformEvent`] to an external
-//! URL via HTTP POST. Failed attempts are retried up to three times with
-//! exponential backoff (1 s, 2 s, 4 s).
+//! Webhooks are delivered by the API's background delivery worker, which
+//! persists each attempt and dead-letters exhausted deliveries. Requests to
+//! a webhook with a secret are signed with [`sign_request`], which adds
+//! `X-Trulience-Timestamp` (Unix seconds) and
+//! `X-Trulience-Signature: sha256=<hex>`, the HMAC-SHA256 of
+//! `"{timestamp}.{body}"` keyed by the secret. See [`sign_webhook_request`].
 
-use std::time::Duration;
-
-use crate::bus::PlatformEvent;
-
-/// Retry delays in seconds (exponential backoff: 1s, 2s, 4s).
-const RETRY_DELAYS_SECS: [u64; 3] = [1, 2, 4];
-
-/// HTTP request timeout for a single delivery attempt.
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+use x121_core::api_keys::{
+    sign_webhook_request, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+};
 
 // ---------------------------------------------------------------------------
 // Error
@@ -31,79 +28,24 @@ pub enum WebhookError {
 }
 
 // ---------------------------------------------------------------------------
-// WebhookDelivery
+// Signing
 // ---------------------------------------------------------------------------
 
-/// Delivers platform events to external webhook endpoints.
-pub struct WebhookDelivery {
-    client: reqwest::Client,
-}
-
-impl WebhookDelivery {
-    /// Create a new delivery service with a pre-configured HTTP client.
-    pub fn new() -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(REQUEST_TIMEOUT)
-            .build()
-            .expect("Failed to build reqwest HTTP client");
-        Self { client }
-    }
-
-    /// Deliver an event payload to a webhook URL with retry.
-    ///
-    /// Retries up to 3 times with exponential backoff before giving up.
-    /// Returns `Ok(())` on the first successful attempt.
-    pub async fn deliver(&self, url: &str, event: &PlatformEvent) -> Result<(), WebhookError> {
-        let payload = serde_json::json!({
-            "event_type": event.event_type,
-            "payload": event.payload,
-            "timestamp": event.timestamp,
-            "source_entity_type": event.source_entity_type,
-            "source_entity_id": event.source_entity_id,
-        });
-
-        let mut last_err: Option<WebhookError> = None;
-
-        for (attempt, delay_secs) in RETRY_DELAYS_SECS.iter().enumerate() {
-            match self.try_send(url, &payload).await {
-                Ok(()) => return Ok(()),
-                Err(e) => {
-                    tracing::warn!(
-                        attempt = attempt + 1,
-                        url,
-                        error = %e,
-                        "Webhook delivery attempt failed, retrying"
-                    );
-                    last_err = Some(e);
-                    tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
-                }
-            }
-        }
-
-        // Final attempt after the last backoff.
-        match self.try_send(url, &payload).await {
-            Ok(()) => Ok(()),
-            Err(e) => {
-                tracing::error!(url, error = %e, "Webhook delivery failed after all retries");
-                Err(last_err.unwrap_or(e))
-            }
-        }
-    }
-
-    /// Execute a single POST request and check the response status.
-    async fn try_send(&self, url: &str, payload: &serde_json::Value) -> Result<(), WebhookError> {
-        let response = self.client.post(url).json(payload).send().await?;
-        if !response.status().is_success() {
-            return Err(WebhookError::HttpStatus(response.status().as_u16()));
-        }
-        Ok(())
-    }
-}
-
-impl Default for WebhookDelivery {
-    fn default() -> Self {
-        Self::new()
-    }
+/// Add the timestamp and signature headers for `body` signed with `secret`.
+///
+/// `body` must be exactly the bytes sent as the request body.
+pub fn sign_request(
+    request: reqwest::RequestBuilder,
+    secret: &str,
+    timestamp: i64,
+    body: &str,
+) -> reqwest::RequestBuilder {
+    request
+        .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+        .header(
+            WEBHOOK_SIGNATURE_HEADER,
+            sign_webhook_request(secret, timestamp, body),
+        )
 }
 
 // ---------------------------------------------------------------------------
@@ -115,13 +57,24 @@ mod tests {
     use super::*;
 
     #[test]
-    fn new_does_not_panic() {
-        let _delivery = WebhookDelivery::new();
-    }
-
-    #[test]
-    fn default_does_not_panic() {
-        let _delivery = WebhookDelivery::default();
+    fn sign_request_sets_known_good_headers() {
+        let body = r#"{"event_type":"job.completed"}"#;
+        let request = sign_request(
+            reqwest::Client::new().post("http://localhost/hook"),
+            "whsec_test",
+            1_700_000_000,
+            body,
+        )
+        .body(body)
+        .build()
+        .unwrap();
+
+        let headers = request.headers();
+        assert_eq!(headers[WEBHOOK_TIMESTAMP_HEADER], "1700000000");
+        assert_eq!(
+            headers[WEBHOOK_SIGNATURE_HEADER],
+            "sha256=dedeabdfa7ef530c8ff5b4a634497ae0006ecf48139920d6bcf06d1e31dabdc2"
+        );
     }
 
     #[test]
@@ -2,19 +2,27 @@
 //!
 //! [`DigestScheduler`] runs as a background task, periodically checking for
 //! users whose digest window has elapsed and marking their queued digest
-//! notifications as delivered. Actual email/webhook delivery of the aggregated
-//! digest summary will be wired in once the job system (PRD-07/08) and SMTP
-//! configuration are in place.
+//! notifications as delivered. Windows open at each user's preferred local
+//! hour in their own timezone (see [`x121_core::digest_schedule`]). Actual
+//! email/webhook delivery of the aggregated digest summary will be wired in
+//! once the job system (PRD-07/08) and SMTP configuration are in place.
 
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use tokio_util::sync::CancellationToken;
 use x121_core::channels::CHANNEL_DIGEST;
+use x121_core::digest_schedule::{
+    is_digest_due, parse_timezone, validate_digest_hour, DigestCadence, DEFAULT_DIGEST_HOUR,
+};
+use x121_db::models::notification::UserNotificationSettings;
 use x121_db::repositories::{NotificationPreferenceRepo, NotificationRepo};
 use x121_db::DbPool;
 
-/// How often the scheduler polls for due digests.
-const DIGEST_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+/// How often the scheduler polls for due digests. Short enough that a digest
+/// goes out within a few minutes of the user's preferred hour.
+const DIGEST_CHECK_INTERVAL: Duration = Duration::from_secs(300);
 
 // ---------------------------------------------------------------------------
 // DigestScheduler
@@ -33,7 +41,7 @@ impl DigestScheduler {
 
     /// Run the digest scheduler loop.
     ///
-    /// Checks every hour for users due for digest delivery. The loop exits
+    /// Checks every few minutes for users due for digest delivery. The loop exits
     /// gracefully when the provided [`CancellationToken`] is cancelled.
     pub async fn run(&self, cancel: CancellationToken) {
         let mut interval = tokio::time::interval(DIGEST_CHECK_INTERVAL);
@@ -55,8 +63,12 @@ impl DigestScheduler {
 
     /// Find all users due for a digest and process each one.
     async fn process_digests(&self) -> Result<(), sqlx::Error> {
-        let due_settings =
-            NotificationPreferenceRepo::list_users_due_for_digest(&self.pool).await?;
+        let now = Utc::now();
+        let due_settings: Vec<_> = NotificationPreferenceRepo::list_digest_enabled(&self.pool)
+            .await?
+            .into_iter()
+            .filter(|settings| digest_due(settings, now))
+            .collect();
 
         for settings in &due_settings {
             if let Err(e) = self.send_digest(settings.user_id).await {
@@ -101,3 +113,22 @@ impl DigestScheduler {
         Ok(())
     }
 }
+
+/// Decide whether a user's digest is due, falling back to defaults (with a
+/// warning) if stored settings are invalid so one bad row cannot stall others.
+fn digest_due(settings: &UserNotificationSettings, now: DateTime<Utc>) -> bool {
+    let cadence = DigestCadence::parse(&settings.digest_interval).unwrap_or_else(|e| {
+        tracing::warn!(user_id = settings.user_id, error = %e, "Falling back to daily digest");
+        DigestCadence::Daily
+    });
+    let tz = parse_timezone(&settings.timezone).unwrap_or_else(|e| {
+        tracing::warn!(user_id = settings.user_id, error = %e, "Falling back to UTC digest");
+        Tz::UTC
+    });
+    let hour = validate_digest_hour(i32::from(settings.digest_hour)).unwrap_or_else(|e| {
+        tracing::warn!(user_id = settings.user_id, error = %e, "Falling back to default hour");
+        DEFAULT_DIGEST_HOUR
+    });
+
+    is_digest_due(settings.digest_last_sent_at, cadence, tz, hour, now)
+}
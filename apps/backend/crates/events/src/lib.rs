@@ -7,8 +7,8 @@
 //!   `tokio::sync::broadcast`.
 //! - [`PlatformEvent`] — the canonical domain event envelope.
 //! - [`EventPersistence`] — background service that durably writes every
-//!   event to the `events` table.
-//! - [`delivery`] — external delivery channels (webhook, email).
+//!   event to the `events` table, and replays stored events by time range.
+//! - [`delivery`] — webhook request signing and email delivery.
 //! - [`DigestScheduler`] — periodic digest notification processor.
 
 pub mod activity;
@@ -19,7 +19,6 @@ pub mod persistence;
 
 pub use activity::ActivityLogBroadcaster;
 pub use bus::{EventBus, PlatformEvent};
-pub use delivery::email::{EmailConfig, EmailDelivery};
-pub use delivery::webhook::WebhookDelivery;
+pub use delivery::email::{EmailConfig, EmailDelivery, EmailRetryPolicy};
 pub use digest::DigestScheduler;
-pub use persistence::EventPersistence;
+pub use persistence::{EventPersistence, StoredEvent};
@@ -4,14 +4,28 @@
 //! broadcast channel and writes every received [`PlatformEvent`] to the
 //! `events` table. It runs as a long-lived background task and shuts down
 //! gracefully when the bus sender is dropped.
+//!
+//! Because every event is stored, consumers that missed events while they
+//! were down can read them back with [`EventPersistence::replay_since`] or
+//! [`EventPersistence::replay_range`].
 
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
 use tokio::sync::broadcast;
 use x121_core::types::DbId;
+use x121_db::models::event::EventWithTypeName;
 use x121_db::repositories::EventRepo;
 use x121_db::DbPool;
 
 use crate::bus::PlatformEvent;
 
+/// A replayed event together with the ID of its stored row.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    pub id: DbId,
+    pub event: PlatformEvent,
+}
+
 /// Background service that persists platform events to the database.
 pub struct EventPersistence;
 
@@ -66,4 +80,52 @@ impl EventPersistence {
         )
         .await
     }
+
+    /// Replay every stored event created at or after `since`, oldest first.
+    ///
+    /// See [`replay_range`](Self::replay_range) for the semantics.
+    pub fn replay_since(
+        pool: &DbPool,
+        since: DateTime<Utc>,
+    ) -> impl Stream<Item = StoredEvent> + '_ {
+        Self::replay_range(pool, since, None)
+    }
+
+    /// Replay stored events created in `[start, end)`, oldest first.
+    ///
+    /// The start is inclusive and the end exclusive, so consecutive ranges
+    /// sharing a boundary never replay an event twice; `None` leaves the
+    /// range open. The boundary is the time the event was stored, which
+    /// becomes the replayed event's `timestamp`. A row that cannot be read
+    /// is logged and skipped without ending the stream.
+    pub fn replay_range(
+        pool: &DbPool,
+        start: DateTime<Utc>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Stream<Item = StoredEvent> + '_ {
+        EventRepo::stream_in_range(pool, start, end).filter_map(|row| async move {
+            match row {
+                Ok(row) => Some(stored_to_event(row)),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Skipping stored event that failed to load");
+                    None
+                }
+            }
+        })
+    }
+}
+
+/// Rebuild the bus representation of a stored event.
+fn stored_to_event(row: EventWithTypeName) -> StoredEvent {
+    StoredEvent {
+        id: row.id,
+        event: PlatformEvent {
+            event_type: row.event_type,
+            source_entity_type: row.source_entity_type,
+            source_entity_id: row.source_entity_id,
+            actor_user_id: row.actor_user_id,
+            payload: row.payload,
+            timestamp: row.created_at,
+        },
+    }
 }
@@ -0,0 +1,82 @@
+//! Integration tests for dead-lettering exhausted notification emails.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use lettre::Message;
+use sqlx::PgPool;
+use x121_db::repositories::NotificationRepo;
+use x121_events::delivery::email::{EmailConfig, EmailError, EmailTransport};
+use x121_events::{EmailDelivery, EmailRetryPolicy, PlatformEvent};
+
+/// Transport whose relay always answers with the given reply code.
+struct RejectingTransport(u16);
+
+#[async_trait::async_trait]
+impl EmailTransport for RejectingTransport {
+    async fn send(&self, _message: Message) -> Result<(), EmailError> {
+        Err(EmailError::Rejected {
+            code: self.0,
+            message: format!("{} mock reply", self.0),
+        })
+    }
+}
+
+fn delivery(pool: &PgPool, reply_code: u16) -> EmailDelivery {
+    let config = EmailConfig {
+        smtp_host: "smtp.test".to_string(),
+        smtp_port: 587,
+        from_address: "noreply@x121.local".to_string(),
+        smtp_user: None,
+        smtp_password: None,
+    };
+    EmailDelivery::with_transport(config, Arc::new(RejectingTransport(reply_code)))
+        .with_retry_policy(EmailRetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            budget: Duration::from_secs(5),
+        })
+        .with_dead_letters(pool.clone())
+}
+
+/// An email that keeps failing transiently is dead-lettered once its
+/// retries run out.
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_exhausted_email_is_dead_lettered(pool: PgPool) {
+    let event =
+        PlatformEvent::new("job.completed").with_payload(serde_json::json!({ "job_id": 7 }));
+
+    let failure = delivery(&pool, 421)
+        .deliver("user@example.com", &event)
+        .await
+        .unwrap_err();
+    assert!(failure.retries_exhausted());
+
+    let rows = NotificationRepo::list_email_dead_letters_for_address(&pool, "user@example.com")
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].event_type, "job.completed");
+    assert_eq!(rows[0].payload["job_id"], 7);
+    assert_eq!(rows[0].attempt_count, 3);
+    assert_eq!(rows[0].attempts.as_array().unwrap().len(), 3);
+    assert!(rows[0].last_error.contains("421"));
+}
+
+/// A permanent rejection is not dead-lettered: resending cannot succeed.
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_permanent_failure_is_not_dead_lettered(pool: PgPool) {
+    let event = PlatformEvent::new("job.completed");
+
+    let failure = delivery(&pool, 550)
+        .deliver("user@example.com", &event)
+        .await
+        .unwrap_err();
+    assert!(!failure.retries_exhausted());
+
+    let rows = NotificationRepo::list_email_dead_letters_for_address(&pool, "user@example.com")
+        .await
+        .unwrap();
+    assert!(rows.is_empty());
+}
@@ -0,0 +1,56 @@
+//! Integration tests for replaying persisted events.
+
+use futures::StreamExt;
+use sqlx::PgPool;
+use x121_db::repositories::EventRepo;
+use x121_events::EventPersistence;
+
+/// Insert a `job.completed` event for `job_id` and return its stored time.
+async fn insert_event(pool: &PgPool, job_id: i64) -> chrono::DateTime<chrono::Utc> {
+    let event_type = EventRepo::get_event_type_by_name(pool, "job.completed")
+        .await
+        .unwrap()
+        .expect("job.completed is seeded");
+    let id = EventRepo::insert(
+        pool,
+        event_type.id,
+        Some("job"),
+        Some(job_id),
+        None,
+        &serde_json::json!({ "job_id": job_id }),
+    )
+    .await
+    .unwrap();
+
+    sqlx::query_scalar("SELECT created_at FROM events WHERE id = $1")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+/// Replaying from the middle of three events yields it and the later one.
+#[sqlx::test(migrations = "../../../db/migrations")]
+async fn test_replay_since_is_inclusive_of_start(pool: PgPool) {
+    insert_event(&pool, 1).await;
+    let middle = insert_event(&pool, 2).await;
+    let last = insert_event(&pool, 3).await;
+
+    let events: Vec<_> = EventPersistence::replay_since(&pool, middle)
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].event.event_type, "job.completed");
+    assert_eq!(events[0].event.source_entity_id, Some(2));
+    assert_eq!(events[0].event.timestamp, middle);
+    assert_eq!(events[1].event.source_entity_id, Some(3));
+    assert_eq!(events[1].event.payload["job_id"], 3);
+
+    // The end of a range is exclusive.
+    let events: Vec<_> = EventPersistence::replay_range(&pool, middle, Some(last))
+        .collect()
+        .await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.source_entity_id, Some(2));
+}
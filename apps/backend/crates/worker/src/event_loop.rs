@@ -951,9 +951,7 @@ async fn auto_validate_workflows(pool: &sqlx::PgPool, comfyui: &Arc<ComfyUIManag
         }
 
         let model_results: Vec<ModelValidationResult> = parsed
-            .referenced_models
-            .iter()
-            .chain(parsed.referenced_loras.iter())
+            .model_references()
             .map(|name| ModelValidationResult {
                 model_name: name.clone(),
                 found_in_registry: false,
@@ -0,0 +1,26 @@
+-- PRD-016: Resumable folder scanning for the importer.
+--
+-- Stores the relative path of the last scanned file as a cursor so an
+-- interrupted preview can continue from the checkpoint instead of
+-- rescanning the whole tree. Files scanned so far are appended to
+-- `import_scan_files`, so a checkpoint writes only the files scanned since
+-- the previous one. Both are cleared once the scan completes.
+
+ALTER TABLE import_sessions
+    ADD COLUMN scan_cursor TEXT;
+
+CREATE TABLE import_scan_files (
+    id               BIGSERIAL PRIMARY KEY,
+    session_id       BIGINT NOT NULL REFERENCES import_sessions(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    relative_path    TEXT NOT NULL,
+    file_size_bytes  BIGINT NOT NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX uq_import_scan_files_session_path
+    ON import_scan_files(session_id, relative_path);
+
+CREATE TRIGGER trg_import_scan_files_updated_at
+    BEFORE UPDATE ON import_scan_files
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,17 @@
+-- PRD-18: Studio-wide cache rebuild as a tracked bulk maintenance operation.
+--
+-- `rebuild_caches` recomputes completeness-driven readiness (PRD-66/107)
+-- for every avatar in chunked background work. `cancelled` lets an admin
+-- stop a long-running operation between chunks.
+
+INSERT INTO bulk_operation_types (name, label) VALUES
+    ('rebuild_caches', 'Rebuild Caches');
+
+INSERT INTO bulk_operation_statuses (name, label) VALUES
+    ('cancelled', 'Cancelled');
+
+-- At most one executing rebuild at a time.
+-- operation_type_id = 4 is "rebuild_caches", status_id = 2 is "executing".
+CREATE UNIQUE INDEX uq_bulk_operations_executing_rebuild
+    ON bulk_operations(operation_type_id)
+    WHERE operation_type_id = 4 AND status_id = 2;
@@ -0,0 +1,16 @@
+-- PRD-12: Per-webhook circuit breaking.
+--
+-- After a run of consecutive permanently failed deliveries a webhook moves
+-- to `suspended`: pending deliveries are held back and only a periodic
+-- recovery probe is sent (at `next_probe_at`). A successful probe or an
+-- admin re-enable returns it to `active`.
+
+ALTER TABLE webhooks
+    ADD COLUMN circuit_state        TEXT NOT NULL DEFAULT 'active'
+        CHECK (circuit_state IN ('active', 'suspended')),
+    ADD COLUMN consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN suspended_at         TIMESTAMPTZ,
+    ADD COLUMN next_probe_at        TIMESTAMPTZ;
+
+CREATE INDEX idx_webhooks_next_probe_at ON webhooks(next_probe_at)
+    WHERE circuit_state = 'suspended';
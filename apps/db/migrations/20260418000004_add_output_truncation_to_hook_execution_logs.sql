@@ -0,0 +1,9 @@
+-- PRD-77: Record when captured hook output was truncated.
+--
+-- `output_text` is capped at the capture limit and ends in a
+-- `…[truncated N bytes]` marker when cut; `output_original_len` keeps the
+-- full output length in bytes.
+
+ALTER TABLE hook_execution_logs
+    ADD COLUMN output_truncated    BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN output_original_len BIGINT;
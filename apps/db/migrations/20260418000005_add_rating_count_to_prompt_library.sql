@@ -0,0 +1,10 @@
+-- PRD-63: Track how many ratings back each prompt library average.
+--
+-- Ranking weighs `avg_rating` by `rating_count` so a single 5-star vote
+-- does not outrank a consistently well-rated entry. Existing rated entries
+-- hold a single directly-set rating.
+
+ALTER TABLE prompt_library
+    ADD COLUMN rating_count INTEGER NOT NULL DEFAULT 0;
+
+UPDATE prompt_library SET rating_count = 1 WHERE avg_rating IS NOT NULL;
@@ -0,0 +1,11 @@
+-- PRD-09: Execution history filtering.
+--
+-- Adds the `cancelled` execution status so history can be filtered by
+-- every terminal outcome, and an index serving per-script history queries
+-- ordered and range-filtered by creation time.
+
+INSERT INTO execution_statuses (name, label) VALUES
+    ('cancelled', 'Cancelled');
+
+CREATE INDEX idx_script_executions_script_id_created_at
+    ON script_executions(script_id, created_at DESC);
@@ -0,0 +1,9 @@
+-- PRD-62: Content-hash keyed keyframe thumbnails.
+--
+-- Stores the SHA-256 of each keyframe's source frame so thumbnails are
+-- cached by content: regenerated frames get a new thumbnail while
+-- unchanged frames reuse the existing one. NULL for pre-existing rows.
+
+ALTER TABLE keyframes ADD COLUMN source_hash TEXT;
+
+CREATE INDEX idx_keyframes_source_hash ON keyframes(source_hash);
@@ -0,0 +1,35 @@
+-- Configurable failure signatures (PRD-64).
+--
+-- Each signature classifies job error text into a known failure type by
+-- substring or regex, tried in descending priority. The matched key is
+-- recorded on the failed job so new failure types can be recognised by
+-- adding rows rather than code.
+
+CREATE TABLE failure_signatures (
+    id           BIGSERIAL   PRIMARY KEY,
+    pattern_key  TEXT        NOT NULL UNIQUE,
+    description  TEXT,
+    kind         TEXT        NOT NULL CHECK (kind IN ('substring', 'regex')),
+    pattern      TEXT        NOT NULL,
+    priority     INTEGER     NOT NULL DEFAULT 0,
+    is_enabled   BOOLEAN     NOT NULL DEFAULT true,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER set_updated_at BEFORE UPDATE ON failure_signatures
+    FOR EACH ROW EXECUTE FUNCTION trigger_set_updated_at();
+
+INSERT INTO failure_signatures (pattern_key, description, kind, pattern, priority) VALUES
+    ('oom', 'GPU or host out of memory', 'regex',
+     '(cuda )?out of memory|\bOOM\b|allocation on device', 100),
+    ('node_missing', 'Workflow references a node type that is not installed', 'regex',
+     'node (type )?\S+ (does not exist|not found)|missing node types?', 90),
+    ('model_missing', 'Model or checkpoint file not found', 'regex',
+     '(checkpoint|model|lora)\S* .*not found|value not in list', 80),
+    ('comfyui_unreachable', 'ComfyUI instance unreachable', 'substring',
+     'ComfyUI submission failed', 50);
+
+ALTER TABLE jobs ADD COLUMN failure_pattern_key TEXT;
+
+CREATE INDEX idx_jobs_failure_pattern_key ON jobs(failure_pattern_key);
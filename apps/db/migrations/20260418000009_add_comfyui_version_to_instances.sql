@@ -0,0 +1,7 @@
+-- PRD-05: Per-instance ComfyUI version detection.
+--
+-- Records the ComfyUI version each instance reported on its most recent
+-- connection so admins can spot version skew across the fleet. NULL until
+-- the instance connects (or when the server predates version reporting).
+
+ALTER TABLE comfyui_instances ADD COLUMN comfyui_version TEXT;
@@ -0,0 +1,17 @@
+-- PRD-10: Timezone-aware digest delivery.
+--
+-- Digests go out at a preferred local hour in each user's IANA timezone.
+-- Interval values written before cadences were validated (e.g. '24h') are
+-- normalised to the supported 'daily' / 'weekly' set.
+
+ALTER TABLE user_notification_settings
+    ADD COLUMN timezone    TEXT     NOT NULL DEFAULT 'UTC',
+    ADD COLUMN digest_hour SMALLINT NOT NULL DEFAULT 9
+        CHECK (digest_hour BETWEEN 0 AND 23);
+
+UPDATE user_notification_settings
+SET digest_interval = CASE
+        WHEN digest_interval IN ('7d', '168h', '1 week', '7 days') THEN 'weekly'
+        ELSE 'daily'
+    END
+WHERE digest_interval NOT IN ('daily', 'weekly');
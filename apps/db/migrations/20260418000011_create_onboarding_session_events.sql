@@ -0,0 +1,19 @@
+-- PRD-67: Onboarding session event history.
+--
+-- One row per wizard transition (advance, go-back, step-data update) so
+-- support can replay where a user got stuck. Rows are written in the same
+-- transaction as the session change they describe.
+
+CREATE TABLE onboarding_session_events (
+    id          BIGSERIAL   PRIMARY KEY,
+    session_id  BIGINT      NOT NULL REFERENCES onboarding_sessions(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    event_type  TEXT        NOT NULL CHECK (event_type IN ('advance', 'go_back', 'step_data')),
+    from_step   INTEGER     NOT NULL,
+    to_step     INTEGER     NOT NULL,
+    step_data   JSONB,
+    actor_id    BIGINT      REFERENCES users(id) ON DELETE SET NULL ON UPDATE CASCADE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_onboarding_session_events_session_id
+    ON onboarding_session_events(session_id, id);
@@ -0,0 +1,22 @@
+-- PRD-66: Avatar metadata completeness snapshots.
+--
+-- Completeness is otherwise computed on demand from the current metadata,
+-- so there is no way to see progress over time. A row is appended whenever
+-- an avatar's completeness percentage changes; the project trend endpoint
+-- downsamples these into a time series.
+
+CREATE TABLE avatar_completeness_snapshots (
+    id              BIGSERIAL        PRIMARY KEY,
+    avatar_id       BIGINT           NOT NULL REFERENCES avatars(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    project_id      BIGINT           NOT NULL REFERENCES projects(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    filled          INTEGER          NOT NULL,
+    total_required  INTEGER          NOT NULL,
+    percentage      DOUBLE PRECISION NOT NULL CHECK (percentage BETWEEN 0 AND 100),
+    recorded_at     TIMESTAMPTZ      NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_avatar_completeness_snapshots_project
+    ON avatar_completeness_snapshots(project_id, recorded_at);
+
+CREATE INDEX idx_avatar_completeness_snapshots_avatar
+    ON avatar_completeness_snapshots(avatar_id, recorded_at DESC);
@@ -0,0 +1,10 @@
+-- PRD-12: Webhook delivery history diagnostics.
+--
+-- Records how long the most recent attempt of each delivery took, and
+-- indexes deliveries for history queries filtered by status and time.
+
+ALTER TABLE webhook_deliveries
+    ADD COLUMN latency_ms INTEGER CHECK (latency_ms >= 0);
+
+CREATE INDEX idx_webhook_deliveries_history
+    ON webhook_deliveries(webhook_id, created_at DESC);
@@ -0,0 +1,8 @@
+-- PRD-29: Per-user theme token overrides.
+--
+-- Users may override individual tokens of the studio theme they use; the
+-- effective theme is the studio tokens with these layered on top.
+
+ALTER TABLE user_theme_preferences
+    ADD COLUMN token_overrides JSONB NOT NULL DEFAULT '{}'
+        CHECK (jsonb_typeof(token_overrides) = 'object');
@@ -0,0 +1,22 @@
+-- PRD-14: Chunked, resumable import commits.
+--
+-- A commit applies report entries in chunks, one transaction per chunk.
+-- Each entry records its outcome once its chunk commits, and the report
+-- tracks how many entries have been applied so an interrupted commit can
+-- be reported on and resumed.
+
+INSERT INTO import_report_statuses (name, description) VALUES
+    ('committing', 'Commit in progress or interrupted; re-commit to resume');
+
+ALTER TABLE import_reports
+    ADD COLUMN committed_records INTEGER NOT NULL DEFAULT 0 CHECK (committed_records >= 0),
+    ADD COLUMN last_commit_error TEXT;
+
+ALTER TABLE import_report_entries
+    ADD COLUMN outcome TEXT
+        CHECK (outcome IN ('created', 'updated', 'skipped', 'failed', 'duplicate')),
+    ADD COLUMN committed_at TIMESTAMPTZ;
+
+CREATE INDEX idx_import_report_entries_pending
+    ON import_report_entries(report_id, record_index)
+    WHERE outcome IS NULL;
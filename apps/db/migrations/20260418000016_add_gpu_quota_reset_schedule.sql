@@ -0,0 +1,11 @@
+-- PRD-08: Timezone-aware GPU quota resets.
+--
+-- Daily and weekly usage is counted from the most recent reset, which
+-- happens at local midnight (Monday midnight for weekly) in the quota's
+-- IANA timezone. A background task advances the stored reset instants as
+-- periods roll over.
+
+ALTER TABLE gpu_quotas
+    ADD COLUMN timezone        TEXT        NOT NULL DEFAULT 'UTC',
+    ADD COLUMN daily_reset_at  TIMESTAMPTZ NOT NULL DEFAULT date_trunc('day', NOW(), 'UTC'),
+    ADD COLUMN weekly_reset_at TIMESTAMPTZ NOT NULL DEFAULT date_trunc('week', NOW(), 'UTC');
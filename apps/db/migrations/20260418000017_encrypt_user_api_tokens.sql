@@ -0,0 +1,15 @@
+-- PRD-104: Encrypt user API tokens at rest.
+--
+-- Tokens are now AES-256-GCM encrypted, and each row records the ID of the
+-- key that sealed it so the key can be rotated while older tokens stay
+-- readable. Rows written before this change hold the plaintext token; there
+-- is no key available here to encrypt them, so the plaintext is cleared and
+-- the token marked invalid for the user to enter again.
+
+ALTER TABLE user_api_tokens
+    ADD COLUMN encryption_key_id TEXT;
+
+UPDATE user_api_tokens
+   SET encrypted_token = ''::bytea,
+       is_valid = false
+ WHERE encryption_key_id IS NULL;
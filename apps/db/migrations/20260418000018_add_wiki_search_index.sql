@@ -0,0 +1,25 @@
+-- PRD-56: Full-text search index for wiki articles.
+--
+-- Kept in its own table so indexing never touches `wiki_articles.updated_at`.
+-- Rows are written by the `reindex_wiki` bulk operation (chunked background
+-- rebuild, progress and cancellation via `bulk_operations`) and refreshed
+-- incrementally when articles are created, updated, or reverted. Articles
+-- without a row fall back to substring search.
+
+CREATE TABLE wiki_article_search_index (
+    article_id    BIGINT PRIMARY KEY REFERENCES wiki_articles(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    search_vector TSVECTOR NOT NULL,
+    indexed_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_wiki_article_search_index_vector
+    ON wiki_article_search_index USING gin(search_vector);
+
+INSERT INTO bulk_operation_types (name, label) VALUES
+    ('reindex_wiki', 'Reindex Wiki');
+
+-- At most one executing reindex at a time.
+-- operation_type_id = 5 is "reindex_wiki", status_id = 2 is "executing".
+CREATE UNIQUE INDEX uq_bulk_operations_executing_reindex_wiki
+    ON bulk_operations(operation_type_id)
+    WHERE operation_type_id = 5 AND status_id = 2;
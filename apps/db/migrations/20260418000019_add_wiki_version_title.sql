@@ -0,0 +1,7 @@
+-- PRD-56: Snapshot the article title with each wiki version so version diffs
+-- can report title changes.
+--
+-- Versions recorded before this migration keep a NULL title; diffs treat an
+-- unknown title as unchanged.
+
+ALTER TABLE wiki_versions ADD COLUMN title TEXT;
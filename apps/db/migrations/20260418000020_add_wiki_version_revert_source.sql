@@ -0,0 +1,9 @@
+-- PRD-56: Record revert provenance on wiki versions.
+--
+-- A revert appends a new version whose content equals the reverted-to
+-- version. These columns record which version was current when the revert
+-- happened and which version it restored; both are NULL for ordinary edits.
+
+ALTER TABLE wiki_versions
+    ADD COLUMN reverted_from_version INTEGER,
+    ADD COLUMN reverted_to_version   INTEGER;
@@ -0,0 +1,7 @@
+-- PRD-95: Full-text search index for production note content.
+--
+-- Backs the relevance-ranked `/notes/search` endpoint; the expression must
+-- match the one used by `ProductionNoteRepo::search` for the index to apply.
+
+CREATE INDEX idx_production_notes_content_fts
+    ON production_notes USING gin(to_tsvector('english', content_md));
@@ -0,0 +1,21 @@
+-- PRD-12: Dead-letter queue for webhook deliveries whose retries are exhausted.
+--
+-- Each row keeps enough to diagnose and replay the failed delivery. Rows
+-- recorded for a registered webhook link to the `dead_lettered` delivery
+-- shown in its history; `replayed_at` is stamped when that delivery is
+-- replayed.
+
+CREATE TABLE webhook_dead_letters (
+    id            BIGSERIAL PRIMARY KEY,
+    webhook_id    BIGINT REFERENCES webhooks(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    delivery_id   BIGINT REFERENCES webhook_deliveries(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    url           TEXT NOT NULL,
+    payload       JSONB NOT NULL,
+    last_error    TEXT NOT NULL,
+    attempt_count SMALLINT NOT NULL,
+    replayed_at   TIMESTAMPTZ,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhook_dead_letters_webhook_id  ON webhook_dead_letters(webhook_id);
+CREATE INDEX idx_webhook_dead_letters_delivery_id ON webhook_dead_letters(delivery_id);
@@ -0,0 +1,19 @@
+-- PRD-08: Fractional queue rank for atomic admin reordering.
+--
+-- The queue orders by priority (descending), then `queue_rank` (ascending).
+-- New jobs rank by submission time, so the order matches the previous
+-- priority/submitted_at ordering. Moving a job gives it a rank between its
+-- new neighbours without rewriting any other row.
+
+ALTER TABLE jobs
+    ADD COLUMN queue_rank DOUBLE PRECISION NOT NULL DEFAULT EXTRACT(EPOCH FROM NOW());
+
+-- Backfill without bumping `updated_at` on every job.
+ALTER TABLE jobs DISABLE TRIGGER trg_jobs_updated_at;
+UPDATE jobs SET queue_rank = EXTRACT(EPOCH FROM submitted_at);
+ALTER TABLE jobs ENABLE TRIGGER trg_jobs_updated_at;
+
+DROP INDEX idx_jobs_pending_unclaimed;
+CREATE INDEX idx_jobs_pending_unclaimed
+    ON jobs(priority DESC, queue_rank ASC)
+    WHERE status_id = 1 AND claimed_at IS NULL;
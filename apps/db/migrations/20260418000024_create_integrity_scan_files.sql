@@ -0,0 +1,23 @@
+-- PRD-43: Per-file results of an integrity scan.
+--
+-- One row per model file checked by a scan. Kept out of
+-- `integrity_scans.results_json` so large reports can be paged and
+-- streamed instead of loaded as a single JSON value.
+
+CREATE TABLE integrity_scan_files (
+    id             BIGSERIAL PRIMARY KEY,
+    scan_id        BIGINT NOT NULL REFERENCES integrity_scans(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    file_path      TEXT NOT NULL,
+    model_name     TEXT,
+    status         TEXT NOT NULL CHECK (status IN ('ok', 'missing', 'corrupted')),
+    expected_hash  TEXT,
+    actual_hash    TEXT,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_integrity_scan_files_scan_id ON integrity_scan_files(scan_id, id);
+
+CREATE TRIGGER trg_integrity_scan_files_updated_at
+    BEFORE UPDATE ON integrity_scan_files
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
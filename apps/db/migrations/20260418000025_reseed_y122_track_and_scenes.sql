@@ -0,0 +1,38 @@
+-- Idempotent re-seed of the y122 "main" track and default scene types.
+--
+-- 20260322200001 inserts these rows unconditionally and fails on databases
+-- that already hold a conflicting track or studio-level scene type. That
+-- migration is left untouched (editing it would change its checksum); this
+-- one fills in whatever rows are still missing and links them to the track.
+
+INSERT INTO tracks (name, slug, sort_order, is_active, pipeline_id)
+SELECT 'Main', 'main', 1, true, p.id
+FROM pipelines p WHERE p.code = 'y122'
+ON CONFLICT DO NOTHING;
+
+INSERT INTO scene_types (name, slug, status_id, sort_order, is_active, pipeline_id, description)
+SELECT unnest(ARRAY['Presenting', 'Listening', 'Reacting', 'Thinking', 'Idle']),
+       unnest(ARRAY['presenting', 'listening', 'reacting', 'thinking', 'idle']),
+       1, -- active status
+       generate_series(1, 5),
+       true,
+       p.id,
+       unnest(ARRAY[
+         'Actively gesturing and engaging with the camera',
+         'Attentive listening posture, nodding',
+         'Subtle facial responses and reactions',
+         'Pausing, looking thoughtful',
+         'Neutral, waiting position'
+       ])
+FROM pipelines p WHERE p.code = 'y122'
+ON CONFLICT DO NOTHING;
+
+INSERT INTO scene_type_tracks (scene_type_id, track_id)
+SELECT st.id, t.id
+FROM scene_types st
+CROSS JOIN tracks t
+WHERE st.pipeline_id = (SELECT id FROM pipelines WHERE code = 'y122')
+  AND t.pipeline_id = st.pipeline_id
+  AND t.slug = 'main'
+  AND st.deleted_at IS NULL
+ON CONFLICT DO NOTHING;
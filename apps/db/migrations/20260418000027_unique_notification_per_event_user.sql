@@ -0,0 +1,13 @@
+-- At most one notification per event and user.
+--
+-- The notification router replays stored events on startup; with this
+-- index a replayed event that was already routed inserts nothing.
+
+DELETE FROM notifications n
+USING notifications dup
+WHERE n.event_id = dup.event_id
+  AND n.user_id = dup.user_id
+  AND n.id > dup.id;
+
+CREATE UNIQUE INDEX uq_notifications_event_user
+    ON notifications(event_id, user_id);
@@ -0,0 +1,19 @@
+-- PRD-10: Dead-letter store for notification emails whose retries are
+-- exhausted.
+--
+-- Each row keeps the recipient, the event, and every failed attempt so the
+-- delivery can be diagnosed and resent. Permanent failures (bad addresses,
+-- 5xx replies) are not recorded: resending them cannot succeed.
+
+CREATE TABLE email_dead_letters (
+    id            BIGSERIAL PRIMARY KEY,
+    to_address    TEXT NOT NULL,
+    event_type    TEXT NOT NULL,
+    payload       JSONB NOT NULL,
+    last_error    TEXT NOT NULL,
+    attempt_count SMALLINT NOT NULL,
+    attempts      JSONB NOT NULL DEFAULT '[]',
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_dead_letters_to_address ON email_dead_letters(to_address);
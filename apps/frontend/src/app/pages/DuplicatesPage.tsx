@@ -18,7 +18,7 @@ import {
   useResolveCheck,
   useUpdateDuplicateSettings,
 } from "@/features/duplicates";
-import type { FlaggedPair } from "@/features/duplicates";
+import type { DuplicateCheck, FlaggedPair } from "@/features/duplicates";
 
 /* --------------------------------------------------------------------------
    Component
@@ -37,8 +37,14 @@ export function DuplicatesPage() {
     batchCheck.mutate(
       { avatar_ids: [] },
       {
-        onSuccess: (checks) => {
-          const pairs: FlaggedPair[] = checks
+        onSuccess: (items) => {
+          // A matched pair's check is listed under both avatars.
+          const checks = new Map<number, DuplicateCheck>();
+          for (const item of items) {
+            if (item.outcome !== "checked") continue;
+            for (const c of item.checks) checks.set(c.id, c);
+          }
+          const pairs: FlaggedPair[] = [...checks.values()]
             .filter((c) => c.matched_avatar_id !== null)
             .map((c) => ({
               checkId: c.id,
@@ -2,7 +2,7 @@
  * Delivery history log for a single webhook (PRD-12).
  *
  * Displays deliveries with status, response codes, and retry info.
- * Supports replaying failed deliveries.
+ * Supports replaying failed and dead-lettered deliveries.
  */
 
 import { useCallback } from "react";
@@ -25,6 +25,7 @@ function deliveryBadgeVariant(status: WebhookDelivery["status"]): "success" | "d
     case "delivered":
       return "success";
     case "failed":
+    case "dead_lettered":
       return "danger";
     case "retrying":
       return "warning";
@@ -44,7 +45,10 @@ interface DeliveryRowProps {
 }
 
 function DeliveryRow({ delivery, onReplay, isReplaying }: DeliveryRowProps) {
-  const canReplay = delivery.status === "failed" || delivery.status === "delivered";
+  const canReplay =
+    delivery.status === "failed" ||
+    delivery.status === "dead_lettered" ||
+    delivery.status === "delivered";
 
   return (
     <tr className="border-b border-[var(--color-border-default)]">
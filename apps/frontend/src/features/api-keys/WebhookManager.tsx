@@ -134,9 +134,16 @@ function WebhookRow({ webhook, onDelete, onViewDeliveries }: WebhookRowProps) {
         </div>
       </td>
       <td className="px-4 py-3">
-        <Badge variant={webhook.is_enabled ? "success" : "default"} size="sm">
-          {webhook.is_enabled ? "Enabled" : "Disabled"}
-        </Badge>
+        <div className="flex flex-wrap gap-1">
+          <Badge variant={webhook.is_enabled ? "success" : "default"} size="sm">
+            {webhook.is_enabled ? "Enabled" : "Disabled"}
+          </Badge>
+          {webhook.circuit_state === "suspended" && (
+            <Badge variant="danger" size="sm">
+              Suspended
+            </Badge>
+          )}
+        </div>
       </td>
       <td className="px-4 py-3 text-xs text-[var(--color-text-muted)]">
         {webhook.last_triggered_at
@@ -18,6 +18,10 @@ const MOCK_WEBHOOK: Webhook = {
   created_by: 1,
   last_triggered_at: "2026-02-20T15:00:00Z",
   failure_count: 0,
+  circuit_state: "active",
+  consecutive_failures: 0,
+  suspended_at: null,
+  next_probe_at: null,
   created_at: "2026-02-19T08:00:00Z",
   updated_at: "2026-02-19T08:00:00Z",
 };
@@ -15,6 +15,7 @@ import type {
   UpdateWebhookInput,
   Webhook,
   WebhookDelivery,
+  WebhookDeliveryPage,
 } from "../types";
 
 /* --------------------------------------------------------------------------
@@ -130,10 +131,12 @@ export function useWebhooks() {
 export function useWebhookDeliveries(webhookId: number | null) {
   return useQuery({
     queryKey: webhookKeys.deliveries(webhookId ?? 0),
-    queryFn: () =>
-      api.get<WebhookDelivery[]>(
+    queryFn: async () => {
+      const page = await api.get<WebhookDeliveryPage>(
         `/admin/webhooks/${webhookId}/deliveries?limit=50`,
-      ),
+      );
+      return page.items;
+    },
     enabled: webhookId !== null,
   });
 }
@@ -76,6 +76,11 @@ export interface Webhook {
   created_by: number;
   last_triggered_at: string | null;
   failure_count: number;
+  /** `suspended` after repeated permanent delivery failures. */
+  circuit_state: "active" | "suspended";
+  consecutive_failures: number;
+  suspended_at: string | null;
+  next_probe_at: string | null;
   created_at: string;
   updated_at: string;
 }
@@ -105,13 +110,21 @@ export interface WebhookDelivery {
   webhook_id: number;
   event_id: number | null;
   payload: unknown;
-  status: "pending" | "retrying" | "delivered" | "failed";
+  status: "pending" | "retrying" | "delivered" | "failed" | "dead_lettered";
   response_status_code: number | null;
   response_body: string | null;
   attempt_count: number;
   max_attempts: number;
   next_retry_at: string | null;
   delivered_at: string | null;
+  /** Duration of the most recent attempt, in milliseconds. */
+  latency_ms: number | null;
   created_at: string;
   updated_at: string;
 }
+
+/** One page of a webhook's delivery history, newest first. */
+export interface WebhookDeliveryPage {
+  items: WebhookDelivery[];
+  total: number;
+}
@@ -102,6 +102,13 @@ export function IntegrityCheck() {
               {result.first_break !== null && (
                 <p className={`${TYPO_DATA_DANGER} mt-2`}>
                   Chain break detected at entry #{result.first_break}
+                  {result.break_details && (
+                    <>
+                      {" "}(position {result.break_details.index}): expected{" "}
+                      {result.break_details.expected_hash.slice(0, 12)}, found{" "}
+                      {result.break_details.actual_hash.slice(0, 12)}
+                    </>
+                  )}
                 </p>
               )}
             </div>
@@ -12,5 +12,6 @@ export type {
   AuditLogPage,
   AuditRetentionPolicy,
   UpdateRetentionPolicy,
+  ChainBreak,
   IntegrityCheckResult,
 } from "./types";
@@ -72,10 +72,19 @@ export interface UpdateRetentionPolicy {
    Integrity check result
    -------------------------------------------------------------------------- */
 
+export interface ChainBreak {
+  /** Zero-based position of the entry within the verified sequence. */
+  index: number;
+  entry_id: number;
+  expected_hash: string;
+  actual_hash: string;
+}
+
 export interface IntegrityCheckResult {
   verified_entries: number;
   chain_valid: boolean;
   first_break: number | null;
+  break_details: ChainBreak | null;
 }
 
 /* --------------------------------------------------------------------------
@@ -16,8 +16,11 @@ export {
 // Types
 export type {
   Checkpoint,
+  FailureCategory,
+  FailureDiagnosis,
   FailureDiagnostics,
   FailureDiagnosticDetail,
+  KnownFix,
   ResumeFromCheckpointInput,
   PipelineStage,
   StageStatus,
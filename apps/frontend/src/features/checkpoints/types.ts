@@ -31,6 +31,7 @@ export interface FailureDiagnostics {
   failure_diagnostics: FailureDiagnosticDetail | null;
   last_checkpoint_id: number | null;
   original_job_id: number | null;
+  diagnosis: FailureDiagnosis | null;
 }
 
 export interface FailureDiagnosticDetail {
@@ -45,6 +46,32 @@ export interface FailureDiagnosticDetail {
   timestamp: string;
 }
 
+export type FailureCategory =
+  | "memory"
+  | "timeout"
+  | "missing_model"
+  | "workflow_validation"
+  | "connection"
+  | "unknown";
+
+/** A fix recorded against the matched failure pattern (PRD-64). */
+export interface KnownFix {
+  fix_id: number;
+  description: string;
+  parameters: Record<string, unknown> | null;
+  effectiveness: string | null;
+}
+
+/** Classified cause of a job failure with a suggested fix. */
+export interface FailureDiagnosis {
+  category: FailureCategory;
+  likely_cause: string;
+  suggested_fix: string;
+  pattern_key: string | null;
+  matched_text: string | null;
+  known_fixes: KnownFix[];
+}
+
 /* --------------------------------------------------------------------------
    Resume from checkpoint
    -------------------------------------------------------------------------- */
@@ -9,6 +9,7 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import { api } from "@/lib/api";
 
 import type {
+  PaletteSearchResult,
   RecordAccessRequest,
   UserRecentItem,
 } from "../types";
@@ -37,12 +38,12 @@ export function useRecentItems(limit: number = DEFAULT_RECENT_LIMIT) {
   });
 }
 
-/** Search the palette (debounced query). */
+/** Search the palette (debounced query), best match first. */
 export function usePaletteSearch(query: string) {
   return useQuery({
     queryKey: paletteKeys.search(query),
     queryFn: () =>
-      api.get<unknown[]>(`/search/palette?q=${encodeURIComponent(query)}`),
+      api.get<PaletteSearchResult[]>(`/search/palette?q=${encodeURIComponent(query)}`),
     enabled: query.trim().length > 0,
   });
 }
@@ -29,11 +29,13 @@ export { useKeyboardNavigation } from "./hooks/useKeyboardNavigation";
 
 // Types
 export type {
+  MatchSpan,
   PaletteCategory,
   PaletteCommand,
   PaletteEntityType,
   PaletteResult as PaletteResultType,
   PaletteSearchParams,
+  PaletteSearchResult,
   RecordAccessRequest,
   UserRecentItem,
 } from "./types";
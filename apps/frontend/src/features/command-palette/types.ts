@@ -43,6 +43,22 @@ export interface PaletteSearchParams {
   limit?: number;
 }
 
+/** Character range `[start, end)` of a name matched by the query. */
+export interface MatchSpan {
+  start: number;
+  end: number;
+}
+
+/** Ranked server-side palette search result. */
+export interface PaletteSearchResult {
+  kind: "command" | "entity";
+  entity_type: string;
+  entity_id: number;
+  name: string;
+  score: number;
+  match_spans: MatchSpan[];
+}
+
 /** Category filter for palette results. */
 export type PaletteCategory = "all" | "commands" | "entities";
 
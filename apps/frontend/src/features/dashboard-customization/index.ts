@@ -39,6 +39,7 @@ export type {
   UpdateDashboardPreset,
   WidgetCategory,
   WidgetDefinition,
+  WidgetInstance,
 } from "./types";
 export {
   GRID_COLS_DESKTOP,
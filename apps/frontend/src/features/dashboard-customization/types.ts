@@ -86,9 +86,16 @@ export interface DashboardRoleDefault {
 /** Layout source indicating which priority level provided the active layout. */
 export type DashboardLayoutSource = "preset" | "role_default" | "platform_default";
 
+/** A widget placed on the resolved dashboard, with its catalogue details. */
+export interface WidgetInstance extends LayoutItem {
+  name: string;
+  category: WidgetCategory;
+}
+
 /** The fully resolved layout the current user should see. */
 export interface DashboardLayout {
-  layout: LayoutItem[];
+  /** Widgets the user can access, ordered top-to-bottom, left-to-right. */
+  layout: WidgetInstance[];
   widget_settings: Record<string, Record<string, unknown>>;
   source: DashboardLayoutSource;
 }
@@ -8,6 +8,7 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 
 import { api } from "@/lib/api";
 import type {
+  BatchCheckItem,
   BatchCheckRequest,
   CheckDuplicateRequest,
   DuplicateCheck,
@@ -51,7 +52,7 @@ export function useBatchCheck() {
 
   return useMutation({
     mutationFn: (input: BatchCheckRequest) =>
-      api.post<DuplicateCheck[]>("/avatars/duplicates/batch", input),
+      api.post<BatchCheckItem[]>("/avatars/duplicates/batch", input),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: duplicateKeys.all });
     },
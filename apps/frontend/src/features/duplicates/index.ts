@@ -18,6 +18,7 @@ export {
 
 // Types
 export type {
+  BatchCheckItem,
   BatchCheckRequest,
   CheckDuplicateRequest,
   DuplicateCheck,
@@ -24,6 +24,16 @@ export interface DuplicateCheck {
   updated_at: string;
 }
 
+/** Per-avatar result of a batch duplicate check. */
+export type BatchCheckItem =
+  | { avatar_id: number; outcome: "checked"; checks: DuplicateCheck[] }
+  | {
+      avatar_id: number;
+      outcome: "skipped";
+      reason: "not_found" | "no_embedding";
+      message: string;
+    };
+
 export interface DuplicateMatchResponse {
   check_id: number;
   matched_avatar_id: number;
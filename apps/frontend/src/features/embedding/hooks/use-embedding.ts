@@ -6,6 +6,8 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 
 import { api } from "@/lib/api";
 import type {
+  BatchExtractRequest,
+  BatchExtractionReport,
   DetectedFace,
   EmbeddingHistory,
   EmbeddingStatusResponse,
@@ -28,8 +30,12 @@ export const embeddingKeys = {
   histories: () => [...embeddingKeys.all, "history"] as const,
   history: (avatarId: number) =>
     [...embeddingKeys.histories(), avatarId] as const,
+  batch: (batchId: string) => [...embeddingKeys.all, "batch", batchId] as const,
 };
 
+/** Poll interval while a batch extraction is running. */
+const BATCH_POLL_MS = 2000;
+
 /* --------------------------------------------------------------------------
    Helpers
    -------------------------------------------------------------------------- */
@@ -78,6 +84,20 @@ export function useEmbeddingHistory(avatarId: number) {
   });
 }
 
+/** Poll a batch extraction's progress until it finishes. */
+export function useBatchExtraction(batchId: string | null) {
+  return useQuery({
+    queryKey: embeddingKeys.batch(batchId ?? ""),
+    queryFn: () =>
+      api.get<BatchExtractionReport>(
+        `/avatars/embedding/batch-extract/${batchId}`,
+      ),
+    enabled: batchId !== null,
+    refetchInterval: (query) =>
+      query.state.data?.finished_at ? false : BATCH_POLL_MS,
+  });
+}
+
 /* --------------------------------------------------------------------------
    Mutation hooks
    -------------------------------------------------------------------------- */
@@ -126,3 +146,34 @@ export function useSelectFace(avatarId: number) {
     },
   });
 }
+
+/** Start face embedding extraction for a set of avatars. */
+export function useBatchExtractEmbeddings() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: BatchExtractRequest) =>
+      api.post<BatchExtractionReport>("/avatars/embedding/batch-extract", data),
+    onSuccess: (report) => {
+      queryClient.setQueryData(embeddingKeys.batch(report.batch_id), report);
+      queryClient.invalidateQueries({ queryKey: embeddingKeys.statuses() });
+    },
+  });
+}
+
+/** Cancel a running batch extraction. */
+export function useCancelBatchExtraction() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (batchId: string) =>
+      api.post<BatchExtractionReport>(
+        `/avatars/embedding/batch-extract/${batchId}/cancel`,
+      ),
+    onSuccess: (report) => {
+      queryClient.invalidateQueries({
+        queryKey: embeddingKeys.batch(report.batch_id),
+      });
+    },
+  });
+}
@@ -8,6 +8,8 @@
 export {
   EMBEDDING_STATUS,
   EMBEDDING_STATUS_LABEL,
+  type BatchExtractRequest,
+  type BatchExtractionReport,
   type BoundingBox,
   type DetectedFace,
   type EmbeddingHistory,
@@ -20,6 +22,9 @@ export {
 // Hooks
 export {
   embeddingKeys,
+  useBatchExtractEmbeddings,
+  useBatchExtraction,
+  useCancelBatchExtraction,
   useDetectedFaces,
   useEmbeddingHistory,
   useEmbeddingStatus,
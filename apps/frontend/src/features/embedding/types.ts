@@ -73,6 +73,19 @@ export interface EmbeddingHistory {
   updated_at: string;
 }
 
+/** Progress report for a batch embedding extraction. */
+export interface BatchExtractionReport {
+  batch_id: string;
+  total: number;
+  done: number;
+  extracted: number;
+  skipped: number;
+  failed: number;
+  cancelled: boolean;
+  started_at: string;
+  finished_at: string | null;
+}
+
 /* --------------------------------------------------------------------------
    Request DTOs
    -------------------------------------------------------------------------- */
@@ -84,3 +97,8 @@ export interface ExtractEmbeddingRequest {
 export interface SelectFaceRequest {
   face_id: number;
 }
+
+export interface BatchExtractRequest {
+  avatar_ids: number[];
+  concurrency?: number;
+}
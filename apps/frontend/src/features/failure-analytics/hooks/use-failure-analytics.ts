@@ -13,6 +13,7 @@ import type {
   FailurePattern,
   HeatmapData,
   PatternFix,
+  SpikeCheckResponse,
   TrendPoint,
 } from "../types";
 
@@ -32,6 +33,8 @@ export const failureAnalyticsKeys = {
     [...failureAnalyticsKeys.all, "trends", patternId, periodDays] as const,
   alerts: (workflowId?: number, avatarId?: number) =>
     [...failureAnalyticsKeys.all, "alerts", workflowId, avatarId] as const,
+  spikes: (patternKey?: string) =>
+    [...failureAnalyticsKeys.all, "spikes", patternKey] as const,
   fixes: (patternId: number) =>
     [...failureAnalyticsKeys.all, "fixes", patternId] as const,
 };
@@ -128,6 +131,21 @@ export function useFailureAlerts(workflowId?: number, avatarId?: number) {
   });
 }
 
+/** Checks the daily job failure trend for a rate spike. */
+export function useFailureSpike(patternKey?: string) {
+  const qs = new URLSearchParams();
+  if (patternKey) qs.set("pattern_key", patternKey);
+  const qsStr = qs.toString();
+
+  return useQuery({
+    queryKey: failureAnalyticsKeys.spikes(patternKey),
+    queryFn: () =>
+      api.get<SpikeCheckResponse>(
+        `/analytics/failure-spikes${qsStr ? `?${qsStr}` : ""}`,
+      ),
+  });
+}
+
 /* --------------------------------------------------------------------------
    Fix queries and mutations
    -------------------------------------------------------------------------- */
@@ -11,6 +11,7 @@ export {
   useFailureHeatmap,
   useFailurePattern,
   useFailurePatterns,
+  useFailureSpike,
   useFailureTrends,
   usePatternFixes,
   useUpdateFixEffectiveness,
@@ -21,9 +22,11 @@ export type {
   AlertResponse,
   CreatePatternFix,
   FailurePattern,
+  FailureSpikeAlert,
   HeatmapCell,
   HeatmapData,
   PatternFix,
+  SpikeCheckResponse,
   TrendPoint,
 } from "./types";
 export {
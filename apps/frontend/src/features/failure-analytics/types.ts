@@ -83,6 +83,21 @@ export interface TrendPoint {
 /** Alert response is simply a list of high-severity matching patterns. */
 export type AlertResponse = FailurePattern[];
 
+/** A failure-rate spike: the trailing window exceeds the baseline rate. */
+export interface FailureSpikeAlert {
+  window_start: string;
+  window_rate: number;
+  window_samples: number;
+  baseline_rate: number;
+  baseline_samples: number;
+}
+
+/** Daily job failure trend plus the spike alert, if one was detected. */
+export interface SpikeCheckResponse {
+  trend: TrendPoint[];
+  alert: FailureSpikeAlert | null;
+}
+
 /* --------------------------------------------------------------------------
    Constants
    -------------------------------------------------------------------------- */
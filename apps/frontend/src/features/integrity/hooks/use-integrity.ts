@@ -12,6 +12,7 @@ import type {
   IntegrityScan,
   ModelChecksum,
   UpdateModelChecksum,
+  WorkerRepairReport,
   WorkerReport,
 } from "../types";
 
@@ -24,6 +25,8 @@ export const integrityKeys = {
   scans: () => [...integrityKeys.all, "scans"] as const,
   workerReport: (workerId: number) =>
     [...integrityKeys.all, "worker-report", workerId] as const,
+  repairRun: (runId: string) =>
+    [...integrityKeys.all, "repair-run", runId] as const,
   checksums: () => [...integrityKeys.all, "checksums"] as const,
   checksumsByType: (modelType: string) =>
     [...integrityKeys.all, "checksums", modelType] as const,
@@ -80,14 +83,17 @@ export function useStartScan() {
    Repair mutations
    -------------------------------------------------------------------------- */
 
-/** Trigger a full repair for a worker. */
+/** Start a full repair for a worker, optionally resuming an earlier run. */
 export function useRepairWorker() {
   const queryClient = useQueryClient();
 
   return useMutation({
-    mutationFn: (workerId: number) =>
-      api.post<IntegrityScan>(`/admin/repair/${workerId}`),
-    onSuccess: (_data, workerId) => {
+    mutationFn: ({ workerId, resumeRunId }: { workerId: number; resumeRunId?: string }) =>
+      api.post<WorkerRepairReport>(`/admin/repair/${workerId}`, {
+        resume_run_id: resumeRunId ?? null,
+      }),
+    onSuccess: (report, { workerId }) => {
+      queryClient.setQueryData(integrityKeys.repairRun(report.run_id), report);
       queryClient.invalidateQueries({
         queryKey: integrityKeys.scans(),
       });
@@ -98,6 +104,31 @@ export function useRepairWorker() {
   });
 }
 
+/** Polls the per-step report of a full repair run until it finishes. */
+export function useRepairRun(runId: string | null) {
+  return useQuery({
+    queryKey: integrityKeys.repairRun(runId ?? ""),
+    queryFn: () => api.get<WorkerRepairReport>(`/admin/repair/runs/${runId}`),
+    enabled: runId !== null,
+    refetchInterval: (query) => (query.state.data?.finished_at ? false : 2000),
+  });
+}
+
+/** Cancel a full repair run before its next step. */
+export function useCancelRepairRun() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (runId: string) =>
+      api.post<WorkerRepairReport>(`/admin/repair/runs/${runId}/cancel`),
+    onSuccess: (report) => {
+      queryClient.invalidateQueries({
+        queryKey: integrityKeys.repairRun(report.run_id),
+      });
+    },
+  });
+}
+
 /** Trigger model sync for a worker. */
 export function useSyncModels() {
   const queryClient = useQueryClient();
@@ -6,11 +6,13 @@ export { WorkerHealthDashboard } from "./WorkerHealthDashboard";
 // Hooks
 export {
   integrityKeys,
+  useCancelRepairRun,
   useCreateChecksum,
   useDeleteChecksum,
   useInstallNodes,
   useIntegrityScans,
   useModelChecksums,
+  useRepairRun,
   useRepairWorker,
   useStartScan,
   useSyncModels,
@@ -24,8 +26,13 @@ export type {
   CreateModelChecksum,
   HealthStatus,
   IntegrityScan,
+  IntegrityScanFile,
   ModelChecksum,
+  RepairStepEntry,
+  RepairStepStatus,
+  ScanFileStatus,
   UpdateModelChecksum,
+  WorkerRepairReport,
   WorkerReport,
 } from "./types";
 export {
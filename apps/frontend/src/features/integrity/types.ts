@@ -32,9 +32,51 @@ export interface CreateIntegrityScan {
   scan_type: string;
 }
 
+export type ScanFileStatus = "ok" | "missing" | "corrupted";
+
+export interface IntegrityScanFile {
+  id: number;
+  scan_id: number;
+  file_path: string;
+  model_name: string | null;
+  status: ScanFileStatus;
+  expected_hash: string | null;
+  actual_hash: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
 export interface WorkerReport {
   scan: IntegrityScan | null;
   health_status: string | null;
+  /** One page of per-file results; `files_total` is the full count. */
+  files: IntegrityScanFile[];
+  files_total: number;
+}
+
+/* --------------------------------------------------------------------------
+   Full repair runs
+   -------------------------------------------------------------------------- */
+
+export type RepairStepStatus = "pending" | "running" | "succeeded" | "failed" | "skipped";
+
+export interface RepairStepEntry {
+  step: string;
+  status: RepairStepStatus;
+  scan_id: number | null;
+  error: string | null;
+  started_at: string | null;
+  finished_at: string | null;
+}
+
+export interface WorkerRepairReport {
+  run_id: string;
+  worker_id: number;
+  resumed_from: string | null;
+  steps: RepairStepEntry[];
+  cancelled: boolean;
+  started_at: string;
+  finished_at: string | null;
 }
 
 /* --------------------------------------------------------------------------
@@ -43,4 +43,5 @@ export type {
   OperationListParams,
   PreviewResponse,
   RepathRequest,
+  ReplacementPreview,
 } from "./types";
@@ -48,11 +48,22 @@ export interface FieldInfo {
   column_name: string;
 }
 
+/** Exact change a find/replace would make to one field value. */
+export interface ReplacementPreview {
+  entity_type: string;
+  column_name: string;
+  entity_id: number;
+  before: string;
+  after: string;
+}
+
 /** Response for a find/replace or re-path preview. */
 export interface PreviewResponse {
   operation_id: number;
   total_matches: number;
   searchable_fields: FieldInfo[];
+  /** First matches with exact before/after values (empty for re-path). */
+  matches: ReplacementPreview[];
 }
 
 /** Response for an execute or undo action. */
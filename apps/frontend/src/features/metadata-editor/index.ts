@@ -27,6 +27,7 @@ export type {
   ProjectCompleteness,
   AvatarMetadataResponse,
   MetadataUpdateResult,
+  CompletenessWarning,
   MetadataValidationFailure,
   MetadataFieldError,
   CsvDiffEntry,
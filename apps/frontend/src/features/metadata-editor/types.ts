@@ -88,6 +88,17 @@ export interface MetadataUpdateResult {
   status: string;
   avatar_id: number;
   metadata: Record<string, unknown>;
+  /** Present when the update cleared previously filled required fields. */
+  completeness_warning?: CompletenessWarning;
+}
+
+/** Warning attached to an update that reduced required-field completeness. */
+export interface CompletenessWarning {
+  message: string;
+  newly_missing: string[];
+  newly_filled: string[];
+  percentage_before: number;
+  percentage_after: number;
 }
 
 /** Result of a validation failure. */
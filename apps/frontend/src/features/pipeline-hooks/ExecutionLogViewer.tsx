@@ -9,7 +9,7 @@
 import { useState } from "react";
 
 import { Badge } from "@/components";
-import { formatDateTime } from "@/lib/format";
+import { formatBytes, formatDateTime } from "@/lib/format";
 
 import { useHookLogs, useJobHookLogs } from "./hooks/use-pipeline-hooks";
 import type { HookExecutionLog } from "./types";
@@ -117,6 +117,11 @@ function LogEntry({ log }: { log: HookExecutionLog }) {
               <span className={TYPO_INPUT_LABEL}>
                 Output:
               </span>
+              {log.output_truncated && log.output_original_len != null && (
+                <span className={`ml-2 ${TYPO_CAPTION}`}>
+                  (truncated, {formatBytes(log.output_original_len)} total)
+                </span>
+              )}
               <pre className={`mt-1 max-h-32 overflow-auto rounded bg-[var(--color-bg-secondary)] p-2 ${TYPO_DATA}`}>
                 {log.output_text}
               </pre>
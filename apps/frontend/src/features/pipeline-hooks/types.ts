@@ -54,6 +54,8 @@ export interface HookExecutionLog {
   job_id: number | null;
   input_json: Record<string, unknown> | null;
   output_text: string | null;
+  output_truncated: boolean;
+  output_original_len: number | null;
   exit_code: number | null;
   duration_ms: number | null;
   success: boolean;
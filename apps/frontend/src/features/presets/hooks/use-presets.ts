@@ -17,6 +17,7 @@ import type {
   MarketplaceSortBy,
   OverrideDiff,
   Preset,
+  PresetApplyResult,
   PresetRating,
   PresetWithRating,
   Template,
@@ -244,7 +245,7 @@ export function useApplyPreset() {
       presetId: number;
       sceneTypeId: number;
     }) =>
-      api.post<Record<string, unknown>>(
+      api.post<PresetApplyResult>(
         `/presets/${presetId}/apply/${sceneTypeId}`,
       ),
     onSuccess: () => {
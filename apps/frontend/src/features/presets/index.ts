@@ -36,6 +36,7 @@ export type {
   MarketplaceSortBy,
   OverrideDiff,
   Preset,
+  PresetApplyResult,
   PresetRating,
   PresetWithRating,
   Scope,
@@ -118,6 +118,12 @@ export interface OverrideDiff {
   preset_value: unknown;
 }
 
+/** Result of applying a preset: the changes written and the resulting params. */
+export interface PresetApplyResult {
+  applied: OverrideDiff[];
+  generation_params: Record<string, unknown>;
+}
+
 /* --------------------------------------------------------------------------
    Enums / Constants
    -------------------------------------------------------------------------- */
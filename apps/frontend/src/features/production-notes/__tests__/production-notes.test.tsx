@@ -411,11 +411,11 @@ describe("productionNoteKeys", () => {
     ]);
   });
 
-  it("search includes query", () => {
-    expect(productionNoteKeys.search("hello")).toEqual([
+  it("search includes params", () => {
+    expect(productionNoteKeys.search({ q: "hello", resolved: false })).toEqual([
       "production-notes",
       "search",
-      "hello",
+      { q: "hello", resolved: false },
     ]);
   });
 
@@ -14,6 +14,9 @@ import type {
   CreateProductionNote,
   NoteCategory,
   NoteEntityType,
+  NoteSearchParams,
+  NoteSearchResult,
+  NoteThread,
   ProductionNote,
   UpdateProductionNote,
 } from "../types";
@@ -30,7 +33,8 @@ export const productionNoteKeys = {
     ["production-notes", "pinned", entityType, entityId] as const,
   thread: (noteId: number) =>
     ["production-notes", "thread", noteId] as const,
-  search: (q: string) => ["production-notes", "search", q] as const,
+  search: (params: NoteSearchParams) =>
+    ["production-notes", "search", params] as const,
   categories: ["production-notes", "categories"] as const,
   detail: (id: number) => ["production-notes", "detail", id] as const,
 };
@@ -85,24 +89,27 @@ export function useProductionNote(id: number) {
   });
 }
 
-/** List replies (thread) for a note. */
+/** Fetch a note's reply thread as a tree. */
 export function useNoteThread(noteId: number) {
   return useQuery({
     queryKey: productionNoteKeys.thread(noteId),
-    queryFn: () => api.get<ProductionNote[]>(`/notes/${noteId}/thread`),
+    queryFn: () => api.get<NoteThread>(`/notes/${noteId}/thread`),
     enabled: noteId > 0,
   });
 }
 
-/** Search notes by content, optionally filtered by entity type. */
-export function useSearchNotes(q: string, entityType?: NoteEntityType) {
-  const qs = new URLSearchParams({ q });
-  if (entityType) qs.set("entity_type", entityType);
+/** Search notes by content and filters, ranked by relevance when `q` is set. */
+export function useSearchNotes(params: NoteSearchParams) {
+  const qs = new URLSearchParams();
+  for (const [key, value] of Object.entries(params)) {
+    if (value !== undefined && value !== "") qs.set(key, String(value));
+  }
 
   return useQuery({
-    queryKey: productionNoteKeys.search(q),
-    queryFn: () => api.get<ProductionNote[]>(`/notes/search?${qs.toString()}`),
-    enabled: q.length > 0,
+    queryKey: productionNoteKeys.search(params),
+    queryFn: () =>
+      api.get<NoteSearchResult[]>(`/notes/search?${qs.toString()}`),
+    enabled: qs.toString().length > 0,
   });
 }
 
@@ -34,6 +34,9 @@ export type {
   NoteCategory,
   NoteEntityType,
   NoteSearchParams,
+  NoteSearchResult,
+  NoteThread,
+  NoteThreadNode,
   NoteVisibility,
   ProductionNote,
   UpdateProductionNote,
@@ -54,6 +54,20 @@ export interface ProductionNote {
   updated_at: string;
 }
 
+/** A note and its replies in a thread; `note` is null for a tombstone. */
+export interface NoteThreadNode {
+  id: number;
+  note: ProductionNote | null;
+  replies: NoteThreadNode[];
+}
+
+/** A reply thread rooted at one note. */
+export interface NoteThread {
+  root: NoteThreadNode;
+  /** Replies whose parent was deleted, shown under tombstones. */
+  orphaned_reply_ids: number[];
+}
+
 /* --------------------------------------------------------------------------
    Request types
    -------------------------------------------------------------------------- */
@@ -82,8 +96,23 @@ export interface UpdateProductionNote {
   visibility?: NoteVisibility;
 }
 
-/** Query parameters for note search. */
+/** Query parameters for note search; all filters combine with AND. */
 export interface NoteSearchParams {
-  q: string;
+  q?: string;
   entity_type?: NoteEntityType;
+  /** Requires `entity_type`. */
+  entity_id?: number;
+  category_id?: number;
+  /** Note author. */
+  user_id?: number;
+  resolved?: boolean;
+  limit?: number;
+  offset?: number;
+}
+
+/** A note matched by search; `rank` and `snippet` are null without `q`. */
+export interface NoteSearchResult extends ProductionNote {
+  rank: number | null;
+  /** Content excerpt with matched terms wrapped in `<b>` tags. */
+  snippet: string | null;
 }
@@ -74,6 +74,15 @@ export interface CreateProductionRunRequest {
   estimated_disk_gb?: number | null;
   /** When true, check for existing approved scenes and pre-mark those cells as completed. */
   retrospective?: boolean;
+  /** Cells to leave out of the run. */
+  excluded_cells?: MatrixCellKey[];
+}
+
+/** Coordinates of one production matrix cell. */
+export interface MatrixCellKey {
+  avatar_id: number;
+  scene_type_id: number;
+  track_id: number | null;
 }
 
 /** An entry from the enabled-scene-types endpoint. */
@@ -22,6 +22,7 @@ const makeEntry = (
   model_compatibility: ["SDXL"],
   usage_count: 42,
   avg_rating: 4.5,
+  rating_count: 12,
   owner_id: 1,
   created_at: "2026-02-23T10:00:00Z",
   updated_at: "2026-02-23T10:00:00Z",
@@ -12,6 +12,7 @@ import { api } from "@/lib/api";
 import type {
   CreateLibraryEntryRequest,
   PromptLibraryEntry,
+  PromptLibrarySort,
   UpdateLibraryEntryRequest,
 } from "../types";
 
@@ -21,8 +22,8 @@ import type {
 
 export const promptLibraryKeys = {
   all: ["prompt-library"] as const,
-  list: (search?: string) =>
-    ["prompt-library", "list", { search }] as const,
+  list: (search?: string, sort?: PromptLibrarySort) =>
+    ["prompt-library", "list", { search, sort }] as const,
   detail: (id: number) => ["prompt-library", "detail", id] as const,
 };
 
@@ -30,16 +31,19 @@ export const promptLibraryKeys = {
    Queries
    -------------------------------------------------------------------------- */
 
-/** List prompt library entries with optional search. */
-export function usePromptLibrary(search?: string) {
+/** List prompt library entries with optional search and ranked ordering. */
+export function usePromptLibrary(search?: string, sort?: PromptLibrarySort) {
   const params = new URLSearchParams();
   if (search) {
     params.set("search", search);
   }
+  if (sort) {
+    params.set("sort", sort);
+  }
   const qs = params.toString();
 
   return useQuery({
-    queryKey: promptLibraryKeys.list(search),
+    queryKey: promptLibraryKeys.list(search, sort),
     queryFn: () =>
       api.get<PromptLibraryEntry[]>(
         `/prompt-library${qs ? `?${qs}` : ""}`,
@@ -33,6 +33,7 @@ export type {
   CreatePromptVersionRequest,
   PromptDiff,
   PromptLibraryEntry,
+  PromptLibrarySort,
   PromptVersion,
   UpdateLibraryEntryRequest,
 } from "./types";
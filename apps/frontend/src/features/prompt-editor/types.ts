@@ -46,11 +46,15 @@ export interface PromptLibraryEntry {
   model_compatibility: string[] | null;
   usage_count: number;
   avg_rating: number | null;
+  rating_count: number;
   owner_id: number;
   created_at: string;
   updated_at: string;
 }
 
+/** Ranked ordering for prompt library listings. */
+export type PromptLibrarySort = "top" | "recent" | "trending";
+
 /** Diff summary between two prompt versions. */
 export interface PromptDiff {
   positive_changed: boolean;
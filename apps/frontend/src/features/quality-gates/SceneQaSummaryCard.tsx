@@ -2,12 +2,13 @@
  * Scene QA Summary Card — displays aggregate QA results for a scene (PRD-49).
  *
  * Shows total segments, failures, warnings, and all-passed count
- * with a color-coded progress bar.
+ * with a color-coded progress bar, plus the scene's gate decision.
  */
 
 import { Card, CardBody, CardHeader } from "@/components/composite";
 import { Tooltip } from "@/components/primitives";
 import { cn } from "@/lib/cn";
+import { qaMetricLabel } from "@/lib/qa-constants";
 
 import type { SceneQaSummary } from "./types";
 import { TYPO_CAPTION } from "@/lib/typography-tokens";
@@ -55,7 +56,7 @@ function BarSegment({
    -------------------------------------------------------------------------- */
 
 export function SceneQaSummaryCard({ summary }: SceneQaSummaryCardProps) {
-  const { total_segments, segments_with_failures, segments_with_warnings, all_passed } =
+  const { total_segments, segments_with_failures, segments_with_warnings, all_passed, gate } =
     summary;
 
   const hasFailures = segments_with_failures > 0;
@@ -76,6 +77,19 @@ export function SceneQaSummaryCard({ summary }: SceneQaSummaryCardProps) {
         >
           Scene QA Summary
         </h3>
+        <p
+          data-testid="gate-status"
+          className={cn(
+            TYPO_CAPTION,
+            gate.pass
+              ? "text-[var(--color-action-success)]"
+              : "text-[var(--color-action-danger)]",
+          )}
+        >
+          {gate.pass
+            ? "Gate passed"
+            : `Gate failed: ${gate.failing_checks.map(qaMetricLabel).join(", ")}`}
+        </p>
       </CardHeader>
       <CardBody>
         <div className="grid grid-cols-2 gap-3 sm:grid-cols-4 mb-4">
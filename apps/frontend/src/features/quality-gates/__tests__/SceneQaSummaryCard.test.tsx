@@ -20,6 +20,7 @@ const summary: SceneQaSummary = {
   segments_with_failures: 2,
   segments_with_warnings: 3,
   all_passed: 5,
+  gate: { pass: false, failing_checks: ["motion"], weighted_score: null },
 };
 
 const allPassed: SceneQaSummary = {
@@ -28,6 +29,7 @@ const allPassed: SceneQaSummary = {
   segments_with_failures: 0,
   segments_with_warnings: 0,
   all_passed: 8,
+  gate: { pass: true, failing_checks: [], weighted_score: null },
 };
 
 /* --------------------------------------------------------------------------
@@ -68,4 +70,15 @@ describe("SceneQaSummaryCard", () => {
     const headerNoFailures = screen.getByText("Scene QA Summary");
     expect(headerNoFailures.className).toContain("color-text-primary");
   });
+
+  test("shows the gate decision with failing checks", () => {
+    const { unmount } = renderWithProviders(
+      <SceneQaSummaryCard summary={summary} />,
+    );
+    expect(screen.getByTestId("gate-status")).toHaveTextContent("Gate failed: Motion");
+    unmount();
+
+    renderWithProviders(<SceneQaSummaryCard summary={allPassed} />);
+    expect(screen.getByTestId("gate-status")).toHaveTextContent("Gate passed");
+  });
 });
@@ -17,6 +17,7 @@ export {
 // Types
 export type {
   CreateQaThreshold,
+  GateDecision,
   QaScoreSummary,
   QaThreshold,
   QualityScore,
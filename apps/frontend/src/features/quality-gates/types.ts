@@ -38,6 +38,15 @@ export interface SceneQaSummary {
   segments_with_failures: number;
   segments_with_warnings: number;
   all_passed: number;
+  gate: GateDecision;
+}
+
+/** Whether a set of QA scores clears the gate. */
+export interface GateDecision {
+  pass: boolean;
+  failing_checks: string[];
+  /** Only present for weighted gate policies. */
+  weighted_score: number | null;
 }
 
 /* --------------------------------------------------------------------------
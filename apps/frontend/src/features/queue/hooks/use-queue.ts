@@ -16,7 +16,9 @@ import type {
   QueueJobFilter,
   QueueStats,
   QueueStatus,
+  QueuedJob,
   QuotaStatus,
+  ReorderJobInput,
   SchedulingPolicy,
   SetGpuQuotaInput,
   UpsertSchedulingPolicyInput,
@@ -130,13 +132,13 @@ export function useJobTransitions(jobId: number) {
    Admin: reorder
    -------------------------------------------------------------------------- */
 
-/** Reorder a job's priority (admin only). */
+/** Move a queued job or change its priority (admin only). Returns the new queue order. */
 export function useReorderJob() {
   const queryClient = useQueryClient();
 
   return useMutation({
-    mutationFn: (input: { job_id: number; new_priority: number }) =>
-      api.put<unknown>("/admin/queue/reorder", input),
+    mutationFn: (input: ReorderJobInput) =>
+      api.put<QueuedJob[]>("/admin/queue/reorder", input),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: queueKeys.status() });
     },
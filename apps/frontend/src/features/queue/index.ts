@@ -41,6 +41,7 @@ export type {
   QueueStatus,
   QueuedJob,
   QuotaStatus,
+  ReorderJobInput,
   GpuQuota,
   SetGpuQuotaInput,
   SchedulingPolicy,
@@ -42,6 +42,19 @@ export interface QueueStatus {
   jobs: QueuedJob[];
 }
 
+/**
+ * Body for PUT /admin/queue/reorder. Give exactly one of `position` or
+ * `new_priority`; `expected_order` (the queue's job ids as last seen) is
+ * required with `position` and the reorder is rejected with 409 if the
+ * queue has changed since.
+ */
+export interface ReorderJobInput {
+  job_id: number;
+  position?: number;
+  new_priority?: number;
+  expected_order?: number[];
+}
+
 /* --------------------------------------------------------------------------
    Quota
    -------------------------------------------------------------------------- */
@@ -54,6 +67,8 @@ export type QuotaStatus =
       daily_limit_secs: number | null;
       used_this_week_secs: number;
       weekly_limit_secs: number | null;
+      daily_resets_at: string;
+      weekly_resets_at: string;
     }
   | {
       status: "exceeded";
@@ -61,6 +76,8 @@ export type QuotaStatus =
       daily_limit_secs: number | null;
       used_this_week_secs: number;
       weekly_limit_secs: number | null;
+      daily_resets_at: string;
+      weekly_resets_at: string;
       exceeded_type: string;
     };
 
@@ -71,6 +88,9 @@ export interface GpuQuota {
   daily_limit_secs: number | null;
   weekly_limit_secs: number | null;
   is_enabled: boolean;
+  timezone: string;
+  daily_reset_at: string;
+  weekly_reset_at: string;
   created_at: string;
   updated_at: string;
 }
@@ -79,6 +99,7 @@ export interface SetGpuQuotaInput {
   daily_limit_secs: number | null;
   weekly_limit_secs: number | null;
   is_enabled: boolean;
+  timezone?: string;
 }
 
 /* --------------------------------------------------------------------------
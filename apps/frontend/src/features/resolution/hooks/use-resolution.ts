@@ -8,6 +8,8 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 
 import { api } from "@/lib/api";
 import type {
+  BatchUpscaleRequest,
+  BatchUpscaleResponse,
   ResolutionTier,
   UpscaleRequest,
   UpscaleResponse,
@@ -74,3 +76,16 @@ export function useUpscaleScene(sceneId: number) {
     },
   });
 }
+
+/** Upscale several scenes to the same tier, reporting each scene's outcome. */
+export function useBatchUpscaleScenes() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (input: BatchUpscaleRequest) =>
+      api.post<BatchUpscaleResponse>("/scenes/batch-upscale", input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: resolutionKeys.all });
+    },
+  });
+}
@@ -6,9 +6,15 @@
 
 // Types
 export type {
+  BatchUpscaleItem,
+  BatchUpscaleOutcome,
+  BatchUpscaleRequest,
+  BatchUpscaleResponse,
   ResolutionTier,
+  UpscalePlan,
   UpscaleRequest,
   UpscaleResponse,
+  UpscaleStep,
 } from "./types";
 export {
   TIER_COLORS,
@@ -19,6 +25,7 @@ export {
 // Hooks
 export {
   resolutionKeys,
+  useBatchUpscaleScenes,
   useResolutionTier,
   useResolutionTiers,
   useSceneTier,
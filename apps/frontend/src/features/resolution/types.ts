@@ -33,10 +33,50 @@ export interface UpscaleRequest {
   target_tier_id: number;
 }
 
+export interface UpscaleStep {
+  from_tier_id: number;
+  to_tier_id: number;
+  to_tier_name: string;
+  scale_factor: number;
+}
+
+export interface UpscalePlan {
+  from_tier_id: number;
+  to_tier_id: number;
+  /** Empty when the scene was already at the target tier. */
+  steps: UpscaleStep[];
+  total_scale_factor: number;
+}
+
 export interface UpscaleResponse {
   original_scene_id: number;
   new_scene_id: number;
   target_tier: string;
+  plan: UpscalePlan;
+}
+
+export interface BatchUpscaleRequest {
+  scene_ids: number[];
+  target_tier_id: number;
+}
+
+export type BatchUpscaleOutcome = "accepted" | "rejected";
+
+export interface BatchUpscaleItem {
+  scene_id: number;
+  outcome: BatchUpscaleOutcome;
+  /** Why the scene was rejected (null when accepted). */
+  reason: string | null;
+  /** The tier hops applied (null when rejected). */
+  plan: UpscalePlan | null;
+}
+
+export interface BatchUpscaleResponse {
+  target_tier_id: number;
+  target_tier: string;
+  accepted: number;
+  rejected: number;
+  results: BatchUpscaleItem[];
 }
 
 /* --------------------------------------------------------------------------
@@ -12,6 +12,8 @@ import type {
   ClearStaleResponse,
   RegenerateRequest,
   RegenerateResponse,
+  SceneRestitchReport,
+  SceneRestitchRequest,
   SegmentVersionInfo,
   SmoothBoundaryRequest,
   SmoothBoundaryResponse,
@@ -27,6 +29,8 @@ export const restitchingKeys = {
     [...restitchingKeys.all, "boundary-check", segmentId] as const,
   versions: (segmentId: number) =>
     [...restitchingKeys.all, "versions", segmentId] as const,
+  sceneRun: (runId: string) =>
+    [...restitchingKeys.all, "scene-run", runId] as const,
 };
 
 /* --------------------------------------------------------------------------
@@ -53,6 +57,18 @@ export function useSegmentVersions(segmentId: number) {
   });
 }
 
+/** Poll a scene re-stitch run until it finishes. */
+export function useSceneRestitchRun(runId: string | null) {
+  return useQuery({
+    queryKey: restitchingKeys.sceneRun(runId ?? ""),
+    queryFn: () =>
+      api.get<SceneRestitchReport>(`/scenes/restitch/${runId}`),
+    enabled: runId !== null,
+    refetchInterval: (query) =>
+      query.state.data?.finished_at ? false : 2000,
+  });
+}
+
 /* --------------------------------------------------------------------------
    Mutations
    -------------------------------------------------------------------------- */
@@ -110,3 +126,33 @@ export function useClearStale(segmentId: number) {
     },
   });
 }
+
+/** Re-stitch a whole scene, regenerating segments with failing boundaries. */
+export function useRestitchScene(sceneId: number) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (input: SceneRestitchRequest) =>
+      api.post<SceneRestitchReport>(`/scenes/${sceneId}/restitch`, input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({
+        queryKey: restitchingKeys.all,
+      });
+    },
+  });
+}
+
+/** Cancel a running scene re-stitch. */
+export function useCancelSceneRestitch() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (runId: string) =>
+      api.post<SceneRestitchReport>(`/scenes/restitch/${runId}/cancel`),
+    onSuccess: (_data, runId) => {
+      queryClient.invalidateQueries({
+        queryKey: restitchingKeys.sceneRun(runId),
+      });
+    },
+  });
+}
@@ -7,8 +7,11 @@ export { SegmentVersionComparison } from "./SegmentVersionComparison";
 export {
   restitchingKeys,
   useBoundaryCheck,
+  useCancelSceneRestitch,
   useClearStale,
   useRegenerateSegment,
+  useRestitchScene,
+  useSceneRestitchRun,
   useSegmentVersions,
   useSmoothBoundary,
 } from "./hooks/use-restitching";
@@ -20,6 +23,11 @@ export type {
   ClearStaleResponse,
   RegenerateRequest,
   RegenerateResponse,
+  RestitchAction,
+  SceneRestitchReport,
+  SceneRestitchRequest,
+  SegmentRestitchEntry,
+  SegmentRestitchStatus,
   SegmentVersionInfo,
   SmoothBoundaryRequest,
   SmoothBoundaryResponse,
@@ -60,6 +60,42 @@ export interface ClearStaleResponse {
   cleared: boolean;
 }
 
+/* --------------------------------------------------------------------------
+   Scene-level re-stitch
+   -------------------------------------------------------------------------- */
+
+export interface SceneRestitchRequest {
+  ssim_threshold?: number;
+  /** Resume an interrupted run from its `checkpoint`. */
+  resume_from?: number;
+}
+
+export type RestitchAction = "keep" | "regenerate";
+
+export type SegmentRestitchStatus = "pending" | "kept" | "regenerated" | "failed";
+
+export interface SegmentRestitchEntry {
+  segment_id: number;
+  sequence_index: number;
+  action: RestitchAction;
+  failing_ssim: number | null;
+  status: SegmentRestitchStatus;
+  new_segment_id: number | null;
+  error: string | null;
+}
+
+export interface SceneRestitchReport {
+  run_id: string;
+  scene_id: number;
+  ssim_threshold: number;
+  segments: SegmentRestitchEntry[];
+  /** Sequence index of the first unprocessed segment; null when done. */
+  checkpoint: number | null;
+  cancelled: boolean;
+  started_at: string;
+  finished_at: string | null;
+}
+
 /* --------------------------------------------------------------------------
    Enums and constants
    -------------------------------------------------------------------------- */
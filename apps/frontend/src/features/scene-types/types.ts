@@ -135,11 +135,16 @@ export interface UpdateSceneType {
   auto_retry_cfg_jitter?: number | null;
 }
 
+/** Where an effective prompt template came from. */
+export type SceneTypeSource = "studio" | "project_scene_type" | "project_override";
+
 export interface PromptPreviewResponse {
   positive_prompt: string;
   negative_prompt: string;
   unresolved_placeholders: string[];
   source: string;
+  positive_source: SceneTypeSource | null;
+  negative_source: SceneTypeSource | null;
 }
 
 export interface MatrixCell {
@@ -10,11 +10,12 @@ import type {
   CreateStorageBackend,
   CreateStorageMigration,
   CreateTieringPolicy,
+  PolicySimulation,
+  SimulatePolicyInput,
   StorageBackend,
   StorageMigration,
   TestS3ConnectionInput,
   TestS3ConnectionResponse,
-  TieringCandidate,
   TieringPolicy,
   UpdateStorageBackend,
 } from "../types";
@@ -136,12 +137,8 @@ export function useCreatePolicy() {
 /** Simulate a tiering policy (dry run). */
 export function useSimulatePolicy() {
   return useMutation({
-    mutationFn: (input: {
-      entity_type: string;
-      source_tier: string;
-      age_threshold_days?: number;
-      access_threshold_days?: number;
-    }) => api.post<TieringCandidate[]>("/admin/storage/policies/simulate", input),
+    mutationFn: (input: SimulatePolicyInput) =>
+      api.post<PolicySimulation>("/admin/storage/policies/simulate", input),
   });
 }
 
@@ -4,16 +4,19 @@ export { MigrationProgressView } from "./MigrationProgressView";
 export { TierIndicator } from "./TierIndicator";
 export type {
   AssetLocation,
+  BackendMoveSummary,
   CreateStorageBackend,
   CreateStorageMigration,
   CreateTieringPolicy,
+  PolicySimulation,
+  SimulatedMove,
+  SimulatePolicyInput,
   StorageBackend,
   StorageBackendStatusId,
   StorageBackendTypeId,
   StorageMigration,
   StorageMigrationStatusId,
   StorageTier,
-  TieringCandidate,
   TieringPolicy,
   UpdateStorageBackend,
 } from "./types";
@@ -180,15 +180,42 @@ export interface CreateTieringPolicy {
   is_active?: boolean;
 }
 
-/** A tiering simulation candidate. */
-export interface TieringCandidate {
+/** A proposed tiering policy to simulate. */
+export interface SimulatePolicyInput {
+  entity_type: string;
+  source_tier: StorageTier;
+  target_tier: StorageTier;
+  target_backend_id: number;
+  age_threshold_days?: number;
+  access_threshold_days?: number;
+}
+
+/** An asset a simulated policy would relocate. */
+export interface SimulatedMove {
   entity_type: string;
   entity_id: number;
   file_field: string;
   file_size_bytes: number;
-  current_backend_id: number;
-  last_accessed_at: string | null;
-  access_count: number;
+  from_backend_id: number;
+  to_backend_id: number;
+  to_tier: StorageTier;
+}
+
+/** Simulated relocations from one backend to another. */
+export interface BackendMoveSummary {
+  from_backend_id: number;
+  to_backend_id: number;
+  asset_count: number;
+  total_bytes: number;
+}
+
+/** What applying a tiering policy would relocate. */
+export interface PolicySimulation {
+  /** Largest first, capped at 500; totals cover every relocated asset. */
+  assets: SimulatedMove[];
+  by_backend: BackendMoveSummary[];
+  total_assets: number;
+  total_bytes: number;
 }
 
 /** A storage migration row from the API. */
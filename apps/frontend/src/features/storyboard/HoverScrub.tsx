@@ -9,7 +9,7 @@
 import { useCallback, useRef, useState } from "react";
 
 import type { Keyframe } from "./types";
-import { formatTimecode } from "./types";
+import { formatTimecode, thumbnailSrc } from "./types";
 
 /* --------------------------------------------------------------------------
    Types
@@ -77,7 +77,7 @@ export function HoverScrub({ keyframes, onFrameSelect }: HoverScrubProps) {
       {/* Active frame image */}
       <img
         data-testid="scrub-image"
-        src={active.full_res_path ?? active.thumbnail_path}
+        src={active.full_res_path ?? thumbnailSrc(active)}
         alt={`Frame ${active.frame_number}`}
         className="w-full object-contain transition-opacity duration-100"
       />
@@ -7,6 +7,7 @@
  */
 
 import { useSceneStoryboard } from "./hooks/use-storyboard";
+import { thumbnailSrc } from "./types";
 
 /* --------------------------------------------------------------------------
    Types
@@ -69,7 +70,7 @@ export function MatrixThumbnail({
     >
       <img
         data-testid={`matrix-poster-${sceneId}`}
-        src={poster.thumbnail_path}
+        src={thumbnailSrc(poster)}
         alt={`Scene ${sceneId} poster`}
         className="h-16 w-full object-cover"
       />
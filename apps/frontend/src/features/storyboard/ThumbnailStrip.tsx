@@ -6,7 +6,7 @@
  */
 
 import type { Keyframe } from "./types";
-import { formatTimecode } from "./types";
+import { formatTimecode, thumbnailSrc } from "./types";
 
 /* --------------------------------------------------------------------------
    Types
@@ -86,7 +86,7 @@ export function ThumbnailStrip({
           className="flex-shrink-0 cursor-pointer rounded border border-transparent hover:border-[var(--color-border-accent)] focus:outline-none focus:ring-2 focus:ring-[var(--color-border-accent)]"
         >
           <img
-            src={kf.thumbnail_path}
+            src={thumbnailSrc(kf)}
             alt={`Frame ${kf.frame_number}`}
             className="h-[100px] w-auto rounded object-cover"
           />
@@ -39,6 +39,7 @@ describe("MatrixThumbnail", () => {
           timestamp_secs: 0.0,
           thumbnail_path: "/thumbs/poster.jpg",
           full_res_path: null,
+          source_hash: null,
           created_at: "2026-02-23T10:00:00Z",
           updated_at: "2026-02-23T10:00:00Z",
         },
@@ -17,6 +17,7 @@ const makeKeyframe = (overrides: Partial<Keyframe> = {}): Keyframe => ({
   timestamp_secs: 0.0,
   thumbnail_path: "/thumbs/frame0.jpg",
   full_res_path: null,
+  source_hash: null,
   created_at: "2026-02-23T10:00:00Z",
   updated_at: "2026-02-23T10:00:00Z",
   ...overrides,
@@ -22,4 +22,4 @@ export type {
   Keyframe,
 } from "./types";
 
-export { formatTimecode } from "./types";
+export { formatTimecode, thumbnailSrc } from "./types";
@@ -14,6 +14,8 @@ export interface Keyframe {
   timestamp_secs: number;
   thumbnail_path: string;
   full_res_path: string | null;
+  /** SHA-256 of the source frame content; null for legacy keyframes. */
+  source_hash: string | null;
   created_at: string;
   updated_at: string;
 }
@@ -27,14 +29,26 @@ export interface CreateKeyframeRequest {
   segment_id: number;
   frame_number: number;
   timestamp_secs: number;
-  thumbnail_path: string;
+  /** Defaults to the content-addressed cache key derived from the source hash. */
+  thumbnail_path?: string | null;
   full_res_path?: string | null;
+  source_hash?: string | null;
 }
 
 /* --------------------------------------------------------------------------
    Formatting helpers
    -------------------------------------------------------------------------- */
 
+/**
+ * Thumbnail URL for a keyframe, versioned by source content hash so the
+ * browser cache is busted when the frame content changes.
+ */
+export function thumbnailSrc(keyframe: Keyframe): string {
+  if (!keyframe.source_hash) return keyframe.thumbnail_path;
+  const sep = keyframe.thumbnail_path.includes("?") ? "&" : "?";
+  return `${keyframe.thumbnail_path}${sep}v=${keyframe.source_hash.slice(0, 16)}`;
+}
+
 /**
  * Format seconds as a timecode string (MM:SS.f).
  *
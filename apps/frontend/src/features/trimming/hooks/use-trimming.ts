@@ -14,6 +14,7 @@ import type {
   BatchTrimRequest,
   BatchTrimResponse,
   CreateTrimRequest,
+  ProposedTrim,
   SeedFrameUpdate,
   SegmentTrim,
 } from "../types";
@@ -26,8 +27,10 @@ export const trimmingKeys = {
   all: ["trims"] as const,
   activeTrim: (segmentId: number) =>
     ["trims", "active", segmentId] as const,
-  seedImpact: (segmentId: number) =>
-    ["trims", "seed-impact", segmentId] as const,
+  seedImpact: (segmentId: number, proposed?: ProposedTrim) =>
+    proposed
+      ? (["trims", "seed-impact", segmentId, proposed] as const)
+      : (["trims", "seed-impact", segmentId] as const),
 };
 
 /* --------------------------------------------------------------------------
@@ -44,12 +47,23 @@ export function useActiveTrim(segmentId: number) {
   });
 }
 
-/** Check the downstream seed frame impact of a trim. */
-export function useSeedFrameImpact(segmentId: number) {
+/**
+ * Check the downstream seed frame impact of a trim.
+ *
+ * Evaluates `proposed` when given, so the UI can warn before applying;
+ * otherwise evaluates the segment's active trim.
+ */
+export function useSeedFrameImpact(segmentId: number, proposed?: ProposedTrim) {
   return useQuery({
-    queryKey: trimmingKeys.seedImpact(segmentId),
-    queryFn: () =>
-      api.get<SeedFrameUpdate>(`/segments/${segmentId}/trim/seed-impact`),
+    queryKey: trimmingKeys.seedImpact(segmentId, proposed),
+    queryFn: () => {
+      const query = proposed
+        ? `?in_frame=${proposed.in_frame}&out_frame=${proposed.out_frame}&total_frames=${proposed.total_frames}`
+        : "";
+      return api.get<SeedFrameUpdate>(
+        `/segments/${segmentId}/trim/seed-impact${query}`,
+      );
+    },
     enabled: segmentId > 0,
   });
 }
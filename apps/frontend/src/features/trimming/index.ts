@@ -24,8 +24,13 @@ export type {
   ApplyPresetRequest,
   BatchTrimRequest,
   BatchTrimResponse,
+  BatchTrimResult,
+  BatchTrimStatus,
+  BatchTrimSummary,
   CreateTrimRequest,
+  ProposedTrim,
   SeedFrameUpdate,
+  SeedImpactReport,
   SegmentTrim,
   TrimPreset,
 } from "./types";
@@ -38,6 +38,10 @@ export interface BatchTrimRequest {
   segment_ids: number[];
   in_frame: number;
   out_frame: number;
+  /** Untrimmed frame count (defaults to `out_frame`). */
+  total_frames?: number;
+  /** Segments trimmed at once (default 4, max 16). */
+  concurrency?: number;
 }
 
 /** Request body for applying a quick trim preset to a segment. */
@@ -52,9 +56,39 @@ export interface ApplyPresetRequest {
    -------------------------------------------------------------------------- */
 
 /** Response returned after a batch trim operation. */
+export type BatchTrimStatus = "trimmed" | "skipped" | "failed";
+
+/** Outcome of one segment within a batch trim. */
+export interface BatchTrimResult {
+  segment_id: number;
+  status: BatchTrimStatus;
+  trim_id: number | null;
+  reason: string | null;
+  impact: SeedImpactReport | null;
+}
+
+export interface BatchTrimSummary {
+  total: number;
+  trimmed: number;
+  skipped: number;
+  failed: number;
+}
+
 export interface BatchTrimResponse {
   trim_ids: number[];
   count: number;
+  results: BatchTrimResult[];
+  summary: BatchTrimSummary;
+}
+
+/** Effect of a trim on the seed frame handed to the next segment. */
+export interface SeedImpactReport {
+  seed_anchor_frame: number;
+  new_seed_frame: number;
+  anchor_retained: boolean;
+  displaced_frames: number;
+  downstream_generated: boolean;
+  regeneration_required: boolean;
 }
 
 /** Response describing the seed frame impact of a trim. */
@@ -63,6 +97,14 @@ export interface SeedFrameUpdate {
   new_seed_frame: number;
   downstream_segment_id: number | null;
   downstream_invalidated: boolean;
+  impact: SeedImpactReport | null;
+}
+
+/** A proposed trim to preview before applying. */
+export interface ProposedTrim {
+  in_frame: number;
+  out_frame: number;
+  total_frames: number;
 }
 
 /* --------------------------------------------------------------------------
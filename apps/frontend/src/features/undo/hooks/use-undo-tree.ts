@@ -6,7 +6,12 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 
 import { api } from "@/lib/api";
 
-import type { SaveUndoTreeInput, UndoTreeEntity } from "../types";
+import type {
+  SaveUndoTreeInput,
+  UndoTreeEntity,
+  UndoTreeListParams,
+  UndoTreeSummary,
+} from "../types";
 
 /* --------------------------------------------------------------------------
    Query Keys
@@ -17,6 +22,8 @@ export const undoTreeKeys = {
   tree: (entityType: string, entityId: number) =>
     [...undoTreeKeys.all, entityType, entityId] as const,
   userTrees: ["undo-trees"] as const,
+  userTreeList: (params: UndoTreeListParams) =>
+    [...undoTreeKeys.userTrees, params] as const,
 };
 
 /* --------------------------------------------------------------------------
@@ -71,10 +78,16 @@ export function useDeleteUndoTree(entityType: string, entityId: number) {
   });
 }
 
-/** List all undo trees for the current user. */
-export function useUserUndoTrees() {
+/** List undo tree summaries for the current user, newest first. */
+export function useUserUndoTrees(params: UndoTreeListParams = {}) {
+  const qs = new URLSearchParams();
+  if (params.entity_type) qs.set("entity_type", params.entity_type);
+  if (params.limit) qs.set("limit", String(params.limit));
+  if (params.offset) qs.set("offset", String(params.offset));
+  const suffix = qs.toString() ? `?${qs.toString()}` : "";
+
   return useQuery({
-    queryKey: undoTreeKeys.userTrees,
-    queryFn: () => api.get<UndoTreeEntity[]>("/user/undo-trees"),
+    queryKey: undoTreeKeys.userTreeList(params),
+    queryFn: () => api.get<UndoTreeSummary[]>(`/user/undo-trees${suffix}`),
   });
 }
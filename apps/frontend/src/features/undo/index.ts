@@ -27,6 +27,8 @@ export type {
   SerializedCommand,
   UndoTreeData,
   UndoTreeEntity,
+  UndoTreeListParams,
+  UndoTreeSummary,
   SaveUndoTreeInput,
   NonUndoableAction,
 } from "./types";
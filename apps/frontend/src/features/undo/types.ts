@@ -44,6 +44,23 @@ export interface UndoTreeEntity {
   updated_at: string;
 }
 
+/** Summary of a stored undo tree, as listed by `/user/undo-trees`. */
+export interface UndoTreeSummary {
+  id: number;
+  entity_type: string;
+  entity_id: number;
+  node_count: number;
+  current_node_id: string | null;
+  updated_at: string;
+}
+
+/** Query parameters for listing undo tree summaries. */
+export interface UndoTreeListParams {
+  entity_type?: string;
+  limit?: number;
+  offset?: number;
+}
+
 /** DTO for saving an undo tree to the server. */
 export interface SaveUndoTreeInput {
   tree_json: UndoTreeData;
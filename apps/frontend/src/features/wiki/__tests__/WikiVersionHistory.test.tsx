@@ -19,27 +19,36 @@ const mockVersions: WikiVersion[] = [
     id: 3,
     article_id: 1,
     version: 3,
+    title: "Getting Started",
     content_md: "Updated content v3",
     edited_by: 100,
     edit_summary: "Fixed typo",
+    reverted_from_version: null,
+    reverted_to_version: null,
     created_at: "2026-02-20T14:00:00Z",
   },
   {
     id: 2,
     article_id: 1,
     version: 2,
+    title: "Getting Started",
     content_md: "Updated content v2",
     edited_by: 101,
     edit_summary: "Added new section",
+    reverted_from_version: null,
+    reverted_to_version: null,
     created_at: "2026-02-20T12:00:00Z",
   },
   {
     id: 1,
     article_id: 1,
     version: 1,
+    title: "Getting Started",
     content_md: "Initial content",
     edited_by: 100,
     edit_summary: "Initial version",
+    reverted_from_version: null,
+    reverted_to_version: null,
     created_at: "2026-02-20T10:00:00Z",
   },
 ];
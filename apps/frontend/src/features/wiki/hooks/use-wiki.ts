@@ -13,7 +13,9 @@ import type {
   DiffResponse,
   UpdateWikiArticle,
   WikiArticle,
+  WikiRevertResult,
   WikiVersion,
+  WordDiffResponse,
 } from "../types";
 
 /* --------------------------------------------------------------------------
@@ -31,6 +33,8 @@ export const wikiKeys = {
     [...wikiKeys.all, "version", slug, version] as const,
   diff: (slug: string, v1: number, v2: number) =>
     [...wikiKeys.all, "diff", slug, v1, v2] as const,
+  wordDiff: (slug: string, v1: number, v2: number) =>
+    [...wikiKeys.all, "word-diff", slug, v1, v2] as const,
   search: (query: string) => [...wikiKeys.all, "search", query] as const,
   help: (elementId: string) =>
     [...wikiKeys.all, "help", elementId] as const,
@@ -134,6 +138,18 @@ export function useDiffVersions(slug: string, v1: number, v2: number) {
   });
 }
 
+/** Compute a word-level diff between two versions of an article. */
+export function useWordDiffVersions(slug: string, v1: number, v2: number) {
+  return useQuery({
+    queryKey: wikiKeys.wordDiff(slug, v1, v2),
+    queryFn: () =>
+      api.get<WordDiffResponse>(
+        `/wiki/articles/${slug}/word-diff?v1=${v1}&v2=${v2}`,
+      ),
+    enabled: slug.length > 0 && v1 > 0 && v2 > 0 && v1 !== v2,
+  });
+}
+
 /* --------------------------------------------------------------------------
    Mutations
    -------------------------------------------------------------------------- */
@@ -183,7 +199,7 @@ export function useRevertVersion(slug: string) {
 
   return useMutation({
     mutationFn: (version: number) =>
-      api.post<WikiArticle>(
+      api.post<WikiRevertResult>(
         `/wiki/articles/${slug}/revert/${version}`,
       ),
     onSuccess: () => {
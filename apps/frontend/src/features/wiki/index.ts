@@ -12,11 +12,15 @@ export type {
   DiffLine,
   DiffLineType,
   DiffResponse,
+  DiffSpan,
   PinLocation,
+  TitleChange,
   UpdateWikiArticle,
   WikiArticle,
   WikiCategory,
+  WikiRevertResult,
   WikiVersion,
+  WordDiffResponse,
 } from "./types";
 export {
   CATEGORY_LABELS,
@@ -38,6 +42,7 @@ export {
   useWikiArticles,
   useWikiVersion,
   useWikiVersions,
+  useWordDiffVersions,
   wikiKeys,
 } from "./hooks/use-wiki";
 
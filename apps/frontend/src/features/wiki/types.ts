@@ -52,12 +52,24 @@ export interface WikiVersion {
   id: number;
   article_id: number;
   version: number;
+  /** Null for versions recorded before titles were snapshotted. */
+  title: string | null;
   content_md: string;
   edited_by: number | null;
   edit_summary: string | null;
+  /** For a revert, the version that was current when it was made. */
+  reverted_from_version: number | null;
+  /** For a revert, the version whose content it restored. */
+  reverted_to_version: number | null;
   created_at: string;
 }
 
+/** Result of reverting an article: the article and the appended version. */
+export interface WikiRevertResult {
+  article: WikiArticle;
+  version: WikiVersion;
+}
+
 /* --------------------------------------------------------------------------
    Diff types
    -------------------------------------------------------------------------- */
@@ -77,6 +89,30 @@ export interface DiffResponse {
   lines: DiffLine[];
 }
 
+export interface DiffSpan {
+  span_type: DiffLineType;
+  text: string;
+}
+
+export interface TitleChange {
+  from: string;
+  to: string;
+}
+
+/** Word-level diff from version `v1` to `v2`, with `v2`'s edit metadata. */
+export interface WordDiffResponse {
+  article_id: number;
+  slug: string;
+  v1: number;
+  v2: number;
+  title_change: TitleChange | null;
+  author: number | null;
+  edit_summary: string | null;
+  words_added: number;
+  words_removed: number;
+  spans: DiffSpan[];
+}
+
 /* --------------------------------------------------------------------------
    Contextual help
    -------------------------------------------------------------------------- */
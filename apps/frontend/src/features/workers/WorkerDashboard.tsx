@@ -46,7 +46,7 @@ export function WorkerDashboard() {
         <Stack gap={6}>
           {/* Fleet stats */}
           {stats && (
-            <div className="grid grid-cols-2 gap-[var(--spacing-3)] sm:grid-cols-3 lg:grid-cols-7">
+            <div className="grid grid-cols-2 gap-[var(--spacing-3)] sm:grid-cols-3 lg:grid-cols-5">
               <StatBadge label="Total" value={stats.total_workers} />
               <StatBadge label="Idle" value={stats.idle_workers} />
               <StatBadge label="Busy" value={stats.busy_workers} />
@@ -54,6 +54,16 @@ export function WorkerDashboard() {
               <StatBadge label="Draining" value={stats.draining_workers} />
               <StatBadge label="Approved" value={stats.approved_workers} />
               <StatBadge label="Enabled" value={stats.enabled_workers} />
+              <StatBadge
+                label="Available GPUs"
+                value={`${stats.available_capacity} / ${stats.total_capacity}`}
+              />
+              <StatBadge
+                label="Utilization"
+                value={
+                  stats.utilization_pct === null ? "-" : `${Math.round(stats.utilization_pct)}%`
+                }
+              />
             </div>
           )}
 
@@ -60,6 +60,9 @@ vi.mock("@/lib/api", () => ({
           draining_workers: 0,
           approved_workers: 2,
           enabled_workers: 2,
+          total_capacity: 4,
+          available_capacity: 2,
+          utilization_pct: 37.5,
         });
       }
       return Promise.resolve([]);
@@ -88,6 +88,12 @@ export interface FleetStats {
   draining_workers: number;
   approved_workers: number;
   enabled_workers: number;
+  /** GPUs on workers that are not offline. */
+  total_capacity: number;
+  /** GPUs on idle, approved, enabled workers. */
+  available_capacity: number;
+  /** Mean GPU utilization (0-100); null when no GPU has reported. */
+  utilization_pct: number | null;
 }
 
 /** A worker health-log entry (status transition record). */
@@ -145,6 +145,10 @@ export interface WorkflowTelemetry {
   workflow_id: number;
   nodes: Record<string, NodeTelemetryEntry>;
   total_ms: number | null;
+  total_nodes: number;
+  total_connections: number;
+  /** Node count per class type, sorted by class type. */
+  class_type_counts: Record<string, number>;
 }
 
 // ---------------------------------------------------------------------------
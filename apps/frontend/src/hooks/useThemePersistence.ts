@@ -21,8 +21,14 @@ interface ThemePreferenceResponse {
   brand_palette: string;
   high_contrast: boolean;
   custom_theme_id: number | null;
+  token_overrides: Record<string, unknown>;
   created_at: string;
   updated_at: string;
+  /** Studio theme tokens with the user's overrides applied. */
+  effective: {
+    tokens: Record<string, unknown>;
+    overridden_tokens: string[];
+  };
 }
 
 interface ThemeState {